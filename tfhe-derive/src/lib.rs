@@ -0,0 +1,145 @@
+//! Derive macros for the high-level API of [TFHE-rs](https://docs.rs/tfhe).
+//!
+//! This crate is not meant to be used directly, the macros are re-exported by `tfhe` when its
+//! `derive` feature is enabled.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::{parse_macro_input, Data, DeriveInput, Fields, Index};
+
+/// Derives `tfhe::FheEncryptable` for a struct whose fields are `FheEncryptable`: integers,
+/// booleans, fixed-size arrays of those, or other structs deriving `FheEncryptable`.
+///
+/// For a struct `Name`, the macro generates a struct `EncryptedName` with the same fields and
+/// the same visibilities, each field holding the encrypted counterpart of the clear field, e.g.
+/// a `FheUint8` for a `u8`. The fields of the encrypted struct can be used directly in
+/// homomorphic operations.
+///
+/// The macro also generates:
+///
+/// - the conversions between `EncryptedName` and the tuple of its fields, in declaration order,
+/// - `tfhe::prelude::FheDecrypt<Name>` for `EncryptedName`.
+///
+/// Generic structs, enums and unions are not supported.
+#[proc_macro_derive(FheEncryptable)]
+pub fn derive_fhe_encryptable(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match expand_fhe_encryptable(&input) {
+        Ok(expanded) => expanded.into(),
+        Err(error) => error.to_compile_error().into(),
+    }
+}
+
+fn expand_fhe_encryptable(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    if !input.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &input.generics,
+            "FheEncryptable cannot be derived for generic structs",
+        ));
+    }
+    let fields = match &input.data {
+        Data::Struct(data) => &data.fields,
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &input.ident,
+                "FheEncryptable can only be derived for structs",
+            ))
+        }
+    };
+    if fields.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &input.ident,
+            "FheEncryptable cannot be derived for structs without fields",
+        ));
+    }
+
+    let vis = &input.vis;
+    let name = &input.ident;
+    let encrypted_name = format_ident!("Encrypted{}", name);
+    let doc = format!("The encrypted counterpart of [`{name}`], see `tfhe::FheEncryptable`.");
+
+    let types = fields.iter().map(|field| &field.ty).collect::<Vec<_>>();
+    let encrypted_types = types
+        .iter()
+        .map(|ty| quote! { <#ty as ::tfhe::FheEncryptable>::Encrypted })
+        .collect::<Vec<_>>();
+    let vars = (0..fields.len())
+        .map(|i| format_ident!("field_{}", i))
+        .collect::<Vec<_>>();
+
+    // The fields are accessed by name for structs with named fields, by index otherwise
+    let (members, definition): (Vec<TokenStream2>, TokenStream2) = match fields {
+        Fields::Named(named) => {
+            let idents = named
+                .named
+                .iter()
+                .map(|field| field.ident.clone().unwrap())
+                .collect::<Vec<_>>();
+            let field_vis = named.named.iter().map(|field| &field.vis);
+            let definition = quote! {
+                #vis struct #encrypted_name {
+                    #(#field_vis #idents: #encrypted_types,)*
+                }
+            };
+            (
+                idents.iter().map(|ident| quote! { #ident }).collect(),
+                definition,
+            )
+        }
+        Fields::Unnamed(unnamed) => {
+            let field_vis = unnamed.unnamed.iter().map(|field| &field.vis);
+            let definition = quote! {
+                #vis struct #encrypted_name(#(#field_vis #encrypted_types,)*);
+            };
+            let indices = (0..fields.len()).map(Index::from);
+            (indices.map(|index| quote! { #index }).collect(), definition)
+        }
+        Fields::Unit => unreachable!("unit structs have no fields"),
+    };
+
+    Ok(quote! {
+        #[doc = #doc]
+        #[derive(Clone)]
+        #definition
+
+        impl ::tfhe::FheEncryptable for #name {
+            type Encrypted = #encrypted_name;
+
+            fn encrypt(&self, key: &::tfhe::ClientKey) -> Self::Encrypted {
+                #encrypted_name {
+                    #(#members: ::tfhe::FheEncryptable::encrypt(&self.#members, key),)*
+                }
+            }
+
+            fn decrypt(encrypted: &Self::Encrypted, key: &::tfhe::ClientKey) -> Self {
+                #name {
+                    #(#members: <#types as ::tfhe::FheEncryptable>::decrypt(
+                        &encrypted.#members,
+                        key,
+                    ),)*
+                }
+            }
+        }
+
+        impl ::tfhe::prelude::FheDecrypt<#name> for #encrypted_name {
+            fn decrypt(&self, key: &::tfhe::ClientKey) -> #name {
+                <#name as ::tfhe::FheEncryptable>::decrypt(self, key)
+            }
+        }
+
+        impl ::core::convert::From<#encrypted_name> for (#(#encrypted_types,)*) {
+            fn from(encrypted: #encrypted_name) -> Self {
+                (#(encrypted.#members,)*)
+            }
+        }
+
+        impl ::core::convert::From<(#(#encrypted_types,)*)> for #encrypted_name {
+            fn from((#(#vars,)*): (#(#encrypted_types,)*)) -> Self {
+                #encrypted_name {
+                    #(#members: #vars,)*
+                }
+            }
+        }
+    })
+}
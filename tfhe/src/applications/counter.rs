@@ -0,0 +1,195 @@
+//! A saturating counter over a small encrypted range.
+//!
+//! A [`FheCounter`] holds an encrypted value between 0 and a clear maximum, e.g. the number of
+//! requests made with an encrypted identifier, and raises an encrypted alarm once a threshold is
+//! exceeded, e.g. for rate limiting or metering.
+//!
+//! The value is made of one or two blocks, small enough for each update to be a lookup table
+//! over the whole value rather than a radix operation: an update costs one PBS per block and
+//! never propagates carries. The saturation is part of the lookup table, so an increment of a
+//! counter at its maximum keeps it there instead of wrapping around.
+//!
+//! # Example
+//!
+//! ```rust
+//! use tfhe::applications::counter::FheCounter;
+//! use tfhe::integer::gen_keys_radix;
+//! use tfhe::shortint::ciphertext::KeyswitchBootstrap;
+//! use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+//!
+//! // Values of 4 bits
+//! let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2, 2);
+//!
+//! let mut counter: FheCounter<KeyswitchBootstrap> = FheCounter::new(&sks, 2, 10);
+//! counter.increment(&sks);
+//! counter.add(&sks, 8);
+//!
+//! let alarm = counter.exceeds(&sks, 9);
+//! assert!(!cks.decrypt_bool(&alarm));
+//!
+//! // Saturates at 10
+//! counter.add(&sks, 5);
+//! assert_eq!(cks.decrypt::<u64, _>(counter.value()), 10);
+//!
+//! let alarm = counter.exceeds(&sks, 9);
+//! assert!(cks.decrypt_bool(&alarm));
+//! ```
+
+use crate::integer::ciphertext::{BooleanBlock, RadixCiphertext};
+use crate::integer::ServerKey;
+use crate::shortint::{CiphertextBase, PBSOrderMarker};
+use serde::{Deserialize, Serialize};
+
+declare_application! {
+    name: "counter",
+    version: (1, 0),
+    /// Counters of 4 bits. Every update is a lookup table over the whole value, which the preset
+    /// keeps within two blocks so that an update costs one PBS per block.
+    num_blocks: 2,
+}
+
+/// An encrypted counter saturating at a clear maximum.
+#[derive(Serialize, Clone, Deserialize)]
+pub struct FheCounter<PBSOrder: PBSOrderMarker> {
+    value: RadixCiphertext<PBSOrder>,
+    max: u64,
+}
+
+impl<PBSOrder: PBSOrderMarker> FheCounter<PBSOrder> {
+    /// Creates a counter of `num_blocks` blocks starting at 0 and saturating at `max`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `num_blocks` is not 1 or 2, if `max` does not fit in `num_blocks` blocks, or if
+    /// the counter has 2 blocks and the carry modulus of the server key is smaller than its
+    /// message modulus, as both blocks are then packed in the input of a single PBS.
+    pub fn new(server_key: &ServerKey, num_blocks: usize, max: u64) -> Self {
+        assert!(
+            num_blocks == 1 || num_blocks == 2,
+            "A counter has 1 or 2 blocks, got {num_blocks}"
+        );
+        let message_modulus = server_key.key.message_modulus.0 as u64;
+        let modulus = message_modulus.pow(num_blocks as u32);
+        assert!(
+            max < modulus,
+            "The maximum {max} does not fit in {num_blocks} blocks, which hold values below \
+            {modulus}"
+        );
+        assert!(
+            num_blocks == 1 || server_key.key.carry_modulus.0 >= server_key.key.message_modulus.0,
+            "A counter of 2 blocks needs a carry modulus at least as large as the message modulus"
+        );
+
+        Self {
+            value: server_key.create_trivial_zero_radix(num_blocks),
+            max,
+        }
+    }
+
+    pub fn max(&self) -> u64 {
+        self.max
+    }
+
+    /// Returns the encrypted value of the counter, whose blocks have empty carries.
+    pub fn value(&self) -> &RadixCiphertext<PBSOrder> {
+        &self.value
+    }
+
+    /// Adds 1 to the counter, unless it is at its maximum.
+    pub fn increment(&mut self, server_key: &ServerKey) {
+        self.add(server_key, 1);
+    }
+
+    /// Adds `amount` to the counter, saturating at its maximum.
+    pub fn add(&mut self, server_key: &ServerKey, amount: u64) {
+        let max = self.max;
+        let f = |x: u64| x.saturating_add(amount).min(max);
+
+        self.value = match self.value.blocks.as_slice() {
+            [_] => RadixCiphertext::from(vec![self.evaluate_block(server_key, f, 0)]),
+            [_, _] => {
+                let (low, high) = rayon::join(
+                    || self.evaluate_block(server_key, f, 0),
+                    || self.evaluate_block(server_key, f, 1),
+                );
+                RadixCiphertext::from(vec![low, high])
+            }
+            _ => unreachable!(),
+        };
+    }
+
+    /// Returns an encryption of true if the value of the counter is strictly greater than
+    /// `threshold`, of false otherwise.
+    ///
+    /// The result is encrypted under the keys of the counter, so it can be combined with the
+    /// other integer ciphertexts of the server, unlike the `FheBool` of the high-level API which
+    /// is encrypted under the keys of the `boolean` module.
+    pub fn exceeds(&self, server_key: &ServerKey, threshold: u64) -> BooleanBlock<PBSOrder> {
+        let block = self.evaluate_block(server_key, |x| u64::from(x > threshold), 0);
+        BooleanBlock::new_unchecked(block)
+    }
+
+    /// Returns the block `block_index` of `f(value)` computed with a single PBS, `f` must output
+    /// values fitting in the blocks of the counter.
+    fn evaluate_block<F>(
+        &self,
+        server_key: &ServerKey,
+        f: F,
+        block_index: u32,
+    ) -> CiphertextBase<PBSOrder>
+    where
+        F: Fn(u64) -> u64,
+    {
+        let message_modulus = server_key.key.message_modulus.0 as u64;
+        let block_of = |x: u64| (f(x) / message_modulus.pow(block_index)) % message_modulus;
+
+        match self.value.blocks.as_slice() {
+            [block] => {
+                let acc = server_key.key.generate_accumulator(block_of);
+                server_key.key.apply_lookup_table(block, &acc)
+            }
+            [low, high] => {
+                server_key
+                    .key
+                    .unchecked_evaluate_bivariate_function(high, low, |high, low| {
+                        block_of(high * message_modulus + low)
+                    })
+            }
+            _ => unreachable!(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::integer::keycache::KEY_CACHE;
+    use crate::shortint::ciphertext::KeyswitchBootstrap;
+
+    #[test]
+    fn test_counter() {
+        let (cks, sks) = KEY_CACHE.get_from_params(DEFAULT_PARAMETERS.block_parameters);
+
+        for (num_blocks, max) in [(1, 3u64), (1, 2), (2, 15), (2, 11)] {
+            let mut counter = FheCounter::<KeyswitchBootstrap>::new(&sks, num_blocks, max);
+            let mut clear = 0u64;
+
+            for amount in [1u64, 0, 2, 1, 7, 1, u64::MAX] {
+                if amount == 1 {
+                    counter.increment(&sks);
+                } else {
+                    counter.add(&sks, amount);
+                }
+                clear = clear.saturating_add(amount).min(max);
+
+                assert_eq!(cks.decrypt_radix::<u64, _>(counter.value()), clear);
+                assert!(counter.value().block_carries_are_empty());
+
+                for threshold in [0, max / 2, max] {
+                    let alarm = counter.exceeds(&sks, threshold);
+                    assert_eq!(cks.decrypt_bool(&alarm), clear > threshold);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,262 @@
+//! Location privacy primitives over encrypted coordinates.
+//!
+//! Coordinates are unsigned integers on a grid chosen by the client, e.g. a latitude `lat` in
+//! degrees quantized to `round((lat + 90) * 10^4)`. The server evaluates predicates on encrypted
+//! positions against clear areas, it only learns the areas and the result, an encrypted
+//! [`BooleanBlock`]:
+//!
+//! * [`in_bbox`] checks whether a position lies in a rectangle of the grid;
+//! * [`within_distance_approx`] checks whether two positions are within a radius of each other.
+//!
+//! The distance is the Euclidean distance on the grid, which only approximates the geographic
+//! distance over small areas, and if the grid units of both axes cover the same distance: a
+//! longitude grid should be scaled by the cosine of the latitude of the area of interest.
+//!
+//! # Example
+//!
+//! ```rust
+//! use tfhe::applications::geo::{in_bbox, within_distance_approx, BoundingBox, EncryptedPoint};
+//! use tfhe::integer::gen_keys_radix;
+//! use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+//!
+//! // Coordinates of 8 bits
+//! let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2, 4);
+//!
+//! let home = EncryptedPoint::new(cks.encrypt(120u64), cks.encrypt(45u64));
+//! let shop = EncryptedPoint::new(cks.encrypt(123u64), cks.encrypt(41u64));
+//!
+//! let area = BoundingBox::new(100, 40, 130, 60);
+//! let is_inside = in_bbox(&sks, &home.lat, &home.lon, &area);
+//! assert!(cks.decrypt_bool(&is_inside));
+//!
+//! // The points are 5 units apart
+//! let is_close = within_distance_approx(&sks, &home, &shop, 5);
+//! assert!(cks.decrypt_bool(&is_close));
+//! let is_close = within_distance_approx(&sks, &home, &shop, 4);
+//! assert!(!cks.decrypt_bool(&is_close));
+//! ```
+
+use crate::integer::ciphertext::{BooleanBlock, RadixCiphertext};
+use crate::integer::ServerKey;
+use crate::shortint::PBSOrderMarker;
+use serde::{Deserialize, Serialize};
+
+declare_application! {
+    name: "geo",
+    version: (1, 0),
+    /// Coordinates of 16 bits, e.g. an area of 65 kilometers wide at a resolution of a meter. The
+    /// squared distances are computed over twice as many blocks, so wider coordinates quickly make
+    /// [`within_distance_approx`] more expensive than the bounding box checks.
+    num_blocks: 8,
+}
+
+/// The maximum number of bits of a coordinate, so that the squared distance between two points
+/// can be compared to a clear squared radius of 64 bits.
+pub const MAX_COORDINATE_BITS: u32 = 32;
+
+/// A rectangle of the grid, bounds included.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BoundingBox {
+    pub min_lat: u64,
+    pub min_lon: u64,
+    pub max_lat: u64,
+    pub max_lon: u64,
+}
+
+impl BoundingBox {
+    /// Creates the rectangle between the corners `(min_lat, min_lon)` and `(max_lat, max_lon)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a minimum is greater than the corresponding maximum. An area crossing the
+    /// boundary of the grid, e.g. the antimeridian, has to be split into two rectangles.
+    pub fn new(min_lat: u64, min_lon: u64, max_lat: u64, max_lon: u64) -> Self {
+        assert!(
+            min_lat <= max_lat && min_lon <= max_lon,
+            "The minimum corner ({min_lat}, {min_lon}) of the bounding box is not below its \
+            maximum corner ({max_lat}, {max_lon})"
+        );
+        Self {
+            min_lat,
+            min_lon,
+            max_lat,
+            max_lon,
+        }
+    }
+}
+
+/// An encrypted position on the grid.
+#[derive(Serialize, Clone, Deserialize)]
+pub struct EncryptedPoint<PBSOrder: PBSOrderMarker> {
+    pub lat: RadixCiphertext<PBSOrder>,
+    pub lon: RadixCiphertext<PBSOrder>,
+}
+
+impl<PBSOrder: PBSOrderMarker> EncryptedPoint<PBSOrder> {
+    pub fn new(lat: RadixCiphertext<PBSOrder>, lon: RadixCiphertext<PBSOrder>) -> Self {
+        Self { lat, lon }
+    }
+}
+
+/// Returns an encryption of true if the position `(lat, lon)` lies in `bbox`, of false
+/// otherwise.
+///
+/// The four bounds are compared in parallel, their results are combined with a single PBS.
+pub fn in_bbox<PBSOrder: PBSOrderMarker>(
+    server_key: &ServerKey,
+    lat: &RadixCiphertext<PBSOrder>,
+    lon: &RadixCiphertext<PBSOrder>,
+    bbox: &BoundingBox,
+) -> BooleanBlock<PBSOrder> {
+    let ((above_min_lat, below_max_lat), (above_min_lon, below_max_lon)) = rayon::join(
+        || {
+            rayon::join(
+                || server_key.scalar_ge_bool_parallelized(lat, bbox.min_lat),
+                || server_key.scalar_le_bool_parallelized(lat, bbox.max_lat),
+            )
+        },
+        || {
+            rayon::join(
+                || server_key.scalar_ge_bool_parallelized(lon, bbox.min_lon),
+                || server_key.scalar_le_bool_parallelized(lon, bbox.max_lon),
+            )
+        },
+    );
+    all_true(
+        server_key,
+        &[above_min_lat, below_max_lat, above_min_lon, below_max_lon],
+    )
+}
+
+/// Returns an encryption of true if the distance between `p1` and `p2` on the grid is at most
+/// `radius`, of false otherwise.
+///
+/// The squared distance is computed over enough bits for it not to overflow, i.e. twice the
+/// number of bits of the coordinates plus one, which makes the two squarings the most expensive
+/// step.
+///
+/// # Panics
+///
+/// Panics if the coordinates do not all have the same number of blocks, or if they have more
+/// than [`MAX_COORDINATE_BITS`] bits.
+pub fn within_distance_approx<PBSOrder: PBSOrderMarker>(
+    server_key: &ServerKey,
+    p1: &EncryptedPoint<PBSOrder>,
+    p2: &EncryptedPoint<PBSOrder>,
+    radius: u32,
+) -> BooleanBlock<PBSOrder> {
+    let num_blocks = p1.lat.blocks.len();
+    assert!(
+        [&p1.lon, &p2.lat, &p2.lon]
+            .iter()
+            .all(|coordinate| coordinate.blocks.len() == num_blocks),
+        "The coordinates of the points must have the same number of blocks"
+    );
+    let bits_per_block = server_key.key.message_modulus.0.ilog2();
+    let coordinate_bits = num_blocks as u32 * bits_per_block;
+    assert!(
+        coordinate_bits <= MAX_COORDINATE_BITS,
+        "The coordinates have {coordinate_bits} bits, at most {MAX_COORDINATE_BITS} are supported"
+    );
+    let squared_distance_bits = 2 * coordinate_bits + 1;
+    let squared_distance_blocks =
+        ((squared_distance_bits + bits_per_block - 1) / bits_per_block) as usize;
+
+    let squared_abs_diff = |lhs: &RadixCiphertext<PBSOrder>, rhs: &RadixCiphertext<PBSOrder>| {
+        let (max, min) = rayon::join(
+            || server_key.max_parallelized(lhs, rhs),
+            || server_key.min_parallelized(lhs, rhs),
+        );
+        let abs_diff = server_key.extend_radix_with_trivial_zero_blocks_msb(
+            &server_key.sub_parallelized(&max, &min),
+            squared_distance_blocks - num_blocks,
+        );
+        server_key.mul_parallelized(&abs_diff, &abs_diff)
+    };
+
+    let (lat_term, lon_term) = rayon::join(
+        || squared_abs_diff(&p1.lat, &p2.lat),
+        || squared_abs_diff(&p1.lon, &p2.lon),
+    );
+    let squared_distance = server_key.add_parallelized(&lat_term, &lon_term);
+
+    server_key.scalar_le_bool_parallelized(&squared_distance, u64::from(radius).pow(2))
+}
+
+/// Returns an encryption of true if all the `conditions` are true, with a single PBS.
+///
+/// The conditions are summed up in one block, so there must be fewer of them than the message
+/// and carry space of a block can hold.
+fn all_true<PBSOrder: PBSOrderMarker>(
+    server_key: &ServerKey,
+    conditions: &[BooleanBlock<PBSOrder>],
+) -> BooleanBlock<PBSOrder> {
+    let count = conditions.len() as u64;
+    let mut sum = conditions[0].as_block().clone();
+    for condition in &conditions[1..] {
+        server_key
+            .key
+            .unchecked_add_assign(&mut sum, condition.as_block());
+    }
+    let acc = server_key
+        .key
+        .generate_accumulator(|x| u64::from(x == count));
+    BooleanBlock::new_unchecked(server_key.key.apply_lookup_table(&sum, &acc))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::integer::keycache::KEY_CACHE;
+
+    #[test]
+    fn test_geo() {
+        let (cks, sks) = KEY_CACHE.get_from_params(DEFAULT_PARAMETERS.block_parameters);
+        let num_blocks = 4;
+
+        let bbox = BoundingBox::new(10, 200, 50, 255);
+        let positions = [
+            (30u64, 220u64),
+            (10, 255),
+            (9, 220),
+            (30, 199),
+            (51, 255),
+            (0, 0),
+        ];
+
+        for (lat, lon) in positions {
+            let ct_lat = cks.encrypt_radix(lat, num_blocks);
+            let ct_lon = cks.encrypt_radix(lon, num_blocks);
+            let is_inside = in_bbox(&sks, &ct_lat, &ct_lon, &bbox);
+
+            let expected = (bbox.min_lat..=bbox.max_lat).contains(&lat)
+                && (bbox.min_lon..=bbox.max_lon).contains(&lon);
+            assert_eq!(cks.decrypt_bool(&is_inside), expected);
+        }
+
+        // The squared distance from (0, 0) to (255, 255), the largest of the grid, needs 17 bits
+        let pairs = [
+            ((3u64, 4u64), (0u64, 0u64), 5u32),
+            ((0, 0), (255, 255), 360),
+            ((7, 7), (7, 7), 0),
+        ];
+
+        for ((lat_1, lon_1), (lat_2, lon_2), radius) in pairs {
+            let p1 = EncryptedPoint::new(
+                cks.encrypt_radix(lat_1, num_blocks),
+                cks.encrypt_radix(lon_1, num_blocks),
+            );
+            let p2 = EncryptedPoint::new(
+                cks.encrypt_radix(lat_2, num_blocks),
+                cks.encrypt_radix(lon_2, num_blocks),
+            );
+            let squared_distance = lat_1.abs_diff(lat_2).pow(2) + lon_1.abs_diff(lon_2).pow(2);
+
+            for r in [radius.saturating_sub(1), radius, radius + 1] {
+                let is_close = within_distance_approx(&sks, &p1, &p2, r);
+                let expected = squared_distance <= u64::from(r).pow(2);
+                assert_eq!(cks.decrypt_bool(&is_close), expected);
+            }
+        }
+    }
+}
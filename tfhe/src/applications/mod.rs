@@ -0,0 +1,290 @@
+//! End-to-end applications built on top of the [`integer`](crate::integer) API.
+//!
+//! These modules show how the homomorphic primitives of the crate combine into realistic
+//! workloads, they favor readability over raw performance.
+//!
+//! Each application is a subsystem behind its own cargo feature, `applications` enabling all of
+//! them:
+//!
+//! | Module         | Feature                   |
+//! |----------------|---------------------------|
+//! | [`counter`]    | `applications-counter`    |
+//! | [`geo`]        | `applications-geo`        |
+//! | [`stats`]      | `applications-stats`      |
+//! | [`structured`] | `applications-structured` |
+//! | [`window`]     | `applications-window`     |
+//!
+//! # Stability
+//!
+//! Each application exposes an `APPLICATION` constant holding its [`ApplicationVersion`], which
+//! is versioned independently of the crate:
+//!
+//! * the public API and the serialized format of the types of an application only change in a
+//!   backward incompatible way with a new major version;
+//! * a minor version can add functions and types, data serialized by an older minor version of
+//!   the same major version can still be deserialized.
+//!
+//! Each application also exposes a `DEFAULT_PARAMETERS` preset, the [`ApplicationParameters`] its
+//! accuracy and performance are tested with. A preset only changes with a new major version.
+//!
+//! Data meant to be stored or sent to another party can be wrapped in a [`Versioned`] envelope,
+//! which records the application and the version it was produced by and is checked when the data
+//! is unwrapped.
+//!
+//! # Example
+//!
+//! ```rust
+//! # #[cfg(feature = "applications-stats")]
+//! # {
+//! use tfhe::applications::stats::{mean, EncryptedFraction, APPLICATION, DEFAULT_PARAMETERS};
+//! use tfhe::applications::Versioned;
+//! use tfhe::shortint::ciphertext::KeyswitchBootstrap;
+//!
+//! let (cks, sks) = DEFAULT_PARAMETERS.gen_keys();
+//!
+//! let xs = [3u64, 5, 10].map(|x| cks.encrypt(x));
+//! let m = mean(&sks, &xs);
+//!
+//! let serialized = bincode::serialize(&Versioned::new(&APPLICATION, m)).unwrap();
+//!
+//! let versioned: Versioned<EncryptedFraction<KeyswitchBootstrap>> =
+//!     bincode::deserialize(&serialized).unwrap();
+//! let m = versioned.into_data(&APPLICATION).unwrap();
+//! assert_eq!(m.decrypt(cks.as_ref()), 6.0);
+//! # }
+//! ```
+
+use crate::integer::{gen_keys_radix, RadixClientKey, ServerKey};
+use crate::shortint::PBSParameters;
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+/// Declares the `APPLICATION` and `DEFAULT_PARAMETERS` constants of an application module.
+///
+/// The preset of every application is made of blocks of [`PARAM_MESSAGE_2_CARRY_2`], only the
+/// number of blocks differs. The doc comment given before `num_blocks` documents the preset, it
+/// should say what a radix ciphertext holds in the application and why that width was chosen.
+///
+/// [`PARAM_MESSAGE_2_CARRY_2`]: crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2
+macro_rules! declare_application {
+    (
+        name: $name:literal,
+        version: ($major:literal, $minor:literal),
+        $(#[doc = $doc:literal])*
+        num_blocks: $num_blocks:literal $(,)?
+    ) => {
+        /// The name and the current version of the application, see the
+        /// [stability policy](crate::applications#stability).
+        pub const APPLICATION: $crate::applications::ApplicationInfo =
+            $crate::applications::ApplicationInfo {
+                name: $name,
+                version: $crate::applications::ApplicationVersion::new($major, $minor),
+            };
+
+        $(#[doc = $doc])*
+        #[doc = ""]
+        #[doc = concat!(
+            "The radix ciphertexts are made of ",
+            stringify!($num_blocks),
+            " blocks of [`PARAM_MESSAGE_2_CARRY_2`]."
+        )]
+        #[doc = ""]
+        #[doc = "[`PARAM_MESSAGE_2_CARRY_2`]: crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2"]
+        pub const DEFAULT_PARAMETERS: $crate::applications::ApplicationParameters =
+            $crate::applications::ApplicationParameters {
+                block_parameters: $crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2,
+                num_blocks: $num_blocks,
+            };
+    };
+}
+
+#[cfg(feature = "applications-counter")]
+pub mod counter;
+#[cfg(feature = "applications-geo")]
+pub mod geo;
+#[cfg(feature = "applications-stats")]
+pub mod stats;
+#[cfg(feature = "applications-structured")]
+pub mod structured;
+#[cfg(feature = "applications-window")]
+pub mod window;
+
+/// The version of the public API and of the serialized format of an application.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub struct ApplicationVersion {
+    pub major: u32,
+    pub minor: u32,
+}
+
+impl ApplicationVersion {
+    pub const fn new(major: u32, minor: u32) -> Self {
+        Self { major, minor }
+    }
+
+    /// Returns true if data produced with the version `other` can be read with `self`, i.e. if
+    /// the two versions have the same major version and `other` is not newer than `self`.
+    pub fn is_compatible_with(&self, other: &Self) -> bool {
+        self.major == other.major && other.minor <= self.minor
+    }
+}
+
+impl Display for ApplicationVersion {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}.{}", self.major, self.minor)
+    }
+}
+
+/// The name and the current version of an application.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct ApplicationInfo {
+    pub name: &'static str,
+    pub version: ApplicationVersion,
+}
+
+/// A parameter preset of an application: the parameters of the blocks and the number of blocks
+/// of the radix ciphertexts.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ApplicationParameters {
+    pub block_parameters: PBSParameters,
+    pub num_blocks: usize,
+}
+
+impl ApplicationParameters {
+    /// Generates a pair of radix keys for the preset.
+    pub fn gen_keys(&self) -> (RadixClientKey, ServerKey) {
+        gen_keys_radix(self.block_parameters, self.num_blocks)
+    }
+}
+
+/// Error returned when unwrapping a [`Versioned`] envelope.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum VersionError {
+    /// The data was produced by another application
+    ApplicationMismatch { expected: String, found: String },
+    /// The data was produced by a version which can not be read by the current one
+    IncompatibleVersion {
+        current: ApplicationVersion,
+        found: ApplicationVersion,
+    },
+}
+
+impl Display for VersionError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::ApplicationMismatch { expected, found } => {
+                write!(
+                    f,
+                    "The data was produced by the application '{found}', expected '{expected}'"
+                )
+            }
+            Self::IncompatibleVersion { current, found } => {
+                write!(
+                    f,
+                    "The data was produced by version {found}, which can not be read by \
+                    version {current}"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for VersionError {}
+
+/// Serializable data tagged with the application and the version it was produced by.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Versioned<T> {
+    application: String,
+    version: ApplicationVersion,
+    data: T,
+}
+
+impl<T> Versioned<T> {
+    /// Tags `data` with the current version of `application`.
+    pub fn new(application: &ApplicationInfo, data: T) -> Self {
+        Self {
+            application: application.name.to_string(),
+            version: application.version,
+            data,
+        }
+    }
+
+    pub fn application(&self) -> &str {
+        &self.application
+    }
+
+    pub fn version(&self) -> ApplicationVersion {
+        self.version
+    }
+
+    /// Returns the data if it was produced by `application` with a version compatible with its
+    /// current version.
+    pub fn into_data(self, application: &ApplicationInfo) -> Result<T, VersionError> {
+        if self.application != application.name {
+            return Err(VersionError::ApplicationMismatch {
+                expected: application.name.to_string(),
+                found: self.application,
+            });
+        }
+        if !application.version.is_compatible_with(&self.version) {
+            return Err(VersionError::IncompatibleVersion {
+                current: application.version,
+                found: self.version,
+            });
+        }
+        Ok(self.data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const APP: ApplicationInfo = ApplicationInfo {
+        name: "test",
+        version: ApplicationVersion::new(1, 2),
+    };
+
+    #[test]
+    fn test_version_compatibility() {
+        let current = APP.version;
+        assert!(current.is_compatible_with(&ApplicationVersion::new(1, 0)));
+        assert!(current.is_compatible_with(&ApplicationVersion::new(1, 2)));
+        assert!(!current.is_compatible_with(&ApplicationVersion::new(1, 3)));
+        assert!(!current.is_compatible_with(&ApplicationVersion::new(0, 2)));
+        assert!(!current.is_compatible_with(&ApplicationVersion::new(2, 0)));
+    }
+
+    #[test]
+    fn test_versioned() {
+        let versioned = Versioned::new(&APP, vec![1u8, 2, 3]);
+        let serialized = bincode::serialize(&versioned).unwrap();
+        let versioned: Versioned<Vec<u8>> = bincode::deserialize(&serialized).unwrap();
+        assert_eq!(versioned.application(), "test");
+        assert_eq!(versioned.version(), ApplicationVersion::new(1, 2));
+        assert_eq!(versioned.into_data(&APP), Ok(vec![1, 2, 3]));
+
+        let other = ApplicationInfo {
+            name: "other",
+            ..APP
+        };
+        assert_eq!(
+            Versioned::new(&other, ()).into_data(&APP),
+            Err(VersionError::ApplicationMismatch {
+                expected: "test".to_string(),
+                found: "other".to_string()
+            })
+        );
+
+        let newer = ApplicationInfo {
+            version: ApplicationVersion::new(1, 3),
+            ..APP
+        };
+        assert_eq!(
+            Versioned::new(&newer, ()).into_data(&APP),
+            Err(VersionError::IncompatibleVersion {
+                current: APP.version,
+                found: newer.version
+            })
+        );
+    }
+}
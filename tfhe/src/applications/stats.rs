@@ -0,0 +1,340 @@
+//! Statistics over encrypted unsigned integers: mean, variance and covariance.
+//!
+//! The server only learns the number of values. Each statistic is returned as an
+//! [`EncryptedFraction`]: an encrypted numerator along with a clear denominator that only depends
+//! on the number of values. The division is left to the owner of the client key, who decrypts
+//! the numerator and divides it in the clear, so no precision is lost by an homomorphic
+//! division.
+//!
+//! # Precision
+//!
+//! Radix ciphertexts wrap around on overflow, so every input is first extended with trivial zero
+//! blocks up to a width large enough to hold the result. For `n` values of at most `b` bits, and
+//! `k = ceil(log2(n))`:
+//!
+//! * the sum of the [`mean`] is computed over `b + k` bits;
+//! * the numerator `n * Σx² - (Σx)²` of the [`variance`] is computed over `2 * (b + k)` bits, the
+//!   squares being full multiplications of the extended inputs;
+//! * the numerator `n * Σxy - Σx * Σy` of the [`covariance`] is computed over `2 * (b + k) + 1`
+//!   bits, the extra bit holding its sign in two's complement.
+//!
+//! Widths are rounded up to a whole number of blocks and must not exceed 128 bits. Extending the
+//! inputs makes the multiplications of the variance and covariance the most expensive step, their
+//! cost grows quadratically with the number of blocks.
+//!
+//! # Example
+//!
+//! ```rust
+//! use tfhe::applications::stats::{covariance, mean, variance};
+//! use tfhe::integer::gen_keys_radix;
+//! use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+//!
+//! let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2, 2);
+//!
+//! let xs = [1u64, 5, 9].map(|x| cks.encrypt(x));
+//! let ys = [6u64, 4, 2].map(|y| cks.encrypt(y));
+//!
+//! let m = mean(&sks, &xs);
+//! assert_eq!(m.decrypt(cks.as_ref()), 5.0);
+//!
+//! // The population variance of [1, 5, 9] is 32 / 3
+//! let v = variance(&sks, &xs);
+//! assert_eq!(v.decrypt_numerator(cks.as_ref()), 96);
+//! assert_eq!(v.denominator(), 9);
+//!
+//! let c = covariance(&sks, &xs, &ys);
+//! assert_eq!(c.decrypt_numerator(cks.as_ref()), -48);
+//! ```
+
+use crate::integer::ciphertext::RadixCiphertext;
+use crate::integer::{ClientKey, ServerKey};
+use crate::shortint::PBSOrderMarker;
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+
+declare_application! {
+    name: "stats",
+    version: (1, 0),
+    /// Input values of 8 bits. The statistics extend the inputs to fit their results, see the
+    /// [precision](self#precision) section, so the cost of the variance and covariance is driven
+    /// by the width of the inputs.
+    num_blocks: 4,
+}
+
+/// An encrypted numerator along with a clear denominator.
+#[derive(Serialize, Clone, Deserialize)]
+pub struct EncryptedFraction<PBSOrder: PBSOrderMarker> {
+    numerator: RadixCiphertext<PBSOrder>,
+    denominator: u128,
+    is_signed: bool,
+}
+
+impl<PBSOrder: PBSOrderMarker> EncryptedFraction<PBSOrder> {
+    pub(crate) fn new(
+        numerator: RadixCiphertext<PBSOrder>,
+        denominator: u128,
+        is_signed: bool,
+    ) -> Self {
+        Self {
+            numerator,
+            denominator,
+            is_signed,
+        }
+    }
+
+    pub fn numerator(&self) -> &RadixCiphertext<PBSOrder> {
+        &self.numerator
+    }
+
+    pub fn denominator(&self) -> u128 {
+        self.denominator
+    }
+
+    /// Returns whether the numerator is encrypted in two's complement.
+    pub fn is_signed(&self) -> bool {
+        self.is_signed
+    }
+
+    /// Decrypts the numerator, interpreting it in two's complement if it is signed.
+    pub fn decrypt_numerator(&self, client_key: &ClientKey) -> i128 {
+        let value: u128 = client_key.decrypt_radix(&self.numerator);
+        let bits =
+            self.numerator.blocks.len() as u32 * client_key.parameters().message_modulus.0.ilog2();
+        if self.is_signed && bits < u128::BITS && value >> (bits - 1) == 1 {
+            value as i128 - (1i128 << bits)
+        } else {
+            value as i128
+        }
+    }
+
+    /// Decrypts the numerator and divides it by the denominator.
+    pub fn decrypt(&self, client_key: &ClientKey) -> f64 {
+        self.decrypt_numerator(client_key) as f64 / self.denominator as f64
+    }
+}
+
+/// Returns the mean of the values, i.e. `Σx / n`.
+///
+/// # Panics
+///
+/// Panics if `values` is empty or if the sum does not fit in 128 bits.
+pub fn mean<PBSOrder: PBSOrderMarker>(
+    server_key: &ServerKey,
+    values: &[RadixCiphertext<PBSOrder>],
+) -> EncryptedFraction<PBSOrder> {
+    let precision = Precision::new(server_key, values);
+    let num_blocks = precision.num_blocks(precision.value_bits + precision.count_bits);
+
+    let sum = sum_tree(server_key, extend_all(server_key, values, num_blocks));
+
+    EncryptedFraction {
+        numerator: sum,
+        denominator: values.len() as u128,
+        is_signed: false,
+    }
+}
+
+/// Returns the population variance of the values, i.e. `(n * Σx² - (Σx)²) / n²`.
+///
+/// # Panics
+///
+/// Panics if `values` is empty or if the numerator does not fit in 128 bits.
+pub fn variance<PBSOrder: PBSOrderMarker>(
+    server_key: &ServerKey,
+    values: &[RadixCiphertext<PBSOrder>],
+) -> EncryptedFraction<PBSOrder> {
+    let precision = Precision::new(server_key, values);
+    let num_blocks = precision.num_blocks(2 * (precision.value_bits + precision.count_bits));
+
+    let values = extend_all(server_key, values, num_blocks);
+    let squares = values
+        .par_iter()
+        .map(|x| server_key.mul_parallelized(x, x))
+        .collect();
+    let (sum, sum_of_squares) = rayon::join(
+        || sum_tree(server_key, values.clone()),
+        || sum_tree(server_key, squares),
+    );
+
+    EncryptedFraction {
+        numerator: centered_numerator(server_key, &sum_of_squares, &sum, &sum, values.len()),
+        denominator: (values.len() as u128).pow(2),
+        is_signed: false,
+    }
+}
+
+/// Returns the population covariance of the pairs `(xs[i], ys[i])`, i.e.
+/// `(n * Σxy - Σx * Σy) / n²`.
+///
+/// # Panics
+///
+/// Panics if the slices are empty or do not have the same length, or if the numerator does not
+/// fit in 128 bits.
+pub fn covariance<PBSOrder: PBSOrderMarker>(
+    server_key: &ServerKey,
+    xs: &[RadixCiphertext<PBSOrder>],
+    ys: &[RadixCiphertext<PBSOrder>],
+) -> EncryptedFraction<PBSOrder> {
+    assert_eq!(
+        xs.len(),
+        ys.len(),
+        "The covariance needs the same number of values on both sides"
+    );
+    let precision_x = Precision::new(server_key, xs);
+    let precision = Precision {
+        value_bits: precision_x
+            .value_bits
+            .max(Precision::new(server_key, ys).value_bits),
+        ..precision_x
+    };
+    let num_blocks = precision.num_blocks(2 * (precision.value_bits + precision.count_bits) + 1);
+
+    let xs = extend_all(server_key, xs, num_blocks);
+    let ys = extend_all(server_key, ys, num_blocks);
+    let products = xs
+        .par_iter()
+        .zip(ys.par_iter())
+        .map(|(x, y)| server_key.mul_parallelized(x, y))
+        .collect();
+    let ((sum_x, sum_y), sum_of_products) = rayon::join(
+        || {
+            rayon::join(
+                || sum_tree(server_key, xs.clone()),
+                || sum_tree(server_key, ys.clone()),
+            )
+        },
+        || sum_tree(server_key, products),
+    );
+
+    EncryptedFraction {
+        numerator: centered_numerator(server_key, &sum_of_products, &sum_x, &sum_y, xs.len()),
+        denominator: (xs.len() as u128).pow(2),
+        is_signed: true,
+    }
+}
+
+/// Bit widths of a set of values
+#[derive(Copy, Clone)]
+struct Precision {
+    value_bits: u32,
+    count_bits: u32,
+    bits_per_block: u32,
+}
+
+impl Precision {
+    fn new<PBSOrder: PBSOrderMarker>(
+        server_key: &ServerKey,
+        values: &[RadixCiphertext<PBSOrder>],
+    ) -> Self {
+        assert!(!values.is_empty(), "Statistics need at least one value");
+        let bits_per_block = server_key.key.message_modulus.0.ilog2();
+        let max_num_blocks = values.iter().map(|x| x.blocks.len()).max().unwrap_or(0);
+
+        Self {
+            value_bits: max_num_blocks as u32 * bits_per_block,
+            count_bits: values.len().next_power_of_two().ilog2(),
+            bits_per_block,
+        }
+    }
+
+    /// Returns the number of blocks needed to hold `bits` bits
+    fn num_blocks(&self, bits: u32) -> usize {
+        assert!(
+            bits <= u128::BITS,
+            "The result needs {bits} bits, at most {} are supported",
+            u128::BITS
+        );
+        ((bits + self.bits_per_block - 1) / self.bits_per_block) as usize
+    }
+}
+
+fn extend_all<PBSOrder: PBSOrderMarker>(
+    server_key: &ServerKey,
+    values: &[RadixCiphertext<PBSOrder>],
+    num_blocks: usize,
+) -> Vec<RadixCiphertext<PBSOrder>> {
+    values
+        .iter()
+        .map(|x| {
+            server_key.extend_radix_with_trivial_zero_blocks_msb(x, num_blocks - x.blocks.len())
+        })
+        .collect()
+}
+
+/// Sums the terms pairwise, so that the depth of the additions is logarithmic in the number of
+/// terms
+fn sum_tree<PBSOrder: PBSOrderMarker>(
+    server_key: &ServerKey,
+    mut terms: Vec<RadixCiphertext<PBSOrder>>,
+) -> RadixCiphertext<PBSOrder> {
+    while terms.len() > 1 {
+        terms = terms
+            .par_chunks(2)
+            .map(|pair| match pair {
+                [lhs, rhs] => server_key.add_parallelized(lhs, rhs),
+                [last] => last.clone(),
+                _ => unreachable!(),
+            })
+            .collect();
+    }
+    terms.pop().expect("Cannot sum an empty list of terms")
+}
+
+/// Returns `count * sum_of_products - sum_x * sum_y`, modulo the width of the ciphertexts
+fn centered_numerator<PBSOrder: PBSOrderMarker>(
+    server_key: &ServerKey,
+    sum_of_products: &RadixCiphertext<PBSOrder>,
+    sum_x: &RadixCiphertext<PBSOrder>,
+    sum_y: &RadixCiphertext<PBSOrder>,
+    count: usize,
+) -> RadixCiphertext<PBSOrder> {
+    let (scaled, product) = rayon::join(
+        || server_key.scalar_mul_parallelized(sum_of_products, count as u64),
+        || server_key.mul_parallelized(sum_x, sum_y),
+    );
+    server_key.sub_parallelized(&scaled, &product)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::integer::keycache::KEY_CACHE;
+
+    #[test]
+    fn test_stats() {
+        let (cks, sks) = KEY_CACHE.get_from_params(DEFAULT_PARAMETERS.block_parameters);
+
+        let clear_xs = [15u64, 0, 7, 15];
+        let clear_ys = [0u64, 15, 3, 1];
+        let xs = clear_xs.map(|x| cks.encrypt_radix(x, 2));
+        let ys = clear_ys.map(|y| cks.encrypt_radix(y, 2));
+        let n = clear_xs.len() as i128;
+
+        let sum_x = clear_xs.iter().sum::<u64>() as i128;
+        let sum_y = clear_ys.iter().sum::<u64>() as i128;
+        let sum_xx = clear_xs.iter().map(|x| x * x).sum::<u64>() as i128;
+        let sum_xy = clear_xs
+            .iter()
+            .zip(clear_ys.iter())
+            .map(|(x, y)| x * y)
+            .sum::<u64>() as i128;
+
+        let m = mean(&sks, &xs);
+        assert_eq!(m.decrypt_numerator(&cks), sum_x);
+        assert_eq!(m.denominator(), 4);
+        assert_eq!(m.decrypt(&cks), 9.25);
+
+        let v = variance(&sks, &xs);
+        assert!(!v.is_signed());
+        assert_eq!(v.decrypt_numerator(&cks), n * sum_xx - sum_x * sum_x);
+        assert_eq!(v.denominator(), 16);
+
+        let c = covariance(&sks, &xs, &ys);
+        assert!(c.is_signed());
+        assert_eq!(c.decrypt_numerator(&cks), n * sum_xy - sum_x * sum_y);
+        assert!(c.decrypt(&cks) < 0.0);
+
+        // A single value has no spread
+        assert_eq!(variance(&sks, &xs[..1]).decrypt_numerator(&cks), 0);
+    }
+}
@@ -0,0 +1,362 @@
+//! Extraction of fields from encrypted JSON documents.
+//!
+//! The server is given an encrypted document and a clear field path such as `"user.name"`, it
+//! returns the encrypted bytes of the value of the field without learning where the field is, or
+//! whether it is present at all.
+//!
+//! Each key of the path is searched as the pattern `"key":` at every position of the document,
+//! the first match located after the match of the previous key selects the start of the value
+//! through a one-hot encoding of its position. The value is then cut at the first `,`, `}` or
+//! `]` that is not inside a string, and padded with null bytes up to a clear maximum length.
+//!
+//! This is a demonstration and has the following limitations:
+//!
+//! * the document must be compact (no whitespace outside of strings), as produced by
+//!   `serde_json::to_vec`;
+//! * escaped quotes are not supported, and a key pattern appearing inside a string value is
+//!   matched like a real key;
+//! * the nesting of objects is not tracked: a key of the path matches in any object located
+//!   after the match of the previous key;
+//! * objects and arrays are cut at their first delimiter, so only scalar values (strings,
+//!   numbers, booleans and `null`) are extracted entirely.
+//!
+//! # Example
+//!
+//! ```rust
+//! use tfhe::applications::structured::{decrypt_value, extract_field, EncryptedDocument};
+//! use tfhe::integer::gen_keys_radix;
+//! use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+//!
+//! let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2, 4);
+//!
+//! let document = EncryptedDocument::encrypt(cks.as_ref(), br#"{"id":7,"u":{"n":"Al"}}"#);
+//!
+//! let value = extract_field(&sks, &document, "u.n", 4);
+//! assert_eq!(decrypt_value(cks.as_ref(), &value), br#""Al""#);
+//! ```
+
+use crate::integer::ciphertext::{OneHotCiphertext, RadixCiphertext};
+use crate::integer::{ClientKey, ServerKey};
+use crate::shortint::ciphertext::{BootstrapKeyswitch, KeyswitchBootstrap};
+use crate::shortint::{CiphertextBase, PBSOrderMarker};
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+declare_application! {
+    name: "structured",
+    version: (1, 0),
+    /// One byte of the document per radix ciphertext, so that each byte can be compared to the
+    /// bytes of a key pattern with a single equality.
+    num_blocks: 4,
+}
+
+/// An encrypted document, each byte is encrypted in a separate radix ciphertext.
+///
+/// The number of bytes of the document is public.
+#[derive(Serialize, Clone, Deserialize)]
+pub struct EncryptedDocument<PBSOrder: PBSOrderMarker> {
+    bytes: Vec<RadixCiphertext<PBSOrder>>,
+}
+
+pub type EncryptedDocumentBig = EncryptedDocument<KeyswitchBootstrap>;
+pub type EncryptedDocumentSmall = EncryptedDocument<BootstrapKeyswitch>;
+
+fn num_blocks_per_byte(message_modulus: usize) -> usize {
+    let bits_per_block = message_modulus.ilog2() as usize;
+    (8 + bits_per_block - 1) / bits_per_block
+}
+
+impl EncryptedDocumentBig {
+    /// Encrypts the bytes of a document.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the document contains a null byte, as null bytes pad the extracted values.
+    pub fn encrypt(client_key: &ClientKey, document: &[u8]) -> Self {
+        assert!(
+            !document.contains(&0),
+            "Encrypted documents cannot contain null bytes"
+        );
+        let num_blocks = num_blocks_per_byte(client_key.parameters().message_modulus.0);
+        let bytes = document
+            .iter()
+            .map(|byte| client_key.encrypt_radix(u64::from(*byte), num_blocks))
+            .collect();
+
+        Self { bytes }
+    }
+}
+
+impl<PBSOrder: PBSOrderMarker> EncryptedDocument<PBSOrder> {
+    pub fn len(&self) -> usize {
+        self.bytes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+}
+
+/// Decrypts the bytes of a value returned by [`extract_field`], the padding null bytes are
+/// removed.
+pub fn decrypt_value<PBSOrder: PBSOrderMarker>(
+    client_key: &ClientKey,
+    value: &[RadixCiphertext<PBSOrder>],
+) -> Vec<u8> {
+    value
+        .iter()
+        .map(|byte| client_key.decrypt_radix::<u64, _>(byte) as u8)
+        .take_while(|byte| *byte != 0)
+        .collect()
+}
+
+/// Returns the encrypted value of the field at `path`, keys being separated by dots.
+///
+/// The result always has `max_value_len` bytes: it is padded with null bytes, and it only
+/// contains null bytes if the field is not found. Values longer than `max_value_len` are
+/// truncated.
+///
+/// # Panics
+///
+/// Panics if `path` has an empty key.
+pub fn extract_field<PBSOrder: PBSOrderMarker>(
+    server_key: &ServerKey,
+    document: &EncryptedDocument<PBSOrder>,
+    path: &str,
+    max_value_len: usize,
+) -> Vec<RadixCiphertext<PBSOrder>> {
+    let keys = path.split('.').collect::<Vec<_>>();
+    assert!(
+        keys.iter().all(|key| !key.is_empty()),
+        "Invalid field path `{path}`"
+    );
+    let num_blocks = num_blocks_per_byte(server_key.key.message_modulus.0);
+    if document.is_empty() {
+        return vec![server_key.create_trivial_zero_radix(num_blocks); max_value_len];
+    }
+
+    let patterns = keys
+        .iter()
+        .map(|key| format!("\"{key}\":").into_bytes())
+        .collect::<Vec<_>>();
+    let scanner = Scanner::new(server_key, &document.bytes, patterns.iter().flatten());
+
+    // found_until[i] encrypts whether the previous key was found ending at or before i, there is
+    // no constraint on the first key
+    let mut found_until: Option<Vec<CiphertextBase<PBSOrder>>> = None;
+    let mut key_end = Vec::new();
+    for pattern in &patterns {
+        let candidates = (0..document.len())
+            .into_par_iter()
+            .map(|end| {
+                if end + 1 < pattern.len() {
+                    return server_key.key.create_trivial(0);
+                }
+                let start = end + 1 - pattern.len();
+                let mut conditions = pattern
+                    .iter()
+                    .enumerate()
+                    .map(|(offset, byte)| scanner.byte_is(*byte, start + offset).clone())
+                    .collect::<Vec<_>>();
+                if let Some(found_until) = &found_until {
+                    conditions.push(match start {
+                        0 => server_key.key.create_trivial(0),
+                        _ => found_until[start - 1].clone(),
+                    });
+                }
+                server_key.all_blocks_are_one(conditions)
+            })
+            .collect::<Vec<_>>();
+
+        let (first, found) = scanner.first_match(&candidates);
+        key_end = first;
+        found_until = Some(found);
+    }
+
+    // The value starts right after the end of the last key
+    let selector = OneHotCiphertext { blocks: key_end };
+    let value = (0..max_value_len)
+        .into_par_iter()
+        .map(|offset| match document.bytes.get(offset + 1..) {
+            Some(bytes) if !bytes.is_empty() => server_key.one_hot_select(&selector, bytes),
+            _ => server_key.create_trivial_zero_radix(num_blocks),
+        })
+        .collect::<Vec<_>>();
+
+    scanner.cut_value(value)
+}
+
+/// Evaluates byte patterns over encrypted bytes.
+struct Scanner<'a, PBSOrder: PBSOrderMarker> {
+    server_key: &'a ServerKey,
+    /// Whether each byte of the document is equal to a given byte value
+    byte_is: HashMap<u8, Vec<CiphertextBase<PBSOrder>>>,
+}
+
+impl<'a, PBSOrder: PBSOrderMarker> Scanner<'a, PBSOrder> {
+    fn new<'b>(
+        server_key: &'a ServerKey,
+        bytes: &[RadixCiphertext<PBSOrder>],
+        symbols: impl IntoIterator<Item = &'b u8>,
+    ) -> Self {
+        let mut symbols = symbols.into_iter().copied().collect::<Vec<_>>();
+        symbols.sort_unstable();
+        symbols.dedup();
+
+        let byte_is = symbols
+            .into_par_iter()
+            .map(|symbol| (symbol, Self::bytes_equal(server_key, bytes, symbol)))
+            .collect();
+
+        Self {
+            server_key,
+            byte_is,
+        }
+    }
+
+    fn bytes_equal(
+        server_key: &ServerKey,
+        bytes: &[RadixCiphertext<PBSOrder>],
+        symbol: u8,
+    ) -> Vec<CiphertextBase<PBSOrder>> {
+        let message_modulus = server_key.key.message_modulus.0 as u64;
+        let accs = (0..message_modulus)
+            .map(|digit| {
+                server_key
+                    .key
+                    .generate_accumulator(|x| u64::from(x == digit))
+            })
+            .collect::<Vec<_>>();
+
+        bytes
+            .par_iter()
+            .map(|byte| {
+                let mut remaining = u64::from(symbol);
+                let digits_match = byte
+                    .blocks
+                    .iter()
+                    .map(|block| {
+                        let digit = remaining % message_modulus;
+                        remaining /= message_modulus;
+                        server_key
+                            .key
+                            .apply_lookup_table(block, &accs[digit as usize])
+                    })
+                    .collect();
+                server_key.all_blocks_are_one(digits_match)
+            })
+            .collect()
+    }
+
+    fn byte_is(&self, symbol: u8, position: usize) -> &CiphertextBase<PBSOrder> {
+        &self.byte_is[&symbol][position]
+    }
+
+    /// Keeps the first candidate set to 1, also returns whether a candidate was found at or
+    /// before each position.
+    fn first_match(
+        &self,
+        candidates: &[CiphertextBase<PBSOrder>],
+    ) -> (Vec<CiphertextBase<PBSOrder>>, Vec<CiphertextBase<PBSOrder>>) {
+        let key = &self.server_key.key;
+        let first_lut = key.generate_accumulator_bivariate(|found, candidate| {
+            u64::from(found == 0 && candidate == 1)
+        });
+        let or_lut = key.generate_accumulator_bivariate(|found, candidate| {
+            u64::from(found == 1 || candidate == 1)
+        });
+
+        let mut found = key.create_trivial(0);
+        let mut first = Vec::with_capacity(candidates.len());
+        let mut found_until = Vec::with_capacity(candidates.len());
+        for candidate in candidates {
+            let (is_first, found_now) = rayon::join(
+                || key.unchecked_apply_lookup_table_bivariate(&found, candidate, &first_lut),
+                || key.unchecked_apply_lookup_table_bivariate(&found, candidate, &or_lut),
+            );
+            first.push(is_first);
+            found_until.push(found_now.clone());
+            found = found_now;
+        }
+        (first, found_until)
+    }
+
+    /// Replaces the bytes from the first delimiter located outside of a string by null bytes
+    fn cut_value(&self, value: Vec<RadixCiphertext<PBSOrder>>) -> Vec<RadixCiphertext<PBSOrder>> {
+        let server_key = self.server_key;
+        let key = &server_key.key;
+
+        let (is_quote, is_delimiter) = rayon::join(
+            || Self::bytes_equal(server_key, &value, b'"'),
+            || {
+                let delimiters = [b',', b'}', b']']
+                    .into_par_iter()
+                    .map(|delimiter| Self::bytes_equal(server_key, &value, delimiter))
+                    .collect::<Vec<_>>();
+                (0..value.len())
+                    .map(|position| {
+                        server_key.sum_exclusive_blocks(
+                            delimiters.iter().map(|is_equal| is_equal[position].clone()),
+                        )
+                    })
+                    .collect::<Vec<_>>()
+            },
+        );
+
+        let toggle_lut = key.generate_accumulator_bivariate(|in_string, quote| in_string ^ quote);
+        let ends_lut = key.generate_accumulator_bivariate(|in_string, delimiter| {
+            u64::from(in_string == 0 && delimiter == 1)
+        });
+        let or_lut = key.generate_accumulator_bivariate(|lhs, rhs| u64::from(lhs == 1 || rhs == 1));
+        let not_acc = key.generate_accumulator(|x| u64::from(x == 0));
+
+        // keep[j] encrypts whether the byte j is part of the value
+        let mut in_string = key.create_trivial(0);
+        let mut ended = key.create_trivial(0);
+        let mut keep = Vec::with_capacity(value.len());
+        for (quote, delimiter) in is_quote.iter().zip(is_delimiter.iter()) {
+            let (ends_here, next_in_string) = rayon::join(
+                || key.unchecked_apply_lookup_table_bivariate(&in_string, delimiter, &ends_lut),
+                || key.unchecked_apply_lookup_table_bivariate(&in_string, quote, &toggle_lut),
+            );
+            ended = key.unchecked_apply_lookup_table_bivariate(&ended, &ends_here, &or_lut);
+            in_string = next_in_string;
+            keep.push(key.apply_lookup_table(&ended, &not_acc));
+        }
+
+        value
+            .par_iter()
+            .zip(keep.par_iter())
+            .map(|(byte, keep)| {
+                let selector = OneHotCiphertext {
+                    blocks: vec![keep.clone()],
+                };
+                server_key.one_hot_select(&selector, std::slice::from_ref(byte))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::integer::keycache::KEY_CACHE;
+
+    #[test]
+    fn test_extract_field() {
+        let (cks, sks) = KEY_CACHE.get_from_params(DEFAULT_PARAMETERS.block_parameters);
+
+        let document = EncryptedDocument::encrypt(&cks, br#"{"a":"x,y","b":{"a":12}}"#);
+        for (path, expected) in [
+            ("a", &br#""x,y""#[..]),
+            ("b.a", b"12"),
+            ("c", b""),
+            ("b.c", b""),
+        ] {
+            let value = extract_field(&sks, &document, path, 5);
+            assert_eq!(value.len(), 5);
+            assert_eq!(decrypt_value(&cks, &value), expected, "path: {path}");
+        }
+    }
+}
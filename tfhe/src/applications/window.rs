@@ -0,0 +1,269 @@
+//! A sliding window over a stream of encrypted unsigned integers.
+//!
+//! A [`FheWindow`] keeps the last `capacity` values pushed into it along with their encrypted
+//! running sum. Each update costs a constant number of homomorphic operations, whatever the size
+//! of the window: the new value is added to the sum and the evicted one, if any, is subtracted.
+//!
+//! These additions and subtractions do not propagate the carries, which is the expensive part of
+//! radix operations. The carries are only propagated (the sum is *renormalized*) when the carry
+//! spaces of the blocks are full, or every `renormalization_period` updates if a period is set,
+//! see [`FheWindow::with_renormalization_period`].
+//!
+//! The sum is computed over enough bits to hold the sum of `capacity` values. Radix ciphertexts
+//! wrap around on overflow, so the sum stays correct while it temporarily holds `capacity + 1`
+//! values during an update.
+//!
+//! # Example
+//!
+//! ```rust
+//! use tfhe::applications::window::FheWindow;
+//! use tfhe::integer::gen_keys_radix;
+//! use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+//!
+//! // Values of 4 bits
+//! let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2, 2);
+//!
+//! let mut window = FheWindow::new(&sks, 3, 2);
+//! for x in [7u64, 15, 2, 9] {
+//!     window.push(&sks, cks.encrypt(x));
+//! }
+//!
+//! // The window holds [15, 2, 9]
+//! let sum: u64 = cks.decrypt(window.sum());
+//! assert_eq!(sum, 26);
+//!
+//! let mean = window.mean(&sks);
+//! assert_eq!(mean.decrypt_numerator(cks.as_ref()), 26);
+//! assert_eq!(mean.denominator(), 3);
+//! ```
+
+use super::stats::EncryptedFraction;
+use crate::integer::ciphertext::RadixCiphertext;
+use crate::integer::ServerKey;
+use crate::shortint::PBSOrderMarker;
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+
+declare_application! {
+    name: "window",
+    version: (1, 0),
+    /// Values of 8 bits. The running sum is wider than the values, it holds `capacity` of them,
+    /// and is renormalized when the carry spaces of its blocks are full.
+    num_blocks: 4,
+}
+
+/// A fixed-size window over the last encrypted values of a stream, along with their running sum.
+#[derive(Serialize, Clone, Deserialize)]
+pub struct FheWindow<PBSOrder: PBSOrderMarker> {
+    values: VecDeque<RadixCiphertext<PBSOrder>>,
+    sum: RadixCiphertext<PBSOrder>,
+    capacity: usize,
+    value_blocks: usize,
+    renormalization_period: Option<usize>,
+    updates_since_renormalization: usize,
+}
+
+impl<PBSOrder: PBSOrderMarker> FheWindow<PBSOrder> {
+    /// Creates an empty window holding at most `capacity` values of at most `value_blocks`
+    /// blocks.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is 0.
+    pub fn new(server_key: &ServerKey, capacity: usize, value_blocks: usize) -> Self {
+        assert!(
+            capacity > 0,
+            "A window needs a capacity of at least one value"
+        );
+
+        let bits_per_block = server_key.key.message_modulus.0.ilog2();
+        let sum_bits = value_blocks as u32 * bits_per_block + capacity.next_power_of_two().ilog2();
+        let sum_blocks = ((sum_bits + bits_per_block - 1) / bits_per_block) as usize;
+
+        Self {
+            values: VecDeque::with_capacity(capacity + 1),
+            sum: server_key.create_trivial_zero_radix(sum_blocks),
+            capacity,
+            value_blocks,
+            renormalization_period: None,
+            updates_since_renormalization: 0,
+        }
+    }
+
+    /// Renormalizes the sum every `period` updates, in addition to the renormalizations needed
+    /// when the carry spaces are full.
+    ///
+    /// A renormalized sum has empty carries, which is required by some operations of the
+    /// [`ServerKey`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `period` is 0.
+    pub fn with_renormalization_period(mut self, period: usize) -> Self {
+        assert!(
+            period > 0,
+            "The renormalization period must be at least one update"
+        );
+        self.renormalization_period = Some(period);
+        self
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.values.len() == self.capacity
+    }
+
+    /// Returns the values of the window, from the oldest to the most recent one.
+    pub fn values(&self) -> impl Iterator<Item = &RadixCiphertext<PBSOrder>> {
+        self.values.iter()
+    }
+
+    /// Returns the encrypted sum of the values of the window.
+    ///
+    /// The carries of the blocks of the sum may not be empty, it can still be decrypted but
+    /// [`FheWindow::renormalize`] must be called before using it in operations requiring empty
+    /// carries.
+    pub fn sum(&self) -> &RadixCiphertext<PBSOrder> {
+        &self.sum
+    }
+
+    /// Returns the mean of the values of the window, see [`EncryptedFraction`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the window is empty.
+    pub fn mean(&mut self, server_key: &ServerKey) -> EncryptedFraction<PBSOrder> {
+        assert!(!self.is_empty(), "The mean of an empty window is undefined");
+        self.renormalize(server_key);
+        EncryptedFraction::new(self.sum.clone(), self.values.len() as u128, false)
+    }
+
+    /// Pushes a new value into the window, returning the evicted value if the window was full.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value has more blocks than the `value_blocks` of the window.
+    pub fn push(
+        &mut self,
+        server_key: &ServerKey,
+        value: RadixCiphertext<PBSOrder>,
+    ) -> Option<RadixCiphertext<PBSOrder>> {
+        assert!(
+            value.blocks.len() <= self.value_blocks,
+            "The value has {} blocks, the window holds values of at most {} blocks",
+            value.blocks.len(),
+            self.value_blocks
+        );
+
+        let mut added = server_key.extend_radix_with_trivial_zero_blocks_msb(
+            &value,
+            self.sum.blocks.len() - value.blocks.len(),
+        );
+        self.make_room(server_key, &mut added, ServerKey::is_add_possible);
+        server_key.unchecked_add_assign(&mut self.sum, &added);
+        self.values.push_back(value);
+
+        let evicted = if self.values.len() > self.capacity {
+            let evicted = self.values.pop_front().unwrap();
+            let mut subtracted = server_key.extend_radix_with_trivial_zero_blocks_msb(
+                &evicted,
+                self.sum.blocks.len() - evicted.blocks.len(),
+            );
+            self.make_room(server_key, &mut subtracted, ServerKey::is_sub_possible);
+            server_key.unchecked_sub_assign(&mut self.sum, &subtracted);
+            Some(evicted)
+        } else {
+            None
+        };
+
+        self.updates_since_renormalization += 1;
+        if self
+            .renormalization_period
+            .map_or(false, |period| self.updates_since_renormalization >= period)
+        {
+            self.renormalize(server_key);
+        }
+
+        evicted
+    }
+
+    /// Propagates the carries of the sum, so that all its blocks have empty carries.
+    pub fn renormalize(&mut self, server_key: &ServerKey) {
+        if !self.sum.block_carries_are_empty() {
+            server_key.full_propagate_parallelized(&mut self.sum);
+        }
+        self.updates_since_renormalization = 0;
+    }
+
+    /// Propagates the carries needed for `operand` to be added to or subtracted from the sum
+    /// without overflowing the carry spaces.
+    fn make_room(
+        &mut self,
+        server_key: &ServerKey,
+        operand: &mut RadixCiphertext<PBSOrder>,
+        is_possible: fn(&ServerKey, &RadixCiphertext<PBSOrder>, &RadixCiphertext<PBSOrder>) -> bool,
+    ) {
+        if !is_possible(server_key, &self.sum, operand) {
+            self.renormalize(server_key);
+        }
+        if !is_possible(server_key, &self.sum, operand) {
+            server_key.full_propagate_parallelized(operand);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::integer::keycache::KEY_CACHE;
+
+    #[test]
+    fn test_window() {
+        let (cks, sks) = KEY_CACHE.get_from_params(DEFAULT_PARAMETERS.block_parameters);
+
+        let stream = [15u64, 3, 0, 12, 15, 15, 7, 1, 9, 14, 15, 6];
+
+        for period in [None, Some(3)] {
+            let mut window = FheWindow::new(&sks, 4, 2);
+            if let Some(period) = period {
+                window = window.with_renormalization_period(period);
+            }
+            let mut clear_window = VecDeque::new();
+
+            for &x in stream.iter() {
+                let evicted = window.push(&sks, cks.encrypt_radix(x, 2));
+                clear_window.push_back(x);
+                let clear_evicted = if clear_window.len() > 4 {
+                    clear_window.pop_front()
+                } else {
+                    None
+                };
+
+                assert_eq!(
+                    evicted.map(|ct| cks.decrypt_radix::<u64, _>(&ct)),
+                    clear_evicted
+                );
+                assert_eq!(window.len(), clear_window.len());
+                let sum: u64 = cks.decrypt_radix(window.sum());
+                assert_eq!(sum, clear_window.iter().sum::<u64>());
+            }
+
+            assert!(window.is_full());
+            let mean = window.mean(&sks);
+            assert_eq!(mean.decrypt_numerator(&cks), 44);
+            assert_eq!(mean.denominator(), 4);
+            assert!(window.sum().block_carries_are_empty());
+        }
+    }
+}
@@ -1,5 +1,6 @@
 use crate::c_api::utils::*;
 pub use crate::core_crypto::commons::dispersion::StandardDev;
+use crate::core_crypto::commons::dispersion::{DynamicDistribution, SecretKeyDistribution};
 pub use crate::core_crypto::commons::parameters::{
     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
 };
@@ -141,8 +142,12 @@ pub unsafe extern "C" fn shortint_create_parameters(
                 lwe_dimension: LweDimension(lwe_dimension),
                 glwe_dimension: GlweDimension(glwe_dimension),
                 polynomial_size: PolynomialSize(polynomial_size),
-                lwe_modular_std_dev: StandardDev(lwe_modular_std_dev),
-                glwe_modular_std_dev: StandardDev(glwe_modular_std_dev),
+                lwe_noise_distribution: DynamicDistribution::new_gaussian(StandardDev(
+                    lwe_modular_std_dev,
+                )),
+                glwe_noise_distribution: DynamicDistribution::new_gaussian(StandardDev(
+                    glwe_modular_std_dev,
+                )),
                 pbs_base_log: DecompositionBaseLog(pbs_base_log),
                 pbs_level: DecompositionLevelCount(pbs_level),
                 ks_base_log: DecompositionBaseLog(ks_base_log),
@@ -155,6 +160,7 @@ pub unsafe extern "C" fn shortint_create_parameters(
                     )
                     .unwrap(),
                 encryption_key_choice: encryption_key_choice.into(),
+                secret_key_distribution: SecretKeyDistribution::Binary,
             }));
 
         *result = Box::into_raw(heap_allocated_parameters);
@@ -1,9 +1,10 @@
 //! Module containing primitives pertaining to the generation of
 //! [`GLWE secret keys`](`GlweSecretKey`).
 
+use crate::core_crypto::commons::dispersion::SecretKeyDistribution;
 use crate::core_crypto::commons::generators::SecretRandomGenerator;
-use crate::core_crypto::commons::math::random::{RandomGenerable, UniformBinary};
-use crate::core_crypto::commons::numeric::Numeric;
+use crate::core_crypto::commons::math::random::{RandomGenerable, UniformBinary, UniformTernary};
+use crate::core_crypto::commons::numeric::{Numeric, UnsignedInteger};
 use crate::core_crypto::commons::parameters::*;
 use crate::core_crypto::commons::traits::*;
 use crate::core_crypto::entities::*;
@@ -69,3 +70,75 @@ pub fn generate_binary_glwe_secret_key<Scalar, InCont, Gen>(
 {
     generator.fill_slice_with_random_uniform_binary(glwe_secret_key.as_mut())
 }
+
+/// Allocate a new [`GLWE secret key`](`GlweSecretKey`) and fill it with random coefficients
+/// following the given distribution.
+///
+/// See [`generate_glwe_secret_key_with_distribution`] for usage.
+pub fn allocate_and_generate_new_glwe_secret_key_with_distribution<Scalar, Gen>(
+    glwe_dimension: GlweDimension,
+    polynomial_size: PolynomialSize,
+    distribution: SecretKeyDistribution,
+    generator: &mut SecretRandomGenerator<Gen>,
+) -> GlweSecretKeyOwned<Scalar>
+where
+    Scalar: RandomGenerable<UniformBinary> + RandomGenerable<UniformTernary> + UnsignedInteger,
+    Gen: ByteRandomGenerator,
+{
+    let mut glwe_secret_key =
+        GlweSecretKeyOwned::new_empty_key(Scalar::ZERO, glwe_dimension, polynomial_size);
+
+    generate_glwe_secret_key_with_distribution(&mut glwe_secret_key, distribution, generator);
+
+    glwe_secret_key
+}
+
+/// Fill a [`GLWE secret key`](`GlweSecretKey`) with random coefficients following the given
+/// distribution, negative coefficients are stored in two's complement.
+///
+/// Keys generated with [`SecretKeyDistribution::Binary`] are the same as the ones generated by
+/// [`generate_binary_glwe_secret_key`].
+///
+/// # Example
+///
+/// ```
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// // Define parameters for GlweSecretKey creation
+/// let glwe_size = GlweSize(2);
+/// let polynomial_size = PolynomialSize(1024);
+///
+/// // Create the PRNG
+/// let mut seeder = new_seeder();
+/// let seeder = seeder.as_mut();
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+///
+/// let mut glwe_secret_key =
+///     GlweSecretKey::new_empty_key(0u64, glwe_size.to_glwe_dimension(), polynomial_size);
+///
+/// generate_glwe_secret_key_with_distribution(
+///     &mut glwe_secret_key,
+///     SecretKeyDistribution::Gaussian { std_dev: 3.2 },
+///     &mut secret_generator,
+/// );
+///
+/// // Check all coefficients are small, 10 standard deviations away from 0 at most
+/// assert!(glwe_secret_key
+///     .as_ref()
+///     .iter()
+///     .all(|&elt| elt <= 32 || elt.wrapping_neg() <= 32));
+/// ```
+pub fn generate_glwe_secret_key_with_distribution<Scalar, InCont, Gen>(
+    glwe_secret_key: &mut GlweSecretKey<InCont>,
+    distribution: SecretKeyDistribution,
+    generator: &mut SecretRandomGenerator<Gen>,
+) where
+    Scalar: RandomGenerable<UniformBinary> + RandomGenerable<UniformTernary> + UnsignedInteger,
+    InCont: ContainerMut<Element = Scalar>,
+    Gen: ByteRandomGenerator,
+{
+    generator.fill_slice_with_secret_key_distribution(glwe_secret_key.as_mut(), distribution)
+}
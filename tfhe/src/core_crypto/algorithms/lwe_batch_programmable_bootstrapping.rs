@@ -0,0 +1,320 @@
+//! Module containing an experimental batched version of the [`LWE programmable
+//! bootstrap`](`LweBootstrapKey#programmable-bootstrapping`).
+//!
+//! The classic bootstrap processes ciphertexts one after the other, each blind rotation going
+//! through the whole bootstrapping key. For large batches the key does not fit in cache and has to
+//! be streamed from memory for every ciphertext. The batched version swaps the loops: every
+//! [`GGSW ciphertext`](`FourierGgswCiphertext`) of the key is loaded once and applied to the
+//! accumulators of all the ciphertexts of the batch, the memory traffic on the key is thus
+//! amortized over the batch. This trades latency for throughput, all the results are available at
+//! the same time at the end of the call.
+
+use crate::core_crypto::algorithms::glwe_sample_extraction::extract_lwe_sample_from_glwe_ciphertext;
+use crate::core_crypto::algorithms::polynomial_algorithms::{
+    polynomial_wrapping_monic_monomial_div_assign, polynomial_wrapping_monic_monomial_mul_assign,
+};
+use crate::core_crypto::commons::computation_buffers::ComputationBuffers;
+use crate::core_crypto::commons::math::decomposition::SignedDecomposer;
+use crate::core_crypto::commons::parameters::*;
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::entities::*;
+use crate::core_crypto::fft_impl::common::pbs_modulus_switch;
+use crate::core_crypto::fft_impl::fft64::crypto::bootstrap::FourierLweBootstrapKey;
+use crate::core_crypto::fft_impl::fft64::crypto::ggsw::{cmux, cmux_scratch};
+use crate::core_crypto::fft_impl::fft64::math::fft::Fft;
+use concrete_fft::c64;
+
+/// Perform a programmable bootstrap of each [`LWE ciphertext`](`LweCiphertext`) of the input
+/// list with the same look-up table, writing the results in the output list.
+///
+/// The blind rotations of all the ciphertexts are computed together, see the [`module
+/// documentation`](`self`) for the trade-offs compared to calling
+/// [`programmable_bootstrap_lwe_ciphertext`](`super::programmable_bootstrap_lwe_ciphertext`)
+/// on each ciphertext. The results are identical.
+///
+/// This allocates one [`GLWE ciphertext`](`GlweCiphertext`) per input ciphertext to hold the
+/// accumulators.
+///
+/// # Example
+///
+/// ```
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// let small_lwe_dimension = LweDimension(742);
+/// let glwe_dimension = GlweDimension(1);
+/// let polynomial_size = PolynomialSize(2048);
+/// let lwe_modular_std_dev = StandardDev(0.000007069849454709433);
+/// let glwe_modular_std_dev = StandardDev(0.00000000000000029403601535432533);
+/// let pbs_base_log = DecompositionBaseLog(23);
+/// let pbs_level = DecompositionLevelCount(1);
+/// let ciphertext_modulus = CiphertextModulus::new_native();
+///
+/// // Create the PRNG
+/// let mut seeder = new_seeder();
+/// let seeder = seeder.as_mut();
+/// let mut encryption_generator =
+///     EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed(), seeder);
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+///
+/// // Create the LweSecretKey
+/// let small_lwe_sk =
+///     allocate_and_generate_new_binary_lwe_secret_key(small_lwe_dimension, &mut secret_generator);
+/// let glwe_sk = allocate_and_generate_new_binary_glwe_secret_key(
+///     glwe_dimension,
+///     polynomial_size,
+///     &mut secret_generator,
+/// );
+/// let big_lwe_sk = glwe_sk.clone().into_lwe_secret_key();
+///
+/// let std_bootstrapping_key = par_allocate_and_generate_new_lwe_bootstrap_key(
+///     &small_lwe_sk,
+///     &glwe_sk,
+///     pbs_base_log,
+///     pbs_level,
+///     glwe_modular_std_dev,
+///     ciphertext_modulus,
+///     &mut encryption_generator,
+/// );
+///
+/// let mut fourier_bsk = FourierLweBootstrapKey::new(
+///     std_bootstrapping_key.input_lwe_dimension(),
+///     std_bootstrapping_key.glwe_size(),
+///     std_bootstrapping_key.polynomial_size(),
+///     std_bootstrapping_key.decomposition_base_log(),
+///     std_bootstrapping_key.decomposition_level_count(),
+/// );
+/// convert_standard_lwe_bootstrap_key_to_fourier(&std_bootstrapping_key, &mut fourier_bsk);
+///
+/// // 2 bits of message, 2 bits of carry and one bit of padding
+/// let message_modulus = 1u64 << 4;
+/// let delta = (1_u64 << 63) / message_modulus;
+///
+/// // Accumulator computing the identity
+/// let box_size = polynomial_size.0 / message_modulus as usize;
+/// let mut accumulator_u64 = vec![0_u64; polynomial_size.0];
+/// for (i, box_) in accumulator_u64.chunks_exact_mut(box_size).enumerate() {
+///     box_.fill(i as u64 * delta);
+/// }
+/// let half_box_size = box_size / 2;
+/// for a_i in accumulator_u64[0..half_box_size].iter_mut() {
+///     *a_i = (*a_i).wrapping_neg();
+/// }
+/// accumulator_u64.rotate_left(half_box_size);
+/// let accumulator = allocate_and_trivially_encrypt_new_glwe_ciphertext(
+///     glwe_dimension.to_glwe_size(),
+///     &PlaintextList::from_container(accumulator_u64),
+///     ciphertext_modulus,
+/// );
+///
+/// let messages = [0u64, 3, 5, 7, 11];
+/// let mut input = LweCiphertextList::new(
+///     0u64,
+///     small_lwe_dimension.to_lwe_size(),
+///     LweCiphertextCount(messages.len()),
+///     ciphertext_modulus,
+/// );
+/// for (mut ct, msg) in input.iter_mut().zip(messages.iter()) {
+///     encrypt_lwe_ciphertext(
+///         &small_lwe_sk,
+///         &mut ct,
+///         Plaintext(msg * delta),
+///         lwe_modular_std_dev,
+///         &mut encryption_generator,
+///     );
+/// }
+///
+/// let mut output = LweCiphertextList::new(
+///     0u64,
+///     big_lwe_sk.lwe_dimension().to_lwe_size(),
+///     LweCiphertextCount(messages.len()),
+///     ciphertext_modulus,
+/// );
+///
+/// batch_programmable_bootstrap_lwe_ciphertext_list(
+///     &input,
+///     &mut output,
+///     &accumulator,
+///     &fourier_bsk,
+/// );
+///
+/// let signed_decomposer =
+///     SignedDecomposer::new(DecompositionBaseLog(5), DecompositionLevelCount(1));
+/// for (ct, msg) in output.iter().zip(messages.iter()) {
+///     let plaintext = decrypt_lwe_ciphertext(&big_lwe_sk, &ct);
+///     let decoded = signed_decomposer.closest_representable(plaintext.0) / delta;
+///     assert_eq!(decoded, *msg);
+/// }
+/// ```
+pub fn batch_programmable_bootstrap_lwe_ciphertext_list<
+    Scalar,
+    InputCont,
+    OutputCont,
+    AccCont,
+    KeyCont,
+>(
+    input: &LweCiphertextList<InputCont>,
+    output: &mut LweCiphertextList<OutputCont>,
+    accumulator: &GlweCiphertext<AccCont>,
+    fourier_bsk: &FourierLweBootstrapKey<KeyCont>,
+) where
+    // CastInto required for PBS modulus switch which returns a usize
+    Scalar: UnsignedTorus + CastInto<usize>,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+    AccCont: Container<Element = Scalar>,
+    KeyCont: Container<Element = c64>,
+{
+    assert_eq!(
+        input.lwe_ciphertext_count(),
+        output.lwe_ciphertext_count(),
+        "Mismatched number of ciphertexts between input ({:?}) and output ({:?})",
+        input.lwe_ciphertext_count(),
+        output.lwe_ciphertext_count()
+    );
+    assert_eq!(
+        input.lwe_size().to_lwe_dimension(),
+        fourier_bsk.input_lwe_dimension(),
+        "Mismatched LweDimension between input ({:?}) and bootstrapping key ({:?})",
+        input.lwe_size().to_lwe_dimension(),
+        fourier_bsk.input_lwe_dimension()
+    );
+    assert_eq!(
+        input.ciphertext_modulus(),
+        output.ciphertext_modulus(),
+        "Mismatched moduli between input ({:?}) and output ({:?})",
+        input.ciphertext_modulus(),
+        output.ciphertext_modulus()
+    );
+    assert_eq!(
+        accumulator.ciphertext_modulus(),
+        output.ciphertext_modulus(),
+        "Mismatched moduli between accumulator ({:?}) and output ({:?})",
+        accumulator.ciphertext_modulus(),
+        output.ciphertext_modulus()
+    );
+    assert_eq!(
+        accumulator.polynomial_size(),
+        fourier_bsk.polynomial_size(),
+        "Mismatched PolynomialSize between accumulator ({:?}) and bootstrapping key ({:?})",
+        accumulator.polynomial_size(),
+        fourier_bsk.polynomial_size()
+    );
+    assert_eq!(
+        accumulator.glwe_size(),
+        fourier_bsk.glwe_size(),
+        "Mismatched GlweSize between accumulator ({:?}) and bootstrapping key ({:?})",
+        accumulator.glwe_size(),
+        fourier_bsk.glwe_size()
+    );
+    assert_eq!(
+        output.lwe_size().to_lwe_dimension(),
+        fourier_bsk.output_lwe_dimension(),
+        "Mismatched LweDimension between output ({:?}) and bootstrapping key ({:?})",
+        output.lwe_size().to_lwe_dimension(),
+        fourier_bsk.output_lwe_dimension()
+    );
+
+    let polynomial_size = accumulator.polynomial_size();
+    let glwe_size = accumulator.glwe_size();
+    let ciphertext_modulus = accumulator.ciphertext_modulus();
+
+    let fft = Fft::new(fourier_bsk.polynomial_size());
+    let fft = fft.as_view();
+
+    let mut buffers = ComputationBuffers::new();
+    buffers.resize(
+        cmux_scratch::<Scalar>(glwe_size, polynomial_size, fft)
+            .unwrap()
+            .unaligned_bytes_required(),
+    );
+
+    // Initialize the accumulator of each ciphertext by rotating the look-up table by its body
+    let mut accumulators = GlweCiphertextList::new(
+        Scalar::ZERO,
+        glwe_size,
+        polynomial_size,
+        GlweCiphertextCount(input.lwe_ciphertext_count().0),
+        ciphertext_modulus,
+    );
+    for (mut local_accumulator, lwe) in accumulators.iter_mut().zip(input.iter()) {
+        local_accumulator
+            .as_mut()
+            .copy_from_slice(accumulator.as_ref());
+
+        let monomial_degree = pbs_modulus_switch(
+            *lwe.get_body().data,
+            polynomial_size,
+            ModulusSwitchOffset(0),
+            LutCountLog(0),
+        );
+        for mut poly in local_accumulator.as_mut_polynomial_list().iter_mut() {
+            polynomial_wrapping_monic_monomial_div_assign(
+                &mut poly,
+                MonomialDegree(monomial_degree),
+            );
+        }
+    }
+
+    let mut rotated =
+        GlweCiphertext::new(Scalar::ZERO, glwe_size, polynomial_size, ciphertext_modulus);
+
+    // Each GGSW of the key is applied to all the accumulators before moving to the next one
+    for (mask_index, bootstrap_key_ggsw) in fourier_bsk.as_view().into_ggsw_iter().enumerate() {
+        for (mut local_accumulator, lwe) in accumulators.iter_mut().zip(input.iter()) {
+            let lwe_mask_element = lwe.get_mask().as_ref()[mask_index];
+            if lwe_mask_element == Scalar::ZERO {
+                continue;
+            }
+
+            // rotated <- accumulator * X^{a_hat}
+            rotated.as_mut().copy_from_slice(local_accumulator.as_ref());
+            let monomial_degree = pbs_modulus_switch(
+                lwe_mask_element,
+                polynomial_size,
+                ModulusSwitchOffset(0),
+                LutCountLog(0),
+            );
+            for mut poly in rotated.as_mut_polynomial_list().iter_mut() {
+                polynomial_wrapping_monic_monomial_mul_assign(
+                    &mut poly,
+                    MonomialDegree(monomial_degree),
+                );
+            }
+
+            cmux(
+                local_accumulator.as_mut_view(),
+                rotated.as_mut_view(),
+                bootstrap_key_ggsw.as_view(),
+                fft,
+                buffers.stack(),
+            );
+        }
+    }
+
+    // Same rounding as the one done at the end of the classic blind rotation, to keep the
+    // information in the MSBs for power of 2 moduli < native modulus
+    let signed_decomposer = (!ciphertext_modulus.is_native_modulus()).then(|| {
+        SignedDecomposer::new(
+            DecompositionBaseLog(ciphertext_modulus.get_custom_modulus().ilog2() as usize),
+            DecompositionLevelCount(1),
+        )
+    });
+
+    for (mut local_accumulator, mut lwe_out) in accumulators.iter_mut().zip(output.iter_mut()) {
+        if let Some(signed_decomposer) = &signed_decomposer {
+            local_accumulator
+                .as_mut()
+                .iter_mut()
+                .for_each(|x| *x = signed_decomposer.closest_representable(*x));
+        }
+
+        extract_lwe_sample_from_glwe_ciphertext(
+            &local_accumulator,
+            &mut lwe_out,
+            MonomialDegree(0),
+        );
+    }
+}
@@ -11,7 +11,8 @@ use crate::core_crypto::fft_impl::fft128::crypto::bootstrap::{
 };
 use crate::core_crypto::fft_impl::fft128::math::fft::{Fft128, Fft128View};
 use crate::core_crypto::fft_impl::fft64::crypto::bootstrap::{
-    bootstrap_scratch, FourierLweBootstrapKey,
+    bootstrap_scratch, bootstrap_with_seeded_key, bootstrap_with_seeded_key_scratch,
+    keyswitch_bootstrap_scratch, FourierLweBootstrapKey,
 };
 use crate::core_crypto::fft_impl::fft64::crypto::ggsw::{
     add_external_product_assign as impl_add_external_product_assign,
@@ -20,6 +21,8 @@ use crate::core_crypto::fft_impl::fft64::crypto::ggsw::{
 };
 use crate::core_crypto::fft_impl::fft64::crypto::wop_pbs::blind_rotate_assign_scratch;
 use crate::core_crypto::fft_impl::fft64::math::fft::{Fft, FftView};
+use crate::core_crypto::fft_impl::ntt64::crypto::bootstrap::NttLweBootstrapKey;
+use crate::core_crypto::fft_impl::ntt64::math::ntt::Ntt;
 use concrete_fft::c64;
 use dyn_stack::{PodStack, SizeOverflow, StackReq};
 
@@ -1117,6 +1120,374 @@ pub fn programmable_bootstrap_lwe_ciphertext_mem_optimized_requirement<Scalar>(
     bootstrap_scratch::<Scalar>(glwe_size, polynomial_size, fft)
 }
 
+/// Keyswitch `input` with `lwe_keyswitch_key` then bootstrap the result into `output`, this is the
+/// KS-PBS sequence of a programmable bootstrap applied to a ciphertext encrypted under the output
+/// key of the bootstrap.
+///
+/// The keyswitched ciphertext is not materialized: its body is computed first, then its mask is
+/// computed in small chunks that are modulus switched and consumed by the blind rotation while
+/// they are still in cache. The output is bit-identical to the one of
+/// [`keyswitch_lwe_ciphertext`](`crate::core_crypto::algorithms::keyswitch_lwe_ciphertext`)
+/// followed by [`programmable_bootstrap_lwe_ciphertext_mem_optimized`].
+///
+/// The caller must provide a properly configured [`FftView`] object and a `PodStack` used as a
+/// memory buffer having a capacity at least as large as the result of
+/// [`keyswitch_programmable_bootstrap_lwe_ciphertext_mem_optimized_requirement`].
+pub fn keyswitch_programmable_bootstrap_lwe_ciphertext_mem_optimized<
+    Scalar,
+    InputCont,
+    OutputCont,
+    AccCont,
+    KSKCont,
+    KeyCont,
+>(
+    input: &LweCiphertext<InputCont>,
+    output: &mut LweCiphertext<OutputCont>,
+    accumulator: &GlweCiphertext<AccCont>,
+    lwe_keyswitch_key: &LweKeyswitchKey<KSKCont>,
+    fourier_bsk: &FourierLweBootstrapKey<KeyCont>,
+    fft: FftView<'_>,
+    stack: PodStack<'_>,
+) where
+    // CastInto required for PBS modulus switch which returns a usize
+    Scalar: UnsignedTorus + CastInto<usize>,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+    AccCont: Container<Element = Scalar>,
+    KSKCont: Container<Element = Scalar>,
+    KeyCont: Container<Element = c64>,
+{
+    assert_eq!(
+        input.ciphertext_modulus(),
+        output.ciphertext_modulus(),
+        "Mismatched moduli between input ({:?}) and output ({:?})",
+        input.ciphertext_modulus(),
+        output.ciphertext_modulus()
+    );
+
+    assert_eq!(
+        accumulator.ciphertext_modulus(),
+        output.ciphertext_modulus(),
+        "Mismatched moduli between accumulator ({:?}) and output ({:?})",
+        accumulator.ciphertext_modulus(),
+        output.ciphertext_modulus()
+    );
+
+    assert_eq!(
+        lwe_keyswitch_key.input_key_lwe_dimension(),
+        input.lwe_size().to_lwe_dimension(),
+        "Mismatched LweDimension between the input of the keyswitch key ({:?}) and the input \
+        ciphertext ({:?})",
+        lwe_keyswitch_key.input_key_lwe_dimension(),
+        input.lwe_size().to_lwe_dimension(),
+    );
+
+    assert_eq!(
+        lwe_keyswitch_key.output_key_lwe_dimension(),
+        fourier_bsk.input_lwe_dimension(),
+        "Mismatched LweDimension between the output of the keyswitch key ({:?}) and the input of \
+        the bootstrap key ({:?})",
+        lwe_keyswitch_key.output_key_lwe_dimension(),
+        fourier_bsk.input_lwe_dimension(),
+    );
+
+    fourier_bsk.as_view().keyswitch_bootstrap(
+        output.as_mut_view(),
+        input.as_view(),
+        lwe_keyswitch_key.as_view(),
+        accumulator.as_view(),
+        fft,
+        stack,
+    );
+}
+
+/// In-place version of [`keyswitch_programmable_bootstrap_lwe_ciphertext_mem_optimized`], `ct` is
+/// keyswitched then bootstrapped into itself.
+///
+/// The caller must provide a properly configured [`FftView`] object and a `PodStack` used as a
+/// memory buffer having a capacity at least as large as the result of
+/// [`keyswitch_programmable_bootstrap_lwe_ciphertext_mem_optimized_requirement`].
+pub fn keyswitch_programmable_bootstrap_lwe_ciphertext_assign_mem_optimized<
+    Scalar,
+    Cont,
+    AccCont,
+    KSKCont,
+    KeyCont,
+>(
+    ct: &mut LweCiphertext<Cont>,
+    accumulator: &GlweCiphertext<AccCont>,
+    lwe_keyswitch_key: &LweKeyswitchKey<KSKCont>,
+    fourier_bsk: &FourierLweBootstrapKey<KeyCont>,
+    fft: FftView<'_>,
+    stack: PodStack<'_>,
+) where
+    // CastInto required for PBS modulus switch which returns a usize
+    Scalar: UnsignedTorus + CastInto<usize>,
+    Cont: ContainerMut<Element = Scalar>,
+    AccCont: Container<Element = Scalar>,
+    KSKCont: Container<Element = Scalar>,
+    KeyCont: Container<Element = c64>,
+{
+    assert_eq!(
+        accumulator.ciphertext_modulus(),
+        ct.ciphertext_modulus(),
+        "Mismatched moduli between accumulator ({:?}) and ciphertext ({:?})",
+        accumulator.ciphertext_modulus(),
+        ct.ciphertext_modulus()
+    );
+
+    assert_eq!(
+        lwe_keyswitch_key.input_key_lwe_dimension(),
+        ct.lwe_size().to_lwe_dimension(),
+        "Mismatched LweDimension between the input of the keyswitch key ({:?}) and the \
+        ciphertext ({:?})",
+        lwe_keyswitch_key.input_key_lwe_dimension(),
+        ct.lwe_size().to_lwe_dimension(),
+    );
+
+    assert_eq!(
+        lwe_keyswitch_key.output_key_lwe_dimension(),
+        fourier_bsk.input_lwe_dimension(),
+        "Mismatched LweDimension between the output of the keyswitch key ({:?}) and the input of \
+        the bootstrap key ({:?})",
+        lwe_keyswitch_key.output_key_lwe_dimension(),
+        fourier_bsk.input_lwe_dimension(),
+    );
+
+    fourier_bsk.as_view().keyswitch_bootstrap_assign(
+        ct.as_mut_view(),
+        lwe_keyswitch_key.as_view(),
+        accumulator.as_view(),
+        fft,
+        stack,
+    );
+}
+
+/// Return the required memory for
+/// [`keyswitch_programmable_bootstrap_lwe_ciphertext_mem_optimized`] and
+/// [`keyswitch_programmable_bootstrap_lwe_ciphertext_assign_mem_optimized`].
+pub fn keyswitch_programmable_bootstrap_lwe_ciphertext_mem_optimized_requirement<Scalar>(
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+    fft: FftView<'_>,
+) -> Result<StackReq, SizeOverflow> {
+    keyswitch_bootstrap_scratch::<Scalar>(glwe_size, polynomial_size, fft)
+}
+
+/// Perform a programmable bootstrap with a [`SeededLweBootstrapKey`] instead of a
+/// [`FourierLweBootstrapKey`].
+///
+/// The GGSW ciphertexts of the key are decompressed and converted to the Fourier domain one at a
+/// time during the blind rotation, so the Fourier key is never stored in memory. This trades
+/// memory for computation: each bootstrap regenerates the mask of the whole key and computes its
+/// Fourier transform.
+///
+/// The caller must provide a properly configured [`FftView`] object and a `PodStack` used as a
+/// memory buffer having a capacity at least as large as the result of
+/// [`seeded_programmable_bootstrap_lwe_ciphertext_mem_optimized_requirement`].
+pub fn seeded_programmable_bootstrap_lwe_ciphertext_mem_optimized<
+    Scalar,
+    InputCont,
+    OutputCont,
+    AccCont,
+    KeyCont,
+>(
+    input: &LweCiphertext<InputCont>,
+    output: &mut LweCiphertext<OutputCont>,
+    accumulator: &GlweCiphertext<AccCont>,
+    seeded_bsk: &SeededLweBootstrapKey<KeyCont>,
+    fft: FftView<'_>,
+    stack: PodStack<'_>,
+) where
+    // CastInto required for PBS modulus switch which returns a usize
+    Scalar: UnsignedTorus + CastInto<usize>,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+    AccCont: Container<Element = Scalar>,
+    KeyCont: Container<Element = Scalar>,
+{
+    assert_eq!(
+        input.ciphertext_modulus(),
+        output.ciphertext_modulus(),
+        "Mismatched moduli between input ({:?}) and output ({:?})",
+        input.ciphertext_modulus(),
+        output.ciphertext_modulus()
+    );
+
+    assert_eq!(
+        accumulator.ciphertext_modulus(),
+        output.ciphertext_modulus(),
+        "Mismatched moduli between accumulator ({:?}) and output ({:?})",
+        accumulator.ciphertext_modulus(),
+        output.ciphertext_modulus()
+    );
+
+    assert_eq!(
+        seeded_bsk.ciphertext_modulus(),
+        output.ciphertext_modulus(),
+        "Mismatched moduli between bootstrap key ({:?}) and output ({:?})",
+        seeded_bsk.ciphertext_modulus(),
+        output.ciphertext_modulus()
+    );
+
+    bootstrap_with_seeded_key(
+        seeded_bsk,
+        output.as_mut_view(),
+        input.as_view(),
+        accumulator.as_view(),
+        fft,
+        stack,
+    );
+}
+
+/// Return the required memory for
+/// [`seeded_programmable_bootstrap_lwe_ciphertext_mem_optimized`].
+pub fn seeded_programmable_bootstrap_lwe_ciphertext_mem_optimized_requirement<Scalar>(
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+    decomposition_level_count: DecompositionLevelCount,
+    fft: FftView<'_>,
+) -> Result<StackReq, SizeOverflow> {
+    bootstrap_with_seeded_key_scratch::<Scalar>(
+        glwe_size,
+        polynomial_size,
+        decomposition_level_count,
+        fft,
+    )
+}
+
+/// Perform a programmable bootstrap evaluating several look-up tables at once on an input [`LWE
+/// ciphertext`](`LweCiphertext`), the i-th result is written in the i-th ciphertext of the output
+/// [`LWE ciphertext list`](`LweCiphertextList`).
+///
+/// The accumulator must pack `2^lut_count_log` look-up tables: the coefficient at index
+/// `j * 2^lut_count_log + i` holds the value of the i-th look-up table for the j-th position.
+/// The rotations of the blind rotation are rounded to multiples of `2^lut_count_log`, which
+/// increases the noise added by the modulus switch compared to
+/// [`programmable_bootstrap_lwe_ciphertext`]. The input precision must leave room for it.
+///
+/// If you want to manage the computation memory manually you can use
+/// [`many_lut_programmable_bootstrap_lwe_ciphertext_mem_optimized`].
+///
+/// # Panics
+///
+/// Panics if the output list holds more than `2^lut_count_log` ciphertexts.
+pub fn many_lut_programmable_bootstrap_lwe_ciphertext<
+    Scalar,
+    InputCont,
+    OutputCont,
+    AccCont,
+    KeyCont,
+>(
+    input: &LweCiphertext<InputCont>,
+    output: &mut LweCiphertextList<OutputCont>,
+    accumulator: &GlweCiphertext<AccCont>,
+    fourier_bsk: &FourierLweBootstrapKey<KeyCont>,
+    lut_count_log: LutCountLog,
+) where
+    // CastInto required for PBS modulus switch which returns a usize
+    Scalar: UnsignedTorus + CastInto<usize>,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+    AccCont: Container<Element = Scalar>,
+    KeyCont: Container<Element = c64>,
+{
+    let mut buffers = ComputationBuffers::new();
+
+    let fft = Fft::new(fourier_bsk.polynomial_size());
+    let fft = fft.as_view();
+
+    buffers.resize(
+        many_lut_programmable_bootstrap_lwe_ciphertext_mem_optimized_requirement::<Scalar>(
+            fourier_bsk.glwe_size(),
+            fourier_bsk.polynomial_size(),
+            fft,
+        )
+        .unwrap()
+        .unaligned_bytes_required(),
+    );
+
+    let stack = buffers.stack();
+
+    many_lut_programmable_bootstrap_lwe_ciphertext_mem_optimized(
+        input,
+        output,
+        accumulator,
+        fourier_bsk,
+        lut_count_log,
+        fft,
+        stack,
+    )
+}
+
+/// Memory optimized version of [`many_lut_programmable_bootstrap_lwe_ciphertext`], the caller must
+/// provide a properly configured [`FftView`] object and a `PodStack` used as a memory buffer having
+/// a capacity at least as large as the result of
+/// [`many_lut_programmable_bootstrap_lwe_ciphertext_mem_optimized_requirement`].
+pub fn many_lut_programmable_bootstrap_lwe_ciphertext_mem_optimized<
+    Scalar,
+    InputCont,
+    OutputCont,
+    AccCont,
+    KeyCont,
+>(
+    input: &LweCiphertext<InputCont>,
+    output: &mut LweCiphertextList<OutputCont>,
+    accumulator: &GlweCiphertext<AccCont>,
+    fourier_bsk: &FourierLweBootstrapKey<KeyCont>,
+    lut_count_log: LutCountLog,
+    fft: FftView<'_>,
+    stack: PodStack<'_>,
+) where
+    // CastInto required for PBS modulus switch which returns a usize
+    Scalar: UnsignedTorus + CastInto<usize>,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+    AccCont: Container<Element = Scalar>,
+    KeyCont: Container<Element = c64>,
+{
+    assert!(
+        output.lwe_ciphertext_count().0 <= 1 << lut_count_log.0,
+        "Cannot extract {:?} ciphertexts from a bootstrap with {:?}",
+        output.lwe_ciphertext_count(),
+        lut_count_log
+    );
+
+    assert_eq!(
+        input.ciphertext_modulus(),
+        output.ciphertext_modulus(),
+        "Mismatched moduli between input ({:?}) and output ({:?})",
+        input.ciphertext_modulus(),
+        output.ciphertext_modulus()
+    );
+
+    assert_eq!(
+        accumulator.ciphertext_modulus(),
+        output.ciphertext_modulus(),
+        "Mismatched moduli between accumulator ({:?}) and output ({:?})",
+        accumulator.ciphertext_modulus(),
+        output.ciphertext_modulus()
+    );
+
+    fourier_bsk.as_view().bootstrap_many_lut(
+        output.as_mut_view(),
+        input.as_view(),
+        accumulator.as_view(),
+        lut_count_log,
+        fft,
+        stack,
+    );
+}
+
+/// Return the required memory for [`many_lut_programmable_bootstrap_lwe_ciphertext_mem_optimized`].
+pub fn many_lut_programmable_bootstrap_lwe_ciphertext_mem_optimized_requirement<Scalar>(
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+    fft: FftView<'_>,
+) -> Result<StackReq, SizeOverflow> {
+    bootstrap_scratch::<Scalar>(glwe_size, polynomial_size, fft)
+}
+
 /// Perform a programmable bootstrap given an input [`LWE ciphertext`](`LweCiphertext`), a
 /// look-up table passed as a [`GLWE ciphertext`](`GlweCiphertext`) and an [`LWE bootstrap
 /// key`](`LweBootstrapKey`) in the fourier domain using f128 see [`fourier LWE bootstrap
@@ -1404,3 +1775,146 @@ pub fn programmable_bootstrap_f128_lwe_ciphertext_mem_optimized_requirement<Scal
 ) -> Result<StackReq, SizeOverflow> {
     bootstrap_scratch_f128::<Scalar>(glwe_size, polynomial_size, fft)
 }
+
+/// Perform a programmable bootstrap with an [`NttLweBootstrapKey`], computing the polynomial
+/// products exactly with number theoretic transforms instead of Fourier transforms.
+///
+/// This is a portable reference implementation using only integer arithmetic. It is much slower
+/// than [`programmable_bootstrap_lwe_ciphertext`], but its output does not carry the error of
+/// the Fourier transforms. `Scalar` must have at most 64 bits.
+///
+/// # Example
+///
+/// ```
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// let small_lwe_dimension = LweDimension(10);
+/// let glwe_dimension = GlweDimension(1);
+/// let polynomial_size = PolynomialSize(256);
+/// let lwe_modular_std_dev = StandardDev(0.000007069849454709433);
+/// let glwe_modular_std_dev = StandardDev(0.00000000000000029403601535432533);
+/// let pbs_base_log = DecompositionBaseLog(23);
+/// let pbs_level = DecompositionLevelCount(1);
+/// let ciphertext_modulus = CiphertextModulus::new_native();
+///
+/// let mut boxed_seeder = new_seeder();
+/// let seeder = boxed_seeder.as_mut();
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+/// let mut encryption_generator =
+///     EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed(), seeder);
+///
+/// let small_lwe_sk =
+///     LweSecretKey::generate_new_binary(small_lwe_dimension, &mut secret_generator);
+/// let glwe_sk =
+///     GlweSecretKey::generate_new_binary(glwe_dimension, polynomial_size, &mut secret_generator);
+/// let big_lwe_sk = glwe_sk.clone().into_lwe_secret_key();
+///
+/// let std_bootstrapping_key = par_allocate_and_generate_new_lwe_bootstrap_key(
+///     &small_lwe_sk,
+///     &glwe_sk,
+///     pbs_base_log,
+///     pbs_level,
+///     glwe_modular_std_dev,
+///     ciphertext_modulus,
+///     &mut encryption_generator,
+/// );
+///
+/// let mut ntt_bsk = NttLweBootstrapKey::new(
+///     std_bootstrapping_key.input_lwe_dimension(),
+///     std_bootstrapping_key.glwe_size(),
+///     std_bootstrapping_key.polynomial_size(),
+///     std_bootstrapping_key.decomposition_base_log(),
+///     std_bootstrapping_key.decomposition_level_count(),
+/// );
+/// convert_standard_lwe_bootstrap_key_to_ntt64(&std_bootstrapping_key, &mut ntt_bsk);
+///
+/// // 2 bits of message and a bit of padding, the accumulator computes the identity
+/// let message_modulus = 4u64;
+/// let delta = (1u64 << 63) / message_modulus;
+/// let box_size = polynomial_size.0 / message_modulus as usize;
+/// let mut accumulator_scalar: Vec<u64> = (0..polynomial_size.0)
+///     .map(|i| (i / box_size) as u64 * delta)
+///     .collect();
+/// // Negate the first half box to manage negacyclicity and rotate
+/// for a_i in accumulator_scalar[..box_size / 2].iter_mut() {
+///     *a_i = a_i.wrapping_neg();
+/// }
+/// accumulator_scalar.rotate_left(box_size / 2);
+/// let accumulator = allocate_and_trivially_encrypt_new_glwe_ciphertext(
+///     glwe_dimension.to_glwe_size(),
+///     &PlaintextList::from_container(accumulator_scalar),
+///     ciphertext_modulus,
+/// );
+///
+/// let lwe_ciphertext_in = allocate_and_encrypt_new_lwe_ciphertext(
+///     &small_lwe_sk,
+///     Plaintext(3 * delta),
+///     lwe_modular_std_dev,
+///     ciphertext_modulus,
+///     &mut encryption_generator,
+/// );
+/// let mut lwe_ciphertext_out = LweCiphertext::new(
+///     0u64,
+///     big_lwe_sk.lwe_dimension().to_lwe_size(),
+///     ciphertext_modulus,
+/// );
+///
+/// programmable_bootstrap_ntt64_lwe_ciphertext(
+///     &lwe_ciphertext_in,
+///     &mut lwe_ciphertext_out,
+///     &accumulator,
+///     &ntt_bsk,
+/// );
+///
+/// let decrypted = decrypt_lwe_ciphertext(&big_lwe_sk, &lwe_ciphertext_out);
+/// let signed_decomposer =
+///     SignedDecomposer::new(DecompositionBaseLog(3), DecompositionLevelCount(1));
+/// assert_eq!(signed_decomposer.closest_representable(decrypted.0) / delta, 3);
+/// ```
+pub fn programmable_bootstrap_ntt64_lwe_ciphertext<
+    Scalar,
+    InputCont,
+    OutputCont,
+    AccCont,
+    KeyCont,
+>(
+    input: &LweCiphertext<InputCont>,
+    output: &mut LweCiphertext<OutputCont>,
+    accumulator: &GlweCiphertext<AccCont>,
+    ntt_bsk: &NttLweBootstrapKey<KeyCont>,
+) where
+    // CastInto required for PBS modulus switch which returns a usize
+    Scalar: UnsignedTorus + CastInto<usize>,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+    AccCont: Container<Element = Scalar>,
+    KeyCont: Container<Element = u64>,
+{
+    assert_eq!(
+        input.ciphertext_modulus(),
+        output.ciphertext_modulus(),
+        "Mismatched moduli between input ({:?}) and output ({:?})",
+        input.ciphertext_modulus(),
+        output.ciphertext_modulus()
+    );
+
+    assert_eq!(
+        accumulator.ciphertext_modulus(),
+        output.ciphertext_modulus(),
+        "Mismatched moduli between accumulator ({:?}) and output ({:?})",
+        accumulator.ciphertext_modulus(),
+        output.ciphertext_modulus()
+    );
+
+    let ntt = Ntt::new(ntt_bsk.polynomial_size());
+
+    ntt_bsk.bootstrap(
+        output.as_mut_view(),
+        input.as_view(),
+        accumulator.as_view(),
+        &ntt,
+    );
+}
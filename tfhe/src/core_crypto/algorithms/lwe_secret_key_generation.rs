@@ -1,9 +1,10 @@
 //! Module containing primitives pertaining to the generation of
 //! [`LWE secret keys`](`LweSecretKey`).
 
+use crate::core_crypto::commons::dispersion::SecretKeyDistribution;
 use crate::core_crypto::commons::generators::SecretRandomGenerator;
-use crate::core_crypto::commons::math::random::{RandomGenerable, UniformBinary};
-use crate::core_crypto::commons::numeric::Numeric;
+use crate::core_crypto::commons::math::random::{RandomGenerable, UniformBinary, UniformTernary};
+use crate::core_crypto::commons::numeric::{Numeric, UnsignedInteger};
 use crate::core_crypto::commons::parameters::*;
 use crate::core_crypto::commons::traits::*;
 use crate::core_crypto::entities::*;
@@ -64,3 +65,71 @@ pub fn generate_binary_lwe_secret_key<Scalar, InCont, Gen>(
 {
     generator.fill_slice_with_random_uniform_binary(lwe_secret_key.as_mut())
 }
+
+/// Allocate a new [`LWE secret key`](`LweSecretKey`) and fill it with random coefficients
+/// following the given distribution.
+///
+/// See [`generate_lwe_secret_key_with_distribution`] for usage.
+pub fn allocate_and_generate_new_lwe_secret_key_with_distribution<Scalar, Gen>(
+    lwe_dimension: LweDimension,
+    distribution: SecretKeyDistribution,
+    generator: &mut SecretRandomGenerator<Gen>,
+) -> LweSecretKeyOwned<Scalar>
+where
+    Scalar: RandomGenerable<UniformBinary> + RandomGenerable<UniformTernary> + UnsignedInteger,
+    Gen: ByteRandomGenerator,
+{
+    let mut lwe_secret_key = LweSecretKeyOwned::new_empty_key(Scalar::ZERO, lwe_dimension);
+
+    generate_lwe_secret_key_with_distribution(&mut lwe_secret_key, distribution, generator);
+
+    lwe_secret_key
+}
+
+/// Fill an [`LWE secret key`](`LweSecretKey`) with random coefficients following the given
+/// distribution, negative coefficients are stored in two's complement.
+///
+/// Keys generated with [`SecretKeyDistribution::Binary`] are the same as the ones generated by
+/// [`generate_binary_lwe_secret_key`].
+///
+/// # Example
+///
+/// ```
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// // Define parameters for LweCiphertext creation
+/// let lwe_dimension = LweDimension(742);
+///
+/// // Create the PRNG
+/// let mut seeder = new_seeder();
+/// let seeder = seeder.as_mut();
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+///
+/// let mut lwe_secret_key = LweSecretKey::new_empty_key(0u64, lwe_dimension);
+///
+/// generate_lwe_secret_key_with_distribution(
+///     &mut lwe_secret_key,
+///     SecretKeyDistribution::Ternary,
+///     &mut secret_generator,
+/// );
+///
+/// // Check all coefficients are in {-1, 0, 1}
+/// assert!(lwe_secret_key
+///     .as_ref()
+///     .iter()
+///     .all(|&elt| elt == 0 || elt == 1 || elt == u64::MAX));
+/// ```
+pub fn generate_lwe_secret_key_with_distribution<Scalar, InCont, Gen>(
+    lwe_secret_key: &mut LweSecretKey<InCont>,
+    distribution: SecretKeyDistribution,
+    generator: &mut SecretRandomGenerator<Gen>,
+) where
+    Scalar: RandomGenerable<UniformBinary> + RandomGenerable<UniformTernary> + UnsignedInteger,
+    InCont: ContainerMut<Element = Scalar>,
+    Gen: ByteRandomGenerator,
+{
+    generator.fill_slice_with_secret_key_distribution(lwe_secret_key.as_mut(), distribution)
+}
@@ -7,6 +7,8 @@ pub mod ggsw_encryption;
 pub mod glwe_encryption;
 pub mod glwe_sample_extraction;
 pub mod glwe_secret_key_generation;
+#[cfg(feature = "experimental")]
+pub mod lwe_batch_programmable_bootstrapping;
 pub mod lwe_bootstrap_key_conversion;
 pub mod lwe_bootstrap_key_generation;
 pub mod lwe_encryption;
@@ -44,6 +46,8 @@ pub use ggsw_encryption::*;
 pub use glwe_encryption::*;
 pub use glwe_sample_extraction::*;
 pub use glwe_secret_key_generation::*;
+#[cfg(feature = "experimental")]
+pub use lwe_batch_programmable_bootstrapping::*;
 pub use lwe_bootstrap_key_conversion::*;
 pub use lwe_bootstrap_key_generation::*;
 pub use lwe_encryption::*;
@@ -5,6 +5,7 @@ use crate::core_crypto::commons::numeric::UnsignedInteger;
 use crate::core_crypto::commons::parameters::MonomialDegree;
 use crate::core_crypto::commons::traits::*;
 use crate::core_crypto::entities::*;
+use crate::core_crypto::fft_impl::ntt64::math::ntt::Ntt;
 
 /// Add a polynomial to the output polynomial.
 ///
@@ -530,6 +531,81 @@ where
     }
 }
 
+/// Fill the output polynomial, with the result of the product of two polynomials, reduced modulo
+/// $(X^{N} + 1)$ with a number theoretic transform (NTT) Complexity: $O(N \log N)$
+///
+/// This is a portable reference implementation using only integer arithmetic: the product is
+/// computed exactly modulo three primes of 62 bits, then reconstructed with the Chinese remainder
+/// theorem. It is slower than the Fourier transforms used by the bootstrapping, but it does not
+/// depend on floating point arithmetic, which makes it useful to check them.
+///
+/// # Note
+///
+/// Computations wrap around (similar to computing modulo $2^{n\_{bits}}$) when exceeding the
+/// unsigned integer capacity.
+///
+/// # Panics
+///
+/// Panics if the polynomial size is not a power of two, is larger than $2^{19}$, or if `Scalar`
+/// has more than 64 bits.
+///
+/// # Example
+///
+/// ```
+/// use tfhe::core_crypto::algorithms::polynomial_algorithms::*;
+/// use tfhe::core_crypto::commons::parameters::*;
+/// use tfhe::core_crypto::entities::*;
+/// let lhs = Polynomial::from_container(vec![3_u64; 1024]);
+/// let rhs = Polynomial::from_container(vec![u64::MAX; 1024]);
+/// let mut res_ntt = Polynomial::new(0u64, PolynomialSize(1024));
+/// let mut res_mul = Polynomial::new(0u64, PolynomialSize(1024));
+/// polynomial_ntt_wrapping_mul(&mut res_ntt, &lhs, &rhs);
+/// polynomial_wrapping_mul(&mut res_mul, &lhs, &rhs);
+/// assert_eq!(res_ntt, res_mul);
+/// ```
+pub fn polynomial_ntt_wrapping_mul<Scalar, OutputCont, LhsCont, RhsCont>(
+    output: &mut Polynomial<OutputCont>,
+    lhs: &Polynomial<LhsCont>,
+    rhs: &Polynomial<RhsCont>,
+) where
+    Scalar: UnsignedInteger,
+    OutputCont: ContainerMut<Element = Scalar>,
+    LhsCont: Container<Element = Scalar>,
+    RhsCont: Container<Element = Scalar>,
+{
+    assert!(
+        output.polynomial_size() == lhs.polynomial_size(),
+        "Output polynomial size {:?} is not the same as input lhs polynomial {:?}.",
+        output.polynomial_size(),
+        lhs.polynomial_size(),
+    );
+    assert!(
+        output.polynomial_size() == rhs.polynomial_size(),
+        "Output polynomial size {:?} is not the same as input rhs polynomial {:?}.",
+        output.polynomial_size(),
+        rhs.polynomial_size(),
+    );
+    assert!(
+        Scalar::BITS <= 64,
+        "The NTT multiplication only supports integers of at most 64 bits, got {} bits.",
+        Scalar::BITS,
+    );
+
+    let ntt = Ntt::new(output.polynomial_size());
+    let ntt_poly_size = ntt.ntt_polynomial_size();
+
+    let mut lhs_ntt = vec![0u64; ntt_poly_size];
+    let mut rhs_ntt = vec![0u64; ntt_poly_size];
+    ntt.forward_as_integer(&mut lhs_ntt, lhs.as_ref());
+    ntt.forward_as_integer(&mut rhs_ntt, rhs.as_ref());
+
+    let mut product = vec![0u64; ntt_poly_size];
+    ntt.mul_add_assign(&mut product, &lhs_ntt, &rhs_ntt);
+
+    output.as_mut().fill(Scalar::ZERO);
+    ntt.add_backward_as_integer(output.as_mut(), &mut product, 1);
+}
+
 #[cfg(test)]
 mod test {
     use rand::Rng;
@@ -641,4 +717,48 @@ mod test {
     pub fn test_multiply_karatsuba_u64() {
         test_multiply_karatsuba::<u64>()
     }
+
+    /// test if we have the same result when using schoolbook or the NTT
+    /// for random polynomial multiplication
+    fn test_multiply_ntt<T: UnsignedTorus>() {
+        let mut rng = rand::thread_rng();
+        let mut generator = new_random_generator();
+
+        for polynomial_log in 0..12 {
+            let polynomial_size = PolynomialSize(1 << polynomial_log);
+
+            for _ in 0..5 {
+                // generate two random Torus polynomials
+                let mut poly_1 = Polynomial::new(T::ZERO, polynomial_size);
+                generator.fill_slice_with_random_uniform::<T>(poly_1.as_mut());
+
+                // coefficients close to the max give the largest products
+                let poly_2 = if rng.gen::<bool>() {
+                    Polynomial::new(T::MAX, polynomial_size)
+                } else {
+                    let mut poly_2 = Polynomial::new(T::ZERO, polynomial_size);
+                    generator.fill_slice_with_random_uniform::<T>(poly_2.as_mut());
+                    poly_2
+                };
+
+                let mut sb_mul = Polynomial::new(T::ZERO, polynomial_size);
+                let mut ntt_mul = Polynomial::new(T::ZERO, polynomial_size);
+
+                polynomial_wrapping_mul(&mut sb_mul, &poly_1, &poly_2);
+                polynomial_ntt_wrapping_mul(&mut ntt_mul, &poly_1, &poly_2);
+
+                assert_eq!(&sb_mul, &ntt_mul);
+            }
+        }
+    }
+
+    #[test]
+    pub fn test_multiply_ntt_u32() {
+        test_multiply_ntt::<u32>()
+    }
+
+    #[test]
+    pub fn test_multiply_ntt_u64() {
+        test_multiply_ntt::<u64>()
+    }
 }
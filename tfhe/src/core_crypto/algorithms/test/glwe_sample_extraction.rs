@@ -0,0 +1,74 @@
+use super::*;
+
+fn glwe_encrypt_sample_extract_decrypt_custom_mod<Scalar: UnsignedTorus>(
+    params: TestParams<Scalar>,
+) {
+    let glwe_dimension = params.glwe_dimension;
+    let polynomial_size = params.polynomial_size;
+    let glwe_modular_std_dev = params.glwe_modular_std_dev;
+    let ciphertext_modulus = params.ciphertext_modulus;
+    let message_modulus_log = params.message_modulus_log;
+    let encoding_with_padding = get_encoding_with_padding(ciphertext_modulus);
+
+    let mut rsc = TestResources::new();
+
+    let msg_modulus = Scalar::ONE.shl(message_modulus_log.0);
+    let mut msg = msg_modulus;
+    let delta: Scalar = encoding_with_padding / msg_modulus;
+
+    while msg != Scalar::ZERO {
+        msg = msg.wrapping_sub(Scalar::ONE);
+        let glwe_sk = allocate_and_generate_new_binary_glwe_secret_key(
+            glwe_dimension,
+            polynomial_size,
+            &mut rsc.secret_random_generator,
+        );
+        let equivalent_lwe_sk = glwe_sk.clone().into_lwe_secret_key();
+
+        let mut glwe = GlweCiphertext::new(
+            Scalar::ZERO,
+            glwe_dimension.to_glwe_size(),
+            polynomial_size,
+            ciphertext_modulus,
+        );
+
+        // Each coefficient encrypts a different message
+        let mut plaintext_list =
+            PlaintextList::new(Scalar::ZERO, PlaintextCount(polynomial_size.0));
+        let mut coefficient_msg = msg;
+        for plaintext in plaintext_list.iter_mut() {
+            *plaintext.0 = coefficient_msg * delta;
+            coefficient_msg = coefficient_msg.wrapping_add(Scalar::ONE) % msg_modulus;
+        }
+
+        encrypt_glwe_ciphertext(
+            &glwe_sk,
+            &mut glwe,
+            &plaintext_list,
+            glwe_modular_std_dev,
+            &mut rsc.encryption_random_generator,
+        );
+
+        assert!(check_content_respects_mod(&glwe, ciphertext_modulus));
+
+        let mut output_lwe = LweCiphertext::new(
+            Scalar::ZERO,
+            equivalent_lwe_sk.lwe_dimension().to_lwe_size(),
+            ciphertext_modulus,
+        );
+
+        for (nth, plaintext) in plaintext_list.iter().enumerate() {
+            extract_lwe_sample_from_glwe_ciphertext(&glwe, &mut output_lwe, MonomialDegree(nth));
+
+            assert!(check_content_respects_mod(&output_lwe, ciphertext_modulus));
+
+            let decrypted = decrypt_lwe_ciphertext(&equivalent_lwe_sk, &output_lwe);
+
+            let decoded = round_decode(decrypted.0, delta) % msg_modulus;
+
+            assert_eq!(decoded, *plaintext.0 / delta);
+        }
+    }
+}
+
+create_parametrized_test!(glwe_encrypt_sample_extract_decrypt_custom_mod);
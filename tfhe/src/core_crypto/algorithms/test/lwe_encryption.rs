@@ -135,12 +135,12 @@ fn test_parallel_and_seeded_lwe_list_encryption_equivalence_non_native_power_of_
 
 #[test]
 fn test_parallel_and_seeded_lwe_list_encryption_equivalence_native_mod_u32() {
-    test_parallel_and_seeded_lwe_list_encryption_equivalence(DUMMY_NATIVE_U32);
+    test_parallel_and_seeded_lwe_list_encryption_equivalence(TEST_PARAMS_4_BITS_NATIVE_U32);
 }
 
 #[test]
 fn test_parallel_and_seeded_lwe_list_encryption_equivalence_non_native_power_of_2_mod_u32() {
-    test_parallel_and_seeded_lwe_list_encryption_equivalence(DUMMY_31_U32);
+    test_parallel_and_seeded_lwe_list_encryption_equivalence(TEST_PARAMS_3_BITS_31_U32);
 }
 
 fn lwe_encrypt_decrypt_custom_mod<Scalar: UnsignedTorus>(params: TestParams<Scalar>) {
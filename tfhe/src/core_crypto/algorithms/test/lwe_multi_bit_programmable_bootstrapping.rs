@@ -229,3 +229,45 @@ pub fn test_lwe_encrypt_multi_bit_pbs_decrypt_factor_3_thread_12_custom_mod() {
         },
     );
 }
+
+#[test]
+pub fn test_lwe_encrypt_multi_bit_pbs_decrypt_factor_2_thread_5_native_mod_u32() {
+    lwe_encrypt_multi_bit_pbs_decrypt_custom_mod::<u32>(
+        // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield
+        // correct computations
+        MultiBitParams {
+            input_lwe_dimension: LweDimension(788),
+            lwe_modular_std_dev: StandardDev(0.000003871078133364534),
+            decomp_base_log: DecompositionBaseLog(22),
+            decomp_level_count: DecompositionLevelCount(1),
+            glwe_dimension: GlweDimension(2),
+            polynomial_size: PolynomialSize(1024),
+            glwe_modular_std_dev: StandardDev(0.0000000000000003152931493498455),
+            message_modulus_log: CiphertextModulusLog(4),
+            ciphertext_modulus: CiphertextModulus::new_native(),
+            grouping_factor: LweBskGroupingFactor(2),
+            thread_count: ThreadCount(5),
+        },
+    );
+}
+
+#[test]
+pub fn test_lwe_encrypt_multi_bit_pbs_decrypt_factor_2_thread_5_native_mod_u128() {
+    lwe_encrypt_multi_bit_pbs_decrypt_custom_mod::<u128>(
+        // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield
+        // correct computations
+        MultiBitParams {
+            input_lwe_dimension: LweDimension(788),
+            lwe_modular_std_dev: StandardDev(0.000003871078133364534),
+            decomp_base_log: DecompositionBaseLog(22),
+            decomp_level_count: DecompositionLevelCount(1),
+            glwe_dimension: GlweDimension(2),
+            polynomial_size: PolynomialSize(1024),
+            glwe_modular_std_dev: StandardDev(0.0000000000000003152931493498455),
+            message_modulus_log: CiphertextModulusLog(4),
+            ciphertext_modulus: CiphertextModulus::new_native(),
+            grouping_factor: LweBskGroupingFactor(2),
+            thread_count: ThreadCount(5),
+        },
+    );
+}
@@ -0,0 +1,115 @@
+use super::*;
+
+fn lwe_encrypt_pfpks_decrypt<Scalar: UnsignedTorus + Sync + Send + CastFrom<usize>>(
+    params: TestParams<Scalar>,
+) {
+    let lwe_dimension = params.lwe_dimension;
+    let lwe_modular_std_dev = params.lwe_modular_std_dev;
+    let glwe_dimension = params.glwe_dimension;
+    let polynomial_size = params.polynomial_size;
+    let pfks_modular_std_dev = params.pfks_modular_std_dev;
+    let pfks_base_log = params.pfks_base_log;
+    let pfks_level = params.pfks_level;
+    let ciphertext_modulus = params.ciphertext_modulus;
+    let message_modulus_log = params.message_modulus_log;
+    let encoding_with_padding = get_encoding_with_padding(ciphertext_modulus);
+
+    let mut rsc = TestResources::new();
+
+    const NB_TESTS: usize = 10;
+    let msg_modulus = Scalar::ONE.shl(message_modulus_log.0);
+    let delta: Scalar = encoding_with_padding / msg_modulus;
+
+    let lwe_sk = allocate_and_generate_new_binary_lwe_secret_key(
+        lwe_dimension,
+        &mut rsc.secret_random_generator,
+    );
+
+    let glwe_sk = allocate_and_generate_new_binary_glwe_secret_key(
+        glwe_dimension,
+        polynomial_size,
+        &mut rsc.secret_random_generator,
+    );
+
+    let mut pfpksk = LwePrivateFunctionalPackingKeyswitchKey::new(
+        Scalar::ZERO,
+        pfks_base_log,
+        pfks_level,
+        lwe_dimension,
+        glwe_dimension.to_glwe_size(),
+        polynomial_size,
+        ciphertext_modulus,
+    );
+
+    // The identity function, applied to the constant polynomial 1
+    let mut polynomial = Polynomial::new(Scalar::ZERO, polynomial_size);
+    polynomial.as_mut()[0] = Scalar::ONE;
+
+    par_generate_lwe_private_functional_packing_keyswitch_key(
+        &lwe_sk,
+        &glwe_sk,
+        &mut pfpksk,
+        pfks_modular_std_dev,
+        &mut rsc.encryption_random_generator,
+        |x| x,
+        &polynomial,
+    );
+
+    assert!(check_content_respects_mod(&pfpksk, ciphertext_modulus));
+
+    for _ in 0..NB_TESTS {
+        // Pack one ciphertext per message
+        let msgs: Vec<Scalar> = (0..1usize << message_modulus_log.0)
+            .map(Scalar::cast_from)
+            .collect();
+
+        let mut input_list = LweCiphertextList::new(
+            Scalar::ZERO,
+            lwe_dimension.to_lwe_size(),
+            LweCiphertextCount(msgs.len()),
+            ciphertext_modulus,
+        );
+
+        encrypt_lwe_ciphertext_list(
+            &lwe_sk,
+            &mut input_list,
+            &PlaintextList::from_container(msgs.iter().map(|&msg| msg * delta).collect::<Vec<_>>()),
+            lwe_modular_std_dev,
+            &mut rsc.encryption_random_generator,
+        );
+
+        let mut output_glwe = GlweCiphertext::new(
+            Scalar::ZERO,
+            glwe_dimension.to_glwe_size(),
+            polynomial_size,
+            ciphertext_modulus,
+        );
+
+        private_functional_keyswitch_lwe_ciphertext_list_and_pack_in_glwe_ciphertext(
+            &pfpksk,
+            &mut output_glwe,
+            &input_list,
+        );
+
+        assert!(check_content_respects_mod(&output_glwe, ciphertext_modulus));
+
+        let mut decrypted = PlaintextList::new(Scalar::ZERO, PlaintextCount(polynomial_size.0));
+        decrypt_glwe_ciphertext(&glwe_sk, &output_glwe, &mut decrypted);
+
+        let decoded: Vec<_> = decrypted
+            .iter()
+            .map(|plaintext| round_decode(*plaintext.0, delta) % msg_modulus)
+            .collect();
+
+        // The ciphertexts are packed in the first coefficients, the others encrypt 0
+        assert_eq!(&decoded[..msgs.len()], &msgs[..]);
+        assert!(decoded[msgs.len()..].iter().all(|&x| x == Scalar::ZERO));
+    }
+}
+
+// The private functional packing keyswitch only supports native moduli
+create_parametrized_test!(lwe_encrypt_pfpks_decrypt {
+    TEST_PARAMS_4_BITS_NATIVE_U64,
+    TEST_PARAMS_4_BITS_NATIVE_U32,
+    TEST_PARAMS_4_BITS_NATIVE_U128
+});
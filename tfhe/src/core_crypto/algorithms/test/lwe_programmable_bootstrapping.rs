@@ -128,44 +128,497 @@ fn lwe_encrypt_pbs_decrypt_custom_mod<
 
 create_parametrized_test!(lwe_encrypt_pbs_decrypt_custom_mod);
 
-// DISCLAIMER: all parameters here are not guaranteed to be secure or yield correct computations
-pub const TEST_PARAMS_4_BITS_NATIVE_U128: TestParams<u128> = TestParams {
-    lwe_dimension: LweDimension(742),
-    glwe_dimension: GlweDimension(1),
-    polynomial_size: PolynomialSize(2048),
-    lwe_modular_std_dev: StandardDev(4.9982771e-11),
-    glwe_modular_std_dev: StandardDev(8.6457178e-32),
-    pbs_base_log: DecompositionBaseLog(23),
-    pbs_level: DecompositionLevelCount(1),
-    ks_level: DecompositionLevelCount(5),
-    ks_base_log: DecompositionBaseLog(3),
-    pfks_level: DecompositionLevelCount(1),
-    pfks_base_log: DecompositionBaseLog(23),
-    pfks_modular_std_dev: StandardDev(0.00000000000000029403601535432533),
-    cbs_level: DecompositionLevelCount(0),
-    cbs_base_log: DecompositionBaseLog(0),
-    message_modulus_log: CiphertextModulusLog(4),
-    ciphertext_modulus: CiphertextModulus::new_native(),
-};
-
-pub const TEST_PARAMS_3_BITS_127_U128: TestParams<u128> = TestParams {
-    lwe_dimension: LweDimension(742),
-    glwe_dimension: GlweDimension(1),
-    polynomial_size: PolynomialSize(2048),
-    lwe_modular_std_dev: StandardDev(4.9982771e-11),
-    glwe_modular_std_dev: StandardDev(8.6457178e-32),
-    pbs_base_log: DecompositionBaseLog(23),
-    pbs_level: DecompositionLevelCount(1),
-    ks_level: DecompositionLevelCount(5),
-    ks_base_log: DecompositionBaseLog(3),
-    pfks_level: DecompositionLevelCount(1),
-    pfks_base_log: DecompositionBaseLog(23),
-    pfks_modular_std_dev: StandardDev(0.00000000000000029403601535432533),
-    cbs_level: DecompositionLevelCount(0),
-    cbs_base_log: DecompositionBaseLog(0),
-    message_modulus_log: CiphertextModulusLog(3),
-    ciphertext_modulus: unsafe { CiphertextModulus::new_unchecked(1 << 127) },
-};
+fn lwe_encrypt_many_lut_pbs_decrypt_custom_mod<
+    Scalar: UnsignedTorus + Sync + Send + CastFrom<usize> + CastInto<usize>,
+>(
+    params: TestParams<Scalar>,
+) {
+    let input_lwe_dimension = params.lwe_dimension;
+    let lwe_modular_std_dev = params.lwe_modular_std_dev;
+    let glwe_modular_std_dev = params.glwe_modular_std_dev;
+    let ciphertext_modulus = params.ciphertext_modulus;
+    let message_modulus_log = params.message_modulus_log;
+    let msg_modulus = Scalar::ONE.shl(message_modulus_log.0);
+    let encoding_with_padding = get_encoding_with_padding(ciphertext_modulus);
+    let glwe_dimension = params.glwe_dimension;
+    let polynomial_size = params.polynomial_size;
+
+    let mut rsc = TestResources::new();
+
+    let f0 = |x: Scalar| x.wrapping_mul(Scalar::TWO).wrapping_rem(msg_modulus);
+    let f1 = |x: Scalar| x.wrapping_add(Scalar::ONE).wrapping_rem(msg_modulus);
+
+    let delta: Scalar = encoding_with_padding / msg_modulus;
+
+    // Interleave the two look-up tables, even coefficients for f0 and odd ones for f1
+    let message_count: usize = msg_modulus.cast_into();
+    let box_size = polynomial_size.0 / message_count;
+    let mut accumulator_scalar = vec![Scalar::ZERO; polynomial_size.0];
+    for (i, box_) in accumulator_scalar.chunks_exact_mut(box_size).enumerate() {
+        let i = Scalar::cast_from(i);
+        for (j, a) in box_.iter_mut().enumerate() {
+            let f_eval = if j % 2 == 0 { f0(i) } else { f1(i) };
+            *a = f_eval * delta;
+        }
+    }
+    let half_box_size = box_size / 2;
+    for a_i in accumulator_scalar[0..half_box_size].iter_mut() {
+        *a_i = (*a_i).wrapping_neg();
+    }
+    accumulator_scalar.rotate_left(half_box_size);
+    let accumulator = allocate_and_trivially_encrypt_new_glwe_ciphertext(
+        glwe_dimension.to_glwe_size(),
+        &PlaintextList::from_container(accumulator_scalar),
+        ciphertext_modulus,
+    );
+
+    let input_lwe_secret_key = allocate_and_generate_new_binary_lwe_secret_key(
+        input_lwe_dimension,
+        &mut rsc.secret_random_generator,
+    );
+    let output_glwe_secret_key = allocate_and_generate_new_binary_glwe_secret_key(
+        glwe_dimension,
+        polynomial_size,
+        &mut rsc.secret_random_generator,
+    );
+    let output_lwe_secret_key = output_glwe_secret_key.clone().into_lwe_secret_key();
+
+    let bsk = par_allocate_and_generate_new_lwe_bootstrap_key(
+        &input_lwe_secret_key,
+        &output_glwe_secret_key,
+        params.pbs_base_log,
+        params.pbs_level,
+        glwe_modular_std_dev,
+        ciphertext_modulus,
+        &mut rsc.encryption_random_generator,
+    );
+
+    let mut fbsk = FourierLweBootstrapKey::new(
+        input_lwe_dimension,
+        glwe_dimension.to_glwe_size(),
+        polynomial_size,
+        params.pbs_base_log,
+        params.pbs_level,
+    );
+
+    convert_standard_lwe_bootstrap_key_to_fourier(&bsk, &mut fbsk);
+
+    drop(bsk);
+
+    for msg in 0..message_count {
+        let msg = Scalar::cast_from(msg);
+        let lwe_ciphertext_in = allocate_and_encrypt_new_lwe_ciphertext(
+            &input_lwe_secret_key,
+            Plaintext(msg * delta),
+            lwe_modular_std_dev,
+            ciphertext_modulus,
+            &mut rsc.encryption_random_generator,
+        );
+
+        let mut out_pbs_cts = LweCiphertextList::new(
+            Scalar::ZERO,
+            output_lwe_secret_key.lwe_dimension().to_lwe_size(),
+            LweCiphertextCount(2),
+            ciphertext_modulus,
+        );
+
+        many_lut_programmable_bootstrap_lwe_ciphertext(
+            &lwe_ciphertext_in,
+            &mut out_pbs_cts,
+            &accumulator,
+            &fbsk,
+            LutCountLog(1),
+        );
+
+        assert!(check_content_respects_mod(&out_pbs_cts, ciphertext_modulus));
+
+        for (lut_index, out_pbs_ct) in out_pbs_cts.iter().enumerate() {
+            let decrypted = decrypt_lwe_ciphertext(&output_lwe_secret_key, &out_pbs_ct);
+            let decoded = round_decode(decrypted.0, delta) % msg_modulus;
+            let expected = if lut_index == 0 { f0(msg) } else { f1(msg) };
+            assert_eq!(decoded, expected);
+        }
+    }
+}
+
+create_parametrized_test!(lwe_encrypt_many_lut_pbs_decrypt_custom_mod);
+
+fn lwe_encrypt_seeded_bsk_pbs_decrypt_custom_mod<
+    Scalar: UnsignedTorus + Sync + Send + CastFrom<usize> + CastInto<usize>,
+>(
+    params: TestParams<Scalar>,
+) {
+    let input_lwe_dimension = params.lwe_dimension;
+    let lwe_modular_std_dev = params.lwe_modular_std_dev;
+    let glwe_modular_std_dev = params.glwe_modular_std_dev;
+    let ciphertext_modulus = params.ciphertext_modulus;
+    let message_modulus_log = params.message_modulus_log;
+    let msg_modulus = Scalar::ONE.shl(message_modulus_log.0);
+    let encoding_with_padding = get_encoding_with_padding(ciphertext_modulus);
+    let glwe_dimension = params.glwe_dimension;
+    let polynomial_size = params.polynomial_size;
+
+    let mut rsc = TestResources::new();
+
+    let f = |x: Scalar| x.wrapping_add(Scalar::ONE).wrapping_rem(msg_modulus);
+
+    let delta: Scalar = encoding_with_padding / msg_modulus;
+
+    let accumulator = generate_accumulator(
+        polynomial_size,
+        glwe_dimension.to_glwe_size(),
+        msg_modulus.cast_into(),
+        ciphertext_modulus,
+        delta,
+        f,
+    );
+
+    let input_lwe_secret_key = allocate_and_generate_new_binary_lwe_secret_key(
+        input_lwe_dimension,
+        &mut rsc.secret_random_generator,
+    );
+    let output_glwe_secret_key = allocate_and_generate_new_binary_glwe_secret_key(
+        glwe_dimension,
+        polynomial_size,
+        &mut rsc.secret_random_generator,
+    );
+    let output_lwe_secret_key = output_glwe_secret_key.clone().into_lwe_secret_key();
+
+    let seeded_bsk = par_allocate_and_generate_new_seeded_lwe_bootstrap_key(
+        &input_lwe_secret_key,
+        &output_glwe_secret_key,
+        params.pbs_base_log,
+        params.pbs_level,
+        glwe_modular_std_dev,
+        ciphertext_modulus,
+        rsc.seeder.as_mut(),
+    );
+
+    let fft = Fft::new(polynomial_size);
+    let fft = fft.as_view();
+    let mut buffers = ComputationBuffers::new();
+    buffers.resize(
+        seeded_programmable_bootstrap_lwe_ciphertext_mem_optimized_requirement::<Scalar>(
+            glwe_dimension.to_glwe_size(),
+            polynomial_size,
+            params.pbs_level,
+            fft,
+        )
+        .unwrap()
+        .unaligned_bytes_required(),
+    );
+
+    let message_count: usize = msg_modulus.cast_into();
+    for msg in 0..message_count {
+        let msg = Scalar::cast_from(msg);
+        let lwe_ciphertext_in = allocate_and_encrypt_new_lwe_ciphertext(
+            &input_lwe_secret_key,
+            Plaintext(msg * delta),
+            lwe_modular_std_dev,
+            ciphertext_modulus,
+            &mut rsc.encryption_random_generator,
+        );
+
+        let mut out_pbs_ct = LweCiphertext::new(
+            Scalar::ZERO,
+            output_lwe_secret_key.lwe_dimension().to_lwe_size(),
+            ciphertext_modulus,
+        );
+
+        seeded_programmable_bootstrap_lwe_ciphertext_mem_optimized(
+            &lwe_ciphertext_in,
+            &mut out_pbs_ct,
+            &accumulator,
+            &seeded_bsk,
+            fft,
+            buffers.stack(),
+        );
+
+        assert!(check_content_respects_mod(&out_pbs_ct, ciphertext_modulus));
+
+        let decrypted = decrypt_lwe_ciphertext(&output_lwe_secret_key, &out_pbs_ct);
+        let decoded = round_decode(decrypted.0, delta) % msg_modulus;
+        assert_eq!(decoded, f(msg));
+    }
+}
+
+create_parametrized_test!(lwe_encrypt_seeded_bsk_pbs_decrypt_custom_mod);
+
+fn lwe_encrypt_fused_ks_pbs_decrypt_custom_mod<
+    Scalar: UnsignedTorus + Sync + Send + CastFrom<usize> + CastInto<usize>,
+>(
+    params: TestParams<Scalar>,
+) {
+    let small_lwe_dimension = params.lwe_dimension;
+    let lwe_modular_std_dev = params.lwe_modular_std_dev;
+    let glwe_modular_std_dev = params.glwe_modular_std_dev;
+    let ciphertext_modulus = params.ciphertext_modulus;
+    let message_modulus_log = params.message_modulus_log;
+    let msg_modulus = Scalar::ONE.shl(message_modulus_log.0);
+    let encoding_with_padding = get_encoding_with_padding(ciphertext_modulus);
+    let glwe_dimension = params.glwe_dimension;
+    let polynomial_size = params.polynomial_size;
+
+    let mut rsc = TestResources::new();
+
+    let f = |x: Scalar| x.wrapping_add(Scalar::ONE).wrapping_rem(msg_modulus);
+
+    let delta: Scalar = encoding_with_padding / msg_modulus;
+
+    let accumulator = generate_accumulator(
+        polynomial_size,
+        glwe_dimension.to_glwe_size(),
+        msg_modulus.cast_into(),
+        ciphertext_modulus,
+        delta,
+        f,
+    );
+
+    let small_lwe_secret_key = allocate_and_generate_new_binary_lwe_secret_key(
+        small_lwe_dimension,
+        &mut rsc.secret_random_generator,
+    );
+    let glwe_secret_key = allocate_and_generate_new_binary_glwe_secret_key(
+        glwe_dimension,
+        polynomial_size,
+        &mut rsc.secret_random_generator,
+    );
+    let big_lwe_secret_key = glwe_secret_key.clone().into_lwe_secret_key();
+
+    let ksk = allocate_and_generate_new_lwe_keyswitch_key(
+        &big_lwe_secret_key,
+        &small_lwe_secret_key,
+        params.ks_base_log,
+        params.ks_level,
+        lwe_modular_std_dev,
+        ciphertext_modulus,
+        &mut rsc.encryption_random_generator,
+    );
+
+    let mut bsk = LweBootstrapKey::new(
+        Scalar::ZERO,
+        glwe_dimension.to_glwe_size(),
+        polynomial_size,
+        params.pbs_base_log,
+        params.pbs_level,
+        small_lwe_dimension,
+        ciphertext_modulus,
+    );
+    par_generate_lwe_bootstrap_key(
+        &small_lwe_secret_key,
+        &glwe_secret_key,
+        &mut bsk,
+        glwe_modular_std_dev,
+        &mut rsc.encryption_random_generator,
+    );
+    let mut fbsk = FourierLweBootstrapKey::new(
+        small_lwe_dimension,
+        glwe_dimension.to_glwe_size(),
+        polynomial_size,
+        params.pbs_base_log,
+        params.pbs_level,
+    );
+    convert_standard_lwe_bootstrap_key_to_fourier(&bsk, &mut fbsk);
+    drop(bsk);
+
+    let fft = Fft::new(polynomial_size);
+    let fft = fft.as_view();
+    let mut buffers = ComputationBuffers::new();
+    buffers.resize(
+        keyswitch_programmable_bootstrap_lwe_ciphertext_mem_optimized_requirement::<Scalar>(
+            glwe_dimension.to_glwe_size(),
+            polynomial_size,
+            fft,
+        )
+        .unwrap()
+        .unaligned_bytes_required(),
+    );
+
+    let message_count: usize = msg_modulus.cast_into();
+    for msg in 0..message_count {
+        let msg = Scalar::cast_from(msg);
+        let lwe_ciphertext_in = allocate_and_encrypt_new_lwe_ciphertext(
+            &big_lwe_secret_key,
+            Plaintext(msg * delta),
+            glwe_modular_std_dev,
+            ciphertext_modulus,
+            &mut rsc.encryption_random_generator,
+        );
+
+        let mut out_fused_ct = LweCiphertext::new(
+            Scalar::ZERO,
+            big_lwe_secret_key.lwe_dimension().to_lwe_size(),
+            ciphertext_modulus,
+        );
+
+        keyswitch_programmable_bootstrap_lwe_ciphertext_mem_optimized(
+            &lwe_ciphertext_in,
+            &mut out_fused_ct,
+            &accumulator,
+            &ksk,
+            &fbsk,
+            fft,
+            buffers.stack(),
+        );
+
+        assert!(check_content_respects_mod(
+            &out_fused_ct,
+            ciphertext_modulus
+        ));
+
+        // Reference implementation: keyswitch then bootstrap
+        let mut lwe_after_ks =
+            LweCiphertext::new(Scalar::ZERO, ksk.output_lwe_size(), ciphertext_modulus);
+        keyswitch_lwe_ciphertext(&ksk, &lwe_ciphertext_in, &mut lwe_after_ks);
+
+        let mut out_reference_ct = LweCiphertext::new(
+            Scalar::ZERO,
+            big_lwe_secret_key.lwe_dimension().to_lwe_size(),
+            ciphertext_modulus,
+        );
+        programmable_bootstrap_lwe_ciphertext(
+            &lwe_after_ks,
+            &mut out_reference_ct,
+            &accumulator,
+            &fbsk,
+        );
+
+        // Both computations are deterministic, their outputs are identical
+        assert_eq!(out_fused_ct, out_reference_ct);
+
+        let mut ct_assign = lwe_ciphertext_in.clone();
+        keyswitch_programmable_bootstrap_lwe_ciphertext_assign_mem_optimized(
+            &mut ct_assign,
+            &accumulator,
+            &ksk,
+            &fbsk,
+            fft,
+            buffers.stack(),
+        );
+        assert_eq!(ct_assign, out_reference_ct);
+
+        let decrypted = decrypt_lwe_ciphertext(&big_lwe_secret_key, &out_fused_ct);
+        let decoded = round_decode(decrypted.0, delta) % msg_modulus;
+        assert_eq!(decoded, f(msg));
+    }
+}
+
+create_parametrized_test!(lwe_encrypt_fused_ks_pbs_decrypt_custom_mod);
+
+#[cfg(feature = "experimental")]
+fn lwe_encrypt_batch_pbs_decrypt_custom_mod<
+    Scalar: UnsignedTorus + Sync + Send + CastFrom<usize> + CastInto<usize>,
+>(
+    params: TestParams<Scalar>,
+) {
+    let input_lwe_dimension = params.lwe_dimension;
+    let lwe_modular_std_dev = params.lwe_modular_std_dev;
+    let glwe_modular_std_dev = params.glwe_modular_std_dev;
+    let ciphertext_modulus = params.ciphertext_modulus;
+    let message_modulus_log = params.message_modulus_log;
+    let msg_modulus = Scalar::ONE.shl(message_modulus_log.0);
+    let encoding_with_padding = get_encoding_with_padding(ciphertext_modulus);
+    let glwe_dimension = params.glwe_dimension;
+    let polynomial_size = params.polynomial_size;
+
+    let mut rsc = TestResources::new();
+
+    let f = |x: Scalar| {
+        x.wrapping_mul(Scalar::TWO)
+            .wrapping_sub(Scalar::ONE)
+            .wrapping_rem(msg_modulus)
+    };
+
+    let delta: Scalar = encoding_with_padding / msg_modulus;
+
+    let accumulator = generate_accumulator(
+        polynomial_size,
+        glwe_dimension.to_glwe_size(),
+        msg_modulus.cast_into(),
+        ciphertext_modulus,
+        delta,
+        f,
+    );
+
+    let input_lwe_secret_key = allocate_and_generate_new_binary_lwe_secret_key(
+        input_lwe_dimension,
+        &mut rsc.secret_random_generator,
+    );
+    let output_glwe_secret_key = allocate_and_generate_new_binary_glwe_secret_key(
+        glwe_dimension,
+        polynomial_size,
+        &mut rsc.secret_random_generator,
+    );
+    let output_lwe_secret_key = output_glwe_secret_key.clone().into_lwe_secret_key();
+
+    let bsk = par_allocate_and_generate_new_lwe_bootstrap_key(
+        &input_lwe_secret_key,
+        &output_glwe_secret_key,
+        params.pbs_base_log,
+        params.pbs_level,
+        glwe_modular_std_dev,
+        ciphertext_modulus,
+        &mut rsc.encryption_random_generator,
+    );
+
+    let mut fbsk = FourierLweBootstrapKey::new(
+        input_lwe_dimension,
+        glwe_dimension.to_glwe_size(),
+        polynomial_size,
+        params.pbs_base_log,
+        params.pbs_level,
+    );
+
+    convert_standard_lwe_bootstrap_key_to_fourier(&bsk, &mut fbsk);
+
+    drop(bsk);
+
+    let msg_count: usize = msg_modulus.cast_into();
+    let mut input = LweCiphertextList::new(
+        Scalar::ZERO,
+        input_lwe_dimension.to_lwe_size(),
+        LweCiphertextCount(msg_count),
+        ciphertext_modulus,
+    );
+    for (msg, mut lwe) in input.iter_mut().enumerate() {
+        encrypt_lwe_ciphertext(
+            &input_lwe_secret_key,
+            &mut lwe,
+            Plaintext(Scalar::cast_from(msg) * delta),
+            lwe_modular_std_dev,
+            &mut rsc.encryption_random_generator,
+        );
+    }
+
+    let mut output = LweCiphertextList::new(
+        Scalar::ZERO,
+        output_lwe_secret_key.lwe_dimension().to_lwe_size(),
+        LweCiphertextCount(msg_count),
+        ciphertext_modulus,
+    );
+
+    batch_programmable_bootstrap_lwe_ciphertext_list(&input, &mut output, &accumulator, &fbsk);
+
+    assert!(check_content_respects_mod(&output, ciphertext_modulus));
+
+    for (msg, (lwe_in, lwe_out)) in input.iter().zip(output.iter()).enumerate() {
+        // The batched bootstrap performs the same operations as the classic one
+        let mut expected = LweCiphertext::new(
+            Scalar::ZERO,
+            output_lwe_secret_key.lwe_dimension().to_lwe_size(),
+            ciphertext_modulus,
+        );
+        programmable_bootstrap_lwe_ciphertext(&lwe_in, &mut expected, &accumulator, &fbsk);
+        assert_eq!(lwe_out.as_ref(), expected.as_ref());
+
+        let decrypted = decrypt_lwe_ciphertext(&output_lwe_secret_key, &lwe_out);
+        let decoded = round_decode(decrypted.0, delta) % msg_modulus;
+        assert_eq!(decoded, f(Scalar::cast_from(msg)));
+    }
+}
+
+#[cfg(feature = "experimental")]
+create_parametrized_test!(lwe_encrypt_batch_pbs_decrypt_custom_mod);
 
 fn lwe_encrypt_pbs_f128_decrypt_custom_mod<
     Scalar: UnsignedTorus + Sync + Send + CastFrom<usize> + CastInto<usize>,
@@ -293,11 +746,169 @@ fn lwe_encrypt_pbs_f128_decrypt_custom_mod<
     }
 }
 
-#[test]
-fn lwe_encrypt_pbs_f128_decrypt_custom_mod_test_params_4_bits_native_u128() {
-    lwe_encrypt_pbs_f128_decrypt_custom_mod(TEST_PARAMS_4_BITS_NATIVE_U128)
-}
-#[test]
-fn lwe_encrypt_pbs_f128_decrypt_custom_mod_test_params_3_bits_127_u128() {
-    lwe_encrypt_pbs_f128_decrypt_custom_mod(TEST_PARAMS_3_BITS_127_U128)
+create_parametrized_test!(lwe_encrypt_pbs_f128_decrypt_custom_mod);
+
+fn lwe_encrypt_pbs_ntt64_decrypt_custom_mod<
+    Scalar: UnsignedTorus + Sync + Send + CastFrom<usize> + CastInto<usize>,
+>(
+    params: TestParams<Scalar>,
+) {
+    let input_lwe_dimension = params.lwe_dimension;
+    let lwe_modular_std_dev = params.lwe_modular_std_dev;
+    let glwe_modular_std_dev = params.glwe_modular_std_dev;
+    let ciphertext_modulus = params.ciphertext_modulus;
+    let message_modulus_log = params.message_modulus_log;
+    let msg_modulus = Scalar::ONE.shl(message_modulus_log.0);
+    let encoding_with_padding = get_encoding_with_padding(ciphertext_modulus);
+    let glwe_dimension = params.glwe_dimension;
+    let polynomial_size = params.polynomial_size;
+    let decomp_base_log = params.pbs_base_log;
+    let decomp_level_count = params.pbs_level;
+
+    let mut rsc = TestResources::new();
+
+    let f = |x: Scalar| {
+        x.wrapping_mul(Scalar::TWO)
+            .wrapping_sub(Scalar::ONE)
+            .wrapping_rem(msg_modulus)
+    };
+
+    let delta: Scalar = encoding_with_padding / msg_modulus;
+    // The NTT PBS is slow, only a few messages spread over the message space are tested
+    const NB_MSG: usize = 4;
+
+    // Distance between two decrypted values on the discretized torus
+    let torus_distance = |lhs: Scalar, rhs: Scalar| -> Scalar {
+        if ciphertext_modulus.is_native_modulus() {
+            let diff = lhs.wrapping_sub(rhs);
+            diff.min(diff.wrapping_neg())
+        } else {
+            let modulus = Scalar::cast_from(ciphertext_modulus.get_custom_modulus());
+            let diff = lhs.wrapping_add(modulus).wrapping_sub(rhs) % modulus;
+            diff.min(modulus - diff)
+        }
+    };
+
+    let accumulator = generate_accumulator(
+        polynomial_size,
+        glwe_dimension.to_glwe_size(),
+        msg_modulus.cast_into(),
+        ciphertext_modulus,
+        delta,
+        f,
+    );
+
+    assert!(check_content_respects_mod(&accumulator, ciphertext_modulus));
+
+    // Create the LweSecretKey
+    let input_lwe_secret_key = allocate_and_generate_new_binary_lwe_secret_key(
+        input_lwe_dimension,
+        &mut rsc.secret_random_generator,
+    );
+    let output_glwe_secret_key = allocate_and_generate_new_binary_glwe_secret_key(
+        glwe_dimension,
+        polynomial_size,
+        &mut rsc.secret_random_generator,
+    );
+    let output_lwe_secret_key = output_glwe_secret_key.clone().into_lwe_secret_key();
+
+    let mut bsk = LweBootstrapKey::new(
+        Scalar::ZERO,
+        glwe_dimension.to_glwe_size(),
+        polynomial_size,
+        decomp_base_log,
+        decomp_level_count,
+        input_lwe_dimension,
+        ciphertext_modulus,
+    );
+
+    par_generate_lwe_bootstrap_key(
+        &input_lwe_secret_key,
+        &output_glwe_secret_key,
+        &mut bsk,
+        glwe_modular_std_dev,
+        &mut rsc.encryption_random_generator,
+    );
+
+    assert!(check_content_respects_mod(&*bsk, ciphertext_modulus));
+
+    // The same key in the Fourier and in the NTT domains
+    let mut fbsk = FourierLweBootstrapKey::new(
+        input_lwe_dimension,
+        glwe_dimension.to_glwe_size(),
+        polynomial_size,
+        decomp_base_log,
+        decomp_level_count,
+    );
+    convert_standard_lwe_bootstrap_key_to_fourier(&bsk, &mut fbsk);
+
+    let mut nbsk = NttLweBootstrapKey::new(
+        input_lwe_dimension,
+        glwe_dimension.to_glwe_size(),
+        polynomial_size,
+        decomp_base_log,
+        decomp_level_count,
+    );
+    convert_standard_lwe_bootstrap_key_to_ntt64(&bsk, &mut nbsk);
+
+    drop(bsk);
+
+    for msg_index in 0..NB_MSG {
+        let msg = msg_modulus / Scalar::cast_from(NB_MSG) * Scalar::cast_from(msg_index);
+        let plaintext = Plaintext(msg * delta);
+
+        let lwe_ciphertext_in = allocate_and_encrypt_new_lwe_ciphertext(
+            &input_lwe_secret_key,
+            plaintext,
+            lwe_modular_std_dev,
+            ciphertext_modulus,
+            &mut rsc.encryption_random_generator,
+        );
+
+        assert!(check_content_respects_mod(
+            &lwe_ciphertext_in,
+            ciphertext_modulus
+        ));
+
+        let mut fft_pbs_ct = LweCiphertext::new(
+            Scalar::ZERO,
+            output_lwe_secret_key.lwe_dimension().to_lwe_size(),
+            ciphertext_modulus,
+        );
+        let mut ntt_pbs_ct = fft_pbs_ct.clone();
+
+        programmable_bootstrap_lwe_ciphertext(
+            &lwe_ciphertext_in,
+            &mut fft_pbs_ct,
+            &accumulator,
+            &fbsk,
+        );
+        programmable_bootstrap_ntt64_lwe_ciphertext(
+            &lwe_ciphertext_in,
+            &mut ntt_pbs_ct,
+            &accumulator,
+            &nbsk,
+        );
+
+        assert!(check_content_respects_mod(&ntt_pbs_ct, ciphertext_modulus));
+
+        let fft_decrypted = decrypt_lwe_ciphertext(&output_lwe_secret_key, &fft_pbs_ct);
+        let ntt_decrypted = decrypt_lwe_ciphertext(&output_lwe_secret_key, &ntt_pbs_ct);
+
+        assert_eq!(round_decode(fft_decrypted.0, delta) % msg_modulus, f(msg));
+        assert_eq!(round_decode(ntt_decrypted.0, delta) % msg_modulus, f(msg));
+
+        // Both PBS rotate the same accumulator with the same key, they only differ by the error of
+        // the Fourier transforms, which must stay far below the decoding margin
+        assert!(
+            torus_distance(fft_decrypted.0, ntt_decrypted.0) < delta / Scalar::cast_from(8usize)
+        );
+    }
 }
+
+create_parametrized_test!(lwe_encrypt_pbs_ntt64_decrypt_custom_mod {
+    TEST_PARAMS_4_BITS_NATIVE_U64,
+    TEST_PARAMS_3_BITS_63_U64,
+    TEST_PARAMS_4_BITS_NATIVE_U32,
+    TEST_PARAMS_3_BITS_31_U32
+});
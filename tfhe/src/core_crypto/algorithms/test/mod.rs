@@ -3,12 +3,14 @@ use paste::paste;
 
 mod ggsw_encryption;
 mod glwe_encryption;
+mod glwe_sample_extraction;
 mod lwe_bootstrap_key_generation;
 mod lwe_encryption;
 mod lwe_keyswitch;
 mod lwe_keyswitch_key_generation;
 mod lwe_linear_algebra;
 mod lwe_multi_bit_programmable_bootstrapping;
+mod lwe_private_functional_packing_keyswitch;
 mod lwe_programmable_bootstrapping;
 
 pub struct TestResources {
@@ -90,7 +92,7 @@ pub const TEST_PARAMS_3_BITS_63_U64: TestParams<u64> = TestParams {
     ciphertext_modulus: unsafe { CiphertextModulus::new_unchecked(1 << 63) },
 };
 
-pub const DUMMY_NATIVE_U32: TestParams<u32> = TestParams {
+pub const TEST_PARAMS_4_BITS_NATIVE_U32: TestParams<u32> = TestParams {
     lwe_dimension: LweDimension(742),
     glwe_dimension: GlweDimension(1),
     polynomial_size: PolynomialSize(2048),
@@ -109,7 +111,7 @@ pub const DUMMY_NATIVE_U32: TestParams<u32> = TestParams {
     ciphertext_modulus: CiphertextModulus::new_native(),
 };
 
-pub const DUMMY_31_U32: TestParams<u32> = TestParams {
+pub const TEST_PARAMS_3_BITS_31_U32: TestParams<u32> = TestParams {
     lwe_dimension: LweDimension(742),
     glwe_dimension: GlweDimension(1),
     polynomial_size: PolynomialSize(2048),
@@ -128,6 +130,44 @@ pub const DUMMY_31_U32: TestParams<u32> = TestParams {
     ciphertext_modulus: unsafe { CiphertextModulus::new_unchecked(1 << 31) },
 };
 
+pub const TEST_PARAMS_4_BITS_NATIVE_U128: TestParams<u128> = TestParams {
+    lwe_dimension: LweDimension(742),
+    glwe_dimension: GlweDimension(1),
+    polynomial_size: PolynomialSize(2048),
+    lwe_modular_std_dev: StandardDev(4.9982771e-11),
+    glwe_modular_std_dev: StandardDev(8.6457178e-32),
+    pbs_base_log: DecompositionBaseLog(23),
+    pbs_level: DecompositionLevelCount(1),
+    ks_level: DecompositionLevelCount(5),
+    ks_base_log: DecompositionBaseLog(3),
+    pfks_level: DecompositionLevelCount(1),
+    pfks_base_log: DecompositionBaseLog(23),
+    pfks_modular_std_dev: StandardDev(0.00000000000000029403601535432533),
+    cbs_level: DecompositionLevelCount(0),
+    cbs_base_log: DecompositionBaseLog(0),
+    message_modulus_log: CiphertextModulusLog(4),
+    ciphertext_modulus: CiphertextModulus::new_native(),
+};
+
+pub const TEST_PARAMS_3_BITS_127_U128: TestParams<u128> = TestParams {
+    lwe_dimension: LweDimension(742),
+    glwe_dimension: GlweDimension(1),
+    polynomial_size: PolynomialSize(2048),
+    lwe_modular_std_dev: StandardDev(4.9982771e-11),
+    glwe_modular_std_dev: StandardDev(8.6457178e-32),
+    pbs_base_log: DecompositionBaseLog(23),
+    pbs_level: DecompositionLevelCount(1),
+    ks_level: DecompositionLevelCount(5),
+    ks_base_log: DecompositionBaseLog(3),
+    pfks_level: DecompositionLevelCount(1),
+    pfks_base_log: DecompositionBaseLog(23),
+    pfks_modular_std_dev: StandardDev(0.00000000000000029403601535432533),
+    cbs_level: DecompositionLevelCount(0),
+    cbs_base_log: DecompositionBaseLog(0),
+    message_modulus_log: CiphertextModulusLog(3),
+    ciphertext_modulus: unsafe { CiphertextModulus::new_unchecked(1 << 127) },
+};
+
 // Our representation of non native power of 2 moduli puts the information in the MSBs and leaves
 // the LSBs empty, this is what this function is checking
 pub fn check_content_respects_mod<Scalar: UnsignedInteger, Input: AsRef<[Scalar]>>(
@@ -243,7 +283,11 @@ macro_rules! create_parametrized_test{
         create_parametrized_test!($name
         {
             TEST_PARAMS_4_BITS_NATIVE_U64,
-            TEST_PARAMS_3_BITS_63_U64
+            TEST_PARAMS_3_BITS_63_U64,
+            TEST_PARAMS_4_BITS_NATIVE_U32,
+            TEST_PARAMS_3_BITS_31_U32,
+            TEST_PARAMS_4_BITS_NATIVE_U128,
+            TEST_PARAMS_3_BITS_127_U128
         });
     };
 }
@@ -8,10 +8,38 @@
 //! + $\sigma$ can be encoded in the [`StandardDev`] type.
 //! + $p$ can be encoded in the [`LogStandardDev`] type.
 //! + $\sigma^2$ can be encoded in the [`Variance`] type.
+//! + $64 + p$, the number of noisy bits of a 64 bits torus element, can be encoded in the
+//!   [`NoiseBits`] type.
+//!
+//! The noise can also be sampled from a t-uniform distribution, whose support is bounded, instead
+//! of a gaussian one. [`DynamicDistribution`] selects one of the two distributions.
 //!
 //! In any of those cases, the corresponding type implements the `DispersionParameter` trait,
 //! which makes if possible to use any of those representations generically when noise must be
-//! defined.
+//! defined. The types can be converted into one another with [`From`], and their [`Display`]
+//! implementations make the unit explicit.
+//!
+//! # Example:
+//!
+//! ```
+//! use tfhe::core_crypto::commons::dispersion::{
+//!     DispersionParameter, LogStandardDev, NoiseBits, StandardDev, Variance,
+//! };
+//! // A noise given as a number of noisy bits in a paper
+//! let noise_bits = NoiseBits::from_noise_bits(39.);
+//! let std_dev = StandardDev::from(noise_bits);
+//! assert_eq!(std_dev, StandardDev(2_f64.powi(-25)));
+//! assert_eq!(Variance::from(std_dev), Variance(2_f64.powi(-50)));
+//! assert_eq!(LogStandardDev::from(std_dev), LogStandardDev(-25.));
+//! assert_eq!(NoiseBits::from(Variance::from(std_dev)), noise_bits);
+//!
+//! assert_eq!(noise_bits.to_string(), "39 bits of noise");
+//! assert_eq!(LogStandardDev::from(std_dev).to_string(), "σ = 2^-25");
+//! assert_eq!(Variance(0.25).to_string(), "σ² = 0.25");
+//! assert_eq!(StandardDev(0.5).to_string(), "σ = 0.5");
+//! ```
+
+use std::fmt::{Display, Formatter};
 
 use serde::{Deserialize, Serialize};
 
@@ -36,6 +64,12 @@ pub trait DispersionParameter: Copy {
 
     /// For a `Uint` type representing $\mathbb{Z}/2^q\mathbb{Z}$, we return $q-p$.
     fn get_modular_log_standard_dev(&self, log2_modulus: u32) -> f64;
+
+    /// If the noise is sampled from a t-uniform distribution, return the base 2 logarithm of the
+    /// bound $2^b$ of its support on the torus, i.e. $b$. Return `None` for a gaussian noise.
+    fn get_t_uniform_log2_bound(&self) -> Option<i32> {
+        None
+    }
 }
 
 /// A distribution parameter that uses the base-2 logarithm of the standard deviation as
@@ -161,7 +195,7 @@ impl DispersionParameter for StandardDev {
 ///     2_f64.powf(32. - 25.).powi(2)
 /// );
 /// ```
-#[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
 pub struct Variance(pub f64);
 
 impl Variance {
@@ -194,3 +228,279 @@ impl DispersionParameter for Variance {
         log2_modulus as f64 + self.0.sqrt().log2()
     }
 }
+
+/// A distribution parameter that uses the number of noisy bits of a 64 bits torus element as
+/// representation, i.e. $64 + p$.
+///
+/// This is the representation commonly used in papers for 64 bits ciphertexts. For other moduli,
+/// [`NoiseBits::from_modular_noise_bits`] converts the number of noisy bits of a
+/// $\mathbb{Z}/2^q\mathbb{Z}$ element.
+///
+/// # Example:
+///
+/// ```
+/// use tfhe::core_crypto::commons::dispersion::{DispersionParameter, NoiseBits};
+/// let params = NoiseBits::from_noise_bits(39.);
+/// assert_eq!(params.get_standard_dev(), 2_f64.powf(-25.));
+/// assert_eq!(params.get_log_standard_dev(), -25.);
+/// assert_eq!(params.get_variance(), 2_f64.powf(-25.).powi(2));
+/// assert_eq!(params.get_modular_standard_dev(32), 2_f64.powf(32. - 25.));
+/// assert_eq!(params.get_modular_log_standard_dev(32), 32. - 25.);
+/// assert_eq!(
+///     params.get_modular_variance(32),
+///     2_f64.powf(32. - 25.).powi(2)
+/// );
+///
+/// let modular_params = NoiseBits::from_modular_noise_bits(7., 32);
+/// assert_eq!(modular_params, params);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct NoiseBits(pub f64);
+
+impl NoiseBits {
+    const LOG2_MODULUS: u32 = 64;
+
+    pub fn from_noise_bits(bits: f64) -> NoiseBits {
+        NoiseBits(bits)
+    }
+
+    pub fn from_modular_noise_bits(bits: f64, log2_modulus: u32) -> NoiseBits {
+        NoiseBits(bits - log2_modulus as f64 + Self::LOG2_MODULUS as f64)
+    }
+}
+
+impl DispersionParameter for NoiseBits {
+    fn get_standard_dev(&self) -> f64 {
+        f64::powf(2., self.get_log_standard_dev())
+    }
+    fn get_variance(&self) -> f64 {
+        f64::powf(2., self.get_log_standard_dev() * 2.)
+    }
+    fn get_log_standard_dev(&self) -> f64 {
+        self.0 - Self::LOG2_MODULUS as f64
+    }
+    fn get_modular_standard_dev(&self, log2_modulus: u32) -> f64 {
+        f64::powf(2., self.get_modular_log_standard_dev(log2_modulus))
+    }
+    fn get_modular_variance(&self, log2_modulus: u32) -> f64 {
+        f64::powf(2., self.get_modular_log_standard_dev(log2_modulus) * 2.)
+    }
+    fn get_modular_log_standard_dev(&self, log2_modulus: u32) -> f64 {
+        log2_modulus as f64 + self.get_log_standard_dev()
+    }
+}
+
+macro_rules! impl_dispersion_conversions {
+    ($($from:ident => [$($to:ident: $get:ident),*]),* $(,)?) => {
+        $($(
+            impl From<$from> for $to {
+                fn from(dispersion: $from) -> Self {
+                    $to(dispersion.$get())
+                }
+            }
+        )*)*
+    };
+}
+
+impl_dispersion_conversions!(
+    LogStandardDev => [StandardDev: get_standard_dev, Variance: get_variance],
+    StandardDev => [LogStandardDev: get_log_standard_dev, Variance: get_variance],
+    Variance => [LogStandardDev: get_log_standard_dev, StandardDev: get_standard_dev],
+    NoiseBits => [
+        LogStandardDev: get_log_standard_dev,
+        StandardDev: get_standard_dev,
+        Variance: get_variance
+    ],
+);
+
+impl From<LogStandardDev> for NoiseBits {
+    fn from(dispersion: LogStandardDev) -> Self {
+        NoiseBits(dispersion.get_modular_log_standard_dev(Self::LOG2_MODULUS))
+    }
+}
+
+impl From<StandardDev> for NoiseBits {
+    fn from(dispersion: StandardDev) -> Self {
+        NoiseBits(dispersion.get_modular_log_standard_dev(Self::LOG2_MODULUS))
+    }
+}
+
+impl From<Variance> for NoiseBits {
+    fn from(dispersion: Variance) -> Self {
+        NoiseBits(dispersion.get_modular_log_standard_dev(Self::LOG2_MODULUS))
+    }
+}
+
+impl Display for LogStandardDev {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "σ = 2^{}", self.0)
+    }
+}
+
+impl Display for StandardDev {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "σ = {}", self.0)
+    }
+}
+
+impl Display for Variance {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "σ² = {}", self.0)
+    }
+}
+
+impl Display for NoiseBits {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} bits of noise", self.0)
+    }
+}
+
+/// The distribution the noise is sampled from, either gaussian or t-uniform.
+///
+/// A t-uniform noise of bound $2^b$ on the torus takes the values of $[-2^b, 2^b]$, the bounds
+/// being half as likely as the other values. For a `Uint` type representing
+/// $\mathbb{Z}/2^q\mathbb{Z}$, the bound is $2^{q+b}$ and the variance is
+/// $\frac{2^{2(q+b)+1}+1}{6}$; on the torus, the variance is taken as its limit $2^{2b}/3$.
+///
+/// # Example:
+///
+/// ```
+/// use tfhe::core_crypto::commons::dispersion::{
+///     DispersionParameter, DynamicDistribution, StandardDev,
+/// };
+/// let gaussian = DynamicDistribution::new_gaussian(StandardDev(2_f64.powi(-25)));
+/// assert_eq!(gaussian.get_log_standard_dev(), -25.);
+/// assert_eq!(gaussian.get_t_uniform_log2_bound(), None);
+///
+/// let t_uniform = DynamicDistribution::new_t_uniform(-40);
+/// assert_eq!(t_uniform.get_t_uniform_log2_bound(), Some(-40));
+/// assert_eq!(t_uniform.get_variance(), 2_f64.powi(-80) / 3.);
+/// assert_eq!(
+///     t_uniform.get_modular_variance(64),
+///     (2_f64.powi(49) + 1.) / 6.
+/// );
+/// assert_eq!(t_uniform.to_string(), "t-uniform noise, bound = 2^-40");
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize)]
+pub enum DynamicDistribution {
+    Gaussian(StandardDev),
+    TUniform {
+        /// The base 2 logarithm of the bound of the support on the torus
+        log2_bound: i32,
+    },
+}
+
+impl DynamicDistribution {
+    pub fn new_gaussian(std: impl Into<StandardDev>) -> DynamicDistribution {
+        DynamicDistribution::Gaussian(std.into())
+    }
+
+    pub fn new_t_uniform(log2_bound: i32) -> DynamicDistribution {
+        DynamicDistribution::TUniform { log2_bound }
+    }
+
+    pub fn new_t_uniform_from_modular_log2_bound(
+        log2_bound: u32,
+        log2_modulus: u32,
+    ) -> DynamicDistribution {
+        DynamicDistribution::TUniform {
+            log2_bound: log2_bound as i32 - log2_modulus as i32,
+        }
+    }
+
+    fn t_uniform_modular_variance(log2_bound: i32, log2_modulus: u32) -> f64 {
+        (f64::powf(2., 2. * (log2_modulus as f64 + log2_bound as f64) + 1.) + 1.) / 6.
+    }
+}
+
+/// The distribution of the coefficients of a secret key.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::core_crypto::commons::dispersion::SecretKeyDistribution;
+///
+/// assert_eq!(SecretKeyDistribution::default(), SecretKeyDistribution::Binary);
+/// ```
+#[derive(Debug, Copy, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub enum SecretKeyDistribution {
+    /// Coefficients uniformly sampled in `{0, 1}`
+    #[default]
+    Binary,
+    /// Coefficients uniformly sampled in `{-1, 0, 1}`
+    Ternary,
+    /// Coefficients sampled from a Gaussian distribution centered on 0 and rounded to the nearest
+    /// integer
+    Gaussian {
+        /// The standard deviation of the coefficients, as an integer value (not on the torus)
+        std_dev: f64,
+    },
+}
+
+impl From<StandardDev> for DynamicDistribution {
+    fn from(std: StandardDev) -> Self {
+        DynamicDistribution::Gaussian(std)
+    }
+}
+
+impl DispersionParameter for DynamicDistribution {
+    fn get_standard_dev(&self) -> f64 {
+        match self {
+            DynamicDistribution::Gaussian(std) => std.get_standard_dev(),
+            DynamicDistribution::TUniform { .. } => self.get_variance().sqrt(),
+        }
+    }
+    fn get_variance(&self) -> f64 {
+        match self {
+            DynamicDistribution::Gaussian(std) => std.get_variance(),
+            DynamicDistribution::TUniform { log2_bound } => {
+                f64::powf(2., *log2_bound as f64 * 2.) / 3.
+            }
+        }
+    }
+    fn get_log_standard_dev(&self) -> f64 {
+        match self {
+            DynamicDistribution::Gaussian(std) => std.get_log_standard_dev(),
+            DynamicDistribution::TUniform { .. } => self.get_standard_dev().log2(),
+        }
+    }
+    fn get_modular_standard_dev(&self, log2_modulus: u32) -> f64 {
+        match self {
+            DynamicDistribution::Gaussian(std) => std.get_modular_standard_dev(log2_modulus),
+            DynamicDistribution::TUniform { .. } => self.get_modular_variance(log2_modulus).sqrt(),
+        }
+    }
+    fn get_modular_variance(&self, log2_modulus: u32) -> f64 {
+        match self {
+            DynamicDistribution::Gaussian(std) => std.get_modular_variance(log2_modulus),
+            DynamicDistribution::TUniform { log2_bound } => {
+                Self::t_uniform_modular_variance(*log2_bound, log2_modulus)
+            }
+        }
+    }
+    fn get_modular_log_standard_dev(&self, log2_modulus: u32) -> f64 {
+        match self {
+            DynamicDistribution::Gaussian(std) => std.get_modular_log_standard_dev(log2_modulus),
+            DynamicDistribution::TUniform { .. } => {
+                self.get_modular_standard_dev(log2_modulus).log2()
+            }
+        }
+    }
+    fn get_t_uniform_log2_bound(&self) -> Option<i32> {
+        match self {
+            DynamicDistribution::Gaussian(_) => None,
+            DynamicDistribution::TUniform { log2_bound } => Some(*log2_bound),
+        }
+    }
+}
+
+impl Display for DynamicDistribution {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DynamicDistribution::Gaussian(std) => write!(f, "gaussian noise, {std}"),
+            DynamicDistribution::TUniform { log2_bound } => {
+                write!(f, "t-uniform noise, bound = 2^{log2_bound}")
+            }
+        }
+    }
+}
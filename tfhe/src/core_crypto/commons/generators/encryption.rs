@@ -3,7 +3,7 @@
 use crate::core_crypto::commons::dispersion::DispersionParameter;
 use crate::core_crypto::commons::math::random::{
     ByteRandomGenerator, Gaussian, ParallelByteRandomGenerator, RandomGenerable, RandomGenerator,
-    Seed, Seeder, Uniform,
+    Seed, Seeder, TUniform, Uniform, UniformBinary,
 };
 use crate::core_crypto::commons::math::torus::UnsignedTorus;
 use crate::core_crypto::commons::numeric::{CastInto, UnsignedInteger};
@@ -206,15 +206,29 @@ impl<G: ByteRandomGenerator> EncryptionRandomGenerator<G> {
             .fill_slice_with_random_uniform_custom_mod(output, ciphertext_modulus);
     }
 
+    // Fills the slice with random uniform binary values, using the noise generator, for secret
+    // randomness which is not part of a key (e.g. the ephemeral key of a public key encryption).
+    pub(crate) fn fill_slice_with_random_noise_binary<Scalar>(&mut self, output: &mut [Scalar])
+    where
+        Scalar: RandomGenerable<UniformBinary>,
+    {
+        self.noise.fill_slice_with_random_uniform_binary(output)
+    }
+
     // Sample a noise value, using the noise generator.
-    pub(crate) fn random_noise<Scalar>(&mut self, std: impl DispersionParameter) -> Scalar
+    pub(crate) fn random_noise<Scalar>(&mut self, noise: impl DispersionParameter) -> Scalar
     where
-        Scalar: RandomGenerable<Gaussian<f64>>,
+        Scalar: UnsignedInteger + RandomGenerable<Gaussian<f64>> + RandomGenerable<TUniform>,
     {
+        if let Some(log2_bound) = noise.get_t_uniform_log2_bound() {
+            let log2_bound = modular_t_uniform_log2_bound(log2_bound, Scalar::BITS as u32);
+            return self.noise.random_t_uniform(log2_bound);
+        }
+
         <Scalar>::generate_one(
             &mut self.noise,
             Gaussian {
-                std: std.get_standard_dev(),
+                std: noise.get_standard_dev(),
                 mean: 0.,
             },
         )
@@ -223,21 +237,33 @@ impl<G: ByteRandomGenerator> EncryptionRandomGenerator<G> {
     // Sample a noise value, using the noise generator.
     pub(crate) fn random_noise_custom_mod<Scalar>(
         &mut self,
-        std: impl DispersionParameter,
+        noise: impl DispersionParameter,
         custom_modulus: CiphertextModulus<Scalar>,
     ) -> Scalar
     where
-        Scalar: UnsignedInteger + RandomGenerable<Gaussian<f64>, CustomModulus = f64>,
+        Scalar: UnsignedInteger
+            + RandomGenerable<Gaussian<f64>, CustomModulus = f64>
+            + RandomGenerable<TUniform, CustomModulus = Scalar>,
     {
         if custom_modulus.is_native_modulus() {
-            return self.random_noise(std);
+            return self.random_noise(noise);
+        }
+
+        if let Some(log2_bound) = noise.get_t_uniform_log2_bound() {
+            let log2_bound = modular_t_uniform_log2_bound(
+                log2_bound,
+                custom_modulus.get_custom_modulus().ilog2(),
+            );
+            return self
+                .noise
+                .random_t_uniform_custom_mod(log2_bound, custom_modulus);
         }
 
         let custom_modulus_f64: f64 = custom_modulus.get_custom_modulus().cast_into();
         Scalar::generate_one_custom_modulus(
             &mut self.noise,
             Gaussian {
-                std: std.get_standard_dev(),
+                std: noise.get_standard_dev(),
                 mean: 0.,
             },
             custom_modulus_f64,
@@ -248,28 +274,43 @@ impl<G: ByteRandomGenerator> EncryptionRandomGenerator<G> {
     pub(crate) fn fill_slice_with_random_noise<Scalar>(
         &mut self,
         output: &mut [Scalar],
-        std: impl DispersionParameter,
+        noise: impl DispersionParameter,
     ) where
+        Scalar: UnsignedInteger + RandomGenerable<TUniform>,
         (Scalar, Scalar): RandomGenerable<Gaussian<f64>>,
     {
+        if noise.get_t_uniform_log2_bound().is_some() {
+            output
+                .iter_mut()
+                .for_each(|elem| *elem = self.random_t_uniform_noise(noise));
+            return;
+        }
+
         self.noise
-            .fill_slice_with_random_gaussian(output, 0., std.get_standard_dev());
+            .fill_slice_with_random_gaussian(output, 0., noise.get_standard_dev());
     }
 
     // Fills the input slice with random noise, using the noise generator.
     pub(crate) fn fill_slice_with_random_noise_custom_mod<Scalar>(
         &mut self,
         output: &mut [Scalar],
-        std: impl DispersionParameter,
+        noise: impl DispersionParameter,
         custom_modulus: CiphertextModulus<Scalar>,
     ) where
-        Scalar: UnsignedInteger,
+        Scalar: UnsignedInteger + RandomGenerable<TUniform, CustomModulus = Scalar>,
         (Scalar, Scalar): RandomGenerable<Gaussian<f64>, CustomModulus = f64>,
     {
+        if noise.get_t_uniform_log2_bound().is_some() {
+            output.iter_mut().for_each(|elem| {
+                *elem = self.random_t_uniform_noise_custom_mod(noise, custom_modulus);
+            });
+            return;
+        }
+
         self.noise.fill_slice_with_random_gaussian_custom_mod(
             output,
             0.,
-            std.get_standard_dev(),
+            noise.get_standard_dev(),
             custom_modulus,
         );
     }
@@ -278,16 +319,23 @@ impl<G: ByteRandomGenerator> EncryptionRandomGenerator<G> {
     pub(crate) fn unsigned_torus_slice_wrapping_add_random_noise_assign<Scalar>(
         &mut self,
         output: &mut [Scalar],
-        std: impl DispersionParameter,
+        noise: impl DispersionParameter,
     ) where
         Scalar: UnsignedTorus,
         (Scalar, Scalar): RandomGenerable<Gaussian<f64>>,
     {
+        if noise.get_t_uniform_log2_bound().is_some() {
+            output.iter_mut().for_each(|elem| {
+                *elem = (*elem).wrapping_add(self.random_t_uniform_noise(noise));
+            });
+            return;
+        }
+
         self.noise
             .unsigned_torus_slice_wrapping_add_random_gaussian_assign(
                 output,
                 0.,
-                std.get_standard_dev(),
+                noise.get_standard_dev(),
             );
     }
 
@@ -295,20 +343,73 @@ impl<G: ByteRandomGenerator> EncryptionRandomGenerator<G> {
     pub(crate) fn unsigned_torus_slice_wrapping_add_random_noise_custom_mod_assign<Scalar>(
         &mut self,
         output: &mut [Scalar],
-        std: impl DispersionParameter,
+        noise: impl DispersionParameter,
         custom_modulus: CiphertextModulus<Scalar>,
     ) where
         Scalar: UnsignedTorus,
         (Scalar, Scalar): RandomGenerable<Gaussian<f64>, CustomModulus = f64>,
     {
+        if noise.get_t_uniform_log2_bound().is_some() {
+            output.iter_mut().for_each(|elem| {
+                let value = self.random_t_uniform_noise_custom_mod(noise, custom_modulus);
+                *elem = (*elem).wrapping_add(value);
+            });
+            return;
+        }
+
         self.noise
             .unsigned_torus_slice_wrapping_add_random_gaussian_custom_mod_assign(
                 output,
                 0.,
-                std.get_standard_dev(),
+                noise.get_standard_dev(),
                 custom_modulus,
             );
     }
+
+    // Sample a t-uniform noise value, the noise must be t-uniform
+    fn random_t_uniform_noise<Scalar>(&mut self, noise: impl DispersionParameter) -> Scalar
+    where
+        Scalar: UnsignedInteger + RandomGenerable<TUniform>,
+    {
+        let log2_bound = noise.get_t_uniform_log2_bound().unwrap();
+        self.noise.random_t_uniform(modular_t_uniform_log2_bound(
+            log2_bound,
+            Scalar::BITS as u32,
+        ))
+    }
+
+    // Sample a t-uniform noise value, the noise must be t-uniform
+    fn random_t_uniform_noise_custom_mod<Scalar>(
+        &mut self,
+        noise: impl DispersionParameter,
+        custom_modulus: CiphertextModulus<Scalar>,
+    ) -> Scalar
+    where
+        Scalar: UnsignedInteger + RandomGenerable<TUniform, CustomModulus = Scalar>,
+    {
+        if custom_modulus.is_native_modulus() {
+            return self.random_t_uniform_noise(noise);
+        }
+
+        let log2_bound = modular_t_uniform_log2_bound(
+            noise.get_t_uniform_log2_bound().unwrap(),
+            custom_modulus.get_custom_modulus().ilog2(),
+        );
+        self.noise
+            .random_t_uniform_custom_mod(log2_bound, custom_modulus)
+    }
+}
+
+// Converts the bound of a t-uniform noise on the torus to a bound for integers modulo
+// 2^log2_modulus.
+fn modular_t_uniform_log2_bound(log2_bound: i32, log2_modulus: u32) -> u32 {
+    let modular_log2_bound = log2_modulus as i32 + log2_bound;
+    assert!(
+        modular_log2_bound >= 0,
+        "A t-uniform bound of 2^{log2_bound} on the torus is smaller than the modulus precision of \
+        2^-{log2_modulus}"
+    );
+    modular_log2_bound as u32
 }
 
 impl<G: ParallelByteRandomGenerator> EncryptionRandomGenerator<G> {
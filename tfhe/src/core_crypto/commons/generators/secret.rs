@@ -1,9 +1,11 @@
 //! Module containing primitives pertaining to random generation in the context of secret key
 //! generation.
 
+use crate::core_crypto::commons::dispersion::SecretKeyDistribution;
 use crate::core_crypto::commons::math::random::{
-    ByteRandomGenerator, RandomGenerable, RandomGenerator, Seed, UniformBinary,
+    ByteRandomGenerator, RandomGenerable, RandomGenerator, Seed, UniformBinary, UniformTernary,
 };
+use crate::core_crypto::commons::numeric::{CastFrom, UnsignedInteger};
 
 /// A random number generator which can be used to generate secret keys.
 pub struct SecretRandomGenerator<G: ByteRandomGenerator>(RandomGenerator<G>);
@@ -25,4 +27,26 @@ impl<G: ByteRandomGenerator> SecretRandomGenerator<G> {
     {
         self.0.fill_slice_with_random_uniform_binary(slice);
     }
+
+    pub(crate) fn fill_slice_with_secret_key_distribution<Scalar>(
+        &mut self,
+        slice: &mut [Scalar],
+        distribution: SecretKeyDistribution,
+    ) where
+        Scalar: RandomGenerable<UniformBinary> + RandomGenerable<UniformTernary> + UnsignedInteger,
+    {
+        match distribution {
+            SecretKeyDistribution::Binary => self.0.fill_slice_with_random_uniform_binary(slice),
+            SecretKeyDistribution::Ternary => self.0.fill_slice_with_random_uniform_ternary(slice),
+            SecretKeyDistribution::Gaussian { std_dev } => {
+                for chunk in slice.chunks_mut(2) {
+                    let samples: (f64, f64) = self.0.random_gaussian(0., std_dev);
+                    for (coef, sample) in chunk.iter_mut().zip([samples.0, samples.1]) {
+                        // Negative coefficients are stored in two's complement
+                        *coef = Scalar::cast_from(sample.round() as i64 as u128);
+                    }
+                }
+            }
+        }
+    }
 }
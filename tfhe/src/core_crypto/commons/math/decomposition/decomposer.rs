@@ -1,4 +1,6 @@
-use crate::core_crypto::commons::math::decomposition::SignedDecompositionIter;
+use crate::core_crypto::commons::math::decomposition::{
+    DecompositionLevel, SignedDecompositionIter,
+};
 use crate::core_crypto::commons::numeric::{Numeric, UnsignedInteger};
 use crate::core_crypto::commons::parameters::{DecompositionBaseLog, DecompositionLevelCount};
 use std::marker::PhantomData;
@@ -6,7 +8,12 @@ use std::marker::PhantomData;
 /// A structure which allows to decompose unsigned integers into a set of smaller terms.
 ///
 /// See the [module level](super) documentation for a description of the signed decomposition.
-#[derive(Debug)]
+///
+/// All the computations are performed with wrapping arithmetic modulo $q = 2^{\text{BITS}}$ where
+/// `BITS` is the bit width of `Scalar`, which is the arithmetic of the ciphertexts of the crate:
+/// values obtained with the decomposer can be combined with the gadget factors returned by
+/// [`SignedDecomposer::gadget_factor`] to build custom gadget products.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub struct SignedDecomposer<Scalar>
 where
     Scalar: UnsignedInteger,
@@ -81,7 +88,11 @@ where
         DecompositionLevelCount(self.level_count)
     }
 
-    /// Return the closet value representable by the decomposition.
+    /// Return the closest value representable by the decomposition.
+    ///
+    /// The representable values are the multiples of $\frac{q}{B^l}$, the input is rounded to
+    /// the nearest one, ties being rounded up, and the result wraps around modulo $q$. Only the
+    /// $b \cdot l$ most significant bits of the output may be non zero.
     ///
     /// # Example
     ///
@@ -113,8 +124,57 @@ where
         res << non_rep_bit_count
     }
 
+    /// Return the gadget factor $\frac{q}{B^i}$ associated to the given level $i$.
+    ///
+    /// The recomposition summand of a term of level $i$ is the product of its value with this
+    /// factor, so that the scalar product of the terms of a decomposition with the gadget factors
+    /// gives back the closest representable value of the input.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the level is not in $[1, l]$.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::commons::math::decomposition::{
+    ///     DecompositionLevel, SignedDecomposer,
+    /// };
+    /// use tfhe::core_crypto::commons::parameters::{DecompositionBaseLog, DecompositionLevelCount};
+    /// let decomposer =
+    ///     SignedDecomposer::<u64>::new(DecompositionBaseLog(8), DecompositionLevelCount(3));
+    /// assert_eq!(decomposer.gadget_factor(DecompositionLevel(1)), 1 << 56);
+    /// assert_eq!(decomposer.gadget_factor(DecompositionLevel(3)), 1 << 40);
+    ///
+    /// // A gadget product between the decomposition of x and a "gadget encoding" of y, i.e. the
+    /// // products of y with the gadget factors, computes x * y up to the rounding of x
+    /// let (x, y) = (0x1234_5678_9abc_def0_u64, 3u64);
+    /// let gadget_product = decomposer.decompose(x).fold(0u64, |acc, term| {
+    ///     let encoding = y.wrapping_mul(decomposer.gadget_factor(term.level()));
+    ///     acc.wrapping_add(term.value().wrapping_mul(encoding))
+    /// });
+    /// assert_eq!(
+    ///     gadget_product,
+    ///     decomposer.closest_representable(x).wrapping_mul(y)
+    /// );
+    /// ```
+    pub fn gadget_factor(&self, level: DecompositionLevel) -> Scalar {
+        assert!(
+            1 <= level.0 && level.0 <= self.level_count,
+            "Level {} is out of the range [1, {}] of the decomposition",
+            level.0,
+            self.level_count
+        );
+        Scalar::ONE << (Scalar::BITS - self.base_log * level.0)
+    }
+
     /// Generate an iterator over the terms of the decomposition of the input.
     ///
+    /// The input is first rounded to the [closest representable
+    /// value](`SignedDecomposer::closest_representable`). The values of the terms are returned as
+    /// unsigned integers holding the two's complement representation of signed values in
+    /// $[-\frac{B}{2}, \frac{B}{2}]$, see [`UnsignedInteger::into_signed`].
+    ///
     /// # Warning
     ///
     /// The returned iterator yields the terms $\tilde{\theta}\_i$ in order of decreasing $i$.
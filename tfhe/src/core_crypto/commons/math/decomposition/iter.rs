@@ -46,7 +46,25 @@ where
         }
     }
 
-    pub(crate) fn is_fresh(&self) -> bool {
+    /// Return whether no term has been yielded by this iterator yet.
+    ///
+    /// Only fresh iterators can be given to
+    /// [`SignedDecomposer::recompose`](super::SignedDecomposer::recompose).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::commons::math::decomposition::SignedDecomposer;
+    /// use tfhe::core_crypto::commons::parameters::{DecompositionBaseLog, DecompositionLevelCount};
+    /// let decomposer =
+    ///     SignedDecomposer::<u32>::new(DecompositionBaseLog(4), DecompositionLevelCount(3));
+    /// let mut decomp = decomposer.decompose(1_340_987_234_u32);
+    /// assert!(decomp.is_fresh());
+    /// decomp.next();
+    /// assert!(!decomp.is_fresh());
+    /// assert!(decomposer.recompose(decomp).is_none());
+    /// ```
+    pub fn is_fresh(&self) -> bool {
         self.fresh
     }
 
@@ -1,4 +1,4 @@
-use crate::core_crypto::commons::math::decomposition::SignedDecomposer;
+use crate::core_crypto::commons::math::decomposition::{DecompositionLevel, SignedDecomposer};
 use crate::core_crypto::commons::math::random::{RandomGenerable, Uniform};
 use crate::core_crypto::commons::math::torus::UnsignedTorus;
 use crate::core_crypto::commons::numeric::{Numeric, SignedInteger, UnsignedInteger};
@@ -57,6 +57,61 @@ fn test_decompose_recompose_u64() {
     test_decompose_recompose::<u64>()
 }
 
+#[test]
+fn test_decompose_recompose_u128() {
+    test_decompose_recompose::<u128>()
+}
+
+fn test_gadget_product<T: UnsignedInteger + Debug + RandomGenerable<Uniform>>() {
+    // Checks that the scalar product of the terms with the gadget factors times a value gives the
+    // closest representable times this value
+    for _ in 0..10_000 {
+        let decomposer = random_decomp::<T>();
+        let input = any_uint::<T>();
+        let factor = any_uint::<T>();
+
+        let mut decomp = decomposer.decompose(input);
+        assert!(decomp.is_fresh());
+        let gadget_product = decomp.by_ref().fold(T::ZERO, |acc, term| {
+            let summand = term
+                .value()
+                .wrapping_mul(decomposer.gadget_factor(term.level()));
+            assert_eq!(summand, term.to_recomposition_summand());
+            acc.wrapping_add(summand.wrapping_mul(factor))
+        });
+        assert!(!decomp.is_fresh());
+        assert!(decomposer.recompose(decomp).is_none());
+
+        assert_eq!(
+            gadget_product,
+            decomposer.closest_representable(input).wrapping_mul(factor)
+        );
+    }
+}
+
+#[test]
+fn test_gadget_product_u32() {
+    test_gadget_product::<u32>()
+}
+
+#[test]
+fn test_gadget_product_u64() {
+    test_gadget_product::<u64>()
+}
+
+#[test]
+fn test_gadget_product_u128() {
+    test_gadget_product::<u128>()
+}
+
+#[test]
+#[should_panic]
+fn test_gadget_factor_level_out_of_range() {
+    let decomposer =
+        SignedDecomposer::<u64>::new(DecompositionBaseLog(4), DecompositionLevelCount(3));
+    decomposer.gadget_factor(DecompositionLevel(4));
+}
+
 fn test_round_to_closest_representable<T: UnsignedTorus>() {
     for _ in 0..1000 {
         let log_b = any_usize();
@@ -1,6 +1,6 @@
 use crate::core_crypto::commons::math::random::{
-    Gaussian, RandomGenerable, Uniform, UniformBinary, UniformLsb, UniformMsb, UniformTernary,
-    UniformWithZeros,
+    Gaussian, RandomGenerable, TUniform, Uniform, UniformBinary, UniformLsb, UniformMsb,
+    UniformTernary, UniformWithZeros,
 };
 use crate::core_crypto::commons::math::torus::{UnsignedInteger, UnsignedTorus};
 use crate::core_crypto::commons::numeric::{CastFrom, CastInto, FloatingPoint};
@@ -270,6 +270,82 @@ impl<G: ByteRandomGenerator> RandomGenerator<G> {
         Scalar::generate_one(self, UniformTernary)
     }
 
+    /// Fill a slice with random uniform ternary values, `-1` being represented by the
+    /// maximum of the type.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::Seed;
+    /// use tfhe::core_crypto::commons::math::random::RandomGenerator;
+    /// let mut generator = RandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let mut vec = vec![0u32; 1000];
+    /// generator.fill_slice_with_random_uniform_ternary(&mut vec);
+    /// assert!(vec.iter().all(|&x| x == 0 || x == 1 || x == u32::MAX));
+    /// assert!(vec.iter().any(|&x| x == u32::MAX));
+    /// ```
+    pub fn fill_slice_with_random_uniform_ternary<Scalar>(&mut self, output: &mut [Scalar])
+    where
+        Scalar: RandomGenerable<UniformTernary>,
+    {
+        Scalar::fill_slice(self, UniformTernary, output);
+    }
+
+    /// Generate a random t-uniform unsigned integer in `[-2^log2_bound, 2^log2_bound]`, negative
+    /// values are returned in two's complement, see [`TUniform`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::Seed;
+    /// use tfhe::core_crypto::commons::math::random::RandomGenerator;
+    /// let mut generator = RandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let random: u64 = generator.random_t_uniform(3);
+    /// assert!((random as i64).abs() <= 8);
+    /// ```
+    pub fn random_t_uniform<Scalar: RandomGenerable<TUniform>>(
+        &mut self,
+        log2_bound: u32,
+    ) -> Scalar {
+        Scalar::generate_one(self, TUniform { log2_bound })
+    }
+
+    /// Generate a random t-uniform unsigned integer in `[-2^log2_bound, 2^log2_bound]`, negative
+    /// values are returned as `custom_modulus - |value|`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::Seed;
+    /// use tfhe::core_crypto::commons::math::random::RandomGenerator;
+    /// use tfhe::core_crypto::commons::parameters::CiphertextModulus;
+    /// let mut generator = RandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let custom_modulus = CiphertextModulus::try_new_power_of_2(31).unwrap();
+    /// let random: u32 = generator.random_t_uniform_custom_mod(3, custom_modulus);
+    /// assert!(random <= 8 || random >= (1 << 31) - 8);
+    /// ```
+    pub fn random_t_uniform_custom_mod<Scalar>(
+        &mut self,
+        log2_bound: u32,
+        custom_modulus: CiphertextModulus<Scalar>,
+    ) -> Scalar
+    where
+        Scalar: UnsignedInteger + RandomGenerable<TUniform, CustomModulus = Scalar>,
+    {
+        if custom_modulus.is_native_modulus() {
+            return self.random_t_uniform(log2_bound);
+        }
+
+        Scalar::generate_one_custom_modulus(
+            self,
+            TUniform { log2_bound },
+            custom_modulus.get_custom_modulus().cast_into(),
+        )
+    }
+
     /// Generate an unsigned integer whose n least significant bits are uniformly random, and the
     /// other bits are zero.
     ///
@@ -7,6 +7,8 @@
 //! probability over the set of representable values.
 //! + [`RandomGenerator::random_gaussian`] samples a random float with using a gaussian
 //! distribution.
+//! + [`RandomGenerator::random_t_uniform`] samples a random unsigned integer with a t-uniform
+//! distribution, i.e. with a bounded support.
 //!
 //! The implementation relies on the [`RandomGenerable`] trait, which gives a type the ability to
 //! be randomly generated according to a given distribution. The module contains multiple
@@ -19,6 +21,7 @@ use crate::core_crypto::commons::numeric::FloatingPoint;
 pub use activated_random_generator::ActivatedRandomGenerator;
 pub use gaussian::*;
 pub use generator::*;
+pub use t_uniform::*;
 pub use uniform::*;
 pub use uniform_binary::*;
 pub use uniform_lsb::*;
@@ -32,6 +35,7 @@ mod tests;
 mod activated_random_generator;
 mod gaussian;
 mod generator;
+mod t_uniform;
 mod uniform;
 mod uniform_binary;
 mod uniform_lsb;
@@ -85,6 +89,7 @@ mod seal {
     impl Sealed for super::UniformWithZeros {}
     impl Sealed for super::UniformBinary {}
     impl Sealed for super::UniformTernary {}
+    impl Sealed for super::TUniform {}
     impl<T: FloatingPoint> Sealed for super::Gaussian<T> {}
 }
 impl Distribution for Uniform {}
@@ -93,4 +98,5 @@ impl Distribution for UniformLsb {}
 impl Distribution for UniformWithZeros {}
 impl Distribution for UniformBinary {}
 impl Distribution for UniformTernary {}
+impl Distribution for TUniform {}
 impl<T: FloatingPoint> Distribution for Gaussian<T> {}
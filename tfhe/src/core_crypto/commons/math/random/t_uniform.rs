@@ -0,0 +1,57 @@
+use super::*;
+use crate::core_crypto::commons::numeric::Numeric;
+
+/// A distribution type representing t-uniform sampling for unsigned integer types: the values of
+/// `[-2^log2_bound, 2^log2_bound]` are sampled with probability `1/2^(log2_bound + 1)`, except
+/// the two bounds which are sampled with probability `1/2^(log2_bound + 2)`.
+///
+/// Negative values are returned in two's complement, or as `custom_modulus - |value|` when a custom
+/// modulus is used.
+#[derive(Copy, Clone)]
+pub struct TUniform {
+    /// The base 2 logarithm of the bound of the support of the distribution.
+    pub log2_bound: u32,
+}
+
+macro_rules! implement_t_uniform {
+    ($T:ty) => {
+        impl RandomGenerable<TUniform> for $T {
+            type CustomModulus = $T;
+            fn generate_one<G: ByteRandomGenerator>(
+                generator: &mut RandomGenerator<G>,
+                TUniform { log2_bound }: TUniform,
+            ) -> Self {
+                let n = log2_bound as usize + 2;
+                assert!(
+                    n <= <$T as Numeric>::BITS,
+                    "A t-uniform bound of 2^{log2_bound} does not fit in {} bits",
+                    <$T as Numeric>::BITS
+                );
+                // Each value of ]-2^b, 2^b[ is reached by two of the 2^(b + 2) random values, the
+                // bounds by only one
+                let random = <$T>::generate_one(generator, UniformLsb { n });
+                ((random >> 1) + (random & 1)).wrapping_sub(1 << log2_bound)
+            }
+
+            fn generate_one_custom_modulus<G: ByteRandomGenerator>(
+                generator: &mut RandomGenerator<G>,
+                distribution: TUniform,
+                custom_modulus: $T,
+            ) -> Self {
+                let value = <$T>::generate_one(generator, distribution);
+                let is_negative = value >> (<$T as Numeric>::BITS - 1) == 1;
+                if is_negative {
+                    custom_modulus.wrapping_add(value)
+                } else {
+                    value
+                }
+            }
+        }
+    };
+}
+
+implement_t_uniform!(u8);
+implement_t_uniform!(u16);
+implement_t_uniform!(u32);
+implement_t_uniform!(u64);
+implement_t_uniform!(u128);
@@ -1,5 +1,6 @@
 use crate::core_crypto::commons::ciphertext_modulus::CiphertextModulus;
 use crate::core_crypto::commons::math::torus::UnsignedTorus;
+use crate::core_crypto::commons::numeric::CastInto;
 use crate::core_crypto::commons::test_tools::*;
 
 fn test_normal_random_three_sigma<T: UnsignedTorus>() {
@@ -353,3 +354,60 @@ fn test_normal_random_add_assign_native_custom_mod_u64() {
 fn test_normal_random_add_assign_native_custom_mod_u128() {
     test_normal_random_add_assign_custom_mod::<u128>(CiphertextModulus::new_native());
 }
+
+fn test_t_uniform_random_custom_mod<Scalar: UnsignedTorus>(
+    ciphertext_modulus: CiphertextModulus<Scalar>,
+) {
+    const SAMPLES: usize = 100_000;
+    const LOG2_BOUND: u32 = 3;
+    let bound = 1i128 << LOG2_BOUND;
+    // Only used for types up to 64 bits so that the modulus fits in an i128
+    let modulus = if ciphertext_modulus.is_native_modulus() {
+        1i128 << Scalar::BITS
+    } else {
+        ciphertext_modulus.get_custom_modulus() as i128
+    };
+
+    let mut rng = new_random_generator();
+    let mut counts = vec![0usize; 2 * bound as usize + 1];
+    for _ in 0..SAMPLES {
+        let sample: Scalar = rng.random_t_uniform_custom_mod(LOG2_BOUND, ciphertext_modulus);
+        let sample: u128 = sample.cast_into();
+        let value = if sample as i128 <= bound {
+            sample as i128
+        } else {
+            sample as i128 - modulus
+        };
+        assert!(
+            value.abs() <= bound,
+            "{value} is out of the t-uniform support"
+        );
+        counts[(value + bound) as usize] += 1;
+    }
+
+    // The bounds are half as likely as the other values
+    let expected = SAMPLES as f64 / (2 * bound) as f64;
+    for (index, count) in counts.iter().enumerate() {
+        let expected = if index == 0 || index == counts.len() - 1 {
+            expected / 2.
+        } else {
+            expected
+        };
+        assert!((*count as f64 - expected).abs() < 0.1 * expected);
+    }
+}
+
+#[test]
+fn test_t_uniform_random_native_u32() {
+    test_t_uniform_random_custom_mod::<u32>(CiphertextModulus::new_native());
+}
+
+#[test]
+fn test_t_uniform_random_native_u64() {
+    test_t_uniform_random_custom_mod::<u64>(CiphertextModulus::new_native());
+}
+
+#[test]
+fn test_t_uniform_random_custom_mod_u64() {
+    test_t_uniform_random_custom_mod::<u64>(CiphertextModulus::try_new_power_of_2(63).unwrap());
+}
@@ -284,7 +284,8 @@ impl<Scalar: UnsignedInteger> LweMultiBitBootstrapKeyOwned<Scalar> {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(bound(deserialize = "C: IntoContainerOwned"))]
 pub struct FourierLweMultiBitBootstrapKey<C: Container<Element = c64>> {
     fourier: FourierPolynomialList<C>,
     input_lwe_dimension: LweDimension,
@@ -46,6 +46,10 @@ pub use crate::core_crypto::fft_impl::fft64::crypto::ggsw::{
     FourierGgswCiphertext, FourierGgswCiphertextList, FourierGgswLevelMatrix, FourierGgswLevelRow,
 };
 pub use crate::core_crypto::fft_impl::fft64::math::polynomial::FourierPolynomial;
+pub use crate::core_crypto::fft_impl::ntt64::crypto::bootstrap::{
+    NttLweBootstrapKey, NttLweBootstrapKeyOwned,
+};
+pub use crate::core_crypto::fft_impl::ntt64::crypto::ggsw::NttGgswCiphertext;
 pub use cleartext::*;
 pub use ggsw_ciphertext::*;
 pub use ggsw_ciphertext_list::*;
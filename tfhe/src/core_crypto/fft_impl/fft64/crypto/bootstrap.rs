@@ -1,8 +1,11 @@
 use super::super::math::fft::{Fft, FftView, FourierPolynomialList};
 use super::ggsw::{cmux, *};
+use crate::core_crypto::algorithms::decompress_seeded_ggsw_ciphertext_with_existing_generator;
 use crate::core_crypto::algorithms::extract_lwe_sample_from_glwe_ciphertext;
 use crate::core_crypto::algorithms::polynomial_algorithms::*;
+use crate::core_crypto::algorithms::slice_algorithms::slice_wrapping_sub_scalar_mul_assign;
 use crate::core_crypto::commons::math::decomposition::SignedDecomposer;
+use crate::core_crypto::commons::math::random::{ActivatedRandomGenerator, RandomGenerator};
 use crate::core_crypto::commons::math::torus::UnsignedTorus;
 use crate::core_crypto::commons::numeric::CastInto;
 use crate::core_crypto::commons::parameters::{
@@ -213,83 +216,256 @@ pub fn bootstrap_scratch<Scalar>(
     )
 }
 
+/// Number of coefficients of the keyswitched ciphertext computed at a time by
+/// [`FourierLweBootstrapKeyView::keyswitch_blind_rotate_assign`].
+const KEYSWITCH_CHUNK_SIZE: usize = 32;
+
+/// Return the required memory for [`FourierLweBootstrapKeyView::keyswitch_blind_rotate_assign`].
+pub fn keyswitch_blind_rotate_scratch<Scalar>(
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+    fft: FftView<'_>,
+) -> Result<StackReq, SizeOverflow> {
+    StackReq::try_new_aligned::<Scalar>(KEYSWITCH_CHUNK_SIZE, CACHELINE_ALIGN)?.try_and(
+        blind_rotate_scratch::<Scalar>(glwe_size, polynomial_size, fft)?,
+    )
+}
+
+/// Return the required memory for [`FourierLweBootstrapKeyView::keyswitch_bootstrap`] and
+/// [`FourierLweBootstrapKeyView::keyswitch_bootstrap_assign`].
+pub fn keyswitch_bootstrap_scratch<Scalar>(
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+    fft: FftView<'_>,
+) -> Result<StackReq, SizeOverflow> {
+    keyswitch_blind_rotate_scratch::<Scalar>(glwe_size, polynomial_size, fft)?.try_and(
+        StackReq::try_new_aligned::<Scalar>(glwe_size.0 * polynomial_size.0, CACHELINE_ALIGN)?,
+    )
+}
+
+/// Rotate the look-up table in `lut` by the modulus switched body of the input ciphertext, this is
+/// the first step of a blind rotation.
+// CastInto required for PBS modulus switch which returns a usize
+fn blind_rotation_init<Scalar: UnsignedTorus + CastInto<usize>>(
+    mut lut: GlweCiphertextMutView<'_, Scalar>,
+    lwe_body: Scalar,
+    lut_count_log: LutCountLog,
+) {
+    let monomial_degree = pbs_modulus_switch(
+        lwe_body,
+        lut.polynomial_size(),
+        ModulusSwitchOffset(0),
+        lut_count_log,
+    );
+
+    lut.as_mut_polynomial_list()
+        .iter_mut()
+        .for_each(|mut poly| {
+            polynomial_wrapping_monic_monomial_div_assign(
+                &mut poly,
+                MonomialDegree(monomial_degree),
+            )
+        });
+}
+
+/// Rotate `ct0` by the modulus switched `lwe_mask_element` if the key coefficient encrypted in
+/// `bootstrap_key_ggsw` is 1, this is one step of a blind rotation.
+// CastInto required for PBS modulus switch which returns a usize
+fn blind_rotation_step<Scalar: UnsignedTorus + CastInto<usize>>(
+    mut ct0: GlweCiphertextMutView<'_, Scalar>,
+    lwe_mask_element: Scalar,
+    bootstrap_key_ggsw: FourierGgswCiphertextView<'_>,
+    lut_count_log: LutCountLog,
+    fft: FftView<'_>,
+    stack: PodStack<'_>,
+) {
+    if lwe_mask_element != Scalar::ZERO {
+        let lut_poly_size = ct0.polynomial_size();
+        let ciphertext_modulus = ct0.ciphertext_modulus();
+
+        // We copy ct_0 to ct_1
+        let (mut ct1, stack) = stack.collect_aligned(CACHELINE_ALIGN, ct0.as_ref().iter().copied());
+        let mut ct1 =
+            GlweCiphertextMutView::from_container(&mut *ct1, lut_poly_size, ciphertext_modulus);
+
+        // We rotate ct_1 by performing ct_1 <- ct_1 * X^{a_hat}
+        for mut poly in ct1.as_mut_polynomial_list().iter_mut() {
+            polynomial_wrapping_monic_monomial_mul_assign(
+                &mut poly,
+                MonomialDegree(pbs_modulus_switch(
+                    lwe_mask_element,
+                    lut_poly_size,
+                    ModulusSwitchOffset(0),
+                    lut_count_log,
+                )),
+            );
+        }
+
+        // ct1 is re-created each loop it can be moved, ct0 is already a view, but
+        // as_mut_view is required to keep borrow rules consistent
+        cmux(ct0.as_mut_view(), ct1, bootstrap_key_ggsw, fft, stack);
+    }
+}
+
+/// Round the output of a blind rotation to the ciphertext modulus, this is the last step of a
+/// blind rotation.
+fn blind_rotation_round<Scalar: UnsignedTorus>(mut ct0: GlweCiphertextMutView<'_, Scalar>) {
+    let ciphertext_modulus = ct0.ciphertext_modulus();
+    if !ciphertext_modulus.is_native_modulus() {
+        // When we convert back from the fourier domain, integer values will contain up to 53
+        // MSBs with information. In our representation of power of 2 moduli < native modulus we
+        // fill the MSBs and leave the LSBs empty, this usage of the signed decomposer allows to
+        // round while keeping the data in the MSBs
+        let signed_decomposer = SignedDecomposer::new(
+            DecompositionBaseLog(ciphertext_modulus.get_custom_modulus().ilog2() as usize),
+            DecompositionLevelCount(1),
+        );
+        ct0.as_mut()
+            .iter_mut()
+            .for_each(|x| *x = signed_decomposer.closest_representable(*x));
+    }
+}
+
 impl<'a> FourierLweBootstrapKeyView<'a> {
     // CastInto required for PBS modulus switch which returns a usize
     pub fn blind_rotate_assign<Scalar: UnsignedTorus + CastInto<usize>>(
+        self,
+        lut: GlweCiphertextMutView<'_, Scalar>,
+        lwe: &[Scalar],
+        fft: FftView<'_>,
+        stack: PodStack<'_>,
+    ) {
+        self.blind_rotate_assign_with_lut_count(lut, lwe, LutCountLog(0), fft, stack)
+    }
+
+    /// Blind rotation where the modulus switch rounds the rotations to multiples of
+    /// `2^lut_count_log`, this allows to pack `2^lut_count_log` look-up tables in `lut`.
+    // CastInto required for PBS modulus switch which returns a usize
+    pub fn blind_rotate_assign_with_lut_count<Scalar: UnsignedTorus + CastInto<usize>>(
         self,
         mut lut: GlweCiphertextMutView<'_, Scalar>,
         lwe: &[Scalar],
+        lut_count_log: LutCountLog,
         fft: FftView<'_>,
         mut stack: PodStack<'_>,
     ) {
         let (lwe_body, lwe_mask) = lwe.split_last().unwrap();
 
-        let lut_poly_size = lut.polynomial_size();
-        let ciphertext_modulus = lut.ciphertext_modulus();
-        let monomial_degree = pbs_modulus_switch(
-            *lwe_body,
-            lut_poly_size,
-            ModulusSwitchOffset(0),
-            LutCountLog(0),
-        );
-
-        lut.as_mut_polynomial_list()
-            .iter_mut()
-            .for_each(|mut poly| {
-                polynomial_wrapping_monic_monomial_div_assign(
-                    &mut poly,
-                    MonomialDegree(monomial_degree),
-                )
-            });
+        blind_rotation_init(lut.as_mut_view(), *lwe_body, lut_count_log);
 
         // We initialize the ct_0 used for the successive cmuxes
         let mut ct0 = lut;
 
         for (lwe_mask_element, bootstrap_key_ggsw) in izip!(lwe_mask.iter(), self.into_ggsw_iter())
         {
-            if *lwe_mask_element != Scalar::ZERO {
-                let stack = stack.rb_mut();
-                // We copy ct_0 to ct_1
-                let (mut ct1, stack) =
-                    stack.collect_aligned(CACHELINE_ALIGN, ct0.as_ref().iter().copied());
-                let mut ct1 = GlweCiphertextMutView::from_container(
-                    &mut *ct1,
-                    lut_poly_size,
-                    ciphertext_modulus,
-                );
+            blind_rotation_step(
+                ct0.as_mut_view(),
+                *lwe_mask_element,
+                bootstrap_key_ggsw,
+                lut_count_log,
+                fft,
+                stack.rb_mut(),
+            );
+        }
 
-                // We rotate ct_1 by performing ct_1 <- ct_1 * X^{a_hat}
-                for mut poly in ct1.as_mut_polynomial_list().iter_mut() {
-                    polynomial_wrapping_monic_monomial_mul_assign(
-                        &mut poly,
-                        MonomialDegree(pbs_modulus_switch(
-                            *lwe_mask_element,
-                            lut_poly_size,
-                            ModulusSwitchOffset(0),
-                            LutCountLog(0),
-                        )),
-                    );
-                }
+        blind_rotation_round(ct0);
+    }
+
+    /// Blind rotation of `lut` by the keyswitch of `lwe` with `lwe_keyswitch_key`, the result is
+    /// the one of [`crate::core_crypto::algorithms::keyswitch_lwe_ciphertext`] followed by
+    /// [`Self::blind_rotate_assign`].
+    ///
+    /// The keyswitched ciphertext is never materialized: its body is computed first to rotate
+    /// `lut`, then its mask is computed a few coefficients at a time, each chunk being modulus
+    /// switched and consumed by the cmuxes of the blind rotation while it is still in cache.
+    // CastInto required for PBS modulus switch which returns a usize
+    pub fn keyswitch_blind_rotate_assign<Scalar: UnsignedTorus + CastInto<usize>>(
+        self,
+        mut lut: GlweCiphertextMutView<'_, Scalar>,
+        lwe: &[Scalar],
+        lwe_keyswitch_key: LweKeyswitchKey<&[Scalar]>,
+        fft: FftView<'_>,
+        mut stack: PodStack<'_>,
+    ) {
+        debug_assert_eq!(
+            lwe_keyswitch_key.input_key_lwe_dimension().to_lwe_size().0,
+            lwe.len()
+        );
+        debug_assert_eq!(
+            lwe_keyswitch_key.output_key_lwe_dimension(),
+            self.input_lwe_dimension()
+        );
+
+        let (lwe_body, lwe_mask) = lwe.split_last().unwrap();
+        let output_lwe_dimension = lwe_keyswitch_key.output_key_lwe_dimension().0;
+
+        let decomposer = SignedDecomposer::new(
+            lwe_keyswitch_key.decomposition_base_log(),
+            lwe_keyswitch_key.decomposition_level_count(),
+        );
 
-                // ct1 is re-created each loop it can be moved, ct0 is already a view, but
-                // as_mut_view is required to keep borrow rules consistent
-                cmux(ct0.as_mut_view(), ct1, bootstrap_key_ggsw, fft, stack);
+        // The body of the keyswitched ciphertext is needed first, to rotate the look-up table
+        let mut keyswitched_body = *lwe_body;
+        for (keyswitch_key_block, &input_mask_element) in
+            izip!(lwe_keyswitch_key.iter(), lwe_mask.iter())
+        {
+            let decomposition_iter = decomposer.decompose(input_mask_element);
+            for (level_key_ciphertext, decomposed) in
+                keyswitch_key_block.iter().zip(decomposition_iter)
+            {
+                let key_body = *level_key_ciphertext.get_body().data;
+                keyswitched_body =
+                    keyswitched_body.wrapping_sub(key_body.wrapping_mul(decomposed.value()));
             }
         }
 
-        if !ciphertext_modulus.is_native_modulus() {
-            // When we convert back from the fourier domain, integer values will contain up to 53
-            // MSBs with information. In our representation of power of 2 moduli < native modulus we
-            // fill the MSBs and leave the LSBs empty, this usage of the signed decomposer allows to
-            // round while keeping the data in the MSBs
-            let signed_decomposer = SignedDecomposer::new(
-                DecompositionBaseLog(ciphertext_modulus.get_custom_modulus().ilog2() as usize),
-                DecompositionLevelCount(1),
+        blind_rotation_init(lut.as_mut_view(), keyswitched_body, LutCountLog(0));
+
+        // We initialize the ct_0 used for the successive cmuxes
+        let mut ct0 = lut;
+        let mut bootstrap_key_ggsw_iter = self.into_ggsw_iter();
+
+        for chunk_start in (0..output_lwe_dimension).step_by(KEYSWITCH_CHUNK_SIZE) {
+            let chunk_end = (chunk_start + KEYSWITCH_CHUNK_SIZE).min(output_lwe_dimension);
+
+            let (mut keyswitched_mask_chunk, mut stack) = stack.rb_mut().collect_aligned(
+                CACHELINE_ALIGN,
+                core::iter::repeat(Scalar::ZERO).take(chunk_end - chunk_start),
             );
-            ct0.as_mut()
-                .iter_mut()
-                .for_each(|x| *x = signed_decomposer.closest_representable(*x));
+
+            // Compute the mask coefficients of the chunk, the decomposition of the input mask is
+            // cheap compared to the products and is done again for each chunk
+            for (keyswitch_key_block, &input_mask_element) in
+                izip!(lwe_keyswitch_key.iter(), lwe_mask.iter())
+            {
+                let decomposition_iter = decomposer.decompose(input_mask_element);
+                for (level_key_ciphertext, decomposed) in
+                    keyswitch_key_block.iter().zip(decomposition_iter)
+                {
+                    slice_wrapping_sub_scalar_mul_assign(
+                        &mut *keyswitched_mask_chunk,
+                        &level_key_ciphertext.as_ref()[chunk_start..chunk_end],
+                        decomposed.value(),
+                    );
+                }
+            }
+
+            for (&lwe_mask_element, bootstrap_key_ggsw) in keyswitched_mask_chunk
+                .iter()
+                .zip(bootstrap_key_ggsw_iter.by_ref())
+            {
+                blind_rotation_step(
+                    ct0.as_mut_view(),
+                    lwe_mask_element,
+                    bootstrap_key_ggsw,
+                    LutCountLog(0),
+                    fft,
+                    stack.rb_mut(),
+                );
+            }
         }
+
+        blind_rotation_round(ct0);
     }
 
     pub fn bootstrap<Scalar>(
@@ -324,6 +500,283 @@ impl<'a> FourierLweBootstrapKeyView<'a> {
             MonomialDegree(0),
         );
     }
+
+    /// Keyswitch `lwe_in` with `lwe_keyswitch_key` and bootstrap the result into `lwe_out`, see
+    /// [`Self::keyswitch_blind_rotate_assign`].
+    pub fn keyswitch_bootstrap<Scalar>(
+        self,
+        mut lwe_out: LweCiphertextMutView<'_, Scalar>,
+        lwe_in: LweCiphertextView<'_, Scalar>,
+        lwe_keyswitch_key: LweKeyswitchKey<&[Scalar]>,
+        accumulator: GlweCiphertextView<'_, Scalar>,
+        fft: FftView<'_>,
+        stack: PodStack<'_>,
+    ) where
+        // CastInto required for PBS modulus switch which returns a usize
+        Scalar: UnsignedTorus + CastInto<usize>,
+    {
+        debug_assert_eq!(lwe_out.ciphertext_modulus(), lwe_in.ciphertext_modulus());
+        debug_assert_eq!(
+            lwe_in.ciphertext_modulus(),
+            accumulator.ciphertext_modulus()
+        );
+
+        let (mut local_accumulator_data, stack) =
+            stack.collect_aligned(CACHELINE_ALIGN, accumulator.as_ref().iter().copied());
+        let mut local_accumulator = GlweCiphertextMutView::from_container(
+            &mut *local_accumulator_data,
+            accumulator.polynomial_size(),
+            accumulator.ciphertext_modulus(),
+        );
+        self.keyswitch_blind_rotate_assign(
+            local_accumulator.as_mut_view(),
+            lwe_in.as_ref(),
+            lwe_keyswitch_key,
+            fft,
+            stack,
+        );
+
+        extract_lwe_sample_from_glwe_ciphertext(
+            &local_accumulator,
+            &mut lwe_out,
+            MonomialDegree(0),
+        );
+    }
+
+    /// In-place version of [`Self::keyswitch_bootstrap`], `lwe` is only overwritten once the
+    /// blind rotation is over.
+    pub fn keyswitch_bootstrap_assign<Scalar>(
+        self,
+        mut lwe: LweCiphertextMutView<'_, Scalar>,
+        lwe_keyswitch_key: LweKeyswitchKey<&[Scalar]>,
+        accumulator: GlweCiphertextView<'_, Scalar>,
+        fft: FftView<'_>,
+        stack: PodStack<'_>,
+    ) where
+        // CastInto required for PBS modulus switch which returns a usize
+        Scalar: UnsignedTorus + CastInto<usize>,
+    {
+        debug_assert_eq!(lwe.ciphertext_modulus(), accumulator.ciphertext_modulus());
+
+        let (mut local_accumulator_data, stack) =
+            stack.collect_aligned(CACHELINE_ALIGN, accumulator.as_ref().iter().copied());
+        let mut local_accumulator = GlweCiphertextMutView::from_container(
+            &mut *local_accumulator_data,
+            accumulator.polynomial_size(),
+            accumulator.ciphertext_modulus(),
+        );
+        self.keyswitch_blind_rotate_assign(
+            local_accumulator.as_mut_view(),
+            lwe.as_ref(),
+            lwe_keyswitch_key,
+            fft,
+            stack,
+        );
+
+        extract_lwe_sample_from_glwe_ciphertext(&local_accumulator, &mut lwe, MonomialDegree(0));
+    }
+
+    /// Bootstrap evaluating up to `2^lut_count_log` look-up tables packed in `accumulator`,
+    /// the i-th output ciphertext encrypts the result of the i-th look-up table.
+    pub fn bootstrap_many_lut<Scalar>(
+        self,
+        mut lwe_out_list: LweCiphertextListMutView<'_, Scalar>,
+        lwe_in: LweCiphertextView<'_, Scalar>,
+        accumulator: GlweCiphertextView<'_, Scalar>,
+        lut_count_log: LutCountLog,
+        fft: FftView<'_>,
+        stack: PodStack<'_>,
+    ) where
+        // CastInto required for PBS modulus switch which returns a usize
+        Scalar: UnsignedTorus + CastInto<usize>,
+    {
+        debug_assert!(lwe_out_list.lwe_ciphertext_count().0 <= 1 << lut_count_log.0);
+
+        let (mut local_accumulator_data, stack) =
+            stack.collect_aligned(CACHELINE_ALIGN, accumulator.as_ref().iter().copied());
+        let mut local_accumulator = GlweCiphertextMutView::from_container(
+            &mut *local_accumulator_data,
+            accumulator.polynomial_size(),
+            accumulator.ciphertext_modulus(),
+        );
+        self.blind_rotate_assign_with_lut_count(
+            local_accumulator.as_mut_view(),
+            lwe_in.as_ref(),
+            lut_count_log,
+            fft,
+            stack,
+        );
+
+        for (lut_index, mut lwe_out) in lwe_out_list.iter_mut().enumerate() {
+            extract_lwe_sample_from_glwe_ciphertext(
+                &local_accumulator,
+                &mut lwe_out,
+                MonomialDegree(lut_index),
+            );
+        }
+    }
+}
+
+/// Return the required memory for [`bootstrap_with_seeded_key`].
+pub fn bootstrap_with_seeded_key_scratch<Scalar>(
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+    decomposition_level_count: DecompositionLevelCount,
+    fft: FftView<'_>,
+) -> Result<StackReq, SizeOverflow> {
+    let ggsw_polynomial_count = decomposition_level_count.0 * glwe_size.0 * glwe_size.0;
+    StackReq::try_all_of([
+        StackReq::try_new_aligned::<Scalar>(glwe_size.0 * polynomial_size.0, CACHELINE_ALIGN)?,
+        StackReq::try_new_aligned::<Scalar>(
+            ggsw_polynomial_count * polynomial_size.0,
+            CACHELINE_ALIGN,
+        )?,
+        StackReq::try_new_aligned::<c64>(
+            ggsw_polynomial_count * polynomial_size.to_fourier_polynomial_size().0,
+            CACHELINE_ALIGN,
+        )?,
+        StackReq::try_any_of([
+            fft.forward_scratch()?,
+            blind_rotate_scratch::<Scalar>(glwe_size, polynomial_size, fft)?,
+        ])?,
+    ])
+}
+
+/// Bootstrap with a [`SeededLweBootstrapKey`], the GGSW ciphertexts of the key are decompressed
+/// and converted to the Fourier domain one at a time, right before being used by the blind
+/// rotation.
+///
+/// Only the seeded key is kept in memory, at the cost of decompressing and converting the whole
+/// key on each bootstrap.
+pub fn bootstrap_with_seeded_key<Scalar, KeyCont>(
+    seeded_bsk: &SeededLweBootstrapKey<KeyCont>,
+    mut lwe_out: LweCiphertextMutView<'_, Scalar>,
+    lwe_in: LweCiphertextView<'_, Scalar>,
+    accumulator: GlweCiphertextView<'_, Scalar>,
+    fft: FftView<'_>,
+    stack: PodStack<'_>,
+) where
+    // CastInto required for PBS modulus switch which returns a usize
+    Scalar: UnsignedTorus + CastInto<usize>,
+    KeyCont: Container<Element = Scalar>,
+{
+    debug_assert_eq!(lwe_out.ciphertext_modulus(), lwe_in.ciphertext_modulus());
+    debug_assert_eq!(
+        lwe_in.ciphertext_modulus(),
+        accumulator.ciphertext_modulus()
+    );
+
+    let polynomial_size = seeded_bsk.polynomial_size();
+    let glwe_size = seeded_bsk.glwe_size();
+    let decomposition_base_log = seeded_bsk.decomposition_base_log();
+    let decomposition_level_count = seeded_bsk.decomposition_level_count();
+    let ciphertext_modulus = accumulator.ciphertext_modulus();
+    let ggsw_polynomial_count = decomposition_level_count.0 * glwe_size.0 * glwe_size.0;
+
+    let (mut local_accumulator_data, stack) =
+        stack.collect_aligned(CACHELINE_ALIGN, accumulator.as_ref().iter().copied());
+    let (standard_ggsw_data, stack) = stack
+        .make_aligned_raw::<Scalar>(ggsw_polynomial_count * polynomial_size.0, CACHELINE_ALIGN);
+    let (fourier_ggsw_data, mut stack) = stack.make_aligned_raw::<c64>(
+        ggsw_polynomial_count * polynomial_size.to_fourier_polynomial_size().0,
+        CACHELINE_ALIGN,
+    );
+
+    let mut standard_ggsw = GgswCiphertext::from_container(
+        &mut *standard_ggsw_data,
+        glwe_size,
+        polynomial_size,
+        decomposition_base_log,
+        seeded_bsk.ciphertext_modulus(),
+    );
+    let mut fourier_ggsw = FourierGgswCiphertext::from_container(
+        &mut *fourier_ggsw_data,
+        glwe_size,
+        polynomial_size,
+        decomposition_base_log,
+        decomposition_level_count,
+    );
+
+    let (lwe_body, lwe_mask) = lwe_in.as_ref().split_last().unwrap();
+
+    let mut ct0 = GlweCiphertextMutView::from_container(
+        &mut *local_accumulator_data,
+        polynomial_size,
+        ciphertext_modulus,
+    );
+    let monomial_degree = pbs_modulus_switch(
+        *lwe_body,
+        polynomial_size,
+        ModulusSwitchOffset(0),
+        LutCountLog(0),
+    );
+    ct0.as_mut_polynomial_list()
+        .iter_mut()
+        .for_each(|mut poly| {
+            polynomial_wrapping_monic_monomial_div_assign(
+                &mut poly,
+                MonomialDegree(monomial_degree),
+            )
+        });
+
+    // The GGSW ciphertexts are decompressed in order with a single generator, so that every
+    // GGSW consumes its share of the random mask even if its cmux is skipped
+    let mut generator =
+        RandomGenerator::<ActivatedRandomGenerator>::new(seeded_bsk.compression_seed().seed);
+
+    for (lwe_mask_element, seeded_ggsw) in izip!(lwe_mask.iter(), seeded_bsk.iter()) {
+        decompress_seeded_ggsw_ciphertext_with_existing_generator(
+            &mut standard_ggsw,
+            &seeded_ggsw,
+            &mut generator,
+        );
+
+        if *lwe_mask_element != Scalar::ZERO {
+            fourier_ggsw.as_mut_view().fill_with_forward_fourier(
+                standard_ggsw.as_view(),
+                fft,
+                stack.rb_mut(),
+            );
+
+            let stack = stack.rb_mut();
+            // We copy ct_0 to ct_1
+            let (mut ct1, stack) =
+                stack.collect_aligned(CACHELINE_ALIGN, ct0.as_ref().iter().copied());
+            let mut ct1 = GlweCiphertextMutView::from_container(
+                &mut *ct1,
+                polynomial_size,
+                ciphertext_modulus,
+            );
+
+            // We rotate ct_1 by performing ct_1 <- ct_1 * X^{a_hat}
+            for mut poly in ct1.as_mut_polynomial_list().iter_mut() {
+                polynomial_wrapping_monic_monomial_mul_assign(
+                    &mut poly,
+                    MonomialDegree(pbs_modulus_switch(
+                        *lwe_mask_element,
+                        polynomial_size,
+                        ModulusSwitchOffset(0),
+                        LutCountLog(0),
+                    )),
+                );
+            }
+
+            cmux(ct0.as_mut_view(), ct1, fourier_ggsw.as_view(), fft, stack);
+        }
+    }
+
+    if !ciphertext_modulus.is_native_modulus() {
+        // See FourierLweBootstrapKeyView::blind_rotate_assign_with_lut_count
+        let signed_decomposer = SignedDecomposer::new(
+            DecompositionBaseLog(ciphertext_modulus.get_custom_modulus().ilog2() as usize),
+            DecompositionLevelCount(1),
+        );
+        ct0.as_mut()
+            .iter_mut()
+            .for_each(|x| *x = signed_decomposer.closest_representable(*x));
+    }
+
+    extract_lwe_sample_from_glwe_ciphertext(&ct0, &mut lwe_out, MonomialDegree(0));
 }
 
 impl<Scalar> FourierBootstrapKey<Scalar> for FourierLweBootstrapKeyOwned
@@ -802,8 +802,7 @@ pub fn vertical_packing<Scalar: UnsignedTorus + CastInto<usize>>(
 
     // Get the base 2 logarithm (rounded down) of the number of polynomials in the list i.e. if
     // there is one polynomial, the number will be 0
-    let log_lut_number: usize =
-        Scalar::BITS - 1 - lut.polynomial_count().0.leading_zeros() as usize;
+    let log_lut_number: usize = lut.polynomial_count().0.ilog2() as usize;
 
     let log_number_of_luts_for_cmux_tree = if log_lut_number > ggsw_list.count() {
         // this means that we dont have enough GGSW to perform the CMux tree, we can only do the
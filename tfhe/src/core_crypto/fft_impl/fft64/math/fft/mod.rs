@@ -251,7 +251,8 @@ fn convert_forward_integer<Scalar: UnsignedTorus>(
         } else if Scalar::BITS == 64 {
             x86::convert_forward_integer_u64(out, id(in_re), id(in_im), twisties);
         } else {
-            unreachable!();
+            // There is no vectorized conversion for wider integers, e.g. u128
+            convert_forward_integer_scalar::<Scalar>(out, in_re, in_im, twisties);
         }
     }
 
@@ -316,7 +317,8 @@ fn convert_add_backward_torus<Scalar: UnsignedTorus>(
         } else if Scalar::BITS == 64 {
             x86::convert_add_backward_torus_u64(id_mut(out_re), id_mut(out_im), inp, twisties);
         } else {
-            unreachable!();
+            // There is no vectorized conversion for wider integers, e.g. u128
+            convert_add_backward_torus_scalar::<Scalar>(out_re, out_im, inp, twisties);
         }
     }
 
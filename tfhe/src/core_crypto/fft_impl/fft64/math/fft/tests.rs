@@ -47,7 +47,7 @@ fn test_roundtrip<Scalar: UnsignedTorus>() {
             if Scalar::BITS == 32 {
                 assert!(abs_diff(*expected, *actual) == Scalar::ZERO);
             } else {
-                assert!(abs_diff(*expected, *actual) < (Scalar::ONE << (64 - 50)));
+                assert!(abs_diff(*expected, *actual) < (Scalar::ONE << (Scalar::BITS - 50)));
             }
         }
     }
@@ -156,6 +156,11 @@ fn test_product_u64() {
     test_product::<u64>();
 }
 
+#[test]
+fn test_product_u128() {
+    test_product::<u128>();
+}
+
 #[test]
 fn test_roundtrip_u32() {
     test_roundtrip::<u32>();
@@ -164,6 +169,10 @@ fn test_roundtrip_u32() {
 fn test_roundtrip_u64() {
     test_roundtrip::<u64>();
 }
+#[test]
+fn test_roundtrip_u128() {
+    test_roundtrip::<u128>();
+}
 
 #[test]
 fn f64_to_i64_bit_twiddles() {
@@ -7,3 +7,5 @@ pub mod fft64;
 
 pub mod fft128;
 mod fft128_u128;
+
+pub mod ntt64;
@@ -0,0 +1,352 @@
+use super::super::math::ntt::{Ntt, NTT_RESIDUE_COUNT};
+use super::ggsw::{cmux, NttGgswCiphertext};
+use crate::core_crypto::algorithms::extract_lwe_sample_from_glwe_ciphertext;
+use crate::core_crypto::algorithms::polynomial_algorithms::*;
+use crate::core_crypto::commons::math::torus::UnsignedTorus;
+use crate::core_crypto::commons::numeric::CastInto;
+use crate::core_crypto::commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweSize, LutCountLog, LweDimension,
+    ModulusSwitchOffset, MonomialDegree, PolynomialSize,
+};
+use crate::core_crypto::commons::traits::{
+    Container, ContiguousEntityContainer, ContiguousEntityContainerMut, Split,
+};
+use crate::core_crypto::commons::utils::izip;
+use crate::core_crypto::entities::*;
+use crate::core_crypto::fft_impl::common::{pbs_modulus_switch, FourierBootstrapKey};
+use crate::core_crypto::prelude::ContainerMut;
+use dyn_stack::{PodStack, SizeOverflow, StackReq};
+
+/// An LWE bootstrap key in the NTT domain.
+///
+/// The key is made of one [`NttGgswCiphertext`] per coefficient of the input LWE secret key, and
+/// is [`NTT_RESIDUE_COUNT`] times larger than the key in the standard domain.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct NttLweBootstrapKey<C: Container<Element = u64>> {
+    data: C,
+    polynomial_size: PolynomialSize,
+    input_lwe_dimension: LweDimension,
+    glwe_size: GlweSize,
+    decomposition_base_log: DecompositionBaseLog,
+    decomposition_level_count: DecompositionLevelCount,
+}
+
+pub type NttLweBootstrapKeyView<'a> = NttLweBootstrapKey<&'a [u64]>;
+pub type NttLweBootstrapKeyMutView<'a> = NttLweBootstrapKey<&'a mut [u64]>;
+pub type NttLweBootstrapKeyOwned = NttLweBootstrapKey<Vec<u64>>;
+
+impl<C: Container<Element = u64>> NttLweBootstrapKey<C> {
+    pub fn from_container(
+        data: C,
+        input_lwe_dimension: LweDimension,
+        glwe_size: GlweSize,
+        polynomial_size: PolynomialSize,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+    ) -> Self {
+        assert_eq!(
+            data.container_len(),
+            input_lwe_dimension.0
+                * NTT_RESIDUE_COUNT
+                * polynomial_size.0
+                * decomposition_level_count.0
+                * glwe_size.0
+                * glwe_size.0
+        );
+        Self {
+            data,
+            polynomial_size,
+            input_lwe_dimension,
+            glwe_size,
+            decomposition_base_log,
+            decomposition_level_count,
+        }
+    }
+
+    /// Return an iterator over the GGSW ciphertexts composing the key.
+    pub fn into_ggsw_iter(self) -> impl DoubleEndedIterator<Item = NttGgswCiphertext<C>>
+    where
+        C: Split,
+    {
+        self.data
+            .split_into(self.input_lwe_dimension.0)
+            .map(move |slice| {
+                NttGgswCiphertext::from_container(
+                    slice,
+                    self.polynomial_size,
+                    self.glwe_size,
+                    self.decomposition_base_log,
+                    self.decomposition_level_count,
+                )
+            })
+    }
+
+    pub fn input_lwe_dimension(&self) -> LweDimension {
+        self.input_lwe_dimension
+    }
+
+    pub fn polynomial_size(&self) -> PolynomialSize {
+        self.polynomial_size
+    }
+
+    pub fn glwe_size(&self) -> GlweSize {
+        self.glwe_size
+    }
+
+    pub fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.decomposition_base_log
+    }
+
+    pub fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.decomposition_level_count
+    }
+
+    pub fn output_lwe_dimension(&self) -> LweDimension {
+        LweDimension((self.glwe_size.0 - 1) * self.polynomial_size.0)
+    }
+
+    pub fn data(self) -> C {
+        self.data
+    }
+
+    pub fn as_view(&self) -> NttLweBootstrapKeyView<'_> {
+        NttLweBootstrapKeyView {
+            data: self.data.as_ref(),
+            polynomial_size: self.polynomial_size,
+            input_lwe_dimension: self.input_lwe_dimension,
+            glwe_size: self.glwe_size,
+            decomposition_base_log: self.decomposition_base_log,
+            decomposition_level_count: self.decomposition_level_count,
+        }
+    }
+
+    pub fn as_mut_view(&mut self) -> NttLweBootstrapKeyMutView<'_>
+    where
+        C: AsMut<[u64]>,
+    {
+        NttLweBootstrapKeyMutView {
+            data: self.data.as_mut(),
+            polynomial_size: self.polynomial_size,
+            input_lwe_dimension: self.input_lwe_dimension,
+            glwe_size: self.glwe_size,
+            decomposition_base_log: self.decomposition_base_log,
+            decomposition_level_count: self.decomposition_level_count,
+        }
+    }
+}
+
+impl NttLweBootstrapKeyOwned {
+    pub fn new(
+        input_lwe_dimension: LweDimension,
+        glwe_size: GlweSize,
+        polynomial_size: PolynomialSize,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+    ) -> Self {
+        let container_len = input_lwe_dimension.0
+            * NTT_RESIDUE_COUNT
+            * polynomial_size.0
+            * decomposition_level_count.0
+            * glwe_size.0
+            * glwe_size.0;
+
+        Self::from_container(
+            vec![0u64; container_len],
+            input_lwe_dimension,
+            glwe_size,
+            polynomial_size,
+            decomposition_base_log,
+            decomposition_level_count,
+        )
+    }
+}
+
+impl<C: ContainerMut<Element = u64>> NttLweBootstrapKey<C> {
+    /// Fill a bootstrapping key with the NTT of a bootstrapping key in the standard domain.
+    pub fn fill_with_forward_ntt<Scalar, ContBsk>(
+        &mut self,
+        coef_bsk: &LweBootstrapKey<ContBsk>,
+        ntt: &Ntt,
+    ) where
+        Scalar: UnsignedTorus,
+        ContBsk: Container<Element = Scalar>,
+    {
+        for (ntt_ggsw, standard_ggsw) in izip!(self.as_mut_view().into_ggsw_iter(), coef_bsk.iter())
+        {
+            ntt_ggsw.fill_with_forward_ntt(standard_ggsw, ntt);
+        }
+    }
+}
+
+impl<C: Container<Element = u64>> NttLweBootstrapKey<C> {
+    // CastInto required for PBS modulus switch which returns a usize
+    pub fn blind_rotate_assign<Scalar: UnsignedTorus + CastInto<usize>>(
+        &self,
+        mut lut: GlweCiphertextMutView<'_, Scalar>,
+        lwe: &[Scalar],
+        ntt: &Ntt,
+    ) {
+        let (lwe_body, lwe_mask) = lwe.split_last().unwrap();
+
+        let lut_poly_size = lut.polynomial_size();
+        let monomial_degree = pbs_modulus_switch(
+            *lwe_body,
+            lut_poly_size,
+            ModulusSwitchOffset(0),
+            LutCountLog(0),
+        );
+
+        lut.as_mut_polynomial_list()
+            .iter_mut()
+            .for_each(|mut poly| {
+                polynomial_wrapping_monic_monomial_div_assign(
+                    &mut poly,
+                    MonomialDegree(monomial_degree),
+                )
+            });
+
+        // We initialize the ct_0 used for the successive cmuxes
+        let mut ct0 = lut;
+
+        for (lwe_mask_element, bootstrap_key_ggsw) in
+            izip!(lwe_mask.iter(), self.as_view().into_ggsw_iter())
+        {
+            if *lwe_mask_element != Scalar::ZERO {
+                // We copy ct_0 to ct_1
+                let mut ct1 = GlweCiphertext::from_container(
+                    ct0.as_ref().to_vec(),
+                    ct0.polynomial_size(),
+                    ct0.ciphertext_modulus(),
+                );
+
+                // We rotate ct_1 by performing ct_1 <- ct_1 * X^{a_hat}
+                for mut poly in ct1.as_mut_polynomial_list().iter_mut() {
+                    polynomial_wrapping_monic_monomial_mul_assign(
+                        &mut poly,
+                        MonomialDegree(pbs_modulus_switch(
+                            *lwe_mask_element,
+                            lut_poly_size,
+                            ModulusSwitchOffset(0),
+                            LutCountLog(0),
+                        )),
+                    );
+                }
+
+                cmux(
+                    ct0.as_mut_view(),
+                    ct1.as_mut_view(),
+                    bootstrap_key_ggsw,
+                    ntt,
+                );
+            }
+        }
+
+        // The products are exact: with a power of 2 modulus smaller than the native one, the
+        // values stay in the MSBs and no rounding is needed, unlike for the Fourier backends
+    }
+
+    pub fn bootstrap<Scalar>(
+        &self,
+        mut lwe_out: LweCiphertextMutView<'_, Scalar>,
+        lwe_in: LweCiphertextView<'_, Scalar>,
+        accumulator: GlweCiphertextView<'_, Scalar>,
+        ntt: &Ntt,
+    ) where
+        // CastInto required for PBS modulus switch which returns a usize
+        Scalar: UnsignedTorus + CastInto<usize>,
+    {
+        debug_assert_eq!(lwe_out.ciphertext_modulus(), lwe_in.ciphertext_modulus());
+        debug_assert_eq!(
+            lwe_in.ciphertext_modulus(),
+            accumulator.ciphertext_modulus()
+        );
+
+        let mut local_accumulator = GlweCiphertext::from_container(
+            accumulator.as_ref().to_vec(),
+            accumulator.polynomial_size(),
+            accumulator.ciphertext_modulus(),
+        );
+        self.blind_rotate_assign(local_accumulator.as_mut_view(), lwe_in.as_ref(), ntt);
+
+        extract_lwe_sample_from_glwe_ciphertext(
+            &local_accumulator,
+            &mut lwe_out,
+            MonomialDegree(0),
+        );
+    }
+}
+
+impl<Scalar> FourierBootstrapKey<Scalar> for NttLweBootstrapKeyOwned
+where
+    Scalar: UnsignedTorus + CastInto<usize>,
+{
+    type Fft = Ntt;
+
+    fn new_fft(polynomial_size: PolynomialSize) -> Self::Fft {
+        Ntt::new(polynomial_size)
+    }
+
+    fn new(
+        input_lwe_dimension: LweDimension,
+        polynomial_size: PolynomialSize,
+        glwe_size: GlweSize,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+    ) -> Self {
+        Self::new(
+            input_lwe_dimension,
+            glwe_size,
+            polynomial_size,
+            decomposition_base_log,
+            decomposition_level_count,
+        )
+    }
+
+    fn fill_with_forward_fourier<ContBsk>(
+        &mut self,
+        coef_bsk: &LweBootstrapKey<ContBsk>,
+        fft: &Self::Fft,
+        stack: PodStack<'_>,
+    ) where
+        ContBsk: Container<Element = Scalar>,
+    {
+        let _ = stack;
+        self.fill_with_forward_ntt(coef_bsk, fft);
+    }
+
+    fn bootstrap_scratch(
+        glwe_size: GlweSize,
+        polynomial_size: PolynomialSize,
+        fft: &Self::Fft,
+    ) -> Result<StackReq, SizeOverflow> {
+        // The NTT backend allocates its own buffers
+        let _ = (glwe_size, polynomial_size, fft);
+        Ok(StackReq::empty())
+    }
+
+    fn bootstrap<ContLweOut, ContLweIn, ContAcc>(
+        &self,
+        lwe_out: &mut LweCiphertext<ContLweOut>,
+        lwe_in: &LweCiphertext<ContLweIn>,
+        accumulator: &GlweCiphertext<ContAcc>,
+        fft: &Self::Fft,
+        stack: PodStack<'_>,
+    ) where
+        ContLweOut: ContainerMut<Element = Scalar>,
+        ContLweIn: Container<Element = Scalar>,
+        ContAcc: Container<Element = Scalar>,
+    {
+        let _ = stack;
+        self.bootstrap(
+            lwe_out.as_mut_view(),
+            lwe_in.as_view(),
+            accumulator.as_view(),
+            fft,
+        )
+    }
+
+    fn fill_with_forward_fourier_scratch(fft: &Self::Fft) -> Result<StackReq, SizeOverflow> {
+        let _ = fft;
+        Ok(StackReq::empty())
+    }
+}
@@ -0,0 +1,187 @@
+use super::super::math::ntt::{Ntt, NTT_RESIDUE_COUNT};
+use crate::core_crypto::commons::math::decomposition::SignedDecomposer;
+use crate::core_crypto::commons::math::torus::UnsignedTorus;
+use crate::core_crypto::commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweSize, PolynomialSize,
+};
+use crate::core_crypto::commons::traits::{
+    Container, ContiguousEntityContainer, ContiguousEntityContainerMut,
+};
+use crate::core_crypto::commons::utils::izip;
+use crate::core_crypto::entities::*;
+
+/// A GGSW ciphertext in the NTT domain.
+///
+/// The polynomials are stored in the same order as in a [`GgswCiphertext`], each one as
+/// [`NTT_RESIDUE_COUNT`] transforms of size $N$.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct NttGgswCiphertext<C: Container<Element = u64>> {
+    data: C,
+    polynomial_size: PolynomialSize,
+    glwe_size: GlweSize,
+    decomposition_base_log: DecompositionBaseLog,
+    decomposition_level_count: DecompositionLevelCount,
+}
+
+pub type NttGgswCiphertextView<'a> = NttGgswCiphertext<&'a [u64]>;
+pub type NttGgswCiphertextMutView<'a> = NttGgswCiphertext<&'a mut [u64]>;
+
+impl<C: Container<Element = u64>> NttGgswCiphertext<C> {
+    pub fn from_container(
+        data: C,
+        polynomial_size: PolynomialSize,
+        glwe_size: GlweSize,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+    ) -> Self {
+        assert_eq!(
+            data.container_len(),
+            NTT_RESIDUE_COUNT
+                * polynomial_size.0
+                * glwe_size.0
+                * glwe_size.0
+                * decomposition_level_count.0
+        );
+
+        Self {
+            data,
+            polynomial_size,
+            glwe_size,
+            decomposition_base_log,
+            decomposition_level_count,
+        }
+    }
+
+    pub fn polynomial_size(&self) -> PolynomialSize {
+        self.polynomial_size
+    }
+
+    pub fn glwe_size(&self) -> GlweSize {
+        self.glwe_size
+    }
+
+    pub fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.decomposition_base_log
+    }
+
+    pub fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.decomposition_level_count
+    }
+
+    pub fn data(self) -> C {
+        self.data
+    }
+
+    pub fn as_view(&self) -> NttGgswCiphertextView<'_> {
+        NttGgswCiphertextView {
+            data: self.data.as_ref(),
+            polynomial_size: self.polynomial_size,
+            glwe_size: self.glwe_size,
+            decomposition_base_log: self.decomposition_base_log,
+            decomposition_level_count: self.decomposition_level_count,
+        }
+    }
+}
+
+impl<'a> NttGgswCiphertextMutView<'a> {
+    /// Fill a GGSW ciphertext with the NTT of a GGSW ciphertext in the standard domain.
+    pub fn fill_with_forward_ntt<Scalar: UnsignedTorus>(
+        self,
+        coef_ggsw: GgswCiphertextView<'_, Scalar>,
+        ntt: &Ntt,
+    ) {
+        debug_assert_eq!(coef_ggsw.polynomial_size(), self.polynomial_size());
+        let ntt_poly_size = ntt.ntt_polynomial_size();
+
+        for (ntt_poly, coef_poly) in izip!(
+            self.data.chunks_exact_mut(ntt_poly_size),
+            coef_ggsw.as_polynomial_list().iter()
+        ) {
+            ntt.forward_as_integer(ntt_poly, coef_poly.as_ref());
+        }
+    }
+}
+
+/// Perform the external product of `ggsw` and `glwe`, and adds the result to `out`.
+///
+/// The products are computed exactly, the output does not depend on any floating point
+/// approximation.
+pub fn add_external_product_assign<Scalar: UnsignedTorus>(
+    mut out: GlweCiphertextMutView<'_, Scalar>,
+    ggsw: NttGgswCiphertextView<'_>,
+    glwe: GlweCiphertextView<'_, Scalar>,
+    ntt: &Ntt,
+) {
+    // we check that the polynomial sizes match
+    debug_assert_eq!(ggsw.polynomial_size(), glwe.polynomial_size());
+    debug_assert_eq!(ggsw.polynomial_size(), out.polynomial_size());
+    // we check that the glwe sizes match
+    debug_assert_eq!(ggsw.glwe_size(), glwe.glwe_size());
+    debug_assert_eq!(ggsw.glwe_size(), out.glwe_size());
+
+    let poly_size = ggsw.polynomial_size().0;
+    let ntt_poly_size = ntt.ntt_polynomial_size();
+    let glwe_size = ggsw.glwe_size().0;
+    let level_count = ggsw.decomposition_level_count().0;
+
+    let decomposer = SignedDecomposer::<Scalar>::new(
+        ggsw.decomposition_base_log(),
+        ggsw.decomposition_level_count(),
+    );
+
+    let mut output_ntt_buffer = vec![0u64; glwe_size * ntt_poly_size];
+    let mut glwe_decomp_terms = vec![Scalar::ZERO; level_count * poly_size];
+    let mut glwe_decomp_term_ntt = vec![0u64; ntt_poly_size];
+
+    // The GGSW is made of one matrix per level, starting with the first level, each matrix is made
+    // of one row of glwe_size polynomials per polynomial of the glwe
+    let row_size = glwe_size * ntt_poly_size;
+    let level_matrix_size = glwe_size * row_size;
+
+    for (glwe_poly_index, glwe_poly) in glwe.as_polynomial_list().iter().enumerate() {
+        // We decompose the coefficients of the polynomial, the terms of the level l are stored in
+        // the (l-1)-th chunk of glwe_decomp_terms
+        for (coeff_index, &coeff) in glwe_poly.as_ref().iter().enumerate() {
+            for term in decomposer.decompose(coeff) {
+                glwe_decomp_terms[(term.level().0 - 1) * poly_size + coeff_index] = term.value();
+            }
+        }
+
+        for (level_index, glwe_decomp_term) in glwe_decomp_terms.chunks_exact(poly_size).enumerate()
+        {
+            ntt.forward_as_integer(&mut glwe_decomp_term_ntt, glwe_decomp_term);
+
+            let row_start = level_index * level_matrix_size + glwe_poly_index * row_size;
+            let ggsw_row = &ggsw.data[row_start..row_start + row_size];
+
+            // We add the product of the decomposition term with every polynomial of the row
+            for (output_ntt_poly, ggsw_poly) in izip!(
+                output_ntt_buffer.chunks_exact_mut(ntt_poly_size),
+                ggsw_row.chunks_exact(ntt_poly_size)
+            ) {
+                ntt.mul_add_assign(output_ntt_poly, &glwe_decomp_term_ntt, ggsw_poly);
+            }
+        }
+    }
+
+    // Each output coefficient is a sum of glwe_size * level_count products
+    for (mut out_poly, output_ntt_poly) in izip!(
+        out.as_mut_polynomial_list().iter_mut(),
+        output_ntt_buffer.chunks_exact_mut(ntt_poly_size)
+    ) {
+        ntt.add_backward_as_integer(out_poly.as_mut(), output_ntt_poly, glwe_size * level_count);
+    }
+}
+
+/// This cmux mutates both ct1 and ct0. The result is in ct0 after the method was called.
+pub fn cmux<Scalar: UnsignedTorus>(
+    ct0: GlweCiphertextMutView<'_, Scalar>,
+    mut ct1: GlweCiphertextMutView<'_, Scalar>,
+    ggsw: NttGgswCiphertextView<'_>,
+    ntt: &Ntt,
+) {
+    izip!(ct1.as_mut(), ct0.as_ref()).for_each(|(c1, c0)| {
+        *c1 = c1.wrapping_sub(*c0);
+    });
+    add_external_product_assign(ct0, ggsw, ct1.as_view(), ntt);
+}
@@ -0,0 +1,119 @@
+use super::ggsw::{add_external_product_assign, NttGgswCiphertext};
+use crate::core_crypto::algorithms::polynomial_algorithms::polynomial_wrapping_add_mul_assign;
+use crate::core_crypto::commons::test_tools::new_random_generator;
+use crate::core_crypto::fft_impl::common::tests::test_bootstrap_generic;
+use crate::core_crypto::fft_impl::ntt64::crypto::bootstrap::NttLweBootstrapKeyOwned;
+use crate::core_crypto::fft_impl::ntt64::math::ntt::{Ntt, NTT_RESIDUE_COUNT};
+use crate::core_crypto::prelude::*;
+
+#[test]
+fn test_bootstrap_u64() {
+    test_bootstrap_generic::<u64, NttLweBootstrapKeyOwned>(
+        StandardDev(0.000007069849454709433),
+        StandardDev(0.00000000000000029403601535432533),
+    );
+}
+
+#[test]
+fn test_bootstrap_u32() {
+    test_bootstrap_generic::<u32, NttLweBootstrapKeyOwned>(
+        StandardDev(0.000007069849454709433),
+        StandardDev(0.00000000000000029403601535432533),
+    );
+}
+
+fn test_external_product_exact_generic<Scalar: UnsignedTorus>() {
+    let mut generator = new_random_generator();
+
+    let polynomial_size = PolynomialSize(64);
+    let glwe_size = GlweSize(3);
+    let base_log = DecompositionBaseLog(4);
+    let level_count = DecompositionLevelCount(3);
+    let ciphertext_modulus = CiphertextModulus::new_native();
+
+    // The external product only relies on the layout of the GGSW, random data is enough
+    let mut ggsw = GgswCiphertext::new(
+        Scalar::ZERO,
+        glwe_size,
+        polynomial_size,
+        base_log,
+        level_count,
+        ciphertext_modulus,
+    );
+    generator.fill_slice_with_random_uniform(ggsw.as_mut());
+    let mut glwe =
+        GlweCiphertext::new(Scalar::ZERO, glwe_size, polynomial_size, ciphertext_modulus);
+    generator.fill_slice_with_random_uniform(glwe.as_mut());
+    let mut output =
+        GlweCiphertext::new(Scalar::ZERO, glwe_size, polynomial_size, ciphertext_modulus);
+    generator.fill_slice_with_random_uniform(output.as_mut());
+
+    // Schoolbook reference: the output row k is incremented by the product of the decomposition
+    // term of level l of the glwe polynomial j with the polynomial k of the row j of the level l
+    let mut expected = output.clone();
+    let decomposer = SignedDecomposer::<Scalar>::new(base_log, level_count);
+    for (level_index, level_matrix) in ggsw.iter().enumerate() {
+        for (glwe_poly, ggsw_row) in glwe
+            .as_polynomial_list()
+            .iter()
+            .zip(level_matrix.as_glwe_list().iter())
+        {
+            let mut decomp_term = Polynomial::new(Scalar::ZERO, polynomial_size);
+            for (term, &coeff) in decomp_term
+                .as_mut()
+                .iter_mut()
+                .zip(glwe_poly.as_ref().iter())
+            {
+                *term = decomposer
+                    .decompose(coeff)
+                    .find(|term| term.level().0 == level_index + 1)
+                    .unwrap()
+                    .value();
+            }
+
+            for (mut expected_poly, ggsw_poly) in expected
+                .as_mut_polynomial_list()
+                .iter_mut()
+                .zip(ggsw_row.as_polynomial_list().iter())
+            {
+                polynomial_wrapping_add_mul_assign(&mut expected_poly, &decomp_term, &ggsw_poly);
+            }
+        }
+    }
+
+    let ntt = Ntt::new(polynomial_size);
+    let mut ntt_ggsw_data =
+        vec![
+            0u64;
+            NTT_RESIDUE_COUNT * polynomial_size.0 * glwe_size.0 * glwe_size.0 * level_count.0
+        ];
+    NttGgswCiphertext::from_container(
+        ntt_ggsw_data.as_mut_slice(),
+        polynomial_size,
+        glwe_size,
+        base_log,
+        level_count,
+    )
+    .fill_with_forward_ntt(ggsw.as_view(), &ntt);
+    let ntt_ggsw = NttGgswCiphertext::from_container(
+        ntt_ggsw_data.as_slice(),
+        polynomial_size,
+        glwe_size,
+        base_log,
+        level_count,
+    );
+
+    add_external_product_assign(output.as_mut_view(), ntt_ggsw, glwe.as_view(), &ntt);
+
+    assert_eq!(output, expected);
+}
+
+#[test]
+fn test_external_product_exact_u64() {
+    test_external_product_exact_generic::<u64>();
+}
+
+#[test]
+fn test_external_product_exact_u32() {
+    test_external_product_exact_generic::<u32>();
+}
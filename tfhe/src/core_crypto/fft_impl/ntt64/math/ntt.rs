@@ -0,0 +1,310 @@
+use crate::core_crypto::commons::numeric::{CastFrom, CastInto, UnsignedInteger};
+use crate::core_crypto::commons::parameters::PolynomialSize;
+use crate::core_crypto::commons::utils::izip;
+
+/// Binary logarithm of the order of the roots of unity in [`NTT_ROOTS`].
+const NTT_LOG2_ROOT_ORDER: usize = 20;
+/// Primes of the form $k \cdot 2^{20} + 1$ below $2^{62}$, their product is larger than $2^{185}$.
+const NTT_PRIMES: [u64; 3] = [
+    0x3fff_ffff_feb0_0001,
+    0x3fff_ffff_fa00_0001,
+    0x3fff_ffff_f9f0_0001,
+];
+/// Roots of unity of order $2^{20}$ modulo each of the [`NTT_PRIMES`].
+const NTT_ROOTS: [u64; 3] = [
+    2_736_110_502_881_845_706,
+    1_469_899_618_568_417_606,
+    1_838_850_007_681_131_305,
+];
+
+/// Number of residues of a polynomial coefficient in the NTT domain, one per prime.
+pub const NTT_RESIDUE_COUNT: usize = NTT_PRIMES.len();
+
+/// Negacyclic number theoretic transforms of a given polynomial size.
+///
+/// A polynomial of size $N$ is represented in the NTT domain by $3N$ residues: the transforms
+/// modulo each of three primes of 62 bits, one after the other. Products of polynomials are
+/// computed exactly in this domain, and brought back modulo $2^{64}$ with the Chinese remainder
+/// theorem.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Ntt {
+    polynomial_size: PolynomialSize,
+    /// For each prime, the powers of $\psi$, a $2N$-th root of unity
+    twists: Vec<u64>,
+    /// For each prime, the powers of $\psi^{-1}$ multiplied by $N^{-1}$
+    inverse_twists: Vec<u64>,
+    /// For each prime, $\omega = \psi^2$
+    roots: [u64; NTT_RESIDUE_COUNT],
+    /// For each prime, $\omega^{-1}$
+    inverse_roots: [u64; NTT_RESIDUE_COUNT],
+}
+
+impl Ntt {
+    /// Create the transforms for polynomials of size `polynomial_size`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the polynomial size is not a power of two smaller than $2^{20}$.
+    pub fn new(polynomial_size: PolynomialSize) -> Self {
+        let poly_size = polynomial_size.0;
+        assert!(
+            poly_size.is_power_of_two() && poly_size < (1 << NTT_LOG2_ROOT_ORDER),
+            "The polynomial size {poly_size} is not a power of two smaller than 2^{}.",
+            NTT_LOG2_ROOT_ORDER,
+        );
+        let log2_poly_size = poly_size.trailing_zeros() as usize;
+
+        let mut twists = Vec::with_capacity(NTT_RESIDUE_COUNT * poly_size);
+        let mut inverse_twists = Vec::with_capacity(NTT_RESIDUE_COUNT * poly_size);
+        let mut roots = [0; NTT_RESIDUE_COUNT];
+        let mut inverse_roots = [0; NTT_RESIDUE_COUNT];
+
+        for (&modulus, &root, root_out, inverse_root_out) in izip!(
+            NTT_PRIMES.iter(),
+            NTT_ROOTS.iter(),
+            roots.iter_mut(),
+            inverse_roots.iter_mut()
+        ) {
+            // The inputs are multiplied by the powers of psi so that the negacyclic product
+            // becomes a cyclic one, computed with NTTs using omega = psi^2
+            let psi = pow_mod(
+                root,
+                1 << (NTT_LOG2_ROOT_ORDER - log2_poly_size - 1),
+                modulus,
+            );
+            let psi_inv = inv_mod(psi, modulus);
+            let omega = mul_mod(psi, psi, modulus);
+            *root_out = omega;
+            *inverse_root_out = inv_mod(omega, modulus);
+
+            let mut power = 1;
+            // The inverse twist also undoes the scaling by N of the inverse transform
+            let mut inverse_power = inv_mod(poly_size as u64, modulus);
+            for _ in 0..poly_size {
+                twists.push(power);
+                inverse_twists.push(inverse_power);
+                power = mul_mod(power, psi, modulus);
+                inverse_power = mul_mod(inverse_power, psi_inv, modulus);
+            }
+        }
+
+        Self {
+            polynomial_size,
+            twists,
+            inverse_twists,
+            roots,
+            inverse_roots,
+        }
+    }
+
+    pub fn polynomial_size(&self) -> PolynomialSize {
+        self.polynomial_size
+    }
+
+    /// Number of residues representing a polynomial in the NTT domain.
+    pub fn ntt_polynomial_size(&self) -> usize {
+        NTT_RESIDUE_COUNT * self.polynomial_size.0
+    }
+
+    /// Fill `output` with the transform of `input`, whose coefficients are seen as unsigned
+    /// integers.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `Scalar` has more than 64 bits.
+    pub fn forward_as_integer<Scalar: UnsignedInteger>(
+        &self,
+        output: &mut [u64],
+        input: &[Scalar],
+    ) {
+        assert!(
+            Scalar::BITS <= 64,
+            "The NTT only supports integers of at most 64 bits, got {} bits.",
+            Scalar::BITS,
+        );
+        let poly_size = self.polynomial_size.0;
+        assert_eq!(input.len(), poly_size);
+        assert_eq!(output.len(), self.ntt_polynomial_size());
+
+        for (output, twists, &modulus, &root) in izip!(
+            output.chunks_exact_mut(poly_size),
+            self.twists.chunks_exact(poly_size),
+            NTT_PRIMES.iter(),
+            self.roots.iter()
+        ) {
+            for (out, &coeff, &twist) in izip!(output.iter_mut(), input.iter(), twists.iter()) {
+                let coeff: u128 = coeff.cast_into();
+                *out = mul_mod(coeff as u64 % modulus, twist, modulus);
+            }
+            ntt_in_place(output, root, modulus);
+        }
+    }
+
+    /// Add the pointwise product of `lhs` and `rhs` to `output`, all in the NTT domain.
+    pub fn mul_add_assign(&self, output: &mut [u64], lhs: &[u64], rhs: &[u64]) {
+        let poly_size = self.polynomial_size.0;
+        assert_eq!(output.len(), self.ntt_polynomial_size());
+        assert_eq!(lhs.len(), self.ntt_polynomial_size());
+        assert_eq!(rhs.len(), self.ntt_polynomial_size());
+
+        for (output, lhs, rhs, &modulus) in izip!(
+            output.chunks_exact_mut(poly_size),
+            lhs.chunks_exact(poly_size),
+            rhs.chunks_exact(poly_size),
+            NTT_PRIMES.iter()
+        ) {
+            for (out, &l, &r) in izip!(output.iter_mut(), lhs.iter(), rhs.iter()) {
+                *out = add_mod(*out, mul_mod(l, r, modulus), modulus);
+            }
+        }
+    }
+
+    /// Add the inverse transform of `input` to `output`, wrapping around modulo $2^{n\_{bits}}$.
+    /// `input` is used as a buffer and left in an unspecified state.
+    ///
+    /// `input` must hold a sum of at most `product_count` products of polynomials of unsigned
+    /// integers of at most 64 bits, so that the exact value of its coefficients can be recovered.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `Scalar` has more than 64 bits.
+    pub fn add_backward_as_integer<Scalar: UnsignedInteger>(
+        &self,
+        output: &mut [Scalar],
+        input: &mut [u64],
+        product_count: usize,
+    ) {
+        assert!(
+            Scalar::BITS <= 64,
+            "The NTT only supports integers of at most 64 bits, got {} bits.",
+            Scalar::BITS,
+        );
+        let poly_size = self.polynomial_size.0;
+        assert_eq!(output.len(), poly_size);
+        assert_eq!(input.len(), self.ntt_polynomial_size());
+        // The reconstruction below needs 2 * product_count * N * 2^128 to be smaller than the
+        // product of the primes
+        assert!(
+            (product_count as u128) * (poly_size as u128) < 1 << 56,
+            "Too many products ({product_count}) to recover the exact coefficients"
+        );
+
+        for (input, inverse_twists, &modulus, &inverse_root) in izip!(
+            input.chunks_exact_mut(poly_size),
+            self.inverse_twists.chunks_exact(poly_size),
+            NTT_PRIMES.iter(),
+            self.inverse_roots.iter()
+        ) {
+            ntt_in_place(input, inverse_root, modulus);
+
+            // The coefficients of the exact result lie in
+            // ]-product_count * N * 2^128, product_count * N * 2^128[, adding
+            // product_count * N * 2^128 makes them positive and smaller than the product of the
+            // primes, without changing them modulo 2^64
+            let offset = mul_mod(
+                pow_mod(2, 128, modulus),
+                (product_count * poly_size) as u64 % modulus,
+                modulus,
+            );
+            for (coeff, &inverse_twist) in izip!(input.iter_mut(), inverse_twists.iter()) {
+                *coeff = add_mod(mul_mod(*coeff, inverse_twist, modulus), offset, modulus);
+            }
+        }
+
+        // Garner's reconstruction: value = v0 + v1 * p0 + v2 * p0 * p1, computed modulo 2^64
+        let [p0, p1, p2] = NTT_PRIMES;
+        let p0_inv_mod_p1 = inv_mod(p0 % p1, p1);
+        let p0_p1_inv_mod_p2 = inv_mod(mul_mod(p0 % p2, p1 % p2, p2), p2);
+        let (residues_0, residues_12) = input.split_at(poly_size);
+        let (residues_1, residues_2) = residues_12.split_at(poly_size);
+        for (out, &r0, &r1, &r2) in izip!(
+            output.iter_mut(),
+            residues_0.iter(),
+            residues_1.iter(),
+            residues_2.iter()
+        ) {
+            let v0 = r0;
+            let v1 = mul_mod(sub_mod(r1, v0 % p1, p1), p0_inv_mod_p1, p1);
+            let partial = add_mod(v0 % p2, mul_mod(v1 % p2, p0 % p2, p2), p2);
+            let v2 = mul_mod(sub_mod(r2, partial, p2), p0_p1_inv_mod_p2, p2);
+
+            let value = v0
+                .wrapping_add(v1.wrapping_mul(p0))
+                .wrapping_add(v2.wrapping_mul(p0.wrapping_mul(p1)));
+            *out = (*out).wrapping_add(Scalar::cast_from(value as u128));
+        }
+    }
+}
+
+fn add_mod(lhs: u64, rhs: u64, modulus: u64) -> u64 {
+    let sum = lhs + rhs;
+    if sum >= modulus {
+        sum - modulus
+    } else {
+        sum
+    }
+}
+
+fn sub_mod(lhs: u64, rhs: u64, modulus: u64) -> u64 {
+    if lhs >= rhs {
+        lhs - rhs
+    } else {
+        lhs + modulus - rhs
+    }
+}
+
+fn mul_mod(lhs: u64, rhs: u64, modulus: u64) -> u64 {
+    ((lhs as u128 * rhs as u128) % modulus as u128) as u64
+}
+
+fn pow_mod(mut base: u64, mut exponent: u64, modulus: u64) -> u64 {
+    let mut result = 1;
+    while exponent != 0 {
+        if exponent & 1 == 1 {
+            result = mul_mod(result, base, modulus);
+        }
+        base = mul_mod(base, base, modulus);
+        exponent >>= 1;
+    }
+    result
+}
+
+/// Inverse modulo a prime, by Fermat's little theorem.
+fn inv_mod(value: u64, modulus: u64) -> u64 {
+    pow_mod(value, modulus - 2, modulus)
+}
+
+/// In place iterative Cooley-Tukey NTT, `root` must be a root of unity of order `values.len()`.
+fn ntt_in_place(values: &mut [u64], root: u64, modulus: u64) {
+    let size = values.len();
+
+    // bit reversal permutation
+    let mut j = 0;
+    for i in 1..size {
+        let mut bit = size >> 1;
+        while j & bit != 0 {
+            j ^= bit;
+            bit >>= 1;
+        }
+        j |= bit;
+        if i < j {
+            values.swap(i, j);
+        }
+    }
+
+    let mut len = 2;
+    while len <= size {
+        let step_root = pow_mod(root, (size / len) as u64, modulus);
+        for chunk in values.chunks_exact_mut(len) {
+            let (low, high) = chunk.split_at_mut(len / 2);
+            let mut twiddle = 1;
+            for (x, y) in low.iter_mut().zip(high.iter_mut()) {
+                let t = mul_mod(*y, twiddle, modulus);
+                *y = sub_mod(*x, t, modulus);
+                *x = add_mod(*x, t, modulus);
+                twiddle = mul_mod(twiddle, step_root, modulus);
+            }
+        }
+        len <<= 1;
+    }
+}
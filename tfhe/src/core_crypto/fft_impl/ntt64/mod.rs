@@ -0,0 +1,9 @@
+//! Portable implementation of the bootstrap using only integer arithmetic.
+//!
+//! The polynomial products are computed exactly with number theoretic transforms (NTT) modulo
+//! three primes of 62 bits and reconstructed with the Chinese remainder theorem. This backend is
+//! slower than the Fourier ones, but it does not depend on floating point arithmetic: its output
+//! does not carry the error of the Fourier transforms, which makes it a reference to check them.
+
+pub mod crypto;
+pub mod math;
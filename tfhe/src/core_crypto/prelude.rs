@@ -9,7 +9,9 @@ pub use super::algorithms::{
 pub use super::commons::computation_buffers::ComputationBuffers;
 pub use super::commons::dispersion::*;
 pub use super::commons::generators::{EncryptionRandomGenerator, SecretRandomGenerator};
-pub use super::commons::math::decomposition::SignedDecomposer;
+pub use super::commons::math::decomposition::{
+    DecompositionLevel, DecompositionTerm, SignedDecomposer, SignedDecompositionIter,
+};
 pub use super::commons::math::random::ActivatedRandomGenerator;
 pub use super::commons::parameters::*;
 pub use super::commons::traits::*;
@@ -2,18 +2,34 @@ use std::borrow::Borrow;
 use std::ops::{BitAnd, BitOr, BitXor};
 
 use crate::boolean::ciphertext::{Ciphertext, CompressedCiphertext};
+use crate::core_crypto::commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+};
 use serde::{Deserialize, Serialize};
 
 use crate::high_level_api::booleans::client_key::GenericBoolClientKey;
 use crate::high_level_api::booleans::parameters::BooleanParameterSet;
 use crate::high_level_api::booleans::public_key::GenericBoolPublicKey;
 use crate::high_level_api::booleans::server_key::GenericBoolServerKey;
+use crate::high_level_api::compressed_list::{
+    CompressedCiphertextList, CompressedListData, CompressedListElement, CompressedListEntry,
+    FromCompressedList,
+};
+use crate::high_level_api::errors::Type;
 use crate::high_level_api::global_state::WithGlobalKey;
+use crate::high_level_api::internal_traits::TypeIdentifier;
 use crate::high_level_api::keys::{
     ClientKey, PublicKey, RefKeyFromKeyChain, RefKeyFromPublicKeyChain,
 };
+use crate::high_level_api::remote_key::{
+    remote_decrypt, remote_encrypt, RemoteClearValue, RemoteClientKey, RemoteKeyError,
+};
+use crate::high_level_api::result_envelope::{
+    parameters_fingerprint, EnvelopeData, EnvelopeEntry, FromResultEnvelope, ResultEnvelope,
+    ResultEnvelopeElement,
+};
 use crate::high_level_api::traits::{
-    FheDecrypt, FheEq, FheTrivialEncrypt, FheTryEncrypt, FheTryTrivialEncrypt,
+    FheDecrypt, FheEq, FheTrivialEncrypt, FheTryDecrypt, FheTryEncrypt, FheTryTrivialEncrypt,
 };
 
 /// The FHE boolean data type.
@@ -136,6 +152,127 @@ where
     }
 }
 
+impl<P> CompressedListElement for CompressedBool<P>
+where
+    P: BooleanParameterSet,
+    P::Id: TypeIdentifier,
+{
+    fn push_to(self, list: &mut CompressedCiphertextList) {
+        list.entries.push(CompressedListEntry {
+            data_type: self.id.type_variant(),
+            data: CompressedListData::Boolean(self.ciphertext),
+        });
+    }
+}
+
+impl<P> FromCompressedList for GenericBool<P>
+where
+    P: BooleanParameterSet,
+    P::Id: Default + TypeIdentifier,
+{
+    fn data_type() -> Type {
+        P::Id::default().type_variant()
+    }
+
+    fn expand_from(list: &CompressedCiphertextList, index: usize) -> Self {
+        match &list.entries[index].data {
+            CompressedListData::Boolean(ciphertext) => {
+                Self::new(ciphertext.clone().into(), P::Id::default())
+            }
+            #[allow(unreachable_patterns)]
+            _ => unreachable!("The element at index {index} is not a boolean ciphertext"),
+        }
+    }
+}
+
+impl<P> ResultEnvelopeElement for GenericBool<P>
+where
+    P: BooleanParameterSet,
+    P::Id: WithGlobalKey<Key = GenericBoolServerKey<P>> + TypeIdentifier,
+{
+    /// The outputs of boolean gates are always bootstrapped, they are pushed as is.
+    fn push_to(self, envelope: &mut ResultEnvelope, bootstrap: bool) {
+        let parameters_fingerprint = match &self.ciphertext {
+            Ciphertext::Encrypted(_) => Some(self.id.with_unwrapped_global(|server_key| {
+                let bootstrapping_key = &server_key.key.bootstrapping_key;
+                let key_switching_key = &server_key.key.key_switching_key;
+                boolean_parameters_fingerprint(
+                    bootstrapping_key.input_lwe_dimension(),
+                    bootstrapping_key.glwe_size().to_glwe_dimension(),
+                    bootstrapping_key.polynomial_size(),
+                    bootstrapping_key.decomposition_base_log(),
+                    bootstrapping_key.decomposition_level_count(),
+                    key_switching_key.decomposition_base_log(),
+                    key_switching_key.decomposition_level_count(),
+                )
+            })),
+            Ciphertext::Trivial(_) => None,
+        };
+        envelope.push_entry(EnvelopeEntry {
+            data_type: self.id.type_variant(),
+            parameters_fingerprint,
+            bootstrapped: bootstrap,
+            data: EnvelopeData::Boolean(self.ciphertext),
+        });
+    }
+}
+
+impl<P> FromResultEnvelope for GenericBool<P>
+where
+    P: BooleanParameterSet,
+    P::Id: Default + TypeIdentifier + RefKeyFromKeyChain<Key = GenericBoolClientKey<P>>,
+{
+    fn data_type() -> Type {
+        P::Id::default().type_variant()
+    }
+
+    fn parameters_fingerprint(client_key: &ClientKey) -> Option<u64> {
+        let parameters = P::Id::default().ref_key(client_key).ok()?.key.parameters;
+        Some(boolean_parameters_fingerprint(
+            parameters.lwe_dimension,
+            parameters.glwe_dimension,
+            parameters.polynomial_size,
+            parameters.pbs_base_log,
+            parameters.pbs_level,
+            parameters.ks_base_log,
+            parameters.ks_level,
+        ))
+    }
+
+    fn extract_from(envelope: &ResultEnvelope, index: usize) -> Self {
+        match envelope.entry_data(index) {
+            EnvelopeData::Boolean(ciphertext) => Self::new(ciphertext.clone(), P::Id::default()),
+            #[allow(unreachable_patterns)]
+            _ => unreachable!("The element at index {index} is not a boolean ciphertext"),
+        }
+    }
+}
+
+/// Fingerprint of boolean parameters, computed from everything the server key is made of: the
+/// dimensions of the keys and the decompositions of the bootstrapping and key switching keys.
+///
+/// The noise distributions can not be read back from a server key, they are not part of the
+/// fingerprint.
+fn boolean_parameters_fingerprint(
+    lwe_dimension: LweDimension,
+    glwe_dimension: GlweDimension,
+    polynomial_size: PolynomialSize,
+    pbs_base_log: DecompositionBaseLog,
+    pbs_level: DecompositionLevelCount,
+    ks_base_log: DecompositionBaseLog,
+    ks_level: DecompositionLevelCount,
+) -> u64 {
+    parameters_fingerprint(&[
+        lwe_dimension.0 as u64,
+        glwe_dimension.0 as u64,
+        polynomial_size.0 as u64,
+        pbs_base_log.0 as u64,
+        pbs_level.0 as u64,
+        ks_base_log.0 as u64,
+        ks_level.0 as u64,
+    ])
+}
+
 impl<P> FheTryEncrypt<bool, ClientKey> for CompressedBool<P>
 where
     P: BooleanParameterSet,
@@ -166,6 +303,33 @@ where
     }
 }
 
+impl<P, K> FheTryEncrypt<bool, K> for GenericBool<P>
+where
+    P: BooleanParameterSet,
+    P::Id: Default + TypeIdentifier + RefKeyFromKeyChain<Key = GenericBoolClientKey<P>>,
+    K: RemoteClientKey,
+{
+    type Error = RemoteKeyError;
+
+    fn try_encrypt(value: bool, key: &K) -> Result<Self, Self::Error> {
+        remote_encrypt(key, RemoteClearValue::from(value))
+    }
+}
+
+impl<P, K> FheTryDecrypt<bool, K> for GenericBool<P>
+where
+    P: BooleanParameterSet,
+    P::Id: TypeIdentifier,
+    K: RemoteClientKey,
+    Self: Clone,
+{
+    type Error = RemoteKeyError;
+
+    fn try_decrypt(&self, key: &K) -> Result<bool, Self::Error> {
+        bool::try_from(remote_decrypt(key, self.clone())?)
+    }
+}
+
 impl<P> FheTryTrivialEncrypt<bool> for GenericBool<P>
 where
     P: BooleanParameterSet,
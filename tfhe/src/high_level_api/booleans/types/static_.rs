@@ -10,6 +10,7 @@ use crate::high_level_api::booleans::server_key::{
 };
 use crate::high_level_api::booleans::types::CompressedBool;
 use crate::high_level_api::errors::Type;
+use crate::high_level_api::internal_traits::TypeIdentifier;
 
 use super::base::GenericBool;
 
@@ -47,6 +48,12 @@ impl From<FheBoolParameters> for StaticBoolParameters {
     }
 }
 
+impl TypeIdentifier for FheBoolId {
+    fn type_variant(&self) -> Type {
+        Type::FheBool
+    }
+}
+
 impl BooleanParameterSet for StaticBoolParameters {
     type Id = FheBoolId;
 }
@@ -0,0 +1,241 @@
+//! A list of compressed ciphertexts of different types.
+//!
+//! A client sending several values of different types, e.g. an [FheUint32], an [FheBool] and an
+//! [FheUint8], can push their compressed ciphertexts in a single [CompressedCiphertextList] and
+//! send it in one upload. Each element of the list records the data type it was encrypted as, so
+//! the server can inspect the list with [CompressedCiphertextList::data_type] and expand each
+//! element to its type with [CompressedCiphertextList::get], which fails if the requested type is
+//! not the one of the element.
+//!
+//! [FheUint32]: crate::high_level_api::FheUint32
+//! [FheBool]: crate::high_level_api::FheBool
+//! [FheUint8]: crate::high_level_api::FheUint8
+//!
+//! # Example
+//!
+//! ```rust
+//! # #[cfg(all(feature = "boolean", feature = "integer"))]
+//! # {
+//! use tfhe::errors::Type;
+//! use tfhe::prelude::*;
+//! use tfhe::{
+//!     generate_keys, CompressedCiphertextList, CompressedFheBool, CompressedFheUint32,
+//!     CompressedFheUint8, ConfigBuilder, FheBool, FheUint32, FheUint8,
+//! };
+//!
+//! let config = ConfigBuilder::all_disabled()
+//!     .enable_default_bool()
+//!     .enable_default_integers()
+//!     .build();
+//! let (client_key, _server_key) = generate_keys(config);
+//!
+//! let mut list = CompressedCiphertextList::new();
+//! list.push(CompressedFheUint32::try_encrypt(1_000_000u32, &client_key).unwrap());
+//! list.push(CompressedFheBool::try_encrypt(true, &client_key).unwrap());
+//! list.push(CompressedFheUint8::try_encrypt(255u8, &client_key).unwrap());
+//! let upload = bincode::serialize(&list).unwrap();
+//!
+//! let list: CompressedCiphertextList = bincode::deserialize(&upload).unwrap();
+//! assert_eq!(list.len(), 3);
+//! assert_eq!(list.data_type(1), Some(Type::FheBool));
+//!
+//! let a: FheUint32 = list.get(0).unwrap();
+//! let b: FheBool = list.get(1).unwrap();
+//! let c: FheUint8 = list.get(2).unwrap();
+//! assert!(list.get::<FheUint8>(0).is_err());
+//!
+//! let a: u32 = a.decrypt(&client_key);
+//! let b: bool = b.decrypt(&client_key);
+//! let c: u8 = c.decrypt(&client_key);
+//! assert_eq!((a, b, c), (1_000_000, true, 255));
+//! # }
+//! ```
+
+use crate::high_level_api::errors::Type;
+#[cfg(feature = "integer")]
+use crate::high_level_api::integers::CompressedRadixCiphertextDyn;
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+/// Error returned when expanding an element of a [CompressedCiphertextList].
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum CompressedListError {
+    /// The index is not the one of an element of the list
+    IndexOutOfBounds { index: usize, len: usize },
+    /// The element was not encrypted as the requested type
+    TypeMismatch { requested: Type, stored: Type },
+}
+
+impl Display for CompressedListError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::IndexOutOfBounds { index, len } => {
+                write!(
+                    f,
+                    "Index {index} is out of bounds for a list of length {len}"
+                )
+            }
+            Self::TypeMismatch { requested, stored } => {
+                write!(
+                    f,
+                    "The element was encrypted as '{stored:?}', it can not be expanded \
+                    as '{requested:?}'"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for CompressedListError {}
+
+/// The ciphertext of an element of the list, whose type is given by its [Type].
+#[derive(Clone, Serialize, Deserialize)]
+pub(in crate::high_level_api) enum CompressedListData {
+    #[cfg(feature = "boolean")]
+    Boolean(crate::boolean::ciphertext::CompressedCiphertext),
+    #[cfg(feature = "integer")]
+    Radix(CompressedRadixCiphertextDyn),
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub(in crate::high_level_api) struct CompressedListEntry {
+    pub(in crate::high_level_api) data_type: Type,
+    pub(in crate::high_level_api) data: CompressedListData,
+}
+
+/// A list of compressed ciphertexts of possibly different types.
+///
+/// See the [module documentation](self) for an example.
+#[derive(Clone, Default, Serialize, Deserialize)]
+pub struct CompressedCiphertextList {
+    pub(in crate::high_level_api) entries: Vec<CompressedListEntry>,
+}
+
+/// Trait implemented by the compressed types which can be pushed in a
+/// [CompressedCiphertextList].
+pub trait CompressedListElement {
+    fn push_to(self, list: &mut CompressedCiphertextList);
+}
+
+/// Trait implemented by the types an element of a [CompressedCiphertextList] can be expanded to.
+pub trait FromCompressedList: Sized {
+    /// Returns the type of the elements this type is expanded from.
+    fn data_type() -> Type;
+
+    /// Expands the element at `index`, whose type is [Self::data_type].
+    fn expand_from(list: &CompressedCiphertextList, index: usize) -> Self;
+}
+
+impl CompressedCiphertextList {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Appends a compressed ciphertext to the list.
+    pub fn push<T>(&mut self, element: T) -> &mut Self
+    where
+        T: CompressedListElement,
+    {
+        element.push_to(self);
+        self
+    }
+
+    /// Returns the type the element at `index` was encrypted as, or `None` if the index is out
+    /// of bounds.
+    pub fn data_type(&self, index: usize) -> Option<Type> {
+        self.entries.get(index).map(|entry| entry.data_type)
+    }
+
+    /// Returns an iterator over the types of the elements of the list.
+    pub fn data_types(&self) -> impl Iterator<Item = Type> + '_ {
+        self.entries.iter().map(|entry| entry.data_type)
+    }
+
+    /// Expands the element at `index` to the type `T`, which must be the type the element was
+    /// encrypted as.
+    pub fn get<T>(&self, index: usize) -> Result<T, CompressedListError>
+    where
+        T: FromCompressedList,
+    {
+        let stored = self
+            .data_type(index)
+            .ok_or(CompressedListError::IndexOutOfBounds {
+                index,
+                len: self.len(),
+            })?;
+        let requested = T::data_type();
+        if stored != requested {
+            return Err(CompressedListError::TypeMismatch { requested, stored });
+        }
+        Ok(T::expand_from(self, index))
+    }
+}
+
+#[cfg(all(test, feature = "boolean", feature = "integer"))]
+mod tests {
+    use super::*;
+    use crate::high_level_api::prelude::*;
+    use crate::high_level_api::{
+        generate_keys, CompressedFheBool, CompressedFheUint16, CompressedFheUint8, ConfigBuilder,
+        FheBool, FheUint16, FheUint8,
+    };
+
+    #[test]
+    fn test_compressed_ciphertext_list() {
+        let config = ConfigBuilder::all_disabled()
+            .enable_default_bool()
+            .enable_default_integers()
+            .build();
+        let (cks, _sks) = generate_keys(config);
+
+        let mut list = CompressedCiphertextList::new();
+        list.push(CompressedFheUint8::try_encrypt(17u8, &cks).unwrap())
+            .push(CompressedFheBool::try_encrypt(false, &cks).unwrap())
+            .push(CompressedFheUint16::try_encrypt(4242u16, &cks).unwrap())
+            .push(CompressedFheBool::try_encrypt(true, &cks).unwrap());
+
+        let serialized = bincode::serialize(&list).unwrap();
+        let list: CompressedCiphertextList = bincode::deserialize(&serialized).unwrap();
+
+        assert_eq!(
+            list.data_types().collect::<Vec<_>>(),
+            vec![
+                Type::FheUint8,
+                Type::FheBool,
+                Type::FheUint16,
+                Type::FheBool
+            ]
+        );
+
+        let a: FheUint8 = list.get(0).unwrap();
+        let b: FheBool = list.get(1).unwrap();
+        let c: FheUint16 = list.get(2).unwrap();
+        let d: FheBool = list.get(3).unwrap();
+        let a: u8 = a.decrypt(&cks);
+        let c: u16 = c.decrypt(&cks);
+        assert_eq!(a, 17);
+        assert!(!b.decrypt(&cks));
+        assert_eq!(c, 4242);
+        assert!(d.decrypt(&cks));
+
+        assert_eq!(
+            list.get::<FheUint16>(0).err(),
+            Some(CompressedListError::TypeMismatch {
+                requested: Type::FheUint16,
+                stored: Type::FheUint8
+            })
+        );
+        assert_eq!(
+            list.get::<FheBool>(4).err(),
+            Some(CompressedListError::IndexOutOfBounds { index: 4, len: 4 })
+        );
+    }
+}
@@ -137,6 +137,48 @@ impl ConfigBuilder {
         self
     }
 
+    /// Enables the adaptive precision mode of the integer types.
+    ///
+    /// In this mode, the additions and multiplications (`+`, `*`, `+=`, `*=`, also with a clear
+    /// scalar) grow the number of blocks of their result when, according to the degrees tracked
+    /// on the blocks, it might not fit in the blocks of the operands. The result keeps the static
+    /// type of the operands, e.g. a `FheUint32`, but holds the exact value: it is recovered by
+    /// decrypting to a wider clear type or by casting to a wider type with `cast_into`.
+    ///
+    /// The other operations, e.g. subtractions and left shifts, still wrap at the number of
+    /// blocks of their widest operand. The mode is disabled by default, as the grown ciphertexts
+    /// make the following operations more expensive.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint32, FheUint64};
+    ///
+    /// let config = ConfigBuilder::all_disabled()
+    ///     .enable_default_integers()
+    ///     .enable_adaptive_integer_precision()
+    ///     .build();
+    /// let (client_key, server_key) = generate_keys(config);
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheUint32::encrypt(u32::MAX, &client_key);
+    /// let b = FheUint32::encrypt(3u32, &client_key);
+    ///
+    /// let product = &a * &b;
+    /// let decrypted: u64 = product.decrypt(&client_key);
+    /// assert_eq!(decrypted, u64::from(u32::MAX) * 3);
+    ///
+    /// let product: FheUint64 = product.cast_into();
+    /// let decrypted: u64 = product.decrypt(&client_key);
+    /// assert_eq!(decrypted, u64::from(u32::MAX) * 3);
+    /// ```
+    #[cfg(feature = "integer")]
+    pub fn enable_adaptive_integer_precision(mut self) -> Self {
+        self.config.integer_config.adaptive_precision = true;
+        self
+    }
+
     #[cfg(feature = "integer")]
     pub fn disable_integers(mut self) -> Self {
         self.config.integer_config = IntegerConfig::all_none();
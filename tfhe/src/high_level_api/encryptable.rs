@@ -0,0 +1,133 @@
+//! Encryption of composite clear values, e.g. records, into composites of FHE types.
+//!
+//! [FheEncryptable] maps a clear type to its encrypted counterpart:
+//!
+//! - the unsigned integers map to the integer types of the same size, e.g. `u8` to
+//!   [FheUint8](crate::high_level_api::FheUint8),
+//! - `bool` maps to [FheBool](crate::high_level_api::FheBool),
+//! - a fixed-size array `[T; N]` maps to an array `[T::Encrypted; N]`.
+//!
+//! With the `derive` feature, `#[derive(FheEncryptable)]` implements the trait for a struct whose
+//! fields are `FheEncryptable`. It generates a struct named after the clear one, prefixed with
+//! `Encrypted`, which has the same fields holding their encrypted counterparts, so that the
+//! fields can be used directly in homomorphic operations. The encrypted struct converts to and
+//! from the tuple of its fields, and can be decrypted with
+//! [FheDecrypt](crate::high_level_api::prelude::FheDecrypt).
+//!
+//! # Example
+//!
+//! ```rust
+//! # #[cfg(all(feature = "derive", feature = "boolean", feature = "integer"))]
+//! # {
+//! use tfhe::prelude::*;
+//! use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheEncryptable};
+//!
+//! #[derive(FheEncryptable, Debug, PartialEq)]
+//! struct Account {
+//!     balance: u32,
+//!     frozen: bool,
+//!     history: [u32; 3],
+//! }
+//!
+//! let config = ConfigBuilder::all_disabled()
+//!     .enable_default_bool()
+//!     .enable_default_integers()
+//!     .build();
+//! let (client_key, server_key) = generate_keys(config);
+//! set_server_key(server_key);
+//!
+//! let account = Account {
+//!     balance: 100,
+//!     frozen: false,
+//!     history: [10, 20, 30],
+//! };
+//! let mut encrypted = account.encrypt(&client_key);
+//!
+//! encrypted.balance = &encrypted.balance - &encrypted.history[2];
+//! encrypted.frozen = !&encrypted.frozen;
+//!
+//! let account: Account = encrypted.decrypt(&client_key);
+//! assert_eq!(
+//!     account,
+//!     Account {
+//!         balance: 70,
+//!         frozen: true,
+//!         history: [10, 20, 30],
+//!     }
+//! );
+//! # }
+//! ```
+
+use crate::high_level_api::ClientKey;
+
+/// Trait for clear types which can be encrypted into a composite of FHE types, see the
+/// [module documentation](self).
+pub trait FheEncryptable: Sized {
+    /// The encrypted counterpart of the type.
+    type Encrypted;
+
+    fn encrypt(&self, key: &ClientKey) -> Self::Encrypted;
+
+    fn decrypt(encrypted: &Self::Encrypted, key: &ClientKey) -> Self;
+}
+
+impl<T, const N: usize> FheEncryptable for [T; N]
+where
+    T: FheEncryptable,
+{
+    type Encrypted = [T::Encrypted; N];
+
+    fn encrypt(&self, key: &ClientKey) -> Self::Encrypted {
+        std::array::from_fn(|i| self[i].encrypt(key))
+    }
+
+    fn decrypt(encrypted: &Self::Encrypted, key: &ClientKey) -> Self {
+        std::array::from_fn(|i| T::decrypt(&encrypted[i], key))
+    }
+}
+
+#[cfg(feature = "boolean")]
+impl FheEncryptable for bool {
+    type Encrypted = crate::high_level_api::FheBool;
+
+    fn encrypt(&self, key: &ClientKey) -> Self::Encrypted {
+        use crate::high_level_api::prelude::FheEncrypt;
+        crate::high_level_api::FheBool::encrypt(*self, key)
+    }
+
+    fn decrypt(encrypted: &Self::Encrypted, key: &ClientKey) -> Self {
+        use crate::high_level_api::prelude::FheDecrypt;
+        encrypted.decrypt(key)
+    }
+}
+
+#[cfg(feature = "integer")]
+macro_rules! impl_fhe_encryptable_for_integers {
+    ($($clear:ty => $fhe_type:ident),* $(,)?) => {
+        $(
+            impl FheEncryptable for $clear {
+                type Encrypted = crate::high_level_api::$fhe_type;
+
+                fn encrypt(&self, key: &ClientKey) -> Self::Encrypted {
+                    use crate::high_level_api::prelude::FheEncrypt;
+                    crate::high_level_api::$fhe_type::encrypt(*self, key)
+                }
+
+                fn decrypt(encrypted: &Self::Encrypted, key: &ClientKey) -> Self {
+                    use crate::high_level_api::prelude::FheDecrypt;
+                    encrypted.decrypt(key)
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(feature = "integer")]
+impl_fhe_encryptable_for_integers!(
+    u8 => FheUint8,
+    u16 => FheUint16,
+    u32 => FheUint32,
+    u64 => FheUint64,
+    u128 => FheUint128,
+    crate::integer::U256 => FheUint256,
+);
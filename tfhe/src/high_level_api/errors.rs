@@ -25,7 +25,7 @@ where
 /// Enum that lists types available
 ///
 /// Mainly used to provide good errors.
-#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
 pub enum Type {
     #[cfg(feature = "boolean")]
     FheBool,
@@ -53,6 +53,14 @@ pub enum Type {
     FheUint128,
     #[cfg(feature = "integer")]
     FheUint256,
+    #[cfg(feature = "integer")]
+    FheInt8,
+    #[cfg(feature = "integer")]
+    FheInt16,
+    #[cfg(feature = "integer")]
+    FheInt32,
+    #[cfg(feature = "integer")]
+    FheInt64,
 }
 
 /// The server key of a given type was not initialized
@@ -128,6 +136,42 @@ impl Display for UninitializedCompressedPublicKey {
 
 impl std::error::Error for UninitializedCompressedPublicKey {}
 
+/// A key required by an operation was not generated, because the feature using it was not enabled
+/// in the config
+///
+/// Contrary to [UninitializedServerKey], the server key of the type is set, but it does not
+/// contain the key named by [MissingKey::key_name].
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct MissingKey {
+    pub(crate) key_name: &'static str,
+    pub(crate) ty: Type,
+}
+
+impl MissingKey {
+    /// The name of the missing key, e.g. `"WoPBS key"`
+    pub fn key_name(&self) -> &'static str {
+        self.key_name
+    }
+
+    /// The type whose operation required the key
+    pub fn type_variant(&self) -> Type {
+        self.ty
+    }
+}
+
+impl Display for MissingKey {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "The {} for the type '{:?}' was not generated\n\
+             Did you forget to enable it in the config ?",
+            self.key_name, self.ty
+        )
+    }
+}
+
+impl std::error::Error for MissingKey {}
+
 /// Error when trying to create a short integer from a value that was too big to be represented
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 pub struct OutOfRangeError;
@@ -147,6 +191,8 @@ pub enum Error {
     UninitializedClientKey(Type),
     UninitializedPublicKey(Type),
     UninitializedServerKey(Type),
+    UninitializedCompressedPublicKey(Type),
+    MissingKey(MissingKey),
 }
 
 impl From<OutOfRangeError> for Error {
@@ -173,6 +219,18 @@ impl From<UninitializedServerKey> for Error {
     }
 }
 
+impl From<UninitializedCompressedPublicKey> for Error {
+    fn from(value: UninitializedCompressedPublicKey) -> Self {
+        Self::UninitializedCompressedPublicKey(value.0)
+    }
+}
+
+impl From<MissingKey> for Error {
+    fn from(value: MissingKey) -> Self {
+        Self::MissingKey(value)
+    }
+}
+
 impl Display for Error {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -188,6 +246,12 @@ impl Display for Error {
             Error::UninitializedServerKey(ty) => {
                 write!(f, "{}", UninitializedServerKey(*ty))
             }
+            Error::UninitializedCompressedPublicKey(ty) => {
+                write!(f, "{}", UninitializedCompressedPublicKey(*ty))
+            }
+            Error::MissingKey(missing_key) => {
+                write!(f, "{missing_key}")
+            }
         }
     }
 }
@@ -1,9 +1,14 @@
+use std::borrow::Cow;
+
+use crate::high_level_api::integers::server_key::{PrecisionGrowth, RadixCiphertextDyn};
 use crate::shortint::EncryptionKeyChoice;
 
 #[derive(Copy, Clone, Debug, serde::Serialize, serde::Deserialize)]
 pub(crate) struct IntegerConfig {
     pub(crate) block_parameters: Option<crate::shortint::PBSParameters>,
     pub(crate) wopbs_block_parameters: Option<crate::shortint::WopbsParameters>,
+    #[serde(default)]
+    pub(crate) adaptive_precision: bool,
 }
 
 impl IntegerConfig {
@@ -14,6 +19,7 @@ impl IntegerConfig {
         Self {
             block_parameters,
             wopbs_block_parameters,
+            adaptive_precision: false,
         }
     }
 
@@ -29,6 +35,7 @@ impl IntegerConfig {
         Self {
             block_parameters: Some(crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2),
             wopbs_block_parameters: None,
+            adaptive_precision: false,
         }
     }
 
@@ -36,6 +43,7 @@ impl IntegerConfig {
         Self {
             block_parameters: Some(crate::shortint::parameters::PARAM_SMALL_MESSAGE_2_CARRY_2),
             wopbs_block_parameters: None,
+            adaptive_precision: false,
         }
     }
 
@@ -58,6 +66,8 @@ pub(crate) struct IntegerClientKey {
     pub(crate) key: Option<crate::integer::ClientKey>,
     pub(crate) wopbs_block_parameters: Option<crate::shortint::WopbsParameters>,
     pub(crate) encryption_type: EncryptionKeyChoice,
+    #[serde(default)]
+    pub(crate) adaptive_precision: bool,
 }
 
 impl IntegerClientKey {
@@ -84,6 +94,7 @@ impl From<IntegerConfig> for IntegerClientKey {
             key,
             wopbs_block_parameters: config.wopbs_block_parameters,
             encryption_type,
+            adaptive_precision: config.adaptive_precision,
         }
     }
 }
@@ -94,6 +105,9 @@ pub struct IntegerServerKey {
     pub(crate) wopbs_key: Option<crate::integer::wopbs::WopbsKey>,
     // Needed to encrypt trivial ciphertexts
     pub(crate) encryption_type: crate::shortint::EncryptionKeyChoice,
+    // Whether additions and multiplications grow their result instead of wrapping
+    #[serde(default)]
+    pub(crate) adaptive_precision: bool,
 }
 
 impl Default for IntegerServerKey {
@@ -102,6 +116,7 @@ impl Default for IntegerServerKey {
             encryption_type: EncryptionKeyChoice::Big,
             key: None,
             wopbs_key: None,
+            adaptive_precision: false,
         }
     }
 }
@@ -122,6 +137,7 @@ impl IntegerServerKey {
             key: Some(base_integer_key),
             wopbs_key,
             encryption_type: client_key.encryption_type(),
+            adaptive_precision: client_key.adaptive_precision,
         }
     }
 
@@ -130,12 +146,150 @@ impl IntegerServerKey {
             .as_ref()
             .expect("Integer ServerKey is not initialized")
     }
+
+    /// Returns the number of bits the result of an operation with the given `growth` may need,
+    /// when the adaptive precision mode is enabled.
+    fn result_bits(
+        &self,
+        lhs_bits: usize,
+        rhs_bits: usize,
+        growth: PrecisionGrowth,
+    ) -> Option<usize> {
+        if !self.adaptive_precision {
+            return None;
+        }
+        match growth {
+            PrecisionGrowth::Wrapping => None,
+            PrecisionGrowth::Add => Some(lhs_bits.max(rhs_bits) + 1),
+            PrecisionGrowth::Mul => Some(lhs_bits + rhs_bits),
+        }
+    }
+
+    /// Returns the number of blocks the operands of a binary operation are extended to.
+    ///
+    /// The operands are always aligned on the widest of them, and are grown further so that
+    /// `result_bits` fit.
+    fn operand_num_blocks(
+        &self,
+        lhs_num_blocks: usize,
+        rhs_num_blocks: usize,
+        result_bits: Option<usize>,
+    ) -> usize {
+        let num_blocks = lhs_num_blocks.max(rhs_num_blocks);
+        result_bits.map_or(num_blocks, |bits| {
+            let bits_per_block = self.pbs_key().key.message_modulus.0.ilog2() as usize;
+            num_blocks.max((bits + bits_per_block - 1) / bits_per_block)
+        })
+    }
+
+    fn extended<'a>(
+        &self,
+        ct: &'a RadixCiphertextDyn,
+        num_blocks: usize,
+    ) -> Cow<'a, RadixCiphertextDyn> {
+        if ct.num_blocks() == num_blocks {
+            Cow::Borrowed(ct)
+        } else {
+            let mut ct = ct.clone();
+            ct.extend_to(self.pbs_key(), num_blocks);
+            Cow::Owned(ct)
+        }
+    }
+
+    /// Computes the binary operation `op`, on operands extended to the same number of blocks.
+    ///
+    /// In adaptive precision mode, the operands are also extended so that the result of an
+    /// operation with the given `growth` cannot overflow, according to the degrees of their
+    /// blocks.
+    pub(in crate::high_level_api::integers) fn apply_binary_op(
+        &self,
+        lhs: &RadixCiphertextDyn,
+        rhs: &RadixCiphertextDyn,
+        growth: PrecisionGrowth,
+        op: impl FnOnce(
+            &crate::integer::ServerKey,
+            &RadixCiphertextDyn,
+            &RadixCiphertextDyn,
+        ) -> RadixCiphertextDyn,
+    ) -> RadixCiphertextDyn {
+        let result_bits = self.result_bits(lhs.max_bits(), rhs.max_bits(), growth);
+        let num_blocks = self.operand_num_blocks(lhs.num_blocks(), rhs.num_blocks(), result_bits);
+        let lhs = self.extended(lhs, num_blocks);
+        let rhs = self.extended(rhs, num_blocks);
+
+        let mut result = op(self.pbs_key(), &lhs, &rhs);
+        if let Some(bits) = result_bits {
+            result.bound_degrees(bits);
+        }
+        result
+    }
+
+    /// Assign version of [`Self::apply_binary_op`].
+    pub(in crate::high_level_api::integers) fn apply_binary_op_assign(
+        &self,
+        lhs: &mut RadixCiphertextDyn,
+        rhs: &RadixCiphertextDyn,
+        growth: PrecisionGrowth,
+        op: impl FnOnce(&crate::integer::ServerKey, &mut RadixCiphertextDyn, &RadixCiphertextDyn),
+    ) {
+        let result_bits = self.result_bits(lhs.max_bits(), rhs.max_bits(), growth);
+        let num_blocks = self.operand_num_blocks(lhs.num_blocks(), rhs.num_blocks(), result_bits);
+        lhs.extend_to(self.pbs_key(), num_blocks);
+        let rhs = self.extended(rhs, num_blocks);
+
+        op(self.pbs_key(), lhs, &rhs);
+        if let Some(bits) = result_bits {
+            lhs.bound_degrees(bits);
+        }
+    }
+
+    /// Computes the scalar operation `op`, growing `lhs` in adaptive precision mode so that the
+    /// result of an operation with the given `growth` cannot overflow.
+    pub(in crate::high_level_api::integers) fn apply_scalar_op(
+        &self,
+        lhs: &RadixCiphertextDyn,
+        rhs: u64,
+        growth: PrecisionGrowth,
+        op: impl FnOnce(&crate::integer::ServerKey, &RadixCiphertextDyn, u64) -> RadixCiphertextDyn,
+    ) -> RadixCiphertextDyn {
+        let rhs_bits = (u64::BITS - rhs.leading_zeros()) as usize;
+        let result_bits = self.result_bits(lhs.max_bits(), rhs_bits, growth);
+        let num_blocks = self.operand_num_blocks(lhs.num_blocks(), 0, result_bits);
+        let lhs = self.extended(lhs, num_blocks);
+
+        let mut result = op(self.pbs_key(), &lhs, rhs);
+        if let Some(bits) = result_bits {
+            result.bound_degrees(bits);
+        }
+        result
+    }
+
+    /// Assign version of [`Self::apply_scalar_op`].
+    pub(in crate::high_level_api::integers) fn apply_scalar_op_assign(
+        &self,
+        lhs: &mut RadixCiphertextDyn,
+        rhs: u64,
+        growth: PrecisionGrowth,
+        op: impl FnOnce(&crate::integer::ServerKey, &mut RadixCiphertextDyn, u64),
+    ) {
+        let rhs_bits = (u64::BITS - rhs.leading_zeros()) as usize;
+        let result_bits = self.result_bits(lhs.max_bits(), rhs_bits, growth);
+        let num_blocks = self.operand_num_blocks(lhs.num_blocks(), 0, result_bits);
+        lhs.extend_to(self.pbs_key(), num_blocks);
+
+        op(self.pbs_key(), lhs, rhs);
+        if let Some(bits) = result_bits {
+            lhs.bound_degrees(bits);
+        }
+    }
 }
 
 #[derive(Clone, serde::Serialize, serde::Deserialize)]
 pub struct IntegerCompressedServerKey {
     pub(crate) key: Option<crate::integer::CompressedServerKey>,
     pub(crate) encryption_type: crate::shortint::EncryptionKeyChoice,
+    #[serde(default)]
+    pub(crate) adaptive_precision: bool,
 }
 
 impl IntegerCompressedServerKey {
@@ -144,6 +298,7 @@ impl IntegerCompressedServerKey {
             return Self {
                 key: None,
                 encryption_type: EncryptionKeyChoice::Big,
+                adaptive_precision: false,
             };
         };
         if client_key.wopbs_block_parameters.is_some() {
@@ -160,6 +315,7 @@ impl IntegerCompressedServerKey {
         Self {
             key: Some(key),
             encryption_type: client_key.encryption_type(),
+            adaptive_precision: client_key.adaptive_precision,
         }
     }
 
@@ -168,6 +324,7 @@ impl IntegerCompressedServerKey {
             key: self.key.map(crate::integer::ServerKey::from),
             wopbs_key: None,
             encryption_type: self.encryption_type,
+            adaptive_precision: self.adaptive_precision,
         }
     }
 }
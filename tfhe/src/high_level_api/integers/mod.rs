@@ -1,8 +1,9 @@
 pub use types::{
     CompressedFheUint10, CompressedFheUint12, CompressedFheUint128, CompressedFheUint14,
     CompressedFheUint16, CompressedFheUint256, CompressedFheUint32, CompressedFheUint64,
-    CompressedFheUint8, FheUint10, FheUint12, FheUint128, FheUint14, FheUint16, FheUint256,
-    FheUint32, FheUint64, FheUint8, GenericInteger,
+    CompressedFheUint8, FheInt16, FheInt32, FheInt64, FheInt8, FheUint10, FheUint12, FheUint128,
+    FheUint14, FheUint16, FheUint256, FheUint32, FheUint64, FheUint8, GenericInteger,
+    GenericSignedInteger,
 };
 
 pub(in crate::high_level_api) use keys::{
@@ -10,6 +11,8 @@ pub(in crate::high_level_api) use keys::{
 };
 pub(in crate::high_level_api) use public_key::compressed::CompressedPublicKeyDyn;
 pub(in crate::high_level_api) use public_key::PublicKeyDyn;
+pub(in crate::high_level_api) use server_key::RadixCiphertextDyn;
+pub(in crate::high_level_api) use types::compressed::CompressedRadixCiphertextDyn;
 
 mod client_key;
 mod keys;
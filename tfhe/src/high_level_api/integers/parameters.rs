@@ -16,3 +16,9 @@ pub trait EvaluationIntegerKey<ClientKey> {
 pub trait IntegerParameter: ParameterType {
     fn num_blocks() -> usize;
 }
+
+/// Trait for the integer parameters which have a counterpart with twice as many blocks, the type
+/// of the results of the widening operations.
+pub trait WideningIntegerParameter: IntegerParameter {
+    type Wide: IntegerParameter;
+}
@@ -222,6 +222,100 @@ pub enum RadixCiphertextDyn {
     Small(crate::integer::RadixCiphertextSmall),
 }
 
+impl RadixCiphertextDyn {
+    pub(super) fn num_blocks(&self) -> usize {
+        match self {
+            Self::Big(ct) => ct.blocks.len(),
+            Self::Small(ct) => ct.blocks.len(),
+        }
+    }
+
+    /// Returns an upper bound on the number of bits of the encrypted value, given by the degrees
+    /// of the blocks.
+    pub(super) fn max_bits(&self) -> usize {
+        match self {
+            Self::Big(ct) => radix_max_bits(ct),
+            Self::Small(ct) => radix_max_bits(ct),
+        }
+    }
+
+    /// Appends trivial zero blocks so that the ciphertext has `num_blocks` blocks.
+    pub(super) fn extend_to(&mut self, sks: &crate::integer::ServerKey, num_blocks: usize) {
+        let num_blocks_to_add = num_blocks.saturating_sub(self.num_blocks());
+        match self {
+            Self::Big(ct) => {
+                sks.extend_radix_with_trivial_zero_blocks_msb_assign(ct, num_blocks_to_add)
+            }
+            Self::Small(ct) => {
+                sks.extend_radix_with_trivial_zero_blocks_msb_assign(ct, num_blocks_to_add)
+            }
+        }
+    }
+
+    /// Lowers the degrees of the blocks to the ones of a value on `bits` bits.
+    ///
+    /// The caller must know that the encrypted value holds on `bits` bits, this is only done when
+    /// the carries are empty.
+    pub(super) fn bound_degrees(&mut self, bits: usize) {
+        match self {
+            Self::Big(ct) => radix_bound_degrees(ct, bits),
+            Self::Small(ct) => radix_bound_degrees(ct, bits),
+        }
+    }
+}
+
+fn radix_max_bits<O: crate::shortint::PBSOrderMarker>(
+    ct: &crate::integer::ciphertext::RadixCiphertext<O>,
+) -> usize {
+    let Some(first_block) = ct.blocks.first() else {
+        return 0;
+    };
+    let bits_per_block = first_block.message_modulus.0.ilog2() as usize;
+    let value_bits = ct
+        .blocks
+        .iter()
+        .enumerate()
+        .filter(|(_, block)| block.degree.0 != 0)
+        .map(|(i, block)| {
+            i * bits_per_block + (usize::BITS - block.degree.0.leading_zeros()) as usize
+        })
+        .max()
+        .unwrap_or(0);
+
+    if ct.block_carries_are_empty() {
+        value_bits
+    } else {
+        // The carries of all the blocks may add up
+        value_bits + (usize::BITS - ct.blocks.len().leading_zeros()) as usize
+    }
+}
+
+fn radix_bound_degrees<O: crate::shortint::PBSOrderMarker>(
+    ct: &mut crate::integer::ciphertext::RadixCiphertext<O>,
+    bits: usize,
+) {
+    if !ct.block_carries_are_empty() {
+        return;
+    }
+    for (i, block) in ct.blocks.iter_mut().enumerate() {
+        let bits_per_block = block.message_modulus.0.ilog2() as usize;
+        let block_bits = bits.saturating_sub(i * bits_per_block).min(bits_per_block);
+        block.degree.0 = block.degree.0.min((1 << block_bits) - 1);
+    }
+}
+
+/// How the number of bits of the result of an operation grows with the ones of its operands, in
+/// the adaptive precision mode.
+#[derive(Copy, Clone)]
+pub(super) enum PrecisionGrowth {
+    /// The operation wraps at the width of its widest operand
+    Wrapping,
+    /// The result needs one more bit than the widest operand
+    Add,
+    /// The result needs as many bits as both operands together
+    Mul,
+}
+
 pub(super) trait ServerKeyDefaultNeg<Ciphertext> {
     type Output;
     fn neg(&self, lhs: Ciphertext) -> Self::Output;
@@ -265,8 +359,12 @@ impl ServerKeyDefaultNeg<&RadixCiphertextDyn> for crate::integer::ServerKey {
 
     fn neg(&self, lhs: &RadixCiphertextDyn) -> Self::Output {
         match lhs {
-            RadixCiphertextDyn::Big(lhs) => RadixCiphertextDyn::Big(self.neg_parallelized(lhs)),
-            RadixCiphertextDyn::Small(lhs) => RadixCiphertextDyn::Small(self.neg_parallelized(lhs)),
+            RadixCiphertextDyn::Big(lhs) => {
+                RadixCiphertextDyn::Big(self.install(|| self.neg_parallelized(lhs)))
+            }
+            RadixCiphertextDyn::Small(lhs) => {
+                RadixCiphertextDyn::Small(self.install(|| self.neg_parallelized(lhs)))
+            }
         }
     }
 }
@@ -285,10 +383,10 @@ macro_rules! impl_default_op_for_tfhe_integer_server_key_dyn {
             ) -> Self::Output {
                 match (lhs_enum, rhs_enum) {
                     (RadixCiphertextDyn::Big(lhs), RadixCiphertextDyn::Big(rhs)) => {
-                        RadixCiphertextDyn::Big(self.$method(lhs, rhs))
+                        RadixCiphertextDyn::Big(self.install(|| self.$method(lhs, rhs)))
                     }
                     (RadixCiphertextDyn::Small(lhs), RadixCiphertextDyn::Small(rhs)) => {
-                        RadixCiphertextDyn::Small(self.$method(lhs, rhs))
+                        RadixCiphertextDyn::Small(self.install(|| self.$method(lhs, rhs)))
                     }
                     (_, _) => unreachable!("internal error: mismatched big and small integer"),
                 }
@@ -307,10 +405,10 @@ macro_rules! impl_default_assign_op_for_tfhe_integer_server_key_dyn {
             ) {
                 match (lhs_enum, rhs_enum) {
                     (RadixCiphertextDyn::Big(lhs), RadixCiphertextDyn::Big(rhs)) => {
-                        self.$method_assign(lhs, rhs)
+                        self.install(|| self.$method_assign(lhs, rhs))
                     }
                     (RadixCiphertextDyn::Small(lhs), RadixCiphertextDyn::Small(rhs)) => {
-                        self.$method_assign(lhs, rhs)
+                        self.install(|| self.$method_assign(lhs, rhs))
                     }
                     (_, _) => unreachable!("internal error: mismatched big and small integer"),
                 }
@@ -326,12 +424,12 @@ macro_rules! impl_default_scalar_op_for_tfhe_integer_server_key_dyn {
 
             fn $default_trait_fn(&self, lhs: &RadixCiphertextDyn, rhs: u64) -> Self::Output {
                 match lhs {
-                    RadixCiphertextDyn::Big(lhs) => {
-                        RadixCiphertextDyn::Big(self.$method(lhs, rhs.try_into().unwrap()))
-                    }
-                    RadixCiphertextDyn::Small(lhs) => {
-                        RadixCiphertextDyn::Small(self.$method(lhs, rhs.try_into().unwrap()))
-                    }
+                    RadixCiphertextDyn::Big(lhs) => RadixCiphertextDyn::Big(
+                        self.install(|| self.$method(lhs, rhs.try_into().unwrap())),
+                    ),
+                    RadixCiphertextDyn::Small(lhs) => RadixCiphertextDyn::Small(
+                        self.install(|| self.$method(lhs, rhs.try_into().unwrap())),
+                    ),
                 }
             }
         }
@@ -344,10 +442,10 @@ macro_rules! impl_default_scalar_assign_op_for_tfhe_integer_server_key_dyn {
             fn $default_trait_fn(&self, lhs: &mut RadixCiphertextDyn, rhs: u64) {
                 match lhs {
                     RadixCiphertextDyn::Big(lhs) => {
-                        self.$method_assign(lhs, rhs.try_into().unwrap())
+                        self.install(|| self.$method_assign(lhs, rhs.try_into().unwrap()))
                     }
                     RadixCiphertextDyn::Small(lhs) => {
-                        self.$method_assign(lhs, rhs.try_into().unwrap())
+                        self.install(|| self.$method_assign(lhs, rhs.try_into().unwrap()))
                     }
                 }
             }
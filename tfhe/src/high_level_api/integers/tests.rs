@@ -1,5 +1,5 @@
 use crate::high_level_api::prelude::*;
-use crate::high_level_api::{generate_keys, set_server_key, ConfigBuilder, FheUint8};
+use crate::high_level_api::{generate_keys, set_server_key, ConfigBuilder, FheInt8, FheUint8};
 use crate::integer::U256;
 use crate::{
     CompressedFheUint16, CompressedFheUint256, CompressedPublicKey, FheUint128, FheUint16,
@@ -31,6 +31,34 @@ fn test_quickstart_uint8() {
     assert_eq!(decrypted_result, clear_result);
 }
 
+#[test]
+fn test_uint8_with_dedicated_thread_pool() {
+    let config = ConfigBuilder::all_disabled()
+        .enable_default_integers()
+        .build();
+
+    let (client_key, mut server_key) = generate_keys(config);
+    server_key
+        .set_parallelism(crate::integer::NumThreads(2))
+        .unwrap();
+
+    set_server_key(server_key);
+
+    let clear_a = 27u8;
+    let clear_b = 128u8;
+
+    let a = FheUint8::encrypt(clear_a, &client_key);
+    let b = FheUint8::encrypt(clear_b, &client_key);
+
+    let result = &a + &b;
+    let decrypted_result: u8 = result.decrypt(&client_key);
+    assert_eq!(decrypted_result, clear_a.wrapping_add(clear_b));
+
+    let result = &a * &b;
+    let decrypted_result: u8 = result.decrypt(&client_key);
+    assert_eq!(decrypted_result, clear_a.wrapping_mul(clear_b));
+}
+
 #[test]
 fn test_uint8_compare() {
     let config = ConfigBuilder::all_disabled()
@@ -73,6 +101,42 @@ fn test_uint8_compare() {
     assert_eq!(decrypted_result, clear_result);
 }
 
+#[test]
+fn test_int8_signed_ops() {
+    let config = ConfigBuilder::all_disabled()
+        .enable_default_integers()
+        .build();
+
+    let (client_key, server_key) = generate_keys(config);
+
+    set_server_key(server_key);
+
+    let clear_a = -27i8;
+    let clear_b = 118i8;
+
+    let a = FheInt8::encrypt(clear_a, &client_key);
+    let b = FheInt8::encrypt(clear_b, &client_key);
+
+    let decrypted: i8 = (&a + &b).decrypt(&client_key);
+    assert_eq!(decrypted, clear_a.wrapping_add(clear_b));
+    let decrypted: i8 = (&a - &b).decrypt(&client_key);
+    assert_eq!(decrypted, clear_a.wrapping_sub(clear_b));
+    let decrypted: i8 = (&a * &b).decrypt(&client_key);
+    assert_eq!(decrypted, clear_a.wrapping_mul(clear_b));
+    let decrypted: i8 = (-&a).decrypt(&client_key);
+    assert_eq!(decrypted, -clear_a);
+
+    let decrypted: i8 = a.abs().decrypt(&client_key);
+    assert_eq!(decrypted, clear_a.abs());
+    let decrypted: i8 = a.signum().decrypt(&client_key);
+    assert_eq!(decrypted, -1);
+    let decrypted: i8 = b.signum().decrypt(&client_key);
+    assert_eq!(decrypted, 1);
+    let zero = FheInt8::encrypt(0i8, &client_key);
+    let decrypted: i8 = zero.signum().decrypt(&client_key);
+    assert_eq!(decrypted, 0);
+}
+
 #[test]
 fn test_integer_compressed_can_be_serialized() {
     let config = ConfigBuilder::all_disabled()
@@ -144,6 +208,77 @@ fn test_uint32() {
     assert_eq!(decrypted, clear_a.wrapping_add(clear_b));
 }
 
+#[test]
+fn test_uint8_reference_operators_sum_product() {
+    let config = ConfigBuilder::all_disabled()
+        .enable_default_integers()
+        .build();
+
+    let (cks, sks) = generate_keys(config);
+
+    let clears = [3u8, 200, 17, 91, 5];
+    let values = clears
+        .iter()
+        .map(|clear| FheUint8::encrypt(*clear, &cks))
+        .collect::<Vec<_>>();
+
+    set_server_key(sks);
+
+    let mut c = &values[0] + &values[1];
+    c -= &values[2];
+    c = c * &values[3];
+    c ^= &values[4];
+    let decrypted: u8 = c.decrypt(&cks);
+    let expected = ((clears[0].wrapping_add(clears[1]).wrapping_sub(clears[2]))
+        .wrapping_mul(clears[3]))
+        ^ clears[4];
+    assert_eq!(decrypted, expected);
+
+    for count in 0..=clears.len() {
+        let sum: FheUint8 = values[..count].iter().sum();
+        let decrypted: u8 = sum.decrypt(&cks);
+        let expected = clears[..count]
+            .iter()
+            .fold(0u8, |acc, x| acc.wrapping_add(*x));
+        assert_eq!(decrypted, expected);
+
+        let product: FheUint8 = values[..count].iter().product();
+        let decrypted: u8 = product.decrypt(&cks);
+        let expected = clears[..count]
+            .iter()
+            .fold(1u8, |acc, x| acc.wrapping_mul(*x));
+        assert_eq!(decrypted, expected);
+    }
+}
+
+#[test]
+fn test_uint32_encrypt_decrypt_slice() {
+    let config = ConfigBuilder::all_disabled()
+        .enable_default_integers_small()
+        .build();
+
+    let (cks, sks) = generate_keys(config);
+
+    use rand::prelude::*;
+
+    let mut rng = rand::thread_rng();
+    let clears = (0..37).map(|_| rng.gen::<u32>()).collect::<Vec<_>>();
+
+    let encrypted = FheUint32::encrypt_slice(&clears, &cks);
+    assert_eq!(encrypted.len(), clears.len());
+
+    let decrypted: Vec<u32> = FheUint32::decrypt_slice(&encrypted, &cks);
+    assert_eq!(decrypted, clears);
+
+    // The ciphertexts are usable like the ones encrypted one by one
+    set_server_key(sks);
+    let sum = &encrypted[0] + &encrypted[36];
+    let decrypted: u32 = sum.decrypt(&cks);
+    assert_eq!(decrypted, clears[0].wrapping_add(clears[36]));
+
+    assert!(FheUint32::encrypt_slice::<u32>(&[], &cks).is_empty());
+}
+
 #[test]
 fn test_uint64() {
     let config = ConfigBuilder::all_disabled()
@@ -296,3 +431,104 @@ fn test_integer_casting() {
         assert_eq!(da, clear);
     }
 }
+
+#[test]
+fn test_widening_operations() {
+    let config = ConfigBuilder::all_disabled()
+        .enable_default_integers()
+        .build();
+    let (client_key, server_key) = generate_keys(config);
+
+    set_server_key(server_key);
+
+    let a = FheUint8::encrypt(u8::MAX, &client_key);
+    let b = FheUint8::encrypt(131u8, &client_key);
+
+    let sum: FheUint16 = a.widening_add(&b);
+    let product: FheUint16 = a.widening_mul(&b);
+    let sum: u16 = sum.decrypt(&client_key);
+    let product: u16 = product.decrypt(&client_key);
+    assert_eq!(sum, u8::MAX as u16 + 131);
+    assert_eq!(product, u8::MAX as u16 * 131);
+
+    let clear_a = 3_000_000_000u32;
+    let clear_b = 4_000_000_000u32;
+    let a = FheUint32::encrypt(clear_a, &client_key);
+    let b = FheUint32::encrypt(clear_b, &client_key);
+
+    let product: FheUint64 = a.widening_mul(b);
+    let product: u64 = product.decrypt(&client_key);
+    assert_eq!(product, clear_a as u64 * clear_b as u64);
+}
+
+#[test]
+fn test_adaptive_precision() {
+    let config = ConfigBuilder::all_disabled()
+        .enable_default_integers()
+        .enable_adaptive_integer_precision()
+        .build();
+    let (client_key, server_key) = generate_keys(config);
+
+    set_server_key(server_key);
+
+    let clear_a = 3_000_000_000u32;
+    let clear_b = 4_000_000_000u32;
+    let a = FheUint32::encrypt(clear_a, &client_key);
+    let b = FheUint32::encrypt(clear_b, &client_key);
+
+    let sum = &a + &b;
+    let decrypted: u64 = sum.decrypt(&client_key);
+    assert_eq!(decrypted, clear_a as u64 + clear_b as u64);
+    // Decrypting to the clear type of the static type still wraps
+    let decrypted: u32 = sum.decrypt(&client_key);
+    assert_eq!(decrypted, clear_a.wrapping_add(clear_b));
+
+    let product = &a * &b;
+    let decrypted: u64 = product.decrypt(&client_key);
+    assert_eq!(decrypted, clear_a as u64 * clear_b as u64);
+    let product: FheUint64 = product.cast_into();
+    let decrypted: u64 = product.decrypt(&client_key);
+    assert_eq!(decrypted, clear_a as u64 * clear_b as u64);
+
+    let mut c = a.clone();
+    c += &b;
+    c *= 3u8;
+    let decrypted: u64 = c.decrypt(&client_key);
+    assert_eq!(decrypted, (clear_a as u64 + clear_b as u64) * 3);
+
+    // The grown ciphertexts can be used with the ones of the static type
+    let eq = c.eq(&a);
+    let decrypted: u8 = eq.decrypt(&client_key);
+    assert_eq!(decrypted, 0);
+    let difference = c - &a;
+    let decrypted: u64 = difference.decrypt(&client_key);
+    assert_eq!(
+        decrypted,
+        (clear_a as u64 + clear_b as u64) * 3 - clear_a as u64
+    );
+}
+
+#[test]
+fn test_function_evaluation_without_wopbs_key() {
+    let config = ConfigBuilder::all_disabled()
+        .enable_default_integers()
+        .build();
+
+    let (client_key, server_key) = generate_keys(config);
+    set_server_key(server_key);
+
+    let a = FheUint8::encrypt(27u8, &client_key);
+    let b = FheUint8::encrypt(128u8, &client_key);
+
+    match a.try_map(|x| x + 1) {
+        Err(crate::Error::MissingKey(missing_key)) => {
+            assert_eq!(missing_key.key_name(), "WoPBS key");
+            assert_eq!(missing_key.type_variant(), crate::errors::Type::FheUint8);
+        }
+        _ => panic!("Expected a missing WoPBS key"),
+    }
+    assert!(matches!(
+        a.try_bivariate_function(&b, std::cmp::max),
+        Err(crate::Error::MissingKey(_))
+    ));
+}
@@ -4,29 +4,42 @@ use std::ops::{
     Neg, Shl, ShlAssign, Shr, ShrAssign, Sub, SubAssign,
 };
 
+use crate::core_crypto::commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+};
 use crate::errors::{
-    UninitializedClientKey, UninitializedCompressedPublicKey, UninitializedPublicKey,
-    UnwrapResultExt,
+    MissingKey, Type, UninitializedClientKey, UninitializedCompressedPublicKey,
+    UninitializedPublicKey, UnwrapResultExt,
 };
 use crate::high_level_api::global_state::WithGlobalKey;
-use crate::high_level_api::integers::parameters::IntegerParameter;
+use crate::high_level_api::integers::parameters::{IntegerParameter, WideningIntegerParameter};
 use crate::high_level_api::integers::server_key::{
-    RadixCiphertextDyn, ServerKeyDefaultAdd, ServerKeyDefaultAddAssign, ServerKeyDefaultBitAnd,
-    ServerKeyDefaultBitAndAssign, ServerKeyDefaultBitOr, ServerKeyDefaultBitOrAssign,
-    ServerKeyDefaultBitXor, ServerKeyDefaultBitXorAssign, ServerKeyDefaultEq, ServerKeyDefaultGe,
-    ServerKeyDefaultGt, ServerKeyDefaultLe, ServerKeyDefaultLt, ServerKeyDefaultMax,
-    ServerKeyDefaultMin, ServerKeyDefaultMul, ServerKeyDefaultMulAssign, ServerKeyDefaultNeg,
-    ServerKeyDefaultShl, ServerKeyDefaultShlAssign, ServerKeyDefaultShr, ServerKeyDefaultShrAssign,
-    ServerKeyDefaultSub, ServerKeyDefaultSubAssign,
+    PrecisionGrowth, RadixCiphertextDyn, ServerKeyDefaultAdd, ServerKeyDefaultAddAssign,
+    ServerKeyDefaultBitAnd, ServerKeyDefaultBitAndAssign, ServerKeyDefaultBitOr,
+    ServerKeyDefaultBitOrAssign, ServerKeyDefaultBitXor, ServerKeyDefaultBitXorAssign,
+    ServerKeyDefaultEq, ServerKeyDefaultGe, ServerKeyDefaultGt, ServerKeyDefaultLe,
+    ServerKeyDefaultLt, ServerKeyDefaultMax, ServerKeyDefaultMin, ServerKeyDefaultMul,
+    ServerKeyDefaultMulAssign, ServerKeyDefaultNeg, ServerKeyDefaultShl, ServerKeyDefaultShlAssign,
+    ServerKeyDefaultShr, ServerKeyDefaultShrAssign, ServerKeyDefaultSub, ServerKeyDefaultSubAssign,
 };
 use crate::high_level_api::integers::IntegerServerKey;
-use crate::high_level_api::internal_traits::{DecryptionKey, TypeIdentifier};
+use crate::high_level_api::internal_traits::{DecryptionKey, ParameterType, TypeIdentifier};
 use crate::high_level_api::keys::{CompressedPublicKey, RefKeyFromKeyChain};
+use crate::high_level_api::remote_key::{
+    remote_decrypt, remote_encrypt, RemoteClearValue, RemoteClientKey, RemoteKeyError,
+};
+use crate::high_level_api::result_envelope::{
+    parameters_fingerprint, EnvelopeData, EnvelopeEntry, FromResultEnvelope, ResultEnvelope,
+    ResultEnvelopeElement,
+};
 use crate::high_level_api::traits::{
-    FheBootstrap, FheDecrypt, FheEq, FheOrd, FheTrivialEncrypt, FheTryEncrypt, FheTryTrivialEncrypt,
+    FheBootstrap, FheDecrypt, FheEq, FheOrd, FheTrivialEncrypt, FheTryDecrypt, FheTryEncrypt,
+    FheTryTrivialEncrypt, FheWideningAdd, FheWideningMul,
 };
 use crate::high_level_api::{ClientKey, PublicKey};
 use crate::integer::U256;
+use crate::shortint::parameters::{CarryModulus, CiphertextModulus, MessageModulus};
+use crate::shortint::EncryptionKeyChoice;
 
 /// A Generic FHE unsigned integer
 ///
@@ -89,7 +102,8 @@ where
     {
         crate::high_level_api::global_state::with_internal_keys(|keys| {
             let integer_key = keys.integer_key.pbs_key();
-            let current_num_blocks = P::num_blocks();
+            // In adaptive precision mode, the ciphertext may have grown past P::num_blocks()
+            let current_num_blocks = self.ciphertext.num_blocks();
             let target_num_blocks = P2::num_blocks();
 
             if target_num_blocks > current_num_blocks {
@@ -116,6 +130,86 @@ where
     }
 }
 
+/// Number of values encrypted or decrypted by a thread before taking a new batch of work in
+/// [`GenericInteger::encrypt_slice`] and [`GenericInteger::decrypt_slice`].
+const SLICE_BATCH_SIZE: usize = 16;
+
+impl<P> GenericInteger<P>
+where
+    P: IntegerParameter,
+    P::Id: Default + TypeIdentifier,
+{
+    /// Encrypts all the values of a slice, in parallel.
+    ///
+    /// The client key is looked up once for the whole slice, and the values are split in batches
+    /// encrypted by the threads of the current rayon thread pool.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use tfhe::prelude::*;
+    /// # use tfhe::{generate_keys, ConfigBuilder, FheUint32};
+    /// #
+    /// # let config = ConfigBuilder::all_disabled().enable_default_integers().build();
+    /// # let (client_key, _) = generate_keys(config);
+    /// let clears = [1u32, 2, 1 << 31, u32::MAX];
+    ///
+    /// let encrypted = FheUint32::encrypt_slice(&clears, &client_key);
+    /// assert_eq!(encrypted.len(), clears.len());
+    ///
+    /// let decrypted: Vec<u32> = FheUint32::decrypt_slice(&encrypted, &client_key);
+    /// assert_eq!(decrypted, clears);
+    /// ```
+    pub fn encrypt_slice<T>(values: &[T], key: &ClientKey) -> Vec<Self>
+    where
+        T: Into<U256> + Copy + Sync,
+    {
+        use rayon::prelude::*;
+
+        let integer_client_key = key
+            .integer_key
+            .key
+            .as_ref()
+            .ok_or(UninitializedClientKey(P::Id::default().type_variant()))
+            .unwrap_display();
+        let encryption_type = key.integer_key.encryption_type();
+
+        values
+            .par_chunks(SLICE_BATCH_SIZE)
+            .flat_map_iter(|batch| {
+                batch.iter().map(|value| {
+                    let value = (*value).into();
+                    let ciphertext = match encryption_type {
+                        crate::shortint::EncryptionKeyChoice::Big => RadixCiphertextDyn::Big(
+                            integer_client_key.encrypt_radix(value, P::num_blocks()),
+                        ),
+                        crate::shortint::EncryptionKeyChoice::Small => RadixCiphertextDyn::Small(
+                            integer_client_key.encrypt_radix_small(value, P::num_blocks()),
+                        ),
+                    };
+                    Self::new(ciphertext, P::Id::default())
+                })
+            })
+            .collect()
+    }
+
+    /// Decrypts all the ciphertexts of a slice, in parallel.
+    ///
+    /// See [`Self::encrypt_slice`] for an example.
+    pub fn decrypt_slice<ClearType>(ciphertexts: &[Self], key: &ClientKey) -> Vec<ClearType>
+    where
+        Self: FheDecrypt<ClearType> + Sync,
+        ClearType: Send,
+    {
+        use rayon::prelude::*;
+
+        ciphertexts
+            .par_chunks(SLICE_BATCH_SIZE)
+            .flat_map_iter(|batch| batch.iter().map(|ciphertext| ciphertext.decrypt(key)))
+            .collect()
+    }
+}
+
 impl<P> FheDecrypt<u8> for GenericInteger<P>
 where
     P: IntegerParameter,
@@ -183,8 +277,7 @@ where
             .integer_key
             .key
             .as_ref()
-            .ok_or(UninitializedClientKey(id.type_variant()))
-            .unwrap_display();
+            .ok_or(UninitializedClientKey(id.type_variant()))?;
         let encryption_type = key.integer_key.encryption_type();
         let ciphertext = match encryption_type {
             crate::shortint::EncryptionKeyChoice::Big => {
@@ -198,6 +291,35 @@ where
     }
 }
 
+impl<P, T, K> FheTryEncrypt<T, K> for GenericInteger<P>
+where
+    T: Into<U256>,
+    P: IntegerParameter,
+    P::Id: Default + TypeIdentifier,
+    K: RemoteClientKey,
+{
+    type Error = RemoteKeyError;
+
+    fn try_encrypt(value: T, key: &K) -> Result<Self, Self::Error> {
+        remote_encrypt(key, RemoteClearValue::from(value.into()))
+    }
+}
+
+impl<P, ClearType, K> FheTryDecrypt<ClearType, K> for GenericInteger<P>
+where
+    ClearType: TryFrom<RemoteClearValue, Error = RemoteKeyError>,
+    P: IntegerParameter,
+    P::Id: WithGlobalKey<Key = IntegerServerKey> + TypeIdentifier,
+    K: RemoteClientKey,
+    Self: Clone,
+{
+    type Error = RemoteKeyError;
+
+    fn try_decrypt(&self, key: &K) -> Result<ClearType, Self::Error> {
+        ClearType::try_from(remote_decrypt(key, self.clone())?)
+    }
+}
+
 impl<P, T> FheTryEncrypt<T, PublicKey> for GenericInteger<P>
 where
     T: Into<U256>,
@@ -212,8 +334,7 @@ where
         let integer_public_key = key
             .base_integer_key
             .as_ref()
-            .ok_or(UninitializedPublicKey(id.type_variant()))
-            .unwrap_display();
+            .ok_or(UninitializedPublicKey(id.type_variant()))?;
         let ciphertext = match integer_public_key {
             crate::high_level_api::integers::PublicKeyDyn::Big(pk) => {
                 RadixCiphertextDyn::Big(pk.encrypt_radix(value, P::num_blocks()))
@@ -240,8 +361,7 @@ where
         let integer_public_key = key
             .base_integer_key
             .as_ref()
-            .ok_or(UninitializedCompressedPublicKey(id.type_variant()))
-            .unwrap_display();
+            .ok_or(UninitializedCompressedPublicKey(id.type_variant()))?;
         let ciphertext = match integer_public_key {
             crate::high_level_api::integers::CompressedPublicKeyDyn::Big(pk) => {
                 RadixCiphertextDyn::Big(pk.encrypt_radix(value, P::num_blocks()))
@@ -307,10 +427,13 @@ where
 {
     pub fn max(&self, rhs: &Self) -> Self {
         let inner_result = self.id.with_unwrapped_global(|integer_key| {
-            <crate::integer::ServerKey as ServerKeyDefaultMax<_, _>>::max(
-                integer_key.pbs_key(),
+            integer_key.apply_binary_op(
                 &self.ciphertext,
                 &rhs.ciphertext,
+                PrecisionGrowth::Wrapping,
+                |sks, lhs, rhs| {
+                    <crate::integer::ServerKey as ServerKeyDefaultMax<_, _>>::max(sks, lhs, rhs)
+                },
             )
         });
         GenericInteger::new(inner_result, self.id)
@@ -330,10 +453,13 @@ where
 {
     pub fn min(&self, rhs: &Self) -> Self {
         let inner_result = self.id.with_unwrapped_global(|integer_key| {
-            <crate::integer::ServerKey as ServerKeyDefaultMin<_, _>>::min(
-                integer_key.pbs_key(),
+            integer_key.apply_binary_op(
                 &self.ciphertext,
                 &rhs.ciphertext,
+                PrecisionGrowth::Wrapping,
+                |sks, lhs, rhs| {
+                    <crate::integer::ServerKey as ServerKeyDefaultMin<_, _>>::min(sks, lhs, rhs)
+                },
             )
         });
         GenericInteger::new(inner_result, self.id)
@@ -356,11 +482,13 @@ where
 
     fn eq(&self, rhs: B) -> Self::Output {
         let inner_result = self.id.with_unwrapped_global(|integer_key| {
-            let borrowed = rhs.borrow();
-            <crate::integer::ServerKey as ServerKeyDefaultEq<_, _>>::eq(
-                integer_key.pbs_key(),
+            integer_key.apply_binary_op(
                 &self.ciphertext,
-                &borrowed.ciphertext,
+                &rhs.borrow().ciphertext,
+                PrecisionGrowth::Wrapping,
+                |sks, lhs, rhs| {
+                    <crate::integer::ServerKey as ServerKeyDefaultEq<_, _>>::eq(sks, lhs, rhs)
+                },
             )
         });
         GenericInteger::new(inner_result, self.id)
@@ -395,11 +523,13 @@ where
 
     fn lt(&self, rhs: B) -> Self::Output {
         let inner_result = self.id.with_unwrapped_global(|integer_key| {
-            let borrowed = rhs.borrow();
-            <crate::integer::ServerKey as ServerKeyDefaultLt<_, _>>::lt(
-                integer_key.pbs_key(),
+            integer_key.apply_binary_op(
                 &self.ciphertext,
-                &borrowed.ciphertext,
+                &rhs.borrow().ciphertext,
+                PrecisionGrowth::Wrapping,
+                |sks, lhs, rhs| {
+                    <crate::integer::ServerKey as ServerKeyDefaultLt<_, _>>::lt(sks, lhs, rhs)
+                },
             )
         });
         GenericInteger::new(inner_result, self.id)
@@ -407,11 +537,13 @@ where
 
     fn le(&self, rhs: B) -> Self::Output {
         let inner_result = self.id.with_unwrapped_global(|integer_key| {
-            let borrowed = rhs.borrow();
-            <crate::integer::ServerKey as ServerKeyDefaultLe<_, _>>::le(
-                integer_key.pbs_key(),
+            integer_key.apply_binary_op(
                 &self.ciphertext,
-                &borrowed.ciphertext,
+                &rhs.borrow().ciphertext,
+                PrecisionGrowth::Wrapping,
+                |sks, lhs, rhs| {
+                    <crate::integer::ServerKey as ServerKeyDefaultLe<_, _>>::le(sks, lhs, rhs)
+                },
             )
         });
         GenericInteger::new(inner_result, self.id)
@@ -419,11 +551,13 @@ where
 
     fn gt(&self, rhs: B) -> Self::Output {
         let inner_result = self.id.with_unwrapped_global(|integer_key| {
-            let borrowed = rhs.borrow();
-            <crate::integer::ServerKey as ServerKeyDefaultGt<_, _>>::gt(
-                integer_key.pbs_key(),
+            integer_key.apply_binary_op(
                 &self.ciphertext,
-                &borrowed.ciphertext,
+                &rhs.borrow().ciphertext,
+                PrecisionGrowth::Wrapping,
+                |sks, lhs, rhs| {
+                    <crate::integer::ServerKey as ServerKeyDefaultGt<_, _>>::gt(sks, lhs, rhs)
+                },
             )
         });
         GenericInteger::new(inner_result, self.id)
@@ -431,39 +565,117 @@ where
 
     fn ge(&self, rhs: B) -> Self::Output {
         let inner_result = self.id.with_unwrapped_global(|integer_key| {
-            let borrowed = rhs.borrow();
-            <crate::integer::ServerKey as ServerKeyDefaultGe<_, _>>::ge(
-                integer_key.pbs_key(),
+            integer_key.apply_binary_op(
                 &self.ciphertext,
-                &borrowed.ciphertext,
+                &rhs.borrow().ciphertext,
+                PrecisionGrowth::Wrapping,
+                |sks, lhs, rhs| {
+                    <crate::integer::ServerKey as ServerKeyDefaultGe<_, _>>::ge(sks, lhs, rhs)
+                },
             )
         });
         GenericInteger::new(inner_result, self.id)
     }
 }
 
+/// The operands are extended to the wide type before the addition, so its result is always
+/// exact.
+///
+/// # Example
+///
+/// ```rust
+/// # #[cfg(feature = "integer")]
+/// # {
+/// use tfhe::prelude::*;
+/// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint16, FheUint8};
+///
+/// let config = ConfigBuilder::all_disabled().enable_default_integers().build();
+/// let (client_key, server_key) = generate_keys(config);
+/// set_server_key(server_key);
+///
+/// let a = FheUint8::encrypt(200u8, &client_key);
+/// let b = FheUint8::encrypt(100u8, &client_key);
+///
+/// let sum: FheUint16 = a.widening_add(&b);
+/// let sum: u16 = sum.decrypt(&client_key);
+/// assert_eq!(sum, 300);
+/// # }
+/// ```
+impl<P, B> FheWideningAdd<B> for GenericInteger<P>
+where
+    P: WideningIntegerParameter,
+    <P::Wide as ParameterType>::Id: Default + WithGlobalKey<Key = IntegerServerKey>,
+    GenericInteger<P>: Clone,
+    B: Borrow<Self>,
+{
+    type Output = GenericInteger<P::Wide>;
+
+    fn widening_add(&self, rhs: B) -> Self::Output {
+        let lhs: GenericInteger<P::Wide> = self.clone().cast_into();
+        let rhs: GenericInteger<P::Wide> = rhs.borrow().clone().cast_into();
+        lhs + rhs
+    }
+}
+
+/// The operands are extended to the wide type before the multiplication, so its result is always
+/// exact.
+///
+/// # Example
+///
+/// ```rust
+/// # #[cfg(feature = "integer")]
+/// # {
+/// use tfhe::prelude::*;
+/// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint16, FheUint8};
+///
+/// let config = ConfigBuilder::all_disabled().enable_default_integers().build();
+/// let (client_key, server_key) = generate_keys(config);
+/// set_server_key(server_key);
+///
+/// let a = FheUint8::encrypt(200u8, &client_key);
+/// let b = FheUint8::encrypt(100u8, &client_key);
+///
+/// let product: FheUint16 = a.widening_mul(&b);
+/// let product: u16 = product.decrypt(&client_key);
+/// assert_eq!(product, 20000);
+/// # }
+/// ```
+impl<P, B> FheWideningMul<B> for GenericInteger<P>
+where
+    P: WideningIntegerParameter,
+    <P::Wide as ParameterType>::Id: Default + WithGlobalKey<Key = IntegerServerKey>,
+    GenericInteger<P>: Clone,
+    B: Borrow<Self>,
+{
+    type Output = GenericInteger<P::Wide>;
+
+    fn widening_mul(&self, rhs: B) -> Self::Output {
+        let lhs: GenericInteger<P::Wide> = self.clone().cast_into();
+        let rhs: GenericInteger<P::Wide> = rhs.borrow().clone().cast_into();
+        lhs * rhs
+    }
+}
+
 impl<P> FheBootstrap for GenericInteger<P>
 where
     P: IntegerParameter,
-    P::Id: WithGlobalKey<Key = IntegerServerKey>,
+    P::Id: WithGlobalKey<Key = IntegerServerKey> + TypeIdentifier,
     crate::integer::wopbs::WopbsKey:
         crate::high_level_api::integers::server_key::WopbsEvaluationKey<
             crate::integer::ServerKey,
             RadixCiphertextDyn,
         >,
 {
+    /// # Panics
+    ///
+    /// Panics if function evaluation on integers was not enabled in the config, see
+    /// [GenericInteger::try_map].
+    #[track_caller]
     fn map<F: Fn(u64) -> u64>(&self, func: F) -> Self {
-        use crate::high_level_api::integers::server_key::WopbsEvaluationKey;
-        self.id.with_unwrapped_global(|integer_key| {
-            let res = integer_key
-                .wopbs_key
-                .as_ref()
-                .expect("Function evalutation on integers was not enabled in the config")
-                .apply_wopbs(integer_key.pbs_key(), &self.ciphertext, func);
-            GenericInteger::<P>::new(res, self.id)
-        })
+        self.try_map(func).unwrap_display()
     }
 
+    #[track_caller]
     fn apply<F: Fn(u64) -> u64>(&mut self, func: F) {
         let result = self.map(func);
         *self = result;
@@ -473,33 +685,231 @@ where
 impl<P> GenericInteger<P>
 where
     P: IntegerParameter,
-    P::Id: WithGlobalKey<Key = IntegerServerKey>,
+    P::Id: WithGlobalKey<Key = IntegerServerKey> + TypeIdentifier,
     crate::integer::wopbs::WopbsKey:
         crate::high_level_api::integers::server_key::WopbsEvaluationKey<
             crate::integer::ServerKey,
             RadixCiphertextDyn,
         >,
 {
+    /// Computes a function over the encrypted message, like [FheBootstrap::map]
+    ///
+    /// Returns an [Error::MissingKey](crate::Error::MissingKey) naming the WoPBS key instead of
+    /// panicking if function evaluation on integers was not enabled in the config, and an
+    /// [Error::UninitializedServerKey](crate::Error::UninitializedServerKey) if the server key
+    /// was not set.
+    pub fn try_map<F: Fn(u64) -> u64>(&self, func: F) -> Result<Self, crate::Error> {
+        use crate::high_level_api::integers::server_key::WopbsEvaluationKey;
+        self.id
+            .with_global(|integer_key| -> Result<Self, crate::Error> {
+                let wopbs_key = integer_key
+                    .wopbs_key
+                    .as_ref()
+                    .ok_or_else(|| self.missing_wopbs_key())?;
+                let res = wopbs_key.apply_wopbs(integer_key.pbs_key(), &self.ciphertext, func);
+                Ok(GenericInteger::<P>::new(res, self.id))
+            })?
+    }
+
+    /// # Panics
+    ///
+    /// Panics if function evaluation on integers was not enabled in the config, see
+    /// [GenericInteger::try_bivariate_function].
+    #[track_caller]
     pub fn bivariate_function<F>(&self, other: &Self, func: F) -> Self
+    where
+        F: Fn(u64, u64) -> u64,
+    {
+        self.try_bivariate_function(other, func).unwrap_display()
+    }
+
+    /// Computes a function over two encrypted messages, like [GenericInteger::bivariate_function]
+    ///
+    /// Returns an [Error::MissingKey](crate::Error::MissingKey) naming the WoPBS key instead of
+    /// panicking if function evaluation on integers was not enabled in the config.
+    pub fn try_bivariate_function<F>(&self, other: &Self, func: F) -> Result<Self, crate::Error>
     where
         F: Fn(u64, u64) -> u64,
     {
         use crate::high_level_api::integers::server_key::WopbsEvaluationKey;
-        self.id.with_unwrapped_global(|integer_key| {
-            let lhs = &self.ciphertext;
-            let rhs = &other.ciphertext;
-            let res = integer_key
-                .wopbs_key
-                .as_ref()
-                .expect("Function evalutation on integers was not enabled in the config")
-                .apply_bivariate_wopbs(integer_key.pbs_key(), lhs, rhs, func);
-            GenericInteger::<P>::new(res, self.id)
-        })
+        self.id
+            .with_global(|integer_key| -> Result<Self, crate::Error> {
+                let lhs = &self.ciphertext;
+                let rhs = &other.ciphertext;
+                let wopbs_key = integer_key
+                    .wopbs_key
+                    .as_ref()
+                    .ok_or_else(|| self.missing_wopbs_key())?;
+                let res = wopbs_key.apply_bivariate_wopbs(integer_key.pbs_key(), lhs, rhs, func);
+                Ok(GenericInteger::<P>::new(res, self.id))
+            })?
+    }
+
+    fn missing_wopbs_key(&self) -> MissingKey {
+        MissingKey {
+            key_name: "WoPBS key",
+            ty: self.id.type_variant(),
+        }
+    }
+}
+
+impl<P> ResultEnvelopeElement for GenericInteger<P>
+where
+    P: IntegerParameter,
+    P::Id: WithGlobalKey<Key = IntegerServerKey> + TypeIdentifier,
+{
+    fn push_to(mut self, envelope: &mut ResultEnvelope, bootstrap: bool) {
+        let parameters_fingerprint = self.id.with_unwrapped_global(|integer_key| {
+            let shortint_key = &integer_key.pbs_key().key;
+            match &mut self.ciphertext {
+                RadixCiphertextDyn::Big(ct) => {
+                    if bootstrap {
+                        // Bootstrapping every block also empties their carries
+                        integer_key.pbs_key().full_propagate_parallelized(ct);
+                    }
+                    RadixFingerprintParameters::from_server_key(
+                        shortint_key,
+                        EncryptionKeyChoice::Big,
+                    )
+                    .fingerprint()
+                }
+                RadixCiphertextDyn::Small(ct) => {
+                    if bootstrap {
+                        integer_key.pbs_key().full_propagate_parallelized(ct);
+                    }
+                    RadixFingerprintParameters::from_server_key(
+                        shortint_key,
+                        EncryptionKeyChoice::Small,
+                    )
+                    .fingerprint()
+                }
+            }
+        });
+        envelope.push_entry(EnvelopeEntry {
+            data_type: self.id.type_variant(),
+            parameters_fingerprint: Some(parameters_fingerprint),
+            bootstrapped: bootstrap,
+            data: EnvelopeData::Radix(self.ciphertext),
+        });
+    }
+}
+
+impl<P> FromResultEnvelope for GenericInteger<P>
+where
+    P: IntegerParameter,
+    P::Id: Default + TypeIdentifier,
+{
+    fn data_type() -> Type {
+        P::Id::default().type_variant()
+    }
+
+    fn parameters_fingerprint(client_key: &ClientKey) -> Option<u64> {
+        let parameters = client_key.integer_key.key.as_ref()?.parameters();
+        let encryption_type = client_key.integer_key.encryption_type();
+        Some(
+            RadixFingerprintParameters::from_parameters(&parameters, encryption_type).fingerprint(),
+        )
+    }
+
+    fn extract_from(envelope: &ResultEnvelope, index: usize) -> Self {
+        match envelope.entry_data(index) {
+            EnvelopeData::Radix(ciphertext) => Self::new(ciphertext.clone(), P::Id::default()),
+            #[allow(unreachable_patterns)]
+            _ => unreachable!("The element at index {index} is not a radix ciphertext"),
+        }
+    }
+}
+
+/// The parameters of the blocks of radix ciphertexts which are part of their fingerprint.
+///
+/// These are everything the server key is made of: the encryption key choice, the dimensions of
+/// the keys, the decompositions of the bootstrapping and key switching keys, the message and
+/// carry moduli and the ciphertext modulus. As for booleans, the noise distributions and the
+/// distribution of the secret key can not be read back from a server key, they are not part of
+/// the fingerprint.
+struct RadixFingerprintParameters {
+    encryption_type: EncryptionKeyChoice,
+    lwe_dimension: LweDimension,
+    glwe_dimension: GlweDimension,
+    polynomial_size: PolynomialSize,
+    pbs_base_log: DecompositionBaseLog,
+    pbs_level: DecompositionLevelCount,
+    ks_base_log: DecompositionBaseLog,
+    ks_level: DecompositionLevelCount,
+    message_modulus: MessageModulus,
+    carry_modulus: CarryModulus,
+    ciphertext_modulus: CiphertextModulus,
+}
+
+impl RadixFingerprintParameters {
+    fn from_server_key(
+        key: &crate::shortint::ServerKey,
+        encryption_type: EncryptionKeyChoice,
+    ) -> Self {
+        let bootstrapping_key = &key.bootstrapping_key;
+        let key_switching_key = &key.key_switching_key;
+        Self {
+            encryption_type,
+            lwe_dimension: bootstrapping_key.input_lwe_dimension(),
+            glwe_dimension: bootstrapping_key.glwe_size().to_glwe_dimension(),
+            polynomial_size: bootstrapping_key.polynomial_size(),
+            pbs_base_log: bootstrapping_key.decomposition_base_log(),
+            pbs_level: bootstrapping_key.decomposition_level_count(),
+            ks_base_log: key_switching_key.decomposition_base_log(),
+            ks_level: key_switching_key.decomposition_level_count(),
+            message_modulus: key.message_modulus,
+            carry_modulus: key.carry_modulus,
+            ciphertext_modulus: key.ciphertext_modulus,
+        }
+    }
+
+    fn from_parameters(
+        parameters: &crate::shortint::PBSParameters,
+        encryption_type: EncryptionKeyChoice,
+    ) -> Self {
+        Self {
+            encryption_type,
+            lwe_dimension: parameters.lwe_dimension,
+            glwe_dimension: parameters.glwe_dimension,
+            polynomial_size: parameters.polynomial_size,
+            pbs_base_log: parameters.pbs_base_log,
+            pbs_level: parameters.pbs_level,
+            ks_base_log: parameters.ks_base_log,
+            ks_level: parameters.ks_level,
+            message_modulus: parameters.message_modulus,
+            carry_modulus: parameters.carry_modulus,
+            ciphertext_modulus: parameters.ciphertext_modulus,
+        }
+    }
+
+    fn fingerprint(&self) -> u64 {
+        let encryption_type = match self.encryption_type {
+            EncryptionKeyChoice::Big => 0,
+            EncryptionKeyChoice::Small => 1,
+        };
+        let ciphertext_modulus = if self.ciphertext_modulus.is_native_modulus() {
+            0
+        } else {
+            self.ciphertext_modulus.get_custom_modulus() as u64
+        };
+        parameters_fingerprint(&[
+            encryption_type,
+            self.lwe_dimension.0 as u64,
+            self.glwe_dimension.0 as u64,
+            self.polynomial_size.0 as u64,
+            self.pbs_base_log.0 as u64,
+            self.pbs_level.0 as u64,
+            self.ks_base_log.0 as u64,
+            self.ks_level.0 as u64,
+            self.message_modulus.0 as u64,
+            self.carry_modulus.0 as u64,
+            ciphertext_modulus,
+        ])
     }
 }
 
 macro_rules! generic_integer_impl_operation (
-    ($rust_trait_name:ident($rust_trait_method:ident,$op:tt) => $trait_name:ident($trait_method:ident)) => {
+    ($rust_trait_name:ident($rust_trait_method:ident,$op:tt) => $trait_name:ident($trait_method:ident), $growth:ident) => {
         #[doc = concat!(" Allows using the `", stringify!($op), "` operator between a")]
         #[doc = " `GenericInteger` and a `GenericInteger` or a `&GenericInteger`"]
         #[doc = " "]
@@ -586,11 +996,15 @@ macro_rules! generic_integer_impl_operation (
 
             fn $rust_trait_method(self, rhs: B) -> Self::Output {
                 let ciphertext = self.id.with_unwrapped_global(|integer_key| {
-                    let borrowed = rhs.borrow();
-                    <crate::integer::ServerKey as $trait_name<_, _>>::$trait_method(
-                        integer_key.pbs_key(),
+                    integer_key.apply_binary_op(
                         &self.ciphertext,
-                        &borrowed.ciphertext,
+                        &rhs.borrow().ciphertext,
+                        PrecisionGrowth::$growth,
+                        |sks, lhs, rhs| {
+                            <crate::integer::ServerKey as $trait_name<_, _>>::$trait_method(
+                                sks, lhs, rhs,
+                            )
+                        },
                     )
                 });
                 GenericInteger::<P>::new(ciphertext, self.id)
@@ -600,7 +1014,7 @@ macro_rules! generic_integer_impl_operation (
 );
 
 macro_rules! generic_integer_impl_operation_assign (
-    ($rust_trait_name:ident($rust_trait_method:ident, $op:tt) => $assign_trait:ident($assign_trait_method:ident)) => {
+    ($rust_trait_name:ident($rust_trait_method:ident, $op:tt) => $assign_trait:ident($assign_trait_method:ident), $growth:ident) => {
         impl<P, I> $rust_trait_name<I> for GenericInteger<P>
         where
             P: IntegerParameter,
@@ -610,10 +1024,15 @@ macro_rules! generic_integer_impl_operation_assign (
         {
             fn $rust_trait_method(&mut self, rhs: I) {
                 self.id.with_unwrapped_global(|integer_key| {
-                    <crate::integer::ServerKey as $assign_trait<_, _>>::$assign_trait_method(
-                        integer_key.pbs_key(),
+                    integer_key.apply_binary_op_assign(
                         &mut self.ciphertext,
-                        &rhs.borrow().ciphertext
+                        &rhs.borrow().ciphertext,
+                        PrecisionGrowth::$growth,
+                        |sks, lhs, rhs| {
+                            <crate::integer::ServerKey as $assign_trait<_, _>>::$assign_trait_method(
+                                sks, lhs, rhs,
+                            )
+                        },
                     )
                 })
             }
@@ -622,7 +1041,7 @@ macro_rules! generic_integer_impl_operation_assign (
 );
 
 macro_rules! generic_integer_impl_scalar_operation {
-    ($rust_trait_name:ident($rust_trait_method:ident) => $trait:ident($trait_method:ident($($scalar_type:ty),*))) => {
+    ($rust_trait_name:ident($rust_trait_method:ident) => $trait:ident($trait_method:ident($($scalar_type:ty),*)), $growth:ident) => {
         $(
             impl<P> $rust_trait_name<$scalar_type> for GenericInteger<P>
             where
@@ -646,10 +1065,15 @@ macro_rules! generic_integer_impl_scalar_operation {
                 fn $rust_trait_method(self, rhs: $scalar_type) -> Self::Output {
                     let ciphertext: RadixCiphertextDyn =
                         self.id.with_unwrapped_global(|integer_key| {
-                            <crate::integer::ServerKey as $trait<_, u64>>::$trait_method(
-                                integer_key.pbs_key(),
+                            integer_key.apply_scalar_op(
                                 &self.ciphertext,
-                                u64::from(rhs)
+                                u64::from(rhs),
+                                PrecisionGrowth::$growth,
+                                |sks, lhs, rhs| {
+                                    <crate::integer::ServerKey as $trait<_, u64>>::$trait_method(
+                                        sks, lhs, rhs,
+                                    )
+                                },
                             )
                         });
 
@@ -661,7 +1085,7 @@ macro_rules! generic_integer_impl_scalar_operation {
 }
 
 macro_rules! generic_integer_impl_scalar_operation_assign {
-    ($rust_trait_name:ident($rust_trait_method:ident) => $assign_trait:ident($assign_trait_method:ident($($scalar_type:ty),*))) => {
+    ($rust_trait_name:ident($rust_trait_method:ident) => $assign_trait:ident($assign_trait_method:ident($($scalar_type:ty),*)), $growth:ident) => {
         $(
             impl<P> $rust_trait_name<$scalar_type> for GenericInteger<P>
                 where
@@ -671,10 +1095,15 @@ macro_rules! generic_integer_impl_scalar_operation_assign {
             {
                 fn $rust_trait_method(&mut self, rhs: $scalar_type) {
                     self.id.with_unwrapped_global(|integer_key| {
-                        <crate::integer::ServerKey as $assign_trait<_, _>>::$assign_trait_method(
-                            integer_key.pbs_key(),
+                        integer_key.apply_scalar_op_assign(
                             &mut self.ciphertext,
-                            u64::from(rhs)
+                            u64::from(rhs),
+                            PrecisionGrowth::$growth,
+                            |sks, lhs, rhs| {
+                                <crate::integer::ServerKey as $assign_trait<_, _>>::$assign_trait_method(
+                                    sks, lhs, rhs,
+                                )
+                            },
                         )
                     })
                 }
@@ -683,31 +1112,31 @@ macro_rules! generic_integer_impl_scalar_operation_assign {
     }
 }
 
-generic_integer_impl_operation!(Add(add,+) => ServerKeyDefaultAdd(add));
-generic_integer_impl_operation!(Sub(sub,-) => ServerKeyDefaultSub(sub));
-generic_integer_impl_operation!(Mul(mul,*) => ServerKeyDefaultMul(mul));
-generic_integer_impl_operation!(BitAnd(bitand,&) => ServerKeyDefaultBitAnd(bitand));
-generic_integer_impl_operation!(BitOr(bitor,|) => ServerKeyDefaultBitOr(bitor));
-generic_integer_impl_operation!(BitXor(bitxor,^) => ServerKeyDefaultBitXor(bitxor));
+generic_integer_impl_operation!(Add(add,+) => ServerKeyDefaultAdd(add), Add);
+generic_integer_impl_operation!(Sub(sub,-) => ServerKeyDefaultSub(sub), Wrapping);
+generic_integer_impl_operation!(Mul(mul,*) => ServerKeyDefaultMul(mul), Mul);
+generic_integer_impl_operation!(BitAnd(bitand,&) => ServerKeyDefaultBitAnd(bitand), Wrapping);
+generic_integer_impl_operation!(BitOr(bitor,|) => ServerKeyDefaultBitOr(bitor), Wrapping);
+generic_integer_impl_operation!(BitXor(bitxor,^) => ServerKeyDefaultBitXor(bitxor), Wrapping);
 
-generic_integer_impl_operation_assign!(AddAssign(add_assign,+=) => ServerKeyDefaultAddAssign(add_assign));
-generic_integer_impl_operation_assign!(SubAssign(sub_assign,-=) => ServerKeyDefaultSubAssign(sub_assign));
-generic_integer_impl_operation_assign!(MulAssign(mul_assign,*=) => ServerKeyDefaultMulAssign(mul_assign));
-generic_integer_impl_operation_assign!(BitAndAssign(bitand_assign,&=) => ServerKeyDefaultBitAndAssign(bitand_assign));
-generic_integer_impl_operation_assign!(BitOrAssign(bitor_assign,|=) => ServerKeyDefaultBitOrAssign(bitor_assign));
-generic_integer_impl_operation_assign!(BitXorAssign(bitxor_assign,^=) => ServerKeyDefaultBitXorAssign(bitxor_assign));
+generic_integer_impl_operation_assign!(AddAssign(add_assign,+=) => ServerKeyDefaultAddAssign(add_assign), Add);
+generic_integer_impl_operation_assign!(SubAssign(sub_assign,-=) => ServerKeyDefaultSubAssign(sub_assign), Wrapping);
+generic_integer_impl_operation_assign!(MulAssign(mul_assign,*=) => ServerKeyDefaultMulAssign(mul_assign), Mul);
+generic_integer_impl_operation_assign!(BitAndAssign(bitand_assign,&=) => ServerKeyDefaultBitAndAssign(bitand_assign), Wrapping);
+generic_integer_impl_operation_assign!(BitOrAssign(bitor_assign,|=) => ServerKeyDefaultBitOrAssign(bitor_assign), Wrapping);
+generic_integer_impl_operation_assign!(BitXorAssign(bitxor_assign,^=) => ServerKeyDefaultBitXorAssign(bitxor_assign), Wrapping);
 
-generic_integer_impl_scalar_operation!(Add(add) => ServerKeyDefaultAdd(add(u8, u16, u32, u64)));
-generic_integer_impl_scalar_operation!(Sub(sub) => ServerKeyDefaultSub(sub(u8, u16, u32, u64)));
-generic_integer_impl_scalar_operation!(Mul(mul) => ServerKeyDefaultMul(mul(u8, u16, u32, u64)));
-generic_integer_impl_scalar_operation!(Shl(shl) => ServerKeyDefaultShl(shl(u8, u16, u32, u64)));
-generic_integer_impl_scalar_operation!(Shr(shr) => ServerKeyDefaultShr(shr(u8, u16, u32, u64)));
+generic_integer_impl_scalar_operation!(Add(add) => ServerKeyDefaultAdd(add(u8, u16, u32, u64)), Add);
+generic_integer_impl_scalar_operation!(Sub(sub) => ServerKeyDefaultSub(sub(u8, u16, u32, u64)), Wrapping);
+generic_integer_impl_scalar_operation!(Mul(mul) => ServerKeyDefaultMul(mul(u8, u16, u32, u64)), Mul);
+generic_integer_impl_scalar_operation!(Shl(shl) => ServerKeyDefaultShl(shl(u8, u16, u32, u64)), Wrapping);
+generic_integer_impl_scalar_operation!(Shr(shr) => ServerKeyDefaultShr(shr(u8, u16, u32, u64)), Wrapping);
 
-generic_integer_impl_scalar_operation_assign!(AddAssign(add_assign) => ServerKeyDefaultAddAssign(add_assign(u8, u16, u32, u64)));
-generic_integer_impl_scalar_operation_assign!(SubAssign(sub_assign) => ServerKeyDefaultSubAssign(sub_assign(u8, u16, u32, u64)));
-generic_integer_impl_scalar_operation_assign!(MulAssign(mul_assign) => ServerKeyDefaultMulAssign(mul_assign(u8, u16, u32, u64)));
-generic_integer_impl_scalar_operation_assign!(ShlAssign(shl_assign) => ServerKeyDefaultShlAssign(shl_assign(u8, u16, u32, u64)));
-generic_integer_impl_scalar_operation_assign!(ShrAssign(shr_assign) => ServerKeyDefaultShrAssign(shr_assign(u8, u16, u32, u64)));
+generic_integer_impl_scalar_operation_assign!(AddAssign(add_assign) => ServerKeyDefaultAddAssign(add_assign(u8, u16, u32, u64)), Add);
+generic_integer_impl_scalar_operation_assign!(SubAssign(sub_assign) => ServerKeyDefaultSubAssign(sub_assign(u8, u16, u32, u64)), Wrapping);
+generic_integer_impl_scalar_operation_assign!(MulAssign(mul_assign) => ServerKeyDefaultMulAssign(mul_assign(u8, u16, u32, u64)), Mul);
+generic_integer_impl_scalar_operation_assign!(ShlAssign(shl_assign) => ServerKeyDefaultShlAssign(shl_assign(u8, u16, u32, u64)), Wrapping);
+generic_integer_impl_scalar_operation_assign!(ShrAssign(shr_assign) => ServerKeyDefaultShrAssign(shr_assign(u8, u16, u32, u64)), Wrapping);
 
 impl<P> Neg for GenericInteger<P>
 where
@@ -742,3 +1171,103 @@ where
         GenericInteger::<P>::new(ciphertext, self.id)
     }
 }
+
+/// Combines the items as a balanced binary tree, so that an item goes through a logarithmic
+/// number of operations instead of a linear one in a left fold.
+///
+/// The items of the first level are only borrowed, an item is cloned only if it is alone.
+fn reduce_as_balanced_tree<P, B, F>(
+    iter: impl Iterator<Item = B>,
+    op: F,
+) -> Option<GenericInteger<P>>
+where
+    P: IntegerParameter,
+    B: Borrow<GenericInteger<P>>,
+    GenericInteger<P>: Clone,
+    F: Fn(&GenericInteger<P>, &GenericInteger<P>) -> GenericInteger<P>,
+{
+    let items = iter.collect::<Vec<_>>();
+    if items.len() == 1 {
+        return Some(items[0].borrow().clone());
+    }
+
+    let mut level = Vec::with_capacity(items.len() / 2);
+    for pair in items.chunks(2) {
+        match pair {
+            [lhs, rhs] => level.push(op(lhs.borrow(), rhs.borrow())),
+            [remainder] => {
+                let last = level.last_mut().unwrap();
+                *last = op(last, remainder.borrow());
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    while level.len() > 1 {
+        let mut next_level = Vec::with_capacity((level.len() + 1) / 2);
+        let mut current_level = level.into_iter();
+        while let Some(lhs) = current_level.next() {
+            match current_level.next() {
+                Some(rhs) => next_level.push(op(&lhs, &rhs)),
+                None => next_level.push(lhs),
+            }
+        }
+        level = next_level;
+    }
+
+    level.pop()
+}
+
+/// Sums the items as a balanced tree, the sum of no items is a trivial encryption of zero.
+///
+/// # Example
+///
+/// ```
+/// # fn main() -> Result<(), tfhe::Error> {
+/// use tfhe::prelude::*;
+/// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint8};
+///
+/// let config = ConfigBuilder::all_disabled()
+///     .enable_default_integers()
+///     .build();
+/// let (keys, server_key) = generate_keys(config);
+///
+/// let values = [FheUint8::try_encrypt(12u8, &keys)?, FheUint8::try_encrypt(30u8, &keys)?];
+///
+/// set_server_key(server_key);
+///
+/// let sum: FheUint8 = values.iter().sum();
+/// let decrypted: u8 = sum.decrypt(&keys);
+/// assert_eq!(decrypted, 42);
+/// # Ok(())
+/// # }
+/// ```
+impl<P, B> std::iter::Sum<B> for GenericInteger<P>
+where
+    B: Borrow<Self>,
+    P: IntegerParameter,
+    Self: FheTryTrivialEncrypt<u8> + Clone,
+    for<'a> &'a Self: Add<&'a Self, Output = Self>,
+{
+    fn sum<I: Iterator<Item = B>>(iter: I) -> Self {
+        reduce_as_balanced_tree(iter, |lhs, rhs| lhs + rhs).unwrap_or_else(|| {
+            Self::try_encrypt_trivial(0u8).expect("Failed to trivially encrypt zero")
+        })
+    }
+}
+
+/// Multiplies the items as a balanced tree, the product of no items is a trivial encryption of
+/// one.
+impl<P, B> std::iter::Product<B> for GenericInteger<P>
+where
+    B: Borrow<Self>,
+    P: IntegerParameter,
+    Self: FheTryTrivialEncrypt<u8> + Clone,
+    for<'a> &'a Self: Mul<&'a Self, Output = Self>,
+{
+    fn product<I: Iterator<Item = B>>(iter: I) -> Self {
+        reduce_as_balanced_tree(iter, |lhs, rhs| lhs * rhs).unwrap_or_else(|| {
+            Self::try_encrypt_trivial(1u8).expect("Failed to trivially encrypt one")
+        })
+    }
+}
@@ -1,4 +1,8 @@
-use crate::errors::{UninitializedClientKey, UnwrapResultExt};
+use crate::errors::{Type, UninitializedClientKey};
+use crate::high_level_api::compressed_list::{
+    CompressedCiphertextList, CompressedListData, CompressedListElement, CompressedListEntry,
+    FromCompressedList,
+};
 use crate::high_level_api::integers::parameters::IntegerParameter;
 use crate::high_level_api::integers::server_key::RadixCiphertextDyn;
 use crate::high_level_api::integers::types::base::GenericInteger;
@@ -8,7 +12,7 @@ use crate::high_level_api::ClientKey;
 use crate::integer::U256;
 
 #[derive(Clone, serde::Serialize, serde::Deserialize)]
-pub(in crate::high_level_api::integers) enum CompressedRadixCiphertextDyn {
+pub(in crate::high_level_api) enum CompressedRadixCiphertextDyn {
     Big(crate::integer::CompressedRadixCiphertextBig),
     Small(crate::integer::CompressedRadixCiphertextSmall),
 }
@@ -71,8 +75,7 @@ where
             .integer_key
             .key
             .as_ref()
-            .ok_or(UninitializedClientKey(id.type_variant()))
-            .unwrap_display();
+            .ok_or(UninitializedClientKey(id.type_variant()))?;
         let inner = match key.integer_key.encryption_type() {
             crate::shortint::EncryptionKeyChoice::Big => CompressedRadixCiphertextDyn::Big(
                 integer_client_key.encrypt_radix_compressed(value, P::num_blocks()),
@@ -84,3 +87,36 @@ where
         Ok(Self::new(inner, id))
     }
 }
+
+impl<P> CompressedListElement for CompressedGenericInteger<P>
+where
+    P: IntegerParameter,
+    P::Id: TypeIdentifier,
+{
+    fn push_to(self, list: &mut CompressedCiphertextList) {
+        list.entries.push(CompressedListEntry {
+            data_type: self.id.type_variant(),
+            data: CompressedListData::Radix(self.ciphertext),
+        });
+    }
+}
+
+impl<P> FromCompressedList for GenericInteger<P>
+where
+    P: IntegerParameter,
+    P::Id: Default + TypeIdentifier,
+{
+    fn data_type() -> Type {
+        P::Id::default().type_variant()
+    }
+
+    fn expand_from(list: &CompressedCiphertextList, index: usize) -> Self {
+        match &list.entries[index].data {
+            CompressedListData::Radix(ciphertext) => {
+                Self::new(ciphertext.clone().into(), P::Id::default())
+            }
+            #[allow(unreachable_patterns)]
+            _ => unreachable!("The element at index {index} is not a radix ciphertext"),
+        }
+    }
+}
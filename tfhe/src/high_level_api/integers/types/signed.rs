@@ -0,0 +1,253 @@
+use std::borrow::Borrow;
+use std::ops::{Add, Mul, Neg, Sub};
+
+use crate::errors::UninitializedClientKey;
+use crate::high_level_api::global_state::WithGlobalKey;
+use crate::high_level_api::integers::parameters::IntegerParameter;
+use crate::high_level_api::integers::IntegerServerKey;
+use crate::high_level_api::internal_traits::TypeIdentifier;
+use crate::high_level_api::keys::RefKeyFromKeyChain;
+use crate::high_level_api::traits::{FheDecrypt, FheTryEncrypt};
+use crate::high_level_api::ClientKey;
+
+#[derive(Clone, serde::Deserialize, serde::Serialize)]
+pub(in crate::high_level_api) enum SignedRadixCiphertextDyn {
+    Big(crate::integer::SignedRadixCiphertextBig),
+    Small(crate::integer::SignedRadixCiphertextSmall),
+}
+
+/// Applies the same server key method to the big or small ciphertext of a
+/// `SignedRadixCiphertextDyn`, in the thread pool of the server key.
+macro_rules! map_signed_dyn {
+    ($integer_key:expr, ($lhs:expr, $rhs:expr), |$l:ident, $r:ident| $body:expr) => {
+        match ($lhs, $rhs) {
+            (SignedRadixCiphertextDyn::Big($l), SignedRadixCiphertextDyn::Big($r)) => {
+                SignedRadixCiphertextDyn::Big($integer_key.install(|| $body))
+            }
+            (SignedRadixCiphertextDyn::Small($l), SignedRadixCiphertextDyn::Small($r)) => {
+                SignedRadixCiphertextDyn::Small($integer_key.install(|| $body))
+            }
+            (_, _) => unreachable!("internal error: mismatched big and small integer"),
+        }
+    };
+    ($integer_key:expr, $ct:expr, |$inner:ident| $body:expr) => {
+        match $ct {
+            SignedRadixCiphertextDyn::Big($inner) => {
+                SignedRadixCiphertextDyn::Big($integer_key.install(|| $body))
+            }
+            SignedRadixCiphertextDyn::Small($inner) => {
+                SignedRadixCiphertextDyn::Small($integer_key.install(|| $body))
+            }
+        }
+    };
+}
+
+/// A Generic FHE signed integer
+///
+/// The signed counterpart of [GenericInteger](crate::high_level_api::GenericInteger): the
+/// integers are encoded in two's complement on the same number of blocks as the unsigned integer
+/// type of the same size, and the arithmetic operations wrap around like the `wrapping_*`
+/// operations of Rust's signed integers.
+///
+/// You will need to use one of this type specialization (e.g., [FheInt8], [FheInt16]), which are
+/// enabled with the unsigned integer types in the config.
+///
+/// [FheInt8]: crate::high_level_api::FheInt8
+/// [FheInt16]: crate::high_level_api::FheInt16
+#[cfg_attr(all(doc, not(doctest)), doc(cfg(feature = "integer")))]
+#[derive(Clone, serde::Deserialize, serde::Serialize)]
+pub struct GenericSignedInteger<P: IntegerParameter> {
+    pub(in crate::high_level_api::integers) ciphertext: SignedRadixCiphertextDyn,
+    pub(in crate::high_level_api::integers) id: P::Id,
+}
+
+impl<P> GenericSignedInteger<P>
+where
+    P: IntegerParameter,
+{
+    pub(in crate::high_level_api::integers) fn new(
+        ciphertext: SignedRadixCiphertextDyn,
+        id: P::Id,
+    ) -> Self {
+        Self { ciphertext, id }
+    }
+}
+
+impl<P, T> FheTryEncrypt<T, ClientKey> for GenericSignedInteger<P>
+where
+    T: Into<i128>,
+    P: IntegerParameter,
+    P::Id: Default + TypeIdentifier,
+{
+    type Error = crate::high_level_api::errors::Error;
+
+    fn try_encrypt(value: T, key: &ClientKey) -> Result<Self, Self::Error> {
+        let id = P::Id::default();
+        let integer_client_key = key
+            .integer_key
+            .key
+            .as_ref()
+            .ok_or(UninitializedClientKey(id.type_variant()))?;
+        let ciphertext = match key.integer_key.encryption_type() {
+            crate::shortint::EncryptionKeyChoice::Big => SignedRadixCiphertextDyn::Big(
+                integer_client_key.encrypt_signed_radix(value, P::num_blocks()),
+            ),
+            crate::shortint::EncryptionKeyChoice::Small => SignedRadixCiphertextDyn::Small(
+                integer_client_key.encrypt_signed_radix_small(value, P::num_blocks()),
+            ),
+        };
+        Ok(Self::new(ciphertext, id))
+    }
+}
+
+impl<P, ClearType> FheDecrypt<ClearType> for GenericSignedInteger<P>
+where
+    ClearType: TryFrom<i128>,
+    <ClearType as TryFrom<i128>>::Error: std::fmt::Debug,
+    P: IntegerParameter,
+    P::Id: RefKeyFromKeyChain<Key = crate::integer::ClientKey>,
+{
+    fn decrypt(&self, key: &ClientKey) -> ClearType {
+        let key = self.id.unwrapped_ref_key(key);
+        match &self.ciphertext {
+            SignedRadixCiphertextDyn::Big(ct) => key.decrypt_signed_radix(ct),
+            SignedRadixCiphertextDyn::Small(ct) => key.decrypt_signed_radix(ct),
+        }
+    }
+}
+
+impl<P> GenericSignedInteger<P>
+where
+    P: IntegerParameter,
+    P::Id: WithGlobalKey<Key = IntegerServerKey>,
+{
+    /// Computes the absolute value, the absolute value of the minimum value being itself, like
+    /// `wrapping_abs`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheInt8};
+    ///
+    /// let config = ConfigBuilder::all_disabled()
+    ///     .enable_default_integers()
+    ///     .build();
+    /// let (keys, server_key) = generate_keys(config);
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheInt8::encrypt(-57i8, &keys);
+    ///
+    /// let decrypted: i8 = a.abs().decrypt(&keys);
+    /// assert_eq!(decrypted, 57);
+    /// ```
+    pub fn abs(&self) -> Self {
+        let ciphertext = self.id.with_unwrapped_global(|integer_key| {
+            let integer_key = integer_key.pbs_key();
+            map_signed_dyn!(integer_key, &self.ciphertext, |ct| integer_key
+                .signed_abs_parallelized(ct))
+        });
+        Self::new(ciphertext, self.id)
+    }
+
+    /// Computes the sign: -1 if the value is negative, 0 if it is zero and 1 if it is positive.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheInt8};
+    ///
+    /// let config = ConfigBuilder::all_disabled()
+    ///     .enable_default_integers()
+    ///     .build();
+    /// let (keys, server_key) = generate_keys(config);
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheInt8::encrypt(-57i8, &keys);
+    ///
+    /// let decrypted: i8 = a.signum().decrypt(&keys);
+    /// assert_eq!(decrypted, -1);
+    /// ```
+    pub fn signum(&self) -> Self {
+        let ciphertext = self.id.with_unwrapped_global(|integer_key| {
+            let integer_key = integer_key.pbs_key();
+            map_signed_dyn!(integer_key, &self.ciphertext, |ct| integer_key
+                .signed_signum_parallelized(ct))
+        });
+        Self::new(ciphertext, self.id)
+    }
+}
+
+macro_rules! generic_signed_integer_impl_operation (
+    ($rust_trait_name:ident($rust_trait_method:ident, $op:tt) => $method:ident) => {
+        #[doc = concat!(" Allows using the `", stringify!($op), "` operator between a")]
+        #[doc = " `GenericSignedInteger` and a `GenericSignedInteger` or a"]
+        #[doc = " `&GenericSignedInteger`, the result wraps around on overflow."]
+        impl<P, B> $rust_trait_name<B> for GenericSignedInteger<P>
+        where
+            P: IntegerParameter,
+            P::Id: WithGlobalKey<Key = IntegerServerKey>,
+            B: Borrow<Self>,
+        {
+            type Output = Self;
+
+            fn $rust_trait_method(self, rhs: B) -> Self::Output {
+                <&Self as $rust_trait_name<B>>::$rust_trait_method(&self, rhs)
+            }
+        }
+
+        impl<P, B> $rust_trait_name<B> for &GenericSignedInteger<P>
+        where
+            P: IntegerParameter,
+            P::Id: WithGlobalKey<Key = IntegerServerKey>,
+            B: Borrow<GenericSignedInteger<P>>,
+        {
+            type Output = GenericSignedInteger<P>;
+
+            fn $rust_trait_method(self, rhs: B) -> Self::Output {
+                let ciphertext = self.id.with_unwrapped_global(|integer_key| {
+                    let integer_key = integer_key.pbs_key();
+                    let rhs = &rhs.borrow().ciphertext;
+                    map_signed_dyn!(integer_key, (&self.ciphertext, rhs), |lhs, rhs| {
+                        integer_key.$method(lhs, rhs)
+                    })
+                });
+                GenericSignedInteger::new(ciphertext, self.id)
+            }
+        }
+    }
+);
+
+generic_signed_integer_impl_operation!(Add(add, +) => signed_add_parallelized);
+generic_signed_integer_impl_operation!(Sub(sub, -) => signed_sub_parallelized);
+generic_signed_integer_impl_operation!(Mul(mul, *) => signed_mul_parallelized);
+
+impl<P> Neg for GenericSignedInteger<P>
+where
+    P: IntegerParameter,
+    P::Id: WithGlobalKey<Key = IntegerServerKey>,
+{
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        <&Self as Neg>::neg(&self)
+    }
+}
+
+impl<P> Neg for &GenericSignedInteger<P>
+where
+    P: IntegerParameter,
+    P::Id: WithGlobalKey<Key = IntegerServerKey>,
+{
+    type Output = GenericSignedInteger<P>;
+
+    fn neg(self) -> Self::Output {
+        let ciphertext = self.id.with_unwrapped_global(|integer_key| {
+            let integer_key = integer_key.pbs_key();
+            map_signed_dyn!(integer_key, &self.ciphertext, |ct| integer_key
+                .signed_neg_parallelized(ct))
+        });
+        GenericSignedInteger::new(ciphertext, self.id)
+    }
+}
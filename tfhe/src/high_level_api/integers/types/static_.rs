@@ -1,7 +1,10 @@
 use serde::{Deserialize, Serialize};
 
 use super::base::GenericInteger;
-use crate::high_level_api::integers::parameters::{EvaluationIntegerKey, IntegerParameter};
+use super::signed::GenericSignedInteger;
+use crate::high_level_api::integers::parameters::{
+    EvaluationIntegerKey, IntegerParameter, WideningIntegerParameter,
+};
 use crate::high_level_api::integers::types::compressed::CompressedGenericInteger;
 use crate::high_level_api::internal_traits::{ParameterType, TypeIdentifier};
 #[cfg(feature = "internal-keycache")]
@@ -127,6 +130,18 @@ macro_rules! static_int_type {
     };
 }
 
+macro_rules! impl_widening_integer_parameters {
+    ($($num_bits:literal => $wide_num_bits:literal),* $(,)?) => {
+        $(
+            paste! {
+                impl WideningIntegerParameter for [<FheUint $num_bits Parameters>] {
+                    type Wide = [<FheUint $wide_num_bits Parameters>];
+                }
+            }
+        )*
+    };
+}
+
 impl<C> EvaluationIntegerKey<C> for crate::integer::ServerKey
 where
     C: AsRef<crate::integer::ClientKey>,
@@ -251,3 +266,108 @@ static_int_type! {
         },
     }
 }
+
+// The signed integer types use the same client and server keys as the unsigned ones
+macro_rules! static_signed_int_type {
+    (
+        {
+            num_bits: $num_bits:literal,
+            parameters: Radix {
+                num_block: $num_block:literal,
+            },
+        }
+    ) => {
+        paste! {
+            #[doc = concat!("Id for the [FheInt", stringify!($num_bits), "] data type.")]
+            #[derive(Copy, Clone, Debug, Default, Serialize, Deserialize)]
+            pub struct [<FheInt $num_bits Id>];
+
+            #[doc = concat!("Parameters for the [FheInt", stringify!($num_bits), "] data type.")]
+            #[derive(Copy, Clone, Debug, Serialize, Deserialize)]
+            pub struct [<FheInt $num_bits Parameters>];
+
+            impl ParameterType for [<FheInt $num_bits Parameters>] {
+                type Id = [<FheInt $num_bits Id>];
+            }
+
+            impl IntegerParameter for [<FheInt $num_bits Parameters>] {
+                fn num_blocks() -> usize {
+                    $num_block
+                }
+            }
+
+            impl TypeIdentifier for [<FheInt $num_bits Id>] {
+                fn type_variant(&self) -> $crate::high_level_api::errors::Type {
+                    $crate::high_level_api::errors::Type::[<FheInt $num_bits>]
+                }
+            }
+
+            #[doc = concat!("A signed integer type with ", stringify!($num_bits), " bits")]
+            #[cfg_attr(all(doc, not(doctest)), cfg(feature = "integer"))]
+            pub type [<FheInt $num_bits>] = GenericSignedInteger<[<FheInt $num_bits Parameters>]>;
+
+            impl $crate::high_level_api::keys::RefKeyFromKeyChain for [<FheInt $num_bits Id>] {
+                type Key = crate::integer::ClientKey;
+
+                fn ref_key(self, keys: &crate::high_level_api::ClientKey)
+                    -> Result<&Self::Key, $crate::high_level_api::errors::UninitializedClientKey> {
+                    keys
+                        .integer_key
+                        .key
+                        .as_ref()
+                        .ok_or($crate::high_level_api::errors::UninitializedClientKey(self.type_variant()))
+                }
+            }
+
+            impl $crate::high_level_api::global_state::WithGlobalKey for [<FheInt $num_bits Id>] {
+                type Key = crate::high_level_api::integers::IntegerServerKey;
+
+                fn with_global<R, F>(self, func: F) -> Result<R, $crate::high_level_api::errors::UninitializedServerKey>
+                where
+                    F: FnOnce(&Self::Key) -> R {
+                    $crate::high_level_api::global_state::with_internal_keys(|keys| {
+                            Ok(func(&keys.integer_key))
+                        })
+                    }
+            }
+        }
+    };
+}
+
+static_signed_int_type! {
+    {
+        num_bits: 8,
+        parameters: Radix {
+            num_block: 4,
+        },
+    }
+}
+
+static_signed_int_type! {
+    {
+        num_bits: 16,
+        parameters: Radix {
+            num_block: 8,
+        },
+    }
+}
+
+static_signed_int_type! {
+    {
+        num_bits: 32,
+        parameters: Radix {
+            num_block: 16,
+        },
+    }
+}
+
+static_signed_int_type! {
+    {
+        num_bits: 64,
+        parameters: Radix {
+            num_block: 32,
+        },
+    }
+}
+
+impl_widening_integer_parameters!(8 => 16, 16 => 32, 32 => 64, 64 => 128, 128 => 256);
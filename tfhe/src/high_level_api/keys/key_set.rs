@@ -0,0 +1,465 @@
+//! This module defines KeySet
+//!
+//! - [KeySet] bundles the keys generated from the same [ClientKey] so that they can be stored and
+//!   transferred as a single artifact.
+//! - [ServerKeySet] is the part of a serialized [KeySet] needed by compute nodes.
+
+use std::collections::hash_map::RandomState;
+use std::fmt::{Display, Formatter};
+use std::hash::{BuildHasher, Hasher};
+
+use crate::high_level_api::config::Config;
+
+use super::{ClientKey, CompressedPublicKey, PublicKey, ServerKey};
+
+/// Version of the serialized layout of a [KeySet]
+const KEY_SET_FORMAT_VERSION: u32 = 1;
+
+/// Selects which keys a [KeySet] holds.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct KeySetComponents {
+    pub client_key: bool,
+    pub server_key: bool,
+    pub public_key: bool,
+    pub compressed_public_key: bool,
+}
+
+impl KeySetComponents {
+    pub const ALL: Self = Self {
+        client_key: true,
+        server_key: true,
+        public_key: true,
+        compressed_public_key: true,
+    };
+
+    /// The keys needed by a client that encrypts and decrypts, but does not compute
+    pub const CLIENT: Self = Self {
+        client_key: true,
+        server_key: false,
+        public_key: false,
+        compressed_public_key: false,
+    };
+
+    /// The keys needed by a compute node
+    pub const SERVER: Self = Self {
+        client_key: false,
+        server_key: true,
+        public_key: false,
+        compressed_public_key: false,
+    };
+
+    /// The keys that can be given to anyone
+    pub const PUBLIC: Self = Self {
+        client_key: false,
+        server_key: true,
+        public_key: true,
+        compressed_public_key: true,
+    };
+
+    /// Returns the components of `self` that are not in `other`
+    fn difference(self, other: Self) -> Self {
+        Self {
+            client_key: self.client_key && !other.client_key,
+            server_key: self.server_key && !other.server_key,
+            public_key: self.public_key && !other.public_key,
+            compressed_public_key: self.compressed_public_key && !other.compressed_public_key,
+        }
+    }
+
+    fn is_empty(self) -> bool {
+        !(self.client_key || self.server_key || self.public_key || self.compressed_public_key)
+    }
+}
+
+impl Display for KeySetComponents {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let names = [
+            (self.client_key, "client key"),
+            (self.server_key, "server key"),
+            (self.public_key, "public key"),
+            (self.compressed_public_key, "compressed public key"),
+        ]
+        .into_iter()
+        .filter_map(|(is_set, name)| is_set.then_some(name))
+        .collect::<Vec<_>>();
+
+        if names.is_empty() {
+            write!(f, "no key")
+        } else {
+            write!(f, "{}", names.join(", "))
+        }
+    }
+}
+
+/// Error returned when a [KeySet] does not hold the expected keys or could not be loaded
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum KeySetError {
+    /// The serialized key set uses a layout this version of the library cannot read
+    UnsupportedVersion(u32),
+    /// The key set does not hold the given components
+    MissingComponents(KeySetComponents),
+    /// The components announced in the header of a serialized key set do not match the
+    /// ones actually stored, the data was altered or truncated
+    CorruptedComponents {
+        expected: KeySetComponents,
+        found: KeySetComponents,
+    },
+    /// The keys come from different key sets
+    KeySetMismatch { expected: u64, found: u64 },
+}
+
+impl Display for KeySetError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnsupportedVersion(version) => {
+                write!(f, "Unsupported key set format version {version}")
+            }
+            Self::MissingComponents(missing) => {
+                write!(f, "The key set is missing the following keys: {missing}")
+            }
+            Self::CorruptedComponents { expected, found } => {
+                write!(
+                    f,
+                    "Corrupted key set, expected ({expected}) but found ({found})"
+                )
+            }
+            Self::KeySetMismatch { expected, found } => {
+                write!(
+                    f,
+                    "The keys belong to the key set {found:#018x}, expected {expected:#018x}"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for KeySetError {}
+
+/// Header stored in front of the keys of a serialized [KeySet]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+struct KeySetHeader {
+    version: u32,
+    id: u64,
+    components: KeySetComponents,
+}
+
+impl KeySetHeader {
+    fn check(&self, found: KeySetComponents) -> Result<(), KeySetError> {
+        if self.version != KEY_SET_FORMAT_VERSION {
+            return Err(KeySetError::UnsupportedVersion(self.version));
+        }
+        if self.components != found {
+            return Err(KeySetError::CorruptedComponents {
+                expected: self.components,
+                found,
+            });
+        }
+        Ok(())
+    }
+}
+
+fn new_key_set_id() -> u64 {
+    // The id only has to differ between key sets, not to be unpredictable,
+    // RandomState is seeded randomly by the standard library
+    RandomState::new().build_hasher().finish()
+}
+
+/// Keys generated from the same [ClientKey], stored as a single artifact.
+///
+/// Each key set has a random identifier, kept when keys are removed from the set with
+/// [KeySet::select], which allows to check that keys stored in different places were
+/// generated together.
+///
+/// When serialized, the keys are written after a header, the server key first and the
+/// client key last. This allows compute nodes to only read the beginning of the artifact
+/// using [ServerKeySet], see its documentation.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "shortint")]
+/// # {
+/// use tfhe::prelude::*;
+/// use tfhe::{set_server_key, ConfigBuilder, FheUint2, KeySet, KeySetComponents};
+///
+/// let config = ConfigBuilder::all_disabled().enable_default_uint2().build();
+/// let key_set = KeySet::generate(config, KeySetComponents::ALL);
+///
+/// let (client_key, server_key) = key_set.clone().into_client_and_server_keys().unwrap();
+/// set_server_key(server_key);
+///
+/// let public_key = key_set.public_key().unwrap();
+/// let a = FheUint2::try_encrypt(1u8, public_key).unwrap();
+/// let b = FheUint2::try_encrypt(2u8, public_key).unwrap();
+///
+/// let c: u8 = (a + b).decrypt(&client_key);
+/// assert_eq!(c, 3);
+///
+/// // Only keep what the server needs
+/// let server_key_set = key_set.select(KeySetComponents::SERVER).unwrap();
+/// assert!(server_key_set.client_key().is_none());
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct KeySet {
+    header: KeySetHeader,
+    client_key: Option<ClientKey>,
+    server_key: Option<ServerKey>,
+    public_key: Option<PublicKey>,
+    compressed_public_key: Option<CompressedPublicKey>,
+}
+
+impl KeySet {
+    /// Generates a new key set holding the requested components.
+    ///
+    /// A client key is always generated as the other keys derive from it, it is dropped
+    /// afterwards if it was not requested.
+    pub fn generate<C: Into<Config>>(config: C, components: KeySetComponents) -> Self {
+        let client_key = ClientKey::generate(config);
+        Self::from_client_key(client_key, components)
+    }
+
+    /// Creates a new key set, generating the requested components from the client key.
+    pub fn from_client_key(client_key: ClientKey, components: KeySetComponents) -> Self {
+        let server_key = components
+            .server_key
+            .then(|| client_key.generate_server_key());
+        let public_key = components.public_key.then(|| PublicKey::new(&client_key));
+        let compressed_public_key = components
+            .compressed_public_key
+            .then(|| CompressedPublicKey::new(&client_key));
+
+        Self {
+            header: KeySetHeader {
+                version: KEY_SET_FORMAT_VERSION,
+                id: new_key_set_id(),
+                components,
+            },
+            client_key: components.client_key.then_some(client_key),
+            server_key,
+            public_key,
+            compressed_public_key,
+        }
+    }
+
+    /// Returns the identifier shared by all the keys generated together
+    pub fn id(&self) -> u64 {
+        self.header.id
+    }
+
+    /// Returns which keys this set holds
+    pub fn components(&self) -> KeySetComponents {
+        self.header.components
+    }
+
+    pub fn client_key(&self) -> Option<&ClientKey> {
+        self.client_key.as_ref()
+    }
+
+    pub fn server_key(&self) -> Option<&ServerKey> {
+        self.server_key.as_ref()
+    }
+
+    pub fn public_key(&self) -> Option<&PublicKey> {
+        self.public_key.as_ref()
+    }
+
+    pub fn compressed_public_key(&self) -> Option<&CompressedPublicKey> {
+        self.compressed_public_key.as_ref()
+    }
+
+    /// Returns an error listing the components that are requested but not held by this set
+    pub fn require(&self, components: KeySetComponents) -> Result<(), KeySetError> {
+        let missing = components.difference(self.components());
+        if missing.is_empty() {
+            Ok(())
+        } else {
+            Err(KeySetError::MissingComponents(missing))
+        }
+    }
+
+    /// Returns a key set holding only the requested components.
+    ///
+    /// The returned set keeps the identifier of `self`.
+    pub fn select(mut self, components: KeySetComponents) -> Result<Self, KeySetError> {
+        self.require(components)?;
+
+        if !components.client_key {
+            self.client_key = None;
+        }
+        if !components.server_key {
+            self.server_key = None;
+        }
+        if !components.public_key {
+            self.public_key = None;
+        }
+        if !components.compressed_public_key {
+            self.compressed_public_key = None;
+        }
+        self.header.components = components;
+        Ok(self)
+    }
+
+    /// Returns an error if `other` was not generated together with `self`
+    pub fn check_same_key_set(&self, other: &Self) -> Result<(), KeySetError> {
+        check_ids(self.id(), other.id())
+    }
+
+    pub fn into_client_and_server_keys(self) -> Result<(ClientKey, ServerKey), KeySetError> {
+        self.require(KeySetComponents {
+            server_key: true,
+            ..KeySetComponents::CLIENT
+        })?;
+        Ok((self.client_key.unwrap(), self.server_key.unwrap()))
+    }
+
+    pub fn into_raw_parts(
+        self,
+    ) -> (
+        Option<ClientKey>,
+        Option<ServerKey>,
+        Option<PublicKey>,
+        Option<CompressedPublicKey>,
+    ) {
+        (
+            self.client_key,
+            self.server_key,
+            self.public_key,
+            self.compressed_public_key,
+        )
+    }
+
+    fn stored_components(&self) -> KeySetComponents {
+        KeySetComponents {
+            client_key: self.client_key.is_some(),
+            server_key: self.server_key.is_some(),
+            public_key: self.public_key.is_some(),
+            compressed_public_key: self.compressed_public_key.is_some(),
+        }
+    }
+}
+
+fn check_ids(expected: u64, found: u64) -> Result<(), KeySetError> {
+    if expected == found {
+        Ok(())
+    } else {
+        Err(KeySetError::KeySetMismatch { expected, found })
+    }
+}
+
+// The order of the fields is part of the format: the server key has to come right after
+// the header so that `ServerKeySet` can be deserialized from the beginning of the data.
+#[derive(serde::Serialize)]
+struct SerializableKeySet<'a> {
+    header: &'a KeySetHeader,
+    server_key: &'a Option<ServerKey>,
+    public_key: &'a Option<PublicKey>,
+    compressed_public_key: &'a Option<CompressedPublicKey>,
+    client_key: &'a Option<ClientKey>,
+}
+
+impl serde::Serialize for KeySet {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        SerializableKeySet {
+            header: &self.header,
+            server_key: &self.server_key,
+            public_key: &self.public_key,
+            compressed_public_key: &self.compressed_public_key,
+            client_key: &self.client_key,
+        }
+        .serialize(serializer)
+    }
+}
+
+#[derive(serde::Deserialize)]
+struct DeserializableKeySet {
+    header: KeySetHeader,
+    server_key: Option<ServerKey>,
+    public_key: Option<PublicKey>,
+    compressed_public_key: Option<CompressedPublicKey>,
+    client_key: Option<ClientKey>,
+}
+
+impl<'de> serde::Deserialize<'de> for KeySet {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let deserialized = DeserializableKeySet::deserialize(deserializer)?;
+        let key_set = Self {
+            header: deserialized.header,
+            client_key: deserialized.client_key,
+            server_key: deserialized.server_key,
+            public_key: deserialized.public_key,
+            compressed_public_key: deserialized.compressed_public_key,
+        };
+        key_set
+            .header
+            .check(key_set.stored_components())
+            .map_err(serde::de::Error::custom)?;
+        Ok(key_set)
+    }
+}
+
+/// The server key of a serialized [KeySet].
+///
+/// Deserializing a `ServerKeySet` from the data of a serialized [KeySet] only reads the
+/// header and the server key, the other keys are never loaded. With formats that read the
+/// data sequentially (e.g. bincode), the remaining bytes are not even read.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "shortint")]
+/// # {
+/// use tfhe::{ConfigBuilder, KeySet, KeySetComponents, ServerKeySet};
+///
+/// let config = ConfigBuilder::all_disabled().enable_default_uint2().build();
+/// let key_set = KeySet::generate(config, KeySetComponents::ALL);
+///
+/// let data = bincode::serialize(&key_set).unwrap();
+///
+/// // On a compute node
+/// let server_key_set: ServerKeySet = bincode::deserialize(&data).unwrap();
+/// assert_eq!(server_key_set.id(), key_set.id());
+/// let server_key = server_key_set.into_server_key().unwrap();
+/// # }
+/// ```
+#[derive(Clone, serde::Deserialize)]
+pub struct ServerKeySet {
+    header: KeySetHeader,
+    server_key: Option<ServerKey>,
+}
+
+impl ServerKeySet {
+    /// Returns the identifier of the key set the server key comes from
+    pub fn id(&self) -> u64 {
+        self.header.id
+    }
+
+    /// Returns an error if the server key was not generated together with `key_set`
+    pub fn check_same_key_set(&self, key_set: &KeySet) -> Result<(), KeySetError> {
+        check_ids(key_set.id(), self.id())
+    }
+
+    /// Returns the server key, checking the header of the key set it was read from
+    pub fn into_server_key(self) -> Result<ServerKey, KeySetError> {
+        if self.header.version != KEY_SET_FORMAT_VERSION {
+            return Err(KeySetError::UnsupportedVersion(self.header.version));
+        }
+        match (self.header.components.server_key, self.server_key) {
+            (true, Some(server_key)) => Ok(server_key),
+            (false, None) => Err(KeySetError::MissingComponents(KeySetComponents::SERVER)),
+            (expected, _) => Err(KeySetError::CorruptedComponents {
+                expected: self.header.components,
+                found: KeySetComponents {
+                    server_key: !expected,
+                    ..self.header.components
+                },
+            }),
+        }
+    }
+}
@@ -2,10 +2,12 @@
 mod client;
 #[macro_use]
 mod public;
+mod key_set;
 mod server;
 
 use crate::high_level_api::config::Config;
 pub use client::{ClientKey, RefKeyFromKeyChain};
+pub use key_set::{KeySet, KeySetComponents, KeySetError, ServerKeySet};
 pub use public::{
     CompressedPublicKey, PublicKey, RefKeyFromCompressedPublicKeyChain, RefKeyFromPublicKeyChain,
 };
@@ -41,6 +41,22 @@ impl ServerKey {
             integer_key: Arc::new(IntegerServerKey::new(&keys.integer_key)),
         }
     }
+
+    /// Confines the operations of the integer types to a dedicated thread pool of
+    /// `num_threads` threads, see [crate::integer::ServerKey::set_parallelism].
+    ///
+    /// Clones of a key share their internal keys, so this is meant to be called before cloning
+    /// the key: changing the setting of a shared key makes a copy of its integer key.
+    #[cfg(feature = "integer")]
+    pub fn set_parallelism(
+        &mut self,
+        num_threads: crate::integer::NumThreads,
+    ) -> Result<(), rayon::ThreadPoolBuildError> {
+        match Arc::make_mut(&mut self.integer_key).key.as_mut() {
+            Some(key) => key.set_parallelism(num_threads),
+            None => Ok(()),
+        }
+    }
 }
 
 // By default, serde does not derives Serialize/Deserialize for `Rc` and `Arc` types
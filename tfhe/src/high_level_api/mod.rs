@@ -1,11 +1,32 @@
 #![allow(unused_doc_comments)]
 pub use config::{Config, ConfigBuilder};
-pub use errors::{Error, OutOfRangeError};
+pub use errors::{Error, MissingKey, OutOfRangeError};
 pub use global_state::{set_server_key, unset_server_key, with_server_key_as_context};
 pub use keys::{
-    generate_keys, ClientKey, CompressedPublicKey, CompressedServerKey, PublicKey, ServerKey,
+    generate_keys, ClientKey, CompressedPublicKey, CompressedServerKey, KeySet, KeySetComponents,
+    KeySetError, PublicKey, ServerKey, ServerKeySet,
 };
 
+#[cfg(any(feature = "boolean", feature = "integer"))]
+pub use compressed_list::{
+    CompressedCiphertextList, CompressedListElement, CompressedListError, FromCompressedList,
+};
+
+#[cfg(any(feature = "boolean", feature = "integer"))]
+pub use result_envelope::{
+    FromResultEnvelope, ResultEnvelope, ResultEnvelopeElement, ResultEnvelopeError,
+};
+
+#[cfg(any(feature = "boolean", feature = "integer"))]
+pub use remote_key::{
+    RemoteClearValue, RemoteClientKey, RemoteKeyError, RemoteKeyRequest, RemoteKeyResponse,
+};
+
+pub use encryptable::FheEncryptable;
+pub use tenant::{TenantCiphertext, TenantContext, TenantError, TenantMetrics, TenantRegistry};
+#[cfg(feature = "derive")]
+pub use tfhe_derive::FheEncryptable;
+
 #[cfg(test)]
 mod tests;
 
@@ -15,8 +36,9 @@ pub use crate::high_level_api::booleans::{CompressedFheBool, FheBool, FheBoolPar
 pub use crate::high_level_api::integers::{
     CompressedFheUint10, CompressedFheUint12, CompressedFheUint128, CompressedFheUint14,
     CompressedFheUint16, CompressedFheUint256, CompressedFheUint32, CompressedFheUint64,
-    CompressedFheUint8, FheUint10, FheUint12, FheUint128, FheUint14, FheUint16, FheUint256,
-    FheUint32, FheUint64, FheUint8, GenericInteger,
+    CompressedFheUint8, FheInt16, FheInt32, FheInt64, FheInt8, FheUint10, FheUint12, FheUint128,
+    FheUint14, FheUint16, FheUint256, FheUint32, FheUint64, FheUint8, GenericInteger,
+    GenericSignedInteger,
 };
 #[cfg(feature = "shortint")]
 pub use crate::high_level_api::shortints::{
@@ -25,6 +47,7 @@ pub use crate::high_level_api::shortints::{
 };
 #[macro_use]
 mod details;
+mod encryptable;
 #[macro_use]
 mod global_state;
 #[macro_use]
@@ -35,12 +58,19 @@ mod traits;
 
 #[cfg(feature = "boolean")]
 mod booleans;
+#[cfg(any(feature = "boolean", feature = "integer"))]
+mod compressed_list;
 pub mod errors;
 #[cfg(feature = "integer")]
 mod integers;
 /// The tfhe prelude.
 pub mod prelude;
+#[cfg(any(feature = "boolean", feature = "integer"))]
+mod remote_key;
+#[cfg(any(feature = "boolean", feature = "integer"))]
+mod result_envelope;
 #[cfg(feature = "shortint")]
 mod shortints;
+mod tenant;
 
 pub mod parameters {}
@@ -5,8 +5,9 @@
 //! ```
 //! use tfhe::prelude::*;
 //! ```
+pub use crate::high_level_api::encryptable::FheEncryptable;
 pub use crate::high_level_api::traits::{
     DynamicFheEncryptor, DynamicFheTrivialEncryptor, DynamicFheTryEncryptor, FheBootstrap,
-    FheDecrypt, FheEncrypt, FheEq, FheNumberConstant, FheOrd, FheTrivialEncrypt, FheTryEncrypt,
-    FheTryTrivialEncrypt,
+    FheDecrypt, FheEncrypt, FheEq, FheNumberConstant, FheOrd, FheTrivialEncrypt, FheTryDecrypt,
+    FheTryEncrypt, FheTryTrivialEncrypt, FheWideningAdd, FheWideningMul,
 };
@@ -0,0 +1,497 @@
+//! Encryption and decryption with a client key held by a remote party.
+//!
+//! An organization may keep its client key inside a hardware security module or an enclave, so
+//! that the key never leaves it. A [RemoteClientKey] sends the encryption and decryption requests
+//! to the holder of the key over a transport provided by the user, and can be used in place of
+//! a [ClientKey]:
+//!
+//! - to encrypt, with [FheTryEncrypt](crate::high_level_api::prelude::FheTryEncrypt) and
+//!   [FheEncrypt](crate::high_level_api::prelude::FheEncrypt),
+//! - to decrypt, with [FheTryDecrypt](crate::high_level_api::prelude::FheTryDecrypt), which is
+//!   also implemented for the [ClientKey] so that code generic over the key works with both.
+//!
+//! The requests and the responses are serializable. The holder of the key answers a request with
+//! [ClientKey::answer_remote_request]. The ciphertexts are exchanged in [ResultEnvelope]s, whose
+//! integrity tags are checked on both sides.
+//!
+//! Only the boolean and the integer types are supported.
+//!
+//! # Example
+//!
+//! ```rust
+//! # #[cfg(feature = "integer")]
+//! # {
+//! use tfhe::prelude::*;
+//! use tfhe::{
+//!     generate_keys, set_server_key, ClientKey, ConfigBuilder, FheUint8, RemoteClientKey,
+//!     RemoteKeyError, RemoteKeyRequest, RemoteKeyResponse,
+//! };
+//!
+//! /// Stands for a connection to the hardware security module
+//! struct Hsm {
+//!     client_key: ClientKey,
+//! }
+//!
+//! impl RemoteClientKey for Hsm {
+//!     fn send(&self, request: RemoteKeyRequest) -> Result<RemoteKeyResponse, RemoteKeyError> {
+//!         let request = bincode::serialize(&request).map_err(RemoteKeyError::transport)?;
+//!
+//!         // Inside the module
+//!         let request = bincode::deserialize(&request).unwrap();
+//!         let response = self.client_key.answer_remote_request(request);
+//!         let response = bincode::serialize(&response).unwrap();
+//!
+//!         bincode::deserialize(&response).map_err(RemoteKeyError::transport)
+//!     }
+//! }
+//!
+//! let config = ConfigBuilder::all_disabled()
+//!     .enable_default_integers()
+//!     .build();
+//! let (client_key, server_key) = generate_keys(config);
+//! let hsm = Hsm { client_key };
+//!
+//! let a = FheUint8::encrypt(40u8, &hsm);
+//! let b = FheUint8::try_encrypt(2u8, &hsm).unwrap();
+//!
+//! set_server_key(server_key);
+//! let c = a + b;
+//!
+//! let c: u8 = c.try_decrypt(&hsm).unwrap();
+//! assert_eq!(c, 42);
+//! # }
+//! ```
+
+use crate::high_level_api::errors::Type;
+use crate::high_level_api::traits::{FheDecrypt, FheTryEncrypt};
+use crate::high_level_api::{
+    ClientKey, FromResultEnvelope, ResultEnvelope, ResultEnvelopeElement, ResultEnvelopeError,
+};
+#[cfg(feature = "integer")]
+use crate::integer::U256;
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+/// A clear value exchanged with the holder of a client key.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub enum RemoteClearValue {
+    Bool(bool),
+    /// An unsigned integer of up to 256 bits, as little endian 64 bits words
+    Integer([u64; 4]),
+}
+
+impl From<bool> for RemoteClearValue {
+    fn from(value: bool) -> Self {
+        Self::Bool(value)
+    }
+}
+
+impl TryFrom<RemoteClearValue> for bool {
+    type Error = RemoteKeyError;
+
+    fn try_from(value: RemoteClearValue) -> Result<Self, Self::Error> {
+        match value {
+            RemoteClearValue::Bool(value) => Ok(value),
+            RemoteClearValue::Integer(_) => Err(RemoteKeyError::UnexpectedResponse),
+        }
+    }
+}
+
+macro_rules! impl_remote_clear_value_for_integers {
+    ($($clear:ty),*) => {
+        $(
+            impl From<$clear> for RemoteClearValue {
+                fn from(value: $clear) -> Self {
+                    let value = value as u128;
+                    Self::Integer([value as u64, (value >> 64) as u64, 0, 0])
+                }
+            }
+
+            impl TryFrom<RemoteClearValue> for $clear {
+                type Error = RemoteKeyError;
+
+                fn try_from(value: RemoteClearValue) -> Result<Self, Self::Error> {
+                    match value {
+                        RemoteClearValue::Integer([low, high, 0, 0]) => {
+                            let value = (high as u128) << 64 | low as u128;
+                            <$clear>::try_from(value).map_err(|_| RemoteKeyError::ValueOutOfRange)
+                        }
+                        RemoteClearValue::Integer(_) => Err(RemoteKeyError::ValueOutOfRange),
+                        RemoteClearValue::Bool(_) => Err(RemoteKeyError::UnexpectedResponse),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_remote_clear_value_for_integers!(u8, u16, u32, u64, u128);
+
+#[cfg(feature = "integer")]
+impl From<U256> for RemoteClearValue {
+    fn from(value: U256) -> Self {
+        Self::Integer(value.0)
+    }
+}
+
+#[cfg(feature = "integer")]
+impl TryFrom<RemoteClearValue> for U256 {
+    type Error = RemoteKeyError;
+
+    fn try_from(value: RemoteClearValue) -> Result<Self, Self::Error> {
+        match value {
+            RemoteClearValue::Integer(words) => Ok(Self(words)),
+            RemoteClearValue::Bool(_) => Err(RemoteKeyError::UnexpectedResponse),
+        }
+    }
+}
+
+/// A request to the holder of a client key.
+#[derive(Clone, Serialize, Deserialize)]
+pub enum RemoteKeyRequest {
+    /// Encrypts `value` as a ciphertext of type `data_type`
+    Encrypt {
+        data_type: Type,
+        value: RemoteClearValue,
+    },
+    /// Decrypts the single ciphertext of the envelope
+    Decrypt(ResultEnvelope),
+}
+
+/// The answer of the holder of a client key to a [RemoteKeyRequest].
+#[derive(Clone, Serialize, Deserialize)]
+pub enum RemoteKeyResponse {
+    /// The envelope holds the single ciphertext requested
+    Encrypted(ResultEnvelope),
+    Decrypted(RemoteClearValue),
+    /// The request was not processed, with the reason given by the holder of the key
+    Rejected(String),
+}
+
+/// Error returned when encrypting or decrypting with a [RemoteClientKey].
+#[derive(Debug)]
+pub enum RemoteKeyError {
+    /// The transport failed to deliver the request or the response
+    Transport(Box<dyn std::error::Error + Send + Sync>),
+    /// The holder of the key rejected the request
+    Rejected(String),
+    /// The response does not answer the request
+    UnexpectedResponse,
+    /// The ciphertext of the response is invalid
+    Envelope(ResultEnvelopeError),
+    /// The decrypted value does not fit in the requested clear type
+    ValueOutOfRange,
+}
+
+impl RemoteKeyError {
+    /// Wraps an error of the transport.
+    pub fn transport<E>(error: E) -> Self
+    where
+        E: Into<Box<dyn std::error::Error + Send + Sync>>,
+    {
+        Self::Transport(error.into())
+    }
+}
+
+impl Display for RemoteKeyError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Transport(error) => write!(f, "Transport error: {error}"),
+            Self::Rejected(reason) => {
+                write!(f, "The holder of the key rejected the request: {reason}")
+            }
+            Self::UnexpectedResponse => {
+                write!(
+                    f,
+                    "The response of the holder of the key does not match the request"
+                )
+            }
+            Self::Envelope(error) => write!(f, "Invalid ciphertext in the response: {error}"),
+            Self::ValueOutOfRange => {
+                write!(
+                    f,
+                    "The decrypted value does not fit in the requested clear type"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for RemoteKeyError {}
+
+impl From<ResultEnvelopeError> for RemoteKeyError {
+    fn from(error: ResultEnvelopeError) -> Self {
+        Self::Envelope(error)
+    }
+}
+
+/// A client key held by a remote party, e.g. a hardware security module, see the
+/// [module documentation](self).
+pub trait RemoteClientKey {
+    /// Sends `request` to the holder of the key and returns its response.
+    fn send(&self, request: RemoteKeyRequest) -> Result<RemoteKeyResponse, RemoteKeyError>;
+}
+
+/// Encrypts `value` as a `T` with the remote key.
+pub(in crate::high_level_api) fn remote_encrypt<T, K>(
+    key: &K,
+    value: RemoteClearValue,
+) -> Result<T, RemoteKeyError>
+where
+    T: FromResultEnvelope,
+    K: RemoteClientKey + ?Sized,
+{
+    let request = RemoteKeyRequest::Encrypt {
+        data_type: T::data_type(),
+        value,
+    };
+    match key.send(request)? {
+        RemoteKeyResponse::Encrypted(envelope) if envelope.len() == 1 => {
+            envelope.verify_integrity()?;
+            Ok(envelope.get(0)?)
+        }
+        RemoteKeyResponse::Rejected(reason) => Err(RemoteKeyError::Rejected(reason)),
+        _ => Err(RemoteKeyError::UnexpectedResponse),
+    }
+}
+
+/// Decrypts `ciphertext` with the remote key.
+pub(in crate::high_level_api) fn remote_decrypt<T, K>(
+    key: &K,
+    ciphertext: T,
+) -> Result<RemoteClearValue, RemoteKeyError>
+where
+    T: ResultEnvelopeElement,
+    K: RemoteClientKey + ?Sized,
+{
+    let mut envelope = ResultEnvelope::new();
+    envelope.push(ciphertext);
+    match key.send(RemoteKeyRequest::Decrypt(envelope))? {
+        RemoteKeyResponse::Decrypted(value) => Ok(value),
+        RemoteKeyResponse::Rejected(reason) => Err(RemoteKeyError::Rejected(reason)),
+        RemoteKeyResponse::Encrypted(_) => Err(RemoteKeyError::UnexpectedResponse),
+    }
+}
+
+impl ClientKey {
+    /// Answers a request sent by a [RemoteClientKey], this is run by the holder of the key.
+    ///
+    /// The ciphertexts to decrypt must have been computed with the parameters of the key, other
+    /// requests are rejected.
+    pub fn answer_remote_request(&self, request: RemoteKeyRequest) -> RemoteKeyResponse {
+        let result = match request {
+            RemoteKeyRequest::Encrypt { data_type, value } => self
+                .encrypt_remote_request(data_type, value)
+                .map(RemoteKeyResponse::Encrypted),
+            RemoteKeyRequest::Decrypt(envelope) => self
+                .decrypt_remote_request(&envelope)
+                .map(RemoteKeyResponse::Decrypted),
+        };
+        result.unwrap_or_else(RemoteKeyResponse::Rejected)
+    }
+
+    fn encrypt_remote_request(
+        &self,
+        data_type: Type,
+        value: RemoteClearValue,
+    ) -> Result<ResultEnvelope, String> {
+        #[cfg(feature = "boolean")]
+        use crate::high_level_api::FheBool;
+        #[cfg(feature = "integer")]
+        use crate::high_level_api::{
+            FheUint10, FheUint12, FheUint128, FheUint14, FheUint16, FheUint256, FheUint32,
+            FheUint64, FheUint8,
+        };
+
+        match (data_type, value) {
+            #[cfg(feature = "boolean")]
+            (Type::FheBool, RemoteClearValue::Bool(value)) => self.encrypt_as::<FheBool, _>(value),
+            #[cfg(feature = "integer")]
+            (Type::FheUint8, RemoteClearValue::Integer(words)) => {
+                self.encrypt_as::<FheUint8, _>(U256(words))
+            }
+            #[cfg(feature = "integer")]
+            (Type::FheUint10, RemoteClearValue::Integer(words)) => {
+                self.encrypt_as::<FheUint10, _>(U256(words))
+            }
+            #[cfg(feature = "integer")]
+            (Type::FheUint12, RemoteClearValue::Integer(words)) => {
+                self.encrypt_as::<FheUint12, _>(U256(words))
+            }
+            #[cfg(feature = "integer")]
+            (Type::FheUint14, RemoteClearValue::Integer(words)) => {
+                self.encrypt_as::<FheUint14, _>(U256(words))
+            }
+            #[cfg(feature = "integer")]
+            (Type::FheUint16, RemoteClearValue::Integer(words)) => {
+                self.encrypt_as::<FheUint16, _>(U256(words))
+            }
+            #[cfg(feature = "integer")]
+            (Type::FheUint32, RemoteClearValue::Integer(words)) => {
+                self.encrypt_as::<FheUint32, _>(U256(words))
+            }
+            #[cfg(feature = "integer")]
+            (Type::FheUint64, RemoteClearValue::Integer(words)) => {
+                self.encrypt_as::<FheUint64, _>(U256(words))
+            }
+            #[cfg(feature = "integer")]
+            (Type::FheUint128, RemoteClearValue::Integer(words)) => {
+                self.encrypt_as::<FheUint128, _>(U256(words))
+            }
+            #[cfg(feature = "integer")]
+            (Type::FheUint256, RemoteClearValue::Integer(words)) => {
+                self.encrypt_as::<FheUint256, _>(U256(words))
+            }
+            (data_type, value) => Err(format!("Cannot encrypt {value:?} as a {data_type:?}")),
+        }
+    }
+
+    fn encrypt_as<T, Clear>(&self, value: Clear) -> Result<ResultEnvelope, String>
+    where
+        T: FheTryEncrypt<Clear, ClientKey> + ResultEnvelopeElement,
+    {
+        let ciphertext = T::try_encrypt(value, self).map_err(|error| error.to_string())?;
+        let mut envelope = ResultEnvelope::new();
+        envelope.push(ciphertext);
+        Ok(envelope)
+    }
+
+    fn decrypt_remote_request(
+        &self,
+        envelope: &ResultEnvelope,
+    ) -> Result<RemoteClearValue, String> {
+        #[cfg(feature = "boolean")]
+        use crate::high_level_api::FheBool;
+        #[cfg(feature = "integer")]
+        use crate::high_level_api::{
+            FheUint10, FheUint12, FheUint128, FheUint14, FheUint16, FheUint256, FheUint32,
+            FheUint64, FheUint8,
+        };
+
+        envelope
+            .verify_integrity()
+            .map_err(|error| error.to_string())?;
+        if envelope.len() != 1 {
+            return Err(format!(
+                "Expected a single ciphertext, got {}",
+                envelope.len()
+            ));
+        }
+
+        match envelope.data_type(0).unwrap() {
+            #[cfg(feature = "boolean")]
+            Type::FheBool => self.decrypt_as::<FheBool, bool>(envelope),
+            #[cfg(feature = "integer")]
+            Type::FheUint8 => self.decrypt_as::<FheUint8, U256>(envelope),
+            #[cfg(feature = "integer")]
+            Type::FheUint10 => self.decrypt_as::<FheUint10, U256>(envelope),
+            #[cfg(feature = "integer")]
+            Type::FheUint12 => self.decrypt_as::<FheUint12, U256>(envelope),
+            #[cfg(feature = "integer")]
+            Type::FheUint14 => self.decrypt_as::<FheUint14, U256>(envelope),
+            #[cfg(feature = "integer")]
+            Type::FheUint16 => self.decrypt_as::<FheUint16, U256>(envelope),
+            #[cfg(feature = "integer")]
+            Type::FheUint32 => self.decrypt_as::<FheUint32, U256>(envelope),
+            #[cfg(feature = "integer")]
+            Type::FheUint64 => self.decrypt_as::<FheUint64, U256>(envelope),
+            #[cfg(feature = "integer")]
+            Type::FheUint128 => self.decrypt_as::<FheUint128, U256>(envelope),
+            #[cfg(feature = "integer")]
+            Type::FheUint256 => self.decrypt_as::<FheUint256, U256>(envelope),
+            // The shortint types are not supported
+            #[allow(unreachable_patterns)]
+            data_type => Err(format!("Cannot decrypt a {data_type:?}")),
+        }
+    }
+
+    fn decrypt_as<T, Clear>(&self, envelope: &ResultEnvelope) -> Result<RemoteClearValue, String>
+    where
+        T: FromResultEnvelope + FheDecrypt<Clear>,
+        Clear: Into<RemoteClearValue>,
+    {
+        let ciphertext: T = envelope
+            .get_with_key(0, self)
+            .map_err(|error| error.to_string())?;
+        Ok(ciphertext.decrypt(self).into())
+    }
+}
+
+#[cfg(all(test, feature = "boolean", feature = "integer"))]
+mod tests {
+    use super::*;
+    use crate::high_level_api::prelude::*;
+    use crate::high_level_api::{generate_keys, set_server_key, ConfigBuilder, FheBool, FheUint16};
+
+    /// Holds the key in the same process, the messages go through bincode as they would over
+    /// a real transport
+    struct Loopback {
+        client_key: ClientKey,
+    }
+
+    impl RemoteClientKey for Loopback {
+        fn send(&self, request: RemoteKeyRequest) -> Result<RemoteKeyResponse, RemoteKeyError> {
+            let request = bincode::serialize(&request).map_err(RemoteKeyError::transport)?;
+            let request = bincode::deserialize(&request).map_err(RemoteKeyError::transport)?;
+            let response = self.client_key.answer_remote_request(request);
+            let response = bincode::serialize(&response).map_err(RemoteKeyError::transport)?;
+            bincode::deserialize(&response).map_err(RemoteKeyError::transport)
+        }
+    }
+
+    #[test]
+    fn test_remote_client_key() {
+        let config = ConfigBuilder::all_disabled()
+            .enable_default_bool()
+            .enable_default_integers()
+            .build();
+        let (client_key, server_key) = generate_keys(config);
+        set_server_key(server_key);
+        let remote = Loopback {
+            client_key: client_key.clone(),
+        };
+
+        let a = FheUint16::try_encrypt(300u16, &remote).unwrap();
+        let b = FheUint16::encrypt(12u16, &remote);
+        let c = &a + &b;
+        let c: u16 = c.try_decrypt(&remote).unwrap();
+        assert_eq!(c, 312);
+
+        // The ciphertexts are the same as the ones of the client key
+        let clear: u16 = a.decrypt(&client_key);
+        assert_eq!(clear, 300);
+        let clear: u16 = FheUint16::encrypt(7u16, &client_key)
+            .try_decrypt(&remote)
+            .unwrap();
+        assert_eq!(clear, 7);
+        let clear: Result<u16, _> = a.try_decrypt(&client_key);
+        assert_eq!(clear, Ok(300));
+
+        let result: Result<u8, _> = a.try_decrypt(&remote);
+        assert!(matches!(result, Err(RemoteKeyError::ValueOutOfRange)));
+
+        let t = FheBool::encrypt(true, &remote);
+        let f = FheBool::encrypt(false, &remote);
+        let result: bool = (t & f).try_decrypt(&remote).unwrap();
+        assert!(!result);
+    }
+
+    #[test]
+    fn test_remote_client_key_rejects_unknown_types() {
+        // The key holder cannot encrypt integers
+        let config = ConfigBuilder::all_disabled().enable_default_bool().build();
+        let (client_key, _) = generate_keys(config);
+        let remote = Loopback { client_key };
+
+        let result = FheUint16::try_encrypt(1u16, &remote);
+        assert!(matches!(result, Err(RemoteKeyError::Rejected(_))));
+
+        let response = remote
+            .client_key
+            .answer_remote_request(RemoteKeyRequest::Encrypt {
+                data_type: Type::FheBool,
+                value: RemoteClearValue::from(1u8),
+            });
+        assert!(matches!(response, RemoteKeyResponse::Rejected(_)));
+    }
+}
@@ -0,0 +1,472 @@
+//! A self-describing container of the results a server returns to a client.
+//!
+//! A service computing several outputs of different types, e.g. an [FheUint32] and an [FheBool],
+//! can push them in a single [ResultEnvelope] and return it as one serialized blob. Each element
+//! of the envelope records:
+//!
+//! - the data type it was computed as, checked by [ResultEnvelope::get],
+//! - a fingerprint of the parameters of its ciphertext, checked against the keys of the client by
+//!   [ResultEnvelope::get_with_key], so that results computed under other parameters are rejected
+//!   instead of decrypting to garbage,
+//! - whether it was bootstrapped when it was pushed, so that its noise does not depend on the
+//!   computation which produced it.
+//!
+//! The envelope also carries an integrity tag over all its elements, checked by
+//! [ResultEnvelope::verify_integrity], which detects a blob corrupted or truncated in transit. The
+//! tag is not keyed: it is not a protection against an attacker, who can recompute it.
+//!
+//! [FheUint32]: crate::high_level_api::FheUint32
+//! [FheBool]: crate::high_level_api::FheBool
+//!
+//! # Example
+//!
+//! ```rust
+//! # #[cfg(all(feature = "boolean", feature = "integer"))]
+//! # {
+//! use tfhe::errors::Type;
+//! use tfhe::prelude::*;
+//! use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheBool, FheUint8, ResultEnvelope};
+//!
+//! let config = ConfigBuilder::all_disabled()
+//!     .enable_default_bool()
+//!     .enable_default_integers()
+//!     .build();
+//! let (client_key, server_key) = generate_keys(config);
+//!
+//! let a = FheUint8::encrypt(200u8, &client_key);
+//! let b = FheUint8::encrypt(100u8, &client_key);
+//!
+//! // On the server
+//! set_server_key(server_key);
+//! let sum = &a + &b;
+//! let overflowed = sum.lt(&a);
+//!
+//! let mut envelope = ResultEnvelope::new();
+//! envelope.push_bootstrapped(sum).push(FheBool::encrypt(true, &client_key));
+//! let response = bincode::serialize(&envelope).unwrap();
+//!
+//! // On the client
+//! let envelope: ResultEnvelope = bincode::deserialize(&response).unwrap();
+//! envelope.verify_integrity().unwrap();
+//! assert_eq!(envelope.data_type(0), Some(Type::FheUint8));
+//! assert!(envelope.is_bootstrapped(0));
+//!
+//! let sum: FheUint8 = envelope.get_with_key(0, &client_key).unwrap();
+//! let flag: FheBool = envelope.get_with_key(1, &client_key).unwrap();
+//! let sum: u8 = sum.decrypt(&client_key);
+//! assert_eq!(sum, 44);
+//! assert!(flag.decrypt(&client_key));
+//! # let _ = overflowed;
+//! # }
+//! ```
+
+use crate::high_level_api::errors::Type;
+#[cfg(feature = "integer")]
+use crate::high_level_api::integers::RadixCiphertextDyn;
+use crate::high_level_api::ClientKey;
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+/// Error returned when opening a [ResultEnvelope].
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum ResultEnvelopeError {
+    /// The index is not the one of an element of the envelope
+    IndexOutOfBounds { index: usize, len: usize },
+    /// The element was not computed as the requested type
+    TypeMismatch { requested: Type, stored: Type },
+    /// The parameters of the element are not the ones of the client key
+    ParametersMismatch { index: usize },
+    /// The integrity tag does not match the content of the envelope
+    IntegrityCheckFailed,
+}
+
+impl Display for ResultEnvelopeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::IndexOutOfBounds { index, len } => {
+                write!(
+                    f,
+                    "Index {index} is out of bounds for an envelope of length {len}"
+                )
+            }
+            Self::TypeMismatch { requested, stored } => {
+                write!(
+                    f,
+                    "The element was computed as '{stored:?}', it can not be extracted \
+                    as '{requested:?}'"
+                )
+            }
+            Self::ParametersMismatch { index } => {
+                write!(
+                    f,
+                    "The element at index {index} was not computed with the parameters of the \
+                    client key"
+                )
+            }
+            Self::IntegrityCheckFailed => {
+                write!(
+                    f,
+                    "The integrity tag does not match the content of the envelope"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for ResultEnvelopeError {}
+
+/// The ciphertext of an element of the envelope, whose type is given by its [Type].
+#[derive(Clone, Serialize, Deserialize)]
+pub(in crate::high_level_api) enum EnvelopeData {
+    #[cfg(feature = "boolean")]
+    Boolean(crate::boolean::ciphertext::Ciphertext),
+    #[cfg(feature = "integer")]
+    Radix(RadixCiphertextDyn),
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub(in crate::high_level_api) struct EnvelopeEntry {
+    pub(in crate::high_level_api) data_type: Type,
+    /// `None` for trivial ciphertexts, which do not depend on the parameters
+    pub(in crate::high_level_api) parameters_fingerprint: Option<u64>,
+    pub(in crate::high_level_api) bootstrapped: bool,
+    pub(in crate::high_level_api) data: EnvelopeData,
+}
+
+/// A list of result ciphertexts of possibly different types, with an integrity tag.
+///
+/// See the [module documentation](self) for an example.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct ResultEnvelope {
+    entries: Vec<EnvelopeEntry>,
+    integrity_tag: u64,
+}
+
+impl Default for ResultEnvelope {
+    fn default() -> Self {
+        Self {
+            entries: Vec::new(),
+            integrity_tag: FNV_OFFSET_BASIS,
+        }
+    }
+}
+
+/// Trait implemented by the types which can be pushed in a [ResultEnvelope].
+pub trait ResultEnvelopeElement {
+    /// Appends `self` to the envelope, after bootstrapping it if `bootstrap` is true.
+    fn push_to(self, envelope: &mut ResultEnvelope, bootstrap: bool);
+}
+
+/// Trait implemented by the types an element of a [ResultEnvelope] can be extracted to.
+pub trait FromResultEnvelope: Sized {
+    /// Returns the type of the elements this type is extracted from.
+    fn data_type() -> Type;
+
+    /// Returns the fingerprint of the parameters of the ciphertexts of this type encrypted with
+    /// `client_key`, or `None` if the client key of this type was not initialized.
+    fn parameters_fingerprint(client_key: &ClientKey) -> Option<u64>;
+
+    /// Extracts the element at `index`, whose type is [Self::data_type].
+    fn extract_from(envelope: &ResultEnvelope, index: usize) -> Self;
+}
+
+impl ResultEnvelope {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Appends a ciphertext to the envelope as is.
+    ///
+    /// The fingerprint of the parameters of the ciphertext is read from the server key of its
+    /// type, which must be set.
+    pub fn push<T>(&mut self, element: T) -> &mut Self
+    where
+        T: ResultEnvelopeElement,
+    {
+        element.push_to(self, false);
+        self
+    }
+
+    /// Appends a ciphertext to the envelope after bootstrapping it, so that its noise does not
+    /// depend on the computation which produced it. As for [Self::push], this requires the
+    /// server key to be set.
+    ///
+    /// The outputs of boolean gates are always bootstrapped, so booleans are pushed as is.
+    pub fn push_bootstrapped<T>(&mut self, element: T) -> &mut Self
+    where
+        T: ResultEnvelopeElement,
+    {
+        element.push_to(self, true);
+        self
+    }
+
+    /// Returns the type the element at `index` was computed as, or `None` if the index is out
+    /// of bounds.
+    pub fn data_type(&self, index: usize) -> Option<Type> {
+        self.entries.get(index).map(|entry| entry.data_type)
+    }
+
+    /// Returns an iterator over the types of the elements of the envelope.
+    pub fn data_types(&self) -> impl Iterator<Item = Type> + '_ {
+        self.entries.iter().map(|entry| entry.data_type)
+    }
+
+    /// Returns true if the element at `index` was bootstrapped when it was pushed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is out of bounds.
+    pub fn is_bootstrapped(&self, index: usize) -> bool {
+        self.entries[index].bootstrapped
+    }
+
+    /// Returns the fingerprint of the parameters of the element at `index`, `None` if the index
+    /// is out of bounds or the element is a trivial ciphertext.
+    pub fn parameters_fingerprint(&self, index: usize) -> Option<u64> {
+        self.entries
+            .get(index)
+            .and_then(|entry| entry.parameters_fingerprint)
+    }
+
+    /// Recomputes the integrity tag from the elements of the envelope, and checks that it is the
+    /// transmitted one.
+    pub fn verify_integrity(&self) -> Result<(), ResultEnvelopeError> {
+        let tag = self
+            .entries
+            .iter()
+            .fold(FNV_OFFSET_BASIS, |tag, entry| entry_tag(tag, entry));
+        if tag == self.integrity_tag {
+            Ok(())
+        } else {
+            Err(ResultEnvelopeError::IntegrityCheckFailed)
+        }
+    }
+
+    /// Extracts the element at `index` to the type `T`, which must be the type the element was
+    /// computed as.
+    pub fn get<T>(&self, index: usize) -> Result<T, ResultEnvelopeError>
+    where
+        T: FromResultEnvelope,
+    {
+        let stored = self
+            .data_type(index)
+            .ok_or(ResultEnvelopeError::IndexOutOfBounds {
+                index,
+                len: self.len(),
+            })?;
+        let requested = T::data_type();
+        if stored != requested {
+            return Err(ResultEnvelopeError::TypeMismatch { requested, stored });
+        }
+        Ok(T::extract_from(self, index))
+    }
+
+    /// Extracts the element at `index` to the type `T` like [ResultEnvelope::get], and checks
+    /// that it was computed with the parameters of `client_key`.
+    pub fn get_with_key<T>(
+        &self,
+        index: usize,
+        client_key: &ClientKey,
+    ) -> Result<T, ResultEnvelopeError>
+    where
+        T: FromResultEnvelope,
+    {
+        let value = self.get(index)?;
+        if let Some(fingerprint) = self.entries[index].parameters_fingerprint {
+            if T::parameters_fingerprint(client_key) != Some(fingerprint) {
+                return Err(ResultEnvelopeError::ParametersMismatch { index });
+            }
+        }
+        Ok(value)
+    }
+
+    pub(in crate::high_level_api) fn push_entry(&mut self, entry: EnvelopeEntry) {
+        self.integrity_tag = entry_tag(self.integrity_tag, &entry);
+        self.entries.push(entry);
+    }
+
+    pub(in crate::high_level_api) fn entry_data(&self, index: usize) -> &EnvelopeData {
+        &self.entries[index].data
+    }
+}
+
+// 64 bits FNV-1a, the tag must not depend on the platform or on the Rust version
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+fn fnv1a(tag: u64, bytes: &[u8]) -> u64 {
+    bytes.iter().fold(tag, |tag, &byte| {
+        (tag ^ byte as u64).wrapping_mul(FNV_PRIME)
+    })
+}
+
+fn fnv1a_words(tag: u64, words: impl IntoIterator<Item = u64>) -> u64 {
+    words
+        .into_iter()
+        .fold(tag, |tag, word| fnv1a(tag, &word.to_le_bytes()))
+}
+
+/// Returns the fingerprint of parameters described by `words`.
+pub(in crate::high_level_api) fn parameters_fingerprint(words: &[u64]) -> u64 {
+    fnv1a_words(FNV_OFFSET_BASIS, words.iter().copied())
+}
+
+fn entry_tag(tag: u64, entry: &EnvelopeEntry) -> u64 {
+    let tag = fnv1a(tag, format!("{:?}", entry.data_type).as_bytes());
+    let tag = fnv1a_words(
+        tag,
+        [
+            entry.parameters_fingerprint.is_some() as u64,
+            entry.parameters_fingerprint.unwrap_or(0),
+            entry.bootstrapped as u64,
+        ],
+    );
+    match &entry.data {
+        #[cfg(feature = "boolean")]
+        EnvelopeData::Boolean(ciphertext) => match ciphertext {
+            crate::boolean::ciphertext::Ciphertext::Encrypted(ct) => {
+                fnv1a_words(tag, ct.as_ref().iter().map(|&coeff| coeff as u64))
+            }
+            crate::boolean::ciphertext::Ciphertext::Trivial(value) => {
+                fnv1a_words(tag, [*value as u64])
+            }
+        },
+        #[cfg(feature = "integer")]
+        EnvelopeData::Radix(RadixCiphertextDyn::Big(ct)) => radix_tag(fnv1a_words(tag, [0]), ct),
+        #[cfg(feature = "integer")]
+        EnvelopeData::Radix(RadixCiphertextDyn::Small(ct)) => radix_tag(fnv1a_words(tag, [1]), ct),
+    }
+}
+
+#[cfg(feature = "integer")]
+fn radix_tag<PBSOrder: crate::shortint::PBSOrderMarker>(
+    tag: u64,
+    ciphertext: &crate::integer::ciphertext::RadixCiphertext<PBSOrder>,
+) -> u64 {
+    ciphertext.blocks.iter().fold(tag, |tag, block| {
+        let tag = fnv1a_words(tag, [block.degree.0 as u64]);
+        fnv1a_words(tag, block.ct.as_ref().iter().copied())
+    })
+}
+
+#[cfg(all(test, feature = "boolean", feature = "integer"))]
+mod tests {
+    use super::*;
+    use crate::core_crypto::commons::parameters::DecompositionLevelCount;
+    use crate::high_level_api::prelude::*;
+    use crate::high_level_api::{
+        generate_keys, set_server_key, ClientKey, ConfigBuilder, FheBool, FheBoolParameters,
+        FheUint16, FheUint8,
+    };
+
+    #[test]
+    fn test_result_envelope() {
+        let config = ConfigBuilder::all_disabled()
+            .enable_default_bool()
+            .enable_default_integers()
+            .build();
+        let (cks, sks) = generate_keys(config);
+        set_server_key(sks);
+
+        let a = FheUint8::encrypt(10u8, &cks);
+        let mut envelope = ResultEnvelope::new();
+        envelope
+            .push_bootstrapped(&a + 7u8)
+            .push(FheBool::encrypt(true, &cks))
+            .push(FheUint16::try_encrypt_trivial(4242u16).unwrap());
+
+        let serialized = bincode::serialize(&envelope).unwrap();
+        let envelope: ResultEnvelope = bincode::deserialize(&serialized).unwrap();
+        envelope.verify_integrity().unwrap();
+
+        assert_eq!(
+            envelope.data_types().collect::<Vec<_>>(),
+            vec![Type::FheUint8, Type::FheBool, Type::FheUint16]
+        );
+        assert!(envelope.is_bootstrapped(0));
+        assert!(!envelope.is_bootstrapped(1));
+
+        let a: FheUint8 = envelope.get_with_key(0, &cks).unwrap();
+        let b: FheBool = envelope.get_with_key(1, &cks).unwrap();
+        let c: FheUint16 = envelope.get_with_key(2, &cks).unwrap();
+        let a: u8 = a.decrypt(&cks);
+        let c: u16 = c.decrypt(&cks);
+        assert_eq!(a, 17);
+        assert!(b.decrypt(&cks));
+        assert_eq!(c, 4242);
+
+        assert_eq!(
+            envelope.get::<FheBool>(0).err(),
+            Some(ResultEnvelopeError::TypeMismatch {
+                requested: Type::FheBool,
+                stored: Type::FheUint8
+            })
+        );
+        assert_eq!(
+            envelope.get::<FheBool>(3).err(),
+            Some(ResultEnvelopeError::IndexOutOfBounds { index: 3, len: 3 })
+        );
+
+        // Keys of other parameters are rejected
+        let other_config = ConfigBuilder::all_disabled()
+            .enable_default_custom_integers(
+                crate::shortint::parameters::PARAM_MESSAGE_1_CARRY_1,
+                None,
+            )
+            .build();
+        let (other_cks, _) = generate_keys(other_config);
+        assert_eq!(
+            envelope.get_with_key::<FheUint8>(0, &other_cks).err(),
+            Some(ResultEnvelopeError::ParametersMismatch { index: 0 })
+        );
+
+        // Including integer keys with the same moduli and dimensions but another decomposition
+        let block_parameters = crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+        let other_config = ConfigBuilder::all_disabled()
+            .enable_default_custom_integers(
+                crate::shortint::PBSParameters {
+                    pbs_level: DecompositionLevelCount(block_parameters.pbs_level.0 + 1),
+                    ..block_parameters
+                },
+                None,
+            )
+            .build();
+        let other_cks = ClientKey::generate(other_config);
+        assert_eq!(
+            envelope.get_with_key::<FheUint8>(0, &other_cks).err(),
+            Some(ResultEnvelopeError::ParametersMismatch { index: 0 })
+        );
+
+        // Including boolean keys with the same LWE dimension but another decomposition
+        let bool_parameters = FheBoolParameters::default();
+        let other_config = ConfigBuilder::all_disabled()
+            .enable_custom_bool(FheBoolParameters {
+                pbs_level: DecompositionLevelCount(bool_parameters.pbs_level.0 + 1),
+                ..bool_parameters
+            })
+            .build();
+        let other_cks = ClientKey::generate(other_config);
+        assert_eq!(
+            envelope.get_with_key::<FheBool>(1, &other_cks).err(),
+            Some(ResultEnvelopeError::ParametersMismatch { index: 1 })
+        );
+
+        // A corrupted envelope is detected
+        let mut corrupted = envelope.clone();
+        if let EnvelopeData::Radix(RadixCiphertextDyn::Big(ct)) = &mut corrupted.entries[0].data {
+            ct.blocks[0].ct.as_mut()[0] ^= 1;
+        }
+        assert_eq!(
+            corrupted.verify_integrity(),
+            Err(ResultEnvelopeError::IntegrityCheckFailed)
+        );
+    }
+}
@@ -3,8 +3,8 @@ use std::fmt::Formatter;
 
 use crate::shortint::parameters::{
     CarryModulus, CoreCiphertextModulus, DecompositionBaseLog, DecompositionLevelCount,
-    EncryptionKeyChoice, GlweDimension, LweDimension, MessageModulus, PBSParameters,
-    PolynomialSize, StandardDev,
+    DispersionParameter, DynamicDistribution, EncryptionKeyChoice, GlweDimension, LweDimension,
+    MessageModulus, PBSParameters, PolynomialSize, SecretKeyDistribution, StandardDev,
 };
 
 use crate::high_level_api::shortints::{CompressedGenericShortint, GenericShortInt};
@@ -30,8 +30,8 @@ pub struct ShortIntegerParameterSet<const MESSAGE_BITS: u8> {
     pub lwe_dimension: LweDimension,
     pub glwe_dimension: GlweDimension,
     pub polynomial_size: PolynomialSize,
-    pub lwe_modular_std_dev: StandardDev,
-    pub glwe_modular_std_dev: StandardDev,
+    pub lwe_noise_distribution: DynamicDistribution,
+    pub glwe_noise_distribution: DynamicDistribution,
     pub pbs_base_log: DecompositionBaseLog,
     pub pbs_level: DecompositionLevelCount,
     pub ks_base_log: DecompositionBaseLog,
@@ -39,6 +39,7 @@ pub struct ShortIntegerParameterSet<const MESSAGE_BITS: u8> {
     pub carry_modulus: CarryModulus,
     pub ciphertext_modulus: CoreCiphertextModulus<u64>,
     pub encryption_key_choice: EncryptionKeyChoice,
+    pub secret_key_distribution: SecretKeyDistribution,
 }
 
 impl<const MESSAGE_BITS: u8> ShortIntegerParameterSet<MESSAGE_BITS> {
@@ -50,8 +51,8 @@ impl<const MESSAGE_BITS: u8> ShortIntegerParameterSet<MESSAGE_BITS> {
             lwe_dimension: params.lwe_dimension,
             glwe_dimension: params.glwe_dimension,
             polynomial_size: params.polynomial_size,
-            lwe_modular_std_dev: params.lwe_modular_std_dev,
-            glwe_modular_std_dev: params.glwe_modular_std_dev,
+            lwe_noise_distribution: params.lwe_noise_distribution,
+            glwe_noise_distribution: params.glwe_noise_distribution,
             pbs_base_log: params.pbs_base_log,
             pbs_level: params.pbs_level,
             ks_base_log: params.ks_base_log,
@@ -59,8 +60,21 @@ impl<const MESSAGE_BITS: u8> ShortIntegerParameterSet<MESSAGE_BITS> {
             carry_modulus: params.carry_modulus,
             ciphertext_modulus: params.ciphertext_modulus,
             encryption_key_choice: params.encryption_key_choice,
+            secret_key_distribution: params.secret_key_distribution,
         }
     }
+
+    /// Returns the standard deviation of the LWE noise.
+    #[deprecated(note = "use the `lwe_noise_distribution` field, which can also be t-uniform")]
+    pub fn lwe_modular_std_dev(&self) -> StandardDev {
+        StandardDev(self.lwe_noise_distribution.get_standard_dev())
+    }
+
+    /// Returns the standard deviation of the GLWE noise.
+    #[deprecated(note = "use the `glwe_noise_distribution` field, which can also be t-uniform")]
+    pub fn glwe_modular_std_dev(&self) -> StandardDev {
+        StandardDev(self.glwe_noise_distribution.get_standard_dev())
+    }
 }
 
 impl<const MESSAGE_BITS: u8> From<ShortIntegerParameterSet<MESSAGE_BITS>> for PBSParameters {
@@ -69,8 +83,8 @@ impl<const MESSAGE_BITS: u8> From<ShortIntegerParameterSet<MESSAGE_BITS>> for PB
             lwe_dimension: params.lwe_dimension,
             glwe_dimension: params.glwe_dimension,
             polynomial_size: params.polynomial_size,
-            lwe_modular_std_dev: params.lwe_modular_std_dev,
-            glwe_modular_std_dev: params.glwe_modular_std_dev,
+            lwe_noise_distribution: params.lwe_noise_distribution,
+            glwe_noise_distribution: params.glwe_noise_distribution,
             pbs_base_log: params.pbs_base_log,
             pbs_level: params.pbs_level,
             ks_base_log: params.ks_base_log,
@@ -79,6 +93,7 @@ impl<const MESSAGE_BITS: u8> From<ShortIntegerParameterSet<MESSAGE_BITS>> for PB
             carry_modulus: params.carry_modulus,
             ciphertext_modulus: params.ciphertext_modulus,
             encryption_key_choice: params.encryption_key_choice,
+            secret_key_distribution: params.secret_key_distribution,
         }
     }
 }
@@ -0,0 +1,321 @@
+//! Serving several tenants, each with their own keys and parameters, from one process.
+//!
+//! The high-level API keeps the server key in a thread local, set with
+//! [set_server_key](crate::high_level_api::set_server_key). A service computing for many
+//! customers would have to switch it by hand before each request, and nothing would stop a
+//! ciphertext of a customer from being combined with the ciphertexts of another one: the
+//! computation would succeed and return garbage, or worse, a value the other customer can
+//! decrypt.
+//!
+//! A [TenantContext] holds the config and the server key of a tenant:
+//!
+//! - [TenantContext::adopt] tags the ciphertexts received from the tenant, as a
+//!   [TenantCiphertext],
+//! - [TenantContext::check] gives access to a tagged ciphertext only if it belongs to the tenant,
+//!   so that an operand of another tenant is rejected before any computation,
+//! - [TenantContext::evaluate] runs a computation with the server key of the tenant, restoring
+//!   the previous server key of the thread afterwards, and tags its result,
+//! - [TenantContext::metrics] counts the evaluations and the rejected operands of the tenant.
+//!
+//! A [TenantRegistry] maps the identifiers of the tenants to their contexts, and can be shared
+//! between the threads of the service.
+//!
+//! The tags are attached by the server when it receives a ciphertext from an authenticated
+//! tenant, they are not serialized: a ciphertext does not carry the identity of its key, so a
+//! tenant sending the ciphertext of another one cannot be detected.
+//!
+//! # Example
+//!
+//! ```rust
+//! # #[cfg(feature = "integer")]
+//! # {
+//! use tfhe::prelude::*;
+//! use tfhe::{generate_keys, ConfigBuilder, FheUint8, TenantContext, TenantRegistry};
+//!
+//! let config = ConfigBuilder::all_disabled().enable_default_integers().build();
+//! let (alice_client_key, alice_server_key) = generate_keys(config.clone());
+//! let (bob_client_key, bob_server_key) = generate_keys(config.clone());
+//!
+//! let registry = TenantRegistry::new();
+//! let alice = registry
+//!     .register(TenantContext::new("alice", config.clone(), alice_server_key))
+//!     .unwrap();
+//! registry
+//!     .register(TenantContext::new("bob", config, bob_server_key))
+//!     .unwrap();
+//!
+//! let bob = registry.get("bob").unwrap();
+//! let a = alice.adopt(FheUint8::encrypt(20u8, &alice_client_key));
+//! let b = alice.adopt(FheUint8::encrypt(22u8, &alice_client_key));
+//! let c = bob.adopt(FheUint8::encrypt(1u8, &bob_client_key));
+//!
+//! let (a_ref, b_ref) = (alice.check(&a).unwrap(), alice.check(&b).unwrap());
+//! let sum = alice.evaluate(|| a_ref + b_ref);
+//! let sum: u8 = sum.into_inner().decrypt(&alice_client_key);
+//! assert_eq!(sum, 42);
+//!
+//! // The operand of bob is rejected
+//! assert!(alice.check(&c).is_err());
+//! assert_eq!(alice.metrics().evaluations, 1);
+//! assert_eq!(alice.metrics().rejected_operands, 1);
+//! # }
+//! ```
+
+use crate::high_level_api::global_state::{set_server_key, unset_server_key};
+use crate::high_level_api::{Config, ServerKey};
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, RwLock};
+
+/// Error returned when an operation would mix tenants.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum TenantError {
+    /// The operand belongs to another tenant.
+    CrossTenantOperand { expected: String, found: String },
+    /// A tenant with the same identifier is already registered.
+    AlreadyRegistered(String),
+}
+
+impl Display for TenantError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::CrossTenantOperand { expected, found } => {
+                write!(
+                    f,
+                    "An operand of tenant '{found}' was used in a computation of tenant \
+                    '{expected}'"
+                )
+            }
+            Self::AlreadyRegistered(tenant_id) => {
+                write!(f, "The tenant '{tenant_id}' is already registered")
+            }
+        }
+    }
+}
+
+impl std::error::Error for TenantError {}
+
+/// A value, typically a ciphertext, tagged with the tenant it belongs to.
+#[derive(Clone, Debug)]
+pub struct TenantCiphertext<T> {
+    tenant_id: Arc<str>,
+    value: T,
+}
+
+impl<T> TenantCiphertext<T> {
+    pub fn tenant_id(&self) -> &str {
+        &self.tenant_id
+    }
+
+    /// Returns the value without its tag, e.g. to send it back to the tenant.
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+/// The counters of a [TenantContext].
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct TenantMetrics {
+    /// The number of calls to [TenantContext::evaluate] and [TenantContext::run].
+    pub evaluations: u64,
+    /// The number of operands rejected by [TenantContext::check].
+    pub rejected_operands: u64,
+}
+
+/// The config, the server key and the counters of a tenant.
+pub struct TenantContext {
+    tenant_id: Arc<str>,
+    config: Config,
+    server_key: ServerKey,
+    evaluations: AtomicU64,
+    rejected_operands: AtomicU64,
+}
+
+impl TenantContext {
+    pub fn new(tenant_id: impl Into<String>, config: Config, server_key: ServerKey) -> Self {
+        Self {
+            tenant_id: Arc::from(tenant_id.into()),
+            config,
+            server_key,
+            evaluations: AtomicU64::new(0),
+            rejected_operands: AtomicU64::new(0),
+        }
+    }
+
+    pub fn tenant_id(&self) -> &str {
+        &self.tenant_id
+    }
+
+    /// Returns the config the keys of the tenant were generated with.
+    pub fn config(&self) -> &Config {
+        &self.config
+    }
+
+    pub fn metrics(&self) -> TenantMetrics {
+        TenantMetrics {
+            evaluations: self.evaluations.load(Ordering::Relaxed),
+            rejected_operands: self.rejected_operands.load(Ordering::Relaxed),
+        }
+    }
+
+    /// Tags `value` as belonging to the tenant.
+    ///
+    /// This is meant for the ciphertexts received from the tenant, over a channel authenticating
+    /// it.
+    pub fn adopt<T>(&self, value: T) -> TenantCiphertext<T> {
+        TenantCiphertext {
+            tenant_id: Arc::clone(&self.tenant_id),
+            value,
+        }
+    }
+
+    /// Returns the value of `operand` if it belongs to the tenant, an error otherwise.
+    pub fn check<'a, T>(&self, operand: &'a TenantCiphertext<T>) -> Result<&'a T, TenantError> {
+        if operand.tenant_id == self.tenant_id {
+            Ok(&operand.value)
+        } else {
+            self.rejected_operands.fetch_add(1, Ordering::Relaxed);
+            Err(TenantError::CrossTenantOperand {
+                expected: self.tenant_id.to_string(),
+                found: operand.tenant_id.to_string(),
+            })
+        }
+    }
+
+    /// Calls `func` with the server key of the tenant set for the current thread.
+    ///
+    /// The server key the thread had before is restored afterwards, also if `func` panics.
+    pub fn run<R, F>(&self, func: F) -> R
+    where
+        F: FnOnce() -> R,
+    {
+        struct RestoreServerKey(Option<ServerKey>);
+
+        impl Drop for RestoreServerKey {
+            fn drop(&mut self) {
+                if let Some(previous_key) = self.0.take() {
+                    set_server_key(previous_key);
+                }
+            }
+        }
+
+        self.evaluations.fetch_add(1, Ordering::Relaxed);
+        let previous_key = unset_server_key();
+        set_server_key(self.server_key.clone());
+        let _restore = RestoreServerKey(Some(previous_key));
+        func()
+    }
+
+    /// Calls `func` with the server key of the tenant, see [TenantContext::run], and tags its
+    /// result as belonging to the tenant.
+    pub fn evaluate<R, F>(&self, func: F) -> TenantCiphertext<R>
+    where
+        F: FnOnce() -> R,
+    {
+        let value = self.run(func);
+        self.adopt(value)
+    }
+}
+
+/// The contexts of the tenants of a service, by tenant identifier.
+#[derive(Default)]
+pub struct TenantRegistry {
+    tenants: RwLock<HashMap<String, Arc<TenantContext>>>,
+}
+
+impl TenantRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers `context`, unless a tenant with the same identifier is already registered.
+    pub fn register(&self, context: TenantContext) -> Result<Arc<TenantContext>, TenantError> {
+        let mut tenants = self.tenants.write().unwrap();
+        if tenants.contains_key(context.tenant_id()) {
+            return Err(TenantError::AlreadyRegistered(
+                context.tenant_id().to_string(),
+            ));
+        }
+        let context = Arc::new(context);
+        tenants.insert(context.tenant_id().to_string(), Arc::clone(&context));
+        Ok(context)
+    }
+
+    pub fn get(&self, tenant_id: &str) -> Option<Arc<TenantContext>> {
+        self.tenants.read().unwrap().get(tenant_id).cloned()
+    }
+
+    /// Removes the tenant from the registry, its context stays valid for the computations which
+    /// hold it.
+    pub fn remove(&self, tenant_id: &str) -> Option<Arc<TenantContext>> {
+        self.tenants.write().unwrap().remove(tenant_id)
+    }
+
+    pub fn len(&self) -> usize {
+        self.tenants.read().unwrap().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+#[cfg(all(test, feature = "integer"))]
+mod tests {
+    use super::*;
+    use crate::high_level_api::prelude::*;
+    use crate::high_level_api::{generate_keys, ConfigBuilder, FheUint8};
+
+    #[test]
+    fn test_tenant_isolation() {
+        let config = ConfigBuilder::all_disabled()
+            .enable_default_integers()
+            .build();
+        let (client_key, server_key) = generate_keys(config.clone());
+
+        let registry = TenantRegistry::new();
+        let tenant = registry
+            .register(TenantContext::new("a", config.clone(), server_key.clone()))
+            .unwrap();
+        let other = registry
+            .register(TenantContext::new("b", config.clone(), server_key.clone()))
+            .unwrap();
+        assert_eq!(
+            registry
+                .register(TenantContext::new("a", config, server_key))
+                .err(),
+            Some(TenantError::AlreadyRegistered("a".to_string()))
+        );
+        assert_eq!(registry.len(), 2);
+
+        let a = tenant.adopt(FheUint8::encrypt(3u8, &client_key));
+        let b = other.adopt(FheUint8::encrypt(4u8, &client_key));
+        assert!(matches!(
+            tenant.check(&b),
+            Err(TenantError::CrossTenantOperand { .. })
+        ));
+
+        let a = tenant.check(&a).unwrap();
+        let result = tenant.evaluate(|| a * 5u8);
+        assert_eq!(result.tenant_id(), "a");
+        let result: u8 = result.into_inner().decrypt(&client_key);
+        assert_eq!(result, 15);
+
+        // The server key was only set for the evaluation
+        let res = std::panic::catch_unwind(|| FheUint8::encrypt_trivial(1u8));
+        assert!(res.is_err());
+
+        assert_eq!(
+            tenant.metrics(),
+            TenantMetrics {
+                evaluations: 1,
+                rejected_operands: 1
+            }
+        );
+        assert_eq!(other.metrics(), TenantMetrics::default());
+
+        assert!(registry.remove("b").is_some());
+        assert!(registry.get("b").is_none());
+    }
+}
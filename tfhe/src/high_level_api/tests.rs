@@ -9,7 +9,7 @@ use crate::high_level_api::{generate_keys, ClientKey, ConfigBuilder, PublicKey};
 use crate::high_level_api::{FheUint256, FheUint8};
 #[cfg(feature = "integer")]
 use crate::integer::U256;
-use crate::{CompressedPublicKey, CompressedServerKey};
+use crate::{CompressedPublicKey, CompressedServerKey, KeySet, KeySetComponents, ServerKeySet};
 #[cfg(any(feature = "boolean", feature = "shortint", feature = "integer"))]
 use std::fmt::Debug;
 
@@ -179,3 +179,89 @@ fn test_serialize_deserialize_are_implemented() {
     can_be_deserialized(&cpks);
     can_be_deserialized(&csks);
 }
+
+#[test]
+fn test_key_set_serialization() {
+    let config = ConfigBuilder::all_disabled().build();
+
+    let key_set = KeySet::generate(config, KeySetComponents::ALL);
+    let data = bincode::serialize(&key_set).unwrap();
+
+    let deserialized: KeySet = bincode::deserialize(&data).unwrap();
+    assert_eq!(deserialized.id(), key_set.id());
+    assert_eq!(deserialized.components(), KeySetComponents::ALL);
+    assert!(deserialized.client_key().is_some());
+    deserialized.check_same_key_set(&key_set).unwrap();
+
+    // Compute nodes only read the beginning of the data
+    let server_key_set: ServerKeySet = bincode::deserialize(&data).unwrap();
+    server_key_set.check_same_key_set(&key_set).unwrap();
+    let _server_key = server_key_set.into_server_key().unwrap();
+
+    let client_only = key_set.clone().select(KeySetComponents::CLIENT).unwrap();
+    assert_eq!(client_only.id(), key_set.id());
+    assert!(client_only.server_key().is_none());
+    assert!(client_only.select(KeySetComponents::SERVER).is_err());
+
+    let data =
+        bincode::serialize(&key_set.clone().select(KeySetComponents::CLIENT).unwrap()).unwrap();
+    let server_key_set: ServerKeySet = bincode::deserialize(&data).unwrap();
+    assert!(server_key_set.into_server_key().is_err());
+
+    let other = KeySet::generate(
+        ConfigBuilder::all_disabled().build(),
+        KeySetComponents::SERVER,
+    );
+    assert!(other.check_same_key_set(&key_set).is_err());
+}
+
+#[cfg(all(feature = "derive", feature = "boolean", feature = "integer"))]
+#[test]
+fn test_derive_fhe_encryptable() {
+    use crate::high_level_api::{set_server_key, FheEncryptable, FheUint16};
+
+    #[derive(FheEncryptable, Debug, PartialEq)]
+    struct Point(u16, u16);
+
+    #[derive(FheEncryptable, Debug, PartialEq)]
+    struct Record {
+        id: u8,
+        valid: bool,
+        position: Point,
+        tags: [u64; 2],
+    }
+
+    let config = ConfigBuilder::all_disabled()
+        .enable_default_bool()
+        .enable_default_integers()
+        .build();
+    let (client_key, server_key) = generate_keys(config);
+    set_server_key(server_key);
+
+    let record = Record {
+        id: 7,
+        valid: true,
+        position: Point(3, 4),
+        tags: [1, u64::MAX],
+    };
+    let encrypted = record.encrypt(&client_key);
+    let decrypted: Record = encrypted.decrypt(&client_key);
+    assert_eq!(decrypted, record);
+
+    // Per-field computations, through the tuple of the fields
+    let (id, valid, position, tags): (FheUint8, FheBool, EncryptedPoint, [_; 2]) = encrypted.into();
+    let (x, y): (FheUint16, FheUint16) = position.into();
+    let position = EncryptedPoint::from((&x + &y, x * 2u16));
+    let encrypted = EncryptedRecord::from((id + 1u8, !valid, position, tags));
+
+    let decrypted = Record::decrypt(&encrypted, &client_key);
+    assert_eq!(
+        decrypted,
+        Record {
+            id: 8,
+            valid: false,
+            position: Point(7, 6),
+            tags: [1, u64::MAX],
+        }
+    );
+}
@@ -78,6 +78,27 @@ pub trait FheDecrypt<T> {
     fn decrypt(&self, key: &ClientKey) -> T;
 }
 
+/// Trait for fallible decryption, with keys other than the [ClientKey].
+///
+/// Decrypting with a [ClientKey] never fails, this trait is implemented for all the types
+/// implementing [FheDecrypt] so that code generic over the key also accepts it.
+pub trait FheTryDecrypt<T, Key> {
+    type Error: std::error::Error;
+
+    fn try_decrypt(&self, key: &Key) -> Result<T, Self::Error>;
+}
+
+impl<Clear, T> FheTryDecrypt<Clear, ClientKey> for T
+where
+    T: FheDecrypt<Clear>,
+{
+    type Error = std::convert::Infallible;
+
+    fn try_decrypt(&self, key: &ClientKey) -> Result<Clear, Self::Error> {
+        Ok(self.decrypt(key))
+    }
+}
+
 /// Trait for fully homomorphic equality test.
 ///
 /// The standard trait [std::cmp::PartialEq] can not be used
@@ -109,6 +130,30 @@ pub trait FheOrd<Rhs = Self> {
     fn ge(&self, other: Rhs) -> Self::Output;
 }
 
+/// Trait for additions whose result is of a wider type, so that they cannot overflow.
+///
+/// The standard trait [std::ops::Add] can not be used, as its result wraps around in the type of
+/// the operands, unless the [adaptive precision mode] is enabled.
+///
+/// [adaptive precision mode]: crate::ConfigBuilder::enable_adaptive_integer_precision
+pub trait FheWideningAdd<Rhs = Self> {
+    type Output;
+
+    fn widening_add(&self, rhs: Rhs) -> Self::Output;
+}
+
+/// Trait for multiplications whose result is of a wider type, so that they cannot overflow.
+///
+/// The standard trait [std::ops::Mul] can not be used, as its result wraps around in the type of
+/// the operands, unless the [adaptive precision mode] is enabled.
+///
+/// [adaptive precision mode]: crate::ConfigBuilder::enable_adaptive_integer_precision
+pub trait FheWideningMul<Rhs = Self> {
+    type Output;
+
+    fn widening_mul(&self, rhs: Rhs) -> Self::Output;
+}
+
 /// Trait required to apply univariate function over homomorphic types.
 ///
 /// A `univariate function` is a function with one variable, e.g., of the form f(x).
@@ -7,7 +7,7 @@ use crate::shortint::{
 use serde::{Deserialize, Serialize};
 
 /// Structure containing a ciphertext in radix decomposition.
-#[derive(Serialize, Clone, Deserialize, PartialEq, Eq, Debug)]
+#[derive(Serialize, Clone, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
 pub struct BaseRadixCiphertext<Block> {
     /// The blocks are stored from LSB to MSB
     pub(crate) blocks: Vec<Block>,
@@ -34,6 +34,45 @@ impl<PBSOrder: PBSOrderMarker> RadixCiphertext<PBSOrder> {
     pub fn block_carries_are_empty(&self) -> bool {
         self.blocks.iter().all(|block| block.carry_is_empty())
     }
+
+    /// Returns a canonical byte encoding of the ciphertext.
+    ///
+    /// This is the number of blocks (8 bytes, little endian) followed by the
+    /// [canonical encoding](CiphertextBase::canonical_bytes) of each block, from LSB to MSB.
+    pub fn canonical_bytes(&self) -> Vec<u8> {
+        let mut bytes = (self.blocks.len() as u64).to_le_bytes().to_vec();
+        for block in &self.blocks {
+            bytes.extend_from_slice(&block.canonical_bytes());
+        }
+        bytes
+    }
+}
+
+/// Structure containing a signed integer in radix decomposition.
+///
+/// The integer is encoded in two's complement on the bits of its blocks: the blocks are the ones
+/// of the unsigned radix ciphertext of the same bits, the most significant bit of the most
+/// significant block being the sign bit.
+#[derive(Serialize, Clone, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct BaseSignedRadixCiphertext<Block> {
+    /// The blocks are stored from LSB to MSB
+    pub(crate) blocks: Vec<Block>,
+}
+
+impl<Block> From<Vec<Block>> for BaseSignedRadixCiphertext<Block> {
+    fn from(blocks: Vec<Block>) -> Self {
+        Self { blocks }
+    }
+}
+
+pub type SignedRadixCiphertext<PBSOrder> = BaseSignedRadixCiphertext<CiphertextBase<PBSOrder>>;
+pub type SignedRadixCiphertextBig = BaseSignedRadixCiphertext<CiphertextBig>;
+pub type SignedRadixCiphertextSmall = BaseSignedRadixCiphertext<CiphertextSmall>;
+
+impl<PBSOrder: PBSOrderMarker> SignedRadixCiphertext<PBSOrder> {
+    pub fn block_carries_are_empty(&self) -> bool {
+        self.blocks.iter().all(|block| block.carry_is_empty())
+    }
 }
 
 impl From<CompressedRadixCiphertextBig> for RadixCiphertextBig {
@@ -102,6 +141,34 @@ impl IntegerCiphertext for RadixCiphertextSmall {
     }
 }
 
+impl IntegerCiphertext for SignedRadixCiphertextBig {
+    type PBSOrder = KeyswitchBootstrap;
+
+    fn from_blocks(blocks: Vec<CiphertextBase<Self::PBSOrder>>) -> Self {
+        Self::from(blocks)
+    }
+    fn blocks(&self) -> &[CiphertextBase<Self::PBSOrder>] {
+        &self.blocks
+    }
+    fn blocks_mut(&mut self) -> &mut [CiphertextBase<Self::PBSOrder>] {
+        &mut self.blocks
+    }
+}
+
+impl IntegerCiphertext for SignedRadixCiphertextSmall {
+    type PBSOrder = BootstrapKeyswitch;
+
+    fn from_blocks(blocks: Vec<CiphertextBase<Self::PBSOrder>>) -> Self {
+        Self::from(blocks)
+    }
+    fn blocks(&self) -> &[CiphertextBase<Self::PBSOrder>] {
+        &self.blocks
+    }
+    fn blocks_mut(&mut self) -> &mut [CiphertextBase<Self::PBSOrder>] {
+        &mut self.blocks
+    }
+}
+
 impl IntegerCiphertext for CrtCiphertext {
     type PBSOrder = KeyswitchBootstrap;
 
@@ -154,3 +221,83 @@ impl From<CompressedCrtCiphertext> for CrtCiphertext {
         Self::from((blocks, moduli))
     }
 }
+
+/// Structure containing a ciphertext encrypting a boolean value.
+///
+/// It is made of a single block that always encrypts either 0 or 1,
+/// which is what comparisons naturally produce. Keeping the result in a single block
+/// avoids paying for the trivial blocks needed to turn it into a full radix ciphertext,
+/// and makes it cheaper to feed into further boolean logic or to send back to a client.
+///
+/// A `BooleanBlock` can also be switched to the key of a smaller parameter set, see
+/// [`BooleanBlockKeySwitchingKey`](crate::integer::server_key::boolean_block::BooleanBlockKeySwitchingKey).
+#[derive(Serialize, Clone, Deserialize)]
+pub struct BooleanBlock<PBSOrder: PBSOrderMarker>(pub(crate) CiphertextBase<PBSOrder>);
+
+pub type BooleanBlockBig = BooleanBlock<KeyswitchBootstrap>;
+pub type BooleanBlockSmall = BooleanBlock<BootstrapKeyswitch>;
+
+impl<PBSOrder: PBSOrderMarker> BooleanBlock<PBSOrder> {
+    /// Creates a `BooleanBlock` from a shortint ciphertext
+    ///
+    /// The ciphertext must encrypt either 0 or 1, this is not checked.
+    pub fn new_unchecked(block: CiphertextBase<PBSOrder>) -> Self {
+        Self(block)
+    }
+
+    pub fn as_block(&self) -> &CiphertextBase<PBSOrder> {
+        &self.0
+    }
+
+    pub fn into_block(self) -> CiphertextBase<PBSOrder> {
+        self.0
+    }
+
+    /// Converts the boolean value into a radix ciphertext of `num_blocks` blocks.
+    ///
+    /// The blocks that are added are trivial zeros created with the given `server_key`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `num_blocks` is 0
+    pub fn into_radix(
+        self,
+        num_blocks: usize,
+        server_key: &crate::integer::ServerKey,
+    ) -> RadixCiphertext<PBSOrder> {
+        assert!(
+            num_blocks > 0,
+            "Cannot create a radix ciphertext with 0 blocks"
+        );
+        let mut blocks = Vec::with_capacity(num_blocks);
+        blocks.push(self.0);
+        for _ in 1..num_blocks {
+            blocks.push(server_key.key.create_trivial(0));
+        }
+        RadixCiphertext::from(blocks)
+    }
+}
+
+/// Structure containing a ciphertext in one-hot encoding.
+///
+/// A value `v` in `[0, num_values)` is encrypted as `num_values` blocks,
+/// the block at index `v` encrypts 1 and all the others encrypt 0.
+///
+/// This takes many more blocks than the radix decomposition, in exchange,
+/// an equality test against a clear value is free (it is one of the blocks), and testing
+/// membership to a clear set is a single sum of blocks followed by one PBS.
+#[derive(Serialize, Clone, Deserialize)]
+pub struct BaseOneHotCiphertext<Block> {
+    pub(crate) blocks: Vec<Block>,
+}
+
+pub type OneHotCiphertext<PBSOrder> = BaseOneHotCiphertext<CiphertextBase<PBSOrder>>;
+pub type OneHotCiphertextBig = BaseOneHotCiphertext<CiphertextBig>;
+pub type OneHotCiphertextSmall = BaseOneHotCiphertext<CiphertextSmall>;
+
+impl<Block> BaseOneHotCiphertext<Block> {
+    /// Returns the number of distinct values this ciphertext can encode
+    pub fn num_values(&self) -> usize {
+        self.blocks.len()
+    }
+}
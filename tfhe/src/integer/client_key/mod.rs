@@ -5,14 +5,18 @@
 
 mod crt;
 mod radix;
+mod streaming;
 pub(crate) mod utils;
 
 use crate::integer::ciphertext::{
-    CompressedCrtCiphertext, CompressedRadixCiphertextBig, CrtCiphertext, RadixCiphertextBig,
-    RadixCiphertextSmall,
+    BooleanBlock, CompressedCrtCiphertext, CompressedRadixCiphertextBig, CrtCiphertext,
+    OneHotCiphertext, OneHotCiphertextBig, OneHotCiphertextSmall, RadixCiphertextBig,
+    RadixCiphertextSmall, SignedRadixCiphertext, SignedRadixCiphertextBig,
+    SignedRadixCiphertextSmall,
 };
 use crate::integer::client_key::utils::i_crt;
 use crate::integer::encryption::{encrypt_crt, encrypt_words_radix_impl, AsLittleEndianWords};
+use crate::integer::U256;
 use crate::shortint::parameters::MessageModulus;
 use crate::shortint::{
     CiphertextBase, CiphertextBig, CiphertextSmall, ClientKey as ShortintClientKey, PBSOrderMarker,
@@ -23,10 +27,42 @@ pub use utils::radix_decomposition;
 
 pub use crt::CrtClientKey;
 pub use radix::RadixClientKey;
+pub use streaming::{DecryptStream, EncryptSink, EncryptedByteChunk};
 
 use super::ciphertext::RadixCiphertext;
 use super::CompressedRadixCiphertextSmall;
 
+/// Returns the 256 bits two's complement encoding of `value`.
+fn sign_extended_words(value: i128) -> U256 {
+    let extension = if value < 0 { u64::MAX } else { 0 };
+    let value = value as u128;
+    U256([value as u64, (value >> 64) as u64, extension, extension])
+}
+
+/// Replaces the bits above the first `num_bits` ones by the bit at `num_bits - 1`, i.e. the sign
+/// bit of a `num_bits` bits integer.
+fn sign_extend_words(mut words: [u64; 4], num_bits: usize) -> [u64; 4] {
+    if num_bits == 0 {
+        return [0; 4];
+    }
+    let sign_bit = num_bits - 1;
+    let extension = if (words[sign_bit / 64] >> (sign_bit % 64)) & 1 == 1 {
+        u64::MAX
+    } else {
+        0
+    };
+    for (i, word) in words.iter_mut().enumerate() {
+        let start = 64 * i;
+        if start >= num_bits {
+            *word = extension;
+        } else if num_bits - start < 64 {
+            let mask = (1u64 << (num_bits - start)) - 1;
+            *word = (*word & mask) | (extension & !mask);
+        }
+    }
+    words
+}
+
 /// A structure containing the client key, which must be kept secret.
 ///
 /// This key can be used to encrypt both in Radix and CRT
@@ -265,6 +301,91 @@ impl ClientKey {
         self.key.decrypt(ct)
     }
 
+    /// Encrypts a value in one-hot encoding.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `message >= num_values`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::ClientKey;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let msg = 5u64;
+    ///
+    /// let ct = cks.encrypt_one_hot(msg, 8);
+    ///
+    /// let dec = cks.decrypt_one_hot(&ct);
+    /// assert_eq!(msg, dec);
+    /// ```
+    pub fn encrypt_one_hot(&self, message: u64, num_values: usize) -> OneHotCiphertextBig {
+        self.encrypt_one_hot_impl(message, num_values, |v| self.encrypt_one_block(v))
+    }
+
+    pub fn encrypt_one_hot_small(&self, message: u64, num_values: usize) -> OneHotCiphertextSmall {
+        self.encrypt_one_hot_impl(message, num_values, |v| self.encrypt_one_block_small(v))
+    }
+
+    fn encrypt_one_hot_impl<PBSOrder, F>(
+        &self,
+        message: u64,
+        num_values: usize,
+        encrypt_block: F,
+    ) -> OneHotCiphertext<PBSOrder>
+    where
+        PBSOrder: PBSOrderMarker,
+        F: Fn(u64) -> CiphertextBase<PBSOrder>,
+    {
+        assert!(
+            message < num_values as u64,
+            "The message {message} cannot be encoded with {num_values} values"
+        );
+        let blocks = (0..num_values as u64)
+            .map(|i| encrypt_block(u64::from(i == message)))
+            .collect();
+        OneHotCiphertext { blocks }
+    }
+
+    /// Decrypts a ciphertext in one-hot encoding.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the ciphertext does not have exactly one block encrypting 1.
+    pub fn decrypt_one_hot<PBSOrder: PBSOrderMarker>(
+        &self,
+        ct: &OneHotCiphertext<PBSOrder>,
+    ) -> u64 {
+        let hot_indices = ct
+            .blocks
+            .iter()
+            .enumerate()
+            .filter(|(_, block)| self.decrypt_one_block(block) == 1)
+            .map(|(i, _)| i as u64)
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            hot_indices.len(),
+            1,
+            "Invalid one-hot ciphertext: {} blocks are set",
+            hot_indices.len()
+        );
+        hot_indices[0]
+    }
+
+    /// Encrypts a boolean value as a single block encrypting 0 or 1.
+    pub fn encrypt_bool(&self, value: bool) -> BooleanBlockBig {
+        BooleanBlock::new_unchecked(self.encrypt_one_block(u64::from(value)))
+    }
+
+    /// Decrypts a ciphertext encrypting a boolean value.
+    pub fn decrypt_bool<PBSOrder: PBSOrderMarker>(&self, ct: &BooleanBlock<PBSOrder>) -> bool {
+        self.decrypt_one_block(&ct.0) != 0
+    }
+
     /// Decrypts a ciphertext encrypting an radix integer
     ///
     /// # Example
@@ -307,6 +428,78 @@ impl ClientKey {
         );
     }
 
+    /// Encrypts a signed integer in radix decomposition.
+    ///
+    /// The integer is encoded in two's complement on the `num_blocks * log2(message_modulus)`
+    /// bits of the blocks, it is wrapped if it does not fit in them.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::ClientKey;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// // 2 * 4 = 8 bits of message
+    /// let ct = cks.encrypt_signed_radix(-100i8, 4);
+    ///
+    /// let dec: i8 = cks.decrypt_signed_radix(&ct);
+    /// assert_eq!(dec, -100);
+    /// ```
+    pub fn encrypt_signed_radix<T: Into<i128>>(
+        &self,
+        message: T,
+        num_blocks: usize,
+    ) -> SignedRadixCiphertextBig {
+        self.encrypt_words_radix(
+            sign_extended_words(message.into()),
+            num_blocks,
+            crate::shortint::ClientKey::encrypt,
+        )
+    }
+
+    pub fn encrypt_signed_radix_small<T: Into<i128>>(
+        &self,
+        message: T,
+        num_blocks: usize,
+    ) -> SignedRadixCiphertextSmall {
+        self.encrypt_words_radix(
+            sign_extended_words(message.into()),
+            num_blocks,
+            crate::shortint::ClientKey::encrypt_small,
+        )
+    }
+
+    /// Decrypts a ciphertext encrypting a signed radix integer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the decrypted value does not fit in `T`.
+    pub fn decrypt_signed_radix<T, PBSOrder>(&self, ctxt: &SignedRadixCiphertext<PBSOrder>) -> T
+    where
+        T: TryFrom<i128>,
+        <T as TryFrom<i128>>::Error: std::fmt::Debug,
+        PBSOrder: PBSOrderMarker,
+    {
+        let mut words = U256::default();
+        self.decrypt_radix_into_words(
+            &RadixCiphertext::from(ctxt.blocks.clone()),
+            &mut words,
+            crate::shortint::ClientKey::decrypt_message_and_carry,
+        );
+
+        let num_bits = ctxt.blocks.len() * self.key.parameters.message_modulus().0.ilog2() as usize;
+        let words = sign_extend_words(words.0, num_bits);
+        let value = (words[0] as u128 | (words[1] as u128) << 64) as i128;
+        let extension = if value < 0 { u64::MAX } else { 0 };
+        assert!(
+            words[2] == extension && words[3] == extension,
+            "The decrypted value does not fit in an i128"
+        );
+        T::try_from(value).expect("The decrypted value does not fit in the requested type")
+    }
+
     /// Decrypts a ciphertext encrypting an radix integer encrypted without padding
     ///
     /// # Example
@@ -1,7 +1,10 @@
 //! Definition of the client key for radix decomposition
 
 use super::ClientKey;
-use crate::integer::ciphertext::RadixCiphertext;
+use crate::integer::ciphertext::{
+    BooleanBlock, BooleanBlockBig, RadixCiphertext, SignedRadixCiphertext,
+    SignedRadixCiphertextBig, SignedRadixCiphertextSmall,
+};
 use crate::integer::encryption::AsLittleEndianWords;
 use crate::integer::{RadixCiphertextBig, RadixCiphertextSmall};
 use crate::shortint::{
@@ -70,6 +73,24 @@ impl RadixClientKey {
         self.key.decrypt_radix(ciphertext)
     }
 
+    pub fn encrypt_signed<T: Into<i128>>(&self, message: T) -> SignedRadixCiphertextBig {
+        self.key.encrypt_signed_radix(message, self.num_blocks)
+    }
+
+    pub fn encrypt_signed_small<T: Into<i128>>(&self, message: T) -> SignedRadixCiphertextSmall {
+        self.key
+            .encrypt_signed_radix_small(message, self.num_blocks)
+    }
+
+    pub fn decrypt_signed<T, PBSOrder>(&self, ciphertext: &SignedRadixCiphertext<PBSOrder>) -> T
+    where
+        T: TryFrom<i128>,
+        <T as TryFrom<i128>>::Error: std::fmt::Debug,
+        PBSOrder: PBSOrderMarker,
+    {
+        self.key.decrypt_signed_radix(ciphertext)
+    }
+
     /// Returns the parameters used by the client key.
     pub fn parameters(&self) -> ShortintParameters {
         self.key.parameters()
@@ -86,6 +107,14 @@ impl RadixClientKey {
         self.key.decrypt_one_block(ct)
     }
 
+    pub fn encrypt_bool(&self, value: bool) -> BooleanBlockBig {
+        self.key.encrypt_bool(value)
+    }
+
+    pub fn decrypt_bool<PBSOrder: PBSOrderMarker>(&self, ct: &BooleanBlock<PBSOrder>) -> bool {
+        self.key.decrypt_bool(ct)
+    }
+
     pub fn num_blocks(&self) -> usize {
         self.num_blocks
     }
@@ -0,0 +1,304 @@
+//! Encryption and decryption of byte streams.
+//!
+//! The bytes are cut into chunks of bounded size, each chunk being encrypted as a radix
+//! ciphertext where each block stores as many bits as its message space allows.
+
+use super::ClientKey;
+use crate::integer::ciphertext::{CompressedRadixCiphertextBig, RadixCiphertextBig};
+use serde::{Deserialize, Serialize};
+use std::io::{self, Read, Write};
+
+/// An encrypted chunk of a byte stream.
+///
+/// The number of bytes in the chunk is stored in clear.
+#[derive(Serialize, Deserialize, Clone)]
+pub enum EncryptedByteChunk {
+    Standard {
+        num_bytes: usize,
+        ciphertext: RadixCiphertextBig,
+    },
+    Compressed {
+        num_bytes: usize,
+        ciphertext: CompressedRadixCiphertextBig,
+    },
+}
+
+impl EncryptedByteChunk {
+    /// Returns the number of clear bytes encrypted in this chunk
+    pub fn num_bytes(&self) -> usize {
+        match self {
+            Self::Standard { num_bytes, .. } | Self::Compressed { num_bytes, .. } => *num_bytes,
+        }
+    }
+
+    /// Returns the chunk as a radix ciphertext, decompressing it if needed
+    pub fn into_radix(self) -> RadixCiphertextBig {
+        match self {
+            Self::Standard { ciphertext, .. } => ciphertext,
+            Self::Compressed { ciphertext, .. } => ciphertext.into(),
+        }
+    }
+}
+
+fn bits_per_block(client_key: &ClientKey) -> usize {
+    let message_modulus = client_key.key.parameters.message_modulus().0;
+    assert!(
+        message_modulus.is_power_of_two() && message_modulus > 1,
+        "Streaming encryption requires a power of two message modulus, got {message_modulus}"
+    );
+    message_modulus.trailing_zeros() as usize
+}
+
+/// Splits the bytes into little endian digits of `bits_per_digit` bits
+fn bytes_to_digits(bytes: &[u8], bits_per_digit: usize) -> impl Iterator<Item = u64> + '_ {
+    let total_bits = bytes.len() * 8;
+    let num_digits = (total_bits + bits_per_digit - 1) / bits_per_digit;
+    (0..num_digits).map(move |digit_index| {
+        (0..bits_per_digit)
+            .map(|j| (digit_index * bits_per_digit + j, j))
+            .filter(|(bit_index, _)| *bit_index < total_bits)
+            .fold(0u64, |digit, (bit_index, j)| {
+                let bit = (bytes[bit_index / 8] >> (bit_index % 8)) & 1;
+                digit | (u64::from(bit) << j)
+            })
+    })
+}
+
+/// Inverse of [bytes_to_digits], writes `out.len()` bytes
+fn digits_to_bytes(digits: &[u64], bits_per_digit: usize, out: &mut [u8]) {
+    out.fill(0);
+    for bit_index in 0..out.len() * 8 {
+        let digit = digits[bit_index / bits_per_digit];
+        let bit = (digit >> (bit_index % bits_per_digit)) & 1;
+        out[bit_index / 8] |= (bit as u8) << (bit_index % 8);
+    }
+}
+
+/// Encrypts bytes written to it, handing the encrypted chunks to a consumer.
+///
+/// At most `chunk_size` clear bytes are buffered at any time, once the buffer is full it is
+/// encrypted and given to the consumer (which may for example serialize it to a file).
+///
+/// Calling [Write::flush] encrypts the currently buffered bytes even if the buffer is not full,
+/// [EncryptSink::finish] must be called once all the data is written.
+///
+/// # Example
+///
+/// ```rust
+/// use std::io::{Read, Write};
+/// use tfhe::integer::{ClientKey, DecryptStream, EncryptSink};
+/// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+///
+/// let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2);
+///
+/// let data = b"Hello, streaming world!";
+///
+/// let mut chunks = Vec::new();
+/// let mut sink = EncryptSink::new(&cks, 8, |chunk| {
+///     chunks.push(chunk);
+///     Ok(())
+/// });
+/// sink.write_all(data).unwrap();
+/// sink.finish().unwrap();
+/// assert_eq!(chunks.len(), 3);
+///
+/// let mut stream = DecryptStream::new(&cks, chunks.into_iter().map(Ok));
+/// let mut decrypted = Vec::new();
+/// stream.read_to_end(&mut decrypted).unwrap();
+/// assert_eq!(decrypted, data);
+/// ```
+pub struct EncryptSink<'a, F>
+where
+    F: FnMut(EncryptedByteChunk) -> io::Result<()>,
+{
+    client_key: &'a ClientKey,
+    buffer: Vec<u8>,
+    chunk_size: usize,
+    compress: bool,
+    consumer: F,
+}
+
+impl<'a, F> EncryptSink<'a, F>
+where
+    F: FnMut(EncryptedByteChunk) -> io::Result<()>,
+{
+    /// Creates a sink producing [EncryptedByteChunk::Standard] chunks of at most `chunk_size`
+    /// bytes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_size` is 0 or if the message modulus of the key is not a power of two.
+    pub fn new(client_key: &'a ClientKey, chunk_size: usize, consumer: F) -> Self {
+        Self::new_impl(client_key, chunk_size, false, consumer)
+    }
+
+    /// Creates a sink producing [EncryptedByteChunk::Compressed] chunks of at most
+    /// `chunk_size` bytes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_size` is 0 or if the message modulus of the key is not a power of two.
+    pub fn new_compressed(client_key: &'a ClientKey, chunk_size: usize, consumer: F) -> Self {
+        Self::new_impl(client_key, chunk_size, true, consumer)
+    }
+
+    fn new_impl(client_key: &'a ClientKey, chunk_size: usize, compress: bool, consumer: F) -> Self {
+        assert!(chunk_size > 0, "The chunk size must be greater than 0");
+        let _ = bits_per_block(client_key);
+        Self {
+            client_key,
+            buffer: Vec::with_capacity(chunk_size),
+            chunk_size,
+            compress,
+            consumer,
+        }
+    }
+
+    fn encrypt_buffer(&mut self) -> io::Result<()> {
+        if self.buffer.is_empty() {
+            return Ok(());
+        }
+
+        let key = &self.client_key.key;
+        let num_bytes = self.buffer.len();
+        let digits = bytes_to_digits(&self.buffer, bits_per_block(self.client_key));
+        let chunk = if self.compress {
+            EncryptedByteChunk::Compressed {
+                num_bytes,
+                ciphertext: digits
+                    .map(|d| key.encrypt_compressed(d))
+                    .collect::<Vec<_>>()
+                    .into(),
+            }
+        } else {
+            EncryptedByteChunk::Standard {
+                num_bytes,
+                ciphertext: digits.map(|d| key.encrypt(d)).collect::<Vec<_>>().into(),
+            }
+        };
+        self.buffer.clear();
+
+        (self.consumer)(chunk)
+    }
+
+    /// Encrypts the remaining buffered bytes and returns the consumer
+    pub fn finish(mut self) -> io::Result<F> {
+        self.encrypt_buffer()?;
+        Ok(self.consumer)
+    }
+}
+
+impl<'a, F> Write for EncryptSink<'a, F>
+where
+    F: FnMut(EncryptedByteChunk) -> io::Result<()>,
+{
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let num_bytes = buf.len().min(self.chunk_size - self.buffer.len());
+        self.buffer.extend_from_slice(&buf[..num_bytes]);
+        if self.buffer.len() == self.chunk_size {
+            self.encrypt_buffer()?;
+        }
+        Ok(num_bytes)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.encrypt_buffer()
+    }
+}
+
+/// Decrypts a sequence of [EncryptedByteChunk] as a byte stream.
+///
+/// Only one chunk is decrypted and kept in memory at a time.
+///
+/// See [EncryptSink] for an example.
+pub struct DecryptStream<'a, I>
+where
+    I: Iterator<Item = io::Result<EncryptedByteChunk>>,
+{
+    client_key: &'a ClientKey,
+    chunks: I,
+    buffer: Vec<u8>,
+    position: usize,
+}
+
+impl<'a, I> DecryptStream<'a, I>
+where
+    I: Iterator<Item = io::Result<EncryptedByteChunk>>,
+{
+    /// # Panics
+    ///
+    /// Panics if the message modulus of the key is not a power of two.
+    pub fn new(client_key: &'a ClientKey, chunks: I) -> Self {
+        let _ = bits_per_block(client_key);
+        Self {
+            client_key,
+            chunks,
+            buffer: Vec::new(),
+            position: 0,
+        }
+    }
+
+    fn decrypt_chunk(&mut self, chunk: EncryptedByteChunk) -> io::Result<()> {
+        let bits_per_digit = bits_per_block(self.client_key);
+        let num_bytes = chunk.num_bytes();
+        let ciphertext = chunk.into_radix();
+
+        if ciphertext.blocks.len() * bits_per_digit < num_bytes * 8 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!(
+                    "Chunk of {} blocks cannot hold {num_bytes} bytes",
+                    ciphertext.blocks.len()
+                ),
+            ));
+        }
+
+        let digits = ciphertext
+            .blocks
+            .iter()
+            .map(|block| self.client_key.decrypt_one_block(block))
+            .collect::<Vec<_>>();
+
+        self.buffer.resize(num_bytes, 0);
+        digits_to_bytes(&digits, bits_per_digit, &mut self.buffer);
+        self.position = 0;
+        Ok(())
+    }
+}
+
+impl<'a, I> Read for DecryptStream<'a, I>
+where
+    I: Iterator<Item = io::Result<EncryptedByteChunk>>,
+{
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        while self.position == self.buffer.len() {
+            match self.chunks.next() {
+                Some(chunk) => self.decrypt_chunk(chunk?)?,
+                None => return Ok(0),
+            }
+        }
+
+        let num_bytes = buf.len().min(self.buffer.len() - self.position);
+        buf[..num_bytes].copy_from_slice(&self.buffer[self.position..self.position + num_bytes]);
+        self.position += num_bytes;
+        Ok(num_bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bytes_digits_round_trip() {
+        let bytes = (0..=255u8).collect::<Vec<_>>();
+        for bits_per_digit in 1..=8 {
+            let digits = bytes_to_digits(&bytes, bits_per_digit).collect::<Vec<_>>();
+            assert!(digits.iter().all(|d| *d < (1 << bits_per_digit)));
+
+            let mut decoded = vec![0u8; bytes.len()];
+            digits_to_bytes(&digits, bits_per_digit, &mut decoded);
+            assert_eq!(decoded, bytes);
+        }
+    }
+}
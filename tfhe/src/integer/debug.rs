@@ -0,0 +1,197 @@
+//! Runtime checks of the invariants of the integer operations, using a client key.
+//!
+//! The carries and the degrees of the blocks of a radix ciphertext are tracked by the server key
+//! without decrypting anything, so a bug in a new homomorphic algorithm usually shows up much
+//! later as a wrong decryption. With the `debug-client-key` feature, a [`DebugClientKey`] can be
+//! registered with [`set_debug_client_key`]: the default operations of the [`ServerKey`] then
+//! decrypt their results and panic, showing the clear blocks, as soon as an invariant is broken:
+//!
+//! * the value of each block, carries included, must not exceed its degree;
+//! * the outputs of the operations propagating the carries must have empty carries.
+//!
+//! Algorithms written downstream can check their own invariants with [`debug_assert_radix`].
+//!
+//! Like the server key of the high level API, the key is registered for the current thread only:
+//! the checks done on the threads of the rayon thread pool are skipped. The checks decrypt the
+//! ciphertexts, this feature must never be enabled in production.
+//!
+//! [`ServerKey`]: crate::integer::ServerKey
+//!
+//! # Example
+//!
+//! ```rust
+//! use tfhe::integer::debug::{debug_assert_radix, set_debug_client_key, DebugClientKey};
+//! use tfhe::integer::gen_keys_radix;
+//! use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+//!
+//! let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2, 4);
+//! set_debug_client_key(DebugClientKey::new(cks.as_ref().clone()));
+//!
+//! let ct = sks.add_parallelized(&cks.encrypt(100u64), &cks.encrypt(27u64));
+//!
+//! // Panics with the clear blocks if the invariant does not hold
+//! debug_assert_radix("sum", &ct, |blocks| blocks.iter().all(|&block| block < 4));
+//! ```
+
+use crate::integer::{ClientKey, RadixCiphertext};
+use crate::shortint::PBSOrderMarker;
+use std::cell::RefCell;
+
+thread_local! {
+    static DEBUG_CLIENT_KEY: RefCell<Option<DebugClientKey>> = RefCell::new(None);
+}
+
+/// A client key used to check the invariants of the operations on the current thread.
+#[derive(Clone)]
+pub struct DebugClientKey {
+    key: ClientKey,
+}
+
+impl DebugClientKey {
+    pub fn new(key: ClientKey) -> Self {
+        Self { key }
+    }
+
+    /// Decrypts the message and the carries of each block, from the least significant one.
+    fn decrypt_blocks<PBSOrder: PBSOrderMarker>(&self, ct: &RadixCiphertext<PBSOrder>) -> Vec<u64> {
+        ct.blocks
+            .iter()
+            .map(|block| self.key.key.decrypt_message_and_carry(block))
+            .collect()
+    }
+}
+
+impl From<ClientKey> for DebugClientKey {
+    fn from(key: ClientKey) -> Self {
+        Self::new(key)
+    }
+}
+
+/// Registers the key checking the invariants of the operations of the current thread, returning
+/// the previous one.
+pub fn set_debug_client_key(key: DebugClientKey) -> Option<DebugClientKey> {
+    DEBUG_CLIENT_KEY.with(|debug_key| debug_key.borrow_mut().replace(key))
+}
+
+/// Unregisters the key of the current thread, disabling the checks.
+pub fn unset_debug_client_key() -> Option<DebugClientKey> {
+    DEBUG_CLIENT_KEY.with(|debug_key| debug_key.borrow_mut().take())
+}
+
+/// Calls `check` with the clear blocks of `ct` if a key is registered for the current thread,
+/// panicking with the clear blocks and `message` if `check` returns false.
+fn check_blocks<PBSOrder, F>(context: &str, ct: &RadixCiphertext<PBSOrder>, message: &str, check: F)
+where
+    PBSOrder: PBSOrderMarker,
+    F: FnOnce(&[u64]) -> bool,
+{
+    DEBUG_CLIENT_KEY.with(|debug_key| {
+        if let Some(debug_key) = debug_key.borrow().as_ref() {
+            let blocks = debug_key.decrypt_blocks(ct);
+            if !check(&blocks) {
+                let degrees = ct
+                    .blocks
+                    .iter()
+                    .map(|block| block.degree.0)
+                    .collect::<Vec<_>>();
+                panic!(
+                    "Invariant violated in {context}: {message}\n\
+                    clear blocks (message and carries, LSB first): {blocks:?}\n\
+                    degrees: {degrees:?}"
+                );
+            }
+        }
+    });
+}
+
+/// Checks a user-defined invariant on the clear blocks (message and carries, from the least
+/// significant one) of `ct`, if a [`DebugClientKey`] is registered for the current thread.
+///
+/// # Panics
+///
+/// Panics with the clear blocks if `invariant` returns false.
+pub fn debug_assert_radix<PBSOrder, F>(context: &str, ct: &RadixCiphertext<PBSOrder>, invariant: F)
+where
+    PBSOrder: PBSOrderMarker,
+    F: FnOnce(&[u64]) -> bool,
+{
+    check_blocks(
+        context,
+        ct,
+        "the user-defined invariant does not hold",
+        invariant,
+    );
+}
+
+/// Checks that the value of each block of `ct` does not exceed its degree.
+pub fn check_degrees<PBSOrder: PBSOrderMarker>(context: &str, ct: &RadixCiphertext<PBSOrder>) {
+    check_blocks(context, ct, "a block exceeds its degree", |blocks| {
+        blocks
+            .iter()
+            .zip(ct.blocks.iter())
+            .all(|(&value, block)| value <= block.degree.0 as u64)
+    });
+}
+
+/// Checks that the carries of the blocks of `ct` are empty, and that their degrees are right.
+pub fn check_carries_empty<PBSOrder: PBSOrderMarker>(
+    context: &str,
+    ct: &RadixCiphertext<PBSOrder>,
+) {
+    check_degrees(context, ct);
+    check_blocks(context, ct, "the carries are not empty", |blocks| {
+        blocks
+            .iter()
+            .zip(ct.blocks.iter())
+            .all(|(&value, block)| value < block.message_modulus.0 as u64)
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::integer::keycache::KEY_CACHE;
+    use crate::shortint::ciphertext::Degree;
+    use crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+
+    #[test]
+    fn test_debug_client_key() {
+        let (cks, sks) = KEY_CACHE.get_from_params(PARAM_MESSAGE_2_CARRY_2);
+
+        // Without a registered key, nothing is checked
+        let mut ct = cks.encrypt_radix(3u64, 2);
+        ct.blocks[0].degree = Degree(0);
+        check_degrees("forged degree", &ct);
+
+        assert!(set_debug_client_key(DebugClientKey::new(cks.clone())).is_none());
+
+        // The default operations pass their own checks
+        let ct_res =
+            sks.add_parallelized(&cks.encrypt_radix(13u64, 2), &cks.encrypt_radix(7u64, 2));
+        let ct_res = sks.mul_parallelized(&ct_res, &cks.encrypt_radix(3u64, 2));
+        let res: u64 = cks.decrypt_radix(&ct_res);
+        assert_eq!(res, 12);
+
+        let result = catch_unwind(AssertUnwindSafe(|| check_degrees("forged degree", &ct)));
+        let payload = result.unwrap_err();
+        let message = payload.downcast_ref::<String>().unwrap();
+        assert!(message.contains("forged degree"));
+        assert!(message.contains("[3, 0]"));
+
+        let ct = sks.unchecked_add(&cks.encrypt_radix(3u64, 2), &cks.encrypt_radix(3u64, 2));
+        check_degrees("unchecked_add", &ct);
+        let result = catch_unwind(AssertUnwindSafe(|| {
+            check_carries_empty("unchecked_add", &ct)
+        }));
+        assert!(result.is_err());
+
+        let result = catch_unwind(AssertUnwindSafe(|| {
+            debug_assert_radix("user", &ct, |blocks| blocks[0] < 4)
+        }));
+        assert!(result.is_err());
+
+        assert!(unset_debug_client_key().is_some());
+        check_carries_empty("unchecked_add", &ct);
+    }
+}
@@ -47,6 +47,15 @@
 //! that the message and carry buffers have the same size.
 extern crate core;
 
+/// Checks the invariants of the result of an operation with the debug client key registered
+/// for the current thread, expands to nothing without the `debug-client-key` feature.
+macro_rules! debug_check {
+    ($check:ident, $context:expr, $ct:expr) => {
+        #[cfg(feature = "debug-client-key")]
+        $crate::integer::debug::$check($context, $ct);
+    };
+}
+
 #[cfg(test)]
 #[macro_use]
 mod tests;
@@ -54,23 +63,32 @@ pub(crate) mod encryption;
 
 pub mod ciphertext;
 pub mod client_key;
+#[cfg(feature = "debug-client-key")]
+pub mod debug;
 #[cfg(any(test, feature = "internal-keycache"))]
 pub mod keycache;
 pub mod parameters;
+pub mod pipeline;
 pub mod public_key;
+pub mod scheduler;
 pub mod server_key;
+pub mod strings;
 pub mod u256;
 pub mod wopbs;
 
 pub use ciphertext::{
-    CompressedRadixCiphertextBig, CompressedRadixCiphertextSmall, CrtCiphertext, IntegerCiphertext,
-    RadixCiphertextBig, RadixCiphertextSmall,
+    BooleanBlock, BooleanBlockBig, BooleanBlockSmall, CompressedRadixCiphertextBig,
+    CompressedRadixCiphertextSmall, CrtCiphertext, IntegerCiphertext, OneHotCiphertextBig,
+    OneHotCiphertextSmall, RadixCiphertextBig, RadixCiphertextSmall, SignedRadixCiphertextBig,
+    SignedRadixCiphertextSmall,
+};
+pub use client_key::{
+    ClientKey, CrtClientKey, DecryptStream, EncryptSink, EncryptedByteChunk, RadixClientKey,
 };
-pub use client_key::{ClientKey, CrtClientKey, RadixClientKey};
 pub use public_key::{
     CompressedPublicKeyBig, CompressedPublicKeySmall, PublicKeyBig, PublicKeySmall,
 };
-pub use server_key::{CheckError, CompressedServerKey, ServerKey};
+pub use server_key::{CheckError, CompressedServerKey, NumThreads, ServerKey};
 pub use u256::U256;
 
 /// Generate a couple of client and server keys with given parameters
@@ -104,8 +122,12 @@ where
             lwe_dimension: wopbs_params.lwe_dimension,
             glwe_dimension: wopbs_params.glwe_dimension,
             polynomial_size: wopbs_params.polynomial_size,
-            lwe_modular_std_dev: wopbs_params.lwe_modular_std_dev,
-            glwe_modular_std_dev: wopbs_params.glwe_modular_std_dev,
+            lwe_noise_distribution: crate::shortint::parameters::DynamicDistribution::Gaussian(
+                wopbs_params.lwe_modular_std_dev,
+            ),
+            glwe_noise_distribution: crate::shortint::parameters::DynamicDistribution::Gaussian(
+                wopbs_params.glwe_modular_std_dev,
+            ),
             pbs_base_log: wopbs_params.pbs_base_log,
             pbs_level: wopbs_params.pbs_level,
             ks_base_log: wopbs_params.ks_base_log,
@@ -114,6 +136,7 @@ where
             carry_modulus: wopbs_params.carry_modulus,
             ciphertext_modulus: wopbs_params.ciphertext_modulus,
             encryption_key_choice: wopbs_params.encryption_key_choice,
+            secret_key_distribution: crate::shortint::parameters::SecretKeyDistribution::Binary,
         };
 
         crate::shortint::parameters::ShortintParameterSet::try_new_pbs_and_wopbs_param_set((
@@ -0,0 +1,511 @@
+//! Streaming computations over radix ciphertexts, split in stages run concurrently.
+//!
+//! A pipeline is a chain of [`Stage`]s, each applying a homomorphic transform (additions, lookup
+//! tables, comparisons, ...) to the ciphertexts flowing through it. No decryption happens inside
+//! the pipeline, stages only need the [`ServerKey`].
+//!
+//! Each stage is run by its own worker threads and stages are connected by bounded channels: when
+//! a stage falls behind, the channel in front of it fills up and the upstream stages, and
+//! eventually the producer, block until there is room again. The number of ciphertexts in flight,
+//! and so the memory used by the pipeline, is therefore bounded whatever the input rate.
+//!
+//! A worker takes up to [`Stage::with_max_batch_size`] ciphertexts already waiting in its input
+//! channel and processes them as a single batch, so that a stage under load spreads the whole
+//! batch on the thread pool of the server key instead of one ciphertext at a time.
+//!
+//! The outputs are returned in the order of the inputs, whatever the number of workers.
+//!
+//! # Example
+//!
+//! ```rust
+//! use tfhe::integer::gen_keys_radix;
+//! use tfhe::integer::pipeline::{PipelineBuilder, Stage};
+//! use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+//!
+//! let num_blocks = 4;
+//! let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2, num_blocks);
+//!
+//! let threshold = sks.create_trivial_radix(100u64, num_blocks);
+//! let (mut input, output) = PipelineBuilder::new(sks)
+//!     .stage(Stage::map(|key, ct| key.scalar_mul_parallelized(ct, 3)))
+//!     .stage(Stage::map(move |key, ct| key.gt_parallelized(ct, &threshold)).with_workers(2))
+//!     .build();
+//!
+//! // Producer and consumer usually run on different threads
+//! let clears = [12u64, 40, 33, 34];
+//! let producer = std::thread::spawn(move || {
+//!     for clear in clears {
+//!         input.send(cks.encrypt(clear)).unwrap();
+//!     }
+//!     // Dropping the input ends the stream
+//!     cks
+//! });
+//!
+//! let results = output.collect::<Vec<_>>();
+//! let cks = producer.join().unwrap();
+//!
+//! let decrypted = results
+//!     .iter()
+//!     .map(|ct| cks.decrypt::<u64, _>(ct))
+//!     .collect::<Vec<_>>();
+//! assert_eq!(decrypted, [0, 1, 0, 1]);
+//! ```
+
+use crate::integer::ciphertext::RadixCiphertext;
+use crate::integer::ServerKey;
+use crate::shortint::PBSOrderMarker;
+use rayon::prelude::*;
+use std::any::Any;
+use std::collections::BTreeMap;
+use std::panic::{catch_unwind, resume_unwind, AssertUnwindSafe};
+use std::sync::mpsc::{sync_channel, Receiver, SendError, SyncSender, TrySendError};
+use std::sync::{Arc, Mutex};
+use std::thread::JoinHandle;
+
+type Batch<PBSOrder> = Vec<RadixCiphertext<PBSOrder>>;
+type BatchOp<PBSOrder> = dyn Fn(&ServerKey, Batch<PBSOrder>) -> Batch<PBSOrder> + Send + Sync;
+
+enum Message<PBSOrder: PBSOrderMarker> {
+    /// A ciphertext along with its position in the input stream
+    Item(u64, RadixCiphertext<PBSOrder>),
+    /// The payload of a panic of a stage, forwarded to the output
+    Panic(Box<dyn Any + Send>),
+}
+
+/// A step of a pipeline, see the [module documentation](self).
+pub struct Stage<PBSOrder: PBSOrderMarker> {
+    op: Arc<BatchOp<PBSOrder>>,
+    num_workers: usize,
+    max_batch_size: usize,
+}
+
+impl<PBSOrder: PBSOrderMarker> Stage<PBSOrder> {
+    /// Creates a stage applying `f` to each ciphertext.
+    ///
+    /// The ciphertexts of a batch are processed in parallel on the thread pool of the server key.
+    pub fn map<F>(f: F) -> Self
+    where
+        F: Fn(&ServerKey, &RadixCiphertext<PBSOrder>) -> RadixCiphertext<PBSOrder>
+            + Send
+            + Sync
+            + 'static,
+    {
+        Self::batch(move |server_key, batch| {
+            server_key.install(|| batch.par_iter().map(|ct| f(server_key, ct)).collect())
+        })
+    }
+
+    /// Creates a stage applying `f` to whole batches of ciphertexts.
+    ///
+    /// `f` must return exactly one ciphertext per input ciphertext, in the same order.
+    pub fn batch<F>(f: F) -> Self
+    where
+        F: Fn(&ServerKey, Vec<RadixCiphertext<PBSOrder>>) -> Vec<RadixCiphertext<PBSOrder>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        Self {
+            op: Arc::new(f),
+            num_workers: 1,
+            max_batch_size: 1,
+        }
+    }
+
+    /// Runs the stage on `num_workers` threads, one by default.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `num_workers` is 0.
+    pub fn with_workers(mut self, num_workers: usize) -> Self {
+        assert!(num_workers > 0, "A stage needs at least one worker");
+        self.num_workers = num_workers;
+        self
+    }
+
+    /// Lets a worker coalesce up to `max_batch_size` waiting ciphertexts in a single batch, one by
+    /// default.
+    ///
+    /// A worker never waits for a batch to fill up: it processes the ciphertexts available when
+    /// it becomes idle.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_batch_size` is 0.
+    pub fn with_max_batch_size(mut self, max_batch_size: usize) -> Self {
+        assert!(
+            max_batch_size > 0,
+            "The batches need at least one ciphertext"
+        );
+        self.max_batch_size = max_batch_size;
+        self
+    }
+
+    /// Pops the next batch from `receiver`, returns `None` once the stream is over.
+    fn next_batch(
+        &self,
+        receiver: &Mutex<Receiver<Message<PBSOrder>>>,
+        sender: &SyncSender<Message<PBSOrder>>,
+    ) -> Option<(Vec<u64>, Vec<RadixCiphertext<PBSOrder>>)> {
+        let receiver = receiver.lock().unwrap();
+        let mut indices = Vec::with_capacity(self.max_batch_size);
+        let mut batch = Vec::with_capacity(self.max_batch_size);
+
+        let mut message = receiver.recv().ok()?;
+        loop {
+            match message {
+                Message::Item(index, ct) => {
+                    indices.push(index);
+                    batch.push(ct);
+                }
+                // Panics are forwarded as is, the output was dropped if the send fails
+                Message::Panic(payload) => {
+                    let _ = sender.send(Message::Panic(payload));
+                }
+            }
+
+            if batch.len() == self.max_batch_size {
+                break;
+            }
+            message = match receiver.try_recv() {
+                Ok(message) => message,
+                Err(_) => break,
+            };
+        }
+
+        Some((indices, batch))
+    }
+
+    fn worker_loop(
+        &self,
+        server_key: &ServerKey,
+        receiver: &Mutex<Receiver<Message<PBSOrder>>>,
+        sender: &SyncSender<Message<PBSOrder>>,
+    ) {
+        while let Some((indices, batch)) = self.next_batch(receiver, sender) {
+            if batch.is_empty() {
+                continue;
+            }
+
+            let result = catch_unwind(AssertUnwindSafe(|| {
+                let outputs = (self.op)(server_key, batch);
+                assert_eq!(
+                    outputs.len(),
+                    indices.len(),
+                    "A stage must output one ciphertext per input ciphertext"
+                );
+                outputs
+            }));
+
+            let messages = match result {
+                Ok(outputs) => indices
+                    .into_iter()
+                    .zip(outputs)
+                    .map(|(index, ct)| Message::Item(index, ct))
+                    .collect(),
+                Err(payload) => vec![Message::Panic(payload)],
+            };
+
+            for message in messages {
+                // Blocks while the next stage is busy, the send only fails if the output was
+                // dropped, in which case the results are not needed anymore
+                if sender.send(message).is_err() {
+                    return;
+                }
+            }
+        }
+    }
+}
+
+/// Builds a pipeline, see the [module documentation](self).
+pub struct PipelineBuilder<PBSOrder: PBSOrderMarker> {
+    server_key: ServerKey,
+    stages: Vec<Stage<PBSOrder>>,
+    channel_capacity: usize,
+}
+
+impl<PBSOrder: PBSOrderMarker> PipelineBuilder<PBSOrder> {
+    /// The default number of ciphertexts waiting in front of each stage
+    pub const DEFAULT_CHANNEL_CAPACITY: usize = 16;
+
+    /// Creates a pipeline without stages, running its future stages with `server_key`.
+    pub fn new(server_key: ServerKey) -> Self {
+        Self {
+            server_key,
+            stages: Vec::new(),
+            channel_capacity: Self::DEFAULT_CHANNEL_CAPACITY,
+        }
+    }
+
+    /// Appends a stage at the end of the pipeline.
+    pub fn stage(mut self, stage: Stage<PBSOrder>) -> Self {
+        self.stages.push(stage);
+        self
+    }
+
+    /// Sets the maximum number of ciphertexts waiting in front of each stage, and in front of the
+    /// output.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `channel_capacity` is 0.
+    pub fn with_channel_capacity(mut self, channel_capacity: usize) -> Self {
+        assert!(
+            channel_capacity > 0,
+            "The channels need room for at least one ciphertext"
+        );
+        self.channel_capacity = channel_capacity;
+        self
+    }
+
+    /// Starts the workers of the stages, returning the two ends of the pipeline.
+    pub fn build(self) -> (PipelineInput<PBSOrder>, PipelineOutput<PBSOrder>) {
+        let server_key = Arc::new(self.server_key);
+
+        let (sender, mut receiver) = sync_channel(self.channel_capacity);
+        let mut workers = Vec::new();
+
+        for stage in self.stages {
+            let (next_sender, next_receiver) = sync_channel(self.channel_capacity);
+            let stage = Arc::new(stage);
+            let stage_receiver = Arc::new(Mutex::new(receiver));
+
+            for _ in 0..stage.num_workers {
+                let stage = Arc::clone(&stage);
+                let server_key = Arc::clone(&server_key);
+                let receiver = Arc::clone(&stage_receiver);
+                let sender = next_sender.clone();
+                workers.push(std::thread::spawn(move || {
+                    stage.worker_loop(&server_key, &receiver, &sender)
+                }));
+            }
+
+            receiver = next_receiver;
+        }
+
+        (
+            PipelineInput {
+                sender,
+                next_index: 0,
+            },
+            PipelineOutput {
+                receiver,
+                next_index: 0,
+                pending: BTreeMap::new(),
+                workers,
+            },
+        )
+    }
+}
+
+/// The end of a pipeline receiving the input ciphertexts.
+///
+/// Dropping the input ends the stream: the output returns the remaining results then stops.
+pub struct PipelineInput<PBSOrder: PBSOrderMarker> {
+    sender: SyncSender<Message<PBSOrder>>,
+    next_index: u64,
+}
+
+impl<PBSOrder: PBSOrderMarker> PipelineInput<PBSOrder> {
+    /// Sends a ciphertext into the pipeline, blocking while the first stage has no room for it.
+    ///
+    /// Returns the ciphertext back as an error if the output has been dropped.
+    pub fn send(
+        &mut self,
+        ct: RadixCiphertext<PBSOrder>,
+    ) -> Result<(), SendError<RadixCiphertext<PBSOrder>>> {
+        match self.sender.send(Message::Item(self.next_index, ct)) {
+            Ok(()) => {
+                self.next_index += 1;
+                Ok(())
+            }
+            Err(SendError(message)) => Err(SendError(Self::into_ciphertext(message))),
+        }
+    }
+
+    /// Sends a ciphertext into the pipeline if the first stage has room for it.
+    ///
+    /// Returns [`TrySendError::Full`] with the ciphertext when the pipeline is applying
+    /// backpressure, and [`TrySendError::Disconnected`] if the output has been dropped.
+    pub fn try_send(
+        &mut self,
+        ct: RadixCiphertext<PBSOrder>,
+    ) -> Result<(), TrySendError<RadixCiphertext<PBSOrder>>> {
+        match self.sender.try_send(Message::Item(self.next_index, ct)) {
+            Ok(()) => {
+                self.next_index += 1;
+                Ok(())
+            }
+            Err(TrySendError::Full(message)) => {
+                Err(TrySendError::Full(Self::into_ciphertext(message)))
+            }
+            Err(TrySendError::Disconnected(message)) => {
+                Err(TrySendError::Disconnected(Self::into_ciphertext(message)))
+            }
+        }
+    }
+
+    fn into_ciphertext(message: Message<PBSOrder>) -> RadixCiphertext<PBSOrder> {
+        match message {
+            Message::Item(_, ct) => ct,
+            Message::Panic(_) => unreachable!("The input only sends ciphertexts"),
+        }
+    }
+}
+
+/// The end of a pipeline returning the output ciphertexts, in the order of the inputs.
+///
+/// Dropping the output stops the workers once they are done with their current batch.
+pub struct PipelineOutput<PBSOrder: PBSOrderMarker> {
+    receiver: Receiver<Message<PBSOrder>>,
+    next_index: u64,
+    pending: BTreeMap<u64, RadixCiphertext<PBSOrder>>,
+    workers: Vec<JoinHandle<()>>,
+}
+
+impl<PBSOrder: PBSOrderMarker> PipelineOutput<PBSOrder> {
+    /// Blocks until the next result is available and returns it, or returns `None` once the input
+    /// has been dropped and all the results have been returned.
+    ///
+    /// # Panics
+    ///
+    /// Panics with the payload of the panic of a stage if a stage panicked.
+    pub fn recv(&mut self) -> Option<RadixCiphertext<PBSOrder>> {
+        loop {
+            if let Some(ct) = self.pending.remove(&self.next_index) {
+                self.next_index += 1;
+                return Some(ct);
+            }
+
+            match self.receiver.recv() {
+                Ok(Message::Item(index, ct)) => {
+                    self.pending.insert(index, ct);
+                }
+                Ok(Message::Panic(payload)) => resume_unwind(payload),
+                Err(_) => {
+                    // All the workers are done, as they dropped their senders
+                    for worker in self.workers.drain(..) {
+                        worker.join().unwrap();
+                    }
+                    return None;
+                }
+            }
+        }
+    }
+}
+
+impl<PBSOrder: PBSOrderMarker> Iterator for PipelineOutput<PBSOrder> {
+    type Item = RadixCiphertext<PBSOrder>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.recv()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::integer::keycache::KEY_CACHE;
+    use crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    use std::sync::mpsc::channel;
+
+    #[test]
+    fn test_pipeline_keeps_input_order() {
+        let (cks, sks) = KEY_CACHE.get_from_params(PARAM_MESSAGE_2_CARRY_2);
+        let num_blocks = 4;
+
+        let batch_sizes = Arc::new(Mutex::new(Vec::new()));
+        let (mut input, output) = {
+            let batch_sizes = Arc::clone(&batch_sizes);
+            PipelineBuilder::new(sks)
+                .with_channel_capacity(2)
+                .stage(Stage::map(|key, ct| key.scalar_add_parallelized(ct, 7)).with_workers(3))
+                .stage(
+                    Stage::batch(move |key, batch| {
+                        batch_sizes.lock().unwrap().push(batch.len());
+                        batch
+                            .iter()
+                            .map(|ct| key.scalar_mul_parallelized(ct, 2))
+                            .collect()
+                    })
+                    .with_max_batch_size(4),
+                )
+                .build()
+        };
+
+        let clears = (0..12u64).map(|i| (i * 37) % 256).collect::<Vec<_>>();
+        let cts = clears
+            .iter()
+            .map(|&clear| cks.encrypt_radix(clear, num_blocks))
+            .collect::<Vec<_>>();
+        let producer = std::thread::spawn(move || {
+            for ct in cts {
+                input.send(ct).unwrap();
+            }
+        });
+
+        let decrypted = output
+            .map(|ct| cks.decrypt_radix::<u64, _>(&ct))
+            .collect::<Vec<_>>();
+        producer.join().unwrap();
+
+        let expected = clears
+            .iter()
+            .map(|clear| ((clear + 7) * 2) % 256)
+            .collect::<Vec<_>>();
+        assert_eq!(decrypted, expected);
+
+        let batch_sizes = batch_sizes.lock().unwrap();
+        assert_eq!(batch_sizes.iter().sum::<usize>(), clears.len());
+        assert!(batch_sizes.iter().all(|&size| (1..=4).contains(&size)));
+    }
+
+    #[test]
+    fn test_pipeline_backpressure() {
+        let (cks, sks) = KEY_CACHE.get_from_params(PARAM_MESSAGE_2_CARRY_2);
+
+        let (started, has_started) = channel::<()>();
+        let (release, released) = channel::<()>();
+        let started = Mutex::new(started);
+        let released = Mutex::new(released);
+        let (mut input, mut output) = PipelineBuilder::new(sks)
+            .with_channel_capacity(1)
+            .stage(Stage::batch(move |_, batch| {
+                started.lock().unwrap().send(()).unwrap();
+                released.lock().unwrap().recv().unwrap();
+                batch
+            }))
+            .build();
+
+        // The first ciphertext is held by the blocked worker, the second one waits in front of it
+        input.send(cks.encrypt_radix(1u64, 2)).unwrap();
+        has_started.recv().unwrap();
+        assert!(input.try_send(cks.encrypt_radix(2u64, 2)).is_ok());
+        assert!(matches!(
+            input.try_send(cks.encrypt_radix(3u64, 2)),
+            Err(TrySendError::Full(_))
+        ));
+
+        drop(input);
+        for expected in [1u64, 2] {
+            release.send(()).unwrap();
+            let dec: u64 = cks.decrypt_radix(&output.recv().unwrap());
+            assert_eq!(dec, expected);
+        }
+        assert!(output.recv().is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "stage failed")]
+    fn test_pipeline_propagates_panics() {
+        let (cks, sks) = KEY_CACHE.get_from_params(PARAM_MESSAGE_2_CARRY_2);
+
+        let (mut input, mut output) = PipelineBuilder::new(sks)
+            .stage(Stage::map(|_, _| panic!("stage failed")))
+            .stage(Stage::map(|_, ct| ct.clone()))
+            .build();
+
+        input.send(cks.encrypt_radix(1u64, 2)).unwrap();
+        output.recv();
+    }
+}
@@ -0,0 +1,344 @@
+//! Priority-aware scheduling of homomorphic operations sharing the same server key.
+//!
+//! Operations are submitted to a [`Scheduler`] with a [`Priority`] and run by a fixed set of
+//! worker threads. Workers always pick interactive operations before batch ones.
+//!
+//! Batch operations can additionally be preempted: at each block boundary, i.e. each call to
+//! [`JobContext::checkpoint`] or between two blocks processed by [`JobContext::map_blocks`], a
+//! batch operation first runs the interactive operations waiting in the queue before resuming.
+//!
+//! # Example
+//!
+//! ```rust
+//! use tfhe::integer::gen_keys_radix;
+//! use tfhe::integer::scheduler::{Priority, Scheduler};
+//! use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+//!
+//! let num_blocks = 4;
+//! let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2, num_blocks);
+//!
+//! let scheduler = Scheduler::new(sks, 2);
+//!
+//! let ct = cks.encrypt(13u64);
+//! let batch = {
+//!     let ct = ct.clone();
+//!     scheduler.submit(Priority::Batch, move |ctx| {
+//!         ctx.map_blocks(&ct, |key, block| key.scalar_add(block, 1))
+//!     })
+//! };
+//! let interactive = scheduler.submit(Priority::Interactive, move |ctx| {
+//!     ctx.server_key().scalar_mul_parallelized(&ct, 3)
+//! });
+//!
+//! let dec: u64 = cks.decrypt(&interactive.wait());
+//! assert_eq!(dec, 39);
+//! let dec: u64 = cks.decrypt(&batch.wait());
+//! assert_eq!(dec, 13 + 0b01010101);
+//! ```
+
+use crate::integer::ciphertext::RadixCiphertext;
+use crate::integer::ServerKey;
+use crate::shortint::{CiphertextBase, PBSOrderMarker};
+use std::collections::VecDeque;
+use std::panic::{catch_unwind, resume_unwind, AssertUnwindSafe};
+use std::sync::mpsc::{channel, Receiver};
+use std::sync::{Arc, Condvar, Mutex};
+use std::thread::JoinHandle;
+
+/// Priority of an operation submitted to a [`Scheduler`].
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Copy, Clone)]
+pub enum Priority {
+    /// Throughput oriented work, preempted at block boundaries by interactive work
+    Batch,
+    /// Latency sensitive work, always run before batch work
+    Interactive,
+}
+
+type Job = Box<dyn FnOnce(&JobContext) + Send>;
+
+#[derive(Default)]
+struct Queues {
+    interactive: VecDeque<Job>,
+    batch: VecDeque<Job>,
+    shutdown: bool,
+}
+
+impl Queues {
+    fn pop(&mut self) -> Option<(Priority, Job)> {
+        self.interactive
+            .pop_front()
+            .map(|job| (Priority::Interactive, job))
+            .or_else(|| self.batch.pop_front().map(|job| (Priority::Batch, job)))
+    }
+}
+
+struct Shared {
+    server_key: ServerKey,
+    queues: Mutex<Queues>,
+    job_available: Condvar,
+}
+
+impl Shared {
+    fn run(&self, priority: Priority, job: Job) {
+        let context = JobContext {
+            shared: self,
+            priority,
+        };
+        job(&context);
+    }
+
+    fn worker_loop(&self) {
+        loop {
+            let (priority, job) = {
+                let mut queues = self.queues.lock().unwrap();
+                loop {
+                    if let Some(next) = queues.pop() {
+                        break next;
+                    }
+                    if queues.shutdown {
+                        return;
+                    }
+                    queues = self.job_available.wait(queues).unwrap();
+                }
+            };
+            self.run(priority, job);
+        }
+    }
+}
+
+/// Runs homomorphic operations submitted with a [`Priority`] on a fixed number of worker threads.
+///
+/// Dropping the scheduler waits for all the submitted operations to complete.
+pub struct Scheduler {
+    shared: Arc<Shared>,
+    workers: Vec<JoinHandle<()>>,
+}
+
+impl Scheduler {
+    /// Creates a scheduler running operations with `server_key` on `num_workers` threads.
+    ///
+    /// Operations still use the thread pool of the server key to parallelize their own work.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `num_workers` is 0.
+    pub fn new(server_key: ServerKey, num_workers: usize) -> Self {
+        assert!(num_workers > 0, "The scheduler needs at least one worker");
+
+        let shared = Arc::new(Shared {
+            server_key,
+            queues: Mutex::new(Queues::default()),
+            job_available: Condvar::new(),
+        });
+
+        let workers = (0..num_workers)
+            .map(|_| {
+                let shared = Arc::clone(&shared);
+                std::thread::spawn(move || shared.worker_loop())
+            })
+            .collect();
+
+        Self { shared, workers }
+    }
+
+    pub fn server_key(&self) -> &ServerKey {
+        &self.shared.server_key
+    }
+
+    /// Submits an operation, the returned handle gives access to its result.
+    pub fn submit<F, R>(&self, priority: Priority, op: F) -> JobHandle<R>
+    where
+        F: FnOnce(&JobContext) -> R + Send + 'static,
+        R: Send + 'static,
+    {
+        let (sender, receiver) = channel();
+        let job: Job = Box::new(move |context| {
+            let result = catch_unwind(AssertUnwindSafe(|| op(context)));
+            // The handle may have been dropped, in which case the result is not needed
+            let _ = sender.send(result);
+        });
+
+        let mut queues = self.shared.queues.lock().unwrap();
+        match priority {
+            Priority::Interactive => queues.interactive.push_back(job),
+            Priority::Batch => queues.batch.push_back(job),
+        }
+        drop(queues);
+        self.shared.job_available.notify_one();
+
+        JobHandle { receiver }
+    }
+
+    /// Returns the number of operations waiting for a worker.
+    pub fn pending(&self) -> usize {
+        let queues = self.shared.queues.lock().unwrap();
+        queues.interactive.len() + queues.batch.len()
+    }
+}
+
+impl Drop for Scheduler {
+    fn drop(&mut self) {
+        self.shared.queues.lock().unwrap().shutdown = true;
+        self.shared.job_available.notify_all();
+        for worker in self.workers.drain(..) {
+            worker.join().unwrap();
+        }
+    }
+}
+
+/// Handle to the result of an operation submitted to a [`Scheduler`].
+pub struct JobHandle<R> {
+    receiver: Receiver<std::thread::Result<R>>,
+}
+
+impl<R> JobHandle<R> {
+    /// Blocks until the operation completes and returns its result.
+    ///
+    /// # Panics
+    ///
+    /// Panics with the payload of the operation if the operation panicked.
+    pub fn wait(self) -> R {
+        match self.receiver.recv().unwrap() {
+            Ok(result) => result,
+            Err(payload) => resume_unwind(payload),
+        }
+    }
+
+    /// Returns the result of the operation if it completed, or the handle otherwise.
+    pub fn try_wait(self) -> Result<R, Self> {
+        match self.receiver.try_recv() {
+            Ok(Ok(result)) => Ok(result),
+            Ok(Err(payload)) => resume_unwind(payload),
+            Err(_) => Err(self),
+        }
+    }
+}
+
+/// Context given to the operations run by a [`Scheduler`].
+pub struct JobContext<'a> {
+    shared: &'a Shared,
+    priority: Priority,
+}
+
+impl<'a> JobContext<'a> {
+    pub fn server_key(&self) -> &'a ServerKey {
+        &self.shared.server_key
+    }
+
+    pub fn priority(&self) -> Priority {
+        self.priority
+    }
+
+    /// Marks a block boundary of a batch operation.
+    ///
+    /// The interactive operations waiting in the queue are run before returning, this does nothing
+    /// for interactive operations.
+    pub fn checkpoint(&self) {
+        if self.priority != Priority::Batch {
+            return;
+        }
+
+        loop {
+            let job = self.shared.queues.lock().unwrap().interactive.pop_front();
+            match job {
+                Some(job) => self.shared.run(Priority::Interactive, job),
+                None => break,
+            }
+        }
+    }
+
+    /// Applies `f` to each block of `ct` sequentially, with a [`Self::checkpoint`] before each
+    /// block.
+    pub fn map_blocks<PBSOrder, F>(
+        &self,
+        ct: &RadixCiphertext<PBSOrder>,
+        mut f: F,
+    ) -> RadixCiphertext<PBSOrder>
+    where
+        PBSOrder: PBSOrderMarker,
+        F: FnMut(
+            &crate::shortint::ServerKey,
+            &CiphertextBase<PBSOrder>,
+        ) -> CiphertextBase<PBSOrder>,
+    {
+        let blocks = ct
+            .blocks
+            .iter()
+            .map(|block| {
+                self.checkpoint();
+                f(&self.server_key().key, block)
+            })
+            .collect::<Vec<_>>();
+
+        RadixCiphertext::from(blocks)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::integer::keycache::KEY_CACHE;
+    use crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+
+    #[test]
+    fn test_scheduler_runs_interactive_first() {
+        let (_, sks) = KEY_CACHE.get_from_params(PARAM_MESSAGE_2_CARRY_2);
+        let scheduler = Scheduler::new(sks, 1);
+        let order = Arc::new(Mutex::new(Vec::new()));
+
+        // Keeps the only worker busy until the other operations are queued
+        let (release, released) = channel::<()>();
+        let blocking = {
+            let order = Arc::clone(&order);
+            scheduler.submit(Priority::Batch, move |ctx| {
+                released.recv().unwrap();
+                ctx.checkpoint();
+                order.lock().unwrap().push("blocking");
+            })
+        };
+
+        let handles = [
+            ("batch", Priority::Batch),
+            ("interactive", Priority::Interactive),
+        ]
+        .map(|(name, priority)| {
+            let order = Arc::clone(&order);
+            scheduler.submit(priority, move |ctx| {
+                assert_eq!(ctx.priority(), priority);
+                order.lock().unwrap().push(name);
+            })
+        });
+
+        release.send(()).unwrap();
+        blocking.wait();
+        handles.into_iter().for_each(JobHandle::wait);
+
+        // The interactive operation preempted the blocking one at its checkpoint
+        assert_eq!(*order.lock().unwrap(), ["interactive", "blocking", "batch"]);
+    }
+
+    #[test]
+    fn test_scheduler_map_blocks() {
+        let (cks, sks) = KEY_CACHE.get_from_params(PARAM_MESSAGE_2_CARRY_2);
+        let scheduler = Scheduler::new(sks, 2);
+
+        let clear = 0b10_01_11_00u64;
+        let ct = cks.encrypt_radix(clear, 4);
+        let handle = scheduler.submit(Priority::Batch, move |ctx| {
+            ctx.map_blocks(&ct, |key, block| key.scalar_add(block, 1))
+        });
+
+        let dec: u64 = cks.decrypt_radix(&handle.wait());
+        assert_eq!(dec, clear + 0b01_01_01_01);
+    }
+
+    #[test]
+    #[should_panic(expected = "operation failed")]
+    fn test_scheduler_propagates_panics() {
+        let (_, sks) = KEY_CACHE.get_from_params(PARAM_MESSAGE_2_CARRY_2);
+        let scheduler = Scheduler::new(sks, 1);
+
+        let handle = scheduler.submit(Priority::Interactive, |_| panic!("operation failed"));
+        handle.wait()
+    }
+}
@@ -0,0 +1,241 @@
+//! Key switching of [`BooleanBlock`]s to the key of a small parameter set.
+//!
+//! A [`BooleanBlock`] returned by a comparison is a block of the parameters of the integer key,
+//! e.g. [`PARAM_MESSAGE_2_CARRY_2`], while it only carries one bit. A
+//! [`BooleanBlockKeySwitchingKey`] moves it to a shortint client key of a smaller parameter set,
+//! e.g. [`PARAM_MESSAGE_1_CARRY_1`], so that the downstream boolean logic is computed with the
+//! cheaper PBS of this parameter set, and so that the ciphertexts returned to the client are
+//! smaller.
+//!
+//! [`ServerKey::keyswitch_boolean_block`] first re-encodes the boolean with a PBS of the integer
+//! server key, using the scaling factor of the output parameters, then switches it to the
+//! encryption key of the output client key. The output parameters must have a message and carry
+//! space dividing the one of the integer key.
+//!
+//! [`PARAM_MESSAGE_2_CARRY_2`]: crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2
+//! [`PARAM_MESSAGE_1_CARRY_1`]: crate::shortint::parameters::PARAM_MESSAGE_1_CARRY_1
+//!
+//! # Example
+//!
+//! ```rust
+//! use tfhe::integer::server_key::boolean_block::BooleanBlockKeySwitchingKey;
+//! use tfhe::integer::{gen_keys, BooleanBlockBig};
+//! use tfhe::shortint::parameters::{
+//!     ShortintKeySwitchingParameters, PARAM_MESSAGE_1_CARRY_1, PARAM_MESSAGE_2_CARRY_2,
+//! };
+//!
+//! let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+//! let (boolean_cks, boolean_sks) = tfhe::shortint::gen_keys(PARAM_MESSAGE_1_CARRY_1);
+//!
+//! // The decomposition of the key switching key of the output parameters
+//! let params = ShortintKeySwitchingParameters::new(
+//!     PARAM_MESSAGE_1_CARRY_1.ks_base_log,
+//!     PARAM_MESSAGE_1_CARRY_1.ks_level,
+//! );
+//! let ksk = BooleanBlockKeySwitchingKey::new(&cks, &boolean_cks, params);
+//!
+//! let num_blocks = 4;
+//! let ct1 = cks.encrypt_radix(14u64, num_blocks);
+//! let ct2 = cks.encrypt_radix(97u64, num_blocks);
+//! let ct3 = cks.encrypt_radix(200u64, num_blocks);
+//!
+//! let lt = sks.lt_bool_parallelized(&ct1, &ct2);
+//! let gt = sks.gt_bool_parallelized(&ct3, &ct2);
+//! let lt: BooleanBlockBig = sks.keyswitch_boolean_block(&ksk, &lt);
+//! let gt: BooleanBlockBig = sks.keyswitch_boolean_block(&ksk, &gt);
+//!
+//! // The boolean logic is computed with the server key of the small parameters
+//! let both = boolean_sks.bitand(lt.as_block(), gt.as_block());
+//! assert_eq!(boolean_cks.decrypt(&both), 1);
+//! ```
+
+use crate::core_crypto::algorithms::*;
+use crate::core_crypto::entities::*;
+use crate::integer::ciphertext::BooleanBlock;
+use crate::integer::{ClientKey, ServerKey};
+use crate::shortint::ciphertext::Degree;
+use crate::shortint::engine::ShortintEngine;
+use crate::shortint::parameters::ShortintKeySwitchingParameters;
+use crate::shortint::{
+    CiphertextBase, ClientKey as ShortintClientKey, PBSOrder, PBSOrderMarker, ShortintParameterSet,
+};
+use serde::{Deserialize, Serialize};
+use std::marker::PhantomData;
+
+/// A key switching the [`BooleanBlock`]s of an integer client key to a shortint client key of a
+/// smaller parameter set, see the [module documentation](self).
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct BooleanBlockKeySwitchingKey {
+    pub(crate) key_switching_key: LweKeyswitchKeyOwned<u64>,
+    pub(crate) input_parameters: ShortintParameterSet,
+    pub(crate) output_parameters: ShortintParameterSet,
+    pub(crate) params: ShortintKeySwitchingParameters,
+}
+
+impl BooleanBlockKeySwitchingKey {
+    /// Generates the key switching key from the encryption key of the integer client key `from`
+    /// to the encryption key of the shortint client key `to`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the message and carry space of `to` does not hold a boolean or does not divide
+    /// the one of `from`, or if the two keys use different ciphertext moduli.
+    pub fn new(
+        from: &ClientKey,
+        to: &ShortintClientKey,
+        params: ShortintKeySwitchingParameters,
+    ) -> Self {
+        let input_parameters = from.key.parameters;
+        let output_parameters = to.parameters;
+        let input_modulus =
+            input_parameters.message_modulus().0 * input_parameters.carry_modulus().0;
+        let output_modulus =
+            output_parameters.message_modulus().0 * output_parameters.carry_modulus().0;
+        assert!(
+            output_modulus >= 2 && input_modulus % output_modulus == 0,
+            "The message and carry space of the output key ({output_modulus}) must hold a boolean \
+            and divide the one of the input key ({input_modulus})"
+        );
+        assert_eq!(
+            input_parameters.ciphertext_modulus(),
+            output_parameters.ciphertext_modulus(),
+            "The two client keys must use the same ciphertext modulus"
+        );
+
+        let key_switching_key = ShortintEngine::with_thread_local_mut(|engine| {
+            engine.new_encryption_key_switching_key(&from.key, to, params)
+        });
+
+        Self {
+            key_switching_key,
+            input_parameters,
+            output_parameters,
+            params,
+        }
+    }
+
+    pub fn parameters(&self) -> ShortintKeySwitchingParameters {
+        self.params
+    }
+
+    /// Returns the parameters of the integer client key the input booleans are encrypted under.
+    pub fn input_parameters(&self) -> ShortintParameterSet {
+        self.input_parameters
+    }
+
+    /// Returns the parameters of the shortint client key the output booleans are encrypted
+    /// under.
+    pub fn output_parameters(&self) -> ShortintParameterSet {
+        self.output_parameters
+    }
+}
+
+impl ServerKey {
+    /// Switches `boolean`, encrypted under the client key of `self`, to the output key of
+    /// `key_switching_key`.
+    ///
+    /// The result is a [`BooleanBlock`] of the output parameters, to be used with the shortint
+    /// server key and client key of these parameters. The PBS re-encoding the boolean also
+    /// resets its noise, the key switch adds the noise of the key switching key.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the parameters of `self` are not the input parameters of `key_switching_key`, or
+    /// if the PBS orders of the input and output booleans do not match the encryption key
+    /// choices of the two keys.
+    pub fn keyswitch_boolean_block<InOrder, OutOrder>(
+        &self,
+        key_switching_key: &BooleanBlockKeySwitchingKey,
+        boolean: &BooleanBlock<InOrder>,
+    ) -> BooleanBlock<OutOrder>
+    where
+        InOrder: PBSOrderMarker,
+        OutOrder: PBSOrderMarker,
+    {
+        let input_parameters = &key_switching_key.input_parameters;
+        let output_parameters = &key_switching_key.output_parameters;
+        assert_eq!(
+            (self.key.message_modulus, self.key.carry_modulus),
+            (
+                input_parameters.message_modulus(),
+                input_parameters.carry_modulus()
+            ),
+            "The server key does not match the input key of the key switching key"
+        );
+        assert_eq!(
+            InOrder::pbs_order(),
+            PBSOrder::from(input_parameters.encryption_key_choice()),
+            "The input boolean is not encrypted under the input key of the key switching key"
+        );
+        assert_eq!(
+            OutOrder::pbs_order(),
+            PBSOrder::from(output_parameters.encryption_key_choice()),
+            "The output boolean type does not match the output key of the key switching key"
+        );
+
+        let input_modulus =
+            input_parameters.message_modulus().0 * input_parameters.carry_modulus().0;
+        let output_modulus =
+            output_parameters.message_modulus().0 * output_parameters.carry_modulus().0;
+        let ratio = (input_modulus / output_modulus) as u64;
+
+        // With the scaling factor of the output parameters, the boolean is multiplied by the
+        // ratio of the two spaces
+        let acc = self.key.generate_accumulator(|x| (x & 1) * ratio);
+        let rescaled = self.key.apply_lookup_table(boolean.as_block(), &acc);
+
+        let ksk = &key_switching_key.key_switching_key;
+        let mut ct_out =
+            LweCiphertextOwned::new(0u64, ksk.output_lwe_size(), ksk.ciphertext_modulus());
+        keyswitch_lwe_ciphertext(ksk, &rescaled.ct, &mut ct_out);
+
+        BooleanBlock::new_unchecked(CiphertextBase {
+            ct: ct_out,
+            degree: Degree(1),
+            noise_level: rescaled.noise_level,
+            message_modulus: output_parameters.message_modulus(),
+            carry_modulus: output_parameters.carry_modulus(),
+            parameter_version: output_parameters.version(),
+            _order_marker: PhantomData,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::integer::keycache::KEY_CACHE;
+    use crate::shortint::keycache::KEY_CACHE as SHORTINT_KEY_CACHE;
+    use crate::shortint::parameters::{
+        PARAM_MESSAGE_1_CARRY_0, PARAM_MESSAGE_1_CARRY_1, PARAM_MESSAGE_2_CARRY_2,
+    };
+    use crate::shortint::CiphertextBig;
+
+    #[test]
+    fn test_keyswitch_boolean_block() {
+        let (cks, sks) = KEY_CACHE.get_from_params(PARAM_MESSAGE_2_CARRY_2);
+
+        for boolean_params in [PARAM_MESSAGE_1_CARRY_1, PARAM_MESSAGE_1_CARRY_0] {
+            let keys = SHORTINT_KEY_CACHE.get_from_param(boolean_params);
+            let (boolean_cks, boolean_sks) = (keys.client_key(), keys.server_key());
+            let params = ShortintKeySwitchingParameters::new(
+                boolean_params.ks_base_log,
+                boolean_params.ks_level,
+            );
+            let ksk = BooleanBlockKeySwitchingKey::new(&cks, boolean_cks, params);
+
+            for value in [false, true] {
+                let boolean = BooleanBlock::new_unchecked(cks.encrypt_one_block(u64::from(value)));
+                let switched: BooleanBlock<_> = sks.keyswitch_boolean_block(&ksk, &boolean);
+                let switched: CiphertextBig = switched.into_block();
+                assert_eq!(boolean_cks.decrypt(&switched), u64::from(value));
+
+                // The switched boolean can be bootstrapped by the server key of the small
+                // parameters
+                let acc = boolean_sks.generate_accumulator(|x| 1 - x);
+                let negated = boolean_sks.apply_lookup_table(&switched, &acc);
+                assert_eq!(boolean_cks.decrypt(&negated), u64::from(!value));
+            }
+        }
+    }
+}
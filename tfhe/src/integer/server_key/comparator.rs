@@ -1,7 +1,7 @@
 use rayon::prelude::*;
 
 use super::ServerKey;
-use crate::integer::ciphertext::RadixCiphertext;
+use crate::integer::ciphertext::{BooleanBlock, RadixCiphertext};
 use crate::shortint::server_key::LookupTableOwned;
 use crate::shortint::{CiphertextBase, PBSOrderMarker};
 
@@ -275,7 +275,7 @@ impl<'a> Comparator<'a> {
         let num_block = lhs.blocks.len();
         let num_block_is_odd = num_block % 2;
 
-        let mut comparisons = if lhs.blocks[0].carry_modulus.0 < lhs.blocks[0].message_modulus.0 {
+        let comparisons = if lhs.blocks[0].carry_modulus.0 < lhs.blocks[0].message_modulus.0 {
             let mut comparisons = Vec::with_capacity(num_block);
             lhs.blocks
                 .par_iter()
@@ -313,6 +313,17 @@ impl<'a> Comparator<'a> {
             comparisons
         };
 
+        self.reduce_block_comparisons_parallelized(comparisons)
+    }
+
+    /// Combines the comparisons of the blocks of two integers, ordered from the least
+    /// significant block to the most significant one, into the comparison of the integers.
+    ///
+    /// The comparisons are combined pairwise with a tree, each level costing one PBS.
+    fn reduce_block_comparisons_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        mut comparisons: Vec<CiphertextBase<PBSOrder>>,
+    ) -> CiphertextBase<PBSOrder> {
         let mut comparisons_2 = Vec::with_capacity(comparisons.len() / 2);
         while comparisons.len() != 1 {
             comparisons
@@ -506,6 +517,21 @@ impl<'a> Comparator<'a> {
         sign_result_handler_fn: F,
         num_blocks: usize,
     ) -> RadixCiphertext<PBSOrder>
+    where
+        F: Fn(u64) -> u64,
+        PBSOrder: PBSOrderMarker,
+    {
+        self.map_comparison_result_to_boolean_block(comparison, sign_result_handler_fn)
+            .into_radix(num_blocks, self.server_key)
+    }
+
+    /// Maps the result of a block comparison (IS_INFERIOR, IS_EQUAL, IS_SUPERIOR)
+    /// to a boolean value using the `sign_result_handler_fn`.
+    fn map_comparison_result_to_boolean_block<F, PBSOrder>(
+        &self,
+        comparison: crate::shortint::CiphertextBase<PBSOrder>,
+        sign_result_handler_fn: F,
+    ) -> BooleanBlock<PBSOrder>
     where
         F: Fn(u64) -> u64,
         PBSOrder: PBSOrderMarker,
@@ -516,13 +542,7 @@ impl<'a> Comparator<'a> {
             .generate_accumulator(sign_result_handler_fn);
         let result_block = self.server_key.key.apply_lookup_table(&comparison, &acc);
 
-        let mut blocks = Vec::with_capacity(num_blocks);
-        blocks.push(result_block);
-        for _ in 0..num_blocks - 1 {
-            blocks.push(self.server_key.key.create_trivial(0));
-        }
-
-        RadixCiphertext { blocks }
+        BooleanBlock::new_unchecked(result_block)
     }
 
     /// Expects the carry buffers to be empty
@@ -567,6 +587,343 @@ impl<'a> Comparator<'a> {
         self.map_comparison_result(comparison, sign_result_handler_fn, lhs.blocks.len())
     }
 
+    /// Expects the carry buffers to be empty
+    fn unchecked_boolean_comparison_parallelized<F, PBSOrder>(
+        &self,
+        sign_result_handler_fn: F,
+        lhs: &RadixCiphertext<PBSOrder>,
+        rhs: &RadixCiphertext<PBSOrder>,
+    ) -> BooleanBlock<PBSOrder>
+    where
+        F: Fn(u64) -> u64,
+        PBSOrder: PBSOrderMarker,
+    {
+        let comparison = self.unchecked_compare_parallelized(lhs, rhs);
+        self.map_comparison_result_to_boolean_block(comparison, sign_result_handler_fn)
+    }
+
+    /// Propagates the carries of the inputs if needed,
+    /// then does the comparison.
+    fn boolean_comparison_parallelized<F, PBSOrder>(
+        &self,
+        sign_result_handler_fn: F,
+        lhs: &RadixCiphertext<PBSOrder>,
+        rhs: &RadixCiphertext<PBSOrder>,
+    ) -> BooleanBlock<PBSOrder>
+    where
+        F: Fn(u64) -> u64,
+        PBSOrder: PBSOrderMarker,
+    {
+        let mut tmp_lhs: RadixCiphertext<PBSOrder>;
+        let mut tmp_rhs: RadixCiphertext<PBSOrder>;
+        let (lhs, rhs) = match (lhs.block_carries_are_empty(), rhs.block_carries_are_empty()) {
+            (true, true) => (lhs, rhs),
+            (true, false) => {
+                tmp_rhs = rhs.clone();
+                self.server_key.full_propagate_parallelized(&mut tmp_rhs);
+                (lhs, &tmp_rhs)
+            }
+            (false, true) => {
+                tmp_lhs = lhs.clone();
+                self.server_key.full_propagate_parallelized(&mut tmp_lhs);
+                (&tmp_lhs, rhs)
+            }
+            (false, false) => {
+                tmp_lhs = lhs.clone();
+                tmp_rhs = rhs.clone();
+                rayon::join(
+                    || self.server_key.full_propagate_parallelized(&mut tmp_lhs),
+                    || self.server_key.full_propagate_parallelized(&mut tmp_rhs),
+                );
+                (&tmp_lhs, &tmp_rhs)
+            }
+        };
+
+        self.unchecked_boolean_comparison_parallelized(sign_result_handler_fn, lhs, rhs)
+    }
+
+    /// Returns the blocks of `scalar` in the radix decomposition of a ciphertext of `num_blocks`
+    /// blocks, or None if `scalar` does not fit in the ciphertext.
+    fn scalar_blocks(&self, scalar: u64, num_blocks: usize) -> Option<Vec<u64>> {
+        let message_modulus = self.server_key.key.message_modulus.0 as u64;
+        let mut remainder = scalar;
+        let blocks = (0..num_blocks)
+            .map(|_| {
+                let block = remainder % message_modulus;
+                remainder /= message_modulus;
+                block
+            })
+            .collect();
+        (remainder == 0).then_some(blocks)
+    }
+
+    /// Checks that all the `blocks` encrypt `value`, which must be either 0 or the maximum value
+    /// of a block, and returns a block encrypting `if_equal` if they do, `if_different` otherwise.
+    ///
+    /// Instead of comparing each block, the blocks are summed in chunks as large as the carries
+    /// allow and each sum is compared with the sum of the expected values, so each level of the
+    /// reduction divides the number of blocks by the size of the chunks.
+    ///
+    /// Expects the carry buffers to be empty
+    fn unchecked_all_blocks_equal_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        blocks: &[CiphertextBase<PBSOrder>],
+        value: u64,
+        if_equal: u64,
+        if_different: u64,
+    ) -> CiphertextBase<PBSOrder> {
+        let message_modulus = self.server_key.key.message_modulus.0;
+        let total_modulus = message_modulus * self.server_key.key.carry_modulus.0;
+
+        // The first level sums the blocks themselves, the next ones sum booleans
+        let mut max_value = message_modulus - 1;
+        let mut expected_value = value;
+        let mut blocks = blocks.to_vec();
+        loop {
+            let chunk_size = (total_modulus - 1) / max_value;
+            let is_last_level = blocks.len() <= chunk_size;
+            let (on_equal, on_different) = if is_last_level {
+                (if_equal, if_different)
+            } else {
+                (1, 0)
+            };
+
+            blocks = blocks
+                .par_chunks(chunk_size)
+                .map(|chunk| {
+                    let expected_sum = chunk.len() as u64 * expected_value;
+                    let lut = self.server_key.key.generate_accumulator(|x| {
+                        if x == expected_sum {
+                            on_equal
+                        } else {
+                            on_different
+                        }
+                    });
+
+                    let mut sum = chunk[0].clone();
+                    for block in &chunk[1..] {
+                        self.server_key.key.unchecked_add_assign(&mut sum, block);
+                    }
+                    self.server_key
+                        .key
+                        .apply_lookup_table_assign(&mut sum, &lut);
+                    sum
+                })
+                .collect();
+
+            if is_last_level {
+                return blocks.pop().unwrap();
+            }
+            max_value = 1;
+            expected_value = 1;
+        }
+    }
+
+    /// Compares `lhs` with the clear `scalar`, returns:
+    ///
+    /// - 0 if lhs < scalar
+    /// - 1 if lhs == scalar
+    /// - 2 if lhs > scalar
+    ///
+    /// or None if `scalar` is too large to fit in `lhs`, in which case lhs < scalar.
+    ///
+    /// The most significant blocks of the scalar are often all zero (small bounds) or all
+    /// maximal (bounds close to the maximum value of the ciphertext). The corresponding blocks
+    /// of `lhs` are not compared one by one: if the high blocks of the scalar are zero, it is
+    /// enough to know whether all the high blocks of `lhs` are zero, and if they are maximal,
+    /// whether all the high blocks of `lhs` are maximal, which
+    /// [`Self::unchecked_all_blocks_equal_parallelized`] computes with far fewer PBS. The high
+    /// part then only adds a single element to the combine tree of the remaining blocks.
+    ///
+    /// Expects the carry buffers to be empty
+    fn unchecked_scalar_compare_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        lhs: &RadixCiphertext<PBSOrder>,
+        scalar: u64,
+    ) -> Option<CiphertextBase<PBSOrder>> {
+        let num_block = lhs.blocks.len();
+        let scalar_blocks = self.scalar_blocks(scalar, num_block)?;
+
+        let message_modulus = self.server_key.key.message_modulus.0 as u64;
+        let max_block = message_modulus - 1;
+
+        // Number of low blocks before the run of zero (resp. maximal) high blocks
+        let zero_start = scalar_blocks
+            .iter()
+            .rposition(|&block| block != 0)
+            .map_or(0, |i| i + 1);
+        let max_start = scalar_blocks
+            .iter()
+            .rposition(|&block| block != max_block)
+            .map_or(0, |i| i + 1);
+
+        let (num_low_block, high_comparison) = if zero_start <= max_start {
+            // lhs_high > 0 means lhs > scalar, otherwise the low blocks decide
+            (zero_start, (0, Self::IS_EQUAL, Self::IS_SUPERIOR))
+        } else {
+            // lhs_high < max means lhs < scalar, otherwise the low blocks decide
+            (max_start, (max_block, Self::IS_EQUAL, Self::IS_INFERIOR))
+        };
+        let (low_blocks, high_blocks) = lhs.blocks.split_at(num_low_block);
+        let low_scalar_blocks = &scalar_blocks[..num_low_block];
+
+        let (mut comparisons, high) = rayon::join(
+            || {
+                self.unchecked_compare_blocks_with_scalar_parallelized(
+                    low_blocks,
+                    low_scalar_blocks,
+                )
+            },
+            || {
+                (!high_blocks.is_empty()).then(|| {
+                    let (value, if_equal, if_different) = high_comparison;
+                    self.unchecked_all_blocks_equal_parallelized(
+                        high_blocks,
+                        value,
+                        if_equal,
+                        if_different,
+                    )
+                })
+            },
+        );
+        comparisons.extend(high);
+
+        Some(self.reduce_block_comparisons_parallelized(comparisons))
+    }
+
+    /// Compares each block of `blocks` (or pair of blocks, if the carries allow to pack them)
+    /// with the corresponding clear block of `scalar_blocks`.
+    ///
+    /// Expects the carry buffers to be empty
+    fn unchecked_compare_blocks_with_scalar_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        blocks: &[CiphertextBase<PBSOrder>],
+        scalar_blocks: &[u64],
+    ) -> Vec<CiphertextBase<PBSOrder>> {
+        let message_modulus = self.server_key.key.message_modulus.0 as u64;
+        let carry_modulus = self.server_key.key.carry_modulus.0 as u64;
+
+        let compare_with_scalar = |mut block: CiphertextBase<PBSOrder>, scalar: u64| {
+            // create_trivial reduces its input modulo the message modulus, which would lose
+            // the high part of a packed scalar
+            let mut trivial_scalar: CiphertextBase<PBSOrder> =
+                self.server_key.key.create_trivial(0);
+            self.server_key
+                .key
+                .unchecked_scalar_add_assign(&mut trivial_scalar, scalar as u8);
+            self.compare_block_assign(&mut block, &trivial_scalar);
+            block
+        };
+
+        if carry_modulus < message_modulus {
+            blocks
+                .par_iter()
+                .zip(scalar_blocks.par_iter())
+                .map(|(block, &scalar)| compare_with_scalar(block.clone(), scalar))
+                .collect()
+        } else {
+            blocks
+                .par_chunks(2)
+                .zip(scalar_blocks.par_chunks(2))
+                .map(|(chunk, scalar_chunk)| {
+                    if let ([low, high], [scalar_low, scalar_high]) = (chunk, scalar_chunk) {
+                        let mut packed = high.clone();
+                        self.pack_block_assign(low, &mut packed);
+                        compare_with_scalar(packed, scalar_high * message_modulus + scalar_low)
+                    } else {
+                        compare_with_scalar(chunk[0].clone(), scalar_chunk[0])
+                    }
+                })
+                .collect()
+        }
+    }
+
+    /// Expects the carry buffers to be empty
+    fn unchecked_scalar_comparison_impl<F, PBSOrder>(
+        &self,
+        sign_result_handler_fn: F,
+        lhs: &RadixCiphertext<PBSOrder>,
+        scalar: u64,
+    ) -> RadixCiphertext<PBSOrder>
+    where
+        F: Fn(u64) -> u64,
+        PBSOrder: PBSOrderMarker,
+    {
+        self.unchecked_scalar_boolean_comparison_impl(sign_result_handler_fn, lhs, scalar)
+            .into_radix(lhs.blocks.len(), self.server_key)
+    }
+
+    /// Expects the carry buffers to be empty
+    fn unchecked_scalar_boolean_comparison_impl<F, PBSOrder>(
+        &self,
+        sign_result_handler_fn: F,
+        lhs: &RadixCiphertext<PBSOrder>,
+        scalar: u64,
+    ) -> BooleanBlock<PBSOrder>
+    where
+        F: Fn(u64) -> u64,
+        PBSOrder: PBSOrderMarker,
+    {
+        match self.unchecked_scalar_compare_parallelized(lhs, scalar) {
+            Some(comparison) => {
+                self.map_comparison_result_to_boolean_block(comparison, sign_result_handler_fn)
+            }
+            // The scalar does not fit in the ciphertext, the result is known without any PBS
+            None => {
+                let result = sign_result_handler_fn(Self::IS_INFERIOR);
+                BooleanBlock::new_unchecked(self.server_key.key.create_trivial(result))
+            }
+        }
+    }
+
+    /// Propagates the carries of the input if needed,
+    /// then does the comparison.
+    fn scalar_comparison_impl<F, PBSOrder>(
+        &self,
+        sign_result_handler_fn: F,
+        lhs: &RadixCiphertext<PBSOrder>,
+        scalar: u64,
+    ) -> RadixCiphertext<PBSOrder>
+    where
+        F: Fn(u64) -> u64,
+        PBSOrder: PBSOrderMarker,
+    {
+        let mut tmp_lhs: RadixCiphertext<PBSOrder>;
+        let lhs = if lhs.block_carries_are_empty() {
+            lhs
+        } else {
+            tmp_lhs = lhs.clone();
+            self.server_key.full_propagate_parallelized(&mut tmp_lhs);
+            &tmp_lhs
+        };
+
+        self.unchecked_scalar_comparison_impl(sign_result_handler_fn, lhs, scalar)
+    }
+
+    /// Propagates the carries of the input if needed,
+    /// then does the comparison.
+    fn scalar_boolean_comparison_impl<F, PBSOrder>(
+        &self,
+        sign_result_handler_fn: F,
+        lhs: &RadixCiphertext<PBSOrder>,
+        scalar: u64,
+    ) -> BooleanBlock<PBSOrder>
+    where
+        F: Fn(u64) -> u64,
+        PBSOrder: PBSOrderMarker,
+    {
+        let mut tmp_lhs: RadixCiphertext<PBSOrder>;
+        let lhs = if lhs.block_carries_are_empty() {
+            lhs
+        } else {
+            tmp_lhs = lhs.clone();
+            self.server_key.full_propagate_parallelized(&mut tmp_lhs);
+            &tmp_lhs
+        };
+
+        self.unchecked_scalar_boolean_comparison_impl(sign_result_handler_fn, lhs, scalar)
+    }
+
     //======================================
     // Unchecked Single-Threaded operations
     //======================================
@@ -1150,6 +1507,238 @@ impl<'a> Comparator<'a> {
             .for_each(|block| self.server_key.key.message_extract_assign(block));
         res
     }
+
+    //======================================
+    // Boolean output operations
+    //======================================
+
+    pub fn unchecked_eq_bool_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        lhs: &RadixCiphertext<PBSOrder>,
+        rhs: &RadixCiphertext<PBSOrder>,
+    ) -> BooleanBlock<PBSOrder> {
+        self.unchecked_boolean_comparison_parallelized(|x| u64::from(x == Self::IS_EQUAL), lhs, rhs)
+    }
+
+    pub fn unchecked_gt_bool_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        lhs: &RadixCiphertext<PBSOrder>,
+        rhs: &RadixCiphertext<PBSOrder>,
+    ) -> BooleanBlock<PBSOrder> {
+        self.unchecked_boolean_comparison_parallelized(
+            |x| u64::from(x == Self::IS_SUPERIOR),
+            lhs,
+            rhs,
+        )
+    }
+
+    pub fn unchecked_ge_bool_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        lhs: &RadixCiphertext<PBSOrder>,
+        rhs: &RadixCiphertext<PBSOrder>,
+    ) -> BooleanBlock<PBSOrder> {
+        self.unchecked_boolean_comparison_parallelized(
+            |x| u64::from(x == Self::IS_EQUAL || x == Self::IS_SUPERIOR),
+            lhs,
+            rhs,
+        )
+    }
+
+    pub fn unchecked_lt_bool_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        lhs: &RadixCiphertext<PBSOrder>,
+        rhs: &RadixCiphertext<PBSOrder>,
+    ) -> BooleanBlock<PBSOrder> {
+        self.unchecked_boolean_comparison_parallelized(
+            |x| u64::from(x == Self::IS_INFERIOR),
+            lhs,
+            rhs,
+        )
+    }
+
+    pub fn unchecked_le_bool_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        lhs: &RadixCiphertext<PBSOrder>,
+        rhs: &RadixCiphertext<PBSOrder>,
+    ) -> BooleanBlock<PBSOrder> {
+        self.unchecked_boolean_comparison_parallelized(
+            |x| u64::from(x == Self::IS_EQUAL || x == Self::IS_INFERIOR),
+            lhs,
+            rhs,
+        )
+    }
+
+    pub fn eq_bool_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        lhs: &RadixCiphertext<PBSOrder>,
+        rhs: &RadixCiphertext<PBSOrder>,
+    ) -> BooleanBlock<PBSOrder> {
+        self.boolean_comparison_parallelized(|x| u64::from(x == Self::IS_EQUAL), lhs, rhs)
+    }
+
+    pub fn gt_bool_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        lhs: &RadixCiphertext<PBSOrder>,
+        rhs: &RadixCiphertext<PBSOrder>,
+    ) -> BooleanBlock<PBSOrder> {
+        self.boolean_comparison_parallelized(|x| u64::from(x == Self::IS_SUPERIOR), lhs, rhs)
+    }
+
+    pub fn ge_bool_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        lhs: &RadixCiphertext<PBSOrder>,
+        rhs: &RadixCiphertext<PBSOrder>,
+    ) -> BooleanBlock<PBSOrder> {
+        self.boolean_comparison_parallelized(
+            |x| u64::from(x == Self::IS_EQUAL || x == Self::IS_SUPERIOR),
+            lhs,
+            rhs,
+        )
+    }
+
+    pub fn lt_bool_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        lhs: &RadixCiphertext<PBSOrder>,
+        rhs: &RadixCiphertext<PBSOrder>,
+    ) -> BooleanBlock<PBSOrder> {
+        self.boolean_comparison_parallelized(|x| u64::from(x == Self::IS_INFERIOR), lhs, rhs)
+    }
+
+    pub fn le_bool_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        lhs: &RadixCiphertext<PBSOrder>,
+        rhs: &RadixCiphertext<PBSOrder>,
+    ) -> BooleanBlock<PBSOrder> {
+        self.boolean_comparison_parallelized(
+            |x| u64::from(x == Self::IS_EQUAL || x == Self::IS_INFERIOR),
+            lhs,
+            rhs,
+        )
+    }
+
+    //======================================
+    // Scalar operations
+    //======================================
+
+    pub fn unchecked_scalar_gt_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        lhs: &RadixCiphertext<PBSOrder>,
+        scalar: u64,
+    ) -> RadixCiphertext<PBSOrder> {
+        self.unchecked_scalar_comparison_impl(|x| u64::from(x == Self::IS_SUPERIOR), lhs, scalar)
+    }
+
+    pub fn unchecked_scalar_ge_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        lhs: &RadixCiphertext<PBSOrder>,
+        scalar: u64,
+    ) -> RadixCiphertext<PBSOrder> {
+        self.unchecked_scalar_comparison_impl(
+            |x| u64::from(x == Self::IS_EQUAL || x == Self::IS_SUPERIOR),
+            lhs,
+            scalar,
+        )
+    }
+
+    pub fn unchecked_scalar_lt_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        lhs: &RadixCiphertext<PBSOrder>,
+        scalar: u64,
+    ) -> RadixCiphertext<PBSOrder> {
+        self.unchecked_scalar_comparison_impl(|x| u64::from(x == Self::IS_INFERIOR), lhs, scalar)
+    }
+
+    pub fn unchecked_scalar_le_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        lhs: &RadixCiphertext<PBSOrder>,
+        scalar: u64,
+    ) -> RadixCiphertext<PBSOrder> {
+        self.unchecked_scalar_comparison_impl(
+            |x| u64::from(x == Self::IS_EQUAL || x == Self::IS_INFERIOR),
+            lhs,
+            scalar,
+        )
+    }
+
+    pub fn scalar_gt_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        lhs: &RadixCiphertext<PBSOrder>,
+        scalar: u64,
+    ) -> RadixCiphertext<PBSOrder> {
+        self.scalar_comparison_impl(|x| u64::from(x == Self::IS_SUPERIOR), lhs, scalar)
+    }
+
+    pub fn scalar_ge_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        lhs: &RadixCiphertext<PBSOrder>,
+        scalar: u64,
+    ) -> RadixCiphertext<PBSOrder> {
+        self.scalar_comparison_impl(
+            |x| u64::from(x == Self::IS_EQUAL || x == Self::IS_SUPERIOR),
+            lhs,
+            scalar,
+        )
+    }
+
+    pub fn scalar_lt_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        lhs: &RadixCiphertext<PBSOrder>,
+        scalar: u64,
+    ) -> RadixCiphertext<PBSOrder> {
+        self.scalar_comparison_impl(|x| u64::from(x == Self::IS_INFERIOR), lhs, scalar)
+    }
+
+    pub fn scalar_le_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        lhs: &RadixCiphertext<PBSOrder>,
+        scalar: u64,
+    ) -> RadixCiphertext<PBSOrder> {
+        self.scalar_comparison_impl(
+            |x| u64::from(x == Self::IS_EQUAL || x == Self::IS_INFERIOR),
+            lhs,
+            scalar,
+        )
+    }
+
+    pub fn scalar_gt_bool_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        lhs: &RadixCiphertext<PBSOrder>,
+        scalar: u64,
+    ) -> BooleanBlock<PBSOrder> {
+        self.scalar_boolean_comparison_impl(|x| u64::from(x == Self::IS_SUPERIOR), lhs, scalar)
+    }
+
+    pub fn scalar_ge_bool_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        lhs: &RadixCiphertext<PBSOrder>,
+        scalar: u64,
+    ) -> BooleanBlock<PBSOrder> {
+        self.scalar_boolean_comparison_impl(
+            |x| u64::from(x == Self::IS_EQUAL || x == Self::IS_SUPERIOR),
+            lhs,
+            scalar,
+        )
+    }
+
+    pub fn scalar_lt_bool_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        lhs: &RadixCiphertext<PBSOrder>,
+        scalar: u64,
+    ) -> BooleanBlock<PBSOrder> {
+        self.scalar_boolean_comparison_impl(|x| u64::from(x == Self::IS_INFERIOR), lhs, scalar)
+    }
+
+    pub fn scalar_le_bool_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        lhs: &RadixCiphertext<PBSOrder>,
+        scalar: u64,
+    ) -> BooleanBlock<PBSOrder> {
+        self.scalar_boolean_comparison_impl(
+            |x| u64::from(x == Self::IS_EQUAL || x == Self::IS_INFERIOR),
+            lhs,
+            scalar,
+        )
+    }
 }
 
 #[cfg(test)]
@@ -1439,7 +2028,7 @@ mod tests {
     /// - $comparison_name_parallelized
     ///
     /// So, for example, for the `gt` comparison fn, this macro will generate the tests for
-    /// the 5 variants described above
+    /// the 5 variants described above, as well as for `$comparison_name_bool_parallelized`
     macro_rules! define_comparison_test_functions {
         ($comparison_name:ident) => {
             paste::paste!{
@@ -1493,6 +2082,20 @@ mod tests {
                     )
                 }
 
+                fn [<$comparison_name _bool_parallelized_256_bits>](params:  crate::shortint::PBSParameters) {
+                    let num_tests = 1;
+                    test_default_function(
+                        params,
+                        num_tests,
+                        |comparator, lhs, rhs| {
+                            comparator
+                                .[<$comparison_name _bool_parallelized>](lhs, rhs)
+                                .into_radix(lhs.blocks.len(), comparator.server_key)
+                        },
+                        |lhs, rhs| U256::from(<U256>::$comparison_name(&lhs, &rhs) as u128),
+                    )
+                }
+
                 create_parametrized_test!([<unchecked_ $comparison_name _256_bits>]
                 {
                     PARAM_MESSAGE_2_CARRY_2,
@@ -1535,6 +2138,12 @@ mod tests {
                         // of message so the overflow behaviour is not the same, leading to false negatives
                         PARAM_MESSAGE_4_CARRY_4
                     });
+
+                create_parametrized_test!([<$comparison_name _bool_parallelized_256_bits>]
+                    {
+                        PARAM_MESSAGE_2_CARRY_2,
+                        PARAM_MESSAGE_4_CARRY_4
+                    });
             }
         };
     }
@@ -1551,6 +2160,129 @@ mod tests {
     define_comparison_test_functions!(gt);
     define_comparison_test_functions!(ge);
 
+    /// Function to test a comparison with a clear scalar.
+    ///
+    /// The scalars go through the early exits of the scalar comparisons: scalars whose high
+    /// blocks are zero or maximal, and scalars that do not fit in the ciphertext. When
+    /// `with_carries` is true, the ciphertexts have non-zero carries.
+    fn test_scalar_function<ScalarFn, ClearF>(
+        param: PBSParameters,
+        with_carries: bool,
+        scalar_comparator_method: ScalarFn,
+        clear_fn: ClearF,
+    ) where
+        ScalarFn:
+            for<'a, 'b> Fn(&'a Comparator<'b>, &'a RadixCiphertextBig, u64) -> RadixCiphertextBig,
+        ClearF: Fn(u64, u64) -> bool,
+    {
+        let (cks, sks) = gen_keys(param);
+        let comparator = Comparator::new(&sks);
+
+        let mut rng = rand::thread_rng();
+
+        let message_modulus = param.message_modulus.0 as u64;
+        let num_block = (16f64 / (message_modulus as f64).log(2.0)).ceil() as usize;
+        let modulus = message_modulus.pow(num_block as u32);
+
+        let scalars = [
+            0,
+            rng.gen::<u64>() % message_modulus,
+            rng.gen::<u64>() % modulus,
+            modulus - 1,
+            modulus - 1 - rng.gen::<u64>() % message_modulus,
+            modulus,
+            u64::MAX,
+        ];
+
+        for scalar in scalars {
+            let clears = [rng.gen::<u64>() % modulus, scalar % modulus, 0];
+            for clear in clears {
+                let ct = if with_carries {
+                    let clear_0 = rng.gen::<u64>() % (clear + 1);
+                    let mut ct = cks.encrypt_radix(clear_0, num_block);
+                    sks.unchecked_add_assign(
+                        &mut ct,
+                        &cks.encrypt_radix(clear - clear_0, num_block),
+                    );
+                    ct
+                } else {
+                    cks.encrypt_radix(clear, num_block)
+                };
+
+                let result = scalar_comparator_method(&comparator, &ct, scalar);
+                let decrypted: u64 = cks.decrypt_radix(&result);
+                assert_eq!(
+                    decrypted,
+                    u64::from(clear_fn(clear, scalar)),
+                    "Invalid result for {clear} and {scalar}"
+                );
+            }
+        }
+    }
+
+    macro_rules! define_scalar_comparison_test_functions {
+        ($comparison_name:ident) => {
+            paste::paste!{
+                fn [<unchecked_scalar_ $comparison_name _parallelized_16_bits>](params: crate::shortint::PBSParameters) {
+                    test_scalar_function(
+                        params,
+                        false,
+                        |comparator, lhs, rhs| comparator.[<unchecked_scalar_ $comparison_name _parallelized>](lhs, rhs),
+                        |lhs, rhs| <u64>::$comparison_name(&lhs, &rhs),
+                    )
+                }
+
+                fn [<scalar_ $comparison_name _parallelized_16_bits>](params: crate::shortint::PBSParameters) {
+                    test_scalar_function(
+                        params,
+                        true,
+                        |comparator, lhs, rhs| comparator.[<scalar_ $comparison_name _parallelized>](lhs, rhs),
+                        |lhs, rhs| <u64>::$comparison_name(&lhs, &rhs),
+                    )
+                }
+
+                fn [<scalar_ $comparison_name _bool_parallelized_16_bits>](params: crate::shortint::PBSParameters) {
+                    test_scalar_function(
+                        params,
+                        true,
+                        |comparator, lhs, rhs| {
+                            comparator
+                                .[<scalar_ $comparison_name _bool_parallelized>](lhs, rhs)
+                                .into_radix(1, comparator.server_key)
+                        },
+                        |lhs, rhs| <u64>::$comparison_name(&lhs, &rhs),
+                    )
+                }
+
+                // PARAM_MESSAGE_3_CARRY_2 does not have enough carries to pack blocks
+                create_parametrized_test!([<unchecked_scalar_ $comparison_name _parallelized_16_bits>]
+                {
+                    PARAM_MESSAGE_2_CARRY_2,
+                    PARAM_MESSAGE_3_CARRY_2
+                });
+
+                create_parametrized_test!([<scalar_ $comparison_name _parallelized_16_bits>]
+                {
+                    PARAM_MESSAGE_2_CARRY_2,
+                    PARAM_MESSAGE_3_CARRY_2
+                });
+
+                create_parametrized_test!([<scalar_ $comparison_name _bool_parallelized_16_bits>]
+                {
+                    PARAM_MESSAGE_2_CARRY_2,
+                    PARAM_MESSAGE_3_CARRY_2
+                });
+            }
+        };
+    }
+
+    use crate::shortint::parameters::PARAM_MESSAGE_3_CARRY_2;
+
+    define_scalar_comparison_test_functions!(lt);
+    define_scalar_comparison_test_functions!(le);
+    define_scalar_comparison_test_functions!(gt);
+    define_scalar_comparison_test_functions!(ge);
+
     //================
     // Min
     //================
@@ -2,6 +2,7 @@
 //!
 //! This module implements the generation of the server public key, together with all the
 //! available homomorphic integer operations.
+pub mod boolean_block;
 pub mod comparator;
 mod crt;
 mod crt_parallel;
@@ -9,11 +10,13 @@ mod radix;
 mod radix_parallel;
 
 use crate::integer::client_key::ClientKey;
-use crate::shortint::server_key::MaxDegree;
+use crate::shortint::server_key::{BootstrappingKeyStorage, MaxDegree};
 use serde::{Deserialize, Serialize};
+use std::sync::Arc;
 
 /// Error returned when the carry buffer is full.
 pub use crate::shortint::CheckError;
+pub use radix_parallel::{FunctionEvaluationStrategy, MAX_WOPBS_INPUT_BITS};
 
 /// A structure containing the server public key.
 ///
@@ -22,6 +25,20 @@ pub use crate::shortint::CheckError;
 #[derive(Serialize, Deserialize, Clone)]
 pub struct ServerKey {
     pub(crate) key: crate::shortint::ServerKey,
+    // This is a runtime setting and not part of the key material
+    #[serde(skip, default = "fused_carry_extraction_default")]
+    pub(crate) fused_carry_extraction: bool,
+    // Dedicated thread pool, see `set_parallelism`
+    #[serde(skip)]
+    pub(crate) thread_pool: Option<Arc<rayon::ThreadPool>>,
+}
+
+/// The number of threads of a thread pool.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub struct NumThreads(pub usize);
+
+fn fused_carry_extraction_default() -> bool {
+    false
 }
 
 impl From<ServerKey> for crate::shortint::ServerKey {
@@ -60,7 +77,11 @@ impl ServerKey {
             MaxDegree(max),
         );
 
-        ServerKey { key: sks }
+        ServerKey {
+            key: sks,
+            fused_carry_extraction: fused_carry_extraction_default(),
+            thread_pool: None,
+        }
     }
 
     /// Creates a ServerKey from an already generated shortint::ServerKey.
@@ -88,7 +109,103 @@ impl ServerKey {
             (cks.key.parameters.message_modulus().0 - 1) * cks.key.parameters.carry_modulus().0 - 1;
 
         key.max_degree = MaxDegree(max);
-        ServerKey { key }
+        ServerKey {
+            key,
+            fused_carry_extraction: fused_carry_extraction_default(),
+            thread_pool: None,
+        }
+    }
+
+    /// Returns a new handle on this server key, sharing its memory.
+    ///
+    /// See [crate::shortint::ServerKey::share_memory]
+    pub fn share_memory(&self) -> Self {
+        Self {
+            key: self.key.share_memory(),
+            fused_carry_extraction: self.fused_carry_extraction,
+            thread_pool: self.thread_pool.clone(),
+        }
+    }
+
+    /// Sets whether carry propagations extract the carry and the message of a block with a
+    /// single bootstrap, see [crate::shortint::ServerKey::carry_and_message_extract].
+    ///
+    /// This is disabled by default: the fused extraction halves the number of bootstraps of a
+    /// carry propagation, but it doubles the modulus switch noise of every one of them, while the
+    /// failure probability of the parameter sets is only guaranteed for the two bootstraps path.
+    /// Only enable it with parameters which were checked for the many-LUT bootstrap.
+    ///
+    /// This setting is not serialized, a deserialized key uses the default.
+    pub fn set_fused_carry_extraction(&mut self, enabled: bool) {
+        self.fused_carry_extraction = enabled;
+    }
+
+    pub fn fused_carry_extraction(&self) -> bool {
+        self.fused_carry_extraction
+    }
+
+    /// Confines the operations run through [`Self::install`] to a dedicated thread pool of
+    /// `num_threads` threads, instead of the global rayon thread pool.
+    ///
+    /// The high level API runs the operations of its integer types this way, so a server can
+    /// bound the CPU usage of each of its keys. Clones of this key share the same thread pool.
+    ///
+    /// Like the other runtime settings, the thread pool is not serialized.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::{gen_keys_radix, NumThreads};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let (cks, mut sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2, 4);
+    /// sks.set_parallelism(NumThreads(2)).unwrap();
+    /// assert_eq!(sks.parallelism(), Some(NumThreads(2)));
+    ///
+    /// let ct1 = cks.encrypt(14u64);
+    /// let ct2 = cks.encrypt(27u64);
+    ///
+    /// let ct_res = sks.install(|| sks.add_parallelized(&ct1, &ct2));
+    ///
+    /// let dec: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, 41);
+    /// ```
+    pub fn set_parallelism(
+        &mut self,
+        num_threads: NumThreads,
+    ) -> Result<(), rayon::ThreadPoolBuildError> {
+        let thread_pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(num_threads.0)
+            .build()?;
+        self.thread_pool = Some(Arc::new(thread_pool));
+        Ok(())
+    }
+
+    /// Removes the dedicated thread pool, operations use the global rayon thread pool again.
+    pub fn unset_parallelism(&mut self) {
+        self.thread_pool = None;
+    }
+
+    /// Returns the number of threads of the dedicated thread pool, if any.
+    pub fn parallelism(&self) -> Option<NumThreads> {
+        self.thread_pool
+            .as_ref()
+            .map(|thread_pool| NumThreads(thread_pool.current_num_threads()))
+    }
+
+    /// Runs `op` in the dedicated thread pool of this key if there is one, all the parallel
+    /// operations started by `op` are confined to this pool.
+    ///
+    /// Without dedicated thread pool, `op` is run on the current thread.
+    pub fn install<OP, R>(&self, op: OP) -> R
+    where
+        OP: FnOnce() -> R + Send,
+        R: Send,
+    {
+        match &self.thread_pool {
+            Some(thread_pool) => thread_pool.install(op),
+            None => op(),
+        }
     }
 }
 
@@ -106,7 +223,41 @@ impl CompressedServerKey {
 
 impl From<CompressedServerKey> for ServerKey {
     fn from(compressed: CompressedServerKey) -> Self {
-        let key = compressed.key.into();
-        Self { key }
+        Self::from_compressed(compressed, BootstrappingKeyStorage::Fourier)
+    }
+}
+
+impl ServerKey {
+    /// Decompresses a [`CompressedServerKey`], storing the bootstrapping key as requested by
+    /// `storage`, see [`crate::shortint::ServerKey::from_compressed`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::{CompressedServerKey, RadixClientKey, ServerKey};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    /// use tfhe::shortint::server_key::BootstrappingKeyStorage;
+    ///
+    /// let cks = RadixClientKey::new(PARAM_MESSAGE_2_CARRY_2, 4);
+    /// let compressed_sks = CompressedServerKey::new(cks.as_ref());
+    /// let sks = ServerKey::from_compressed(compressed_sks, BootstrappingKeyStorage::OnTheFly);
+    ///
+    /// let ct1 = cks.encrypt(14u64);
+    /// let ct2 = cks.encrypt(27u64);
+    /// let ct_res = sks.add_parallelized(&ct1, &ct2);
+    ///
+    /// let dec: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, 41);
+    /// ```
+    pub fn from_compressed(
+        compressed: CompressedServerKey,
+        storage: BootstrappingKeyStorage,
+    ) -> Self {
+        let key = crate::shortint::ServerKey::from_compressed(compressed.key, storage);
+        Self {
+            key,
+            fused_carry_extraction: fused_carry_extraction_default(),
+            thread_pool: None,
+        }
     }
 }
@@ -432,9 +432,15 @@ impl ServerKey {
         ctxt: &mut RadixCiphertext<PBSOrder>,
         index: usize,
     ) {
-        let carry = self.key.carry_extract(&ctxt.blocks[index]);
-
-        ctxt.blocks[index] = self.key.message_extract(&ctxt.blocks[index]);
+        let carry = if self.fused_carry_extraction {
+            let (carry, message) = self.key.carry_and_message_extract(&ctxt.blocks[index]);
+            ctxt.blocks[index] = message;
+            carry
+        } else {
+            let carry = self.key.carry_extract(&ctxt.blocks[index]);
+            ctxt.blocks[index] = self.key.message_extract(&ctxt.blocks[index]);
+            carry
+        };
 
         //add the carry to the next block
         if index < ctxt.blocks.len() - 1 {
@@ -471,6 +477,7 @@ impl ServerKey {
     /// ```
     pub fn full_propagate<PBSOrder: PBSOrderMarker>(&self, ctxt: &mut RadixCiphertext<PBSOrder>) {
         self.partial_propagate(ctxt, 0);
+        debug_check!(check_carries_empty, "full_propagate", ctxt);
     }
 
     /// Propagates carries from
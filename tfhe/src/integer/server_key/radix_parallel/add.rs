@@ -189,6 +189,7 @@ impl ServerKey {
             self.unchecked_add_assign(lhs, rhs);
             self.full_propagate_parallelized(lhs);
         }
+        debug_check!(check_carries_empty, "add_assign_parallelized", lhs);
     }
 
     pub fn add_parallelized_work_efficient<PBSOrder: PBSOrderMarker>(
@@ -0,0 +1,245 @@
+use crate::integer::ciphertext::{IntegerCiphertext, RadixCiphertext};
+use crate::integer::wopbs::WopbsKey;
+use crate::integer::ServerKey;
+use crate::shortint::PBSOrderMarker;
+use rayon::prelude::*;
+
+impl ServerKey {
+    /// Returns whether clean blocks can be packed in a single block, in which case a bivariate
+    /// function over single block ciphertexts is a single bivariate PBS.
+    fn bivariate_function_fits_in_block(&self, num_blocks: usize) -> bool {
+        num_blocks == 1 && self.key.carry_modulus.0 >= self.key.message_modulus.0
+    }
+
+    /// Computes homomorphically `f(lhs, rhs)`, the result has as many blocks as the inputs and
+    /// is reduced modulo the message space of the inputs.
+    ///
+    /// The evaluation strategy depends on the width of the inputs:
+    ///
+    /// * single block inputs are packed in one block and go through a single bivariate PBS, if
+    ///   the carry space is large enough to hold the message of the other input;
+    /// * otherwise the inputs are decomposed in one-hot encodings of their values, and each block
+    ///   of the result is a selection among look-up tables. The number of PBS grows linearly
+    ///   with the number of values the inputs can take, so this is only meant for small inputs.
+    ///
+    /// See [`Self::apply_bivariate_function_with_wopbs_parallelized`] to evaluate functions over
+    /// wider inputs.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let num_blocks = 2;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let ct1 = cks.encrypt(13u64);
+    /// let ct2 = cks.encrypt(6u64);
+    ///
+    /// let ct_res = sks.apply_bivariate_function_parallelized(&ct1, &ct2, |x, y| x.max(y) - y);
+    ///
+    /// let dec: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, 7);
+    /// ```
+    pub fn apply_bivariate_function_parallelized<PBSOrder, F>(
+        &self,
+        lhs: &RadixCiphertext<PBSOrder>,
+        rhs: &RadixCiphertext<PBSOrder>,
+        f: F,
+    ) -> RadixCiphertext<PBSOrder>
+    where
+        PBSOrder: PBSOrderMarker,
+        F: Fn(u64, u64) -> u64 + Sync,
+    {
+        assert_eq!(
+            lhs.blocks.len(),
+            rhs.blocks.len(),
+            "The ciphertexts must have the same number of blocks"
+        );
+
+        if self.bivariate_function_fits_in_block(lhs.blocks.len()) {
+            self.apply_bivariate_function_in_block(lhs, rhs, f)
+        } else {
+            self.apply_bivariate_function_by_decomposition(lhs, rhs, f)
+        }
+    }
+
+    /// Computes homomorphically `f(lhs, rhs)`, the result has as many blocks as the inputs and
+    /// is reduced modulo the message space of the inputs.
+    ///
+    /// Single block inputs are packed in one block when possible, like in
+    /// [`Self::apply_bivariate_function_parallelized`]. Otherwise the function is evaluated as
+    /// a single WoPBS with vertical packing, which handles wider inputs than the decomposition
+    /// into look-up tables.
+    ///
+    /// The WoPBS key must have been generated for the parameters of this server key.
+    pub fn apply_bivariate_function_with_wopbs_parallelized<PBSOrder, F>(
+        &self,
+        wopbs_key: &WopbsKey,
+        lhs: &RadixCiphertext<PBSOrder>,
+        rhs: &RadixCiphertext<PBSOrder>,
+        f: F,
+    ) -> RadixCiphertext<PBSOrder>
+    where
+        PBSOrder: PBSOrderMarker,
+        RadixCiphertext<PBSOrder>: IntegerCiphertext<PBSOrder = PBSOrder>,
+        F: Fn(u64, u64) -> u64 + Sync,
+    {
+        assert_eq!(
+            lhs.blocks.len(),
+            rhs.blocks.len(),
+            "The ciphertexts must have the same number of blocks"
+        );
+
+        if self.bivariate_function_fits_in_block(lhs.blocks.len()) {
+            self.apply_bivariate_function_in_block(lhs, rhs, f)
+        } else {
+            self.apply_bivariate_function_by_wopbs(wopbs_key, lhs, rhs, f)
+        }
+    }
+
+    pub(super) fn apply_bivariate_function_by_wopbs<PBSOrder, F>(
+        &self,
+        wopbs_key: &WopbsKey,
+        lhs: &RadixCiphertext<PBSOrder>,
+        rhs: &RadixCiphertext<PBSOrder>,
+        f: F,
+    ) -> RadixCiphertext<PBSOrder>
+    where
+        PBSOrder: PBSOrderMarker,
+        RadixCiphertext<PBSOrder>: IntegerCiphertext<PBSOrder = PBSOrder>,
+        F: Fn(u64, u64) -> u64,
+    {
+        let (lhs, rhs) = rayon::join(
+            || wopbs_key.keyswitch_to_wopbs_params(self, lhs),
+            || wopbs_key.keyswitch_to_wopbs_params(self, rhs),
+        );
+        let lut = wopbs_key.generate_lut_bivariate_radix(&lhs, &rhs, f);
+        let ct_res = wopbs_key.bivariate_wopbs_with_degree(&lhs, &rhs, &lut);
+        wopbs_key.keyswitch_to_pbs_params(&ct_res)
+    }
+
+    pub(super) fn apply_bivariate_function_in_block<PBSOrder, F>(
+        &self,
+        lhs: &RadixCiphertext<PBSOrder>,
+        rhs: &RadixCiphertext<PBSOrder>,
+        f: F,
+    ) -> RadixCiphertext<PBSOrder>
+    where
+        PBSOrder: PBSOrderMarker,
+        F: Fn(u64, u64) -> u64,
+    {
+        let mut lhs_block = lhs.blocks[0].clone();
+        let mut rhs_block = rhs.blocks[0].clone();
+        if lhs_block.degree.0 >= self.key.message_modulus.0 {
+            self.key.message_extract_assign(&mut lhs_block);
+        }
+        if rhs_block.degree.0 >= self.key.message_modulus.0 {
+            self.key.message_extract_assign(&mut rhs_block);
+        }
+
+        let message_modulus = self.key.message_modulus.0 as u64;
+        let lut = self
+            .key
+            .generate_accumulator_bivariate(|x, y| f(x, y) % message_modulus);
+        let block = self
+            .key
+            .unchecked_apply_lookup_table_bivariate(&lhs_block, &rhs_block, &lut);
+
+        RadixCiphertext::from(vec![block])
+    }
+
+    pub(super) fn apply_bivariate_function_by_decomposition<PBSOrder, F>(
+        &self,
+        lhs: &RadixCiphertext<PBSOrder>,
+        rhs: &RadixCiphertext<PBSOrder>,
+        f: F,
+    ) -> RadixCiphertext<PBSOrder>
+    where
+        PBSOrder: PBSOrderMarker,
+        F: Fn(u64, u64) -> u64 + Sync,
+    {
+        let num_blocks = lhs.blocks.len();
+        let message_modulus = self.key.message_modulus.0 as u64;
+        let num_values = message_modulus
+            .checked_pow(num_blocks as u32)
+            .filter(|num_values| *num_values <= u32::MAX as u64)
+            .expect("Too many blocks to decompose the inputs in one-hot encodings");
+
+        let (lhs_one_hot, rhs_one_hot) = rayon::join(
+            || self.radix_to_one_hot_parallelized(lhs, num_values as usize),
+            || self.radix_to_one_hot_parallelized(rhs, num_values as usize),
+        );
+
+        // Keeps the right input when the left one is 1
+        let select_lut =
+            self.key
+                .generate_accumulator_bivariate(|condition, x| if condition == 1 { x } else { 0 });
+
+        // selected[u][i] encrypts the i-th digit of f(u, rhs) if lhs == u, 0 otherwise,
+        // or is None if this digit is 0 for all the values of rhs
+        let selected = lhs_one_hot
+            .blocks
+            .par_iter()
+            .enumerate()
+            .map(|(lhs_value, lhs_is_value)| {
+                let results = (0..num_values)
+                    .map(|rhs_value| f(lhs_value as u64, rhs_value) % num_values)
+                    .collect::<Vec<_>>();
+
+                (0..num_blocks)
+                    .into_par_iter()
+                    .map(|block_index| {
+                        let weight = message_modulus.pow(block_index as u32);
+                        let terms = rhs_one_hot
+                            .blocks
+                            .iter()
+                            .zip(results.iter())
+                            .filter_map(|(rhs_is_value, result)| {
+                                let digit = (result / weight) % message_modulus;
+                                (digit != 0).then(|| {
+                                    self.key.unchecked_scalar_mul(rhs_is_value, digit as u8)
+                                })
+                            })
+                            .collect::<Vec<_>>();
+                        if terms.is_empty() {
+                            return None;
+                        }
+
+                        let mut digit = self.sum_exclusive_blocks(terms);
+                        if !select_lut.is_bivariate_pbs_possible(lhs_is_value, &digit) {
+                            self.key.message_extract_assign(&mut digit);
+                        }
+                        assert!(
+                            select_lut.is_bivariate_pbs_possible(lhs_is_value, &digit),
+                            "The parameters do not allow a bivariate PBS between a block and a \
+                            boolean block"
+                        );
+                        Some(self.key.unchecked_apply_lookup_table_bivariate(
+                            lhs_is_value,
+                            &digit,
+                            &select_lut,
+                        ))
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        let blocks = (0..num_blocks)
+            .into_par_iter()
+            .map(|block_index| {
+                let terms = selected
+                    .iter()
+                    .filter_map(|digits| digits[block_index].clone());
+                let mut block = self.sum_exclusive_blocks(terms);
+                if block.degree.0 >= self.key.message_modulus.0 {
+                    self.key.message_extract_assign(&mut block);
+                }
+                block
+            })
+            .collect::<Vec<_>>();
+
+        RadixCiphertext::from(blocks)
+    }
+}
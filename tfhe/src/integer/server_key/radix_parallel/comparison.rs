@@ -1,6 +1,6 @@
 use super::ServerKey;
 
-use crate::integer::ciphertext::RadixCiphertext;
+use crate::integer::ciphertext::{BooleanBlock, RadixCiphertext};
 use crate::integer::server_key::comparator::Comparator;
 use crate::shortint::PBSOrderMarker;
 
@@ -172,4 +172,259 @@ impl ServerKey {
     ) -> RadixCiphertext<PBSOrder> {
         Comparator::new(self).min_parallelized(lhs, rhs)
     }
+
+    pub fn unchecked_eq_bool_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        lhs: &RadixCiphertext<PBSOrder>,
+        rhs: &RadixCiphertext<PBSOrder>,
+    ) -> BooleanBlock<PBSOrder> {
+        Comparator::new(self).unchecked_eq_bool_parallelized(lhs, rhs)
+    }
+
+    pub fn unchecked_gt_bool_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        lhs: &RadixCiphertext<PBSOrder>,
+        rhs: &RadixCiphertext<PBSOrder>,
+    ) -> BooleanBlock<PBSOrder> {
+        Comparator::new(self).unchecked_gt_bool_parallelized(lhs, rhs)
+    }
+
+    pub fn unchecked_ge_bool_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        lhs: &RadixCiphertext<PBSOrder>,
+        rhs: &RadixCiphertext<PBSOrder>,
+    ) -> BooleanBlock<PBSOrder> {
+        Comparator::new(self).unchecked_ge_bool_parallelized(lhs, rhs)
+    }
+
+    pub fn unchecked_lt_bool_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        lhs: &RadixCiphertext<PBSOrder>,
+        rhs: &RadixCiphertext<PBSOrder>,
+    ) -> BooleanBlock<PBSOrder> {
+        Comparator::new(self).unchecked_lt_bool_parallelized(lhs, rhs)
+    }
+
+    pub fn unchecked_le_bool_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        lhs: &RadixCiphertext<PBSOrder>,
+        rhs: &RadixCiphertext<PBSOrder>,
+    ) -> BooleanBlock<PBSOrder> {
+        Comparator::new(self).unchecked_le_bool_parallelized(lhs, rhs)
+    }
+
+    /// Compares for equality 2 ciphertexts
+    ///
+    /// Contrary to [Self::eq_parallelized], the result is returned as a [BooleanBlock]
+    /// which is made of only one block encrypting 0 or 1.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msg1 = 14u64;
+    /// let msg2 = 97u64;
+    ///
+    /// let ct1 = cks.encrypt(msg1);
+    /// let ct2 = cks.encrypt(msg2);
+    ///
+    /// let ct_res = sks.eq_bool_parallelized(&ct1, &ct2);
+    ///
+    /// // Decrypt:
+    /// let dec_result = cks.decrypt_bool(&ct_res);
+    /// assert_eq!(dec_result, msg1 == msg2);
+    /// ```
+    pub fn eq_bool_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        lhs: &RadixCiphertext<PBSOrder>,
+        rhs: &RadixCiphertext<PBSOrder>,
+    ) -> BooleanBlock<PBSOrder> {
+        Comparator::new(self).eq_bool_parallelized(lhs, rhs)
+    }
+
+    pub fn gt_bool_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        lhs: &RadixCiphertext<PBSOrder>,
+        rhs: &RadixCiphertext<PBSOrder>,
+    ) -> BooleanBlock<PBSOrder> {
+        Comparator::new(self).gt_bool_parallelized(lhs, rhs)
+    }
+
+    pub fn ge_bool_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        lhs: &RadixCiphertext<PBSOrder>,
+        rhs: &RadixCiphertext<PBSOrder>,
+    ) -> BooleanBlock<PBSOrder> {
+        Comparator::new(self).ge_bool_parallelized(lhs, rhs)
+    }
+
+    pub fn lt_bool_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        lhs: &RadixCiphertext<PBSOrder>,
+        rhs: &RadixCiphertext<PBSOrder>,
+    ) -> BooleanBlock<PBSOrder> {
+        Comparator::new(self).lt_bool_parallelized(lhs, rhs)
+    }
+
+    pub fn le_bool_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        lhs: &RadixCiphertext<PBSOrder>,
+        rhs: &RadixCiphertext<PBSOrder>,
+    ) -> BooleanBlock<PBSOrder> {
+        Comparator::new(self).le_bool_parallelized(lhs, rhs)
+    }
+
+    pub fn unchecked_scalar_gt_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        lhs: &RadixCiphertext<PBSOrder>,
+        scalar: u64,
+    ) -> RadixCiphertext<PBSOrder> {
+        Comparator::new(self).unchecked_scalar_gt_parallelized(lhs, scalar)
+    }
+
+    pub fn unchecked_scalar_ge_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        lhs: &RadixCiphertext<PBSOrder>,
+        scalar: u64,
+    ) -> RadixCiphertext<PBSOrder> {
+        Comparator::new(self).unchecked_scalar_ge_parallelized(lhs, scalar)
+    }
+
+    pub fn unchecked_scalar_lt_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        lhs: &RadixCiphertext<PBSOrder>,
+        scalar: u64,
+    ) -> RadixCiphertext<PBSOrder> {
+        Comparator::new(self).unchecked_scalar_lt_parallelized(lhs, scalar)
+    }
+
+    pub fn unchecked_scalar_le_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        lhs: &RadixCiphertext<PBSOrder>,
+        scalar: u64,
+    ) -> RadixCiphertext<PBSOrder> {
+        Comparator::new(self).unchecked_scalar_le_parallelized(lhs, scalar)
+    }
+
+    /// Compares a ciphertext with a clear scalar, returns an encryption of 1 if `lhs > scalar`
+    /// and an encryption of 0 otherwise.
+    ///
+    /// The blocks of `lhs` matching the most significant blocks of `scalar` that are all zero
+    /// (or all maximal) are not compared one by one, which makes the comparison with small
+    /// bounds (or bounds close to the maximum value of the ciphertext) much cheaper than a
+    /// comparison of two ciphertexts. If `scalar` does not fit in `lhs`, the result is computed
+    /// without any PBS.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msg = 97u64;
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// let ct_res = sks.scalar_gt_parallelized(&ct, 14);
+    /// let dec_result: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec_result, u64::from(msg > 14));
+    ///
+    /// // 300 does not fit in 8 bits
+    /// let ct_res = sks.scalar_gt_parallelized(&ct, 300);
+    /// let dec_result: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec_result, 0);
+    /// ```
+    pub fn scalar_gt_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        lhs: &RadixCiphertext<PBSOrder>,
+        scalar: u64,
+    ) -> RadixCiphertext<PBSOrder> {
+        Comparator::new(self).scalar_gt_parallelized(lhs, scalar)
+    }
+
+    pub fn scalar_ge_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        lhs: &RadixCiphertext<PBSOrder>,
+        scalar: u64,
+    ) -> RadixCiphertext<PBSOrder> {
+        Comparator::new(self).scalar_ge_parallelized(lhs, scalar)
+    }
+
+    pub fn scalar_lt_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        lhs: &RadixCiphertext<PBSOrder>,
+        scalar: u64,
+    ) -> RadixCiphertext<PBSOrder> {
+        Comparator::new(self).scalar_lt_parallelized(lhs, scalar)
+    }
+
+    pub fn scalar_le_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        lhs: &RadixCiphertext<PBSOrder>,
+        scalar: u64,
+    ) -> RadixCiphertext<PBSOrder> {
+        Comparator::new(self).scalar_le_parallelized(lhs, scalar)
+    }
+
+    /// Compares a ciphertext with a clear scalar
+    ///
+    /// Contrary to [Self::scalar_gt_parallelized], the result is returned as a [BooleanBlock]
+    /// which is made of only one block encrypting 0 or 1.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msg = 97u64;
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// let ct_res = sks.scalar_gt_bool_parallelized(&ct, 14);
+    /// assert_eq!(cks.decrypt_bool(&ct_res), msg > 14);
+    /// ```
+    pub fn scalar_gt_bool_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        lhs: &RadixCiphertext<PBSOrder>,
+        scalar: u64,
+    ) -> BooleanBlock<PBSOrder> {
+        Comparator::new(self).scalar_gt_bool_parallelized(lhs, scalar)
+    }
+
+    pub fn scalar_ge_bool_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        lhs: &RadixCiphertext<PBSOrder>,
+        scalar: u64,
+    ) -> BooleanBlock<PBSOrder> {
+        Comparator::new(self).scalar_ge_bool_parallelized(lhs, scalar)
+    }
+
+    pub fn scalar_lt_bool_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        lhs: &RadixCiphertext<PBSOrder>,
+        scalar: u64,
+    ) -> BooleanBlock<PBSOrder> {
+        Comparator::new(self).scalar_lt_bool_parallelized(lhs, scalar)
+    }
+
+    pub fn scalar_le_bool_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        lhs: &RadixCiphertext<PBSOrder>,
+        scalar: u64,
+    ) -> BooleanBlock<PBSOrder> {
+        Comparator::new(self).scalar_le_bool_parallelized(lhs, scalar)
+    }
 }
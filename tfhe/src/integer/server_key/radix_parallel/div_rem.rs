@@ -0,0 +1,286 @@
+use crate::integer::ciphertext::RadixCiphertext;
+use crate::integer::ServerKey;
+use crate::shortint::{CiphertextBase, PBSOrderMarker};
+use rayon::prelude::*;
+
+impl ServerKey {
+    /// Computes homomorphically `(numerator / divisor, numerator % divisor)`.
+    ///
+    /// This is a restoring long division, processing the bits of the numerator from the most
+    /// significant one: at each step the partial remainder is shifted left to take the next bit,
+    /// compared to the divisor, and the divisor is subtracted from it if it is not smaller, the
+    /// outcome of the comparison being the next bit of the quotient. The partial remainder has one
+    /// more block than the inputs, so that shifting it does not overflow.
+    ///
+    /// Dividing by an encryption of zero does not fail, as the divisor is not known: the quotient
+    /// is then the maximum value of the ciphertext, i.e. all its bits are set, and the remainder
+    /// is the numerator.
+    ///
+    /// # Requirements
+    ///
+    /// - The input ciphertexts carry buffers are empty / clean
+    ///
+    /// # Panics
+    ///
+    /// Panics if the ciphertexts do not have the same number of blocks, or if the carry space of
+    /// the parameters is too small to pack two blocks for a bivariate PBS.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msg1 = 231;
+    /// let msg2 = 17;
+    ///
+    /// let ct1 = cks.encrypt(msg1);
+    /// let ct2 = cks.encrypt(msg2);
+    ///
+    /// let (q, r) = sks.unchecked_div_rem_parallelized(&ct1, &ct2);
+    /// let q: u64 = cks.decrypt(&q);
+    /// let r: u64 = cks.decrypt(&r);
+    /// assert_eq!(q, msg1 / msg2);
+    /// assert_eq!(r, msg1 % msg2);
+    ///
+    /// // Division by zero
+    /// let zero = cks.encrypt(0u64);
+    /// let (q, r) = sks.unchecked_div_rem_parallelized(&ct1, &zero);
+    /// let q: u64 = cks.decrypt(&q);
+    /// let r: u64 = cks.decrypt(&r);
+    /// assert_eq!(q, 255);
+    /// assert_eq!(r, msg1);
+    /// ```
+    pub fn unchecked_div_rem_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        numerator: &RadixCiphertext<PBSOrder>,
+        divisor: &RadixCiphertext<PBSOrder>,
+    ) -> (RadixCiphertext<PBSOrder>, RadixCiphertext<PBSOrder>) {
+        assert_eq!(
+            numerator.blocks.len(),
+            divisor.blocks.len(),
+            "The numerator and the divisor must have the same number of blocks"
+        );
+        debug_assert!(numerator.block_carries_are_empty());
+        debug_assert!(divisor.block_carries_are_empty());
+
+        let num_blocks = numerator.blocks.len();
+        let message_modulus = self.key.message_modulus.0 as u64;
+        let bits_per_block = message_modulus.ilog2() as usize;
+        let num_bits = num_blocks * bits_per_block;
+
+        // The bits of the numerator, from the least significant one
+        let numerator_bits = (0..num_bits)
+            .into_par_iter()
+            .map(|i| {
+                let shift = i % bits_per_block;
+                let acc = self.key.generate_accumulator(|x| (x >> shift) & 1);
+                self.key
+                    .apply_lookup_table(&numerator.blocks[i / bits_per_block], &acc)
+            })
+            .collect::<Vec<_>>();
+
+        // Shifts a block left by one bit, taking the most significant bit of the block below
+        let shift_lut = self.key.generate_accumulator_bivariate(|block, lower| {
+            ((block << 1) | (lower >> (bits_per_block - 1))) % message_modulus
+        });
+        // Takes the first block of the shifted remainder, and a bit of the numerator
+        let shift_in_lut = self
+            .key
+            .generate_accumulator_bivariate(|block, bit| ((block << 1) | bit) % message_modulus);
+        let mask_lut = self
+            .key
+            .generate_accumulator_bivariate(|block, condition| block * condition);
+
+        let mut extended_divisor = divisor.clone();
+        extended_divisor.blocks.push(self.key.create_trivial(0));
+        let mut remainder = RadixCiphertext::from(
+            (0..=num_blocks)
+                .map(|_| self.key.create_trivial(0))
+                .collect::<Vec<CiphertextBase<PBSOrder>>>(),
+        );
+        let mut quotient = RadixCiphertext::from(
+            (0..num_blocks)
+                .map(|_| self.key.create_trivial(0))
+                .collect::<Vec<CiphertextBase<PBSOrder>>>(),
+        );
+        // Two clean blocks can be packed for a bivariate PBS iff the carry space can hold a block
+        assert!(
+            self.key.carry_modulus.0 >= self.key.message_modulus.0,
+            "The parameters do not allow a bivariate PBS between two blocks"
+        );
+
+        for (i, numerator_bit) in numerator_bits.iter().enumerate().rev() {
+            // remainder = 2 * remainder + numerator_bit
+            let blocks = (0..=num_blocks)
+                .into_par_iter()
+                .map(|j| {
+                    if j == 0 {
+                        self.key.unchecked_apply_lookup_table_bivariate(
+                            &remainder.blocks[0],
+                            numerator_bit,
+                            &shift_in_lut,
+                        )
+                    } else {
+                        self.key.unchecked_apply_lookup_table_bivariate(
+                            &remainder.blocks[j],
+                            &remainder.blocks[j - 1],
+                            &shift_lut,
+                        )
+                    }
+                })
+                .collect::<Vec<_>>();
+            remainder = RadixCiphertext::from(blocks);
+
+            let is_ge = self.unchecked_ge_bool_parallelized(&remainder, &extended_divisor);
+            let condition = is_ge.as_block();
+
+            let mut subtrahend = extended_divisor.clone();
+            subtrahend.blocks.par_iter_mut().for_each(|block| {
+                self.key
+                    .unchecked_apply_lookup_table_bivariate_assign(block, condition, &mask_lut)
+            });
+            remainder = self.sub_parallelized(&remainder, &subtrahend);
+
+            // The bits of a quotient block are disjoint, so they are added without carry
+            let bit = self
+                .key
+                .unchecked_scalar_mul(condition, 1 << (i % bits_per_block));
+            self.key
+                .unchecked_add_assign(&mut quotient.blocks[i / bits_per_block], &bit);
+        }
+
+        // The remainder is smaller than the divisor, or is the numerator for a zero divisor
+        remainder.blocks.truncate(num_blocks);
+        if !quotient.block_carries_are_empty() {
+            self.full_propagate_parallelized(&mut quotient);
+        }
+        (quotient, remainder)
+    }
+
+    /// Computes homomorphically `(numerator / divisor, numerator % divisor)`, see
+    /// [`Self::unchecked_div_rem_parallelized`] for the division by zero.
+    ///
+    /// The carries of the inputs are propagated if needed.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msg1 = 100;
+    /// let msg2 = 7;
+    ///
+    /// let ct1 = cks.encrypt(msg1);
+    /// let mut ct2 = cks.encrypt(msg2);
+    /// // The sum has non-empty carries
+    /// let mut ct3 = sks.unchecked_add(&ct1, &ct2);
+    ///
+    /// let (q, r) = sks.smart_div_rem_parallelized(&mut ct3, &mut ct2);
+    /// let q: u64 = cks.decrypt(&q);
+    /// let r: u64 = cks.decrypt(&r);
+    /// assert_eq!(q, (msg1 + msg2) / msg2);
+    /// assert_eq!(r, (msg1 + msg2) % msg2);
+    /// ```
+    pub fn smart_div_rem_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        numerator: &mut RadixCiphertext<PBSOrder>,
+        divisor: &mut RadixCiphertext<PBSOrder>,
+    ) -> (RadixCiphertext<PBSOrder>, RadixCiphertext<PBSOrder>) {
+        rayon::join(
+            || {
+                if !numerator.block_carries_are_empty() {
+                    self.full_propagate_parallelized(numerator)
+                }
+            },
+            || {
+                if !divisor.block_carries_are_empty() {
+                    self.full_propagate_parallelized(divisor)
+                }
+            },
+        );
+        self.unchecked_div_rem_parallelized(numerator, divisor)
+    }
+
+    /// Computes homomorphically `(numerator / divisor, numerator % divisor)`, see
+    /// [`Self::unchecked_div_rem_parallelized`] for the division by zero.
+    ///
+    /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
+    /// check that the input ciphertexts block carries are empty and clears them if it's not the
+    /// case and the operation requires it. It outputs ciphertexts whose block carries are always
+    /// empty.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    pub fn div_rem_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        numerator: &RadixCiphertext<PBSOrder>,
+        divisor: &RadixCiphertext<PBSOrder>,
+    ) -> (RadixCiphertext<PBSOrder>, RadixCiphertext<PBSOrder>) {
+        let mut tmp_numerator;
+        let mut tmp_divisor;
+
+        let (numerator, divisor) = match (
+            numerator.block_carries_are_empty(),
+            divisor.block_carries_are_empty(),
+        ) {
+            (true, true) => (numerator, divisor),
+            (true, false) => {
+                tmp_divisor = divisor.clone();
+                self.full_propagate_parallelized(&mut tmp_divisor);
+                (numerator, &tmp_divisor)
+            }
+            (false, true) => {
+                tmp_numerator = numerator.clone();
+                self.full_propagate_parallelized(&mut tmp_numerator);
+                (&tmp_numerator, divisor)
+            }
+            (false, false) => {
+                tmp_numerator = numerator.clone();
+                tmp_divisor = divisor.clone();
+                rayon::join(
+                    || self.full_propagate_parallelized(&mut tmp_numerator),
+                    || self.full_propagate_parallelized(&mut tmp_divisor),
+                );
+                (&tmp_numerator, &tmp_divisor)
+            }
+        };
+        self.unchecked_div_rem_parallelized(numerator, divisor)
+    }
+
+    /// Computes homomorphically `numerator / divisor`, see [`Self::div_rem_parallelized`].
+    pub fn div_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        numerator: &RadixCiphertext<PBSOrder>,
+        divisor: &RadixCiphertext<PBSOrder>,
+    ) -> RadixCiphertext<PBSOrder> {
+        self.div_rem_parallelized(numerator, divisor).0
+    }
+
+    /// Computes homomorphically `numerator % divisor`, see [`Self::div_rem_parallelized`].
+    pub fn rem_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        numerator: &RadixCiphertext<PBSOrder>,
+        divisor: &RadixCiphertext<PBSOrder>,
+    ) -> RadixCiphertext<PBSOrder> {
+        self.div_rem_parallelized(numerator, divisor).1
+    }
+}
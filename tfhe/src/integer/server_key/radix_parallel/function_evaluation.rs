@@ -0,0 +1,386 @@
+//! Automatic choice of the mechanism evaluating a function over radix ciphertexts.
+
+use crate::integer::ciphertext::{IntegerCiphertext, RadixCiphertext};
+use crate::integer::wopbs::WopbsKey;
+use crate::integer::ServerKey;
+use crate::shortint::PBSOrderMarker;
+use rayon::prelude::*;
+
+/// The maximum number of input bits of a function evaluated with the WoPBS.
+///
+/// The vertical packing look-up table has one entry per value of the inputs for each block of
+/// the result, above this width it takes more than a few megabytes and its evaluation is not
+/// negligible anymore.
+pub const MAX_WOPBS_INPUT_BITS: usize = 16;
+
+/// The mechanism evaluating a function over radix ciphertexts.
+///
+/// A function over encrypted integers can be evaluated in several ways, whose cost depends on the
+/// width of the inputs, on the parameters of the server key and on the keys available:
+///
+/// * [`Pbs`]: a single block input goes through one PBS;
+/// * [`BivariatePacking`]: two blocks, either the two blocks of a univariate input or the single
+///   blocks of two inputs, are packed in one block when the carry space can hold a message, then
+///   each block of the result is one PBS;
+/// * [`OneHotDecomposition`]: the inputs are decomposed in one-hot encodings of their values, and
+///   each block of the result is a linear combination of the one-hot blocks. This only needs the
+///   server key, but the number of PBS grows linearly with the number of values of the inputs;
+/// * [`Wopbs`]: the bits of the inputs are extracted and the function is evaluated as a vertical
+///   packing look-up table. This needs a [`WopbsKey`], the number of PBS grows linearly with the
+///   number of bits of the inputs.
+///
+/// [`ServerKey::univariate_function_strategy`] and [`ServerKey::bivariate_function_strategy`]
+/// pick the cheapest strategy according to the cost model of
+/// [`ServerKey::function_evaluation_cost`], and the `apply_*_function_auto_parallelized` methods
+/// evaluate a function with it.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::integer::gen_keys_radix;
+/// use tfhe::integer::server_key::FunctionEvaluationStrategy;
+/// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+///
+/// let num_blocks = 2;
+/// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2, num_blocks);
+///
+/// // The two blocks of the input fit in a single block
+/// assert_eq!(
+///     sks.univariate_function_strategy(num_blocks, None),
+///     Some(FunctionEvaluationStrategy::BivariatePacking)
+/// );
+///
+/// let ct = cks.encrypt(13u64);
+/// let ct_res = sks.apply_univariate_function_auto_parallelized(None, &ct, |x| x * x + 1);
+///
+/// let dec: u64 = cks.decrypt(&ct_res);
+/// assert_eq!(dec, (13 * 13 + 1) % 16);
+/// ```
+///
+/// [`Pbs`]: Self::Pbs
+/// [`BivariatePacking`]: Self::BivariatePacking
+/// [`OneHotDecomposition`]: Self::OneHotDecomposition
+/// [`Wopbs`]: Self::Wopbs
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum FunctionEvaluationStrategy {
+    /// A single PBS on the single block of the input
+    Pbs,
+    /// A PBS per block of the result on the two input blocks packed in one block
+    BivariatePacking,
+    /// Decomposition of the inputs in one-hot encodings
+    OneHotDecomposition,
+    /// WoPBS with vertical packing
+    Wopbs,
+}
+
+impl FunctionEvaluationStrategy {
+    const ALL: [Self; 4] = [
+        Self::Pbs,
+        Self::BivariatePacking,
+        Self::OneHotDecomposition,
+        Self::Wopbs,
+    ];
+}
+
+impl ServerKey {
+    /// Returns the estimated number of PBS needed to evaluate a function of `num_inputs` inputs
+    /// of `num_blocks` blocks each with `strategy`, or `None` if the strategy cannot evaluate
+    /// such a function with the given keys.
+    ///
+    /// With `m` the message modulus, `V = m^num_blocks` the number of values of an input and `B`
+    /// the total number of bits of the inputs, the cost model is:
+    ///
+    /// * [`Pbs`](FunctionEvaluationStrategy::Pbs): 1, only for a single input of a single block;
+    /// * [`BivariatePacking`](FunctionEvaluationStrategy::BivariatePacking): `num_blocks`, only
+    ///   for two blocks in total and if the carry modulus is at least the message modulus;
+    /// * [`OneHotDecomposition`](FunctionEvaluationStrategy::OneHotDecomposition): the one-hot
+    ///   encoding of an input takes `num_blocks * m` PBS, plus `V` if the input has several
+    ///   blocks. A bivariate function then selects each block of the result among `V` blocks
+    ///   with a bivariate PBS, and each block of the result is cleaned with a final PBS. Only for
+    ///   `V` up to `2^32`;
+    /// * [`Wopbs`](FunctionEvaluationStrategy::Wopbs): `B` bit extractions, `B` circuit
+    ///   bootstraps of `cbs_level` PBS each and a PBS per block of the result to go back to the
+    ///   parameters of the server key. The vertical packing itself is neglected, which holds up to
+    ///   [`MAX_WOPBS_INPUT_BITS`] bits, so only up to this width and if a WoPBS key is given.
+    ///
+    /// The PBS of the WoPBS use larger parameters than those of the server key, which the model
+    /// does not account for: it only compares numbers of PBS, which differ by an order of
+    /// magnitude between the strategies as soon as the inputs have a few blocks.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `num_inputs` is not 1 or 2, or if `num_blocks` is 0.
+    pub fn function_evaluation_cost(
+        &self,
+        strategy: FunctionEvaluationStrategy,
+        num_inputs: usize,
+        num_blocks: usize,
+        wopbs_key: Option<&WopbsKey>,
+    ) -> Option<u64> {
+        assert!(
+            num_inputs == 1 || num_inputs == 2,
+            "Only univariate and bivariate functions are supported"
+        );
+        assert!(num_blocks > 0, "The inputs must have at least one block");
+
+        let message_modulus = self.key.message_modulus.0 as u64;
+        let bits_per_block = message_modulus.ilog2() as u64;
+        let num_blocks_u64 = num_blocks as u64;
+
+        match strategy {
+            FunctionEvaluationStrategy::Pbs => (num_inputs == 1 && num_blocks == 1).then_some(1),
+            FunctionEvaluationStrategy::BivariatePacking => (num_inputs * num_blocks == 2
+                && self.key.carry_modulus.0 >= self.key.message_modulus.0)
+                .then_some(num_blocks_u64),
+            FunctionEvaluationStrategy::OneHotDecomposition => {
+                let num_values = message_modulus
+                    .checked_pow(num_blocks as u32)
+                    .filter(|num_values| *num_values <= u32::MAX as u64)?;
+                let one_hot_cost =
+                    num_blocks_u64 * message_modulus + if num_blocks > 1 { num_values } else { 0 };
+                let selection_cost = if num_inputs == 2 {
+                    num_values * num_blocks_u64
+                } else {
+                    0
+                };
+                Some(num_inputs as u64 * one_hot_cost + selection_cost + num_blocks_u64)
+            }
+            FunctionEvaluationStrategy::Wopbs => {
+                let cbs_level = wopbs_key?.parameters().cbs_level.0 as u64;
+                let num_bits = num_inputs as u64 * num_blocks_u64 * bits_per_block;
+                (num_bits <= MAX_WOPBS_INPUT_BITS as u64)
+                    .then_some(num_bits * (1 + cbs_level) + num_blocks_u64)
+            }
+        }
+    }
+
+    /// Returns the cheapest strategy to evaluate a univariate function over inputs of
+    /// `num_blocks` blocks, or `None` if the function cannot be evaluated with the given keys.
+    ///
+    /// See [`ServerKey::function_evaluation_cost`] for the cost model.
+    pub fn univariate_function_strategy(
+        &self,
+        num_blocks: usize,
+        wopbs_key: Option<&WopbsKey>,
+    ) -> Option<FunctionEvaluationStrategy> {
+        self.cheapest_function_evaluation_strategy(1, num_blocks, wopbs_key)
+    }
+
+    /// Returns the cheapest strategy to evaluate a bivariate function over inputs of
+    /// `num_blocks` blocks each, or `None` if the function cannot be evaluated with the given
+    /// keys.
+    ///
+    /// See [`ServerKey::function_evaluation_cost`] for the cost model.
+    pub fn bivariate_function_strategy(
+        &self,
+        num_blocks: usize,
+        wopbs_key: Option<&WopbsKey>,
+    ) -> Option<FunctionEvaluationStrategy> {
+        self.cheapest_function_evaluation_strategy(2, num_blocks, wopbs_key)
+    }
+
+    fn cheapest_function_evaluation_strategy(
+        &self,
+        num_inputs: usize,
+        num_blocks: usize,
+        wopbs_key: Option<&WopbsKey>,
+    ) -> Option<FunctionEvaluationStrategy> {
+        FunctionEvaluationStrategy::ALL
+            .into_iter()
+            .filter_map(|strategy| {
+                self.function_evaluation_cost(strategy, num_inputs, num_blocks, wopbs_key)
+                    .map(|cost| (strategy, cost))
+            })
+            .min_by_key(|(_, cost)| *cost)
+            .map(|(strategy, _)| strategy)
+    }
+
+    /// Computes homomorphically `f(ct)` with the cheapest strategy given the keys available, see
+    /// [`ServerKey::univariate_function_strategy`].
+    ///
+    /// The result has as many blocks as the input and is reduced modulo the message space of the
+    /// input. The WoPBS key, if any, must have been generated for the parameters of this server
+    /// key.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no strategy can evaluate the function with the given keys.
+    pub fn apply_univariate_function_auto_parallelized<PBSOrder, F>(
+        &self,
+        wopbs_key: Option<&WopbsKey>,
+        ct: &RadixCiphertext<PBSOrder>,
+        f: F,
+    ) -> RadixCiphertext<PBSOrder>
+    where
+        PBSOrder: PBSOrderMarker,
+        RadixCiphertext<PBSOrder>: IntegerCiphertext<PBSOrder = PBSOrder>,
+        F: Fn(u64) -> u64 + Sync,
+    {
+        let num_blocks = ct.blocks.len();
+        let strategy = self
+            .univariate_function_strategy(num_blocks, wopbs_key)
+            .unwrap_or_else(|| {
+                panic!("No strategy can evaluate a function over {num_blocks} blocks")
+            });
+
+        match strategy {
+            FunctionEvaluationStrategy::Pbs => {
+                let message_modulus = self.key.message_modulus.0 as u64;
+                let acc = self
+                    .key
+                    .generate_accumulator(|x| f(x % message_modulus) % message_modulus);
+                RadixCiphertext::from(vec![self.key.apply_lookup_table(&ct.blocks[0], &acc)])
+            }
+            FunctionEvaluationStrategy::BivariatePacking => {
+                self.apply_univariate_function_by_packing(ct, f)
+            }
+            FunctionEvaluationStrategy::OneHotDecomposition => {
+                self.apply_univariate_function_by_decomposition(ct, f)
+            }
+            FunctionEvaluationStrategy::Wopbs => {
+                // A WoPBS key is needed for the strategy to be chosen
+                let wopbs_key = wopbs_key.unwrap();
+                let ct = wopbs_key.keyswitch_to_wopbs_params(self, ct);
+                let lut = wopbs_key.generate_lut_radix(&ct, f);
+                let ct_res = wopbs_key.wopbs(&ct, &lut);
+                wopbs_key.keyswitch_to_pbs_params(&ct_res)
+            }
+        }
+    }
+
+    /// Computes homomorphically `f(lhs, rhs)` with the cheapest strategy given the keys
+    /// available, see [`ServerKey::bivariate_function_strategy`].
+    ///
+    /// The result has as many blocks as the inputs and is reduced modulo the message space of the
+    /// inputs. The WoPBS key, if any, must have been generated for the parameters of this server
+    /// key.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the inputs do not have the same number of blocks, or if no strategy can
+    /// evaluate the function with the given keys.
+    pub fn apply_bivariate_function_auto_parallelized<PBSOrder, F>(
+        &self,
+        wopbs_key: Option<&WopbsKey>,
+        lhs: &RadixCiphertext<PBSOrder>,
+        rhs: &RadixCiphertext<PBSOrder>,
+        f: F,
+    ) -> RadixCiphertext<PBSOrder>
+    where
+        PBSOrder: PBSOrderMarker,
+        RadixCiphertext<PBSOrder>: IntegerCiphertext<PBSOrder = PBSOrder>,
+        F: Fn(u64, u64) -> u64 + Sync,
+    {
+        assert_eq!(
+            lhs.blocks.len(),
+            rhs.blocks.len(),
+            "The ciphertexts must have the same number of blocks"
+        );
+
+        let num_blocks = lhs.blocks.len();
+        let strategy = self
+            .bivariate_function_strategy(num_blocks, wopbs_key)
+            .unwrap_or_else(|| {
+                panic!("No strategy can evaluate a function over two inputs of {num_blocks} blocks")
+            });
+
+        match strategy {
+            FunctionEvaluationStrategy::Pbs => {
+                unreachable!("A bivariate function cannot be a single PBS on a single block")
+            }
+            FunctionEvaluationStrategy::BivariatePacking => {
+                self.apply_bivariate_function_in_block(lhs, rhs, f)
+            }
+            FunctionEvaluationStrategy::OneHotDecomposition => {
+                self.apply_bivariate_function_by_decomposition(lhs, rhs, f)
+            }
+            FunctionEvaluationStrategy::Wopbs => {
+                // A WoPBS key is needed for the strategy to be chosen
+                self.apply_bivariate_function_by_wopbs(wopbs_key.unwrap(), lhs, rhs, f)
+            }
+        }
+    }
+
+    /// Evaluates a univariate function over two blocks packed in a single block, each block of
+    /// the result being a bivariate PBS of the most and least significant input blocks.
+    fn apply_univariate_function_by_packing<PBSOrder, F>(
+        &self,
+        ct: &RadixCiphertext<PBSOrder>,
+        f: F,
+    ) -> RadixCiphertext<PBSOrder>
+    where
+        PBSOrder: PBSOrderMarker,
+        F: Fn(u64) -> u64 + Sync,
+    {
+        let message_modulus = self.key.message_modulus.0 as u64;
+        let num_values = message_modulus * message_modulus;
+
+        let mut blocks = ct.blocks.clone();
+        blocks.par_iter_mut().for_each(|block| {
+            if block.degree.0 >= self.key.message_modulus.0 {
+                self.key.message_extract_assign(block);
+            }
+        });
+        let (low, high) = (&blocks[0], &blocks[1]);
+
+        let blocks = (0..2u32)
+            .into_par_iter()
+            .map(|block_index| {
+                let weight = message_modulus.pow(block_index);
+                let lut = self.key.generate_accumulator_bivariate(|high, low| {
+                    (f(high * message_modulus + low) % num_values / weight) % message_modulus
+                });
+                self.key
+                    .unchecked_apply_lookup_table_bivariate(high, low, &lut)
+            })
+            .collect::<Vec<_>>();
+
+        RadixCiphertext::from(blocks)
+    }
+
+    /// Evaluates a univariate function as a linear combination of the blocks of the one-hot
+    /// encoding of the input.
+    fn apply_univariate_function_by_decomposition<PBSOrder, F>(
+        &self,
+        ct: &RadixCiphertext<PBSOrder>,
+        f: F,
+    ) -> RadixCiphertext<PBSOrder>
+    where
+        PBSOrder: PBSOrderMarker,
+        F: Fn(u64) -> u64,
+    {
+        let num_blocks = ct.blocks.len();
+        let message_modulus = self.key.message_modulus.0 as u64;
+        let num_values = message_modulus
+            .checked_pow(num_blocks as u32)
+            .filter(|num_values| *num_values <= u32::MAX as u64)
+            .expect("Too many blocks to decompose the input in a one-hot encoding");
+
+        let one_hot = self.radix_to_one_hot_parallelized(ct, num_values as usize);
+        let results = (0..num_values)
+            .map(|value| f(value) % num_values)
+            .collect::<Vec<_>>();
+
+        let blocks =
+            (0..num_blocks)
+                .into_par_iter()
+                .map(|block_index| {
+                    let weight = message_modulus.pow(block_index as u32);
+                    let terms = one_hot.blocks.iter().zip(results.iter()).filter_map(
+                        |(is_value, result)| {
+                            let digit = (result / weight) % message_modulus;
+                            (digit != 0)
+                                .then(|| self.key.unchecked_scalar_mul(is_value, digit as u8))
+                        },
+                    );
+                    let mut block = self.sum_exclusive_blocks(terms);
+                    if block.degree.0 >= self.key.message_modulus.0 {
+                        self.key.message_extract_assign(&mut block);
+                    }
+                    block
+                })
+                .collect::<Vec<_>>();
+
+        RadixCiphertext::from(blocks)
+    }
+}
@@ -1,18 +1,28 @@
 mod add;
 mod bitwise_op;
+mod bivariate_function;
 mod comparison;
+mod div_rem;
+mod function_evaluation;
 mod mul;
 mod neg;
+mod one_hot;
+mod overflow;
 mod rotate;
+mod saturating;
 mod scalar_add;
+mod scalar_div;
 mod scalar_mul;
 mod scalar_sub;
 mod shift;
+mod signed;
 mod sub;
 
 #[cfg(test)]
 mod tests;
 
+pub use function_evaluation::{FunctionEvaluationStrategy, MAX_WOPBS_INPUT_BITS};
+
 use super::ServerKey;
 use crate::integer::ciphertext::RadixCiphertext;
 use crate::shortint::PBSOrderMarker;
@@ -49,10 +59,14 @@ impl ServerKey {
         ctxt: &mut RadixCiphertext<PBSOrder>,
         index: usize,
     ) {
-        let (carry, message) = rayon::join(
-            || self.key.carry_extract(&ctxt.blocks[index]),
-            || self.key.message_extract(&ctxt.blocks[index]),
-        );
+        let (carry, message) = if self.fused_carry_extraction {
+            self.key.carry_and_message_extract(&ctxt.blocks[index])
+        } else {
+            rayon::join(
+                || self.key.carry_extract(&ctxt.blocks[index]),
+                || self.key.message_extract(&ctxt.blocks[index]),
+            )
+        };
         ctxt.blocks[index] = message;
 
         //add the carry to the next block
@@ -102,6 +116,7 @@ impl ServerKey {
         &self,
         ctxt: &mut RadixCiphertext<PBSOrder>,
     ) {
-        self.partial_propagate_parallelized(ctxt, 0)
+        self.partial_propagate_parallelized(ctxt, 0);
+        debug_check!(check_carries_empty, "full_propagate_parallelized", ctxt);
     }
 }
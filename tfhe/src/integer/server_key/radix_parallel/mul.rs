@@ -479,6 +479,17 @@ impl ServerKey {
         lhs: &mut RadixCiphertext<PBSOrder>,
         rhs: &RadixCiphertext<PBSOrder>,
     ) {
+        let terms = self.compute_multiplication_terms(lhs, rhs);
+        self.sum_multiplication_terms_into(lhs, terms);
+    }
+
+    /// Computes the partial products of `lhs` with each block of `rhs`, shifted by the index of
+    /// the block, to be summed by [`Self::sum_multiplication_terms_into`].
+    fn compute_multiplication_terms<PBSOrder: PBSOrderMarker>(
+        &self,
+        lhs: &RadixCiphertext<PBSOrder>,
+        rhs: &RadixCiphertext<PBSOrder>,
+    ) -> Vec<RadixCiphertext<PBSOrder>> {
         let num_blocks = lhs.blocks.len();
         let mut terms = vec![self.create_trivial_zero_radix(num_blocks); num_blocks];
         terms
@@ -487,8 +498,7 @@ impl ServerKey {
             .for_each(|(term, (i, rhs_i))| {
                 *term = self.unchecked_block_mul_parallelized(lhs, rhs_i, i);
             });
-
-        self.sum_multiplication_terms_into(lhs, terms);
+        terms
     }
 
     /// Computes homomorphically a multiplication between two ciphertexts encrypting integer values.
@@ -670,5 +680,181 @@ impl ServerKey {
         };
 
         self.unchecked_mul_assign_parallelized(lhs, rhs);
+        debug_check!(check_carries_empty, "mul_assign_parallelized", lhs);
+    }
+
+    /// Computes homomorphically `lhs * rhs + addend`, the result is assigned to `lhs`.
+    ///
+    /// The addend is summed along with the partial products of the multiplication, so that the
+    /// carries are propagated once, instead of once for the multiplication and once for the
+    /// addition.
+    ///
+    /// This function computes the operation without checking if it exceeds the capacity of the
+    /// ciphertext: the blocks of `rhs` and `addend` must have empty carries.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    ///
+    /// # Panics
+    ///
+    /// Panics if `addend` does not have as many blocks as `lhs`.
+    pub fn unchecked_mul_add_assign_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        lhs: &mut RadixCiphertext<PBSOrder>,
+        rhs: &RadixCiphertext<PBSOrder>,
+        addend: &RadixCiphertext<PBSOrder>,
+    ) {
+        assert_eq!(
+            lhs.blocks.len(),
+            addend.blocks.len(),
+            "The addend must have as many blocks as the operands of the multiplication"
+        );
+        let mut terms = self.compute_multiplication_terms(lhs, rhs);
+        terms.push(addend.clone());
+        self.sum_multiplication_terms_into(lhs, terms);
+    }
+
+    /// Computes homomorphically `lhs * rhs + addend`, see
+    /// [`Self::unchecked_mul_add_assign_parallelized`].
+    ///
+    /// The result is returned as a new ciphertext.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    pub fn unchecked_mul_add_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        lhs: &RadixCiphertext<PBSOrder>,
+        rhs: &RadixCiphertext<PBSOrder>,
+        addend: &RadixCiphertext<PBSOrder>,
+    ) -> RadixCiphertext<PBSOrder> {
+        let mut result = lhs.clone();
+        self.unchecked_mul_add_assign_parallelized(&mut result, rhs, addend);
+        result
+    }
+
+    /// Computes homomorphically `lhs * rhs + addend`, see
+    /// [`Self::unchecked_mul_add_assign_parallelized`].
+    ///
+    /// The carries of the operands are propagated first if they are not empty. The result is
+    /// returned as a new ciphertext.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    pub fn smart_mul_add_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        lhs: &mut RadixCiphertext<PBSOrder>,
+        rhs: &mut RadixCiphertext<PBSOrder>,
+        addend: &mut RadixCiphertext<PBSOrder>,
+    ) -> RadixCiphertext<PBSOrder> {
+        rayon::join(
+            || {
+                if !lhs.block_carries_are_empty() {
+                    self.full_propagate_parallelized(lhs)
+                }
+            },
+            || {
+                rayon::join(
+                    || {
+                        if !rhs.block_carries_are_empty() {
+                            self.full_propagate_parallelized(rhs)
+                        }
+                    },
+                    || {
+                        if !addend.block_carries_are_empty() {
+                            self.full_propagate_parallelized(addend)
+                        }
+                    },
+                )
+            },
+        );
+
+        self.unchecked_mul_add_parallelized(lhs, rhs, addend)
+    }
+
+    /// Computes homomorphically `lhs * rhs + addend`.
+    ///
+    /// The addend is summed along with the partial products of the multiplication, so that the
+    /// carries are propagated once: this is cheaper than a [multiplication](Self::mul_parallelized)
+    /// followed by an [addition](Self::add_parallelized), e.g. to evaluate a polynomial with the
+    /// Horner scheme.
+    ///
+    /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
+    /// check that the input ciphertexts block carries are empty and clears them if it's not the
+    /// case and the operation requires it. It outputs a ciphertext whose block carries are always
+    /// empty.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    ///
+    /// # Panics
+    ///
+    /// Panics if the operands do not all have the same number of blocks.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let clear_1 = 170;
+    /// let clear_2 = 6;
+    /// let clear_3 = 97;
+    ///
+    /// // Encrypt three messages
+    /// let ctxt_1 = cks.encrypt(clear_1);
+    /// let ctxt_2 = cks.encrypt(clear_2);
+    /// let ctxt_3 = cks.encrypt(clear_3);
+    ///
+    /// // Compute homomorphically a multiplication followed by an addition
+    /// let ct_res = sks.mul_add_parallelized(&ctxt_1, &ctxt_2, &ctxt_3);
+    /// // Decrypt
+    /// let res: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!((clear_1 * clear_2 + clear_3) % 256, res);
+    /// ```
+    pub fn mul_add_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        lhs: &RadixCiphertext<PBSOrder>,
+        rhs: &RadixCiphertext<PBSOrder>,
+        addend: &RadixCiphertext<PBSOrder>,
+    ) -> RadixCiphertext<PBSOrder> {
+        let mut ct_res = lhs.clone();
+        self.mul_add_assign_parallelized(&mut ct_res, rhs, addend);
+        ct_res
+    }
+
+    pub fn mul_add_assign_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        lhs: &mut RadixCiphertext<PBSOrder>,
+        rhs: &RadixCiphertext<PBSOrder>,
+        addend: &RadixCiphertext<PBSOrder>,
+    ) {
+        let propagated_copy = |ct: &RadixCiphertext<PBSOrder>| {
+            (!ct.block_carries_are_empty()).then(|| {
+                let mut tmp = ct.clone();
+                self.full_propagate_parallelized(&mut tmp);
+                tmp
+            })
+        };
+
+        let (_, (tmp_rhs, tmp_addend)) = rayon::join(
+            || {
+                if !lhs.block_carries_are_empty() {
+                    self.full_propagate_parallelized(lhs)
+                }
+            },
+            || rayon::join(|| propagated_copy(rhs), || propagated_copy(addend)),
+        );
+        let rhs = tmp_rhs.as_ref().unwrap_or(rhs);
+        let addend = tmp_addend.as_ref().unwrap_or(addend);
+
+        self.unchecked_mul_add_assign_parallelized(lhs, rhs, addend);
     }
 }
@@ -1,6 +1,7 @@
-use crate::integer::ciphertext::RadixCiphertext;
+use crate::integer::ciphertext::{BooleanBlock, RadixCiphertext};
 use crate::integer::ServerKey;
 use crate::shortint::PBSOrderMarker;
+use rayon::prelude::*;
 
 impl ServerKey {
     /// Homomorphically computes the opposite of a ciphertext encrypting an integer message.
@@ -90,4 +91,119 @@ impl ServerKey {
         self.full_propagate_parallelized(&mut ctxt);
         ctxt
     }
+
+    /// Computes the two's complement of a ciphertext, that is `!ct + 1`.
+    ///
+    /// Each block is inverted with a PBS, then 1 is added to the first block and the
+    /// carries are propagated. The result has empty carries.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let ctxt = cks.encrypt(3u64);
+    ///
+    /// let ct_res = sks.twos_complement_parallelized(&ctxt);
+    ///
+    /// let dec: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(253, dec);
+    /// ```
+    pub fn twos_complement_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        ctxt: &RadixCiphertext<PBSOrder>,
+    ) -> RadixCiphertext<PBSOrder> {
+        let mut result = self.bitnot_parallelized(ctxt);
+        if let Some(first_block) = result.blocks.first_mut() {
+            self.key.unchecked_scalar_add_assign(first_block, 1);
+        }
+        self.full_propagate_parallelized(&mut result);
+        result
+    }
+
+    /// Negates the ciphertext if `condition` encrypts true, returns it unchanged otherwise.
+    ///
+    /// This does not branch on the condition: each block goes through a single bivariate PBS
+    /// computing `condition ? (modulus - 1 - block) : block`, then the condition is added to
+    /// the first block and the carries are propagated, which gives `!ct + 1` when the
+    /// condition is true. The result has empty carries.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the carry space of the parameters is too small to pack a block and the
+    /// condition for a bivariate PBS.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let ctxt = cks.encrypt(3u64);
+    /// let yes = cks.encrypt_bool(true);
+    /// let no = cks.encrypt_bool(false);
+    ///
+    /// let ct_res = sks.conditional_negate_parallelized(&ctxt, &yes);
+    /// let dec: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(253, dec);
+    ///
+    /// let ct_res = sks.conditional_negate_parallelized(&ctxt, &no);
+    /// let dec: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(3, dec);
+    /// ```
+    pub fn conditional_negate_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        ctxt: &RadixCiphertext<PBSOrder>,
+        condition: &BooleanBlock<PBSOrder>,
+    ) -> RadixCiphertext<PBSOrder> {
+        let mut result = ctxt.clone();
+        self.conditional_negate_assign_parallelized(&mut result, condition);
+        result
+    }
+
+    pub fn conditional_negate_assign_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        ctxt: &mut RadixCiphertext<PBSOrder>,
+        condition: &BooleanBlock<PBSOrder>,
+    ) {
+        if !ctxt.block_carries_are_empty() {
+            self.full_propagate_parallelized(ctxt);
+        }
+
+        let modulus = self.key.message_modulus.0 as u64;
+        let lut = self.key.generate_accumulator_bivariate(|x, condition| {
+            if condition == 0 {
+                x
+            } else {
+                (modulus - 1 - x) % modulus
+            }
+        });
+
+        let condition = condition.as_block();
+        if let Some(first_block) = ctxt.blocks.first() {
+            assert!(
+                lut.is_bivariate_pbs_possible(first_block, condition),
+                "The parameters do not allow a bivariate PBS between a block and the condition"
+            );
+        }
+
+        ctxt.blocks.par_iter_mut().for_each(|block| {
+            self.key
+                .unchecked_apply_lookup_table_bivariate_assign(block, condition, &lut)
+        });
+
+        if let Some(first_block) = ctxt.blocks.first_mut() {
+            self.key.unchecked_add_assign(first_block, condition);
+        }
+        self.full_propagate_parallelized(ctxt);
+    }
 }
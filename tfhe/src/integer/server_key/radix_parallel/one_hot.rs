@@ -0,0 +1,303 @@
+use crate::integer::ciphertext::{BooleanBlock, OneHotCiphertext, RadixCiphertext};
+use crate::integer::ServerKey;
+use crate::shortint::{CiphertextBase, PBSOrderMarker};
+use rayon::prelude::*;
+
+impl ServerKey {
+    /// Sums blocks of which at most one encrypts a non-zero value.
+    ///
+    /// As the result is known to fit in the message space, the carries are cleaned
+    /// only when the degree or the noise level would exceed the capacity of the block.
+    pub(crate) fn sum_exclusive_blocks<PBSOrder: PBSOrderMarker>(
+        &self,
+        blocks: impl IntoIterator<Item = CiphertextBase<PBSOrder>>,
+    ) -> CiphertextBase<PBSOrder> {
+        let mut blocks = blocks.into_iter();
+        let Some(mut sum) = blocks.next() else {
+            return self.key.create_trivial(0);
+        };
+
+        for block in blocks {
+            if sum.degree.0 + block.degree.0 > self.key.max_degree.0
+                || !self
+                    .key
+                    .max_noise_level()
+                    .validate(sum.noise_level + block.noise_level)
+            {
+                self.key.message_extract_assign(&mut sum);
+            }
+            self.key.unchecked_add_assign(&mut sum, &block);
+        }
+        sum
+    }
+
+    /// Returns a block encrypting 1 if all the input blocks encrypt 1, 0 otherwise.
+    ///
+    /// The input blocks must encrypt 0 or 1.
+    pub(crate) fn all_blocks_are_one<PBSOrder: PBSOrderMarker>(
+        &self,
+        mut blocks: Vec<CiphertextBase<PBSOrder>>,
+    ) -> CiphertextBase<PBSOrder> {
+        let max_chunk_size = self.key.max_degree.0.max(1);
+        loop {
+            if blocks.is_empty() {
+                return self.key.create_trivial(1);
+            }
+            if blocks.len() == 1 {
+                return blocks.pop().unwrap();
+            }
+
+            blocks = blocks
+                .par_chunks(max_chunk_size)
+                .map(|chunk| {
+                    let mut sum = chunk[0].clone();
+                    for block in &chunk[1..] {
+                        self.key.unchecked_add_assign(&mut sum, block);
+                    }
+                    let count = chunk.len() as u64;
+                    let acc = self.key.generate_accumulator(|x| u64::from(x == count));
+                    self.key.apply_lookup_table(&sum, &acc)
+                })
+                .collect();
+        }
+    }
+
+    /// Returns whether the one-hot ciphertext encrypts the given clear value.
+    ///
+    /// This does not require any PBS: the result is a copy of the block at index `value`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2, 2);
+    ///
+    /// let ct = cks.as_ref().encrypt_one_hot(3, 8);
+    ///
+    /// let is_three = sks.one_hot_scalar_eq(&ct, 3);
+    /// let is_four = sks.one_hot_scalar_eq(&ct, 4);
+    ///
+    /// assert!(cks.decrypt_bool(&is_three));
+    /// assert!(!cks.decrypt_bool(&is_four));
+    /// ```
+    pub fn one_hot_scalar_eq<PBSOrder: PBSOrderMarker>(
+        &self,
+        ct: &OneHotCiphertext<PBSOrder>,
+        value: u64,
+    ) -> BooleanBlock<PBSOrder> {
+        let block = usize::try_from(value)
+            .ok()
+            .and_then(|index| ct.blocks.get(index))
+            .map_or_else(|| self.key.create_trivial(0), Clone::clone);
+        BooleanBlock::new_unchecked(block)
+    }
+
+    /// Returns whether the one-hot ciphertext encrypts one of the given clear values.
+    ///
+    /// Values outside of the range of the ciphertext are ignored.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2, 2);
+    ///
+    /// let ct = cks.as_ref().encrypt_one_hot(5, 16);
+    ///
+    /// let res = sks.one_hot_scalar_contains(&ct, &[1, 5, 9, 12, 20]);
+    /// assert!(cks.decrypt_bool(&res));
+    ///
+    /// let res = sks.one_hot_scalar_contains(&ct, &[0, 2, 4]);
+    /// assert!(!cks.decrypt_bool(&res));
+    /// ```
+    pub fn one_hot_scalar_contains<PBSOrder: PBSOrderMarker>(
+        &self,
+        ct: &OneHotCiphertext<PBSOrder>,
+        values: &[u64],
+    ) -> BooleanBlock<PBSOrder> {
+        let mut indices = values
+            .iter()
+            .filter_map(|value| usize::try_from(*value).ok())
+            .filter(|index| *index < ct.blocks.len())
+            .collect::<Vec<_>>();
+        indices.sort_unstable();
+        indices.dedup();
+
+        let sum = self.sum_exclusive_blocks(indices.into_iter().map(|i| ct.blocks[i].clone()));
+        BooleanBlock::new_unchecked(sum)
+    }
+
+    /// Returns whether two one-hot ciphertexts encrypt the same value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the ciphertexts do not have the same number of values.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2, 2);
+    ///
+    /// let ct1 = cks.as_ref().encrypt_one_hot(6, 8);
+    /// let ct2 = cks.as_ref().encrypt_one_hot(6, 8);
+    /// let ct3 = cks.as_ref().encrypt_one_hot(2, 8);
+    ///
+    /// assert!(cks.decrypt_bool(&sks.one_hot_eq_parallelized(&ct1, &ct2)));
+    /// assert!(!cks.decrypt_bool(&sks.one_hot_eq_parallelized(&ct1, &ct3)));
+    /// ```
+    pub fn one_hot_eq_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        lhs: &OneHotCiphertext<PBSOrder>,
+        rhs: &OneHotCiphertext<PBSOrder>,
+    ) -> BooleanBlock<PBSOrder> {
+        assert_eq!(
+            lhs.num_values(),
+            rhs.num_values(),
+            "Cannot compare one-hot ciphertexts with different number of values"
+        );
+
+        let acc = self.key.generate_accumulator(|x| u64::from(x == 2));
+        let products = lhs
+            .blocks
+            .par_iter()
+            .zip(rhs.blocks.par_iter())
+            .map(|(lhs_block, rhs_block)| {
+                let sum = self.key.unchecked_add(lhs_block, rhs_block);
+                self.key.apply_lookup_table(&sum, &acc)
+            })
+            .collect::<Vec<_>>();
+
+        BooleanBlock::new_unchecked(self.sum_exclusive_blocks(products))
+    }
+
+    /// Converts a one-hot ciphertext into a radix ciphertext with `num_blocks` blocks.
+    ///
+    /// Each output block is a linear combination of the one-hot blocks weighted by
+    /// the corresponding clear digit, so this only needs PBS to clean the carries.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let num_blocks = 2;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let ct = cks.as_ref().encrypt_one_hot(11, 16);
+    ///
+    /// let ct_res = sks.one_hot_to_radix_parallelized(&ct, num_blocks);
+    ///
+    /// let dec: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, 11);
+    /// ```
+    pub fn one_hot_to_radix_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        ct: &OneHotCiphertext<PBSOrder>,
+        num_blocks: usize,
+    ) -> RadixCiphertext<PBSOrder> {
+        let message_modulus = self.key.message_modulus.0 as u64;
+
+        let blocks = (0..num_blocks)
+            .into_par_iter()
+            .map(|block_index| {
+                let terms = ct.blocks.iter().enumerate().filter_map(|(value, block)| {
+                    let digit = message_modulus
+                        .checked_pow(block_index as u32)
+                        .map_or(0, |weight| (value as u64 / weight) % message_modulus);
+                    (digit != 0).then(|| self.key.unchecked_scalar_mul(block, digit as u8))
+                });
+                let mut block = self.sum_exclusive_blocks(terms);
+                if block.degree.0 >= self.key.message_modulus.0 {
+                    self.key.message_extract_assign(&mut block);
+                }
+                block
+            })
+            .collect::<Vec<_>>();
+
+        RadixCiphertext::from(blocks)
+    }
+
+    /// Converts a radix ciphertext into a one-hot ciphertext able to encode `num_values`
+    /// values.
+    ///
+    /// The radix ciphertext must encrypt a value smaller than `num_values`,
+    /// otherwise the result will not have any block set.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2, 2);
+    ///
+    /// let ct = cks.encrypt(9u64);
+    ///
+    /// let ct_res = sks.radix_to_one_hot_parallelized(&ct, 12);
+    ///
+    /// let dec = cks.as_ref().decrypt_one_hot(&ct_res);
+    /// assert_eq!(dec, 9);
+    /// ```
+    pub fn radix_to_one_hot_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        ct: &RadixCiphertext<PBSOrder>,
+        num_values: usize,
+    ) -> OneHotCiphertext<PBSOrder> {
+        let mut tmp_ct;
+        let ct = if ct.block_carries_are_empty() {
+            ct
+        } else {
+            tmp_ct = ct.clone();
+            self.full_propagate_parallelized(&mut tmp_ct);
+            &tmp_ct
+        };
+
+        let message_modulus = self.key.message_modulus.0 as u64;
+        let accs = (0..message_modulus)
+            .map(|digit| self.key.generate_accumulator(|x| u64::from(x == digit)))
+            .collect::<Vec<_>>();
+
+        // digit_is_equal[i][d] encrypts whether the i-th block encrypts d
+        let digit_is_equal = ct
+            .blocks
+            .par_iter()
+            .map(|block| {
+                accs.par_iter()
+                    .map(|acc| self.key.apply_lookup_table(block, acc))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        let blocks = (0..num_values as u64)
+            .into_par_iter()
+            .map(|value| {
+                let mut remaining = value;
+                let matching_digits = digit_is_equal
+                    .iter()
+                    .map(|block_equalities| {
+                        let digit = remaining % message_modulus;
+                        remaining /= message_modulus;
+                        block_equalities[digit as usize].clone()
+                    })
+                    .collect::<Vec<_>>();
+
+                if remaining != 0 {
+                    // The value cannot be represented with the blocks of the input
+                    self.key.create_trivial(0)
+                } else {
+                    self.all_blocks_are_one(matching_digits)
+                }
+            })
+            .collect::<Vec<_>>();
+
+        OneHotCiphertext { blocks }
+    }
+}
@@ -0,0 +1,154 @@
+//! Unsigned operations which also compute whether they overflowed.
+//!
+//! Like Rust's `overflowing_*` methods on the primitive integers, these return the wrapped result
+//! alongside a [`BooleanBlock`] encrypting whether the result wrapped around `2^num_bits`, so that
+//! the overflow can be handled homomorphically, e.g. with a cmux.
+//!
+//! Like the "default" operations, these clear the carries of their inputs if needed, and output
+//! ciphertexts whose block carries are empty.
+use crate::integer::ciphertext::{BooleanBlock, RadixCiphertext};
+use crate::integer::ServerKey;
+use crate::shortint::PBSOrderMarker;
+
+impl ServerKey {
+    /// Computes homomorphically `lhs + rhs`, and whether the addition overflowed.
+    ///
+    /// The inputs are extended with one block so that the sum does not wrap: the carry out of
+    /// the last block of the inputs is then the overflow flag.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let msg1 = 200u8;
+    /// let msg2 = 100u8;
+    ///
+    /// let ct1 = cks.encrypt(msg1 as u64);
+    /// let ct2 = cks.encrypt(msg2 as u64);
+    ///
+    /// let (ct_res, overflowed) = sks.overflowing_add_parallelized(&ct1, &ct2);
+    ///
+    /// let dec_result: u64 = cks.decrypt(&ct_res);
+    /// let dec_overflowed = cks.decrypt_bool(&overflowed);
+    /// let (expected, expected_overflowed) = msg1.overflowing_add(msg2);
+    /// assert_eq!(dec_result, expected as u64);
+    /// assert_eq!(dec_overflowed, expected_overflowed);
+    /// ```
+    pub fn overflowing_add_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        lhs: &RadixCiphertext<PBSOrder>,
+        rhs: &RadixCiphertext<PBSOrder>,
+    ) -> (RadixCiphertext<PBSOrder>, BooleanBlock<PBSOrder>) {
+        let extended_lhs = self.extend_radix_with_trivial_zero_blocks_msb(lhs, 1);
+        let extended_rhs = self.extend_radix_with_trivial_zero_blocks_msb(rhs, 1);
+
+        let mut result = self.add_parallelized(&extended_lhs, &extended_rhs);
+        // The last block encrypts the carry out of the inputs, i.e. 0 or 1
+        let overflowed = BooleanBlock::new_unchecked(result.blocks.pop().unwrap());
+        (result, overflowed)
+    }
+
+    /// Computes homomorphically `lhs - rhs`, and whether the subtraction overflowed, i.e. whether
+    /// `lhs < rhs`.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let msg1 = 27u8;
+    /// let msg2 = 100u8;
+    ///
+    /// let ct1 = cks.encrypt(msg1 as u64);
+    /// let ct2 = cks.encrypt(msg2 as u64);
+    ///
+    /// let (ct_res, overflowed) = sks.overflowing_sub_parallelized(&ct1, &ct2);
+    ///
+    /// let dec_result: u64 = cks.decrypt(&ct_res);
+    /// let dec_overflowed = cks.decrypt_bool(&overflowed);
+    /// let (expected, expected_overflowed) = msg1.overflowing_sub(msg2);
+    /// assert_eq!(dec_result, expected as u64);
+    /// assert_eq!(dec_overflowed, expected_overflowed);
+    /// ```
+    pub fn overflowing_sub_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        lhs: &RadixCiphertext<PBSOrder>,
+        rhs: &RadixCiphertext<PBSOrder>,
+    ) -> (RadixCiphertext<PBSOrder>, BooleanBlock<PBSOrder>) {
+        rayon::join(
+            || self.sub_parallelized(lhs, rhs),
+            || self.lt_bool_parallelized(lhs, rhs),
+        )
+    }
+
+    /// Computes homomorphically `lhs * rhs`, and whether the multiplication overflowed.
+    ///
+    /// The full product is computed on twice as many blocks as the inputs: the multiplication
+    /// overflowed if its upper half is not zero. This makes this operation noticeably more
+    /// expensive than [`Self::mul_parallelized`].
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let msg1 = 27u8;
+    /// let msg2 = 12u8;
+    ///
+    /// let ct1 = cks.encrypt(msg1 as u64);
+    /// let ct2 = cks.encrypt(msg2 as u64);
+    ///
+    /// let (ct_res, overflowed) = sks.overflowing_mul_parallelized(&ct1, &ct2);
+    ///
+    /// let dec_result: u64 = cks.decrypt(&ct_res);
+    /// let dec_overflowed = cks.decrypt_bool(&overflowed);
+    /// let (expected, expected_overflowed) = msg1.overflowing_mul(msg2);
+    /// assert_eq!(dec_result, expected as u64);
+    /// assert_eq!(dec_overflowed, expected_overflowed);
+    /// ```
+    pub fn overflowing_mul_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        lhs: &RadixCiphertext<PBSOrder>,
+        rhs: &RadixCiphertext<PBSOrder>,
+    ) -> (RadixCiphertext<PBSOrder>, BooleanBlock<PBSOrder>) {
+        let num_blocks = lhs.blocks.len();
+        let extended_lhs = self.extend_radix_with_trivial_zero_blocks_msb(lhs, num_blocks);
+        let extended_rhs = self.extend_radix_with_trivial_zero_blocks_msb(rhs, num_blocks);
+
+        let mut result = self.mul_parallelized(&extended_lhs, &extended_rhs);
+        let upper_half = RadixCiphertext::from(result.blocks.split_off(num_blocks));
+        let overflowed = self.unchecked_gt_bool_parallelized(
+            &upper_half,
+            &self.create_trivial_zero_radix(num_blocks),
+        );
+        (result, overflowed)
+    }
+}
@@ -0,0 +1,144 @@
+//! Unsigned operations which saturate at the bounds of the ciphertexts instead of wrapping.
+//!
+//! Like Rust's `saturating_*` methods on the primitive integers, these compute the overflowing
+//! operation, then select with a cmux on each block between the wrapped result and the bound
+//! which was crossed: `2^num_bits - 1` for the addition and the multiplication, 0 for the
+//! subtraction.
+//!
+//! Like the "default" operations, these clear the carries of their inputs if needed, and output
+//! ciphertexts whose block carries are empty.
+use crate::integer::ciphertext::{BooleanBlock, RadixCiphertext};
+use crate::integer::ServerKey;
+use crate::shortint::PBSOrderMarker;
+use rayon::prelude::*;
+
+impl ServerKey {
+    /// Replaces each block of `ct` by `bound_block` if `overflowed` encrypts true.
+    fn saturate_assign_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        ct: &mut RadixCiphertext<PBSOrder>,
+        overflowed: &BooleanBlock<PBSOrder>,
+        bound_block: u64,
+    ) {
+        let bound = self.key.create_trivial(bound_block);
+        let condition = overflowed.as_block();
+        ct.blocks.par_iter_mut().for_each(|block| {
+            *block = self.key.if_then_else(condition, &bound, block);
+        });
+    }
+
+    /// Computes homomorphically `lhs + rhs`, saturating at `2^num_bits - 1`.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let msg1 = 200u8;
+    /// let msg2 = 100u8;
+    ///
+    /// let ct1 = cks.encrypt(msg1 as u64);
+    /// let ct2 = cks.encrypt(msg2 as u64);
+    ///
+    /// let ct_res = sks.saturating_add_parallelized(&ct1, &ct2);
+    ///
+    /// let dec_result: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec_result, msg1.saturating_add(msg2) as u64);
+    /// ```
+    pub fn saturating_add_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        lhs: &RadixCiphertext<PBSOrder>,
+        rhs: &RadixCiphertext<PBSOrder>,
+    ) -> RadixCiphertext<PBSOrder> {
+        let (mut result, overflowed) = self.overflowing_add_parallelized(lhs, rhs);
+        let max_block = self.key.message_modulus.0 as u64 - 1;
+        self.saturate_assign_parallelized(&mut result, &overflowed, max_block);
+        result
+    }
+
+    /// Computes homomorphically `lhs - rhs`, saturating at 0.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let msg1 = 27u8;
+    /// let msg2 = 100u8;
+    ///
+    /// let ct1 = cks.encrypt(msg1 as u64);
+    /// let ct2 = cks.encrypt(msg2 as u64);
+    ///
+    /// let ct_res = sks.saturating_sub_parallelized(&ct1, &ct2);
+    ///
+    /// let dec_result: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec_result, msg1.saturating_sub(msg2) as u64);
+    /// ```
+    pub fn saturating_sub_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        lhs: &RadixCiphertext<PBSOrder>,
+        rhs: &RadixCiphertext<PBSOrder>,
+    ) -> RadixCiphertext<PBSOrder> {
+        let (mut result, overflowed) = self.overflowing_sub_parallelized(lhs, rhs);
+        self.saturate_assign_parallelized(&mut result, &overflowed, 0);
+        result
+    }
+
+    /// Computes homomorphically `lhs * rhs`, saturating at `2^num_bits - 1`.
+    ///
+    /// This is as expensive as [`Self::overflowing_mul_parallelized`].
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let msg1 = 27u8;
+    /// let msg2 = 12u8;
+    ///
+    /// let ct1 = cks.encrypt(msg1 as u64);
+    /// let ct2 = cks.encrypt(msg2 as u64);
+    ///
+    /// let ct_res = sks.saturating_mul_parallelized(&ct1, &ct2);
+    ///
+    /// let dec_result: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec_result, msg1.saturating_mul(msg2) as u64);
+    /// ```
+    pub fn saturating_mul_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        lhs: &RadixCiphertext<PBSOrder>,
+        rhs: &RadixCiphertext<PBSOrder>,
+    ) -> RadixCiphertext<PBSOrder> {
+        let (mut result, overflowed) = self.overflowing_mul_parallelized(lhs, rhs);
+        let max_block = self.key.message_modulus.0 as u64 - 1;
+        self.saturate_assign_parallelized(&mut result, &overflowed, max_block);
+        result
+    }
+}
@@ -0,0 +1,385 @@
+use crate::integer::ciphertext::RadixCiphertext;
+use crate::integer::ServerKey;
+use crate::shortint::PBSOrderMarker;
+
+/// Constants used to divide by a divisor that is not a power of two
+/// using a multiplication and shifts.
+///
+/// See "Division by Invariant Integers using Multiplication" (Granlund, Montgomery),
+/// Figure 4.1: `q = (t + ((x - t) >> 1)) >> (l - 1)` with `t = (m * x) >> N`.
+struct InvariantDivisor {
+    /// The multiplier, it is strictly smaller than 2^N
+    multiplier: u128,
+    /// ceil(log2(divisor))
+    log2_ceil: u32,
+}
+
+impl InvariantDivisor {
+    fn new(divisor: u64, numerator_bits: u32) -> Self {
+        debug_assert!(!divisor.is_power_of_two());
+        debug_assert!(numerator_bits <= 128);
+
+        let divisor = u128::from(divisor);
+        let log2_ceil = divisor.ilog2() + 1;
+
+        // multiplier = floor(2^N * (2^l - d) / d) + 1, computed with a long division
+        // as 2^N * (2^l - d) may not fit in a u128.
+        // 2^l - d < d so the quotient fits in N bits
+        let mut remainder = (1u128 << log2_ceil) - divisor;
+        let mut quotient = 0u128;
+        for _ in 0..numerator_bits {
+            remainder <<= 1;
+            quotient <<= 1;
+            if remainder >= divisor {
+                remainder -= divisor;
+                quotient |= 1;
+            }
+        }
+
+        Self {
+            multiplier: quotient + 1,
+            log2_ceil,
+        }
+    }
+}
+
+impl ServerKey {
+    /// Returns the number of message bits in the ciphertext
+    fn radix_num_bits<PBSOrder: PBSOrderMarker>(&self, ct: &RadixCiphertext<PBSOrder>) -> u32 {
+        self.key.message_modulus.0.ilog2() * ct.blocks.len() as u32
+    }
+
+    /// Keeps the `num_bits` least significant bits of the ciphertext,
+    /// and sets the others to 0.
+    ///
+    /// Only the block containing the bit at index `num_bits` needs a PBS,
+    /// blocks under it are kept as is, blocks above it are replaced by trivial zeros.
+    fn unchecked_keep_low_bits<PBSOrder: PBSOrderMarker>(
+        &self,
+        ct: &RadixCiphertext<PBSOrder>,
+        num_bits: u32,
+    ) -> RadixCiphertext<PBSOrder> {
+        let bits_per_block = self.key.message_modulus.0.ilog2();
+        let num_full_blocks = (num_bits / bits_per_block) as usize;
+        let remaining_bits = num_bits % bits_per_block;
+
+        let mut result = ct.clone();
+        for (i, block) in result.blocks.iter_mut().enumerate() {
+            if i < num_full_blocks {
+                continue;
+            }
+            if i == num_full_blocks && remaining_bits != 0 {
+                let mask = (1u64 << remaining_bits) - 1;
+                let acc = self.key.generate_accumulator(|x| x & mask);
+                self.key.apply_lookup_table_assign(block, &acc);
+            } else {
+                self.key.create_trivial_assign(block, 0);
+            }
+        }
+        result
+    }
+
+    /// Computes homomorphically `(ct / divisor, ct % divisor)`.
+    ///
+    /// When the divisor is a power of two, the quotient is a right shift and the remainder a
+    /// mask of the low bits, which needs at most one PBS.
+    /// Otherwise, the quotient is computed with a multiplication by a precomputed constant
+    /// followed by shifts.
+    ///
+    /// # Requirements
+    ///
+    /// - The input ciphertext carry buffer is empty / clean
+    ///
+    /// # Panics
+    ///
+    /// Panics if `divisor` is 0, or if the divisor is not a power of two and the ciphertext has
+    /// more than 128 bits of message.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msg = 230;
+    ///
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// let (q, r) = sks.unchecked_scalar_div_rem_parallelized(&ct, 16);
+    /// let q: u64 = cks.decrypt(&q);
+    /// let r: u64 = cks.decrypt(&r);
+    /// assert_eq!(q, msg / 16);
+    /// assert_eq!(r, msg % 16);
+    ///
+    /// let (q, r) = sks.unchecked_scalar_div_rem_parallelized(&ct, 7);
+    /// let q: u64 = cks.decrypt(&q);
+    /// let r: u64 = cks.decrypt(&r);
+    /// assert_eq!(q, msg / 7);
+    /// assert_eq!(r, msg % 7);
+    /// ```
+    pub fn unchecked_scalar_div_rem_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        ct: &RadixCiphertext<PBSOrder>,
+        divisor: u64,
+    ) -> (RadixCiphertext<PBSOrder>, RadixCiphertext<PBSOrder>) {
+        assert_ne!(divisor, 0, "attempt to divide by zero");
+        debug_assert!(ct.block_carries_are_empty());
+
+        if divisor.is_power_of_two() {
+            let shift = divisor.ilog2();
+            return rayon::join(
+                || self.unchecked_scalar_right_shift_parallelized(ct, u64::from(shift)),
+                || self.unchecked_keep_low_bits(ct, shift),
+            );
+        }
+
+        let numerator_bits = self.radix_num_bits(ct);
+        if numerator_bits < 64 && divisor >= 1u64 << numerator_bits {
+            // The divisor is greater than any value the ciphertext can hold
+            let mut quotient = ct.clone();
+            for block in &mut quotient.blocks {
+                self.key.create_trivial_assign(block, 0);
+            }
+            return (quotient, ct.clone());
+        }
+
+        assert!(
+            numerator_bits <= 128,
+            "Division by a scalar that is not a power of two is only supported \
+            for ciphertexts of at most 128 bits, got {numerator_bits} bits"
+        );
+
+        let quotient = self.unchecked_scalar_div_by_invariant(ct, divisor, numerator_bits);
+        let product = self.scalar_mul_parallelized(&quotient, divisor);
+        let remainder = self.sub_parallelized(ct, &product);
+        (quotient, remainder)
+    }
+
+    fn unchecked_scalar_div_by_invariant<PBSOrder: PBSOrderMarker>(
+        &self,
+        ct: &RadixCiphertext<PBSOrder>,
+        divisor: u64,
+        numerator_bits: u32,
+    ) -> RadixCiphertext<PBSOrder> {
+        let InvariantDivisor {
+            multiplier,
+            log2_ceil,
+        } = InvariantDivisor::new(divisor, numerator_bits);
+
+        let num_blocks = ct.blocks.len();
+
+        // Compute t = (multiplier * ct) >> N using twice as many blocks
+        let mut extended = ct.clone();
+        extended
+            .blocks
+            .resize_with(2 * num_blocks, || self.key.create_trivial(0));
+
+        let multiplier_low = multiplier as u64;
+        let multiplier_high = (multiplier >> 64) as u64;
+        let mut product = if multiplier_high == 0 {
+            self.scalar_mul_parallelized(&extended, multiplier_low)
+        } else {
+            let (low, mut high) = rayon::join(
+                || self.scalar_mul_parallelized(&extended, multiplier_low),
+                || self.scalar_mul_parallelized(&extended, multiplier_high),
+            );
+            self.scalar_left_shift_assign_parallelized(&mut high, 64);
+            self.add_parallelized(&low, &high)
+        };
+        let t = RadixCiphertext::from(product.blocks.split_off(num_blocks));
+
+        // q = (t + ((ct - t) >> 1)) >> (l - 1)
+        let mut q = self.sub_parallelized(ct, &t);
+        self.scalar_right_shift_assign_parallelized(&mut q, 1);
+        self.add_assign_parallelized(&mut q, &t);
+        self.scalar_right_shift_assign_parallelized(&mut q, u64::from(log2_ceil - 1));
+        q
+    }
+
+    /// Computes homomorphically `ct / divisor`.
+    ///
+    /// See [Self::unchecked_scalar_div_rem_parallelized] for the requirements.
+    pub fn unchecked_scalar_div_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        ct: &RadixCiphertext<PBSOrder>,
+        divisor: u64,
+    ) -> RadixCiphertext<PBSOrder> {
+        assert_ne!(divisor, 0, "attempt to divide by zero");
+        if divisor.is_power_of_two() {
+            // Avoids computing the remainder
+            return self.unchecked_scalar_right_shift_parallelized(ct, u64::from(divisor.ilog2()));
+        }
+        self.unchecked_scalar_div_rem_parallelized(ct, divisor).0
+    }
+
+    /// Computes homomorphically `ct % divisor`.
+    ///
+    /// See [Self::unchecked_scalar_div_rem_parallelized] for the requirements.
+    pub fn unchecked_scalar_rem_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        ct: &RadixCiphertext<PBSOrder>,
+        divisor: u64,
+    ) -> RadixCiphertext<PBSOrder> {
+        assert_ne!(
+            divisor, 0,
+            "attempt to calculate the remainder with a divisor of zero"
+        );
+        if divisor.is_power_of_two() {
+            // Avoids computing the quotient
+            return self.unchecked_keep_low_bits(ct, divisor.ilog2());
+        }
+        self.unchecked_scalar_div_rem_parallelized(ct, divisor).1
+    }
+
+    /// Computes homomorphically `(ct / divisor, ct % divisor)`.
+    ///
+    /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
+    /// check that the input ciphertext block carries are empty and clears them if it's not the
+    /// case and the operation requires it. It outputs ciphertexts whose block carries are always
+    /// empty.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `divisor` is 0, or if the divisor is not a power of two and the ciphertext has
+    /// more than 128 bits of message.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msg = 201;
+    /// let divisor = 10;
+    ///
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// let (q, r) = sks.scalar_div_rem_parallelized(&ct, divisor);
+    /// let q: u64 = cks.decrypt(&q);
+    /// let r: u64 = cks.decrypt(&r);
+    /// assert_eq!(q, msg / divisor);
+    /// assert_eq!(r, msg % divisor);
+    /// ```
+    pub fn scalar_div_rem_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        ct: &RadixCiphertext<PBSOrder>,
+        divisor: u64,
+    ) -> (RadixCiphertext<PBSOrder>, RadixCiphertext<PBSOrder>) {
+        let mut tmp_ct;
+        let ct = if ct.block_carries_are_empty() {
+            ct
+        } else {
+            tmp_ct = ct.clone();
+            self.full_propagate_parallelized(&mut tmp_ct);
+            &tmp_ct
+        };
+        self.unchecked_scalar_div_rem_parallelized(ct, divisor)
+    }
+
+    /// Computes homomorphically `ct / divisor`.
+    ///
+    /// Dividing by a power of two only costs a right shift.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msg = 201;
+    ///
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// let ct_res = sks.scalar_div_parallelized(&ct, 8);
+    /// let dec: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, msg / 8);
+    /// ```
+    pub fn scalar_div_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        ct: &RadixCiphertext<PBSOrder>,
+        divisor: u64,
+    ) -> RadixCiphertext<PBSOrder> {
+        let mut tmp_ct;
+        let ct = if ct.block_carries_are_empty() {
+            ct
+        } else {
+            tmp_ct = ct.clone();
+            self.full_propagate_parallelized(&mut tmp_ct);
+            &tmp_ct
+        };
+        self.unchecked_scalar_div_parallelized(ct, divisor)
+    }
+
+    /// Computes homomorphically `ct % divisor`.
+    ///
+    /// Computing the remainder of a division by a power of two needs at most one PBS.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msg = 201;
+    ///
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// let ct_res = sks.scalar_rem_parallelized(&ct, 8);
+    /// let dec: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, msg % 8);
+    /// ```
+    pub fn scalar_rem_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        ct: &RadixCiphertext<PBSOrder>,
+        divisor: u64,
+    ) -> RadixCiphertext<PBSOrder> {
+        let mut tmp_ct;
+        let ct = if ct.block_carries_are_empty() {
+            ct
+        } else {
+            tmp_ct = ct.clone();
+            self.full_propagate_parallelized(&mut tmp_ct);
+            &tmp_ct
+        };
+        self.unchecked_scalar_rem_parallelized(ct, divisor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::InvariantDivisor;
+
+    #[test]
+    fn test_invariant_divisor_clear() {
+        // Check the formula on clear values for 8 bits numerators
+        for divisor in (3u64..256).filter(|d| !d.is_power_of_two()) {
+            let InvariantDivisor {
+                multiplier,
+                log2_ceil,
+            } = InvariantDivisor::new(divisor, 8);
+            assert!(multiplier < 1 << 8);
+
+            for x in 0u128..256 {
+                let t = (multiplier * x) >> 8;
+                let q = (t + ((x - t) >> 1)) >> (log2_ceil - 1);
+                assert_eq!(q, x / u128::from(divisor));
+            }
+        }
+    }
+}
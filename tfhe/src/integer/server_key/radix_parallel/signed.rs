@@ -0,0 +1,401 @@
+//! Operations on signed integers in radix decomposition.
+//!
+//! Adding, subtracting, multiplying and negating are the same operations on the two's complement
+//! encodings as on unsigned integers, modulo `2^num_bits`, so the unsigned operations are used.
+//! The comparisons flip the sign bit of their operands first: this maps the signed range
+//! `[-2^(num_bits-1), 2^(num_bits-1))` to `[0, 2^num_bits)` while keeping the order, so that the
+//! unsigned comparisons can be used too.
+//!
+//! Like the "default" unsigned operations, these clear the carries of their inputs if needed, and
+//! output ciphertexts whose block carries are empty.
+use crate::integer::ciphertext::{BooleanBlock, RadixCiphertext, SignedRadixCiphertext};
+use crate::integer::ServerKey;
+use crate::shortint::PBSOrderMarker;
+use rayon::prelude::*;
+
+fn as_unsigned<PBSOrder: PBSOrderMarker>(
+    ctxt: &SignedRadixCiphertext<PBSOrder>,
+) -> RadixCiphertext<PBSOrder> {
+    RadixCiphertext::from(ctxt.blocks.clone())
+}
+
+fn into_signed<PBSOrder: PBSOrderMarker>(
+    ctxt: RadixCiphertext<PBSOrder>,
+) -> SignedRadixCiphertext<PBSOrder> {
+    SignedRadixCiphertext::from(ctxt.blocks)
+}
+
+impl ServerKey {
+    /// Computes homomorphically the wrapping addition of two signed integers.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let msg1 = -14i8;
+    /// let msg2 = 97i8;
+    ///
+    /// let ct1 = cks.encrypt_signed(msg1);
+    /// let ct2 = cks.encrypt_signed(msg2);
+    ///
+    /// let ct_res = sks.signed_add_parallelized(&ct1, &ct2);
+    ///
+    /// let dec_result: i8 = cks.decrypt_signed(&ct_res);
+    /// assert_eq!(dec_result, msg1 + msg2);
+    /// ```
+    pub fn signed_add_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        lhs: &SignedRadixCiphertext<PBSOrder>,
+        rhs: &SignedRadixCiphertext<PBSOrder>,
+    ) -> SignedRadixCiphertext<PBSOrder> {
+        into_signed(self.add_parallelized(&as_unsigned(lhs), &as_unsigned(rhs)))
+    }
+
+    /// Computes homomorphically the wrapping subtraction of two signed integers.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    pub fn signed_sub_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        lhs: &SignedRadixCiphertext<PBSOrder>,
+        rhs: &SignedRadixCiphertext<PBSOrder>,
+    ) -> SignedRadixCiphertext<PBSOrder> {
+        into_signed(self.sub_parallelized(&as_unsigned(lhs), &as_unsigned(rhs)))
+    }
+
+    /// Computes homomorphically the wrapping multiplication of two signed integers.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let ct1 = cks.encrypt_signed(-7i8);
+    /// let ct2 = cks.encrypt_signed(12i8);
+    ///
+    /// let ct_res = sks.signed_mul_parallelized(&ct1, &ct2);
+    ///
+    /// let dec_result: i8 = cks.decrypt_signed(&ct_res);
+    /// assert_eq!(dec_result, -84);
+    /// ```
+    pub fn signed_mul_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        lhs: &SignedRadixCiphertext<PBSOrder>,
+        rhs: &SignedRadixCiphertext<PBSOrder>,
+    ) -> SignedRadixCiphertext<PBSOrder> {
+        into_signed(self.mul_parallelized(&as_unsigned(lhs), &as_unsigned(rhs)))
+    }
+
+    /// Computes homomorphically the wrapping opposite of a signed integer, the opposite of the
+    /// minimum value being itself.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    pub fn signed_neg_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        ctxt: &SignedRadixCiphertext<PBSOrder>,
+    ) -> SignedRadixCiphertext<PBSOrder> {
+        into_signed(self.neg_parallelized(&as_unsigned(ctxt)))
+    }
+
+    /// Returns an encryption of true if the signed integer is negative.
+    pub fn signed_is_negative_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        ctxt: &SignedRadixCiphertext<PBSOrder>,
+    ) -> BooleanBlock<PBSOrder> {
+        let mut tmp_ctxt;
+        let ctxt = if ctxt.block_carries_are_empty() {
+            ctxt
+        } else {
+            tmp_ctxt = ctxt.clone();
+            self.signed_full_propagate_parallelized(&mut tmp_ctxt);
+            &tmp_ctxt
+        };
+
+        let half_modulus = self.key.message_modulus.0 as u64 / 2;
+        let lut = self
+            .key
+            .generate_accumulator(|x| u64::from(x >= half_modulus));
+        let sign_block = ctxt
+            .blocks
+            .last()
+            .expect("Cannot compute the sign of a ciphertext without blocks");
+        BooleanBlock::new_unchecked(self.key.apply_lookup_table(sign_block, &lut))
+    }
+
+    /// Computes homomorphically the wrapping absolute value of a signed integer, the absolute
+    /// value of the minimum value being itself.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let ct = cks.encrypt_signed(-57i8);
+    ///
+    /// let ct_res = sks.signed_abs_parallelized(&ct);
+    ///
+    /// let dec_result: i8 = cks.decrypt_signed(&ct_res);
+    /// assert_eq!(dec_result, 57);
+    /// ```
+    pub fn signed_abs_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        ctxt: &SignedRadixCiphertext<PBSOrder>,
+    ) -> SignedRadixCiphertext<PBSOrder> {
+        let mut result = ctxt.clone();
+        self.signed_full_propagate_parallelized(&mut result);
+        let is_negative = self.signed_is_negative_parallelized(&result);
+        into_signed(self.conditional_negate_parallelized(&as_unsigned(&result), &is_negative))
+    }
+
+    /// Computes homomorphically the sign of a signed integer: -1 if it is negative, 0 if it is
+    /// zero and 1 if it is positive.
+    ///
+    /// The sign block and whether the integer is non-zero are computed in parallel, then all the
+    /// blocks of the result are computed from them in a single parallel pass.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let ct = cks.encrypt_signed(-57i8);
+    ///
+    /// let ct_res = sks.signed_signum_parallelized(&ct);
+    ///
+    /// let dec_result: i8 = cks.decrypt_signed(&ct_res);
+    /// assert_eq!(dec_result, -1);
+    /// ```
+    pub fn signed_signum_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        ctxt: &SignedRadixCiphertext<PBSOrder>,
+    ) -> SignedRadixCiphertext<PBSOrder> {
+        let mut tmp_ctxt;
+        let ctxt = if ctxt.block_carries_are_empty() {
+            ctxt
+        } else {
+            tmp_ctxt = ctxt.clone();
+            self.signed_full_propagate_parallelized(&mut tmp_ctxt);
+            &tmp_ctxt
+        };
+
+        let (is_negative, is_non_zero) = rayon::join(
+            || self.signed_is_negative_parallelized(ctxt),
+            || {
+                let zero = self.create_trivial_zero_radix(ctxt.blocks.len());
+                self.unchecked_gt_bool_parallelized(&as_unsigned(ctxt), &zero)
+            },
+        );
+
+        // -1 has all its blocks set to the maximum value, 1 only has its first block set to 1
+        let max_value = self.key.message_modulus.0 as u64 - 1;
+        let first_block_lut =
+            self.key
+                .generate_accumulator_bivariate(|is_negative, is_non_zero| {
+                    if is_negative == 1 {
+                        max_value
+                    } else {
+                        is_non_zero
+                    }
+                });
+        let other_blocks_lut = self
+            .key
+            .generate_accumulator(|is_negative| is_negative * max_value);
+
+        let blocks = (0..ctxt.blocks.len())
+            .into_par_iter()
+            .map(|i| {
+                if i == 0 {
+                    self.key.unchecked_apply_lookup_table_bivariate(
+                        is_negative.as_block(),
+                        is_non_zero.as_block(),
+                        &first_block_lut,
+                    )
+                } else {
+                    self.key
+                        .apply_lookup_table(is_negative.as_block(), &other_blocks_lut)
+                }
+            })
+            .collect::<Vec<_>>();
+        SignedRadixCiphertext::from(blocks)
+    }
+
+    /// Propagates the carries of a signed integer, the carry out of the most significant block
+    /// is discarded.
+    pub fn signed_full_propagate_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        ctxt: &mut SignedRadixCiphertext<PBSOrder>,
+    ) {
+        let mut unsigned = RadixCiphertext::from(std::mem::take(&mut ctxt.blocks));
+        self.full_propagate_parallelized(&mut unsigned);
+        ctxt.blocks = unsigned.blocks;
+    }
+
+    /// Returns the unsigned integer of the same bits as `ctxt` with its sign bit flipped, that is
+    /// `ctxt + 2^(num_bits-1)`, whose order is the signed order of `ctxt`.
+    fn flip_sign_bit_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        ctxt: &SignedRadixCiphertext<PBSOrder>,
+    ) -> RadixCiphertext<PBSOrder> {
+        let mut result = as_unsigned(ctxt);
+        if !result.block_carries_are_empty() {
+            self.full_propagate_parallelized(&mut result);
+        }
+
+        let half_modulus = self.key.message_modulus.0 as u64 / 2;
+        let lut = self.key.generate_accumulator(|x| x ^ half_modulus);
+        let sign_block = result
+            .blocks
+            .last_mut()
+            .expect("Cannot compare ciphertexts without blocks");
+        self.key.apply_lookup_table_assign(sign_block, &lut);
+        result
+    }
+
+    pub fn signed_eq_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        lhs: &SignedRadixCiphertext<PBSOrder>,
+        rhs: &SignedRadixCiphertext<PBSOrder>,
+    ) -> BooleanBlock<PBSOrder> {
+        self.eq_bool_parallelized(&as_unsigned(lhs), &as_unsigned(rhs))
+    }
+
+    /// Computes homomorphically whether `lhs > rhs` for two signed integers.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let ct1 = cks.encrypt_signed(3i8);
+    /// let ct2 = cks.encrypt_signed(-100i8);
+    ///
+    /// let ct_res = sks.signed_gt_parallelized(&ct1, &ct2);
+    /// assert!(cks.decrypt_bool(&ct_res));
+    ///
+    /// let ct_res = sks.signed_min_parallelized(&ct1, &ct2);
+    /// let dec_result: i8 = cks.decrypt_signed(&ct_res);
+    /// assert_eq!(dec_result, -100);
+    /// ```
+    pub fn signed_gt_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        lhs: &SignedRadixCiphertext<PBSOrder>,
+        rhs: &SignedRadixCiphertext<PBSOrder>,
+    ) -> BooleanBlock<PBSOrder> {
+        let (lhs, rhs) = rayon::join(
+            || self.flip_sign_bit_parallelized(lhs),
+            || self.flip_sign_bit_parallelized(rhs),
+        );
+        self.gt_bool_parallelized(&lhs, &rhs)
+    }
+
+    pub fn signed_ge_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        lhs: &SignedRadixCiphertext<PBSOrder>,
+        rhs: &SignedRadixCiphertext<PBSOrder>,
+    ) -> BooleanBlock<PBSOrder> {
+        let (lhs, rhs) = rayon::join(
+            || self.flip_sign_bit_parallelized(lhs),
+            || self.flip_sign_bit_parallelized(rhs),
+        );
+        self.ge_bool_parallelized(&lhs, &rhs)
+    }
+
+    pub fn signed_lt_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        lhs: &SignedRadixCiphertext<PBSOrder>,
+        rhs: &SignedRadixCiphertext<PBSOrder>,
+    ) -> BooleanBlock<PBSOrder> {
+        let (lhs, rhs) = rayon::join(
+            || self.flip_sign_bit_parallelized(lhs),
+            || self.flip_sign_bit_parallelized(rhs),
+        );
+        self.lt_bool_parallelized(&lhs, &rhs)
+    }
+
+    pub fn signed_le_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        lhs: &SignedRadixCiphertext<PBSOrder>,
+        rhs: &SignedRadixCiphertext<PBSOrder>,
+    ) -> BooleanBlock<PBSOrder> {
+        let (lhs, rhs) = rayon::join(
+            || self.flip_sign_bit_parallelized(lhs),
+            || self.flip_sign_bit_parallelized(rhs),
+        );
+        self.le_bool_parallelized(&lhs, &rhs)
+    }
+
+    pub fn signed_max_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        lhs: &SignedRadixCiphertext<PBSOrder>,
+        rhs: &SignedRadixCiphertext<PBSOrder>,
+    ) -> SignedRadixCiphertext<PBSOrder> {
+        let (lhs, rhs) = rayon::join(
+            || self.flip_sign_bit_parallelized(lhs),
+            || self.flip_sign_bit_parallelized(rhs),
+        );
+        let max = into_signed(self.max_parallelized(&lhs, &rhs));
+        into_signed(self.flip_sign_bit_parallelized(&max))
+    }
+
+    pub fn signed_min_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        lhs: &SignedRadixCiphertext<PBSOrder>,
+        rhs: &SignedRadixCiphertext<PBSOrder>,
+    ) -> SignedRadixCiphertext<PBSOrder> {
+        let (lhs, rhs) = rayon::join(
+            || self.flip_sign_bit_parallelized(lhs),
+            || self.flip_sign_bit_parallelized(rhs),
+        );
+        let min = into_signed(self.min_parallelized(&lhs, &rhs));
+        into_signed(self.flip_sign_bit_parallelized(&min))
+    }
+}
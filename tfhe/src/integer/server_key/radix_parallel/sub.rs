@@ -229,6 +229,7 @@ impl ServerKey {
             self.unchecked_sub_assign(lhs, rhs);
             self.full_propagate_parallelized(lhs);
         }
+        debug_check!(check_carries_empty, "sub_assign_parallelized", lhs);
     }
 
     pub fn sub_parallelized_work_efficient<PBSOrder: PBSOrderMarker>(
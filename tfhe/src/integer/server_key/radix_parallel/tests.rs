@@ -1,4 +1,5 @@
 use crate::integer::keycache::KEY_CACHE;
+use crate::integer::server_key::FunctionEvaluationStrategy;
 use crate::integer::{RadixClientKey, ServerKey};
 use crate::shortint::parameters::*;
 use crate::shortint::PBSParameters;
@@ -96,10 +97,41 @@ create_parametrized_test!(integer_smart_block_mul);
 create_parametrized_test!(integer_default_block_mul);
 create_parametrized_test!(integer_smart_mul);
 create_parametrized_test!(integer_default_mul);
+create_parametrized_test!(integer_default_mul_add);
 create_parametrized_test!(integer_smart_scalar_sub);
 create_parametrized_test!(integer_default_scalar_sub);
 create_parametrized_test!(integer_smart_scalar_add);
 create_parametrized_test!(integer_default_scalar_add);
+create_parametrized_test!(integer_default_scalar_div_rem);
+create_parametrized_test!(integer_default_conditional_negate);
+create_parametrized_test!(integer_default_signed_ops {
+    PARAM_MESSAGE_1_CARRY_1,
+    PARAM_MESSAGE_2_CARRY_2
+});
+create_parametrized_test!(integer_default_div_rem {
+    PARAM_MESSAGE_1_CARRY_1,
+    PARAM_MESSAGE_2_CARRY_2
+});
+create_parametrized_test!(integer_default_overflowing_ops {
+    PARAM_MESSAGE_1_CARRY_1,
+    PARAM_MESSAGE_2_CARRY_2
+});
+create_parametrized_test!(integer_default_saturating_ops {
+    PARAM_MESSAGE_1_CARRY_1,
+    PARAM_MESSAGE_2_CARRY_2
+});
+create_parametrized_test!(integer_full_propagate_fused_and_unfused);
+create_parametrized_test!(integer_one_hot_conversions {
+    PARAM_MESSAGE_1_CARRY_1,
+    PARAM_MESSAGE_2_CARRY_2
+});
+create_parametrized_test!(integer_apply_bivariate_function {
+    PARAM_MESSAGE_1_CARRY_1,
+    PARAM_MESSAGE_2_CARRY_2
+});
+create_parametrized_test!(integer_apply_function_auto {
+    PARAM_MESSAGE_2_CARRY_2
+});
 
 fn integer_smart_add(param: PBSParameters) {
     let (cks, sks) = KEY_CACHE.get_from_params(param);
@@ -1799,6 +1831,43 @@ fn integer_default_mul(param: PBSParameters) {
     }
 }
 
+fn integer_default_mul_add(param: PBSParameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = param.message_modulus.0.pow(NB_CTXT as u32) as u64;
+
+    for _ in 0..NB_TEST_SMALLER {
+        let clear1 = rng.gen::<u64>() % modulus;
+        let clear2 = rng.gen::<u64>() % modulus;
+        let clear3 = rng.gen::<u64>() % modulus;
+
+        let ctxt_1 = cks.encrypt(clear1);
+        let ctxt_2 = cks.encrypt(clear2);
+        let ctxt_3 = cks.encrypt(clear3);
+
+        let res = sks.mul_add_parallelized(&ctxt_1, &ctxt_2, &ctxt_3);
+        assert!(res.block_carries_are_empty());
+        let dec: u64 = cks.decrypt(&res);
+        assert_eq!(dec, (clear1 * clear2 + clear3) % modulus);
+
+        // Operands with non empty carries
+        let ctxt_4 = sks.unchecked_add(&ctxt_1, &ctxt_3);
+        let ctxt_5 = sks.unchecked_add(&ctxt_2, &ctxt_1);
+        let clear4 = (clear1 + clear3) % modulus;
+        let clear5 = (clear2 + clear1) % modulus;
+
+        let mut res = ctxt_4.clone();
+        sks.mul_add_assign_parallelized(&mut res, &ctxt_2, &ctxt_5);
+        assert!(res.block_carries_are_empty());
+        let dec: u64 = cks.decrypt(&res);
+        assert_eq!(dec, (clear4 * clear2 + clear5) % modulus);
+    }
+}
+
 fn integer_smart_scalar_add(param: PBSParameters) {
     // generate the server-client key set
     let (cks, sks) = KEY_CACHE.get_from_params(param);
@@ -1970,3 +2039,435 @@ fn integer_default_scalar_sub(param: PBSParameters) {
         }
     }
 }
+
+fn integer_default_scalar_div_rem(param: PBSParameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = param.message_modulus.0.pow(NB_CTXT as u32) as u64;
+
+    for i in 0..NB_TEST_SMALLER {
+        let clear = rng.gen::<u64>() % modulus;
+        // Alternate between power of two and arbitrary divisors
+        let divisor = if i % 2 == 0 {
+            1 << (rng.gen::<u32>() % modulus.ilog2())
+        } else {
+            rng.gen_range(1..modulus)
+        };
+
+        let ctxt = cks.encrypt(clear);
+
+        let (q, r) = sks.scalar_div_rem_parallelized(&ctxt, divisor);
+        assert!(q.block_carries_are_empty());
+        assert!(r.block_carries_are_empty());
+
+        let dec_q: u64 = cks.decrypt(&q);
+        let dec_r: u64 = cks.decrypt(&r);
+        assert_eq!(dec_q, clear / divisor);
+        assert_eq!(dec_r, clear % divisor);
+
+        let q = sks.scalar_div_parallelized(&ctxt, divisor);
+        let dec_q: u64 = cks.decrypt(&q);
+        assert_eq!(dec_q, clear / divisor);
+
+        let r = sks.scalar_rem_parallelized(&ctxt, divisor);
+        let dec_r: u64 = cks.decrypt(&r);
+        assert_eq!(dec_r, clear % divisor);
+    }
+}
+
+fn integer_one_hot_conversions(param: PBSParameters) {
+    // One-hot ciphertexts have one block per value, keep the number of values small
+    let num_blocks = 2;
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+    let cks = RadixClientKey::from((cks, num_blocks));
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    let modulus = param.message_modulus.0.pow(num_blocks as u32) as u64;
+
+    for _ in 0..NB_TEST_SMALLER {
+        let clear1 = rng.gen::<u64>() % modulus;
+        let clear2 = rng.gen::<u64>() % modulus;
+
+        let ctxt = cks.encrypt(clear1);
+        let one_hot1 = sks.radix_to_one_hot_parallelized(&ctxt, modulus as usize);
+        assert_eq!(cks.as_ref().decrypt_one_hot(&one_hot1), clear1);
+
+        let ct_res = sks.one_hot_to_radix_parallelized(&one_hot1, num_blocks);
+        let dec: u64 = cks.decrypt(&ct_res);
+        assert_eq!(dec, clear1);
+
+        let one_hot2 = cks.as_ref().encrypt_one_hot(clear2, modulus as usize);
+        let res = sks.one_hot_eq_parallelized(&one_hot1, &one_hot2);
+        assert_eq!(cks.decrypt_bool(&res), clear1 == clear2);
+
+        let res = sks.one_hot_scalar_eq(&one_hot1, clear2);
+        assert_eq!(cks.decrypt_bool(&res), clear1 == clear2);
+
+        let values = [clear2, (clear2 + 1) % modulus];
+        let res = sks.one_hot_scalar_contains(&one_hot1, &values);
+        assert_eq!(cks.decrypt_bool(&res), values.contains(&clear1));
+    }
+}
+
+fn integer_default_signed_ops(param: PBSParameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    let num_bits = NB_CTXT as u32 * param.message_modulus.0.ilog2();
+    let modulus = 1i64 << num_bits;
+    // Wraps a value to the signed range of the ciphertexts, like the two's complement does
+    let wrap = |value: i64| {
+        let value = value.rem_euclid(modulus);
+        if value >= modulus / 2 {
+            value - modulus
+        } else {
+            value
+        }
+    };
+
+    for _ in 0..NB_TEST_SMALLER {
+        let clear_0 = wrap(rng.gen::<i64>());
+        let clear_1 = wrap(rng.gen::<i64>());
+
+        let ctxt_0 = cks.encrypt_signed(clear_0);
+        let ctxt_1 = cks.encrypt_signed(clear_1);
+
+        let ct_res = sks.signed_add_parallelized(&ctxt_0, &ctxt_1);
+        assert!(ct_res.block_carries_are_empty());
+        let dec: i64 = cks.decrypt_signed(&ct_res);
+        assert_eq!(dec, wrap(clear_0 + clear_1));
+
+        let ct_res = sks.signed_sub_parallelized(&ctxt_0, &ctxt_1);
+        let dec: i64 = cks.decrypt_signed(&ct_res);
+        assert_eq!(dec, wrap(clear_0 - clear_1));
+
+        let ct_res = sks.signed_mul_parallelized(&ctxt_0, &ctxt_1);
+        let dec: i64 = cks.decrypt_signed(&ct_res);
+        assert_eq!(dec, wrap(clear_0 * clear_1));
+
+        let ct_res = sks.signed_neg_parallelized(&ctxt_0);
+        let dec: i64 = cks.decrypt_signed(&ct_res);
+        assert_eq!(dec, wrap(-clear_0));
+
+        let ct_res = sks.signed_abs_parallelized(&ctxt_0);
+        let dec: i64 = cks.decrypt_signed(&ct_res);
+        assert_eq!(dec, wrap(clear_0.abs()));
+
+        let ct_res = sks.signed_signum_parallelized(&ctxt_0);
+        let dec: i64 = cks.decrypt_signed(&ct_res);
+        assert_eq!(dec, clear_0.signum());
+
+        let ct_res = sks.signed_is_negative_parallelized(&ctxt_0);
+        assert_eq!(cks.decrypt_bool(&ct_res), clear_0 < 0);
+
+        let ct_res = sks.signed_eq_parallelized(&ctxt_0, &ctxt_1);
+        assert_eq!(cks.decrypt_bool(&ct_res), clear_0 == clear_1);
+        let ct_res = sks.signed_gt_parallelized(&ctxt_0, &ctxt_1);
+        assert_eq!(cks.decrypt_bool(&ct_res), clear_0 > clear_1);
+        let ct_res = sks.signed_ge_parallelized(&ctxt_0, &ctxt_1);
+        assert_eq!(cks.decrypt_bool(&ct_res), clear_0 >= clear_1);
+        let ct_res = sks.signed_lt_parallelized(&ctxt_0, &ctxt_1);
+        assert_eq!(cks.decrypt_bool(&ct_res), clear_0 < clear_1);
+        let ct_res = sks.signed_le_parallelized(&ctxt_0, &ctxt_1);
+        assert_eq!(cks.decrypt_bool(&ct_res), clear_0 <= clear_1);
+
+        let ct_res = sks.signed_max_parallelized(&ctxt_0, &ctxt_1);
+        let dec: i64 = cks.decrypt_signed(&ct_res);
+        assert_eq!(dec, clear_0.max(clear_1));
+        let ct_res = sks.signed_min_parallelized(&ctxt_0, &ctxt_1);
+        let dec: i64 = cks.decrypt_signed(&ct_res);
+        assert_eq!(dec, clear_0.min(clear_1));
+    }
+
+    // The minimum value is its own opposite and absolute value
+    let min = cks.encrypt_signed(-modulus / 2);
+    let dec: i64 = cks.decrypt_signed(&sks.signed_neg_parallelized(&min));
+    assert_eq!(dec, -modulus / 2);
+    let dec: i64 = cks.decrypt_signed(&sks.signed_abs_parallelized(&min));
+    assert_eq!(dec, -modulus / 2);
+
+    let zero = cks.encrypt_signed(0i64);
+    let dec: i64 = cks.decrypt_signed(&sks.signed_signum_parallelized(&zero));
+    assert_eq!(dec, 0);
+}
+
+fn integer_default_div_rem(param: PBSParameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = param.message_modulus.0.pow(NB_CTXT as u32) as u64;
+
+    for _ in 0..NB_TEST_SMALLER {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = (rng.gen::<u64>() % (modulus - 1)) + 1;
+
+        let ctxt_0 = cks.encrypt(clear_0);
+        let ctxt_1 = cks.encrypt(clear_1);
+
+        let (q, r) = sks.div_rem_parallelized(&ctxt_0, &ctxt_1);
+        assert!(q.block_carries_are_empty());
+        assert!(r.block_carries_are_empty());
+        let dec_q: u64 = cks.decrypt(&q);
+        let dec_r: u64 = cks.decrypt(&r);
+        assert_eq!(dec_q, clear_0 / clear_1);
+        assert_eq!(dec_r, clear_0 % clear_1);
+
+        // Dividing by zero gives the maximum value and the numerator as the remainder
+        let zero = cks.encrypt(0u64);
+        let (q, r) = sks.div_rem_parallelized(&ctxt_0, &zero);
+        let dec_q: u64 = cks.decrypt(&q);
+        let dec_r: u64 = cks.decrypt(&r);
+        assert_eq!(dec_q, modulus - 1);
+        assert_eq!(dec_r, clear_0);
+    }
+}
+
+fn integer_default_overflowing_ops(param: PBSParameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = param.message_modulus.0.pow(NB_CTXT as u32) as u64;
+
+    for _ in 0..NB_TEST_SMALLER {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+
+        let ctxt_0 = cks.encrypt(clear_0);
+        let ctxt_1 = cks.encrypt(clear_1);
+
+        let (ct_res, overflowed) = sks.overflowing_add_parallelized(&ctxt_0, &ctxt_1);
+        assert!(ct_res.block_carries_are_empty());
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(dec_res, (clear_0 + clear_1) % modulus);
+        assert_eq!(cks.decrypt_bool(&overflowed), clear_0 + clear_1 >= modulus);
+
+        let (ct_res, overflowed) = sks.overflowing_sub_parallelized(&ctxt_0, &ctxt_1);
+        assert!(ct_res.block_carries_are_empty());
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(dec_res, clear_0.wrapping_sub(clear_1) % modulus);
+        assert_eq!(cks.decrypt_bool(&overflowed), clear_0 < clear_1);
+
+        let (ct_res, overflowed) = sks.overflowing_mul_parallelized(&ctxt_0, &ctxt_1);
+        assert!(ct_res.block_carries_are_empty());
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(dec_res, (clear_0 * clear_1) % modulus);
+        assert_eq!(cks.decrypt_bool(&overflowed), clear_0 * clear_1 >= modulus);
+    }
+}
+
+fn integer_default_saturating_ops(param: PBSParameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = param.message_modulus.0.pow(NB_CTXT as u32) as u64;
+
+    for _ in 0..NB_TEST_SMALLER {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+
+        let ctxt_0 = cks.encrypt(clear_0);
+        let ctxt_1 = cks.encrypt(clear_1);
+
+        let ct_res = sks.saturating_add_parallelized(&ctxt_0, &ctxt_1);
+        assert!(ct_res.block_carries_are_empty());
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(dec_res, (clear_0 + clear_1).min(modulus - 1));
+
+        let ct_res = sks.saturating_sub_parallelized(&ctxt_0, &ctxt_1);
+        assert!(ct_res.block_carries_are_empty());
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(dec_res, clear_0.saturating_sub(clear_1));
+
+        let ct_res = sks.saturating_mul_parallelized(&ctxt_0, &ctxt_1);
+        assert!(ct_res.block_carries_are_empty());
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(dec_res, (clear_0 * clear_1).min(modulus - 1));
+    }
+}
+
+fn integer_default_conditional_negate(param: PBSParameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = param.message_modulus.0.pow(NB_CTXT as u32) as u64;
+
+    for _ in 0..NB_TEST_SMALLER {
+        let clear = rng.gen::<u64>() % modulus;
+        let condition = rng.gen::<bool>();
+
+        let ctxt = cks.encrypt(clear);
+        let ct_condition = cks.encrypt_bool(condition);
+
+        let ct_res = sks.conditional_negate_parallelized(&ctxt, &ct_condition);
+        assert!(ct_res.block_carries_are_empty());
+        let dec: u64 = cks.decrypt(&ct_res);
+        let expected = if condition {
+            clear.wrapping_neg() % modulus
+        } else {
+            clear
+        };
+        assert_eq!(dec, expected);
+
+        let ct_res = sks.twos_complement_parallelized(&ctxt);
+        assert!(ct_res.block_carries_are_empty());
+        let dec: u64 = cks.decrypt(&ct_res);
+        assert_eq!(dec, clear.wrapping_neg() % modulus);
+    }
+}
+
+fn integer_full_propagate_fused_and_unfused(param: PBSParameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let mut fused_sks = sks.clone();
+    fused_sks.set_fused_carry_extraction(true);
+    assert!(!sks.fused_carry_extraction());
+    assert!(fused_sks.fused_carry_extraction());
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = param.message_modulus.0.pow(NB_CTXT as u32) as u64;
+
+    for _ in 0..NB_TEST_SMALLER {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+
+        let ctxt_0 = cks.encrypt(clear_0);
+        let ctxt_1 = cks.encrypt(clear_1);
+
+        let ct_sum = sks.unchecked_add(&ctxt_0, &ctxt_1);
+        let expected = (clear_0 + clear_1) % modulus;
+
+        for server_key in [&sks, &fused_sks] {
+            let mut ct_res = ct_sum.clone();
+            server_key.full_propagate_parallelized(&mut ct_res);
+            assert!(ct_res.block_carries_are_empty());
+            let dec: u64 = cks.decrypt(&ct_res);
+            assert_eq!(dec, expected);
+
+            let mut ct_res = ct_sum.clone();
+            server_key.full_propagate(&mut ct_res);
+            assert!(ct_res.block_carries_are_empty());
+            let dec: u64 = cks.decrypt(&ct_res);
+            assert_eq!(dec, expected);
+        }
+    }
+}
+
+fn integer_apply_bivariate_function(param: PBSParameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    let f = |x: u64, y: u64| x * x + 3 * y;
+
+    // One block goes through the packed bivariate PBS, two blocks through the one-hot
+    // decomposition which is only meant for small inputs
+    for num_blocks in [1, 2] {
+        let cks = RadixClientKey::from((cks.clone(), num_blocks));
+
+        let modulus = param.message_modulus.0.pow(num_blocks as u32) as u64;
+
+        for _ in 0..NB_TEST_SMALLER {
+            let clear1 = rng.gen::<u64>() % modulus;
+            let clear2 = rng.gen::<u64>() % modulus;
+
+            let ctxt1 = cks.encrypt(clear1);
+            let ctxt2 = cks.encrypt(clear2);
+
+            let ct_res = sks.apply_bivariate_function_parallelized(&ctxt1, &ctxt2, f);
+            assert!(ct_res.block_carries_are_empty());
+            let dec: u64 = cks.decrypt(&ct_res);
+            assert_eq!(dec, f(clear1, clear2) % modulus);
+        }
+    }
+}
+
+fn integer_apply_function_auto(param: PBSParameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    // Without a WoPBS key, the strategy depends only on the number of blocks
+    assert_eq!(
+        sks.univariate_function_strategy(1, None),
+        Some(FunctionEvaluationStrategy::Pbs)
+    );
+    assert_eq!(
+        sks.univariate_function_strategy(2, None),
+        Some(FunctionEvaluationStrategy::BivariatePacking)
+    );
+    assert_eq!(
+        sks.univariate_function_strategy(3, None),
+        Some(FunctionEvaluationStrategy::OneHotDecomposition)
+    );
+    assert_eq!(
+        sks.bivariate_function_strategy(1, None),
+        Some(FunctionEvaluationStrategy::BivariatePacking)
+    );
+    assert_eq!(
+        sks.bivariate_function_strategy(2, None),
+        Some(FunctionEvaluationStrategy::OneHotDecomposition)
+    );
+
+    let f = |x: u64| x * x + 1;
+    let g = |x: u64, y: u64| 2 * x + y * y;
+
+    for num_blocks in [1, 2, 3] {
+        let cks = RadixClientKey::from((cks.clone(), num_blocks));
+
+        let modulus = param.message_modulus.0.pow(num_blocks as u32) as u64;
+
+        for _ in 0..NB_TEST_SMALLER {
+            let clear1 = rng.gen::<u64>() % modulus;
+            let clear2 = rng.gen::<u64>() % modulus;
+
+            let ctxt1 = cks.encrypt(clear1);
+            let ctxt2 = cks.encrypt(clear2);
+
+            let ct_res = sks.apply_univariate_function_auto_parallelized(None, &ctxt1, f);
+            assert!(ct_res.block_carries_are_empty());
+            let dec: u64 = cks.decrypt(&ct_res);
+            assert_eq!(dec, f(clear1) % modulus);
+
+            // The one-hot decomposition of two inputs of 3 blocks is too expensive for a test
+            if num_blocks < 3 {
+                let ct_res =
+                    sks.apply_bivariate_function_auto_parallelized(None, &ctxt1, &ctxt2, g);
+                assert!(ct_res.block_carries_are_empty());
+                let dec: u64 = cks.decrypt(&ct_res);
+                assert_eq!(dec, g(clear1, clear2) % modulus);
+            }
+        }
+    }
+}
@@ -0,0 +1,313 @@
+//! Encrypted strings.
+//!
+//! An [`EncryptedString`] stores one radix ciphertext per Unicode scalar value (i.e. per `char`),
+//! so that operations never split a multi-byte UTF-8 sequence. Strings are padded with encrypted
+//! null characters up to a public capacity: the length of the string is only known to the owner
+//! of the client key.
+//!
+//! Operations taking an encrypted position select characters with one-hot encodings of the
+//! position, every character of the string takes part in the selection so the position is not
+//! leaked.
+//!
+//! # Example
+//!
+//! ```rust
+//! use tfhe::integer::gen_keys_radix;
+//! use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+//!
+//! let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2, 2);
+//!
+//! let s = cks.as_ref().encrypt_str("héllo", 6);
+//!
+//! let len = sks.str_len(&s);
+//! let len: u64 = cks.decrypt(&len);
+//! assert_eq!(len, 5);
+//!
+//! let index = cks.encrypt(1u64);
+//! let c = sks.str_char_at(&s, &index);
+//! assert_eq!(cks.as_ref().decrypt_char(&c), Some('é'));
+//!
+//! let sub = sks.str_substring(&s, &index, 3);
+//! assert_eq!(cks.as_ref().decrypt_str(&sub), "éll");
+//! ```
+
+use crate::integer::ciphertext::{BaseRadixCiphertext, OneHotCiphertext, RadixCiphertext};
+use crate::integer::{ClientKey, ServerKey};
+use crate::shortint::ciphertext::{BootstrapKeyswitch, KeyswitchBootstrap};
+use crate::shortint::{CiphertextBase, PBSOrderMarker};
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Number of bits needed to represent any Unicode scalar value
+const CHAR_BITS: u32 = 21;
+
+/// Structure containing an encrypted string padded with null characters.
+///
+/// Each character is a radix ciphertext encrypting its Unicode scalar value, the null
+/// characters are all at the end of the string.
+#[derive(Serialize, Clone, Deserialize)]
+pub struct BaseEncryptedString<Block> {
+    pub(crate) chars: Vec<BaseRadixCiphertext<Block>>,
+}
+
+pub type EncryptedString<PBSOrder> = BaseEncryptedString<CiphertextBase<PBSOrder>>;
+pub type EncryptedStringBig = EncryptedString<KeyswitchBootstrap>;
+pub type EncryptedStringSmall = EncryptedString<BootstrapKeyswitch>;
+
+impl<Block> BaseEncryptedString<Block> {
+    /// Returns the maximum number of characters of the string
+    pub fn capacity(&self) -> usize {
+        self.chars.len()
+    }
+}
+
+fn num_blocks_per_char(message_modulus: usize) -> usize {
+    let bits_per_block = message_modulus.ilog2();
+    ((CHAR_BITS + bits_per_block - 1) / bits_per_block) as usize
+}
+
+impl ClientKey {
+    /// Encrypts a string, padded with null characters up to `capacity` characters.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the string has more than `capacity` characters or contains a null character.
+    pub fn encrypt_str(&self, s: &str, capacity: usize) -> EncryptedStringBig {
+        assert!(
+            !s.contains('\0'),
+            "Encrypted strings cannot contain null characters"
+        );
+        let num_chars = s.chars().count();
+        assert!(
+            num_chars <= capacity,
+            "The string has {num_chars} characters, more than the capacity of {capacity}"
+        );
+
+        let num_blocks = num_blocks_per_char(self.parameters().message_modulus.0);
+        let chars = s
+            .chars()
+            .map(u64::from)
+            .chain(std::iter::repeat(0).take(capacity - num_chars))
+            .map(|c| self.encrypt_radix(c, num_blocks))
+            .collect();
+
+        EncryptedString { chars }
+    }
+
+    /// Decrypts a string, the padding null characters are removed.
+    pub fn decrypt_str<PBSOrder: PBSOrderMarker>(&self, s: &EncryptedString<PBSOrder>) -> String {
+        s.chars.iter().map_while(|c| self.decrypt_char(c)).collect()
+    }
+
+    /// Decrypts a character, returns `None` for the null character.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the ciphertext does not encrypt a Unicode scalar value.
+    pub fn decrypt_char<PBSOrder: PBSOrderMarker>(
+        &self,
+        c: &RadixCiphertext<PBSOrder>,
+    ) -> Option<char> {
+        let value: u64 = self.decrypt_radix(c);
+        let c = u32::try_from(value)
+            .ok()
+            .and_then(char::from_u32)
+            .expect("The ciphertext does not encrypt a valid character");
+        (c != '\0').then_some(c)
+    }
+}
+
+impl ServerKey {
+    /// Returns the number of characters of the string, i.e. the number of non-null characters.
+    ///
+    /// The result has enough blocks to encrypt the capacity of the string.
+    pub fn str_len<PBSOrder: PBSOrderMarker>(
+        &self,
+        s: &EncryptedString<PBSOrder>,
+    ) -> RadixCiphertext<PBSOrder> {
+        let acc_is_zero = self.key.generate_accumulator(|x| u64::from(x == 0));
+        let is_null = s
+            .chars
+            .par_iter()
+            .map(|c| {
+                let blocks_are_zero = c
+                    .blocks
+                    .iter()
+                    .map(|block| self.key.apply_lookup_table(block, &acc_is_zero))
+                    .collect();
+                self.all_blocks_are_one(blocks_are_zero)
+            })
+            .collect::<Vec<_>>();
+
+        // The string has length k if the character k is the first null one, the position past
+        // the end is considered null
+        let acc_is_end = self
+            .key
+            .generate_accumulator_bivariate(|prev_is_null, is_null| {
+                u64::from(prev_is_null == 0 && is_null == 1)
+            });
+        let ends_at = (0..=s.capacity())
+            .into_par_iter()
+            .map(|position| {
+                let prev_is_null = match position {
+                    0 => self.key.create_trivial(0),
+                    _ => is_null[position - 1].clone(),
+                };
+                let is_null = is_null
+                    .get(position)
+                    .map_or_else(|| self.key.create_trivial(1), Clone::clone);
+                self.key.unchecked_apply_lookup_table_bivariate(
+                    &prev_is_null,
+                    &is_null,
+                    &acc_is_end,
+                )
+            })
+            .collect::<Vec<_>>();
+
+        let message_modulus = self.key.message_modulus.0;
+        let mut num_blocks = 1;
+        while message_modulus.pow(num_blocks as u32) <= s.capacity() {
+            num_blocks += 1;
+        }
+        self.one_hot_to_radix_parallelized(&OneHotCiphertext { blocks: ends_at }, num_blocks)
+    }
+
+    /// Returns the character at the encrypted position `index`, or an encrypted null character
+    /// if `index` is past the end of the string.
+    pub fn str_char_at<PBSOrder: PBSOrderMarker>(
+        &self,
+        s: &EncryptedString<PBSOrder>,
+        index: &RadixCiphertext<PBSOrder>,
+    ) -> RadixCiphertext<PBSOrder> {
+        // Every position is past the end of an empty string
+        if s.capacity() == 0 {
+            return self.create_trivial_zero_radix(num_blocks_per_char(self.key.message_modulus.0));
+        }
+
+        let index = self.radix_to_one_hot_parallelized(index, s.capacity());
+        self.one_hot_select(&index, &s.chars)
+    }
+
+    /// Returns the `len` characters starting at the encrypted position `start`.
+    ///
+    /// The result has a capacity of `len`, it is padded with null characters if the string
+    /// ends before `start + len`.
+    pub fn str_substring<PBSOrder: PBSOrderMarker>(
+        &self,
+        s: &EncryptedString<PBSOrder>,
+        start: &RadixCiphertext<PBSOrder>,
+        len: usize,
+    ) -> EncryptedString<PBSOrder> {
+        let start = self.radix_to_one_hot_parallelized(start, s.capacity());
+        let num_blocks = num_blocks_per_char(self.key.message_modulus.0);
+
+        // The character j of the substring is the character start + j of the string: selecting
+        // it with the one-hot encoding of start amounts to shifting the characters by j
+        let chars = (0..len)
+            .into_par_iter()
+            .map(|offset| match s.chars.get(offset..) {
+                Some(chars) if !chars.is_empty() => self.one_hot_select(&start, chars),
+                _ => self.create_trivial_zero_radix(num_blocks),
+            })
+            .collect();
+
+        EncryptedString { chars }
+    }
+
+    /// Returns the sum of the radix ciphertexts multiplied by the block of `selector` at the
+    /// same position, i.e. the ciphertext selected by the one-hot encoding.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chars` is empty, the number of blocks of the result being unknown.
+    pub(crate) fn one_hot_select<PBSOrder: PBSOrderMarker>(
+        &self,
+        selector: &OneHotCiphertext<PBSOrder>,
+        chars: &[RadixCiphertext<PBSOrder>],
+    ) -> RadixCiphertext<PBSOrder> {
+        assert!(
+            !chars.is_empty(),
+            "Cannot select a ciphertext among an empty list"
+        );
+
+        // Keeps the right input when the left one is 1
+        let select_lut =
+            self.key
+                .generate_accumulator_bivariate(|condition, x| if condition == 1 { x } else { 0 });
+
+        let num_blocks = chars[0].blocks.len();
+        let blocks = (0..num_blocks)
+            .into_par_iter()
+            .map(|block_index| {
+                let terms = selector
+                    .blocks
+                    .par_iter()
+                    .zip(chars.par_iter())
+                    .map(|(is_selected, c)| {
+                        self.key.unchecked_apply_lookup_table_bivariate(
+                            is_selected,
+                            &c.blocks[block_index],
+                            &select_lut,
+                        )
+                    })
+                    .collect::<Vec<_>>();
+
+                let mut block = self.sum_exclusive_blocks(terms);
+                if block.degree.0 >= self.key.message_modulus.0 {
+                    self.key.message_extract_assign(&mut block);
+                }
+                block
+            })
+            .collect::<Vec<_>>();
+
+        RadixCiphertext::from(blocks)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::integer::keycache::KEY_CACHE;
+    use crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+
+    #[test]
+    fn test_str_len_and_char_at() {
+        let (cks, sks) = KEY_CACHE.get_from_params(PARAM_MESSAGE_2_CARRY_2);
+
+        for clear in ["", "a", "ℝ𝔽", "abc"] {
+            let s = cks.encrypt_str(clear, 3);
+            assert_eq!(cks.decrypt_str(&s), clear);
+
+            let len: u64 = cks.decrypt_radix(&sks.str_len(&s));
+            assert_eq!(len, clear.chars().count() as u64);
+
+            for index in 0..4u64 {
+                let c = sks.str_char_at(&s, &cks.encrypt_radix(index, 2));
+                assert_eq!(cks.decrypt_char(&c), clear.chars().nth(index as usize));
+            }
+        }
+
+        let empty = cks.encrypt_str("", 0);
+        assert_eq!(cks.decrypt_str(&empty), "");
+        let c = sks.str_char_at(&empty, &cks.encrypt_radix(0u64, 2));
+        assert_eq!(cks.decrypt_char(&c), None);
+    }
+
+    #[test]
+    fn test_str_substring() {
+        let (cks, sks) = KEY_CACHE.get_from_params(PARAM_MESSAGE_2_CARRY_2);
+
+        let clear = "añb";
+        let s = cks.encrypt_str(clear, 4);
+        for start in 0..4u64 {
+            let sub = sks.str_substring(&s, &cks.encrypt_radix(start, 2), 2);
+            assert_eq!(sub.capacity(), 2);
+
+            let expected = clear
+                .chars()
+                .skip(start as usize)
+                .take(2)
+                .collect::<String>();
+            assert_eq!(cks.decrypt_str(&sub), expected);
+        }
+    }
+}
@@ -11,7 +11,7 @@ pub use crate::core_crypto::commons::parameters::{CiphertextCount, PlaintextCoun
 use crate::core_crypto::prelude::*;
 use crate::integer::client_key::utils::i_crt;
 use crate::integer::{ClientKey, CrtCiphertext, IntegerCiphertext, ServerKey};
-use crate::shortint::ciphertext::Degree;
+use crate::shortint::ciphertext::{Degree, NoiseLevel};
 use crate::shortint::wopbs::WopbsLUTBase;
 use crate::shortint::{PBSOrderMarker, WopbsParameters};
 use rayon::prelude::*;
@@ -309,8 +309,10 @@ impl WopbsKey {
             ct_vec_out.push(crate::shortint::CiphertextBase {
                 ct: block_out,
                 degree: Degree(block.message_modulus.0 - 1),
+                noise_level: NoiseLevel::NOMINAL,
                 message_modulus: block.message_modulus,
                 carry_modulus: block.carry_modulus,
+                parameter_version: block.parameter_version,
                 _order_marker: Default::default(),
             });
         }
@@ -395,8 +397,10 @@ impl WopbsKey {
             ct_vec_out.push(crate::shortint::CiphertextBase {
                 ct: block_out,
                 degree: Degree(block.message_modulus.0 - 1),
+                noise_level: NoiseLevel::NOMINAL,
                 message_modulus: block.message_modulus,
                 carry_modulus: block.carry_modulus,
+                parameter_version: block.parameter_version,
                 _order_marker: Default::default(),
             });
         }
@@ -1106,14 +1110,21 @@ impl WopbsKey {
             ct_vec_out.push(crate::shortint::CiphertextBase {
                 ct: block_out,
                 degree: Degree(block.message_modulus.0 - 1),
+                noise_level: NoiseLevel::NOMINAL,
                 message_modulus: block.message_modulus,
                 carry_modulus: block.carry_modulus,
+                parameter_version: block.parameter_version,
                 _order_marker: Default::default(),
             });
         }
         T::from_blocks(ct_vec_out)
     }
 
+    /// Returns the parameters the WoPBS is computed with.
+    pub fn parameters(&self) -> WopbsParameters {
+        self.wopbs_key.param
+    }
+
     pub fn keyswitch_to_wopbs_params<'a, T>(&self, sks: &ServerKey, ct_in: &'a T) -> T
     where
         T: IntegerCiphertext,
@@ -39,6 +39,7 @@ create_parametrized_test!(wopbs_crt);
 create_parametrized_test!(wopbs_bivariate_radix);
 create_parametrized_test!(wopbs_bivariate_crt);
 create_parametrized_test!(wopbs_radix);
+create_parametrized_test!(wopbs_apply_bivariate_function);
 
 fn make_basis(message_modulus: usize) -> Vec<u64> {
     match message_modulus {
@@ -94,8 +95,8 @@ pub fn wopbs_native_crt_bivariate() {
         lwe_dimension: wopbs_params.lwe_dimension,
         glwe_dimension: wopbs_params.glwe_dimension,
         polynomial_size: wopbs_params.polynomial_size,
-        lwe_modular_std_dev: wopbs_params.lwe_modular_std_dev,
-        glwe_modular_std_dev: wopbs_params.glwe_modular_std_dev,
+        lwe_noise_distribution: DynamicDistribution::Gaussian(wopbs_params.lwe_modular_std_dev),
+        glwe_noise_distribution: DynamicDistribution::Gaussian(wopbs_params.glwe_modular_std_dev),
         pbs_base_log: wopbs_params.pbs_base_log,
         pbs_level: wopbs_params.pbs_level,
         ks_base_log: wopbs_params.ks_base_log,
@@ -104,6 +105,7 @@ pub fn wopbs_native_crt_bivariate() {
         carry_modulus: wopbs_params.carry_modulus,
         ciphertext_modulus: wopbs_params.ciphertext_modulus,
         encryption_key_choice: wopbs_params.encryption_key_choice,
+        secret_key_distribution: SecretKeyDistribution::Binary,
     };
 
     let params = (pbs_params, wopbs_params);
@@ -259,6 +261,34 @@ pub fn wopbs_bivariate_radix(params: (PBSParameters, WopbsParameters)) {
     }
 }
 
+pub fn wopbs_apply_bivariate_function(params: (PBSParameters, WopbsParameters)) {
+    let mut rng = rand::thread_rng();
+
+    let nb_block = 2;
+
+    // The WoPBS key of the cache is generated from the client key of the cache
+    let (cks, sks) = KEY_CACHE.get_from_params(params.0);
+    let wopbs_key = KEY_CACHE_WOPBS.get_from_params(params);
+
+    let msg_space = (params.0.message_modulus.0 as u64).pow(nb_block as u32);
+
+    for _ in 0..NB_TEST {
+        let clear1 = rng.gen::<u64>() % msg_space;
+        let clear2 = rng.gen::<u64>() % msg_space;
+
+        let ct1 = cks.encrypt_radix(clear1, nb_block);
+        let ct2 = cks.encrypt_radix(clear2, nb_block);
+
+        let ct_res =
+            sks.apply_bivariate_function_with_wopbs_parallelized(&wopbs_key, &ct1, &ct2, |x, y| {
+                x * y + x
+            });
+
+        let res: u64 = cks.decrypt_radix(&ct_res);
+        assert_eq!(res, (clear1 * clear2 + clear1) % msg_space);
+    }
+}
+
 // test wopbs bivariate fake crt with different degree for each Ct
 pub fn wopbs_bivariate_crt(params: (PBSParameters, WopbsParameters)) {
     let mut rng = rand::thread_rng();
@@ -171,8 +171,12 @@ impl Shortint {
             lwe_dimension: LweDimension(lwe_dimension),
             glwe_dimension: GlweDimension(glwe_dimension),
             polynomial_size: PolynomialSize(polynomial_size),
-            lwe_modular_std_dev: StandardDev(lwe_modular_std_dev),
-            glwe_modular_std_dev: StandardDev(glwe_modular_std_dev),
+            lwe_noise_distribution: DynamicDistribution::new_gaussian(StandardDev(
+                lwe_modular_std_dev,
+            )),
+            glwe_noise_distribution: DynamicDistribution::new_gaussian(StandardDev(
+                glwe_modular_std_dev,
+            )),
             pbs_base_log: DecompositionBaseLog(pbs_base_log),
             pbs_level: DecompositionLevelCount(pbs_level),
             ks_base_log: DecompositionBaseLog(ks_base_log),
@@ -184,6 +188,7 @@ impl Shortint {
             )
             .unwrap(),
             encryption_key_choice: encryption_key_choice.into(),
+            secret_key_distribution: SecretKeyDistribution::Binary,
         })
     }
 
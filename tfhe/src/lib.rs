@@ -8,6 +8,21 @@
 #![cfg_attr(all(doc, not(doctest)), feature(doc_cfg))]
 #![deny(rustdoc::broken_intra_doc_links)]
 
+// Lets the code generated by the derive macros, which refers to `::tfhe`, compile in the tests of
+// the crate
+#[cfg(all(test, feature = "derive"))]
+extern crate self as tfhe;
+
+#[cfg(any(
+    feature = "applications-counter",
+    feature = "applications-geo",
+    feature = "applications-stats",
+    feature = "applications-structured",
+    feature = "applications-window"
+))]
+/// cbindgen:ignore
+pub mod applications;
+
 #[cfg(feature = "__c_api")]
 pub mod c_api;
 
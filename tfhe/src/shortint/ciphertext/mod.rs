@@ -1,15 +1,67 @@
 //! Module with the definition of the Ciphertext.
 use crate::core_crypto::entities::*;
-use crate::shortint::parameters::{CarryModulus, MessageModulus};
+use crate::shortint::parameters::{CarryModulus, MessageModulus, ParameterVersion};
 use serde::{Deserialize, Serialize};
 use std::cmp;
-use std::fmt::Debug;
+use std::fmt::{Debug, Display, Formatter};
+use std::hash::{Hash, Hasher};
 use std::marker::PhantomData;
 
 /// This tracks the number of operations that has been done.
 #[derive(Debug, PartialEq, Eq, Copy, Clone, Serialize, Deserialize)]
 pub struct Degree(pub usize);
 
+/// This tracks the noise of a ciphertext, as a multiple of the noise of a fresh encryption.
+///
+/// The output of a PBS has the nominal noise level of a fresh encryption and a trivial
+/// encryption has no noise. Adding two ciphertexts adds their noise levels and multiplying a
+/// ciphertext by a scalar multiplies its noise level by the scalar. The degree alone does not
+/// account for noise: e.g. subtracting a ciphertext from itself gives a ciphertext of degree 0
+/// whose noise is twice the one of the operand.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Copy, Clone, Serialize, Deserialize)]
+pub struct NoiseLevel(pub usize);
+
+impl NoiseLevel {
+    /// The noise level of a fresh encryption and of the output of a PBS.
+    pub const NOMINAL: Self = Self(1);
+    /// The noise level of a trivial encryption.
+    pub const ZERO: Self = Self(0);
+}
+
+impl Default for NoiseLevel {
+    fn default() -> Self {
+        Self::NOMINAL
+    }
+}
+
+impl std::ops::Add for NoiseLevel {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self(self.0.saturating_add(rhs.0))
+    }
+}
+
+impl std::ops::AddAssign for NoiseLevel {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl std::ops::Mul<usize> for NoiseLevel {
+    type Output = Self;
+
+    fn mul(self, rhs: usize) -> Self::Output {
+        Self(self.0.saturating_mul(rhs))
+    }
+}
+
+impl std::ops::MulAssign<usize> for NoiseLevel {
+    fn mul_assign(&mut self, rhs: usize) {
+        *self = *self * rhs;
+    }
+}
+
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
 pub enum PBSOrder {
     /// Ciphertext is encrypted using the big LWE secret key corresponding to the GLWE secret key.
@@ -44,7 +96,7 @@ impl PBSOrderMarker for KeyswitchBootstrap {
     }
 }
 
-#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
 pub struct BootstrapKeyswitch;
 
 impl PBSOrderMarker for BootstrapKeyswitch {
@@ -100,7 +152,6 @@ impl Degree {
         Degree(result)
     }
 
-    #[allow(dead_code)]
     pub(crate) fn after_pbs<F>(&self, f: F) -> Degree
     where
         F: Fn(usize) -> usize,
@@ -123,25 +174,155 @@ impl Degree {
 pub struct CiphertextBase<OpOrder: PBSOrderMarker> {
     pub ct: LweCiphertextOwned<u64>,
     pub degree: Degree,
+    pub noise_level: NoiseLevel,
     pub message_modulus: MessageModulus,
     pub carry_modulus: CarryModulus,
+    /// Version of the built-in parameter set the ciphertext was encrypted with, `None` for
+    /// custom parameters and trivial encryptions, see
+    /// [`crate::shortint::parameters::versioning`]
+    pub parameter_version: Option<ParameterVersion>,
     pub _order_marker: PhantomData<OpOrder>,
 }
 
 pub type CiphertextBig = CiphertextBase<KeyswitchBootstrap>;
 pub type CiphertextSmall = CiphertextBase<BootstrapKeyswitch>;
 
+/// Error returned when decrypting a ciphertext which is not a trivial encryption without a key.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct NotTrivialCiphertextError;
+
+impl Display for NotTrivialCiphertextError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "The ciphertext is not a trivial encryption")
+    }
+}
+
+impl std::error::Error for NotTrivialCiphertextError {}
+
 impl<OpOrder: PBSOrderMarker> CiphertextBase<OpOrder> {
     pub fn carry_is_empty(&self) -> bool {
         self.degree.0 < self.message_modulus.0
     }
 
+    /// Returns true if the ciphertext is a trivial encryption, i.e. its mask is zero.
+    ///
+    /// A trivial ciphertext stays trivial through the operations which do not involve a
+    /// non-trivial ciphertext, e.g. the scalar operations, and the PBS of a trivial ciphertext is
+    /// trivial as well.
+    pub fn is_trivial(&self) -> bool {
+        self.ct.get_mask().as_ref().iter().all(|&x| x == 0)
+    }
+
+    /// Decrypts a trivial ciphertext, without the client key, returning its message and carry.
+    pub fn decrypt_trivial_message_and_carry(&self) -> Result<u64, NotTrivialCiphertextError> {
+        if !self.is_trivial() {
+            return Err(NotTrivialCiphertextError);
+        }
+
+        let scaling = self.ct.ciphertext_modulus().get_scaling_to_native_torus();
+        let body = self.ct.get_body().data.wrapping_mul(scaling);
+
+        let delta = (1_u64 << 63) / (self.message_modulus.0 * self.carry_modulus.0) as u64;
+        let rounding = (body & (delta >> 1)) << 1;
+        Ok(body.wrapping_add(rounding) / delta)
+    }
+
+    /// Decrypts a trivial ciphertext, without the client key, returning its message.
+    ///
+    /// This is meant to inspect public values, e.g. the intermediate results of a computation in
+    /// tests: the result of an operation involving a non-trivial ciphertext is not trivial.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::ciphertext::NotTrivialCiphertextError;
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    /// use tfhe::shortint::CiphertextBig;
+    ///
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let ct: CiphertextBig = sks.create_trivial(2);
+    /// let ct = sks.scalar_mul(&ct, 3);
+    /// assert_eq!(ct.decrypt_trivial(), Ok(2));
+    ///
+    /// let ct = sks.add(&ct, &cks.encrypt(1));
+    /// assert!(!ct.is_trivial());
+    /// assert_eq!(ct.decrypt_trivial(), Err(NotTrivialCiphertextError));
+    /// ```
+    pub fn decrypt_trivial(&self) -> Result<u64, NotTrivialCiphertextError> {
+        self.decrypt_trivial_message_and_carry()
+            .map(|message_and_carry| message_and_carry % self.message_modulus.0 as u64)
+    }
+
     pub fn copy_from(&mut self, other: &Self) {
         self.ct.as_mut().copy_from_slice(other.ct.as_ref());
+        self.noise_level = other.noise_level;
         self.message_modulus = other.message_modulus;
         self.carry_modulus = other.carry_modulus;
+        self.parameter_version = other.parameter_version;
         self._order_marker = other._order_marker;
     }
+
+    /// Returns a canonical byte encoding of the ciphertext.
+    ///
+    /// Contrary to the serde serialization, this encoding does not depend on the serializer
+    /// being used: it is the concatenation, in little endian, of the PBS order (1 byte), the
+    /// message modulus, carry modulus, degree, noise level (8 bytes each), the ciphertext modulus
+    /// (16 bytes, 0 meaning the native modulus), the parameter version (1 byte set to 1 followed
+    /// by the major and minor versions on 2 bytes each, or a single 0 byte if there is none), the
+    /// LWE size (8 bytes) and the LWE coefficients (8 bytes each).
+    ///
+    /// Two ciphertexts are equal if and only if their canonical encodings are equal,
+    /// this encoding is what [`Hash`] and [`Ord`] are computed on.
+    pub fn canonical_bytes(&self) -> Vec<u8> {
+        let coefficients = self.ct.as_ref();
+        let ciphertext_modulus = self.ct.ciphertext_modulus();
+        let ciphertext_modulus = if ciphertext_modulus.is_native_modulus() {
+            0u128
+        } else {
+            ciphertext_modulus.get_custom_modulus()
+        };
+
+        let mut bytes = Vec::with_capacity(1 + 8 * 4 + 16 + 5 + 8 + 8 * coefficients.len());
+        bytes.push(OpOrder::pbs_order() as u8);
+        bytes.extend_from_slice(&(self.message_modulus.0 as u64).to_le_bytes());
+        bytes.extend_from_slice(&(self.carry_modulus.0 as u64).to_le_bytes());
+        bytes.extend_from_slice(&(self.degree.0 as u64).to_le_bytes());
+        bytes.extend_from_slice(&(self.noise_level.0 as u64).to_le_bytes());
+        bytes.extend_from_slice(&ciphertext_modulus.to_le_bytes());
+        match self.parameter_version {
+            Some(version) => {
+                bytes.push(1);
+                bytes.extend_from_slice(&version.major.to_le_bytes());
+                bytes.extend_from_slice(&version.minor.to_le_bytes());
+            }
+            None => bytes.push(0),
+        }
+        bytes.extend_from_slice(&(coefficients.len() as u64).to_le_bytes());
+        for coefficient in coefficients {
+            bytes.extend_from_slice(&coefficient.to_le_bytes());
+        }
+        bytes
+    }
+}
+
+impl<OpOrder: PBSOrderMarker> Hash for CiphertextBase<OpOrder> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write(&self.canonical_bytes());
+    }
+}
+
+impl<OpOrder: PBSOrderMarker + Eq> PartialOrd for CiphertextBase<OpOrder> {
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<OpOrder: PBSOrderMarker + Eq> Ord for CiphertextBase<OpOrder> {
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        self.canonical_bytes().cmp(&other.canonical_bytes())
+    }
 }
 
 #[derive(Serialize, Deserialize)]
@@ -151,6 +332,12 @@ struct SerialiazableCiphertextBase {
     pub message_modulus: MessageModulus,
     pub carry_modulus: CarryModulus,
     pub op_order: PBSOrder,
+    #[serde(default)]
+    pub parameter_version: Option<ParameterVersion>,
+    // Ciphertexts serialized before the noise level was tracked are assumed to have the
+    // nominal noise level
+    #[serde(default)]
+    pub noise_level: NoiseLevel,
 }
 
 // Manual impl to be able to carry the OpOrder information
@@ -165,6 +352,8 @@ impl<OpOrder: PBSOrderMarker> Serialize for CiphertextBase<OpOrder> {
             message_modulus: self.message_modulus,
             carry_modulus: self.carry_modulus,
             op_order: OpOrder::pbs_order(),
+            parameter_version: self.parameter_version,
+            noise_level: self.noise_level,
         }
         .serialize(serializer)
     }
@@ -191,8 +380,10 @@ impl<'de, OpOrder: PBSOrderMarker> Deserialize<'de> for CiphertextBase<OpOrder>
         Ok(CiphertextBase {
             ct: intermediate.ct,
             degree: intermediate.degree,
+            noise_level: intermediate.noise_level,
             message_modulus: intermediate.message_modulus,
             carry_modulus: intermediate.carry_modulus,
+            parameter_version: intermediate.parameter_version,
             _order_marker: Default::default(),
         })
     }
@@ -207,6 +398,7 @@ pub struct CompressedCiphertextBase<OpOrder: PBSOrderMarker> {
     pub degree: Degree,
     pub message_modulus: MessageModulus,
     pub carry_modulus: CarryModulus,
+    pub parameter_version: Option<ParameterVersion>,
     pub _order_marker: PhantomData<OpOrder>,
 }
 
@@ -220,6 +412,8 @@ struct SerialiazableCompressedCiphertextBase {
     pub message_modulus: MessageModulus,
     pub carry_modulus: CarryModulus,
     pub op_order: PBSOrder,
+    #[serde(default)]
+    pub parameter_version: Option<ParameterVersion>,
 }
 
 // Manual impl to be able to carry the OpOrder information
@@ -234,6 +428,7 @@ impl<OpOrder: PBSOrderMarker> Serialize for CompressedCiphertextBase<OpOrder> {
             message_modulus: self.message_modulus,
             carry_modulus: self.carry_modulus,
             op_order: OpOrder::pbs_order(),
+            parameter_version: self.parameter_version,
         }
         .serialize(serializer)
     }
@@ -262,6 +457,7 @@ impl<'de, OpOrder: PBSOrderMarker> Deserialize<'de> for CompressedCiphertextBase
             degree: intermediate.degree,
             message_modulus: intermediate.message_modulus,
             carry_modulus: intermediate.carry_modulus,
+            parameter_version: intermediate.parameter_version,
             _order_marker: Default::default(),
         })
     }
@@ -274,14 +470,17 @@ impl<OpOrder: PBSOrderMarker> CompressedCiphertextBase<OpOrder> {
             degree,
             message_modulus,
             carry_modulus,
+            parameter_version,
             _order_marker,
         } = self;
 
         CiphertextBase {
             ct: ct.decompress_into_lwe_ciphertext(),
             degree,
+            noise_level: NoiseLevel::NOMINAL,
             message_modulus,
             carry_modulus,
+            parameter_version,
             _order_marker,
         }
     }
@@ -293,10 +492,50 @@ impl<OpOrder: PBSOrderMarker> From<CompressedCiphertextBase<OpOrder>> for Cipher
     }
 }
 
+/// A ciphertext encrypted without the padding bit.
+///
+/// Without the padding bit, the whole `message_modulus * carry_modulus` space
+/// is available to store the message, and linear operations wrap around modulo this space.
+///
+/// However, the regular programmable bootstrapping requires the padding bit
+/// to evaluate arbitrary functions, so only the operations that are valid without it
+/// are available on this type:
+///
+/// - the linear operations (addition, subtraction, negation, scalar multiplication), which are
+///   computed modulo `message_modulus * carry_modulus`
+/// - the PBS of antisymmetric functions, see
+///   [`crate::shortint::ServerKey::generate_lookup_table_odd`]
+/// - the PBS of arbitrary functions at the cost of a second PBS, see
+///   [`crate::shortint::ServerKey::generate_lookup_table_full_domain`]
+/// - the WoPBS, see [`crate::shortint::wopbs::WopbsKey::programmable_bootstrapping_no_padding`]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[must_use]
+pub struct CiphertextNoPaddingBase<OpOrder: PBSOrderMarker>(pub(crate) CiphertextBase<OpOrder>);
+
+pub type CiphertextNoPaddingBig = CiphertextNoPaddingBase<KeyswitchBootstrap>;
+pub type CiphertextNoPaddingSmall = CiphertextNoPaddingBase<BootstrapKeyswitch>;
+
+impl<OpOrder: PBSOrderMarker> CiphertextNoPaddingBase<OpOrder> {
+    /// Returns the modulus in which the message is stored
+    pub fn modulus(&self) -> usize {
+        self.0.message_modulus.0 * self.0.carry_modulus.0
+    }
+
+    pub fn as_inner(&self) -> &CiphertextBase<OpOrder> {
+        &self.0
+    }
+
+    pub fn into_inner(self) -> CiphertextBase<OpOrder> {
+        self.0
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use super::NotTrivialCiphertextError;
     use crate::shortint::gen_keys;
-    use crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    use crate::shortint::parameters::{ParameterVersion, PARAM_MESSAGE_2_CARRY_2};
+    use crate::shortint::{CiphertextBig, CiphertextSmall, CompressedCiphertextBig};
 
     #[test]
     fn test_copy_from() {
@@ -314,4 +553,61 @@ mod tests {
         ct_1.copy_from(&ct_2);
         assert_eq!(ct_1, ct_2);
     }
+
+    #[test]
+    fn test_parameter_version_serialization() {
+        let (client_key, server_key) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+
+        let ct = client_key.encrypt(1);
+        assert_eq!(ct.parameter_version, Some(ParameterVersion::V0_3));
+
+        let serialized = bincode::serialize(&ct).unwrap();
+        let deserialized: CiphertextBig = bincode::deserialize(&serialized).unwrap();
+        assert_eq!(deserialized.parameter_version, Some(ParameterVersion::V0_3));
+
+        let compressed = client_key.encrypt_compressed(1);
+        let serialized = bincode::serialize(&compressed).unwrap();
+        let deserialized: CompressedCiphertextBig = bincode::deserialize(&serialized).unwrap();
+        assert_eq!(
+            deserialized.decompress().parameter_version,
+            Some(ParameterVersion::V0_3)
+        );
+
+        // The version is kept by the operations and absent from trivial ciphertexts
+        let ct_res = server_key.scalar_add(&ct, 1);
+        assert_eq!(ct_res.parameter_version, Some(ParameterVersion::V0_3));
+        let trivial: CiphertextBig = server_key.create_trivial(1);
+        assert_eq!(trivial.parameter_version, None);
+
+        let mut versioned = trivial.clone();
+        versioned.parameter_version = Some(ParameterVersion::V0_3);
+        assert_ne!(trivial.canonical_bytes(), versioned.canonical_bytes());
+    }
+
+    #[test]
+    fn test_decrypt_trivial() {
+        let (client_key, server_key) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+
+        for msg in 0..4 {
+            let ct: CiphertextBig = server_key.create_trivial(msg);
+            assert!(ct.is_trivial());
+            assert_eq!(ct.decrypt_trivial(), Ok(msg));
+
+            let ct_small: CiphertextSmall = server_key.create_trivial(msg);
+            assert_eq!(ct_small.decrypt_trivial(), Ok(msg));
+
+            // The carries are kept by the unchecked operations and emptied by the PBS
+            let ct = server_key.unchecked_scalar_add(&ct, 3);
+            assert_eq!(ct.decrypt_trivial_message_and_carry(), Ok(msg + 3));
+            assert_eq!(ct.decrypt_trivial(), Ok((msg + 3) % 4));
+            let acc = server_key.generate_accumulator(|x| x * 2);
+            let ct = server_key.apply_lookup_table(&ct, &acc);
+            assert!(ct.is_trivial());
+            assert_eq!(ct.decrypt_trivial(), Ok((msg + 3) * 2 % 4));
+        }
+
+        let ct = client_key.encrypt(1);
+        assert!(!ct.is_trivial());
+        assert_eq!(ct.decrypt_trivial(), Err(NotTrivialCiphertextError));
+    }
 }
@@ -1,9 +1,12 @@
 //! Module with the definition of the ClientKey.
 
+use crate::core_crypto::commons::generators::DeterministicSeeder;
+use crate::core_crypto::commons::math::random::{ActivatedRandomGenerator, Seed, Seeder};
 use crate::core_crypto::entities::*;
 use crate::shortint::ciphertext::{
-    CiphertextBase, CiphertextBig, CiphertextSmall, CompressedCiphertextBig,
-    CompressedCiphertextSmall, PBSOrderMarker,
+    CiphertextBase, CiphertextBig, CiphertextNoPaddingBase, CiphertextNoPaddingBig,
+    CiphertextNoPaddingSmall, CiphertextSmall, CompressedCiphertextBig, CompressedCiphertextSmall,
+    Degree, PBSOrderMarker,
 };
 use crate::shortint::engine::ShortintEngine;
 use crate::shortint::parameters::{MessageModulus, ShortintParameterSet};
@@ -25,9 +28,90 @@ pub struct ClientKey {
     pub(crate) glwe_secret_key: GlweSecretKeyOwned<u64>,
     /// Key used as the output of the keyswitch operation
     pub(crate) small_lwe_secret_key: LweSecretKeyOwned<u64>,
+    #[cfg_attr(
+        feature = "insecure-test-params",
+        serde(serialize_with = "crate::shortint::parameters::serialize_key_parameters")
+    )]
     pub parameters: ShortintParameterSet,
 }
 
+/// A secret of 256 bits from which a [`ClientKey`] is derived, see [`ClientKey::new_from_seed`].
+///
+/// The seed is as sensitive as the client key it derives.
+///
+/// # Derivation
+///
+/// The seed is compressed to the 128 bits seed of a [`ShortintEngine`], which then generates the
+/// key. With `s0` the first 16 bytes of the seed and `s1` the last 16 bytes, `L` the 16 bytes of
+/// the ASCII string `"tfhe-shortint-ck"`, all read as little endian integers, and `G(s)` the
+/// first 16 bytes output by the CSPRNG seeded with `s`, i.e. AES-128 in counter mode keyed by
+/// `s`, the seed of the engine is `G(G(s0) ^ s1 ^ L)`. The label `L` separates this use of the
+/// secret from its other uses.
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub struct ClientKeySeed(pub [u8; 32]);
+
+impl ClientKeySeed {
+    const DERIVATION_LABEL: [u8; 16] = *b"tfhe-shortint-ck";
+
+    /// Returns the seeder whose first seed is the seed of the engine generating the key.
+    fn engine_seeder(&self) -> DeterministicSeeder<ActivatedRandomGenerator> {
+        let word = |bytes: &[u8]| u128::from_le_bytes(bytes.try_into().unwrap());
+        let first_block = |seed: u128| {
+            DeterministicSeeder::<ActivatedRandomGenerator>::new(Seed(seed))
+                .seed()
+                .0
+        };
+
+        let (s0, s1) = self.0.split_at(16);
+        let label = u128::from_le_bytes(Self::DERIVATION_LABEL);
+        DeterministicSeeder::new(Seed(first_block(word(s0)) ^ word(s1) ^ label))
+    }
+}
+
+/// The inconsistency found by [`ClientKey::sanitize`] in a ciphertext, which was re-encrypted.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SanitizationIssue {
+    /// The padding bit of the ciphertext is set
+    PaddingBitSet,
+    /// The ciphertext decrypts to a value greater than its degree
+    DegreeExceeded {
+        degree: Degree,
+        message_and_carry: u64,
+    },
+    /// The noise of the ciphertext is above a quarter of the distance between two encoded values,
+    /// half of what the decryption tolerates
+    ExcessiveNoise,
+}
+
+/// The noise of a ciphertext, measured by [`ClientKey::decrypt_with_noise`].
+///
+/// The noise is only measured correctly while it is below the decryption bound: past it, the
+/// ciphertext decrypts to another message and the noise is measured from that message.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct NoiseEstimate {
+    /// The signed distance between the phase of the ciphertext and the nearest encoded value, on
+    /// the 64 bits torus
+    pub noise: i64,
+    /// The distance above which the phase decrypts to another value, i.e. half of the distance
+    /// between two encoded values
+    pub decryption_bound: u64,
+}
+
+impl NoiseEstimate {
+    /// Returns the noise as a fraction of the decryption bound, between 0 and 1.
+    pub fn relative_noise(&self) -> f64 {
+        self.noise.unsigned_abs() as f64 / self.decryption_bound as f64
+    }
+
+    /// Returns the number of bits between the noise and the decryption bound, that is how many
+    /// times the noise can double before the decryption fails.
+    ///
+    /// A noiseless ciphertext, e.g. a trivial one, has an infinite margin.
+    pub fn margin_bits(&self) -> f64 {
+        (self.decryption_bound as f64).log2() - (self.noise.unsigned_abs() as f64).log2()
+    }
+}
+
 impl ClientKey {
     /// Generate a client key.
     ///
@@ -52,6 +136,43 @@ impl ClientKey {
         })
     }
 
+    /// Generate a client key deterministically from a secret seed.
+    ///
+    /// The same seed and parameters always give the same client key, so a key derived from an
+    /// existing secret, e.g. held in a wallet or an HSM, can be generated again on any device
+    /// instead of being stored. The derivation is described in [`ClientKeySeed`], the key
+    /// generation itself is the one of [`ClientKey::new`].
+    ///
+    /// The derivation is stable across platforms, but a change of the key generation in a future
+    /// version of the library, e.g. of the order in which the secret keys are sampled, would
+    /// derive different keys from the same seed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::client_key::{ClientKey, ClientKeySeed};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let seed = ClientKeySeed([42; 32]);
+    ///
+    /// let cks = ClientKey::new_from_seed(PARAM_MESSAGE_2_CARRY_2, seed);
+    /// let ct = cks.encrypt(3);
+    ///
+    /// // On another device
+    /// let cks = ClientKey::new_from_seed(PARAM_MESSAGE_2_CARRY_2, seed);
+    /// assert_eq!(cks.decrypt(&ct), 3);
+    /// ```
+    pub fn new_from_seed<P>(parameters: P, seed: ClientKeySeed) -> ClientKey
+    where
+        P: TryInto<ShortintParameterSet>,
+        <P as TryInto<ShortintParameterSet>>::Error: Debug,
+    {
+        let mut engine = ShortintEngine::new_from_seeder(&mut seed.engine_seeder());
+        engine
+            .new_client_key(parameters.try_into().unwrap())
+            .unwrap()
+    }
+
     /// Encrypt a small integer message using the client key.
     ///
     /// The input message is reduced to the encrypted message space modulus
@@ -447,6 +568,91 @@ impl ClientKey {
         ShortintEngine::with_thread_local_mut(|engine| engine.decrypt(self, ct).unwrap())
     }
 
+    /// Decrypt a ciphertext encrypting a message using the client key, and measure its noise.
+    ///
+    /// This is meant to monitor the noise margins of the ciphertexts returned by a server: a
+    /// margin shrinking over time reveals parameters unfit for the computations, before the
+    /// decryptions start failing.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    /// use tfhe::shortint::{gen_keys, ClientKey};
+    ///
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let ct = cks.encrypt(3);
+    /// let (dec, fresh_noise) = cks.decrypt_with_noise(&ct);
+    /// assert_eq!(dec, 3);
+    ///
+    /// // The unchecked additions accumulate the noise of their operands
+    /// let mut sum = ct.clone();
+    /// for _ in 0..4 {
+    ///     sks.unchecked_add_assign(&mut sum, &ct);
+    /// }
+    /// let (dec, noise) = cks.decrypt_with_noise(&sum);
+    /// assert_eq!(dec, 15 % 4);
+    /// assert!(noise.relative_noise() < 1.0);
+    /// assert!(noise.margin_bits() > 0.0);
+    /// assert_eq!(noise.decryption_bound, fresh_noise.decryption_bound);
+    /// ```
+    pub fn decrypt_with_noise<OpOrder: PBSOrderMarker>(
+        &self,
+        ct: &CiphertextBase<OpOrder>,
+    ) -> (u64, NoiseEstimate) {
+        ShortintEngine::with_thread_local_mut(|engine| engine.decrypt_with_noise(self, ct).unwrap())
+    }
+
+    /// Checks that a ciphertext about to be sent to the server is consistent with its metadata,
+    /// and re-encrypts it if it is not.
+    ///
+    /// The ciphertext is re-encrypted with fresh noise if:
+    /// * its padding bit is set;
+    /// * it decrypts to a value greater than its degree;
+    /// * its noise is too large for the server to compute on it without decryption errors.
+    ///
+    /// In the first two cases, the decrypted message and carry is re-encrypted and the degree is
+    /// set to the maximum of the message and carry spaces, so that it does not reveal the message
+    /// to the server. The found issue is returned, `None` meaning the ciphertext is left unchanged.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the message and carry spaces or the LWE dimension of the ciphertext do not match
+    /// the ones of the client key: such a ciphertext can not be decrypted, hence repaired.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::ciphertext::Degree;
+    /// use tfhe::shortint::client_key::SanitizationIssue;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    /// use tfhe::shortint::ClientKey;
+    ///
+    /// let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let mut ct = cks.encrypt(3);
+    /// assert_eq!(cks.sanitize(&mut ct), None);
+    ///
+    /// // A client bug makes the ciphertext claim a smaller degree than its value
+    /// ct.degree = Degree(1);
+    /// assert_eq!(
+    ///     cks.sanitize(&mut ct),
+    ///     Some(SanitizationIssue::DegreeExceeded {
+    ///         degree: Degree(1),
+    ///         message_and_carry: 3
+    ///     })
+    /// );
+    /// assert_eq!(ct.degree, Degree(15));
+    /// assert_eq!(cks.decrypt(&ct), 3);
+    /// ```
+    pub fn sanitize<OpOrder: PBSOrderMarker>(
+        &self,
+        ct: &mut CiphertextBase<OpOrder>,
+    ) -> Option<SanitizationIssue> {
+        ShortintEngine::with_thread_local_mut(|engine| engine.sanitize(self, ct).unwrap())
+    }
+
     /// Encrypt a small integer message using the client key without padding bit.
     ///
     /// The input message is reduced to the encrypted message space modulus
@@ -641,6 +847,66 @@ impl ClientKey {
         })
     }
 
+    /// Encrypt a small integer message using the client key without padding bit,
+    /// returning a ciphertext whose type only allows the operations
+    /// that are valid without the padding bit.
+    ///
+    /// The input message is reduced to the `message_modulus * carry_modulus` space.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    /// use tfhe::shortint::ClientKey;
+    ///
+    /// let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// // The whole 4 bits of message + carry can be used
+    /// let msg = 13;
+    /// let ct = cks.encrypt_no_padding(msg);
+    ///
+    /// let dec = cks.decrypt_no_padding(&ct);
+    /// assert_eq!(msg, dec);
+    /// ```
+    pub fn encrypt_no_padding(&self, message: u64) -> CiphertextNoPaddingBig {
+        let modulus =
+            (self.parameters.message_modulus().0 * self.parameters.carry_modulus().0) as u64;
+        ShortintEngine::with_thread_local_mut(|engine| {
+            let mut ct = engine
+                .encrypt_without_padding(self, message % modulus)
+                .unwrap();
+            ct.degree = Degree(modulus as usize - 1);
+            CiphertextNoPaddingBase(ct)
+        })
+    }
+
+    /// Encrypt a small integer message using the client key without padding bit,
+    /// the returned ciphertext is encrypted under the small key.
+    ///
+    /// See [`Self::encrypt_no_padding`]
+    pub fn encrypt_no_padding_small(&self, message: u64) -> CiphertextNoPaddingSmall {
+        let modulus =
+            (self.parameters.message_modulus().0 * self.parameters.carry_modulus().0) as u64;
+        ShortintEngine::with_thread_local_mut(|engine| {
+            let mut ct = engine
+                .encrypt_without_padding(self, message % modulus)
+                .unwrap();
+            ct.degree = Degree(modulus as usize - 1);
+            CiphertextNoPaddingBase(ct)
+        })
+    }
+
+    /// Decrypt a ciphertext encrypted without padding bit.
+    ///
+    /// The result is in the `message_modulus * carry_modulus` space.
+    pub fn decrypt_no_padding<OpOrder: PBSOrderMarker>(
+        &self,
+        ct: &CiphertextNoPaddingBase<OpOrder>,
+    ) -> u64 {
+        let modulus = ct.modulus() as u64;
+        self.decrypt_message_and_carry_without_padding(&ct.0) % modulus
+    }
+
     /// Encrypt a small integer message using the client key without padding bit with some modulus.
     ///
     /// The input message is reduced to the encrypted message space modulus
@@ -1,9 +1,10 @@
 //! All the `ShortintEngine` method related to client side (encrypt / decrypt)
-use super::{EngineResult, ShortintEngine};
+use super::{native_torus_value, plaintext_on_modulus, EngineResult, ShortintEngine};
 use crate::core_crypto::algorithms::*;
 use crate::core_crypto::commons::dispersion::DispersionParameter;
 use crate::core_crypto::entities::*;
-use crate::shortint::ciphertext::Degree;
+use crate::shortint::ciphertext::{Degree, NoiseLevel};
+use crate::shortint::client_key::{NoiseEstimate, SanitizationIssue};
 use crate::shortint::parameters::{CarryModulus, MessageModulus};
 use crate::shortint::{
     CiphertextBase, ClientKey, CompressedCiphertextBase, PBSOrder, PBSOrderMarker,
@@ -47,10 +48,11 @@ impl ShortintEngine {
             &mut self.secret_generator,
         );
 
-        // generate the rlwe secret key
-        let glwe_secret_key = allocate_and_generate_new_binary_glwe_secret_key(
+        // generate the rlwe secret key, the lwe secret key stays binary for the blind rotation
+        let glwe_secret_key = allocate_and_generate_new_glwe_secret_key_with_distribution(
             parameters.glwe_dimension(),
             parameters.polynomial_size(),
+            parameters.secret_key_distribution(),
             &mut self.secret_generator,
         );
 
@@ -144,7 +146,8 @@ impl ShortintEngine {
 
         let shifted_message = m * delta;
 
-        let encoded = Plaintext(shifted_message);
+        let encoded =
+            plaintext_on_modulus(shifted_message, client_key_parameters.ciphertext_modulus());
 
         allocate_and_encrypt_new_lwe_ciphertext(
             client_lwe_sk,
@@ -155,7 +158,7 @@ impl ShortintEngine {
         )
     }
 
-    pub(crate) fn encrypt_with_message_modulus<OpOrder: PBSOrderMarker>(
+    pub fn encrypt_with_message_modulus<OpOrder: PBSOrderMarker>(
         &mut self,
         client_key: &ClientKey,
         message: u64,
@@ -166,11 +169,11 @@ impl ShortintEngine {
         let (encryption_lwe_sk, encryption_noise) = match (OpOrder::pbs_order(), params_op_order) {
             (PBSOrder::KeyswitchBootstrap, PBSOrder::KeyswitchBootstrap) => (
                 &client_key.large_lwe_secret_key,
-                client_key.parameters.glwe_modular_std_dev(),
+                client_key.parameters.glwe_noise_distribution(),
             ),
             (PBSOrder::BootstrapKeyswitch, PBSOrder::BootstrapKeyswitch) => (
                 &client_key.small_lwe_secret_key,
-                client_key.parameters.lwe_modular_std_dev(),
+                client_key.parameters.lwe_noise_distribution(),
             ),
             (ct_order, params_order) => {
                 return Err(EncryptionError::EncryptionKeyMismatch {
@@ -198,13 +201,15 @@ impl ShortintEngine {
         Ok(CiphertextBase {
             ct,
             degree: Degree(message_modulus.0 - 1),
+            noise_level: NoiseLevel::NOMINAL,
             message_modulus,
             carry_modulus: CarryModulus(carry_modulus),
+            parameter_version: client_key.parameters.version(),
             _order_marker: Default::default(),
         })
     }
 
-    pub(crate) fn encrypt_with_message_modulus_compressed<OpOrder: PBSOrderMarker>(
+    pub fn encrypt_with_message_modulus_compressed<OpOrder: PBSOrderMarker>(
         &mut self,
         client_key: &ClientKey,
         message: u64,
@@ -226,18 +231,19 @@ impl ShortintEngine {
 
         let shifted_message = m * delta;
 
-        let encoded = Plaintext(shifted_message);
+        let encoded =
+            plaintext_on_modulus(shifted_message, client_key.parameters.ciphertext_modulus());
 
         let params_op_order: PBSOrder = client_key.parameters.encryption_key_choice().into();
 
         let (encryption_lwe_sk, encryption_noise) = match (OpOrder::pbs_order(), params_op_order) {
             (PBSOrder::KeyswitchBootstrap, PBSOrder::KeyswitchBootstrap) => (
                 &client_key.large_lwe_secret_key,
-                client_key.parameters.glwe_modular_std_dev(),
+                client_key.parameters.glwe_noise_distribution(),
             ),
             (PBSOrder::BootstrapKeyswitch, PBSOrder::BootstrapKeyswitch) => (
                 &client_key.small_lwe_secret_key,
-                client_key.parameters.lwe_modular_std_dev(),
+                client_key.parameters.lwe_noise_distribution(),
             ),
             (ct_order, params_order) => {
                 return Err(EncryptionError::EncryptionKeyMismatch {
@@ -261,11 +267,12 @@ impl ShortintEngine {
             degree: Degree(message_modulus.0 - 1),
             message_modulus,
             carry_modulus: CarryModulus(carry_modulus),
+            parameter_version: client_key.parameters.version(),
             _order_marker: Default::default(),
         })
     }
 
-    pub(crate) fn unchecked_encrypt<OpOrder: PBSOrderMarker>(
+    pub fn unchecked_encrypt<OpOrder: PBSOrderMarker>(
         &mut self,
         client_key: &ClientKey,
         message: u64,
@@ -275,11 +282,11 @@ impl ShortintEngine {
         let (encryption_lwe_sk, encryption_noise) = match (OpOrder::pbs_order(), params_op_order) {
             (PBSOrder::KeyswitchBootstrap, PBSOrder::KeyswitchBootstrap) => (
                 &client_key.large_lwe_secret_key,
-                client_key.parameters.glwe_modular_std_dev(),
+                client_key.parameters.glwe_noise_distribution(),
             ),
             (PBSOrder::BootstrapKeyswitch, PBSOrder::BootstrapKeyswitch) => (
                 &client_key.small_lwe_secret_key,
-                client_key.parameters.lwe_modular_std_dev(),
+                client_key.parameters.lwe_noise_distribution(),
             ),
             (ct_order, params_order) => {
                 return Err(EncryptionError::EncryptionKeyMismatch {
@@ -295,7 +302,8 @@ impl ShortintEngine {
                 as u64;
         let shifted_message = message * delta;
 
-        let encoded = Plaintext(shifted_message);
+        let encoded =
+            plaintext_on_modulus(shifted_message, client_key.parameters.ciphertext_modulus());
 
         let ct = allocate_and_encrypt_new_lwe_ciphertext(
             encryption_lwe_sk,
@@ -311,13 +319,15 @@ impl ShortintEngine {
                 client_key.parameters.message_modulus().0 * client_key.parameters.carry_modulus().0
                     - 1,
             ),
+            noise_level: NoiseLevel::NOMINAL,
             message_modulus: client_key.parameters.message_modulus(),
             carry_modulus: client_key.parameters.carry_modulus(),
+            parameter_version: client_key.parameters.version(),
             _order_marker: Default::default(),
         })
     }
 
-    pub(crate) fn decrypt_message_and_carry<OpOrder: PBSOrderMarker>(
+    pub fn decrypt_message_and_carry<OpOrder: PBSOrderMarker>(
         &mut self,
         client_key: &ClientKey,
         ct: &CiphertextBase<OpOrder>,
@@ -330,7 +340,10 @@ impl ShortintEngine {
         // decryption
         let decrypted_encoded = decrypt_lwe_ciphertext(lwe_decryption_key, &ct.ct);
 
-        let decrypted_u64: u64 = decrypted_encoded.0;
+        let decrypted_u64 = native_torus_value(
+            decrypted_encoded,
+            client_key.parameters.ciphertext_modulus(),
+        );
 
         let delta = (1_u64 << 63)
             / (client_key.parameters.message_modulus().0 * client_key.parameters.carry_modulus().0)
@@ -354,7 +367,114 @@ impl ShortintEngine {
             .map(|message_and_carry| message_and_carry % ct.message_modulus.0 as u64)
     }
 
-    pub(crate) fn encrypt_without_padding<OpOrder: PBSOrderMarker>(
+    pub fn decrypt_with_noise<OpOrder: PBSOrderMarker>(
+        &mut self,
+        client_key: &ClientKey,
+        ct: &CiphertextBase<OpOrder>,
+    ) -> EngineResult<(u64, NoiseEstimate)> {
+        let lwe_decryption_key = match OpOrder::pbs_order() {
+            PBSOrder::KeyswitchBootstrap => &client_key.large_lwe_secret_key,
+            PBSOrder::BootstrapKeyswitch => &client_key.small_lwe_secret_key,
+        };
+
+        let decrypted_u64 = native_torus_value(
+            decrypt_lwe_ciphertext(lwe_decryption_key, &ct.ct),
+            client_key.parameters.ciphertext_modulus(),
+        );
+
+        let delta = (1_u64 << 63)
+            / (client_key.parameters.message_modulus().0 * client_key.parameters.carry_modulus().0)
+                as u64;
+        let rounding = (decrypted_u64 & (delta >> 1)) << 1;
+        let decoded = decrypted_u64.wrapping_add(rounding) / delta;
+        let noise = decrypted_u64.wrapping_sub(decoded.wrapping_mul(delta)) as i64;
+
+        Ok((
+            decoded % ct.message_modulus.0 as u64,
+            NoiseEstimate {
+                noise,
+                decryption_bound: delta / 2,
+            },
+        ))
+    }
+
+    pub fn sanitize<OpOrder: PBSOrderMarker>(
+        &mut self,
+        client_key: &ClientKey,
+        ct: &mut CiphertextBase<OpOrder>,
+    ) -> EngineResult<Option<SanitizationIssue>> {
+        let modulus_sup =
+            client_key.parameters.message_modulus().0 * client_key.parameters.carry_modulus().0;
+        assert_eq!(
+            ct.message_modulus.0 * ct.carry_modulus.0,
+            modulus_sup,
+            "The message and carry spaces of the ciphertext do not match the client key"
+        );
+
+        let (lwe_secret_key, noise_parameter) = match OpOrder::pbs_order() {
+            PBSOrder::KeyswitchBootstrap => (
+                &client_key.large_lwe_secret_key,
+                client_key.parameters.glwe_noise_distribution(),
+            ),
+            PBSOrder::BootstrapKeyswitch => (
+                &client_key.small_lwe_secret_key,
+                client_key.parameters.lwe_noise_distribution(),
+            ),
+        };
+        assert_eq!(
+            ct.ct.lwe_size(),
+            lwe_secret_key.lwe_dimension().to_lwe_size(),
+            "The LWE dimension of the ciphertext does not match the client key"
+        );
+
+        let decrypted_u64 = native_torus_value(
+            decrypt_lwe_ciphertext(lwe_secret_key, &ct.ct),
+            client_key.parameters.ciphertext_modulus(),
+        );
+
+        let delta = (1_u64 << 63) / modulus_sup as u64;
+        let rounding = (decrypted_u64 & (delta >> 1)) << 1;
+        // The decoded value, padding bit included
+        let decoded = decrypted_u64.wrapping_add(rounding) / delta;
+        let noise = decrypted_u64.wrapping_sub(decoded.wrapping_mul(delta)) as i64;
+
+        let message_and_carry = decoded % modulus_sup as u64;
+
+        let issue = if decoded >= modulus_sup as u64 {
+            SanitizationIssue::PaddingBitSet
+        } else if message_and_carry > ct.degree.0 as u64 {
+            SanitizationIssue::DegreeExceeded {
+                degree: ct.degree,
+                message_and_carry,
+            }
+        } else if noise.unsigned_abs() >= delta / 4 {
+            SanitizationIssue::ExcessiveNoise
+        } else {
+            return Ok(None);
+        };
+
+        // The degree is not lowered to the decrypted value, which would reveal it to the server
+        if issue != SanitizationIssue::ExcessiveNoise {
+            ct.degree = Degree(modulus_sup - 1);
+        }
+
+        let encoded = plaintext_on_modulus(
+            message_and_carry * delta,
+            client_key.parameters.ciphertext_modulus(),
+        );
+        ct.ct = allocate_and_encrypt_new_lwe_ciphertext(
+            lwe_secret_key,
+            encoded,
+            noise_parameter,
+            client_key.parameters.ciphertext_modulus(),
+            &mut self.encryption_generator,
+        );
+        ct.noise_level = NoiseLevel::NOMINAL;
+
+        Ok(Some(issue))
+    }
+
+    pub fn encrypt_without_padding<OpOrder: PBSOrderMarker>(
         &mut self,
         client_key: &ClientKey,
         message: u64,
@@ -367,18 +487,19 @@ impl ShortintEngine {
 
         let shifted_message = message * delta;
 
-        let encoded = Plaintext(shifted_message);
+        let encoded =
+            plaintext_on_modulus(shifted_message, client_key.parameters.ciphertext_modulus());
 
         let params_op_order: PBSOrder = client_key.parameters.encryption_key_choice().into();
 
         let (encryption_lwe_sk, encryption_noise) = match (OpOrder::pbs_order(), params_op_order) {
             (PBSOrder::KeyswitchBootstrap, PBSOrder::KeyswitchBootstrap) => (
                 &client_key.large_lwe_secret_key,
-                client_key.parameters.glwe_modular_std_dev(),
+                client_key.parameters.glwe_noise_distribution(),
             ),
             (PBSOrder::BootstrapKeyswitch, PBSOrder::BootstrapKeyswitch) => (
                 &client_key.small_lwe_secret_key,
-                client_key.parameters.lwe_modular_std_dev(),
+                client_key.parameters.lwe_noise_distribution(),
             ),
             (ct_order, params_order) => {
                 return Err(EncryptionError::EncryptionKeyMismatch {
@@ -400,13 +521,15 @@ impl ShortintEngine {
         Ok(CiphertextBase {
             ct,
             degree: Degree(client_key.parameters.message_modulus().0 - 1),
+            noise_level: NoiseLevel::NOMINAL,
             message_modulus: client_key.parameters.message_modulus(),
             carry_modulus: client_key.parameters.carry_modulus(),
+            parameter_version: client_key.parameters.version(),
             _order_marker: Default::default(),
         })
     }
 
-    pub(crate) fn encrypt_without_padding_compressed<OpOrder: PBSOrderMarker>(
+    pub fn encrypt_without_padding_compressed<OpOrder: PBSOrderMarker>(
         &mut self,
         client_key: &ClientKey,
         message: u64,
@@ -419,18 +542,19 @@ impl ShortintEngine {
 
         let shifted_message = message * delta;
 
-        let encoded = Plaintext(shifted_message);
+        let encoded =
+            plaintext_on_modulus(shifted_message, client_key.parameters.ciphertext_modulus());
 
         let params_op_order: PBSOrder = client_key.parameters.encryption_key_choice().into();
 
         let (encryption_lwe_sk, encryption_noise) = match (OpOrder::pbs_order(), params_op_order) {
             (PBSOrder::KeyswitchBootstrap, PBSOrder::KeyswitchBootstrap) => (
                 &client_key.large_lwe_secret_key,
-                client_key.parameters.glwe_modular_std_dev(),
+                client_key.parameters.glwe_noise_distribution(),
             ),
             (PBSOrder::BootstrapKeyswitch, PBSOrder::BootstrapKeyswitch) => (
                 &client_key.small_lwe_secret_key,
-                client_key.parameters.lwe_modular_std_dev(),
+                client_key.parameters.lwe_noise_distribution(),
             ),
             (ct_order, params_order) => {
                 return Err(EncryptionError::EncryptionKeyMismatch {
@@ -454,11 +578,12 @@ impl ShortintEngine {
             degree: Degree(client_key.parameters.message_modulus().0 - 1),
             message_modulus: client_key.parameters.message_modulus(),
             carry_modulus: client_key.parameters.carry_modulus(),
+            parameter_version: client_key.parameters.version(),
             _order_marker: Default::default(),
         })
     }
 
-    pub(crate) fn decrypt_message_and_carry_without_padding<OpOrder: PBSOrderMarker>(
+    pub fn decrypt_message_and_carry_without_padding<OpOrder: PBSOrderMarker>(
         &mut self,
         client_key: &ClientKey,
         ct: &CiphertextBase<OpOrder>,
@@ -471,7 +596,10 @@ impl ShortintEngine {
         // decryption
         let decrypted_encoded = decrypt_lwe_ciphertext(lwe_decryption_key, &ct.ct);
 
-        let decrypted_u64: u64 = decrypted_encoded.0;
+        let decrypted_u64 = native_torus_value(
+            decrypted_encoded,
+            client_key.parameters.ciphertext_modulus(),
+        );
 
         let delta = ((1_u64 << 63)
             / (client_key.parameters.message_modulus().0 * client_key.parameters.carry_modulus().0)
@@ -487,7 +615,7 @@ impl ShortintEngine {
         Ok((decrypted_u64.wrapping_add(rounding)) / delta)
     }
 
-    pub(crate) fn decrypt_without_padding<OpOrder: PBSOrderMarker>(
+    pub fn decrypt_without_padding<OpOrder: PBSOrderMarker>(
         &mut self,
         client_key: &ClientKey,
         ct: &CiphertextBase<OpOrder>,
@@ -496,7 +624,7 @@ impl ShortintEngine {
             .map(|message_and_carry| message_and_carry % ct.message_modulus.0 as u64)
     }
 
-    pub(crate) fn encrypt_native_crt<OpOrder: PBSOrderMarker>(
+    pub fn encrypt_native_crt<OpOrder: PBSOrderMarker>(
         &mut self,
         client_key: &ClientKey,
         message: u64,
@@ -506,6 +634,13 @@ impl ShortintEngine {
         let m = (message % message_modulus as u64) as u128;
         let shifted_message = (m * (1 << 64) / message_modulus as u128) as u64;
 
+        assert!(
+            client_key
+                .parameters
+                .ciphertext_modulus()
+                .is_native_modulus(),
+            "The native CRT encoding requires the native ciphertext modulus"
+        );
         let encoded = Plaintext(shifted_message);
 
         let params_op_order: PBSOrder = client_key.parameters.encryption_key_choice().into();
@@ -513,11 +648,11 @@ impl ShortintEngine {
         let (encryption_lwe_sk, encryption_noise) = match (OpOrder::pbs_order(), params_op_order) {
             (PBSOrder::KeyswitchBootstrap, PBSOrder::KeyswitchBootstrap) => (
                 &client_key.large_lwe_secret_key,
-                client_key.parameters.glwe_modular_std_dev(),
+                client_key.parameters.glwe_noise_distribution(),
             ),
             (PBSOrder::BootstrapKeyswitch, PBSOrder::BootstrapKeyswitch) => (
                 &client_key.small_lwe_secret_key,
-                client_key.parameters.lwe_modular_std_dev(),
+                client_key.parameters.lwe_noise_distribution(),
             ),
             (ct_order, params_order) => {
                 return Err(EncryptionError::EncryptionKeyMismatch {
@@ -539,13 +674,15 @@ impl ShortintEngine {
         Ok(CiphertextBase {
             ct,
             degree: Degree(message_modulus as usize - 1),
+            noise_level: NoiseLevel::NOMINAL,
             message_modulus: MessageModulus(message_modulus as usize),
             carry_modulus: CarryModulus(carry_modulus),
+            parameter_version: client_key.parameters.version(),
             _order_marker: Default::default(),
         })
     }
 
-    pub(crate) fn encrypt_native_crt_compressed<OpOrder: PBSOrderMarker>(
+    pub fn encrypt_native_crt_compressed<OpOrder: PBSOrderMarker>(
         &mut self,
         client_key: &ClientKey,
         message: u64,
@@ -555,6 +692,13 @@ impl ShortintEngine {
         let m = (message % message_modulus as u64) as u128;
         let shifted_message = (m * (1 << 64) / message_modulus as u128) as u64;
 
+        assert!(
+            client_key
+                .parameters
+                .ciphertext_modulus()
+                .is_native_modulus(),
+            "The native CRT encoding requires the native ciphertext modulus"
+        );
         let encoded = Plaintext(shifted_message);
 
         let params_op_order: PBSOrder = client_key.parameters.encryption_key_choice().into();
@@ -562,11 +706,11 @@ impl ShortintEngine {
         let (encryption_lwe_sk, encryption_noise) = match (OpOrder::pbs_order(), params_op_order) {
             (PBSOrder::KeyswitchBootstrap, PBSOrder::KeyswitchBootstrap) => (
                 &client_key.large_lwe_secret_key,
-                client_key.parameters.glwe_modular_std_dev(),
+                client_key.parameters.glwe_noise_distribution(),
             ),
             (PBSOrder::BootstrapKeyswitch, PBSOrder::BootstrapKeyswitch) => (
                 &client_key.small_lwe_secret_key,
-                client_key.parameters.lwe_modular_std_dev(),
+                client_key.parameters.lwe_noise_distribution(),
             ),
             (ct_order, params_order) => {
                 return Err(EncryptionError::EncryptionKeyMismatch {
@@ -590,11 +734,12 @@ impl ShortintEngine {
             degree: Degree(message_modulus as usize - 1),
             message_modulus: MessageModulus(message_modulus as usize),
             carry_modulus: CarryModulus(carry_modulus),
+            parameter_version: client_key.parameters.version(),
             _order_marker: Default::default(),
         })
     }
 
-    pub(crate) fn decrypt_message_native_crt<OpOrder: PBSOrderMarker>(
+    pub fn decrypt_message_native_crt<OpOrder: PBSOrderMarker>(
         &mut self,
         client_key: &ClientKey,
         ct: &CiphertextBase<OpOrder>,
@@ -608,7 +753,10 @@ impl ShortintEngine {
         // decryption
         let decrypted_encoded = decrypt_lwe_ciphertext(lwe_decryption_key, &ct.ct);
 
-        let decrypted_u64: u64 = decrypted_encoded.0;
+        let decrypted_u64 = native_torus_value(
+            decrypted_encoded,
+            client_key.parameters.ciphertext_modulus(),
+        );
 
         let mut result = decrypted_u64 as u128 * basis as u128;
         result = result.wrapping_add((result & 1 << 63) << 1) / (1 << 64);
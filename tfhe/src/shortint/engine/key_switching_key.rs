@@ -0,0 +1,126 @@
+//! All the `ShortintEngine` method related to the key switching between client keys
+use super::{EngineResult, ShortintEngine};
+use crate::core_crypto::algorithms::*;
+use crate::core_crypto::entities::LweKeyswitchKeyOwned;
+use crate::shortint::key_switching_key::{CastingKey, KeySwitchingKey};
+use crate::shortint::parameters::{EncryptionKeyChoice, ShortintKeySwitchingParameters};
+use crate::shortint::ClientKey;
+
+impl ShortintEngine {
+    pub fn new_key_switching_key(
+        &mut self,
+        input_key: &ClientKey,
+        output_key: &ClientKey,
+        params: ShortintKeySwitchingParameters,
+    ) -> EngineResult<KeySwitchingKey> {
+        let input_params = input_key.parameters;
+        let output_params = output_key.parameters;
+        assert_eq!(
+            (input_params.message_modulus(), input_params.carry_modulus()),
+            (
+                output_params.message_modulus(),
+                output_params.carry_modulus()
+            ),
+            "The two client keys must use the same message and carry spaces"
+        );
+        assert_eq!(
+            input_params.ciphertext_modulus(),
+            output_params.ciphertext_modulus(),
+            "The two client keys must use the same ciphertext modulus"
+        );
+
+        let key_switching_key =
+            self.new_encryption_key_switching_key(input_key, output_key, params);
+
+        Ok(KeySwitchingKey {
+            key_switching_key,
+            input_parameters: input_params,
+            output_parameters: output_params,
+            params,
+        })
+    }
+
+    /// Generates the key switching key from the encryption key of `input_key` to the encryption
+    /// key of `output_key`, without checking that their message and carry spaces match.
+    pub(crate) fn new_encryption_key_switching_key(
+        &mut self,
+        input_key: &ClientKey,
+        output_key: &ClientKey,
+        params: ShortintKeySwitchingParameters,
+    ) -> LweKeyswitchKeyOwned<u64> {
+        let input_params = input_key.parameters;
+        let output_params = output_key.parameters;
+
+        // Each key switches from or to the key its ciphertexts are encrypted under
+        let input_secret_key = match input_params.encryption_key_choice() {
+            EncryptionKeyChoice::Big => &input_key.large_lwe_secret_key,
+            EncryptionKeyChoice::Small => &input_key.small_lwe_secret_key,
+        };
+        let (output_secret_key, noise_distribution) = match output_params.encryption_key_choice() {
+            EncryptionKeyChoice::Big => (
+                &output_key.large_lwe_secret_key,
+                output_params.glwe_noise_distribution(),
+            ),
+            EncryptionKeyChoice::Small => (
+                &output_key.small_lwe_secret_key,
+                output_params.lwe_noise_distribution(),
+            ),
+        };
+
+        allocate_and_generate_new_lwe_keyswitch_key(
+            input_secret_key,
+            output_secret_key,
+            params.ks_base_log,
+            params.ks_level,
+            noise_distribution,
+            output_params.ciphertext_modulus(),
+            &mut self.encryption_generator,
+        )
+    }
+
+    pub fn new_casting_key(
+        &mut self,
+        input_key: &ClientKey,
+        output_key: &ClientKey,
+        params: ShortintKeySwitchingParameters,
+    ) -> EngineResult<CastingKey> {
+        let input_params = input_key.parameters;
+        let output_params = output_key.parameters;
+        let input_modulus = input_params.message_modulus().0 * input_params.carry_modulus().0;
+        let output_modulus = output_params.message_modulus().0 * output_params.carry_modulus().0;
+        assert!(
+            output_modulus % input_modulus == 0,
+            "The message and carry space of the output key ({output_modulus}) must be a multiple \
+            of the one of the input key ({input_modulus})"
+        );
+        assert_eq!(
+            input_params.ciphertext_modulus(),
+            output_params.ciphertext_modulus(),
+            "The two client keys must use the same ciphertext modulus"
+        );
+
+        let input_secret_key = match input_params.encryption_key_choice() {
+            EncryptionKeyChoice::Big => &input_key.large_lwe_secret_key,
+            EncryptionKeyChoice::Small => &input_key.small_lwe_secret_key,
+        };
+
+        // The casting ends with a PBS of the output server key, so the ciphertexts are switched
+        // directly to its input key, i.e. the small key
+        let key_switching_key = allocate_and_generate_new_lwe_keyswitch_key(
+            input_secret_key,
+            &output_key.small_lwe_secret_key,
+            params.ks_base_log,
+            params.ks_level,
+            output_params.lwe_noise_distribution(),
+            output_params.ciphertext_modulus(),
+            &mut self.encryption_generator,
+        );
+
+        Ok(CastingKey {
+            key_switching_key,
+            input_parameters: input_params,
+            output_parameters: output_params,
+            params,
+        })
+    }
+}
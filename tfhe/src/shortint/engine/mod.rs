@@ -2,6 +2,37 @@
 //!
 //! Engines are required to abstract cryptographic notions and efficiently manage memory from the
 //! underlying `core_crypto` module.
+//!
+//! The methods of the keys use a [`ShortintEngine`] stored in a thread local, created the first
+//! time a thread needs it. The operations are also available as methods of the engine, taking the
+//! keys as arguments: a caller which cannot rely on thread locals, e.g. an async task which may be
+//! resumed on another thread of the executor or an FFI host managing its own threads, can own an
+//! engine and pass it explicitly. An engine is [`Send`], so it can move between threads along
+//! with the work using it, but it must not be used by two threads at once.
+//!
+//! The operations of the engine return an [`EngineResult`] instead of panicking on errors.
+//!
+//! # Example
+//!
+//! ```rust
+//! use tfhe::shortint::engine::ShortintEngine;
+//! use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+//! use tfhe::shortint::CiphertextBig;
+//!
+//! let mut engine = ShortintEngine::new();
+//! let cks = engine
+//!     .new_client_key(PARAM_MESSAGE_2_CARRY_2.into())
+//!     .unwrap();
+//! let sks = engine.new_server_key(&cks).unwrap();
+//!
+//! let handle = std::thread::spawn(move || {
+//!     let ct_1: CiphertextBig = engine.encrypt(&cks, 3).unwrap();
+//!     let ct_2: CiphertextBig = engine.encrypt(&cks, 2).unwrap();
+//!     let ct_res = engine.unchecked_mul_lsb(&sks, &ct_1, &ct_2).unwrap();
+//!     engine.decrypt(&cks, &ct_res).unwrap()
+//! });
+//! assert_eq!(handle.join().unwrap(), 2);
+//! ```
 
 use crate::core_crypto::commons::computation_buffers::ComputationBuffers;
 use crate::core_crypto::commons::generators::{
@@ -19,10 +50,12 @@ use crate::shortint::ServerKey;
 use std::cell::RefCell;
 use std::fmt::Debug;
 
-use super::parameters::MessageModulus;
+use super::parameters::{CiphertextModulus, MessageModulus};
 use super::server_key::BivariateLookupTable;
 
 mod client_side;
+mod key_switching_key;
+mod noise_squashing;
 mod public_side;
 mod server_side;
 mod wopbs;
@@ -78,6 +111,8 @@ impl Memory {
             acc,
             // As a safety, the degree should be updated once the accumulator is actually filled
             degree: Degree(server_key.max_degree.0),
+            message_modulus: server_key.message_modulus,
+            carry_modulus: server_key.carry_modulus,
         };
 
         let (after_ks_elements, after_pbs_elements) =
@@ -154,6 +189,105 @@ where
     max_value
 }
 
+/// Fills the accumulator with several look-up tables interleaved coefficient by coefficient, the
+/// i-th coefficient of each box holds the value of the `i % functions.len()`-th function.
+///
+/// The accumulator is meant to be used by a many-LUT bootstrap evaluating `functions.len()`
+/// look-up tables, which must be a power of two.
+///
+/// Returns the max value of each function.
+fn fill_many_lut_accumulator<C>(
+    accumulator: &mut GlweCiphertext<C>,
+    server_key: &ServerKey,
+    functions: &[&dyn Fn(u64) -> u64],
+) -> Vec<u64>
+where
+    C: ContainerMut<Element = u64>,
+{
+    assert!(functions.len().is_power_of_two());
+    assert_eq!(
+        accumulator.polynomial_size(),
+        server_key.bootstrapping_key.polynomial_size()
+    );
+    assert_eq!(
+        accumulator.glwe_size(),
+        server_key.bootstrapping_key.glwe_size()
+    );
+
+    let mut accumulator_view = accumulator.as_mut_view();
+
+    accumulator_view.get_mut_mask().as_mut().fill(0);
+
+    // Modulus of the msg contained in the msg bits and operations buffer
+    let modulus_sup = server_key.message_modulus.0 * server_key.carry_modulus.0;
+
+    // N/(p/2) = size of each block
+    let box_size = server_key.bootstrapping_key.polynomial_size().0 / modulus_sup;
+
+    // The rotation by half a box must keep each coefficient on the slot of its function
+    assert_eq!((box_size / 2) % functions.len(), 0);
+
+    // Value of the shift we multiply our messages by
+    let delta = (1_u64 << 63) / modulus_sup as u64;
+
+    let mut body = accumulator_view.get_mut_body();
+    let accumulator_u64 = body.as_mut();
+
+    let mut max_values = vec![0; functions.len()];
+
+    for (i, box_) in accumulator_u64.chunks_exact_mut(box_size).enumerate() {
+        for (function_index, f) in functions.iter().enumerate() {
+            let f_eval = f(i as u64);
+            max_values[function_index] = max_values[function_index].max(f_eval);
+            box_.iter_mut()
+                .skip(function_index)
+                .step_by(functions.len())
+                .for_each(|a| *a = f_eval * delta);
+        }
+    }
+
+    let half_box_size = box_size / 2;
+
+    // Negate the first half_box_size coefficients
+    for a_i in accumulator_u64[0..half_box_size].iter_mut() {
+        *a_i = (*a_i).wrapping_neg();
+    }
+
+    // Rotate the accumulator
+    accumulator_u64.rotate_left(half_box_size);
+
+    max_values
+}
+
+/// Converts a value of the native torus to a plaintext encoded on the ciphertext modulus.
+///
+/// Shortint encodes messages on the native torus: with a power of two ciphertext modulus smaller
+/// than 2^64 the data lives in the MSBs of the `u64`, which is also how `core_crypto` stores such
+/// ciphertexts. The `core_crypto` encryption primitives however take plaintexts encoded on the
+/// ciphertext modulus, i.e. in the LSBs.
+fn plaintext_on_modulus(value: u64, ciphertext_modulus: CiphertextModulus) -> Plaintext<u64> {
+    assert!(
+        ciphertext_modulus.is_compatible_with_native_modulus(),
+        "Shortint only supports the native ciphertext modulus and power of two moduli, \
+        got {ciphertext_modulus}"
+    );
+    let scaling = ciphertext_modulus.get_scaling_to_native_torus();
+    debug_assert_eq!(
+        value % scaling,
+        0,
+        "The value does not fit in the ciphertext modulus"
+    );
+    Plaintext(value / scaling)
+}
+
+/// Converts a plaintext encoded on the ciphertext modulus, as returned by the `core_crypto`
+/// decryption primitives, back to a value of the native torus, see [`plaintext_on_modulus`].
+fn native_torus_value(plaintext: Plaintext<u64>, ciphertext_modulus: CiphertextModulus) -> u64 {
+    plaintext
+        .0
+        .wrapping_mul(ciphertext_modulus.get_scaling_to_native_torus())
+}
+
 /// Simple wrapper around [`std::error::Error`] to be able to
 /// forward all the possible `EngineError` type from [`core_cryto`](crate::core_crypto)
 #[allow(dead_code)]
@@ -179,7 +313,7 @@ impl std::fmt::Display for EngineError {
     }
 }
 
-pub(crate) type EngineResult<T> = Result<T, EngineError>;
+pub type EngineResult<T> = Result<T, EngineError>;
 
 /// ShortintEngine
 ///
@@ -217,9 +351,9 @@ impl ShortintEngine {
 
     /// Create a new shortint engine
     ///
-    /// Creating a `ShortintEngine` should not be needed, as each
-    /// rust thread gets its own `thread_local` engine created automatically,
-    /// see [ShortintEngine::with_thread_local_mut]
+    /// Creating a `ShortintEngine` is only needed to pass it explicitly to the operations, see the
+    /// [module documentation](self), otherwise each rust thread gets its own `thread_local` engine
+    /// created automatically, see [ShortintEngine::with_thread_local_mut]
     ///
     ///
     /// # Panics
@@ -267,6 +401,8 @@ impl ShortintEngine {
         Ok(LookupTableOwned {
             acc,
             degree: Degree(max_value as usize),
+            message_modulus: server_key.message_modulus,
+            carry_modulus: server_key.carry_modulus,
         })
     }
 
@@ -0,0 +1,207 @@
+//! All the `ShortintEngine` method related to the noise squashing
+use super::{EngineResult, ShortintEngine};
+use crate::core_crypto::algorithms::*;
+use crate::core_crypto::commons::parameters::*;
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::entities::*;
+use crate::core_crypto::prelude::Fft128;
+use crate::shortint::noise_squashing::{
+    NoiseSquashingKey, NoiseSquashingPrivateKey, SquashedNoiseCiphertext,
+};
+use crate::shortint::parameters::NoiseSquashingParameters;
+use crate::shortint::{CiphertextBase, ClientKey, PBSOrder, PBSOrderMarker, ServerKey};
+
+impl ShortintEngine {
+    pub fn new_noise_squashing_private_key(
+        &mut self,
+        params: NoiseSquashingParameters,
+    ) -> EngineResult<NoiseSquashingPrivateKey> {
+        params.check_noise_budget()?;
+
+        let glwe_secret_key = allocate_and_generate_new_binary_glwe_secret_key(
+            params.glwe_dimension,
+            params.polynomial_size,
+            &mut self.secret_generator,
+        );
+
+        Ok(NoiseSquashingPrivateKey {
+            glwe_secret_key,
+            params,
+        })
+    }
+
+    pub fn new_noise_squashing_key(
+        &mut self,
+        client_key: &ClientKey,
+        private_key: &NoiseSquashingPrivateKey,
+    ) -> EngineResult<NoiseSquashingKey> {
+        let params = private_key.params;
+        assert_eq!(
+            (params.message_modulus, params.carry_modulus),
+            (
+                client_key.parameters.message_modulus(),
+                client_key.parameters.carry_modulus()
+            ),
+            "The noise squashing parameters do not match the message and carry spaces of the \
+            client key"
+        );
+
+        // The blind rotation takes the small LWE secret key of the client key as input, the key
+        // is binary so its coefficients are the same on 128 bits
+        let small_lwe_secret_key = LweSecretKey::from_container(
+            client_key
+                .small_lwe_secret_key
+                .as_ref()
+                .iter()
+                .map(|&coefficient| coefficient as u128)
+                .collect::<Vec<_>>(),
+        );
+
+        let bootstrap_key: LweBootstrapKeyOwned<u128> =
+            par_allocate_and_generate_new_lwe_bootstrap_key(
+                &small_lwe_secret_key,
+                &private_key.glwe_secret_key,
+                params.decomp_base_log,
+                params.decomp_level_count,
+                params.glwe_noise_distribution,
+                params.ciphertext_modulus,
+                &mut self.encryption_generator,
+            );
+
+        let mut fourier_bsk = Fourier128LweBootstrapKey::new(
+            bootstrap_key.input_lwe_dimension(),
+            bootstrap_key.glwe_size(),
+            bootstrap_key.polynomial_size(),
+            bootstrap_key.decomposition_base_log(),
+            bootstrap_key.decomposition_level_count(),
+        );
+
+        convert_standard_lwe_bootstrap_key_to_fourier_128(&bootstrap_key, &mut fourier_bsk);
+
+        Ok(NoiseSquashingKey {
+            bootstrapping_key: fourier_bsk,
+            params,
+        })
+    }
+
+    pub fn squash_noise<OpOrder: PBSOrderMarker>(
+        &mut self,
+        squashing_key: &NoiseSquashingKey,
+        server_key: &ServerKey,
+        ct: &CiphertextBase<OpOrder>,
+    ) -> EngineResult<SquashedNoiseCiphertext> {
+        let params = squashing_key.params;
+        assert!(
+            ct.ct.ciphertext_modulus().is_native_modulus(),
+            "The noise squashing only supports ciphertexts on the native modulus"
+        );
+        assert_eq!(
+            (ct.message_modulus, ct.carry_modulus),
+            (params.message_modulus, params.carry_modulus),
+            "The noise squashing parameters do not match the message and carry spaces of the \
+            ciphertext"
+        );
+
+        // The blind rotation is computed under the small LWE secret key
+        let ct_small = match OpOrder::pbs_order() {
+            PBSOrder::KeyswitchBootstrap => {
+                let mut ct_small = LweCiphertext::new(
+                    0u64,
+                    server_key.key_switching_key.output_lwe_size(),
+                    ct.ct.ciphertext_modulus(),
+                );
+                keyswitch_lwe_ciphertext(&server_key.key_switching_key, &ct.ct, &mut ct_small);
+                ct_small
+            }
+            PBSOrder::BootstrapKeyswitch => ct.ct.clone(),
+        };
+
+        // Scale the input to the 128 bits torus, the encoded value does not change
+        let input = LweCiphertext::from_container(
+            ct_small
+                .as_ref()
+                .iter()
+                .map(|&coefficient| (coefficient as u128) << 64)
+                .collect::<Vec<_>>(),
+            params.ciphertext_modulus,
+        );
+
+        let accumulator = Self::generate_noise_squashing_accumulator(params);
+
+        let fourier_bsk = &squashing_key.bootstrapping_key;
+        let mut output = LweCiphertext::new(
+            0u128,
+            fourier_bsk.output_lwe_dimension().to_lwe_size(),
+            params.ciphertext_modulus,
+        );
+
+        let fft = Fft128::new(fourier_bsk.polynomial_size());
+        let fft = fft.as_view();
+        self.computation_buffers.resize(
+            programmable_bootstrap_f128_lwe_ciphertext_mem_optimized_requirement::<u128>(
+                fourier_bsk.glwe_size(),
+                fourier_bsk.polynomial_size(),
+                fft,
+            )
+            .unwrap()
+            .unaligned_bytes_required(),
+        );
+        let stack = self.computation_buffers.stack();
+
+        programmable_bootstrap_f128_lwe_ciphertext_mem_optimized(
+            &input,
+            &mut output,
+            &accumulator,
+            fourier_bsk,
+            fft,
+            stack,
+        );
+
+        // Flood the noise of the bootstrapping, which depends on the input ciphertext
+        let body = output.as_mut().last_mut().unwrap();
+        self.encryption_generator
+            .unsigned_torus_slice_wrapping_add_random_noise_assign(
+                std::slice::from_mut(body),
+                params.flooding_noise_distribution,
+            );
+
+        Ok(SquashedNoiseCiphertext {
+            ct: output,
+            degree: ct.degree,
+            message_modulus: ct.message_modulus,
+            carry_modulus: ct.carry_modulus,
+        })
+    }
+
+    /// Identity accumulator over the message and carry spaces, with the encoding of the 128 bits
+    /// torus
+    fn generate_noise_squashing_accumulator(
+        params: NoiseSquashingParameters,
+    ) -> GlweCiphertextOwned<u128> {
+        let polynomial_size = params.polynomial_size.0;
+        let modulus_sup = params.message_modulus.0 * params.carry_modulus.0;
+        let delta = (1u128 << 127) / modulus_sup as u128;
+
+        // N/(p/2) = size of each block
+        let box_size = polynomial_size / modulus_sup;
+
+        let mut accumulator = vec![0u128; polynomial_size];
+        for (i, value_box) in accumulator.chunks_exact_mut(box_size).enumerate() {
+            value_box.fill(i as u128 * delta);
+        }
+
+        let half_box_size = box_size / 2;
+
+        // Negate the first half_box_size coefficients to manage negacyclicity and rotate
+        for a_i in accumulator[0..half_box_size].iter_mut() {
+            *a_i = (*a_i).wrapping_neg();
+        }
+        accumulator.rotate_left(half_box_size);
+
+        allocate_and_trivially_encrypt_new_glwe_ciphertext(
+            params.glwe_dimension.to_glwe_size(),
+            &PlaintextList::from_container(accumulator),
+            params.ciphertext_modulus,
+        )
+    }
+}
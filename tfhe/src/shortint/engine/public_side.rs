@@ -1,12 +1,16 @@
 //! All the `ShortintEngine` method related to public side (encrypt / decrypt)
-use super::{EngineResult, ShortintEngine};
+use super::{plaintext_on_modulus, EngineResult, ShortintEngine};
+use crate::core_crypto::algorithms::polynomial_algorithms::polynomial_wrapping_add_mul_assign;
 use crate::core_crypto::algorithms::*;
 use crate::core_crypto::commons::parameters::*;
+use crate::core_crypto::commons::traits::*;
 use crate::core_crypto::entities::*;
-use crate::shortint::ciphertext::Degree;
+use crate::shortint::ciphertext::{Degree, NoiseLevel};
 use crate::shortint::parameters::{CarryModulus, MessageModulus};
 use crate::shortint::{
-    CiphertextBase, ClientKey, CompressedPublicKeyBase, PBSOrderMarker, PublicKeyBase,
+    CiphertextBase, ClientKey, CompactCiphertextListBase, CompactCiphertextListBig,
+    CompactPublicKeyBase, CompactPublicKeyBig, CompressedPublicKeyBase, PBSOrderMarker,
+    PublicKeyBase,
 };
 
 // We have q = 2^64 so log2q = 64
@@ -22,7 +26,7 @@ pub fn shortint_public_key_zero_encryption_count(
 }
 
 impl ShortintEngine {
-    pub(crate) fn new_public_key<OpOrder: PBSOrderMarker>(
+    pub fn new_public_key<OpOrder: PBSOrderMarker>(
         &mut self,
         client_key: &ClientKey,
     ) -> EngineResult<PublicKeyBase<OpOrder>> {
@@ -31,11 +35,11 @@ impl ShortintEngine {
         let (secret_encryption_key, encryption_noise) = match OpOrder::pbs_order() {
             crate::shortint::PBSOrder::KeyswitchBootstrap => (
                 &client_key.large_lwe_secret_key,
-                client_parameters.glwe_modular_std_dev(),
+                client_parameters.glwe_noise_distribution(),
             ),
             crate::shortint::PBSOrder::BootstrapKeyswitch => (
                 &client_key.small_lwe_secret_key,
-                client_parameters.lwe_modular_std_dev(),
+                client_parameters.lwe_noise_distribution(),
             ),
         };
 
@@ -68,7 +72,7 @@ impl ShortintEngine {
         })
     }
 
-    pub(crate) fn new_compressed_public_key<OpOrder: PBSOrderMarker>(
+    pub fn new_compressed_public_key<OpOrder: PBSOrderMarker>(
         &mut self,
         client_key: &ClientKey,
     ) -> EngineResult<CompressedPublicKeyBase<OpOrder>> {
@@ -77,11 +81,11 @@ impl ShortintEngine {
         let (secret_encryption_key, encryption_noise) = match OpOrder::pbs_order() {
             crate::shortint::PBSOrder::KeyswitchBootstrap => (
                 &client_key.large_lwe_secret_key,
-                client_parameters.glwe_modular_std_dev(),
+                client_parameters.glwe_noise_distribution(),
             ),
             crate::shortint::PBSOrder::BootstrapKeyswitch => (
                 &client_key.small_lwe_secret_key,
-                client_parameters.lwe_modular_std_dev(),
+                client_parameters.lwe_noise_distribution(),
             ),
         };
 
@@ -114,7 +118,164 @@ impl ShortintEngine {
         })
     }
 
-    pub(crate) fn encrypt_with_public_key<OpOrder: PBSOrderMarker>(
+    pub fn new_compact_public_key(
+        &mut self,
+        client_key: &ClientKey,
+    ) -> EngineResult<CompactPublicKeyBig> {
+        let client_parameters = client_key.parameters;
+
+        let ciphertext_modulus = client_parameters.ciphertext_modulus();
+        assert!(
+            ciphertext_modulus.is_native_modulus(),
+            "The compact public key requires the native ciphertext modulus"
+        );
+
+        // The large LWE secret key is the GLWE secret key of the bootstrapping key seen as an LWE
+        // key: the compact public key is a GLWE encryption of zero with the same key, ring and
+        // noise as the GGSW ciphertexts of the bootstrapping key, which are already public. The
+        // sample extraction of the GLWE ciphertexts encrypted with it gives back LWE ciphertexts
+        // under the large LWE secret key.
+        let polynomial_size = client_parameters.polynomial_size();
+        assert!(
+            polynomial_size.0.is_power_of_two(),
+            "The compact public key requires a power of two polynomial size, got {}",
+            polynomial_size.0
+        );
+        let glwe_secret_key = GlweSecretKey::from_container(
+            client_key.large_lwe_secret_key.as_ref(),
+            polynomial_size,
+        );
+
+        let mut glwe_public_key = GlweCiphertextOwned::new(
+            0u64,
+            client_parameters.glwe_dimension().to_glwe_size(),
+            polynomial_size,
+            ciphertext_modulus,
+        );
+        encrypt_glwe_ciphertext_assign(
+            &glwe_secret_key,
+            &mut glwe_public_key,
+            client_parameters.glwe_noise_distribution(),
+            &mut self.encryption_generator,
+        );
+
+        Ok(CompactPublicKeyBase {
+            glwe_public_key,
+            parameters: client_parameters,
+            _order_marker: Default::default(),
+        })
+    }
+
+    pub fn encrypt_slice_with_compact_public_key(
+        &mut self,
+        public_key: &CompactPublicKeyBig,
+        messages: &[u64],
+    ) -> EngineResult<CompactCiphertextListBig> {
+        let parameters = public_key.parameters;
+        let encryption_noise = parameters.glwe_noise_distribution();
+        let (message_modulus, carry_modulus) =
+            (parameters.message_modulus(), parameters.carry_modulus());
+
+        //The delta is the one defined by the parameters
+        let delta = (1_u64 << 63) / (message_modulus.0 * carry_modulus.0) as u64;
+
+        let glwe_public_key = &public_key.glwe_public_key;
+        let polynomial_size = glwe_public_key.polynomial_size();
+
+        let mut binary_random_polynomial = Polynomial::new(0u64, polynomial_size);
+
+        let ct_list = messages
+            .chunks(polynomial_size.0)
+            .map(|chunk| {
+                let mut ct = GlweCiphertextOwned::new(
+                    0u64,
+                    glwe_public_key.glwe_size(),
+                    polynomial_size,
+                    glwe_public_key.ciphertext_modulus(),
+                );
+
+                // With the public key (A, B = <A, S> + E), the encryption of M is
+                // (A.R + E1, B.R + E2 + M) for a fresh binary polynomial R. R is secret, so it is
+                // drawn from the private noise generator, and not from the secret key generator
+                // whose stream must only depend on the seed of the client key.
+                //
+                // The noise of the message is E.R + E2 - <E1, S>: with binary R and S, its
+                // variance is about (1 + (k + 1) * N / 2) times the GLWE encryption variance,
+                // orders of magnitude below the variance of a PBS output, so the expanded
+                // ciphertexts have the nominal noise level.
+                self.encryption_generator
+                    .fill_slice_with_random_noise_binary(binary_random_polynomial.as_mut());
+                for (mut ct_polynomial, pk_polynomial) in ct
+                    .as_mut_polynomial_list()
+                    .iter_mut()
+                    .zip(glwe_public_key.as_polynomial_list().iter())
+                {
+                    polynomial_wrapping_add_mul_assign(
+                        &mut ct_polynomial,
+                        &pk_polynomial,
+                        &binary_random_polynomial,
+                    );
+                }
+                self.encryption_generator
+                    .unsigned_torus_slice_wrapping_add_random_noise_assign(
+                        ct.as_mut(),
+                        encryption_noise,
+                    );
+
+                let mut body = ct.get_mut_body();
+                for (coefficient, message) in body.as_mut_polynomial().iter_mut().zip(chunk) {
+                    //The input is reduced modulus the message_modulus
+                    let m = message % message_modulus.0 as u64;
+                    *coefficient = coefficient.wrapping_add(m * delta);
+                }
+
+                ct
+            })
+            .collect();
+
+        Ok(CompactCiphertextListBase {
+            ct_list,
+            message_count: messages.len(),
+            message_modulus,
+            carry_modulus,
+            parameter_version: parameters.version(),
+            _order_marker: Default::default(),
+        })
+    }
+
+    pub fn expand_compact_ciphertext_list<OpOrder: PBSOrderMarker>(
+        &mut self,
+        list: &CompactCiphertextListBase<OpOrder>,
+    ) -> EngineResult<Vec<CiphertextBase<OpOrder>>> {
+        let expanded = list
+            .ct_list
+            .iter()
+            .flat_map(|ct| {
+                let lwe_size =
+                    LweDimension(ct.glwe_size().to_glwe_dimension().0 * ct.polynomial_size().0)
+                        .to_lwe_size();
+                (0..ct.polynomial_size().0).map(move |index| {
+                    let mut lwe = LweCiphertextOwned::new(0u64, lwe_size, ct.ciphertext_modulus());
+                    extract_lwe_sample_from_glwe_ciphertext(ct, &mut lwe, MonomialDegree(index));
+                    lwe
+                })
+            })
+            .take(list.message_count)
+            .map(|lwe| CiphertextBase {
+                ct: lwe,
+                degree: Degree(list.message_modulus.0 - 1),
+                noise_level: NoiseLevel::NOMINAL,
+                message_modulus: list.message_modulus,
+                carry_modulus: list.carry_modulus,
+                parameter_version: list.parameter_version,
+                _order_marker: Default::default(),
+            })
+            .collect();
+
+        Ok(expanded)
+    }
+
+    pub fn encrypt_with_public_key<OpOrder: PBSOrderMarker>(
         &mut self,
         public_key: &PublicKeyBase<OpOrder>,
         message: u64,
@@ -128,7 +289,7 @@ impl ShortintEngine {
         Ok(ciphertext)
     }
 
-    pub(crate) fn encrypt_with_compressed_public_key<OpOrder: PBSOrderMarker>(
+    pub fn encrypt_with_compressed_public_key<OpOrder: PBSOrderMarker>(
         &mut self,
         public_key: &CompressedPublicKeyBase<OpOrder>,
         message: u64,
@@ -142,7 +303,7 @@ impl ShortintEngine {
         Ok(ciphertext)
     }
 
-    pub(crate) fn encrypt_with_message_modulus_and_public_key<OpOrder: PBSOrderMarker>(
+    pub fn encrypt_with_message_modulus_and_public_key<OpOrder: PBSOrderMarker>(
         &mut self,
         public_key: &PublicKeyBase<OpOrder>,
         message: u64,
@@ -164,7 +325,10 @@ impl ShortintEngine {
 
         let shifted_message = m * delta;
         // encode the message
-        let plain = Plaintext(shifted_message);
+        let plain = plaintext_on_modulus(
+            shifted_message,
+            public_key.lwe_public_key.ciphertext_modulus(),
+        );
 
         // This allocates the required ct
         let mut encrypted_ct = LweCiphertextOwned::new(
@@ -183,15 +347,15 @@ impl ShortintEngine {
         Ok(CiphertextBase {
             ct: encrypted_ct,
             degree: Degree(message_modulus.0 - 1),
+            noise_level: NoiseLevel::NOMINAL,
             message_modulus,
             carry_modulus: CarryModulus(carry_modulus),
+            parameter_version: public_key.parameters.version(),
             _order_marker: Default::default(),
         })
     }
 
-    pub(crate) fn encrypt_with_message_modulus_and_compressed_public_key<
-        OpOrder: PBSOrderMarker,
-    >(
+    pub fn encrypt_with_message_modulus_and_compressed_public_key<OpOrder: PBSOrderMarker>(
         &mut self,
         public_key: &CompressedPublicKeyBase<OpOrder>,
         message: u64,
@@ -213,7 +377,10 @@ impl ShortintEngine {
 
         let shifted_message = m * delta;
         // encode the message
-        let plain = Plaintext(shifted_message);
+        let plain = plaintext_on_modulus(
+            shifted_message,
+            public_key.lwe_public_key.ciphertext_modulus(),
+        );
 
         // This allocates the required ct
         let mut encrypted_ct = LweCiphertext::new(
@@ -233,13 +400,15 @@ impl ShortintEngine {
         Ok(CiphertextBase {
             ct: encrypted_ct,
             degree: Degree(message_modulus.0 - 1),
+            noise_level: NoiseLevel::NOMINAL,
             message_modulus,
             carry_modulus: CarryModulus(carry_modulus),
+            parameter_version: public_key.parameters.version(),
             _order_marker: Default::default(),
         })
     }
 
-    pub(crate) fn encrypt_without_padding_with_public_key<OpOrder: PBSOrderMarker>(
+    pub fn encrypt_without_padding_with_public_key<OpOrder: PBSOrderMarker>(
         &mut self,
         public_key: &PublicKeyBase<OpOrder>,
         message: u64,
@@ -252,7 +421,10 @@ impl ShortintEngine {
 
         let shifted_message = message * delta;
         // encode the message
-        let plain = Plaintext(shifted_message);
+        let plain = plaintext_on_modulus(
+            shifted_message,
+            public_key.lwe_public_key.ciphertext_modulus(),
+        );
 
         // This allocates the required ct
         let mut encrypted_ct = LweCiphertextOwned::new(
@@ -272,13 +444,15 @@ impl ShortintEngine {
         Ok(CiphertextBase {
             ct: encrypted_ct,
             degree: Degree(public_key.parameters.message_modulus().0 - 1),
+            noise_level: NoiseLevel::NOMINAL,
             message_modulus: public_key.parameters.message_modulus(),
             carry_modulus: public_key.parameters.carry_modulus(),
+            parameter_version: public_key.parameters.version(),
             _order_marker: Default::default(),
         })
     }
 
-    pub(crate) fn encrypt_without_padding_with_compressed_public_key<OpOrder: PBSOrderMarker>(
+    pub fn encrypt_without_padding_with_compressed_public_key<OpOrder: PBSOrderMarker>(
         &mut self,
         public_key: &CompressedPublicKeyBase<OpOrder>,
         message: u64,
@@ -291,7 +465,10 @@ impl ShortintEngine {
 
         let shifted_message = message * delta;
         // encode the message
-        let plain = Plaintext(shifted_message);
+        let plain = plaintext_on_modulus(
+            shifted_message,
+            public_key.lwe_public_key.ciphertext_modulus(),
+        );
 
         // This allocates the required ct
         let mut encrypted_ct = LweCiphertextOwned::new(
@@ -311,13 +488,15 @@ impl ShortintEngine {
         Ok(CiphertextBase {
             ct: encrypted_ct,
             degree: Degree(public_key.parameters.message_modulus().0 - 1),
+            noise_level: NoiseLevel::NOMINAL,
             message_modulus: public_key.parameters.message_modulus(),
             carry_modulus: public_key.parameters.carry_modulus(),
+            parameter_version: public_key.parameters.version(),
             _order_marker: Default::default(),
         })
     }
 
-    pub(crate) fn encrypt_native_crt_with_public_key<OpOrder: PBSOrderMarker>(
+    pub fn encrypt_native_crt_with_public_key<OpOrder: PBSOrderMarker>(
         &mut self,
         public_key: &PublicKeyBase<OpOrder>,
         message: u64,
@@ -328,6 +507,13 @@ impl ShortintEngine {
         let shifted_message = m * (1 << 64) / message_modulus as u128;
         // encode the message
 
+        assert!(
+            public_key
+                .lwe_public_key
+                .ciphertext_modulus()
+                .is_native_modulus(),
+            "The native CRT encoding requires the native ciphertext modulus"
+        );
         let plain = Plaintext(shifted_message as u64);
 
         // This allocates the required ct
@@ -347,13 +533,15 @@ impl ShortintEngine {
         Ok(CiphertextBase {
             ct: encrypted_ct,
             degree: Degree(message_modulus as usize - 1),
+            noise_level: NoiseLevel::NOMINAL,
             message_modulus: MessageModulus(message_modulus as usize),
             carry_modulus: CarryModulus(carry_modulus),
+            parameter_version: public_key.parameters.version(),
             _order_marker: Default::default(),
         })
     }
 
-    pub(crate) fn encrypt_native_crt_with_compressed_public_key<OpOder: PBSOrderMarker>(
+    pub fn encrypt_native_crt_with_compressed_public_key<OpOder: PBSOrderMarker>(
         &mut self,
         public_key: &CompressedPublicKeyBase<OpOder>,
         message: u64,
@@ -364,6 +552,13 @@ impl ShortintEngine {
         let shifted_message = m * (1 << 64) / message_modulus as u128;
         // encode the message
 
+        assert!(
+            public_key
+                .lwe_public_key
+                .ciphertext_modulus()
+                .is_native_modulus(),
+            "The native CRT encoding requires the native ciphertext modulus"
+        );
         let plain = Plaintext(shifted_message as u64);
 
         // This allocates the required ct
@@ -383,13 +578,15 @@ impl ShortintEngine {
         Ok(CiphertextBase {
             ct: encrypted_ct,
             degree: Degree(message_modulus as usize - 1),
+            noise_level: NoiseLevel::NOMINAL,
             message_modulus: MessageModulus(message_modulus as usize),
             carry_modulus: CarryModulus(carry_modulus),
+            parameter_version: public_key.parameters.version(),
             _order_marker: Default::default(),
         })
     }
 
-    pub(crate) fn unchecked_encrypt_with_public_key<OpOrder: PBSOrderMarker>(
+    pub fn unchecked_encrypt_with_public_key<OpOrder: PBSOrderMarker>(
         &mut self,
         public_key: &PublicKeyBase<OpOrder>,
         message: u64,
@@ -399,7 +596,10 @@ impl ShortintEngine {
                 as u64;
         let shifted_message = message * delta;
         // encode the message
-        let plain = Plaintext(shifted_message);
+        let plain = plaintext_on_modulus(
+            shifted_message,
+            public_key.lwe_public_key.ciphertext_modulus(),
+        );
 
         // This allocates the required ct
         let mut encrypted_ct = LweCiphertextOwned::new(
@@ -421,13 +621,15 @@ impl ShortintEngine {
                 public_key.parameters.message_modulus().0 * public_key.parameters.carry_modulus().0
                     - 1,
             ),
+            noise_level: NoiseLevel::NOMINAL,
             message_modulus: public_key.parameters.message_modulus(),
             carry_modulus: public_key.parameters.carry_modulus(),
+            parameter_version: public_key.parameters.version(),
             _order_marker: Default::default(),
         })
     }
 
-    pub(crate) fn unchecked_encrypt_with_compressed_public_key<OpOrder: PBSOrderMarker>(
+    pub fn unchecked_encrypt_with_compressed_public_key<OpOrder: PBSOrderMarker>(
         &mut self,
         public_key: &CompressedPublicKeyBase<OpOrder>,
         message: u64,
@@ -437,7 +639,10 @@ impl ShortintEngine {
                 as u64;
         let shifted_message = message * delta;
         // encode the message
-        let plain = Plaintext(shifted_message);
+        let plain = plaintext_on_modulus(
+            shifted_message,
+            public_key.lwe_public_key.ciphertext_modulus(),
+        );
 
         // This allocates the required ct
         let mut encrypted_ct = LweCiphertextOwned::new(
@@ -459,8 +664,10 @@ impl ShortintEngine {
                 public_key.parameters.message_modulus().0 * public_key.parameters.carry_modulus().0
                     - 1,
             ),
+            noise_level: NoiseLevel::NOMINAL,
             message_modulus: public_key.parameters.message_modulus(),
             carry_modulus: public_key.parameters.carry_modulus(),
+            parameter_version: public_key.parameters.version(),
             _order_marker: Default::default(),
         })
     }
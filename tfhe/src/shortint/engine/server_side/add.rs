@@ -1,10 +1,10 @@
 use crate::core_crypto::algorithms::*;
-use crate::shortint::ciphertext::Degree;
+use crate::shortint::ciphertext::{Degree, NoiseLevel};
 use crate::shortint::engine::{EngineResult, ShortintEngine};
 use crate::shortint::{CiphertextBase, PBSOrderMarker, ServerKey};
 
 impl ShortintEngine {
-    pub(crate) fn unchecked_add<OpOrder: PBSOrderMarker>(
+    pub fn unchecked_add<OpOrder: PBSOrderMarker>(
         &mut self,
         ct_left: &CiphertextBase<OpOrder>,
         ct_right: &CiphertextBase<OpOrder>,
@@ -14,17 +14,18 @@ impl ShortintEngine {
         Ok(result)
     }
 
-    pub(crate) fn unchecked_add_assign<OpOrder: PBSOrderMarker>(
+    pub fn unchecked_add_assign<OpOrder: PBSOrderMarker>(
         &mut self,
         ct_left: &mut CiphertextBase<OpOrder>,
         ct_right: &CiphertextBase<OpOrder>,
     ) -> EngineResult<()> {
         lwe_ciphertext_add_assign(&mut ct_left.ct, &ct_right.ct);
         ct_left.degree = Degree(ct_left.degree.0 + ct_right.degree.0);
+        ct_left.noise_level += ct_right.noise_level;
         Ok(())
     }
 
-    pub(crate) fn smart_add<OpOrder: PBSOrderMarker>(
+    pub fn smart_add<OpOrder: PBSOrderMarker>(
         &mut self,
         server_key: &ServerKey,
         ct_left: &mut CiphertextBase<OpOrder>,
@@ -35,7 +36,7 @@ impl ShortintEngine {
         Ok(result)
     }
 
-    pub(crate) fn smart_add_assign<OpOrder: PBSOrderMarker>(
+    pub fn smart_add_assign<OpOrder: PBSOrderMarker>(
         &mut self,
         server_key: &ServerKey,
         ct_left: &mut CiphertextBase<OpOrder>,
@@ -43,9 +44,16 @@ impl ShortintEngine {
     ) -> EngineResult<()> {
         //If the ciphertext cannot be added together without exceeding the capacity of a ciphertext
         if !server_key.is_add_possible(ct_left, ct_right) {
-            if ct_left.message_modulus.0 - 1 + ct_right.degree.0 <= server_key.max_degree.0 {
+            // Whether the addition is possible once the other operand is cleaned
+            let is_add_possible_with_clean = |ct: &CiphertextBase<OpOrder>| {
+                ct.message_modulus.0 - 1 + ct.degree.0 <= server_key.max_degree.0
+                    && server_key
+                        .max_noise_level()
+                        .validate(NoiseLevel::NOMINAL + ct.noise_level)
+            };
+            if is_add_possible_with_clean(ct_right) {
                 self.message_extract_assign(server_key, ct_left)?;
-            } else if ct_right.message_modulus.0 - 1 + ct_left.degree.0 <= server_key.max_degree.0 {
+            } else if is_add_possible_with_clean(ct_left) {
                 self.message_extract_assign(server_key, ct_right)?;
             } else {
                 self.message_extract_assign(server_key, ct_left)?;
@@ -2,7 +2,7 @@ use crate::shortint::engine::{EngineResult, ShortintEngine};
 use crate::shortint::{CiphertextBase, PBSOrderMarker, ServerKey};
 
 impl ShortintEngine {
-    pub(crate) fn unchecked_bitand<OpOrder: PBSOrderMarker>(
+    pub fn unchecked_bitand<OpOrder: PBSOrderMarker>(
         &mut self,
         server_key: &ServerKey,
         ct_left: &CiphertextBase<OpOrder>,
@@ -13,7 +13,7 @@ impl ShortintEngine {
         Ok(result)
     }
 
-    pub(crate) fn unchecked_bitand_assign<OpOrder: PBSOrderMarker>(
+    pub fn unchecked_bitand_assign<OpOrder: PBSOrderMarker>(
         &mut self,
         server_key: &ServerKey,
         ct_left: &mut CiphertextBase<OpOrder>,
@@ -29,7 +29,7 @@ impl ShortintEngine {
         Ok(())
     }
 
-    pub(crate) fn smart_bitand<OpOrder: PBSOrderMarker>(
+    pub fn smart_bitand<OpOrder: PBSOrderMarker>(
         &mut self,
         server_key: &ServerKey,
         ct_left: &mut CiphertextBase<OpOrder>,
@@ -40,7 +40,7 @@ impl ShortintEngine {
         Ok(result)
     }
 
-    pub(crate) fn smart_bitand_assign<OpOrder: PBSOrderMarker>(
+    pub fn smart_bitand_assign<OpOrder: PBSOrderMarker>(
         &mut self,
         server_key: &ServerKey,
         ct_left: &mut CiphertextBase<OpOrder>,
@@ -54,7 +54,7 @@ impl ShortintEngine {
         Ok(())
     }
 
-    pub(crate) fn unchecked_bitxor<OpOrder: PBSOrderMarker>(
+    pub fn unchecked_bitxor<OpOrder: PBSOrderMarker>(
         &mut self,
         server_key: &ServerKey,
         ct_left: &CiphertextBase<OpOrder>,
@@ -65,7 +65,7 @@ impl ShortintEngine {
         Ok(result)
     }
 
-    pub(crate) fn unchecked_bitxor_assign<OpOrder: PBSOrderMarker>(
+    pub fn unchecked_bitxor_assign<OpOrder: PBSOrderMarker>(
         &mut self,
         server_key: &ServerKey,
         ct_left: &mut CiphertextBase<OpOrder>,
@@ -81,7 +81,7 @@ impl ShortintEngine {
         Ok(())
     }
 
-    pub(crate) fn smart_bitxor<OpOrder: PBSOrderMarker>(
+    pub fn smart_bitxor<OpOrder: PBSOrderMarker>(
         &mut self,
         server_key: &ServerKey,
         ct_left: &mut CiphertextBase<OpOrder>,
@@ -92,7 +92,7 @@ impl ShortintEngine {
         Ok(result)
     }
 
-    pub(crate) fn smart_bitxor_assign<OpOrder: PBSOrderMarker>(
+    pub fn smart_bitxor_assign<OpOrder: PBSOrderMarker>(
         &mut self,
         server_key: &ServerKey,
         ct_left: &mut CiphertextBase<OpOrder>,
@@ -106,7 +106,7 @@ impl ShortintEngine {
         Ok(())
     }
 
-    pub(crate) fn unchecked_bitor<OpOrder: PBSOrderMarker>(
+    pub fn unchecked_bitor<OpOrder: PBSOrderMarker>(
         &mut self,
         server_key: &ServerKey,
         ct_left: &CiphertextBase<OpOrder>,
@@ -117,7 +117,7 @@ impl ShortintEngine {
         Ok(result)
     }
 
-    pub(crate) fn unchecked_bitor_assign<OpOrder: PBSOrderMarker>(
+    pub fn unchecked_bitor_assign<OpOrder: PBSOrderMarker>(
         &mut self,
         server_key: &ServerKey,
         ct_left: &mut CiphertextBase<OpOrder>,
@@ -133,7 +133,7 @@ impl ShortintEngine {
         Ok(())
     }
 
-    pub(crate) fn smart_bitor<OpOrder: PBSOrderMarker>(
+    pub fn smart_bitor<OpOrder: PBSOrderMarker>(
         &mut self,
         server_key: &ServerKey,
         ct_left: &mut CiphertextBase<OpOrder>,
@@ -144,7 +144,7 @@ impl ShortintEngine {
         Ok(result)
     }
 
-    pub(crate) fn smart_bitor_assign<OpOrder: PBSOrderMarker>(
+    pub fn smart_bitor_assign<OpOrder: PBSOrderMarker>(
         &mut self,
         server_key: &ServerKey,
         ct_left: &mut CiphertextBase<OpOrder>,
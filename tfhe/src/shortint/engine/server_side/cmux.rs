@@ -0,0 +1,73 @@
+use crate::shortint::ciphertext::Degree;
+use crate::shortint::engine::{EngineResult, ShortintEngine};
+use crate::shortint::{CiphertextBase, PBSOrderMarker, ServerKey};
+
+impl ShortintEngine {
+    pub fn unchecked_if_then_else<OpOrder: PBSOrderMarker>(
+        &mut self,
+        server_key: &ServerKey,
+        condition: &CiphertextBase<OpOrder>,
+        ct_then: &CiphertextBase<OpOrder>,
+        ct_else: &CiphertextBase<OpOrder>,
+    ) -> EngineResult<CiphertextBase<OpOrder>> {
+        let message_modulus = server_key.message_modulus.0 as u64;
+
+        if server_key.is_packed_if_then_else_possible(condition, ct_then, ct_else) {
+            // Pack the three operands as then * then_factor + else * else_factor + condition, so
+            // that a single PBS selects the branch
+            let else_factor = condition.degree.0 as u64 + 1;
+            let then_factor = else_factor * (ct_else.degree.0 as u64 + 1);
+
+            let mut packed = ct_then.clone();
+            self.unchecked_scalar_mul_assign(&mut packed, (ct_else.degree.0 + 1) as u8)?;
+            self.unchecked_add_assign(&mut packed, ct_else)?;
+            self.unchecked_scalar_mul_assign(&mut packed, else_factor as u8)?;
+            self.unchecked_add_assign(&mut packed, condition)?;
+
+            let acc = self.generate_accumulator(server_key, |x| {
+                let selected = if x % else_factor != 0 {
+                    x / then_factor
+                } else {
+                    (x / else_factor) % (then_factor / else_factor)
+                };
+                selected % message_modulus
+            })?;
+            self.apply_lookup_table_assign(server_key, &mut packed, &acc)?;
+            return Ok(packed);
+        }
+
+        let mut result = self.unchecked_evaluate_bivariate_function(
+            server_key,
+            ct_then,
+            condition,
+            |then_value, condition| if condition != 0 { then_value } else { 0 },
+        )?;
+        let else_part = self.unchecked_evaluate_bivariate_function(
+            server_key,
+            ct_else,
+            condition,
+            |else_value, condition| if condition != 0 { 0 } else { else_value },
+        )?;
+        // One of the two parts is an encryption of 0, so the degree of the sum is the largest of
+        // their degrees
+        let degree = Degree(result.degree.0.max(else_part.degree.0));
+        self.unchecked_add_assign(&mut result, &else_part)?;
+        result.degree = degree;
+        Ok(result)
+    }
+
+    pub fn smart_if_then_else<OpOrder: PBSOrderMarker>(
+        &mut self,
+        server_key: &ServerKey,
+        condition: &mut CiphertextBase<OpOrder>,
+        ct_then: &mut CiphertextBase<OpOrder>,
+        ct_else: &mut CiphertextBase<OpOrder>,
+    ) -> EngineResult<CiphertextBase<OpOrder>> {
+        if !server_key.is_if_then_else_possible(condition, ct_then, ct_else) {
+            self.message_extract_assign(server_key, condition)?;
+            self.message_extract_assign(server_key, ct_then)?;
+            self.message_extract_assign(server_key, ct_else)?;
+        }
+        self.unchecked_if_then_else(server_key, condition, ct_then, ct_else)
+    }
+}
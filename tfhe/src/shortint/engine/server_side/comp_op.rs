@@ -2,7 +2,7 @@ use crate::shortint::engine::{EngineResult, ShortintEngine};
 use crate::shortint::{CiphertextBase, PBSOrderMarker, ServerKey};
 
 impl ShortintEngine {
-    pub(crate) fn unchecked_greater<OpOrder: PBSOrderMarker>(
+    pub fn unchecked_greater<OpOrder: PBSOrderMarker>(
         &mut self,
         server_key: &ServerKey,
         ct_left: &CiphertextBase<OpOrder>,
@@ -28,7 +28,7 @@ impl ShortintEngine {
         Ok(())
     }
 
-    pub(crate) fn smart_greater<OpOrder: PBSOrderMarker>(
+    pub fn smart_greater<OpOrder: PBSOrderMarker>(
         &mut self,
         server_key: &ServerKey,
         ct_left: &mut CiphertextBase<OpOrder>,
@@ -39,7 +39,7 @@ impl ShortintEngine {
         Ok(result)
     }
 
-    pub(crate) fn smart_greater_assign<OpOrder: PBSOrderMarker>(
+    pub fn smart_greater_assign<OpOrder: PBSOrderMarker>(
         &mut self,
         server_key: &ServerKey,
         ct_left: &mut CiphertextBase<OpOrder>,
@@ -54,7 +54,7 @@ impl ShortintEngine {
         Ok(())
     }
 
-    pub(crate) fn unchecked_greater_or_equal<OpOrder: PBSOrderMarker>(
+    pub fn unchecked_greater_or_equal<OpOrder: PBSOrderMarker>(
         &mut self,
         server_key: &ServerKey,
         ct_left: &CiphertextBase<OpOrder>,
@@ -80,7 +80,7 @@ impl ShortintEngine {
         Ok(())
     }
 
-    pub(crate) fn smart_greater_or_equal<OpOrder: PBSOrderMarker>(
+    pub fn smart_greater_or_equal<OpOrder: PBSOrderMarker>(
         &mut self,
         server_key: &ServerKey,
         ct_left: &mut CiphertextBase<OpOrder>,
@@ -91,7 +91,7 @@ impl ShortintEngine {
         Ok(result)
     }
 
-    pub(crate) fn smart_greater_or_equal_assign<OpOrder: PBSOrderMarker>(
+    pub fn smart_greater_or_equal_assign<OpOrder: PBSOrderMarker>(
         &mut self,
         server_key: &ServerKey,
         ct_left: &mut CiphertextBase<OpOrder>,
@@ -105,7 +105,7 @@ impl ShortintEngine {
         Ok(())
     }
 
-    pub(crate) fn unchecked_less<OpOrder: PBSOrderMarker>(
+    pub fn unchecked_less<OpOrder: PBSOrderMarker>(
         &mut self,
         server_key: &ServerKey,
         ct_left: &CiphertextBase<OpOrder>,
@@ -131,7 +131,7 @@ impl ShortintEngine {
         Ok(())
     }
 
-    pub(crate) fn smart_less<OpOrder: PBSOrderMarker>(
+    pub fn smart_less<OpOrder: PBSOrderMarker>(
         &mut self,
         server_key: &ServerKey,
         ct_left: &mut CiphertextBase<OpOrder>,
@@ -142,7 +142,7 @@ impl ShortintEngine {
         Ok(result)
     }
 
-    pub(crate) fn smart_less_assign<OpOrder: PBSOrderMarker>(
+    pub fn smart_less_assign<OpOrder: PBSOrderMarker>(
         &mut self,
         server_key: &ServerKey,
         ct_left: &mut CiphertextBase<OpOrder>,
@@ -156,7 +156,7 @@ impl ShortintEngine {
         Ok(())
     }
 
-    pub(crate) fn unchecked_less_or_equal<OpOrder: PBSOrderMarker>(
+    pub fn unchecked_less_or_equal<OpOrder: PBSOrderMarker>(
         &mut self,
         server_key: &ServerKey,
         ct_left: &CiphertextBase<OpOrder>,
@@ -182,7 +182,7 @@ impl ShortintEngine {
         Ok(())
     }
 
-    pub(crate) fn smart_less_or_equal<OpOrder: PBSOrderMarker>(
+    pub fn smart_less_or_equal<OpOrder: PBSOrderMarker>(
         &mut self,
         server_key: &ServerKey,
         ct_left: &mut CiphertextBase<OpOrder>,
@@ -193,7 +193,7 @@ impl ShortintEngine {
         Ok(result)
     }
 
-    pub(crate) fn smart_less_or_equal_assign<OpOrder: PBSOrderMarker>(
+    pub fn smart_less_or_equal_assign<OpOrder: PBSOrderMarker>(
         &mut self,
         server_key: &ServerKey,
         ct_left: &mut CiphertextBase<OpOrder>,
@@ -207,7 +207,7 @@ impl ShortintEngine {
         Ok(())
     }
 
-    pub(crate) fn unchecked_equal<OpOrder: PBSOrderMarker>(
+    pub fn unchecked_equal<OpOrder: PBSOrderMarker>(
         &mut self,
         server_key: &ServerKey,
         ct_left: &CiphertextBase<OpOrder>,
@@ -233,7 +233,7 @@ impl ShortintEngine {
         Ok(())
     }
 
-    pub(crate) fn smart_equal<OpOrder: PBSOrderMarker>(
+    pub fn smart_equal<OpOrder: PBSOrderMarker>(
         &mut self,
         server_key: &ServerKey,
         ct_left: &mut CiphertextBase<OpOrder>,
@@ -244,7 +244,7 @@ impl ShortintEngine {
         Ok(result)
     }
 
-    pub(crate) fn smart_equal_assign<OpOrder: PBSOrderMarker>(
+    pub fn smart_equal_assign<OpOrder: PBSOrderMarker>(
         &mut self,
         server_key: &ServerKey,
         ct_left: &mut CiphertextBase<OpOrder>,
@@ -258,7 +258,7 @@ impl ShortintEngine {
         Ok(())
     }
 
-    pub(crate) fn smart_scalar_equal<OpOrder: PBSOrderMarker>(
+    pub fn smart_scalar_equal<OpOrder: PBSOrderMarker>(
         &mut self,
         server_key: &ServerKey,
         ct_left: &CiphertextBase<OpOrder>,
@@ -283,7 +283,7 @@ impl ShortintEngine {
         Ok(())
     }
 
-    pub(crate) fn unchecked_not_equal<OpOrder: PBSOrderMarker>(
+    pub fn unchecked_not_equal<OpOrder: PBSOrderMarker>(
         &mut self,
         server_key: &ServerKey,
         ct_left: &CiphertextBase<OpOrder>,
@@ -309,7 +309,7 @@ impl ShortintEngine {
         Ok(())
     }
 
-    pub(crate) fn smart_not_equal<OpOrder: PBSOrderMarker>(
+    pub fn smart_not_equal<OpOrder: PBSOrderMarker>(
         &mut self,
         server_key: &ServerKey,
         ct_left: &mut CiphertextBase<OpOrder>,
@@ -320,7 +320,7 @@ impl ShortintEngine {
         Ok(result)
     }
 
-    pub(crate) fn smart_not_equal_assign<OpOrder: PBSOrderMarker>(
+    pub fn smart_not_equal_assign<OpOrder: PBSOrderMarker>(
         &mut self,
         server_key: &ServerKey,
         ct_left: &mut CiphertextBase<OpOrder>,
@@ -334,7 +334,7 @@ impl ShortintEngine {
         Ok(())
     }
 
-    pub(crate) fn smart_scalar_not_equal<OpOrder: PBSOrderMarker>(
+    pub fn smart_scalar_not_equal<OpOrder: PBSOrderMarker>(
         &mut self,
         server_key: &ServerKey,
         ct_left: &CiphertextBase<OpOrder>,
@@ -359,7 +359,7 @@ impl ShortintEngine {
         Ok(())
     }
 
-    pub(crate) fn smart_scalar_greater_or_equal<OpOrder: PBSOrderMarker>(
+    pub fn smart_scalar_greater_or_equal<OpOrder: PBSOrderMarker>(
         &mut self,
         server_key: &ServerKey,
         ct_left: &CiphertextBase<OpOrder>,
@@ -382,7 +382,7 @@ impl ShortintEngine {
         Ok(())
     }
 
-    pub(crate) fn smart_scalar_less_or_equal<OpOrder: PBSOrderMarker>(
+    pub fn smart_scalar_less_or_equal<OpOrder: PBSOrderMarker>(
         &mut self,
         server_key: &ServerKey,
         ct_left: &CiphertextBase<OpOrder>,
@@ -405,7 +405,7 @@ impl ShortintEngine {
         Ok(())
     }
 
-    pub(crate) fn smart_scalar_greater<OpOrder: PBSOrderMarker>(
+    pub fn smart_scalar_greater<OpOrder: PBSOrderMarker>(
         &mut self,
         server_key: &ServerKey,
         ct_left: &CiphertextBase<OpOrder>,
@@ -428,7 +428,7 @@ impl ShortintEngine {
         Ok(())
     }
 
-    pub(crate) fn smart_scalar_less<OpOrder: PBSOrderMarker>(
+    pub fn smart_scalar_less<OpOrder: PBSOrderMarker>(
         &mut self,
         server_key: &ServerKey,
         ct_left: &CiphertextBase<OpOrder>,
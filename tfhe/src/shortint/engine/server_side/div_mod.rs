@@ -1,9 +1,9 @@
-use crate::shortint::ciphertext::Degree;
+use crate::shortint::ciphertext::{Degree, NoiseLevel};
 use crate::shortint::engine::{EngineResult, ShortintEngine};
 use crate::shortint::{CiphertextBase, PBSOrderMarker, ServerKey};
 
 // Specific division function returning 0 in case of a division by 0
-pub(crate) fn safe_division(x: u64, y: u64) -> u64 {
+pub fn safe_division(x: u64, y: u64) -> u64 {
     if y == 0 {
         0
     } else {
@@ -11,8 +11,18 @@ pub(crate) fn safe_division(x: u64, y: u64) -> u64 {
     }
 }
 
+// Specific modulo function returning x in case of a modulo by 0, so that
+// x == safe_division(x, y) * y + safe_modulo(x, y) holds for any y
+pub fn safe_modulo(x: u64, y: u64) -> u64 {
+    if y == 0 {
+        x
+    } else {
+        x % y
+    }
+}
+
 impl ShortintEngine {
-    pub(crate) fn unchecked_div<OpOrder: PBSOrderMarker>(
+    pub fn unchecked_div<OpOrder: PBSOrderMarker>(
         &mut self,
         server_key: &ServerKey,
         ct_left: &CiphertextBase<OpOrder>,
@@ -23,7 +33,7 @@ impl ShortintEngine {
         Ok(result)
     }
 
-    pub(crate) fn unchecked_div_assign<OpOrder: PBSOrderMarker>(
+    pub fn unchecked_div_assign<OpOrder: PBSOrderMarker>(
         &mut self,
         server_key: &ServerKey,
         ct_left: &mut CiphertextBase<OpOrder>,
@@ -38,7 +48,7 @@ impl ShortintEngine {
         Ok(())
     }
 
-    pub(crate) fn smart_div<OpOrder: PBSOrderMarker>(
+    pub fn smart_div<OpOrder: PBSOrderMarker>(
         &mut self,
         server_key: &ServerKey,
         ct_left: &mut CiphertextBase<OpOrder>,
@@ -49,16 +59,84 @@ impl ShortintEngine {
         Ok(result)
     }
 
-    pub(crate) fn smart_div_assign<OpOrder: PBSOrderMarker>(
+    pub fn smart_div_assign<OpOrder: PBSOrderMarker>(
+        &mut self,
+        server_key: &ServerKey,
+        ct_left: &mut CiphertextBase<OpOrder>,
+        ct_right: &mut CiphertextBase<OpOrder>,
+    ) -> EngineResult<()> {
+        self.prepare_smart_division(server_key, ct_left, ct_right)?;
+        self.unchecked_div_assign(server_key, ct_left, ct_right)?;
+        Ok(())
+    }
+
+    pub fn unchecked_mod<OpOrder: PBSOrderMarker>(
+        &mut self,
+        server_key: &ServerKey,
+        ct_left: &CiphertextBase<OpOrder>,
+        ct_right: &CiphertextBase<OpOrder>,
+    ) -> EngineResult<CiphertextBase<OpOrder>> {
+        let mut result = ct_left.clone();
+        self.unchecked_mod_assign(server_key, &mut result, ct_right)?;
+        Ok(result)
+    }
+
+    pub fn unchecked_mod_assign<OpOrder: PBSOrderMarker>(
+        &mut self,
+        server_key: &ServerKey,
+        ct_left: &mut CiphertextBase<OpOrder>,
+        ct_right: &CiphertextBase<OpOrder>,
+    ) -> EngineResult<()> {
+        self.unchecked_evaluate_bivariate_function_assign(
+            server_key,
+            ct_left,
+            ct_right,
+            safe_modulo,
+        )?;
+        Ok(())
+    }
+
+    pub fn smart_mod<OpOrder: PBSOrderMarker>(
+        &mut self,
+        server_key: &ServerKey,
+        ct_left: &mut CiphertextBase<OpOrder>,
+        ct_right: &mut CiphertextBase<OpOrder>,
+    ) -> EngineResult<CiphertextBase<OpOrder>> {
+        let mut result = ct_left.clone();
+        self.smart_mod_assign(server_key, &mut result, ct_right)?;
+        Ok(result)
+    }
+
+    pub fn smart_mod_assign<OpOrder: PBSOrderMarker>(
+        &mut self,
+        server_key: &ServerKey,
+        ct_left: &mut CiphertextBase<OpOrder>,
+        ct_right: &mut CiphertextBase<OpOrder>,
+    ) -> EngineResult<()> {
+        self.prepare_smart_division(server_key, ct_left, ct_right)?;
+        self.unchecked_mod_assign(server_key, ct_left, ct_right)?;
+        Ok(())
+    }
+
+    /// Clears the carries of the operands of a division or a modulo which cannot be computed with
+    /// a bivariate PBS, only clearing one of them when it is enough.
+    fn prepare_smart_division<OpOrder: PBSOrderMarker>(
         &mut self,
         server_key: &ServerKey,
         ct_left: &mut CiphertextBase<OpOrder>,
         ct_right: &mut CiphertextBase<OpOrder>,
     ) -> EngineResult<()> {
         if !server_key.is_functional_bivariate_pbs_possible(ct_left, ct_right) {
-            if ct_left.message_modulus.0 + ct_right.degree.0 <= server_key.max_degree.0 {
+            let max_noise_level = server_key.max_noise_level();
+            if ct_left.message_modulus.0 + ct_right.degree.0 <= server_key.max_degree.0
+                && max_noise_level
+                    .validate(NoiseLevel::NOMINAL * (ct_right.degree.0 + 1) + ct_right.noise_level)
+            {
                 self.message_extract_assign(server_key, ct_left)?;
             } else if ct_right.message_modulus.0 + (ct_left.degree.0 + 1) <= server_key.max_degree.0
+                && max_noise_level.validate(
+                    ct_left.noise_level * ct_right.message_modulus.0 + NoiseLevel::NOMINAL,
+                )
             {
                 self.message_extract_assign(server_key, ct_right)?;
             } else {
@@ -66,14 +144,13 @@ impl ShortintEngine {
                 self.message_extract_assign(server_key, ct_right)?;
             }
         }
-        self.unchecked_div_assign(server_key, ct_left, ct_right)?;
         Ok(())
     }
 
     /// # Panics
     ///
     /// This function will panic if `scalar == 0`
-    pub(crate) fn unchecked_scalar_div<OpOrder: PBSOrderMarker>(
+    pub fn unchecked_scalar_div<OpOrder: PBSOrderMarker>(
         &mut self,
         server_key: &ServerKey,
         ct: &CiphertextBase<OpOrder>,
@@ -87,7 +164,7 @@ impl ShortintEngine {
     /// # Panics
     ///
     /// This function will panic if `scalar == 0`
-    pub(crate) fn unchecked_scalar_div_assign<OpOrder: PBSOrderMarker>(
+    pub fn unchecked_scalar_div_assign<OpOrder: PBSOrderMarker>(
         &mut self,
         server_key: &ServerKey,
         ct: &mut CiphertextBase<OpOrder>,
@@ -101,7 +178,7 @@ impl ShortintEngine {
         Ok(())
     }
 
-    pub(crate) fn unchecked_scalar_mod<OpOrder: PBSOrderMarker>(
+    pub fn unchecked_scalar_mod<OpOrder: PBSOrderMarker>(
         &mut self,
         server_key: &ServerKey,
         ct: &CiphertextBase<OpOrder>,
@@ -115,7 +192,7 @@ impl ShortintEngine {
     /// # Panics
     ///
     /// This function will panic if `modulus == 0`
-    pub(crate) fn unchecked_scalar_mod_assign<OpOrder: PBSOrderMarker>(
+    pub fn unchecked_scalar_mod_assign<OpOrder: PBSOrderMarker>(
         &mut self,
         server_key: &ServerKey,
         ct: &mut CiphertextBase<OpOrder>,
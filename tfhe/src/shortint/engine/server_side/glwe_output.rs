@@ -0,0 +1,129 @@
+use crate::core_crypto::algorithms::*;
+use crate::core_crypto::commons::parameters::MonomialDegree;
+use crate::core_crypto::entities::*;
+use crate::core_crypto::fft_impl::fft64::math::fft::Fft;
+use crate::shortint::ciphertext::{Degree, NoiseLevel};
+use crate::shortint::engine::{EngineResult, ShortintEngine};
+use crate::shortint::server_key::{GlweAccumulator, LookupTableOwned};
+use crate::shortint::{CiphertextBase, PBSOrder, PBSOrderMarker, ServerKey};
+use std::marker::PhantomData;
+
+impl ShortintEngine {
+    pub fn apply_lookup_table_to_glwe<OpOrder: PBSOrderMarker>(
+        &mut self,
+        server_key: &ServerKey,
+        ct: &CiphertextBase<OpOrder>,
+        acc: &LookupTableOwned,
+    ) -> EngineResult<GlweAccumulator> {
+        let (mut ciphertext_buffers, buffers) =
+            self.get_carry_clearing_accumulator_and_buffers(server_key);
+
+        let fourier_bsk: &FourierLweBootstrapKeyOwned =
+            &server_key.bootstrapping_key.to_fourier()?;
+
+        let fft = Fft::new(fourier_bsk.polynomial_size());
+        let fft = fft.as_view();
+        buffers.resize(
+            blind_rotate_assign_mem_optimized_requirement::<u64>(
+                fourier_bsk.glwe_size(),
+                fourier_bsk.polynomial_size(),
+                fft,
+            )
+            .unwrap()
+            .unaligned_bytes_required(),
+        );
+        let stack = buffers.stack();
+
+        let mut glwe = acc.acc.clone();
+
+        match OpOrder::pbs_order() {
+            PBSOrder::KeyswitchBootstrap => {
+                keyswitch_lwe_ciphertext(
+                    &server_key.key_switching_key,
+                    &ct.ct,
+                    &mut ciphertext_buffers.buffer_lwe_after_ks,
+                );
+                blind_rotate_assign_mem_optimized(
+                    &ciphertext_buffers.buffer_lwe_after_ks,
+                    &mut glwe,
+                    fourier_bsk,
+                    fft,
+                    stack,
+                );
+            }
+            PBSOrder::BootstrapKeyswitch => {
+                blind_rotate_assign_mem_optimized(&ct.ct, &mut glwe, fourier_bsk, fft, stack);
+            }
+        }
+
+        Ok(GlweAccumulator {
+            glwe,
+            degree: acc.degree,
+            noise_level: NoiseLevel::NOMINAL,
+            message_modulus: ct.message_modulus,
+            carry_modulus: ct.carry_modulus,
+            parameter_version: ct.parameter_version,
+        })
+    }
+
+    pub fn extract_from_glwe<OutOrder: PBSOrderMarker>(
+        &mut self,
+        server_key: &ServerKey,
+        acc: &GlweAccumulator,
+    ) -> EngineResult<CiphertextBase<OutOrder>> {
+        let mut ct_big = LweCiphertextOwned::new(
+            0u64,
+            server_key
+                .bootstrapping_key
+                .output_lwe_dimension()
+                .to_lwe_size(),
+            server_key.ciphertext_modulus,
+        );
+        extract_lwe_sample_from_glwe_ciphertext(&acc.glwe, &mut ct_big, MonomialDegree(0));
+
+        let ct_out = match OutOrder::pbs_order() {
+            PBSOrder::KeyswitchBootstrap => ct_big,
+            PBSOrder::BootstrapKeyswitch => {
+                let mut ct_small = LweCiphertextOwned::new(
+                    0u64,
+                    server_key.key_switching_key.output_lwe_size(),
+                    server_key.ciphertext_modulus,
+                );
+                keyswitch_lwe_ciphertext(&server_key.key_switching_key, &ct_big, &mut ct_small);
+                ct_small
+            }
+        };
+
+        Ok(CiphertextBase {
+            ct: ct_out,
+            degree: acc.degree,
+            noise_level: acc.noise_level,
+            message_modulus: acc.message_modulus,
+            carry_modulus: acc.carry_modulus,
+            parameter_version: acc.parameter_version,
+            _order_marker: PhantomData,
+        })
+    }
+
+    pub fn unchecked_glwe_add_assign(
+        &mut self,
+        acc_left: &mut GlweAccumulator,
+        acc_right: &GlweAccumulator,
+    ) -> EngineResult<()> {
+        slice_wrapping_add_assign(acc_left.glwe.as_mut(), acc_right.glwe.as_ref());
+        acc_left.degree = Degree(acc_left.degree.0 + acc_right.degree.0);
+        acc_left.noise_level += acc_right.noise_level;
+        Ok(())
+    }
+
+    pub fn unchecked_glwe_scalar_mul_assign(
+        &mut self,
+        acc: &mut GlweAccumulator,
+        scalar: u8,
+    ) -> EngineResult<()> {
+        slice_wrapping_scalar_mul_assign(acc.glwe.as_mut(), scalar as u64);
+        acc.degree = Degree(acc.degree.0 * scalar as usize);
+        acc.noise_level *= scalar as usize;
+        Ok(())
+    }
+}
@@ -1,24 +1,37 @@
-use super::ShortintEngine;
+use super::{plaintext_on_modulus, ShortintEngine};
 use crate::core_crypto::algorithms::*;
 use crate::core_crypto::commons::ciphertext_modulus::CiphertextModulus;
+use crate::core_crypto::commons::parameters::{
+    LutCountLog, LweBskGroupingFactor, LweCiphertextCount,
+};
 use crate::core_crypto::entities::*;
-use crate::core_crypto::fft_impl::fft64::crypto::bootstrap::FourierLweBootstrapKey;
+use crate::core_crypto::fft_impl::fft64::crypto::bootstrap::{
+    FourierLweBootstrapKey, FourierLweBootstrapKeyOwned,
+};
 use crate::core_crypto::fft_impl::fft64::math::fft::Fft;
-use crate::shortint::ciphertext::Degree;
+use crate::shortint::ciphertext::{Degree, NoiseLevel};
 use crate::shortint::engine::EngineResult;
 use crate::shortint::parameters::MessageModulus;
-use crate::shortint::server_key::{BivariateLookupTableOwned, LookupTableOwned, MaxDegree};
+use crate::shortint::server_key::{
+    BivariateLookupTableOwned, BootstrappingKeyStorage, LookupTableOwned, ManyLookupTableOwned,
+    MaxDegree, ShortintBootstrappingKey,
+};
 use crate::shortint::{
     CiphertextBase, CiphertextBig, CiphertextSmall, ClientKey, CompressedServerKey, PBSOrder,
     PBSOrderMarker, ServerKey,
 };
+use std::sync::Arc;
 
 mod add;
 mod bitwise_op;
+mod cmux;
 mod comp_op;
 mod div_mod;
+mod glwe_output;
 mod mul;
 mod neg;
+mod no_padding;
+mod pbs_order;
 mod scalar_add;
 mod scalar_mul;
 mod scalar_sub;
@@ -26,7 +39,7 @@ mod shift;
 mod sub;
 
 impl ShortintEngine {
-    pub(crate) fn new_server_key(&mut self, cks: &ClientKey) -> EngineResult<ServerKey> {
+    pub fn new_server_key(&mut self, cks: &ClientKey) -> EngineResult<ServerKey> {
         // Plaintext Max Value
         let max_value = cks.parameters.message_modulus().0 * cks.parameters.carry_modulus().0 - 1;
 
@@ -35,18 +48,48 @@ impl ShortintEngine {
         self.new_server_key_with_max_degree(cks, max)
     }
 
-    pub(crate) fn new_server_key_with_max_degree(
+    pub fn new_server_key_with_max_degree(
         &mut self,
         cks: &ClientKey,
         max_degree: MaxDegree,
     ) -> EngineResult<ServerKey> {
+        let bootstrapping_key = match cks.parameters.multi_bit_parameters() {
+            Some(multi_bit_parameters) => ShortintBootstrappingKey::MultiBit(
+                self.new_fourier_multi_bit_bootstrap_key(cks, multi_bit_parameters.grouping_factor),
+            ),
+            None => ShortintBootstrappingKey::Fourier(self.new_fourier_bootstrap_key(cks)),
+        };
+
+        // Creation of the key switching key
+        let key_switching_key = allocate_and_generate_new_lwe_keyswitch_key(
+            &cks.large_lwe_secret_key,
+            &cks.small_lwe_secret_key,
+            cks.parameters.ks_base_log(),
+            cks.parameters.ks_level(),
+            cks.parameters.lwe_noise_distribution(),
+            cks.parameters.ciphertext_modulus(),
+            &mut self.encryption_generator,
+        );
+
+        // Pack the keys in the server key set:
+        Ok(ServerKey {
+            key_switching_key: Arc::new(key_switching_key),
+            bootstrapping_key: Arc::new(bootstrapping_key),
+            message_modulus: cks.parameters.message_modulus(),
+            carry_modulus: cks.parameters.carry_modulus(),
+            max_degree,
+            ciphertext_modulus: cks.parameters.ciphertext_modulus(),
+        })
+    }
+
+    fn new_fourier_bootstrap_key(&mut self, cks: &ClientKey) -> FourierLweBootstrapKeyOwned {
         let bootstrap_key: LweBootstrapKeyOwned<u64> =
             par_allocate_and_generate_new_lwe_bootstrap_key(
                 &cks.small_lwe_secret_key,
                 &cks.glwe_secret_key,
                 cks.parameters.pbs_base_log(),
                 cks.parameters.pbs_level(),
-                cks.parameters.glwe_modular_std_dev(),
+                cks.parameters.glwe_noise_distribution(),
                 cks.parameters.ciphertext_modulus(),
                 &mut self.encryption_generator,
             );
@@ -77,29 +120,55 @@ impl ShortintEngine {
             stack,
         );
 
-        // Creation of the key switching key
-        let key_switching_key = allocate_and_generate_new_lwe_keyswitch_key(
-            &cks.large_lwe_secret_key,
-            &cks.small_lwe_secret_key,
-            cks.parameters.ks_base_log(),
-            cks.parameters.ks_level(),
-            cks.parameters.lwe_modular_std_dev(),
-            cks.parameters.ciphertext_modulus(),
-            &mut self.encryption_generator,
+        fourier_bsk
+    }
+
+    fn new_fourier_multi_bit_bootstrap_key(
+        &mut self,
+        cks: &ClientKey,
+        grouping_factor: LweBskGroupingFactor,
+    ) -> FourierLweMultiBitBootstrapKeyOwned {
+        let bootstrap_key: LweMultiBitBootstrapKeyOwned<u64> =
+            par_allocate_and_generate_new_lwe_multi_bit_bootstrap_key(
+                &cks.small_lwe_secret_key,
+                &cks.glwe_secret_key,
+                cks.parameters.pbs_base_log(),
+                cks.parameters.pbs_level(),
+                grouping_factor,
+                cks.parameters.glwe_noise_distribution(),
+                cks.parameters.ciphertext_modulus(),
+                &mut self.encryption_generator,
+            );
+
+        let mut fourier_bsk = FourierLweMultiBitBootstrapKeyOwned::new(
+            bootstrap_key.input_lwe_dimension(),
+            bootstrap_key.glwe_size(),
+            bootstrap_key.polynomial_size(),
+            bootstrap_key.decomposition_base_log(),
+            bootstrap_key.decomposition_level_count(),
+            grouping_factor,
         );
 
-        // Pack the keys in the server key set:
-        Ok(ServerKey {
-            key_switching_key,
-            bootstrapping_key: fourier_bsk,
-            message_modulus: cks.parameters.message_modulus(),
-            carry_modulus: cks.parameters.carry_modulus(),
-            max_degree,
-            ciphertext_modulus: cks.parameters.ciphertext_modulus(),
-        })
+        let fft = Fft::new(bootstrap_key.polynomial_size());
+        let fft = fft.as_view();
+        self.computation_buffers.resize(
+            convert_standard_lwe_multi_bit_bootstrap_key_to_fourier_mem_optimized_requirement(fft)
+                .unwrap()
+                .unaligned_bytes_required(),
+        );
+        let stack = self.computation_buffers.stack();
+
+        convert_standard_lwe_multi_bit_bootstrap_key_to_fourier_mem_optimized(
+            &bootstrap_key,
+            &mut fourier_bsk,
+            fft,
+            stack,
+        );
+
+        fourier_bsk
     }
 
-    pub(crate) fn new_compressed_server_key(
+    pub fn new_compressed_server_key(
         &mut self,
         cks: &ClientKey,
     ) -> EngineResult<CompressedServerKey> {
@@ -111,18 +180,23 @@ impl ShortintEngine {
         self.new_compressed_server_key_with_max_degree(cks, max)
     }
 
-    pub(crate) fn new_compressed_server_key_with_max_degree(
+    pub fn new_compressed_server_key_with_max_degree(
         &mut self,
         cks: &ClientKey,
         max_degree: MaxDegree,
     ) -> EngineResult<CompressedServerKey> {
+        assert!(
+            !cks.parameters.multi_bit_pbs_only(),
+            "Compressed server keys do not support the multi-bit PBS"
+        );
+
         #[cfg(not(feature = "__wasm_api"))]
         let bootstrapping_key = par_allocate_and_generate_new_seeded_lwe_bootstrap_key(
             &cks.small_lwe_secret_key,
             &cks.glwe_secret_key,
             cks.parameters.pbs_base_log(),
             cks.parameters.pbs_level(),
-            cks.parameters.glwe_modular_std_dev(),
+            cks.parameters.glwe_noise_distribution(),
             cks.parameters.ciphertext_modulus(),
             &mut self.seeder,
         );
@@ -133,7 +207,7 @@ impl ShortintEngine {
             &cks.glwe_secret_key,
             cks.parameters.pbs_base_log(),
             cks.parameters.pbs_level(),
-            cks.parameters.glwe_modular_std_dev(),
+            cks.parameters.glwe_noise_distribution(),
             cks.parameters.ciphertext_modulus(),
             &mut self.seeder,
         );
@@ -144,7 +218,7 @@ impl ShortintEngine {
             &cks.small_lwe_secret_key,
             cks.parameters.ks_base_log(),
             cks.parameters.ks_level(),
-            cks.parameters.lwe_modular_std_dev(),
+            cks.parameters.lwe_noise_distribution(),
             cks.parameters.ciphertext_modulus(),
             &mut self.seeder,
         );
@@ -160,7 +234,7 @@ impl ShortintEngine {
         })
     }
 
-    pub(crate) fn generate_accumulator<F>(
+    pub fn generate_accumulator<F>(
         &mut self,
         server_key: &ServerKey,
         f: F,
@@ -171,7 +245,7 @@ impl ShortintEngine {
         Self::generate_accumulator_with_engine(server_key, f)
     }
 
-    pub(crate) fn keyswitch_bootstrap_assign(
+    pub fn keyswitch_bootstrap_assign(
         &mut self,
         server_key: &ServerKey,
         ct: &mut CiphertextBig,
@@ -180,44 +254,24 @@ impl ShortintEngine {
         let (mut ciphertext_buffers, buffers) =
             self.get_carry_clearing_accumulator_and_buffers(server_key);
 
-        // Compute a keyswitch
-        keyswitch_lwe_ciphertext(
-            &server_key.key_switching_key,
-            &ct.ct,
-            &mut ciphertext_buffers.buffer_lwe_after_ks,
-        );
-
-        let fourier_bsk = &server_key.bootstrapping_key;
-
-        let fft = Fft::new(fourier_bsk.polynomial_size());
-        let fft = fft.as_view();
-        buffers.resize(
-            programmable_bootstrap_lwe_ciphertext_mem_optimized_requirement::<u64>(
-                fourier_bsk.glwe_size(),
-                fourier_bsk.polynomial_size(),
-                fft,
-            )
-            .unwrap()
-            .unaligned_bytes_required(),
-        );
-        let stack = buffers.stack();
-
-        // Compute a bootstrap
-        programmable_bootstrap_lwe_ciphertext_mem_optimized(
-            &ciphertext_buffers.buffer_lwe_after_ks,
-            &mut ct.ct,
-            &ciphertext_buffers.accumulator.acc,
-            fourier_bsk,
-            fft,
-            stack,
-        );
+        // Compute a keyswitch and a bootstrap
+        server_key
+            .bootstrapping_key
+            .keyswitch_programmable_bootstrap_assign(
+                &server_key.key_switching_key,
+                &mut ct.ct,
+                &ciphertext_buffers.accumulator.acc,
+                &mut ciphertext_buffers.buffer_lwe_after_ks,
+                buffers,
+            );
 
         ct.degree = ciphertext_buffers.accumulator.degree;
+        ct.noise_level = NoiseLevel::NOMINAL;
 
         Ok(())
     }
 
-    pub(crate) fn clear_carry<OpOrder: PBSOrderMarker>(
+    pub fn clear_carry<OpOrder: PBSOrderMarker>(
         &mut self,
         server_key: &ServerKey,
         ct: &CiphertextBase<OpOrder>,
@@ -227,7 +281,7 @@ impl ShortintEngine {
         Ok(ct_in)
     }
 
-    pub(crate) fn clear_carry_assign<OpOrder: PBSOrderMarker>(
+    pub fn clear_carry_assign<OpOrder: PBSOrderMarker>(
         &mut self,
         server_key: &ServerKey,
         ct: &mut CiphertextBase<OpOrder>,
@@ -245,7 +299,7 @@ impl ShortintEngine {
         Ok(())
     }
 
-    pub(crate) fn keyswitch_programmable_bootstrap_assign(
+    pub fn keyswitch_programmable_bootstrap_assign(
         &mut self,
         server_key: &ServerKey,
         ct: &mut CiphertextBig,
@@ -255,44 +309,24 @@ impl ShortintEngine {
         let (mut ciphertext_buffers, buffers) =
             self.get_carry_clearing_accumulator_and_buffers(server_key);
 
-        // Compute a key switch
-        keyswitch_lwe_ciphertext(
-            &server_key.key_switching_key,
-            &ct.ct,
-            &mut ciphertext_buffers.buffer_lwe_after_ks,
-        );
-
-        let fourier_bsk = &server_key.bootstrapping_key;
-
-        let fft = Fft::new(fourier_bsk.polynomial_size());
-        let fft = fft.as_view();
-        buffers.resize(
-            programmable_bootstrap_lwe_ciphertext_mem_optimized_requirement::<u64>(
-                fourier_bsk.glwe_size(),
-                fourier_bsk.polynomial_size(),
-                fft,
-            )
-            .unwrap()
-            .unaligned_bytes_required(),
-        );
-        let stack = buffers.stack();
-
-        // Compute a bootstrap
-        programmable_bootstrap_lwe_ciphertext_mem_optimized(
-            &ciphertext_buffers.buffer_lwe_after_ks,
-            &mut ct.ct,
-            &acc.acc,
-            fourier_bsk,
-            fft,
-            stack,
-        );
+        // Compute a key switch and a bootstrap
+        server_key
+            .bootstrapping_key
+            .keyswitch_programmable_bootstrap_assign(
+                &server_key.key_switching_key,
+                &mut ct.ct,
+                &acc.acc,
+                &mut ciphertext_buffers.buffer_lwe_after_ks,
+                buffers,
+            );
 
         ct.degree = acc.degree;
+        ct.noise_level = NoiseLevel::NOMINAL;
 
         Ok(())
     }
 
-    pub(crate) fn unchecked_apply_lookup_table_bivariate<OpOrder: PBSOrderMarker>(
+    pub fn unchecked_apply_lookup_table_bivariate<OpOrder: PBSOrderMarker>(
         &mut self,
         server_key: &ServerKey,
         ct_left: &CiphertextBase<OpOrder>,
@@ -304,7 +338,7 @@ impl ShortintEngine {
         Ok(ct_res)
     }
 
-    pub(crate) fn unchecked_apply_lookup_table_bivariate_assign<OpOrder: PBSOrderMarker>(
+    pub fn unchecked_apply_lookup_table_bivariate_assign<OpOrder: PBSOrderMarker>(
         &mut self,
         server_key: &ServerKey,
         ct_left: &mut CiphertextBase<OpOrder>,
@@ -325,7 +359,7 @@ impl ShortintEngine {
         Ok(())
     }
 
-    pub(crate) fn generate_accumulator_bivariate_with_factor<F>(
+    pub fn generate_accumulator_bivariate_with_factor<F>(
         &mut self,
         server_key: &ServerKey,
         f: F,
@@ -337,7 +371,7 @@ impl ShortintEngine {
         Self::generate_accumulator_bivariate_with_engine(server_key, f, left_message_scaling)
     }
 
-    pub(crate) fn generate_accumulator_bivariate<F>(
+    pub fn generate_accumulator_bivariate<F>(
         &mut self,
         server_key: &ServerKey,
         f: F,
@@ -351,7 +385,7 @@ impl ShortintEngine {
         self.generate_accumulator_bivariate_with_factor(server_key, f, server_key.message_modulus)
     }
 
-    pub(crate) fn unchecked_evaluate_bivariate_function<F, OpOrder: PBSOrderMarker>(
+    pub fn unchecked_evaluate_bivariate_function<F, OpOrder: PBSOrderMarker>(
         &mut self,
         server_key: &ServerKey,
         ct_left: &CiphertextBase<OpOrder>,
@@ -366,7 +400,7 @@ impl ShortintEngine {
         Ok(ct_res)
     }
 
-    pub(crate) fn unchecked_evaluate_bivariate_function_assign<OpOrder: PBSOrderMarker, F>(
+    pub fn unchecked_evaluate_bivariate_function_assign<OpOrder: PBSOrderMarker, F>(
         &mut self,
         server_key: &ServerKey,
         ct_left: &mut CiphertextBase<OpOrder>,
@@ -384,7 +418,7 @@ impl ShortintEngine {
         Ok(())
     }
 
-    pub(crate) fn smart_evaluate_bivariate_function<OpOrder: PBSOrderMarker, F>(
+    pub fn smart_evaluate_bivariate_function<OpOrder: PBSOrderMarker, F>(
         &mut self,
         server_key: &ServerKey,
         ct_left: &mut CiphertextBase<OpOrder>,
@@ -400,7 +434,7 @@ impl ShortintEngine {
         Ok(ct_res)
     }
 
-    pub(crate) fn smart_evaluate_bivariate_function_assign<OpOrder: PBSOrderMarker, F>(
+    pub fn smart_evaluate_bivariate_function_assign<OpOrder: PBSOrderMarker, F>(
         &mut self,
         server_key: &ServerKey,
         ct_left: &mut CiphertextBase<OpOrder>,
@@ -418,7 +452,7 @@ impl ShortintEngine {
         Ok(())
     }
 
-    pub(crate) fn smart_apply_lookup_table_bivariate<OpOrder: PBSOrderMarker>(
+    pub fn smart_apply_lookup_table_bivariate<OpOrder: PBSOrderMarker>(
         &mut self,
         server_key: &ServerKey,
         ct_left: &CiphertextBase<OpOrder>,
@@ -430,7 +464,7 @@ impl ShortintEngine {
         Ok(ct_res)
     }
 
-    pub(crate) fn smart_apply_lookup_table_bivariate_assign<OpOrder: PBSOrderMarker>(
+    pub fn smart_apply_lookup_table_bivariate_assign<OpOrder: PBSOrderMarker>(
         &mut self,
         server_key: &ServerKey,
         ct_left: &mut CiphertextBase<OpOrder>,
@@ -448,7 +482,105 @@ impl ShortintEngine {
         self.unchecked_apply_lookup_table_bivariate_assign(server_key, ct_left, ct_right, acc)
     }
 
-    pub(crate) fn programmable_bootstrap_keyswitch_assign(
+    pub fn unchecked_evaluate_trivariate_function<OpOrder: PBSOrderMarker, F>(
+        &mut self,
+        server_key: &ServerKey,
+        ct_0: &CiphertextBase<OpOrder>,
+        ct_1: &CiphertextBase<OpOrder>,
+        ct_2: &CiphertextBase<OpOrder>,
+        f: F,
+    ) -> EngineResult<CiphertextBase<OpOrder>>
+    where
+        F: Fn(u64, u64, u64) -> u64,
+    {
+        let mut ct_res = ct_0.clone();
+        self.unchecked_evaluate_trivariate_function_assign(server_key, &mut ct_res, ct_1, ct_2, f)?;
+        Ok(ct_res)
+    }
+
+    pub fn unchecked_evaluate_trivariate_function_assign<OpOrder: PBSOrderMarker, F>(
+        &mut self,
+        server_key: &ServerKey,
+        ct_0: &mut CiphertextBase<OpOrder>,
+        ct_1: &CiphertextBase<OpOrder>,
+        ct_2: &CiphertextBase<OpOrder>,
+        f: F,
+    ) -> EngineResult<()>
+    where
+        F: Fn(u64, u64, u64) -> u64,
+    {
+        // The operands are packed as (ct_0 * modulus_1 + ct_1) * modulus_2 + ct_2, each modulus
+        // being the smallest one for which the next operand cannot overflow on the previous ones
+        let modulus_1 = (ct_1.degree.0 + 1) as u64;
+        let modulus_2 = (ct_2.degree.0 + 1) as u64;
+        let message_modulus = server_key.message_modulus.0 as u64;
+        let wrapped_f = |input: u64| -> u64 {
+            let x = (input / (modulus_1 * modulus_2)) % message_modulus;
+            let y = ((input / modulus_2) % modulus_1) % message_modulus;
+            let z = (input % modulus_2) % message_modulus;
+
+            f(x, y, z)
+        };
+        let acc = Self::generate_accumulator_with_engine(server_key, wrapped_f)?;
+
+        self.unchecked_scalar_mul_assign(ct_0, modulus_1 as u8)?;
+        self.unchecked_add_assign(ct_0, ct_1)?;
+        self.unchecked_scalar_mul_assign(ct_0, modulus_2 as u8)?;
+        self.unchecked_add_assign(ct_0, ct_2)?;
+
+        // Compute the PBS
+        self.apply_lookup_table_assign(server_key, ct_0, &acc)?;
+
+        Ok(())
+    }
+
+    pub fn smart_evaluate_trivariate_function<OpOrder: PBSOrderMarker, F>(
+        &mut self,
+        server_key: &ServerKey,
+        ct_0: &mut CiphertextBase<OpOrder>,
+        ct_1: &mut CiphertextBase<OpOrder>,
+        ct_2: &mut CiphertextBase<OpOrder>,
+        f: F,
+    ) -> EngineResult<CiphertextBase<OpOrder>>
+    where
+        F: Fn(u64, u64, u64) -> u64,
+    {
+        let mut ct_res = ct_0.clone();
+        self.smart_evaluate_trivariate_function_assign(server_key, &mut ct_res, ct_1, ct_2, f)?;
+        Ok(ct_res)
+    }
+
+    pub fn smart_evaluate_trivariate_function_assign<OpOrder: PBSOrderMarker, F>(
+        &mut self,
+        server_key: &ServerKey,
+        ct_0: &mut CiphertextBase<OpOrder>,
+        ct_1: &mut CiphertextBase<OpOrder>,
+        ct_2: &mut CiphertextBase<OpOrder>,
+        f: F,
+    ) -> EngineResult<()>
+    where
+        F: Fn(u64, u64, u64) -> u64,
+    {
+        if !server_key.is_functional_trivariate_pbs_possible(ct_0, ct_1, ct_2) {
+            // After the message_extract, the operands are in [0, message_modulus[ and have the
+            // nominal noise level
+            let message_modulus = server_key.message_modulus.0;
+            for ct in [&mut *ct_0, &mut *ct_1, &mut *ct_2] {
+                if ct.degree.0 >= message_modulus || ct.noise_level > NoiseLevel::NOMINAL {
+                    self.message_extract_assign(server_key, ct)?;
+                }
+            }
+            assert!(
+                server_key.is_functional_trivariate_pbs_possible(ct_0, ct_1, ct_2),
+                "The parameters cannot pack these three operands in one ciphertext, even with \
+                empty carries and the nominal noise level"
+            );
+        }
+
+        self.unchecked_evaluate_trivariate_function_assign(server_key, ct_0, ct_1, ct_2, f)
+    }
+
+    pub fn programmable_bootstrap_keyswitch_assign(
         &mut self,
         server_key: &ServerKey,
         ct: &mut CiphertextSmall,
@@ -457,29 +589,12 @@ impl ShortintEngine {
         let (mut ciphertext_buffers, buffers) =
             self.get_carry_clearing_accumulator_and_buffers(server_key);
 
-        let fourier_bsk = &server_key.bootstrapping_key;
-
-        let fft = Fft::new(fourier_bsk.polynomial_size());
-        let fft = fft.as_view();
-        buffers.resize(
-            programmable_bootstrap_lwe_ciphertext_mem_optimized_requirement::<u64>(
-                fourier_bsk.glwe_size(),
-                fourier_bsk.polynomial_size(),
-                fft,
-            )
-            .unwrap()
-            .unaligned_bytes_required(),
-        );
-        let stack = buffers.stack();
-
         // Compute a bootstrap
-        programmable_bootstrap_lwe_ciphertext_mem_optimized(
+        server_key.bootstrapping_key.programmable_bootstrap(
             &ct.ct,
             &mut ciphertext_buffers.buffer_lwe_after_pbs,
             &acc.acc,
-            fourier_bsk,
-            fft,
-            stack,
+            buffers,
         );
 
         // Compute a key switch
@@ -490,11 +605,12 @@ impl ShortintEngine {
         );
 
         ct.degree = acc.degree;
+        ct.noise_level = NoiseLevel::NOMINAL;
 
         Ok(())
     }
 
-    pub(crate) fn bootstrap_keyswitch_assign(
+    pub fn bootstrap_keyswitch_assign(
         &mut self,
         server_key: &ServerKey,
         ct: &mut CiphertextSmall,
@@ -503,29 +619,12 @@ impl ShortintEngine {
         let (mut ciphertext_buffers, buffers) =
             self.get_carry_clearing_accumulator_and_buffers(server_key);
 
-        let fourier_bsk = &server_key.bootstrapping_key;
-
-        let fft = Fft::new(fourier_bsk.polynomial_size());
-        let fft = fft.as_view();
-        buffers.resize(
-            programmable_bootstrap_lwe_ciphertext_mem_optimized_requirement::<u64>(
-                fourier_bsk.glwe_size(),
-                fourier_bsk.polynomial_size(),
-                fft,
-            )
-            .unwrap()
-            .unaligned_bytes_required(),
-        );
-        let stack = buffers.stack();
-
         // Compute a bootstrap
-        programmable_bootstrap_lwe_ciphertext_mem_optimized(
+        server_key.bootstrapping_key.programmable_bootstrap(
             &ct.ct,
             &mut ciphertext_buffers.buffer_lwe_after_pbs,
             &ciphertext_buffers.accumulator.acc,
-            fourier_bsk,
-            fft,
-            stack,
+            buffers,
         );
 
         // Compute a keyswitch
@@ -536,11 +635,12 @@ impl ShortintEngine {
         );
 
         ct.degree = ciphertext_buffers.accumulator.degree;
+        ct.noise_level = NoiseLevel::NOMINAL;
 
         Ok(())
     }
 
-    pub(crate) fn apply_lookup_table_assign<OpOrder: PBSOrderMarker>(
+    pub fn apply_lookup_table_assign<OpOrder: PBSOrderMarker>(
         &mut self,
         server_key: &ServerKey,
         ct: &mut CiphertextBase<OpOrder>,
@@ -564,7 +664,7 @@ impl ShortintEngine {
         Ok(())
     }
 
-    pub(crate) fn apply_lookup_table<OpOrder: PBSOrderMarker>(
+    pub fn apply_lookup_table<OpOrder: PBSOrderMarker>(
         &mut self,
         server_key: &ServerKey,
         ct: &CiphertextBase<OpOrder>,
@@ -577,7 +677,7 @@ impl ShortintEngine {
         Ok(ct_res)
     }
 
-    pub(crate) fn apply_msg_identity_lut_assign<OpOrder: PBSOrderMarker>(
+    pub fn apply_msg_identity_lut_assign<OpOrder: PBSOrderMarker>(
         &mut self,
         server_key: &ServerKey,
         ct: &mut CiphertextBase<OpOrder>,
@@ -600,7 +700,7 @@ impl ShortintEngine {
         Ok(())
     }
 
-    pub(crate) fn carry_extract_assign<OpOrder: PBSOrderMarker>(
+    pub fn carry_extract_assign<OpOrder: PBSOrderMarker>(
         &mut self,
         server_key: &ServerKey,
         ct: &mut CiphertextBase<OpOrder>,
@@ -614,7 +714,7 @@ impl ShortintEngine {
         Ok(())
     }
 
-    pub(crate) fn carry_extract<OpOrder: PBSOrderMarker>(
+    pub fn carry_extract<OpOrder: PBSOrderMarker>(
         &mut self,
         server_key: &ServerKey,
         ct: &CiphertextBase<OpOrder>,
@@ -624,7 +724,7 @@ impl ShortintEngine {
         Ok(result)
     }
 
-    pub(crate) fn message_extract_assign<OpOrder: PBSOrderMarker>(
+    pub fn message_extract_assign<OpOrder: PBSOrderMarker>(
         &mut self,
         server_key: &ServerKey,
         ct: &mut CiphertextBase<OpOrder>,
@@ -638,7 +738,7 @@ impl ShortintEngine {
         Ok(())
     }
 
-    pub(crate) fn message_extract<OpOrder: PBSOrderMarker>(
+    pub fn message_extract<OpOrder: PBSOrderMarker>(
         &mut self,
         server_key: &ServerKey,
         ct: &CiphertextBase<OpOrder>,
@@ -648,8 +748,167 @@ impl ShortintEngine {
         Ok(result)
     }
 
+    /// Extracts the carry and the message of a ciphertext with a single bootstrap evaluating
+    /// both look-up tables, or with two bootstraps if the polynomial size does not leave enough
+    /// room to pack two look-up tables.
+    pub fn carry_and_message_extract<OpOrder: PBSOrderMarker>(
+        &mut self,
+        server_key: &ServerKey,
+        ct: &CiphertextBase<OpOrder>,
+    ) -> EngineResult<(CiphertextBase<OpOrder>, CiphertextBase<OpOrder>)> {
+        let modulus_sup = server_key.message_modulus.0 * server_key.carry_modulus.0;
+        let box_size = server_key.bootstrapping_key.polynomial_size().0 / modulus_sup;
+        // Each half box must hold at least one coefficient per look-up table, and the many-LUT
+        // bootstrap needs a key in the Fourier domain
+        if box_size < 4
+            || server_key.bootstrapping_key_storage() != BootstrappingKeyStorage::Fourier
+        {
+            let carry = self.carry_extract(server_key, ct)?;
+            let message = self.message_extract(server_key, ct)?;
+            return Ok((carry, message));
+        }
+
+        let modulus = ct.message_modulus.0 as u64;
+        let carry_lut = |x: u64| x / modulus;
+        let message_lut = |x: u64| x % modulus;
+        let acc = self.generate_many_accumulator(server_key, &[&carry_lut, &message_lut])?;
+
+        let mut results = self
+            .apply_many_lookup_tables(server_key, ct, &acc)?
+            .into_iter();
+        let carry = results.next().unwrap();
+        let message = results.next().unwrap();
+        Ok((carry, message))
+    }
+
+    pub fn generate_many_accumulator(
+        &mut self,
+        server_key: &ServerKey,
+        functions: &[&dyn Fn(u64) -> u64],
+    ) -> EngineResult<ManyLookupTableOwned> {
+        let mut acc = GlweCiphertext::new(
+            0,
+            server_key.bootstrapping_key.glwe_size(),
+            server_key.bootstrapping_key.polynomial_size(),
+            server_key.ciphertext_modulus,
+        );
+        let max_values = super::fill_many_lut_accumulator(&mut acc, server_key, functions);
+
+        Ok(ManyLookupTableOwned {
+            acc,
+            degrees: max_values
+                .into_iter()
+                .map(|max_value| Degree(max_value as usize))
+                .collect(),
+        })
+    }
+
+    pub fn apply_many_lookup_tables<OpOrder: PBSOrderMarker>(
+        &mut self,
+        server_key: &ServerKey,
+        ct: &CiphertextBase<OpOrder>,
+        acc: &ManyLookupTableOwned,
+    ) -> EngineResult<Vec<CiphertextBase<OpOrder>>> {
+        let function_count = acc.function_count();
+        let lut_count_log = LutCountLog(function_count.ilog2() as usize);
+
+        let (mut ciphertext_buffers, buffers) =
+            self.get_carry_clearing_accumulator_and_buffers(server_key);
+
+        let fourier_bsk: &FourierLweBootstrapKeyOwned =
+            &server_key.bootstrapping_key.to_fourier()?;
+
+        let fft = Fft::new(fourier_bsk.polynomial_size());
+        let fft = fft.as_view();
+        buffers.resize(
+            many_lut_programmable_bootstrap_lwe_ciphertext_mem_optimized_requirement::<u64>(
+                fourier_bsk.glwe_size(),
+                fourier_bsk.polynomial_size(),
+                fft,
+            )
+            .unwrap()
+            .unaligned_bytes_required(),
+        );
+        let stack = buffers.stack();
+
+        let mut outputs_after_pbs = LweCiphertextList::new(
+            0,
+            fourier_bsk.output_lwe_dimension().to_lwe_size(),
+            LweCiphertextCount(function_count),
+            server_key.ciphertext_modulus,
+        );
+
+        let outputs = match OpOrder::pbs_order() {
+            PBSOrder::KeyswitchBootstrap => {
+                keyswitch_lwe_ciphertext(
+                    &server_key.key_switching_key,
+                    &ct.ct,
+                    &mut ciphertext_buffers.buffer_lwe_after_ks,
+                );
+
+                many_lut_programmable_bootstrap_lwe_ciphertext_mem_optimized(
+                    &ciphertext_buffers.buffer_lwe_after_ks,
+                    &mut outputs_after_pbs,
+                    &acc.acc,
+                    fourier_bsk,
+                    lut_count_log,
+                    fft,
+                    stack,
+                );
+
+                outputs_after_pbs
+                    .iter()
+                    .map(|lwe| {
+                        LweCiphertext::from_container(
+                            lwe.as_ref().to_vec(),
+                            server_key.ciphertext_modulus,
+                        )
+                    })
+                    .collect::<Vec<_>>()
+            }
+            PBSOrder::BootstrapKeyswitch => {
+                many_lut_programmable_bootstrap_lwe_ciphertext_mem_optimized(
+                    &ct.ct,
+                    &mut outputs_after_pbs,
+                    &acc.acc,
+                    fourier_bsk,
+                    lut_count_log,
+                    fft,
+                    stack,
+                );
+
+                outputs_after_pbs
+                    .iter()
+                    .map(|lwe| {
+                        let mut output = LweCiphertext::new(
+                            0,
+                            server_key.key_switching_key.output_lwe_size(),
+                            server_key.ciphertext_modulus,
+                        );
+                        keyswitch_lwe_ciphertext(&server_key.key_switching_key, &lwe, &mut output);
+                        output
+                    })
+                    .collect::<Vec<_>>()
+            }
+        };
+
+        Ok(outputs
+            .into_iter()
+            .zip(acc.degrees.iter())
+            .map(|(lwe, degree)| CiphertextBase {
+                ct: lwe,
+                degree: *degree,
+                noise_level: NoiseLevel::NOMINAL,
+                message_modulus: ct.message_modulus,
+                carry_modulus: ct.carry_modulus,
+                parameter_version: ct.parameter_version,
+                _order_marker: Default::default(),
+            })
+            .collect())
+    }
+
     // Impossible to call the assign function in this case
-    pub(crate) fn create_trivial<OpOrder: PBSOrderMarker>(
+    pub fn create_trivial<OpOrder: PBSOrderMarker>(
         &mut self,
         server_key: &ServerKey,
         value: u64,
@@ -673,7 +932,7 @@ impl ShortintEngine {
 
         let shifted_value = (modular_value as u64) * delta;
 
-        let encoded = Plaintext(shifted_value);
+        let encoded = plaintext_on_modulus(shifted_value, ciphertext_modulus);
 
         let ct = allocate_and_trivially_encrypt_new_lwe_ciphertext(
             lwe_size,
@@ -686,13 +945,15 @@ impl ShortintEngine {
         Ok(CiphertextBase {
             ct,
             degree,
+            noise_level: NoiseLevel::ZERO,
             message_modulus: server_key.message_modulus,
             carry_modulus: server_key.carry_modulus,
+            parameter_version: None,
             _order_marker: Default::default(),
         })
     }
 
-    pub(crate) fn create_trivial_assign<OpOrder: PBSOrderMarker>(
+    pub fn create_trivial_assign<OpOrder: PBSOrderMarker>(
         &mut self,
         server_key: &ServerKey,
         ct: &mut CiphertextBase<OpOrder>,
@@ -705,11 +966,13 @@ impl ShortintEngine {
 
         let shifted_value = (modular_value as u64) * delta;
 
-        let encoded = Plaintext(shifted_value);
+        let encoded = plaintext_on_modulus(shifted_value, ct.ct.ciphertext_modulus());
 
         trivially_encrypt_lwe_ciphertext(&mut ct.ct, encoded);
 
         ct.degree = Degree(modular_value);
+        ct.noise_level = NoiseLevel::ZERO;
+        ct.parameter_version = None;
         Ok(())
     }
 }
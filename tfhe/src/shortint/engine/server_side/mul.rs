@@ -1,9 +1,9 @@
-use crate::shortint::ciphertext::Degree;
+use crate::shortint::ciphertext::{Degree, NoiseLevel};
 use crate::shortint::engine::{EngineResult, ShortintEngine};
 use crate::shortint::{CiphertextBase, PBSOrderMarker, ServerKey};
 
 impl ShortintEngine {
-    pub(crate) fn unchecked_mul_lsb<OpOrder: PBSOrderMarker>(
+    pub fn unchecked_mul_lsb<OpOrder: PBSOrderMarker>(
         &mut self,
         server_key: &ServerKey,
         ct_left: &CiphertextBase<OpOrder>,
@@ -14,7 +14,7 @@ impl ShortintEngine {
         Ok(result)
     }
 
-    pub(crate) fn unchecked_mul_lsb_assign<OpOrder: PBSOrderMarker>(
+    pub fn unchecked_mul_lsb_assign<OpOrder: PBSOrderMarker>(
         &mut self,
         server_key: &ServerKey,
         ct_left: &mut CiphertextBase<OpOrder>,
@@ -41,7 +41,7 @@ impl ShortintEngine {
         Ok(())
     }
 
-    pub(crate) fn unchecked_mul_msb<OpOrder: PBSOrderMarker>(
+    pub fn unchecked_mul_msb<OpOrder: PBSOrderMarker>(
         &mut self,
         server_key: &ServerKey,
         ct_left: &CiphertextBase<OpOrder>,
@@ -53,7 +53,7 @@ impl ShortintEngine {
         Ok(result)
     }
 
-    pub(crate) fn unchecked_mul_msb_assign<OpOrder: PBSOrderMarker>(
+    pub fn unchecked_mul_msb_assign<OpOrder: PBSOrderMarker>(
         &mut self,
         server_key: &ServerKey,
         ct_left: &mut CiphertextBase<OpOrder>,
@@ -82,7 +82,7 @@ impl ShortintEngine {
         Ok(())
     }
 
-    pub(crate) fn unchecked_mul_lsb_small_carry_modulus<OpOrder: PBSOrderMarker>(
+    pub fn unchecked_mul_lsb_small_carry_modulus<OpOrder: PBSOrderMarker>(
         &mut self,
         server_key: &ServerKey,
         ct1: &mut CiphertextBase<OpOrder>,
@@ -111,7 +111,7 @@ impl ShortintEngine {
         self.unchecked_sub(server_key, &ct_tmp_left, &ct_tmp_right)
     }
 
-    pub(crate) fn unchecked_mul_lsb_small_carry_modulus_assign<OpOrder: PBSOrderMarker>(
+    pub fn unchecked_mul_lsb_small_carry_modulus_assign<OpOrder: PBSOrderMarker>(
         &mut self,
         server_key: &ServerKey,
         ct1: &mut CiphertextBase<OpOrder>,
@@ -121,7 +121,7 @@ impl ShortintEngine {
         Ok(())
     }
 
-    pub(crate) fn smart_mul_lsb_assign<OpOrder: PBSOrderMarker>(
+    pub fn smart_mul_lsb_assign<OpOrder: PBSOrderMarker>(
         &mut self,
         server_key: &ServerKey,
         ct_left: &mut CiphertextBase<OpOrder>,
@@ -140,12 +140,19 @@ impl ShortintEngine {
             //If the ciphertext cannot be added together without exceeding the capacity of a
             // ciphertext
             if !server_key.is_mul_possible(ct_left, ct_right) {
+                let max_noise_level = server_key.max_noise_level();
                 if (server_key.message_modulus.0 - 1) * ct_right.degree.0
                     < (ct_right.carry_modulus.0 * ct_right.message_modulus.0 - 1)
+                    && max_noise_level.validate(
+                        NoiseLevel::NOMINAL * (ct_right.degree.0 + 1) + ct_right.noise_level,
+                    )
                 {
                     self.message_extract_assign(server_key, ct_left)?;
                 } else if (server_key.message_modulus.0 - 1) + ct_left.degree.0
                     < (ct_right.carry_modulus.0 * ct_right.message_modulus.0 - 1)
+                    && max_noise_level.validate(
+                        ct_left.noise_level * server_key.message_modulus.0 + NoiseLevel::NOMINAL,
+                    )
                 {
                     self.message_extract_assign(server_key, ct_right)?;
                 } else {
@@ -158,7 +165,7 @@ impl ShortintEngine {
         Ok(())
     }
 
-    pub(crate) fn smart_mul_lsb<OpOrder: PBSOrderMarker>(
+    pub fn smart_mul_lsb<OpOrder: PBSOrderMarker>(
         &mut self,
         server_key: &ServerKey,
         ct_left: &mut CiphertextBase<OpOrder>,
@@ -169,7 +176,7 @@ impl ShortintEngine {
         Ok(result)
     }
 
-    pub(crate) fn smart_mul_msb_assign<OpOrder: PBSOrderMarker>(
+    pub fn smart_mul_msb_assign<OpOrder: PBSOrderMarker>(
         &mut self,
         server_key: &ServerKey,
         ct_left: &mut CiphertextBase<OpOrder>,
@@ -183,7 +190,7 @@ impl ShortintEngine {
         Ok(())
     }
 
-    pub(crate) fn smart_mul_msb<OpOrder: PBSOrderMarker>(
+    pub fn smart_mul_msb<OpOrder: PBSOrderMarker>(
         &mut self,
         server_key: &ServerKey,
         ct_left: &mut CiphertextBase<OpOrder>,
@@ -1,11 +1,10 @@
 use crate::core_crypto::algorithms::*;
-use crate::core_crypto::entities::*;
 use crate::shortint::ciphertext::Degree;
-use crate::shortint::engine::{EngineResult, ShortintEngine};
+use crate::shortint::engine::{plaintext_on_modulus, EngineResult, ShortintEngine};
 use crate::shortint::{CiphertextBase, PBSOrderMarker, ServerKey};
 
 impl ShortintEngine {
-    pub(crate) fn unchecked_neg<OpOrder: PBSOrderMarker>(
+    pub fn unchecked_neg<OpOrder: PBSOrderMarker>(
         &mut self,
         server_key: &ServerKey,
         ct: &CiphertextBase<OpOrder>,
@@ -15,7 +14,7 @@ impl ShortintEngine {
         Ok(result)
     }
 
-    pub(crate) fn unchecked_neg_with_correcting_term<OpOrder: PBSOrderMarker>(
+    pub fn unchecked_neg_with_correcting_term<OpOrder: PBSOrderMarker>(
         &mut self,
         server_key: &ServerKey,
         ct: &CiphertextBase<OpOrder>,
@@ -25,7 +24,7 @@ impl ShortintEngine {
         Ok((result, z))
     }
 
-    pub(crate) fn unchecked_neg_assign<OpOrder: PBSOrderMarker>(
+    pub fn unchecked_neg_assign<OpOrder: PBSOrderMarker>(
         &mut self,
         server_key: &ServerKey,
         ct: &mut CiphertextBase<OpOrder>,
@@ -34,7 +33,7 @@ impl ShortintEngine {
         Ok(())
     }
 
-    pub(crate) fn unchecked_neg_assign_with_correcting_term<OpOrder: PBSOrderMarker>(
+    pub fn unchecked_neg_assign_with_correcting_term<OpOrder: PBSOrderMarker>(
         &mut self,
         server_key: &ServerKey,
         ct: &mut CiphertextBase<OpOrder>,
@@ -49,7 +48,7 @@ impl ShortintEngine {
             (1_u64 << 63) / (server_key.message_modulus.0 * server_key.carry_modulus.0) as u64;
 
         //Scaling + 1 on the padding bit
-        let w = Plaintext(z * delta);
+        let w = plaintext_on_modulus(z * delta, ct.ct.ciphertext_modulus());
 
         // (0,Delta*z) - ct
         lwe_ciphertext_opposite_assign(&mut ct.ct);
@@ -62,7 +61,7 @@ impl ShortintEngine {
         Ok(z)
     }
 
-    pub(crate) fn smart_neg<OpOrder: PBSOrderMarker>(
+    pub fn smart_neg<OpOrder: PBSOrderMarker>(
         &mut self,
         server_key: &ServerKey,
         ct: &mut CiphertextBase<OpOrder>,
@@ -74,7 +73,7 @@ impl ShortintEngine {
         self.unchecked_neg(server_key, ct)
     }
 
-    pub(crate) fn smart_neg_assign<OpOrder: PBSOrderMarker>(
+    pub fn smart_neg_assign<OpOrder: PBSOrderMarker>(
         &mut self,
         server_key: &ServerKey,
         ct: &mut CiphertextBase<OpOrder>,
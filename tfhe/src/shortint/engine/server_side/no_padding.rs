@@ -0,0 +1,235 @@
+use crate::core_crypto::algorithms::*;
+use crate::core_crypto::entities::*;
+use crate::shortint::ciphertext::{CiphertextNoPaddingBase, Degree};
+use crate::shortint::engine::{
+    fill_accumulator, plaintext_on_modulus, EngineResult, ShortintEngine,
+};
+use crate::shortint::server_key::{FullDomainLookupTable, LookupTableOwned};
+use crate::shortint::{PBSOrderMarker, ServerKey};
+
+/// Without padding bit the values wrap around the modulus,
+/// so the degree can never exceed `modulus - 1`.
+fn saturating_degree(degree: usize, modulus: usize) -> Degree {
+    Degree(degree.min(modulus - 1))
+}
+
+impl ShortintEngine {
+    pub fn unchecked_add_no_padding_assign<OpOrder: PBSOrderMarker>(
+        &mut self,
+        ct_left: &mut CiphertextNoPaddingBase<OpOrder>,
+        ct_right: &CiphertextNoPaddingBase<OpOrder>,
+    ) -> EngineResult<()> {
+        lwe_ciphertext_add_assign(&mut ct_left.0.ct, &ct_right.0.ct);
+        ct_left.0.degree =
+            saturating_degree(ct_left.0.degree.0 + ct_right.0.degree.0, ct_left.modulus());
+        ct_left.0.noise_level += ct_right.0.noise_level;
+        Ok(())
+    }
+
+    pub fn unchecked_sub_no_padding_assign<OpOrder: PBSOrderMarker>(
+        &mut self,
+        ct_left: &mut CiphertextNoPaddingBase<OpOrder>,
+        ct_right: &CiphertextNoPaddingBase<OpOrder>,
+    ) -> EngineResult<()> {
+        lwe_ciphertext_sub_assign(&mut ct_left.0.ct, &ct_right.0.ct);
+        // The subtraction may wrap around, so any value can be obtained
+        ct_left.0.degree = Degree(ct_left.modulus() - 1);
+        ct_left.0.noise_level += ct_right.0.noise_level;
+        Ok(())
+    }
+
+    pub fn unchecked_neg_no_padding_assign<OpOrder: PBSOrderMarker>(
+        &mut self,
+        ct: &mut CiphertextNoPaddingBase<OpOrder>,
+    ) -> EngineResult<()> {
+        lwe_ciphertext_opposite_assign(&mut ct.0.ct);
+        ct.0.degree = Degree(ct.modulus() - 1);
+        Ok(())
+    }
+
+    pub fn unchecked_scalar_add_no_padding_assign<OpOrder: PBSOrderMarker>(
+        &mut self,
+        ct: &mut CiphertextNoPaddingBase<OpOrder>,
+        scalar: u8,
+    ) -> EngineResult<()> {
+        let modulus = ct.modulus();
+        // Multiply by 2 as there is no padding bit
+        let delta = ((1_u64 << 63) / modulus as u64) * 2;
+        let shift_plaintext = (u64::from(scalar) % modulus as u64) * delta;
+        let encoded_scalar = plaintext_on_modulus(shift_plaintext, ct.0.ct.ciphertext_modulus());
+        lwe_ciphertext_plaintext_add_assign(&mut ct.0.ct, encoded_scalar);
+
+        ct.0.degree = saturating_degree(ct.0.degree.0 + scalar as usize, modulus);
+        Ok(())
+    }
+
+    pub fn unchecked_scalar_mul_no_padding_assign<OpOrder: PBSOrderMarker>(
+        &mut self,
+        ct: &mut CiphertextNoPaddingBase<OpOrder>,
+        scalar: u8,
+    ) -> EngineResult<()> {
+        lwe_ciphertext_cleartext_mul_assign(&mut ct.0.ct, Cleartext(u64::from(scalar)));
+
+        ct.0.degree = saturating_degree(ct.0.degree.0 * scalar as usize, ct.modulus());
+        ct.0.noise_level *= scalar as usize;
+        Ok(())
+    }
+
+    /// Generates an accumulator for a function `f` such that
+    /// `f(x + modulus / 2) == -f(x) mod modulus` where `modulus = message_modulus * carry_modulus`.
+    ///
+    /// Such functions match the negacyclic structure of the bootstrapping, so they can be
+    /// evaluated on the whole `[0, modulus)` space of a ciphertext without padding bit:
+    /// the accumulator only needs to hold the values for `[0, modulus / 2)`,
+    /// the other half is obtained for free by the negation happening in the blind rotation.
+    pub fn generate_lookup_table_odd<F>(
+        &mut self,
+        server_key: &ServerKey,
+        f: F,
+    ) -> EngineResult<LookupTableOwned>
+    where
+        F: Fn(u64) -> u64,
+    {
+        let modulus = server_key.message_modulus.0 * server_key.carry_modulus.0;
+
+        // No padding bit, the output is encoded on the whole torus
+        let delta = ((1_u64 << 63) / modulus as u64) * 2;
+
+        let acc = odd_accumulator(server_key, |i| (f(i) % modulus as u64).wrapping_mul(delta));
+
+        Ok(LookupTableOwned {
+            acc,
+            // The negated half can take any value
+            degree: Degree(modulus - 1),
+            message_modulus: server_key.message_modulus,
+            carry_modulus: server_key.carry_modulus,
+        })
+    }
+
+    /// Generates the accumulators of an arbitrary function `f` over the `[0, modulus)` space of
+    /// a ciphertext without padding bit, see [`FullDomainLookupTable`].
+    ///
+    /// The first accumulator folds the input `x` into `v(x)` in the `[0, modulus)` space of a
+    /// ciphertext with padding bit, with `v(x) = modulus / 2 + x` if `x < modulus / 2` and
+    /// `v(x) = modulus - 1 - x` otherwise. As the bootstrapping negates the upper half, the
+    /// folding accumulator holds `x + 1/2` for `x` in `[0, modulus / 2)` and `(modulus - 1) / 2`
+    /// is added to its output, both in units of the encoding with padding bit: `v` is then an
+    /// integer in both halves.
+    ///
+    /// The second accumulator is a regular one evaluating `f` on the inverse of the folding,
+    /// whose output is encoded without padding bit.
+    pub fn generate_lookup_table_full_domain<F>(
+        &mut self,
+        server_key: &ServerKey,
+        f: F,
+    ) -> EngineResult<FullDomainLookupTable>
+    where
+        F: Fn(u64) -> u64,
+    {
+        let modulus = (server_key.message_modulus.0 * server_key.carry_modulus.0) as u64;
+        let half_modulus = modulus / 2;
+
+        // Half of the delta of the encoding with padding bit
+        let half_delta = (1_u64 << 62) / modulus;
+        let folding_acc = odd_accumulator(server_key, |i| (2 * i + 1) * half_delta);
+
+        let unfold = |v: u64| {
+            if v >= half_modulus {
+                v - half_modulus
+            } else {
+                modulus - 1 - v
+            }
+        };
+        let mut acc = GlweCiphertext::new(
+            0,
+            server_key.bootstrapping_key.glwe_size(),
+            server_key.bootstrapping_key.polynomial_size(),
+            server_key.ciphertext_modulus,
+        );
+        // fill_accumulator encodes the output with padding bit, doubling it encodes it without
+        fill_accumulator(&mut acc, server_key, |v| 2 * (f(unfold(v)) % modulus));
+        let max_value = (0..modulus).map(|x| f(x) % modulus).max().unwrap_or(0);
+
+        Ok(FullDomainLookupTable {
+            folding: LookupTableOwned {
+                acc: folding_acc,
+                degree: Degree(modulus as usize - 1),
+                message_modulus: server_key.message_modulus,
+                carry_modulus: server_key.carry_modulus,
+            },
+            acc: LookupTableOwned {
+                acc,
+                degree: Degree(max_value as usize),
+                message_modulus: server_key.message_modulus,
+                carry_modulus: server_key.carry_modulus,
+            },
+        })
+    }
+
+    pub fn apply_lookup_table_no_padding_assign<OpOrder: PBSOrderMarker>(
+        &mut self,
+        server_key: &ServerKey,
+        ct: &mut CiphertextNoPaddingBase<OpOrder>,
+        acc: &LookupTableOwned,
+    ) -> EngineResult<()> {
+        self.apply_lookup_table_assign(server_key, &mut ct.0, acc)
+    }
+
+    pub fn apply_lookup_table_full_domain_assign<OpOrder: PBSOrderMarker>(
+        &mut self,
+        server_key: &ServerKey,
+        ct: &mut CiphertextNoPaddingBase<OpOrder>,
+        acc: &FullDomainLookupTable,
+    ) -> EngineResult<()> {
+        let modulus = ct.modulus() as u64;
+
+        self.apply_lookup_table_assign(server_key, &mut ct.0, &acc.folding)?;
+        // (modulus - 1) / 2 in the encoding with padding bit
+        let shift = (modulus - 1) * ((1_u64 << 62) / modulus);
+        let shift = plaintext_on_modulus(shift, ct.0.ct.ciphertext_modulus());
+        lwe_ciphertext_plaintext_add_assign(&mut ct.0.ct, shift);
+
+        self.apply_lookup_table_assign(server_key, &mut ct.0, &acc.acc)
+    }
+}
+
+/// Fills an accumulator for the negacyclic bootstrapping of ciphertexts without padding bit,
+/// `encoded(i)` being the output of the box of `i` in `[0, modulus / 2)`.
+fn odd_accumulator<F>(server_key: &ServerKey, encoded: F) -> GlweCiphertextOwned<u64>
+where
+    F: Fn(u64) -> u64,
+{
+    let modulus = server_key.message_modulus.0 * server_key.carry_modulus.0;
+    let half_modulus = modulus / 2;
+
+    let polynomial_size = server_key.bootstrapping_key.polynomial_size();
+    let mut acc = GlweCiphertext::new(
+        0,
+        server_key.bootstrapping_key.glwe_size(),
+        polynomial_size,
+        server_key.ciphertext_modulus,
+    );
+
+    // N / (modulus / 2) = size of each box
+    let box_size = polynomial_size.0 / half_modulus;
+
+    let mut body = acc.get_mut_body();
+    let accumulator_u64 = body.as_mut();
+
+    for i in 0..half_modulus {
+        let index = i * box_size;
+        accumulator_u64[index..index + box_size].fill(encoded(i as u64));
+    }
+
+    let half_box_size = box_size / 2;
+
+    // Negate the first half_box_size coefficients
+    for a_i in accumulator_u64[0..half_box_size].iter_mut() {
+        *a_i = (*a_i).wrapping_neg();
+    }
+
+    // Rotate the accumulator
+    accumulator_u64.rotate_left(half_box_size);
+
+    acc
+}
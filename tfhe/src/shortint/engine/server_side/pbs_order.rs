@@ -0,0 +1,100 @@
+use crate::core_crypto::algorithms::*;
+use crate::core_crypto::entities::*;
+use crate::shortint::ciphertext::NoiseLevel;
+use crate::shortint::engine::{EngineResult, ShortintEngine};
+use crate::shortint::server_key::{LookupTableOwned, PBSOrderSwitchingKey};
+use crate::shortint::{CiphertextBase, ClientKey, PBSOrder, PBSOrderMarker, ServerKey};
+use std::marker::PhantomData;
+
+impl ShortintEngine {
+    pub fn new_pbs_order_switching_key(
+        &mut self,
+        cks: &ClientKey,
+    ) -> EngineResult<PBSOrderSwitchingKey> {
+        // The key switching key from the small key to the large one, the reverse of the one of
+        // the server key
+        let key_switching_key = allocate_and_generate_new_lwe_keyswitch_key(
+            &cks.small_lwe_secret_key,
+            &cks.large_lwe_secret_key,
+            cks.parameters.ks_base_log(),
+            cks.parameters.ks_level(),
+            cks.parameters.glwe_noise_distribution(),
+            cks.parameters.ciphertext_modulus(),
+            &mut self.encryption_generator,
+        );
+
+        Ok(PBSOrderSwitchingKey { key_switching_key })
+    }
+
+    pub fn apply_lookup_table_with_order<InOrder, OutOrder>(
+        &mut self,
+        server_key: &ServerKey,
+        ct: &CiphertextBase<InOrder>,
+        acc: &LookupTableOwned,
+    ) -> EngineResult<CiphertextBase<OutOrder>>
+    where
+        InOrder: PBSOrderMarker,
+        OutOrder: PBSOrderMarker,
+    {
+        let (mut ciphertext_buffers, buffers) =
+            self.get_carry_clearing_accumulator_and_buffers(server_key);
+
+        let mut ct_big = LweCiphertextOwned::new(
+            0u64,
+            server_key
+                .bootstrapping_key
+                .output_lwe_dimension()
+                .to_lwe_size(),
+            server_key.ciphertext_modulus,
+        );
+
+        // The PBS takes ciphertexts encrypted under the small key and outputs ciphertexts
+        // encrypted under the large one, the key switches are only computed when needed
+        match InOrder::pbs_order() {
+            PBSOrder::KeyswitchBootstrap => {
+                keyswitch_lwe_ciphertext(
+                    &server_key.key_switching_key,
+                    &ct.ct,
+                    &mut ciphertext_buffers.buffer_lwe_after_ks,
+                );
+                server_key.bootstrapping_key.programmable_bootstrap(
+                    &ciphertext_buffers.buffer_lwe_after_ks,
+                    &mut ct_big,
+                    &acc.acc,
+                    buffers,
+                );
+            }
+            PBSOrder::BootstrapKeyswitch => {
+                server_key.bootstrapping_key.programmable_bootstrap(
+                    &ct.ct,
+                    &mut ct_big,
+                    &acc.acc,
+                    buffers,
+                );
+            }
+        }
+
+        let ct_out = match OutOrder::pbs_order() {
+            PBSOrder::KeyswitchBootstrap => ct_big,
+            PBSOrder::BootstrapKeyswitch => {
+                let mut ct_small = LweCiphertextOwned::new(
+                    0u64,
+                    server_key.key_switching_key.output_lwe_size(),
+                    server_key.ciphertext_modulus,
+                );
+                keyswitch_lwe_ciphertext(&server_key.key_switching_key, &ct_big, &mut ct_small);
+                ct_small
+            }
+        };
+
+        Ok(CiphertextBase {
+            ct: ct_out,
+            degree: acc.degree,
+            noise_level: NoiseLevel::NOMINAL,
+            message_modulus: ct.message_modulus,
+            carry_modulus: ct.carry_modulus,
+            parameter_version: ct.parameter_version,
+            _order_marker: PhantomData,
+        })
+    }
+}
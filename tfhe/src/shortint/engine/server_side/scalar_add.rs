@@ -1,11 +1,10 @@
 use crate::core_crypto::algorithms::*;
-use crate::core_crypto::entities::*;
 use crate::shortint::ciphertext::Degree;
-use crate::shortint::engine::{EngineResult, ShortintEngine};
+use crate::shortint::engine::{plaintext_on_modulus, EngineResult, ShortintEngine};
 use crate::shortint::{CiphertextBase, PBSOrderMarker, ServerKey};
 
 impl ShortintEngine {
-    pub(crate) fn unchecked_scalar_add<OpOrder: PBSOrderMarker>(
+    pub fn unchecked_scalar_add<OpOrder: PBSOrderMarker>(
         &mut self,
         ct: &CiphertextBase<OpOrder>,
         scalar: u8,
@@ -15,21 +14,21 @@ impl ShortintEngine {
         Ok(ct_result)
     }
 
-    pub(crate) fn unchecked_scalar_add_assign<OpOrder: PBSOrderMarker>(
+    pub fn unchecked_scalar_add_assign<OpOrder: PBSOrderMarker>(
         &mut self,
         ct: &mut CiphertextBase<OpOrder>,
         scalar: u8,
     ) -> EngineResult<()> {
         let delta = (1_u64 << 63) / (ct.message_modulus.0 * ct.carry_modulus.0) as u64;
         let shift_plaintext = u64::from(scalar) * delta;
-        let encoded_scalar = Plaintext(shift_plaintext);
+        let encoded_scalar = plaintext_on_modulus(shift_plaintext, ct.ct.ciphertext_modulus());
         lwe_ciphertext_plaintext_add_assign(&mut ct.ct, encoded_scalar);
 
         ct.degree = Degree(ct.degree.0 + scalar as usize);
         Ok(())
     }
 
-    pub(crate) fn unchecked_scalar_add_assign_crt<OpOrder: PBSOrderMarker>(
+    pub fn unchecked_scalar_add_assign_crt<OpOrder: PBSOrderMarker>(
         &mut self,
         server_key: &ServerKey,
         ct: &mut CiphertextBase<OpOrder>,
@@ -38,14 +37,14 @@ impl ShortintEngine {
         let delta =
             (1_u64 << 63) / (server_key.message_modulus.0 * server_key.carry_modulus.0) as u64;
         let shift_plaintext = u64::from(scalar) * delta;
-        let encoded_scalar = Plaintext(shift_plaintext);
+        let encoded_scalar = plaintext_on_modulus(shift_plaintext, ct.ct.ciphertext_modulus());
         lwe_ciphertext_plaintext_add_assign(&mut ct.ct, encoded_scalar);
 
         ct.degree = Degree(ct.degree.0 + scalar as usize);
         Ok(())
     }
 
-    pub(crate) fn smart_scalar_add<OpOrder: PBSOrderMarker>(
+    pub fn smart_scalar_add<OpOrder: PBSOrderMarker>(
         &mut self,
         server_key: &ServerKey,
         ct: &mut CiphertextBase<OpOrder>,
@@ -57,7 +56,7 @@ impl ShortintEngine {
         Ok(ct_result)
     }
 
-    pub(crate) fn smart_scalar_add_assign<OpOrder: PBSOrderMarker>(
+    pub fn smart_scalar_add_assign<OpOrder: PBSOrderMarker>(
         &mut self,
         server_key: &ServerKey,
         ct: &mut CiphertextBase<OpOrder>,
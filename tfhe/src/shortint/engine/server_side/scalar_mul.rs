@@ -5,7 +5,7 @@ use crate::shortint::engine::{EngineResult, ShortintEngine};
 use crate::shortint::{CiphertextBase, PBSOrderMarker, ServerKey};
 
 impl ShortintEngine {
-    pub(crate) fn unchecked_scalar_mul<OpOrder: PBSOrderMarker>(
+    pub fn unchecked_scalar_mul<OpOrder: PBSOrderMarker>(
         &mut self,
         ct: &CiphertextBase<OpOrder>,
         scalar: u8,
@@ -16,7 +16,7 @@ impl ShortintEngine {
         Ok(ct_result)
     }
 
-    pub(crate) fn unchecked_scalar_mul_assign<OpOrder: PBSOrderMarker>(
+    pub fn unchecked_scalar_mul_assign<OpOrder: PBSOrderMarker>(
         &mut self,
         ct: &mut CiphertextBase<OpOrder>,
         scalar: u8,
@@ -26,10 +26,11 @@ impl ShortintEngine {
         lwe_ciphertext_cleartext_mul_assign(&mut ct.ct, cleartext_scalar);
 
         ct.degree = Degree(ct.degree.0 * scalar as usize);
+        ct.noise_level *= scalar as usize;
         Ok(())
     }
 
-    pub(crate) fn smart_scalar_mul<OpOrder: PBSOrderMarker>(
+    pub fn smart_scalar_mul<OpOrder: PBSOrderMarker>(
         &mut self,
         server_key: &ServerKey,
         ctxt: &mut CiphertextBase<OpOrder>,
@@ -41,7 +42,7 @@ impl ShortintEngine {
         Ok(ct_result)
     }
 
-    pub(crate) fn smart_scalar_mul_assign<OpOrder: PBSOrderMarker>(
+    pub fn smart_scalar_mul_assign<OpOrder: PBSOrderMarker>(
         &mut self,
         server_key: &ServerKey,
         ctxt: &mut CiphertextBase<OpOrder>,
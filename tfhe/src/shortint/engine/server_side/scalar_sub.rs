@@ -1,11 +1,10 @@
 use crate::core_crypto::algorithms::*;
-use crate::core_crypto::entities::*;
 use crate::shortint::ciphertext::Degree;
-use crate::shortint::engine::{EngineResult, ShortintEngine};
+use crate::shortint::engine::{plaintext_on_modulus, EngineResult, ShortintEngine};
 use crate::shortint::{CiphertextBase, PBSOrderMarker, ServerKey};
 
 impl ShortintEngine {
-    pub(crate) fn unchecked_scalar_sub<OpOrder: PBSOrderMarker>(
+    pub fn unchecked_scalar_sub<OpOrder: PBSOrderMarker>(
         &mut self,
         ct: &CiphertextBase<OpOrder>,
         scalar: u8,
@@ -15,7 +14,7 @@ impl ShortintEngine {
         Ok(ct_result)
     }
 
-    pub(crate) fn unchecked_scalar_sub_assign<OpOrder: PBSOrderMarker>(
+    pub fn unchecked_scalar_sub_assign<OpOrder: PBSOrderMarker>(
         &mut self,
         ct: &mut CiphertextBase<OpOrder>,
         scalar: u8,
@@ -23,7 +22,7 @@ impl ShortintEngine {
         let neg_scalar = u64::from(scalar.wrapping_neg()) % ct.message_modulus.0 as u64;
         let delta = (1_u64 << 63) / (ct.message_modulus.0 * ct.carry_modulus.0) as u64;
         let shift_plaintext = neg_scalar * delta;
-        let encoded_scalar = Plaintext(shift_plaintext);
+        let encoded_scalar = plaintext_on_modulus(shift_plaintext, ct.ct.ciphertext_modulus());
 
         lwe_ciphertext_plaintext_add_assign(&mut ct.ct, encoded_scalar);
 
@@ -31,7 +30,7 @@ impl ShortintEngine {
         Ok(())
     }
 
-    pub(crate) fn smart_scalar_sub<OpOrder: PBSOrderMarker>(
+    pub fn smart_scalar_sub<OpOrder: PBSOrderMarker>(
         &mut self,
         server_key: &ServerKey,
         ct: &mut CiphertextBase<OpOrder>,
@@ -43,7 +42,7 @@ impl ShortintEngine {
         Ok(ct_result)
     }
 
-    pub(crate) fn smart_scalar_sub_assign<OpOrder: PBSOrderMarker>(
+    pub fn smart_scalar_sub_assign<OpOrder: PBSOrderMarker>(
         &mut self,
         server_key: &ServerKey,
         ct: &mut CiphertextBase<OpOrder>,
@@ -3,7 +3,7 @@ use crate::shortint::engine::{EngineResult, ShortintEngine};
 use crate::shortint::{CiphertextBase, PBSOrderMarker, ServerKey};
 
 impl ShortintEngine {
-    pub(crate) fn unchecked_scalar_right_shift<OpOrder: PBSOrderMarker>(
+    pub fn unchecked_scalar_right_shift<OpOrder: PBSOrderMarker>(
         &mut self,
         server_key: &ServerKey,
         ct: &CiphertextBase<OpOrder>,
@@ -14,7 +14,7 @@ impl ShortintEngine {
         Ok(result)
     }
 
-    pub(crate) fn unchecked_scalar_right_shift_assign<OpOrder: PBSOrderMarker>(
+    pub fn unchecked_scalar_right_shift_assign<OpOrder: PBSOrderMarker>(
         &mut self,
         server_key: &ServerKey,
         ct: &mut CiphertextBase<OpOrder>,
@@ -27,7 +27,7 @@ impl ShortintEngine {
         Ok(())
     }
 
-    pub(crate) fn unchecked_scalar_left_shift<OpOrder: PBSOrderMarker>(
+    pub fn unchecked_scalar_left_shift<OpOrder: PBSOrderMarker>(
         &mut self,
         ct: &CiphertextBase<OpOrder>,
         shift: u8,
@@ -37,7 +37,7 @@ impl ShortintEngine {
         Ok(result)
     }
 
-    pub(crate) fn unchecked_scalar_left_shift_assign<OpOrder: PBSOrderMarker>(
+    pub fn unchecked_scalar_left_shift_assign<OpOrder: PBSOrderMarker>(
         &mut self,
         ct: &mut CiphertextBase<OpOrder>,
         shift: u8,
@@ -47,7 +47,7 @@ impl ShortintEngine {
         Ok(())
     }
 
-    pub(crate) fn smart_scalar_left_shift<OpOrder: PBSOrderMarker>(
+    pub fn smart_scalar_left_shift<OpOrder: PBSOrderMarker>(
         &mut self,
         server_key: &ServerKey,
         ct: &mut CiphertextBase<OpOrder>,
@@ -58,7 +58,7 @@ impl ShortintEngine {
         Ok(result)
     }
 
-    pub(crate) fn smart_scalar_left_shift_assign<OpOrder: PBSOrderMarker>(
+    pub fn smart_scalar_left_shift_assign<OpOrder: PBSOrderMarker>(
         &mut self,
         server_key: &ServerKey,
         ct: &mut CiphertextBase<OpOrder>,
@@ -74,4 +74,78 @@ impl ShortintEngine {
         }
         Ok(())
     }
+
+    pub fn unchecked_scalar_rotate_left<OpOrder: PBSOrderMarker>(
+        &mut self,
+        server_key: &ServerKey,
+        ct: &CiphertextBase<OpOrder>,
+        rotation: u8,
+    ) -> EngineResult<CiphertextBase<OpOrder>> {
+        let mut result = ct.clone();
+        self.unchecked_scalar_rotate_left_assign(server_key, &mut result, rotation)?;
+        Ok(result)
+    }
+
+    pub fn unchecked_scalar_rotate_left_assign<OpOrder: PBSOrderMarker>(
+        &mut self,
+        server_key: &ServerKey,
+        ct: &mut CiphertextBase<OpOrder>,
+        rotation: u8,
+    ) -> EngineResult<()> {
+        let message_modulus = server_key.message_modulus.0;
+        let num_bits = rotation_bit_count(server_key);
+        let rotation = rotation as u32 % num_bits;
+
+        // Rotate the bits of the message, the carries are dropped
+        let rotate = |x: usize| {
+            let x = x % message_modulus;
+            if rotation == 0 {
+                x
+            } else {
+                ((x << rotation) | (x >> (num_bits - rotation))) % message_modulus
+            }
+        };
+
+        // Only the messages up to the input degree can be rotated
+        let degree = ct.degree.after_pbs(rotate);
+        let acc = self.generate_accumulator(server_key, |x| rotate(x as usize) as u64)?;
+        self.apply_lookup_table_assign(server_key, ct, &acc)?;
+
+        ct.degree = degree;
+        Ok(())
+    }
+
+    pub fn unchecked_scalar_rotate_right<OpOrder: PBSOrderMarker>(
+        &mut self,
+        server_key: &ServerKey,
+        ct: &CiphertextBase<OpOrder>,
+        rotation: u8,
+    ) -> EngineResult<CiphertextBase<OpOrder>> {
+        let mut result = ct.clone();
+        self.unchecked_scalar_rotate_right_assign(server_key, &mut result, rotation)?;
+        Ok(result)
+    }
+
+    pub fn unchecked_scalar_rotate_right_assign<OpOrder: PBSOrderMarker>(
+        &mut self,
+        server_key: &ServerKey,
+        ct: &mut CiphertextBase<OpOrder>,
+        rotation: u8,
+    ) -> EngineResult<()> {
+        // A right rotation is a left rotation by the complement of the number of message bits
+        let num_bits = rotation_bit_count(server_key) as u8;
+        let rotation = rotation % num_bits;
+        self.unchecked_scalar_rotate_left_assign(server_key, ct, num_bits - rotation)
+    }
+}
+
+/// Number of bits of the message rotated by the rotations, checked before reducing a rotation
+/// modulo it
+fn rotation_bit_count(server_key: &ServerKey) -> u32 {
+    let message_modulus = server_key.message_modulus.0;
+    assert!(
+        message_modulus.is_power_of_two() && message_modulus > 1,
+        "Rotations require a message modulus which is a power of two above 1, got {message_modulus}"
+    );
+    message_modulus.trailing_zeros()
 }
@@ -4,7 +4,7 @@ use crate::shortint::engine::{EngineResult, ShortintEngine};
 use crate::shortint::{CiphertextBase, PBSOrderMarker, ServerKey};
 
 impl ShortintEngine {
-    pub(crate) fn unchecked_sub<OpOrder: PBSOrderMarker>(
+    pub fn unchecked_sub<OpOrder: PBSOrderMarker>(
         &mut self,
         server_key: &ServerKey,
         ct_left: &CiphertextBase<OpOrder>,
@@ -16,7 +16,7 @@ impl ShortintEngine {
         Ok(result)
     }
 
-    pub(crate) fn unchecked_sub_assign<OpOrder: PBSOrderMarker>(
+    pub fn unchecked_sub_assign<OpOrder: PBSOrderMarker>(
         &mut self,
         server_key: &ServerKey,
         ct_left: &mut CiphertextBase<OpOrder>,
@@ -26,7 +26,7 @@ impl ShortintEngine {
         Ok(())
     }
 
-    pub(crate) fn unchecked_sub_with_correcting_term<OpOrder: PBSOrderMarker>(
+    pub fn unchecked_sub_with_correcting_term<OpOrder: PBSOrderMarker>(
         &mut self,
         server_key: &ServerKey,
         ct_left: &CiphertextBase<OpOrder>,
@@ -39,7 +39,7 @@ impl ShortintEngine {
         Ok((result, z))
     }
 
-    pub(crate) fn unchecked_sub_assign_with_correcting_term<OpOrder: PBSOrderMarker>(
+    pub fn unchecked_sub_assign_with_correcting_term<OpOrder: PBSOrderMarker>(
         &mut self,
         server_key: &ServerKey,
         ct_left: &mut CiphertextBase<OpOrder>,
@@ -50,11 +50,12 @@ impl ShortintEngine {
         lwe_ciphertext_add_assign(&mut ct_left.ct, &neg_right.ct);
 
         ct_left.degree = Degree(ct_left.degree.0 + z as usize);
+        ct_left.noise_level += neg_right.noise_level;
 
         Ok(z)
     }
 
-    pub(crate) fn smart_sub<OpOrder: PBSOrderMarker>(
+    pub fn smart_sub<OpOrder: PBSOrderMarker>(
         &mut self,
         server_key: &ServerKey,
         ct_left: &mut CiphertextBase<OpOrder>,
@@ -68,7 +69,7 @@ impl ShortintEngine {
         self.unchecked_sub(server_key, ct_left, ct_right)
     }
 
-    pub(crate) fn smart_sub_assign<OpOrder: PBSOrderMarker>(
+    pub fn smart_sub_assign<OpOrder: PBSOrderMarker>(
         &mut self,
         server_key: &ServerKey,
         ct_left: &mut CiphertextBase<OpOrder>,
@@ -84,7 +85,7 @@ impl ShortintEngine {
         Ok(())
     }
 
-    pub(crate) fn smart_sub_with_correcting_term<OpOrder: PBSOrderMarker>(
+    pub fn smart_sub_with_correcting_term<OpOrder: PBSOrderMarker>(
         &mut self,
         server_key: &ServerKey,
         ct_left: &mut CiphertextBase<OpOrder>,
@@ -5,15 +5,16 @@ use crate::core_crypto::commons::traits::*;
 use crate::core_crypto::entities::*;
 use crate::core_crypto::fft_impl::fft64::crypto::bootstrap::FourierLweBootstrapKey;
 use crate::core_crypto::fft_impl::fft64::math::fft::Fft;
-use crate::shortint::ciphertext::Degree;
+use crate::shortint::ciphertext::{Degree, NoiseLevel};
 use crate::shortint::engine::{EngineResult, ShortintEngine};
-use crate::shortint::server_key::MaxDegree;
-use crate::shortint::wopbs::{WopbsKey, WopbsLUTBase};
+use crate::shortint::server_key::{MaxDegree, ShortintBootstrappingKey};
+use crate::shortint::wopbs::{ShortintWopbsLUT, WopbsBits, WopbsKey, WopbsLUTBase};
 use crate::shortint::{CiphertextBase, ClientKey, PBSOrderMarker, ServerKey, WopbsParameters};
+use std::sync::Arc;
 
 impl ShortintEngine {
     // Creates a key when ONLY a wopbs is used.
-    pub(crate) fn new_wopbs_key_only_for_wopbs(
+    pub fn new_wopbs_key_only_for_wopbs(
         &mut self,
         cks: &ClientKey,
         sks: &ServerKey,
@@ -35,7 +36,7 @@ impl ShortintEngine {
         let wopbs_key = WopbsKey {
             wopbs_server_key: sks_cpy.clone(),
             cbs_pfpksk,
-            ksk_pbs_to_wopbs: sks.key_switching_key.clone(),
+            ksk_pbs_to_wopbs: (*sks.key_switching_key).clone(),
             param: wop_params.to_owned(),
             pbs_server_key: sks_cpy,
         };
@@ -43,7 +44,7 @@ impl ShortintEngine {
     }
 
     //Creates a new WoPBS key.
-    pub(crate) fn new_wopbs_key(
+    pub fn new_wopbs_key(
         &mut self,
         cks: &ClientKey,
         sks: &ServerKey,
@@ -130,7 +131,7 @@ impl ShortintEngine {
             &cks.small_lwe_secret_key,
             cks.parameters.ks_base_log(),
             cks.parameters.ks_level(),
-            cks.parameters.lwe_modular_std_dev(),
+            cks.parameters.lwe_noise_distribution(),
             parameters.ciphertext_modulus,
             &mut self.encryption_generator,
         );
@@ -146,8 +147,8 @@ impl ShortintEngine {
         );
 
         let wopbs_server_key = ServerKey {
-            key_switching_key: ksk_wopbs_large_to_wopbs_small,
-            bootstrapping_key: small_bsk,
+            key_switching_key: Arc::new(ksk_wopbs_large_to_wopbs_small),
+            bootstrapping_key: Arc::new(ShortintBootstrappingKey::Fourier(small_bsk)),
             message_modulus: parameters.message_modulus,
             carry_modulus: parameters.carry_modulus,
             max_degree: MaxDegree(parameters.message_modulus.0 * parameters.carry_modulus.0 - 1),
@@ -155,8 +156,8 @@ impl ShortintEngine {
         };
 
         let pbs_server_key = ServerKey {
-            key_switching_key: ksk_wopbs_large_to_pbs_small,
-            bootstrapping_key: sks.bootstrapping_key.clone(),
+            key_switching_key: Arc::new(ksk_wopbs_large_to_pbs_small),
+            bootstrapping_key: Arc::clone(&sks.bootstrapping_key),
             message_modulus: cks.parameters.message_modulus(),
             carry_modulus: cks.parameters.carry_modulus(),
             max_degree: MaxDegree(
@@ -175,7 +176,7 @@ impl ShortintEngine {
         Ok(wopbs_key)
     }
 
-    pub(crate) fn extract_bits(
+    pub fn extract_bits(
         &mut self,
         delta_log: DeltaLog,
         lwe_in: &LweCiphertextOwned<u64>,
@@ -202,24 +203,25 @@ impl ShortintEngine {
             wopbs_key,
             extracted_bit_count,
             &mut output,
-        );
+        )?;
 
         Ok(output)
     }
 
-    pub(crate) fn extract_bits_assign<OutputCont>(
+    pub fn extract_bits_assign<OutputCont>(
         &mut self,
         delta_log: DeltaLog,
         lwe_in: &LweCiphertextOwned<u64>,
         wopbs_key: &WopbsKey,
         extracted_bit_count: ExtractedBitsCount,
         output: &mut LweCiphertextList<OutputCont>,
-    ) where
+    ) -> EngineResult<()>
+    where
         OutputCont: ContainerMut<Element = u64>,
     {
         let server_key = &wopbs_key.wopbs_server_key;
 
-        let bsk = &server_key.bootstrapping_key;
+        let bsk: &FourierLweBootstrapKeyOwned = &server_key.bootstrapping_key.to_fourier()?;
         let ksk = &server_key.key_switching_key;
 
         let fft = Fft::new(bsk.polynomial_size());
@@ -249,9 +251,11 @@ impl ShortintEngine {
             fft,
             stack,
         );
+
+        Ok(())
     }
 
-    pub(crate) fn circuit_bootstrap_with_bits<InputCont, LutCont>(
+    pub fn circuit_bootstrap_with_bits<InputCont, LutCont>(
         &mut self,
         wopbs_key: &WopbsKey,
         extracted_bits: &LweCiphertextList<InputCont>,
@@ -263,7 +267,7 @@ impl ShortintEngine {
         LutCont: Container<Element = u64>,
     {
         let sks = &wopbs_key.wopbs_server_key;
-        let fourier_bsk = &sks.bootstrapping_key;
+        let fourier_bsk: &FourierLweBootstrapKeyOwned = &sks.bootstrapping_key.to_fourier()?;
 
         let output_lwe_size = fourier_bsk.output_lwe_dimension().to_lwe_size();
 
@@ -299,7 +303,7 @@ impl ShortintEngine {
             extracted_bits,
             &mut output_cbs_vp_ct,
             &lut,
-            &sks.bootstrapping_key,
+            fourier_bsk,
             &wopbs_key.cbs_pfpksk,
             wopbs_key.param.cbs_base_log,
             wopbs_key.param.cbs_level,
@@ -310,7 +314,7 @@ impl ShortintEngine {
         Ok(output_cbs_vp_ct)
     }
 
-    pub(crate) fn extract_bits_circuit_bootstrapping<OpOrder: PBSOrderMarker>(
+    pub fn extract_bits_circuit_bootstrapping<OpOrder: PBSOrderMarker>(
         &mut self,
         wopbs_key: &WopbsKey,
         ct_in: &CiphertextBase<OpOrder>,
@@ -339,15 +343,17 @@ impl ShortintEngine {
         let ct_out = CiphertextBase {
             ct: ciphertext,
             degree: Degree(sks.message_modulus.0 - 1),
+            noise_level: NoiseLevel::NOMINAL,
             message_modulus: sks.message_modulus,
             carry_modulus: sks.carry_modulus,
+            parameter_version: ct_in.parameter_version,
             _order_marker: Default::default(),
         };
 
         Ok(ct_out)
     }
 
-    pub(crate) fn programmable_bootstrapping_without_padding<OpOrder: PBSOrderMarker>(
+    pub fn programmable_bootstrapping_without_padding<OpOrder: PBSOrderMarker>(
         &mut self,
         wopbs_key: &WopbsKey,
         ct_in: &CiphertextBase<OpOrder>,
@@ -374,7 +380,7 @@ impl ShortintEngine {
         Ok(ciphertext)
     }
 
-    pub(crate) fn keyswitch_to_wopbs_params<OpOrder: PBSOrderMarker>(
+    pub fn keyswitch_to_wopbs_params<OpOrder: PBSOrderMarker>(
         &mut self,
         sks: &ServerKey,
         wopbs_key: &WopbsKey,
@@ -405,13 +411,15 @@ impl ShortintEngine {
         Ok(CiphertextBase {
             ct: buffer_lwe_after_ks,
             degree: ct_in.degree,
+            noise_level: NoiseLevel::NOMINAL,
             message_modulus: ct_clean.message_modulus,
             carry_modulus: ct_clean.carry_modulus,
+            parameter_version: ct_in.parameter_version,
             _order_marker: Default::default(),
         })
     }
 
-    pub(crate) fn keyswitch_to_pbs_params<OpOrder: PBSOrderMarker>(
+    pub fn keyswitch_to_pbs_params<OpOrder: PBSOrderMarker>(
         &mut self,
         wopbs_key: &WopbsKey,
         ct_in: &CiphertextBase<OpOrder>,
@@ -431,45 +439,117 @@ impl ShortintEngine {
             &mut ciphertext_buffers.buffer_lwe_after_ks,
         );
 
-        let fourier_bsk = &wopbs_key.pbs_server_key.bootstrapping_key;
+        let bootstrapping_key = &wopbs_key.pbs_server_key.bootstrapping_key;
 
-        let out_lwe_size = fourier_bsk.output_lwe_dimension().to_lwe_size();
+        let out_lwe_size = bootstrapping_key.output_lwe_dimension().to_lwe_size();
         let mut ct_out =
             LweCiphertextOwned::new(0, out_lwe_size, wopbs_key.param.ciphertext_modulus);
 
-        let fft = Fft::new(fourier_bsk.polynomial_size());
-        let fft = fft.as_view();
-        buffers.resize(
-            programmable_bootstrap_lwe_ciphertext_mem_optimized_requirement::<u64>(
-                fourier_bsk.glwe_size(),
-                fourier_bsk.polynomial_size(),
-                fft,
-            )
-            .unwrap()
-            .unaligned_bytes_required(),
-        );
-        let stack = buffers.stack();
-
         // Compute a bootstrap
-        programmable_bootstrap_lwe_ciphertext_mem_optimized(
+        bootstrapping_key.programmable_bootstrap(
             &ciphertext_buffers.buffer_lwe_after_ks,
             &mut ct_out,
             &acc.acc,
-            fourier_bsk,
-            fft,
-            stack,
+            buffers,
         );
 
         Ok(CiphertextBase {
             ct: ct_out,
             degree: ct_in.degree,
+            noise_level: NoiseLevel::NOMINAL,
             message_modulus: ct_in.message_modulus,
             carry_modulus: ct_in.carry_modulus,
+            parameter_version: ct_in.parameter_version,
             _order_marker: Default::default(),
         })
     }
 
-    pub(crate) fn wopbs<OpOrder: PBSOrderMarker>(
+    pub fn extract_all_bits<OpOrder: PBSOrderMarker>(
+        &mut self,
+        wopbs_key: &WopbsKey,
+        sks: &ServerKey,
+        ct_in: &CiphertextBase<OpOrder>,
+    ) -> EngineResult<WopbsBits<OpOrder>> {
+        let ct_wopbs = self.keyswitch_to_wopbs_params(sks, wopbs_key, ct_in)?;
+
+        // Same encoding as the one expected by the wopbs
+        let message_modulus = ct_wopbs.message_modulus.0 as u64;
+        let carry_modulus = ct_wopbs.carry_modulus.0 as u64;
+        let delta = (1u64 << 63) / (carry_modulus * message_modulus);
+        // casting to usize is fine, ilog2 of u64 is guaranteed to be < 64
+        let delta_log = DeltaLog(delta.ilog2() as usize);
+        let nb_bit_to_extract = f64::log2((message_modulus * carry_modulus) as f64) as usize;
+
+        let bits = self.extract_bits(
+            delta_log,
+            &ct_wopbs.ct,
+            wopbs_key,
+            ExtractedBitsCount(nb_bit_to_extract),
+        )?;
+
+        Ok(WopbsBits {
+            bits,
+            message_modulus: ct_wopbs.message_modulus,
+            carry_modulus: ct_wopbs.carry_modulus,
+            parameter_version: ct_in.parameter_version,
+            _order_marker: Default::default(),
+        })
+    }
+
+    pub fn insert_bits<OpOrder: PBSOrderMarker>(
+        &mut self,
+        wopbs_key: &WopbsKey,
+        bits: &WopbsBits<OpOrder>,
+    ) -> EngineResult<CiphertextBase<OpOrder>> {
+        // The identity over the whole message and carry space, encoded as by
+        // WopbsKey::generate_lut
+        let basis = bits.message_modulus.0 * bits.carry_modulus.0;
+        let delta = 64 - f64::log2(basis as f64).ceil() as u64 - 1;
+        let poly_size = wopbs_key
+            .wopbs_server_key
+            .bootstrapping_key
+            .polynomial_size()
+            .0;
+        let mut lut = ShortintWopbsLUT::new(PlaintextCount(poly_size));
+        for (i, value) in lut
+            .as_mut()
+            .lut_mut()
+            .as_mut()
+            .iter_mut()
+            .enumerate()
+            .take(basis)
+        {
+            *value = (i as u64) << delta;
+        }
+
+        let ciphertext_list = self.circuit_bootstrap_with_bits(
+            wopbs_key,
+            &bits.bits.as_view(),
+            lut.as_ref().lut(),
+            LweCiphertextCount(1),
+        )?;
+
+        // Here the output list contains a single ciphertext, we can consume the container to
+        // convert it to a single ciphertext
+        let ciphertext = LweCiphertextOwned::from_container(
+            ciphertext_list.into_container(),
+            wopbs_key.param.ciphertext_modulus,
+        );
+
+        let ct_wopbs = CiphertextBase {
+            ct: ciphertext,
+            degree: Degree(basis - 1),
+            noise_level: NoiseLevel::NOMINAL,
+            message_modulus: bits.message_modulus,
+            carry_modulus: bits.carry_modulus,
+            parameter_version: bits.parameter_version,
+            _order_marker: Default::default(),
+        };
+
+        self.keyswitch_to_pbs_params(wopbs_key, &ct_wopbs)
+    }
+
+    pub fn wopbs<OpOrder: PBSOrderMarker>(
         &mut self,
         wopbs_key: &WopbsKey,
         ct_in: &CiphertextBase<OpOrder>,
@@ -494,7 +574,7 @@ impl ShortintEngine {
         Ok(ct_out)
     }
 
-    pub(crate) fn programmable_bootstrapping<OpOrder: PBSOrderMarker>(
+    pub fn programmable_bootstrapping<OpOrder: PBSOrderMarker>(
         &mut self,
         wopbs_key: &WopbsKey,
         sks: &ServerKey,
@@ -508,7 +588,7 @@ impl ShortintEngine {
         Ok(ct_out)
     }
 
-    pub(crate) fn programmable_bootstrapping_native_crt<OpOrder: PBSOrderMarker>(
+    pub fn programmable_bootstrapping_native_crt<OpOrder: PBSOrderMarker>(
         &mut self,
         wopbs_key: &WopbsKey,
         ct_in: &mut CiphertextBase<OpOrder>,
@@ -0,0 +1,164 @@
+//! Casting of ciphertexts between client keys of different parameter sets.
+//!
+//! A [`KeySwitchingKey`](super::KeySwitchingKey) keeps the encoding of the messages, so it only
+//! connects keys with the same message and carry spaces. A [`CastingKey`] connects a key to a key
+//! with a larger space, e.g. from [`PARAM_MESSAGE_2_CARRY_2`] to [`PARAM_MESSAGE_4_CARRY_4`], so
+//! that cheap parameters can feed a stage computing with a higher precision.
+//!
+//! [`ServerKey::cast_to`] switches the ciphertext to the small key of the output client key, then
+//! a PBS of the output server key re-encodes the message with the scaling factor of the output
+//! parameters, which also resets the noise. The value of the ciphertext, carries included, is
+//! kept: a ciphertext whose carries are not empty is cast to a ciphertext of the same value.
+//!
+//! [`PARAM_MESSAGE_2_CARRY_2`]: crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2
+//! [`PARAM_MESSAGE_4_CARRY_4`]: crate::shortint::parameters::PARAM_MESSAGE_4_CARRY_4
+//!
+//! # Example
+//!
+//! ```rust
+//! use tfhe::shortint::gen_keys;
+//! use tfhe::shortint::key_switching_key::CastingKey;
+//! use tfhe::shortint::parameters::{
+//!     ShortintKeySwitchingParameters, PARAM_MESSAGE_2_CARRY_2, PARAM_MESSAGE_3_CARRY_3,
+//! };
+//! use tfhe::shortint::CiphertextBig;
+//!
+//! let (cks_2_2, sks_2_2) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+//! let (cks_3_3, sks_3_3) = gen_keys(PARAM_MESSAGE_3_CARRY_3);
+//!
+//! // The decomposition of the key switching key of the output parameters
+//! let params = ShortintKeySwitchingParameters::new(
+//!     PARAM_MESSAGE_3_CARRY_3.ks_base_log,
+//!     PARAM_MESSAGE_3_CARRY_3.ks_level,
+//! );
+//! let casting_key = CastingKey::new(&cks_2_2, &cks_3_3, params);
+//!
+//! // 3 + 3 = 6 does not fit in the message of the first parameters
+//! let ct = sks_2_2.unchecked_add(&cks_2_2.encrypt(3), &cks_2_2.encrypt(3));
+//!
+//! let ct: CiphertextBig = sks_3_3.cast_to(&casting_key, &ct);
+//! assert_eq!(cks_3_3.decrypt(&ct), 6);
+//! let ct = sks_3_3.scalar_mul(&ct, 7);
+//! assert_eq!(cks_3_3.decrypt(&ct), 42 % 8);
+//! ```
+
+use crate::core_crypto::algorithms::*;
+use crate::core_crypto::entities::*;
+use crate::shortint::ciphertext::Degree;
+use crate::shortint::engine::ShortintEngine;
+use crate::shortint::parameters::ShortintKeySwitchingParameters;
+use crate::shortint::{
+    CiphertextBase, CiphertextSmall, ClientKey, PBSOrder, PBSOrderMarker, ServerKey,
+    ShortintParameterSet,
+};
+use serde::{Deserialize, Serialize};
+use std::marker::PhantomData;
+
+/// A key casting the ciphertexts of a client key to another client key whose message and carry
+/// space is at least as large, see the [module documentation](self).
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct CastingKey {
+    pub(crate) key_switching_key: LweKeyswitchKeyOwned<u64>,
+    pub(crate) input_parameters: ShortintParameterSet,
+    pub(crate) output_parameters: ShortintParameterSet,
+    pub(crate) params: ShortintKeySwitchingParameters,
+}
+
+impl CastingKey {
+    /// Generates the key casting the ciphertexts encrypted under `from` to ciphertexts encrypted
+    /// under `to`.
+    ///
+    /// The key switching key goes from the encryption key of `from` to the small key of `to`, the
+    /// decomposition of the key switching key of the parameters of `to` can be used.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the message and carry space of `to` is not a multiple of the one of `from`, or
+    /// if the two keys use different ciphertext moduli.
+    pub fn new(from: &ClientKey, to: &ClientKey, params: ShortintKeySwitchingParameters) -> Self {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine.new_casting_key(from, to, params).unwrap()
+        })
+    }
+
+    pub fn parameters(&self) -> ShortintKeySwitchingParameters {
+        self.params
+    }
+
+    /// Returns the parameters of the client key the input ciphertexts are encrypted under.
+    pub fn input_parameters(&self) -> ShortintParameterSet {
+        self.input_parameters
+    }
+
+    /// Returns the parameters of the client key the output ciphertexts are encrypted under.
+    pub fn output_parameters(&self) -> ShortintParameterSet {
+        self.output_parameters
+    }
+}
+
+impl ServerKey {
+    /// Casts `ct`, encrypted under the input key of `casting_key`, to a ciphertext of the same
+    /// value encrypted under its output key, which must be the client key of `self`.
+    ///
+    /// The output ciphertext can be of either PBS order, the PBS computed by the cast outputs a
+    /// ciphertext encrypted under the large key which is only switched to the small key for a
+    /// [`CiphertextSmall`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the PBS order of `ct` does not match the encryption key choice of the input key
+    /// or if the message and carry spaces of `self` differ from the ones of the output key.
+    pub fn cast_to<InOrder, OutOrder>(
+        &self,
+        casting_key: &CastingKey,
+        ct: &CiphertextBase<InOrder>,
+    ) -> CiphertextBase<OutOrder>
+    where
+        InOrder: PBSOrderMarker,
+        OutOrder: PBSOrderMarker,
+    {
+        let input_parameters = &casting_key.input_parameters;
+        let output_parameters = &casting_key.output_parameters;
+        assert_eq!(
+            InOrder::pbs_order(),
+            PBSOrder::from(input_parameters.encryption_key_choice()),
+            "The input ciphertext is not encrypted under the input key of the casting key"
+        );
+        assert_eq!(
+            (self.message_modulus, self.carry_modulus),
+            (
+                output_parameters.message_modulus(),
+                output_parameters.carry_modulus()
+            ),
+            "The server key does not match the output key of the casting key"
+        );
+
+        let input_modulus =
+            input_parameters.message_modulus().0 * input_parameters.carry_modulus().0;
+        let output_modulus = self.message_modulus.0 * self.carry_modulus.0;
+        let ratio = output_modulus / input_modulus;
+
+        let ksk = &casting_key.key_switching_key;
+        let mut ct_small =
+            LweCiphertextOwned::new(0u64, ksk.output_lwe_size(), ksk.ciphertext_modulus());
+        keyswitch_lwe_ciphertext(ksk, &ct.ct, &mut ct_small);
+
+        // With the scaling factor of the output parameters, the switched ciphertext encrypts the
+        // value of `ct` multiplied by the ratio of the two spaces
+        let ct_small = CiphertextSmall {
+            ct: ct_small,
+            degree: Degree(ct.degree.0 * ratio),
+            noise_level: ct.noise_level,
+            message_modulus: self.message_modulus,
+            carry_modulus: self.carry_modulus,
+            parameter_version: output_parameters.version(),
+            _order_marker: PhantomData,
+        };
+
+        let acc = self.generate_accumulator(|x| x / ratio as u64);
+        let mut ct_res: CiphertextBase<OutOrder> =
+            self.apply_lookup_table_with_order(&ct_small, &acc);
+        ct_res.degree = ct.degree;
+        ct_res
+    }
+}
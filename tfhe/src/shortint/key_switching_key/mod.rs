@@ -0,0 +1,150 @@
+//! Module with the definition of the key switching between two client keys.
+//!
+//! Ciphertexts encrypted under different client keys can not be combined by a server key. A
+//! [`KeySwitchingKey`] re-encrypts the ciphertexts of a first client key under a second one
+//! without decrypting them: once switched with [`ServerKey::keyswitch`], the ciphertexts of the
+//! first client can be used in the circuits evaluated with the server key of the second client,
+//! together with the ciphertexts of this second client.
+//!
+//! The key switching key is generated from the two client keys, so it has to be produced by a
+//! party holding both keys (or by a protocol between their owners). The two keys must use the
+//! same message and carry spaces, their other parameters, including the encryption key choice,
+//! may differ. Ciphertexts can also be cast to a larger message and carry space with a
+//! [`CastingKey`], see the [`casting`] module.
+//!
+//! # Example
+//!
+//! ```rust
+//! use tfhe::shortint::key_switching_key::KeySwitchingKey;
+//! use tfhe::shortint::parameters::{PARAM_KEYSWITCH_MESSAGE_2_CARRY_2, PARAM_MESSAGE_2_CARRY_2};
+//! use tfhe::shortint::{gen_keys, CiphertextBig};
+//!
+//! let (cks_1, _sks_1) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+//! let (cks_2, sks_2) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+//!
+//! let ksk = KeySwitchingKey::new(&cks_1, &cks_2, PARAM_KEYSWITCH_MESSAGE_2_CARRY_2);
+//!
+//! let ct_1 = cks_1.encrypt(2);
+//! let ct_2 = cks_2.encrypt(1);
+//!
+//! let ct_1: CiphertextBig = sks_2.keyswitch(&ksk, &ct_1);
+//! let ct = sks_2.add(&ct_1, &ct_2);
+//! assert_eq!(cks_2.decrypt(&ct), 3);
+//! ```
+
+use crate::core_crypto::algorithms::*;
+use crate::core_crypto::entities::*;
+use crate::shortint::engine::ShortintEngine;
+use crate::shortint::parameters::ShortintKeySwitchingParameters;
+use crate::shortint::{
+    CiphertextBase, ClientKey, PBSOrder, PBSOrderMarker, ServerKey, ShortintParameterSet,
+};
+use serde::{Deserialize, Serialize};
+use std::marker::PhantomData;
+
+pub mod casting;
+#[cfg(test)]
+mod test;
+
+pub use casting::CastingKey;
+
+/// A key switching key from the encryption key of a client key to the encryption key of another
+/// one.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct KeySwitchingKey {
+    pub(crate) key_switching_key: LweKeyswitchKeyOwned<u64>,
+    pub(crate) input_parameters: ShortintParameterSet,
+    pub(crate) output_parameters: ShortintParameterSet,
+    pub(crate) params: ShortintKeySwitchingParameters,
+}
+
+impl KeySwitchingKey {
+    /// Generates the key switching key from the encryption key of `from` to the encryption key
+    /// of `to`, i.e. their large key if their parameters use [`EncryptionKeyChoice::Big`] and
+    /// their small key otherwise.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the two client keys use different message or carry spaces or different
+    /// ciphertext moduli.
+    ///
+    /// [`EncryptionKeyChoice::Big`]: crate::shortint::EncryptionKeyChoice::Big
+    pub fn new(from: &ClientKey, to: &ClientKey, params: ShortintKeySwitchingParameters) -> Self {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine.new_key_switching_key(from, to, params).unwrap()
+        })
+    }
+
+    pub fn parameters(&self) -> ShortintKeySwitchingParameters {
+        self.params
+    }
+
+    /// Returns the parameters of the client key the input ciphertexts are encrypted under.
+    pub fn input_parameters(&self) -> ShortintParameterSet {
+        self.input_parameters
+    }
+
+    /// Returns the parameters of the client key the output ciphertexts are encrypted under.
+    pub fn output_parameters(&self) -> ShortintParameterSet {
+        self.output_parameters
+    }
+}
+
+impl ServerKey {
+    /// Re-encrypts `ct`, encrypted under the input key of `key_switching_key`, under its output
+    /// key, which must be the client key of `self`.
+    ///
+    /// The message, the carries and the degree of `ct` are kept. The key switch adds noise to
+    /// the ciphertext, which can be cleaned with a PBS of the server key if needed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the PBS orders of the input and output ciphertexts do not match the encryption
+    /// key choices of the key switching key, or if the message and carry spaces of `self` differ
+    /// from the ones of the output key.
+    pub fn keyswitch<InOrder, OutOrder>(
+        &self,
+        key_switching_key: &KeySwitchingKey,
+        ct: &CiphertextBase<InOrder>,
+    ) -> CiphertextBase<OutOrder>
+    where
+        InOrder: PBSOrderMarker,
+        OutOrder: PBSOrderMarker,
+    {
+        let input_parameters = &key_switching_key.input_parameters;
+        let output_parameters = &key_switching_key.output_parameters;
+        assert_eq!(
+            InOrder::pbs_order(),
+            PBSOrder::from(input_parameters.encryption_key_choice()),
+            "The input ciphertext is not encrypted under the input key of the key switching key"
+        );
+        assert_eq!(
+            OutOrder::pbs_order(),
+            PBSOrder::from(output_parameters.encryption_key_choice()),
+            "The output ciphertext type does not match the output key of the key switching key"
+        );
+        assert_eq!(
+            (self.message_modulus, self.carry_modulus),
+            (
+                output_parameters.message_modulus(),
+                output_parameters.carry_modulus()
+            ),
+            "The server key does not match the output key of the key switching key"
+        );
+
+        let ksk = &key_switching_key.key_switching_key;
+        let mut ct_out =
+            LweCiphertextOwned::new(0u64, ksk.output_lwe_size(), ksk.ciphertext_modulus());
+        keyswitch_lwe_ciphertext(ksk, &ct.ct, &mut ct_out);
+
+        CiphertextBase {
+            ct: ct_out,
+            degree: ct.degree,
+            noise_level: ct.noise_level,
+            message_modulus: self.message_modulus,
+            carry_modulus: self.carry_modulus,
+            parameter_version: output_parameters.version(),
+            _order_marker: PhantomData,
+        }
+    }
+}
@@ -0,0 +1,135 @@
+use crate::shortint::gen_keys;
+use crate::shortint::key_switching_key::{CastingKey, KeySwitchingKey};
+use crate::shortint::parameters::{
+    ShortintKeySwitchingParameters, PARAM_KEYSWITCH_MESSAGE_2_CARRY_2,
+    PARAM_KEYSWITCH_SMALL_MESSAGE_2_CARRY_2, PARAM_MESSAGE_1_CARRY_1, PARAM_MESSAGE_2_CARRY_2,
+    PARAM_MESSAGE_2_CARRY_3, PARAM_MESSAGE_3_CARRY_3, PARAM_SMALL_MESSAGE_2_CARRY_2,
+};
+use crate::shortint::PBSParameters;
+use crate::shortint::{CiphertextBig, CiphertextSmall};
+use rand::Rng;
+
+const NB_TEST: usize = 10;
+
+#[test]
+fn test_keyswitch_param_message_2_carry_2() {
+    let (cks_1, _sks_1) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    let (cks_2, sks_2) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    let ksk = KeySwitchingKey::new(&cks_1, &cks_2, PARAM_KEYSWITCH_MESSAGE_2_CARRY_2);
+
+    let mut rng = rand::thread_rng();
+    let modulus = cks_1.parameters.message_modulus().0 as u64;
+
+    for _ in 0..NB_TEST {
+        let clear_1 = rng.gen::<u64>() % modulus;
+        let clear_2 = rng.gen::<u64>() % modulus;
+
+        let ct_1 = cks_1.encrypt(clear_1);
+        let ct_1: CiphertextBig = sks_2.keyswitch(&ksk, &ct_1);
+        assert_eq!(ct_1.degree.0, modulus as usize - 1);
+        assert_eq!(cks_2.decrypt(&ct_1), clear_1);
+
+        // The switched ciphertext can be combined with the ciphertexts of the output key
+        let ct = sks_2.mul_lsb(&ct_1, &cks_2.encrypt(clear_2));
+        assert_eq!(cks_2.decrypt(&ct), (clear_1 * clear_2) % modulus);
+    }
+}
+
+#[test]
+fn test_keyswitch_param_small_message_2_carry_2() {
+    let (cks_1, _sks_1) = gen_keys(PARAM_SMALL_MESSAGE_2_CARRY_2);
+    let (cks_2, sks_2) = gen_keys(PARAM_SMALL_MESSAGE_2_CARRY_2);
+    let ksk = KeySwitchingKey::new(&cks_1, &cks_2, PARAM_KEYSWITCH_SMALL_MESSAGE_2_CARRY_2);
+
+    let mut rng = rand::thread_rng();
+    let modulus = cks_1.parameters.message_modulus().0 as u64;
+
+    for _ in 0..NB_TEST {
+        let clear_1 = rng.gen::<u64>() % modulus;
+        let clear_2 = rng.gen::<u64>() % modulus;
+
+        let ct_1 = cks_1.encrypt_small(clear_1);
+        let ct_1: CiphertextSmall = sks_2.keyswitch(&ksk, &ct_1);
+        assert_eq!(cks_2.decrypt(&ct_1), clear_1);
+
+        let ct = sks_2.add(&ct_1, &cks_2.encrypt_small(clear_2));
+        assert_eq!(cks_2.decrypt(&ct), (clear_1 + clear_2) % modulus);
+    }
+}
+
+#[test]
+fn test_keyswitch_big_to_small() {
+    // The output key is small, so the key switching key uses the decomposition of the key
+    // switching key of the output server key
+    let (cks_1, _sks_1) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    let (cks_2, sks_2) = gen_keys(PARAM_SMALL_MESSAGE_2_CARRY_2);
+    let params = ShortintKeySwitchingParameters::new(
+        PARAM_SMALL_MESSAGE_2_CARRY_2.ks_base_log,
+        PARAM_SMALL_MESSAGE_2_CARRY_2.ks_level,
+    );
+    let ksk = KeySwitchingKey::new(&cks_1, &cks_2, params);
+
+    for clear in 0..cks_1.parameters.message_modulus().0 as u64 {
+        let ct: CiphertextSmall = sks_2.keyswitch(&ksk, &cks_1.encrypt(clear));
+        assert_eq!(cks_2.decrypt(&ct), clear);
+    }
+}
+
+#[test]
+#[should_panic(expected = "The two client keys must use the same message and carry spaces")]
+fn test_keyswitch_message_space_mismatch() {
+    let (cks_1, _sks_1) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    let (cks_2, _sks_2) = gen_keys(PARAM_MESSAGE_2_CARRY_3);
+    let _ = KeySwitchingKey::new(&cks_1, &cks_2, PARAM_KEYSWITCH_MESSAGE_2_CARRY_2);
+}
+
+fn casting_parameters(output_params: PBSParameters) -> ShortintKeySwitchingParameters {
+    ShortintKeySwitchingParameters::new(output_params.ks_base_log, output_params.ks_level)
+}
+
+#[test]
+fn test_cast_param_message_1_carry_1_to_param_message_2_carry_2() {
+    let (cks_1, sks_1) = gen_keys(PARAM_MESSAGE_1_CARRY_1);
+    let (cks_2, sks_2) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    let casting_key = CastingKey::new(&cks_1, &cks_2, casting_parameters(PARAM_MESSAGE_2_CARRY_2));
+
+    for clear_0 in 0..2 {
+        for clear_1 in 0..2 {
+            // The carry of the input is kept
+            let ct = sks_1.unchecked_add(&cks_1.encrypt(clear_0), &cks_1.encrypt(clear_1));
+
+            let ct_big: CiphertextBig = sks_2.cast_to(&casting_key, &ct);
+            assert_eq!(ct_big.degree, ct.degree);
+            assert_eq!(cks_2.decrypt(&ct_big), clear_0 + clear_1);
+
+            let ct_small: CiphertextSmall = sks_2.cast_to(&casting_key, &ct);
+            assert_eq!(cks_2.decrypt(&ct_small), clear_0 + clear_1);
+        }
+    }
+}
+
+#[test]
+fn test_cast_param_small_message_2_carry_2_to_param_message_3_carry_3() {
+    let (cks_1, _sks_1) = gen_keys(PARAM_SMALL_MESSAGE_2_CARRY_2);
+    let (cks_2, sks_2) = gen_keys(PARAM_MESSAGE_3_CARRY_3);
+    let casting_key = CastingKey::new(&cks_1, &cks_2, casting_parameters(PARAM_MESSAGE_3_CARRY_3));
+
+    let mut rng = rand::thread_rng();
+    let modulus = cks_1.parameters.message_modulus().0 as u64;
+
+    for _ in 0..NB_TEST {
+        let clear = rng.gen::<u64>() % modulus;
+
+        let ct: CiphertextBig = sks_2.cast_to(&casting_key, &cks_1.encrypt_small(clear));
+        let ct = sks_2.unchecked_scalar_mul(&ct, 2);
+        assert_eq!(cks_2.decrypt(&ct), 2 * clear);
+    }
+}
+
+#[test]
+#[should_panic(expected = "must be a multiple of the one of the input key")]
+fn test_cast_to_smaller_space() {
+    let (cks_1, _sks_1) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    let (cks_2, _sks_2) = gen_keys(PARAM_MESSAGE_1_CARRY_1);
+    let _ = CastingKey::new(&cks_1, &cks_2, casting_parameters(PARAM_MESSAGE_1_CARRY_1));
+}
@@ -0,0 +1,261 @@
+//! Key generation split between a constrained client and the server.
+//!
+//! Generating a [`ServerKey`] is expensive: the bootstrapping key holds thousands of GGSW
+//! ciphertexts, which are then converted to the Fourier domain. On a browser or a mobile device,
+//! the client only needs to generate the secret keys and to encrypt them, which is the part of
+//! the key generation that requires them:
+//!
+//! 1. the client calls [`gen_keys_for_transfer`], keeping the [`ClientKey`] and uploading the
+//!    [`ServerKeyMaterial`]. The key material is seeded: the masks of its ciphertexts are replaced
+//!    by seeds, which divides its size by about `k + 1` (for a GLWE dimension `k`) compared to an
+//!    uncompressed bootstrapping key, and by much more compared to the Fourier domain one;
+//! 2. the server calls [`ServerKeyMaterial::finalize`], which checks the material against the
+//!    parameters it expects, regenerates the masks from the seeds and converts the bootstrapping
+//!    key to the Fourier domain.
+//!
+//! # Example
+//!
+//! ```rust
+//! use tfhe::shortint::key_transfer::{gen_keys_for_transfer, ServerKeyMaterial};
+//! use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+//!
+//! // On the client
+//! let (cks, key_material) = gen_keys_for_transfer(PARAM_MESSAGE_2_CARRY_2);
+//! let upload = bincode::serialize(&key_material).unwrap();
+//!
+//! // On the server
+//! let key_material: ServerKeyMaterial = bincode::deserialize(&upload).unwrap();
+//! let sks = key_material.finalize(PARAM_MESSAGE_2_CARRY_2).unwrap();
+//!
+//! let ct = sks.unchecked_add(&cks.encrypt(1), &cks.encrypt(2));
+//! assert_eq!(cks.decrypt(&ct), 3);
+//! ```
+
+use crate::core_crypto::prelude::*;
+use crate::shortint::server_key::BootstrappingKeyStorage;
+use crate::shortint::{ClientKey, CompressedServerKey, ServerKey, ShortintParameterSet};
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+/// Error returned by [`ServerKeyMaterial::finalize`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum KeyMaterialError {
+    /// The key material was generated for other parameters than the expected ones
+    ParametersMismatch {
+        expected: Box<ShortintParameterSet>,
+        received: Box<ShortintParameterSet>,
+    },
+    /// A component of the key material does not match the parameters it was announced with
+    InvalidKey { component: &'static str },
+}
+
+impl Display for KeyMaterialError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::ParametersMismatch { expected, received } => write!(
+                f,
+                "The key material was generated for {received:?}, expected {expected:?}"
+            ),
+            Self::InvalidKey { component } => write!(
+                f,
+                "The {component} of the key material does not match its parameters"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for KeyMaterialError {}
+
+/// The encrypted key material sent by the client, from which the server derives its
+/// [`ServerKey`], see the [module documentation](self).
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ServerKeyMaterial {
+    pub(crate) parameters: ShortintParameterSet,
+    pub(crate) compressed_server_key: CompressedServerKey,
+}
+
+impl ServerKeyMaterial {
+    /// Generates the key material of `client_key`, this is the only part of the server key
+    /// generation done by the client.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the parameters of the client key use the multi-bit PBS, whose keys can not be
+    /// compressed.
+    pub fn new(client_key: &ClientKey) -> Self {
+        Self {
+            parameters: client_key.parameters,
+            compressed_server_key: CompressedServerKey::new(client_key),
+        }
+    }
+
+    /// Returns the parameters the key material was generated for.
+    pub fn parameters(&self) -> ShortintParameterSet {
+        self.parameters
+    }
+
+    /// Returns the size of the key material, i.e. of the upload of the client, in bytes.
+    pub fn size_bytes(&self) -> usize {
+        let CompressedServerKey {
+            key_switching_key,
+            bootstrapping_key,
+            ..
+        } = &self.compressed_server_key;
+        let bootstrapping_key_len = bootstrapping_key.as_view().into_container().len();
+        let key_switching_key_len = key_switching_key.as_view().into_container().len();
+
+        (bootstrapping_key_len + key_switching_key_len) * std::mem::size_of::<u64>()
+    }
+
+    /// Finalizes the [`ServerKey`], with a bootstrapping key in the Fourier domain.
+    ///
+    /// The key material comes from the client and is checked first: it must have been generated
+    /// for `expected_parameters` and its keys must match these parameters.
+    pub fn finalize<P>(self, expected_parameters: P) -> Result<ServerKey, KeyMaterialError>
+    where
+        P: Into<ShortintParameterSet>,
+    {
+        self.finalize_with_storage(expected_parameters, BootstrappingKeyStorage::Fourier)
+    }
+
+    /// Finalizes the [`ServerKey`] like [`Self::finalize`], storing the bootstrapping key as
+    /// requested by `storage`, see [`ServerKey::from_compressed`].
+    pub fn finalize_with_storage<P>(
+        self,
+        expected_parameters: P,
+        storage: BootstrappingKeyStorage,
+    ) -> Result<ServerKey, KeyMaterialError>
+    where
+        P: Into<ShortintParameterSet>,
+    {
+        let expected_parameters = expected_parameters.into();
+        if self.parameters != expected_parameters {
+            return Err(KeyMaterialError::ParametersMismatch {
+                expected: Box::new(expected_parameters),
+                received: Box::new(self.parameters),
+            });
+        }
+        self.check_keys()?;
+
+        Ok(ServerKey::from_compressed(
+            self.compressed_server_key,
+            storage,
+        ))
+    }
+
+    /// Checks that the keys have the dimensions and decompositions of the parameters.
+    fn check_keys(&self) -> Result<(), KeyMaterialError> {
+        let params = &self.parameters;
+        let CompressedServerKey {
+            key_switching_key,
+            bootstrapping_key,
+            message_modulus,
+            carry_modulus,
+            max_degree,
+            ciphertext_modulus,
+        } = &self.compressed_server_key;
+
+        let invalid = |component| Err(KeyMaterialError::InvalidKey { component });
+
+        if *message_modulus != params.message_modulus()
+            || *carry_modulus != params.carry_modulus()
+            || *ciphertext_modulus != params.ciphertext_modulus()
+            || max_degree.0 > message_modulus.0 * carry_modulus.0 - 1
+        {
+            return invalid("message space");
+        }
+
+        if bootstrapping_key.input_lwe_dimension() != params.lwe_dimension()
+            || bootstrapping_key.glwe_size() != params.glwe_dimension().to_glwe_size()
+            || bootstrapping_key.polynomial_size() != params.polynomial_size()
+            || bootstrapping_key.decomposition_base_log() != params.pbs_base_log()
+            || bootstrapping_key.decomposition_level_count() != params.pbs_level()
+            || bootstrapping_key.ciphertext_modulus() != params.ciphertext_modulus()
+        {
+            return invalid("bootstrapping key");
+        }
+
+        let large_lwe_dimension =
+            LweDimension(params.glwe_dimension().0 * params.polynomial_size().0);
+        if key_switching_key.input_key_lwe_dimension() != large_lwe_dimension
+            || key_switching_key.output_key_lwe_dimension() != params.lwe_dimension()
+            || key_switching_key.decomposition_base_log() != params.ks_base_log()
+            || key_switching_key.decomposition_level_count() != params.ks_level()
+            || key_switching_key.ciphertext_modulus() != params.ciphertext_modulus()
+        {
+            return invalid("key switching key");
+        }
+
+        Ok(())
+    }
+}
+
+/// Generates a [`ClientKey`] and the [`ServerKeyMaterial`] to send to the server.
+///
+/// # Panics
+///
+/// Panics if the parameters use the multi-bit PBS, whose keys can not be compressed.
+pub fn gen_keys_for_transfer<P>(parameters: P) -> (ClientKey, ServerKeyMaterial)
+where
+    P: TryInto<ShortintParameterSet>,
+    <P as TryInto<ShortintParameterSet>>::Error: std::fmt::Debug,
+{
+    let client_key = ClientKey::new(parameters);
+    let key_material = ServerKeyMaterial::new(&client_key);
+    (client_key, key_material)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shortint::keycache::KEY_CACHE;
+    use crate::shortint::parameters::{PARAM_MESSAGE_2_CARRY_2, PARAM_MESSAGE_2_CARRY_3};
+
+    #[test]
+    fn test_key_transfer() {
+        let keys = KEY_CACHE.get_from_param(PARAM_MESSAGE_2_CARRY_2);
+        let cks = keys.client_key();
+
+        let key_material = ServerKeyMaterial::new(cks);
+        let sks = ServerKey::from(key_material.compressed_server_key.clone());
+        assert!(
+            key_material.size_bytes()
+                < sks.bootstrapping_key_size_bytes() + sks.key_switching_key_size_bytes()
+        );
+
+        let serialized = bincode::serialize(&key_material).unwrap();
+        let key_material: ServerKeyMaterial = bincode::deserialize(&serialized).unwrap();
+
+        assert_eq!(
+            key_material.clone().finalize(PARAM_MESSAGE_2_CARRY_3).err(),
+            Some(KeyMaterialError::ParametersMismatch {
+                expected: Box::new(PARAM_MESSAGE_2_CARRY_3.into()),
+                received: Box::new(PARAM_MESSAGE_2_CARRY_2.into()),
+            })
+        );
+
+        // Parameters announced by the client which do not match its keys
+        let mut forged = key_material.clone();
+        forged.parameters = PARAM_MESSAGE_2_CARRY_3.into();
+        assert_eq!(
+            forged.finalize(PARAM_MESSAGE_2_CARRY_3).err(),
+            Some(KeyMaterialError::InvalidKey {
+                component: "message space"
+            })
+        );
+
+        for storage in [
+            BootstrappingKeyStorage::Fourier,
+            BootstrappingKeyStorage::OnTheFly,
+        ] {
+            let sks = key_material
+                .clone()
+                .finalize_with_storage(PARAM_MESSAGE_2_CARRY_2, storage)
+                .unwrap();
+            assert_eq!(sks.bootstrapping_key_storage(), storage);
+
+            let ct = sks.mul_lsb(&cks.encrypt(3), &cks.encrypt(3));
+            assert_eq!(cks.decrypt(&ct), 1);
+        }
+    }
+}
@@ -254,9 +254,63 @@ impl NamedParam for PBSParameters {
                 PARAM_MESSAGE_8_CARRY_0,
                 // Small
                 PARAM_SMALL_MESSAGE_1_CARRY_1,
+                PARAM_SMALL_MESSAGE_1_CARRY_2,
+                PARAM_SMALL_MESSAGE_1_CARRY_3,
+                PARAM_SMALL_MESSAGE_1_CARRY_4,
+                PARAM_SMALL_MESSAGE_1_CARRY_5,
+                PARAM_SMALL_MESSAGE_1_CARRY_6,
+                PARAM_SMALL_MESSAGE_1_CARRY_7,
+                PARAM_SMALL_MESSAGE_2_CARRY_1,
                 PARAM_SMALL_MESSAGE_2_CARRY_2,
+                PARAM_SMALL_MESSAGE_2_CARRY_3,
+                PARAM_SMALL_MESSAGE_2_CARRY_4,
+                PARAM_SMALL_MESSAGE_2_CARRY_5,
+                PARAM_SMALL_MESSAGE_2_CARRY_6,
+                PARAM_SMALL_MESSAGE_3_CARRY_1,
+                PARAM_SMALL_MESSAGE_3_CARRY_2,
                 PARAM_SMALL_MESSAGE_3_CARRY_3,
+                PARAM_SMALL_MESSAGE_3_CARRY_4,
+                PARAM_SMALL_MESSAGE_3_CARRY_5,
+                PARAM_SMALL_MESSAGE_4_CARRY_1,
+                PARAM_SMALL_MESSAGE_4_CARRY_2,
+                PARAM_SMALL_MESSAGE_4_CARRY_3,
                 PARAM_SMALL_MESSAGE_4_CARRY_4,
+                PARAM_SMALL_MESSAGE_5_CARRY_1,
+                PARAM_SMALL_MESSAGE_5_CARRY_2,
+                PARAM_SMALL_MESSAGE_5_CARRY_3,
+                PARAM_SMALL_MESSAGE_6_CARRY_1,
+                PARAM_SMALL_MESSAGE_6_CARRY_2,
+                PARAM_SMALL_MESSAGE_7_CARRY_1,
+                // Non native ciphertext modulus
+                PARAM_MESSAGE_1_CARRY_1_MODULUS_2_63,
+                PARAM_MESSAGE_1_CARRY_2_MODULUS_2_63,
+                PARAM_MESSAGE_1_CARRY_3_MODULUS_2_63,
+                PARAM_MESSAGE_1_CARRY_4_MODULUS_2_63,
+                PARAM_MESSAGE_1_CARRY_5_MODULUS_2_63,
+                PARAM_MESSAGE_1_CARRY_6_MODULUS_2_63,
+                PARAM_MESSAGE_1_CARRY_7_MODULUS_2_63,
+                PARAM_MESSAGE_2_CARRY_1_MODULUS_2_63,
+                PARAM_MESSAGE_2_CARRY_2_MODULUS_2_63,
+                PARAM_MESSAGE_2_CARRY_3_MODULUS_2_63,
+                PARAM_MESSAGE_2_CARRY_4_MODULUS_2_63,
+                PARAM_MESSAGE_2_CARRY_5_MODULUS_2_63,
+                PARAM_MESSAGE_2_CARRY_6_MODULUS_2_63,
+                PARAM_MESSAGE_3_CARRY_1_MODULUS_2_63,
+                PARAM_MESSAGE_3_CARRY_2_MODULUS_2_63,
+                PARAM_MESSAGE_3_CARRY_3_MODULUS_2_63,
+                PARAM_MESSAGE_3_CARRY_4_MODULUS_2_63,
+                PARAM_MESSAGE_3_CARRY_5_MODULUS_2_63,
+                PARAM_MESSAGE_4_CARRY_1_MODULUS_2_63,
+                PARAM_MESSAGE_4_CARRY_2_MODULUS_2_63,
+                PARAM_MESSAGE_4_CARRY_3_MODULUS_2_63,
+                PARAM_MESSAGE_4_CARRY_4_MODULUS_2_63,
+                PARAM_MESSAGE_5_CARRY_1_MODULUS_2_63,
+                PARAM_MESSAGE_5_CARRY_2_MODULUS_2_63,
+                PARAM_MESSAGE_5_CARRY_3_MODULUS_2_63,
+                PARAM_MESSAGE_6_CARRY_1_MODULUS_2_63,
+                PARAM_MESSAGE_6_CARRY_2_MODULUS_2_63,
+                PARAM_MESSAGE_7_CARRY_1_MODULUS_2_63,
+                PARAM_MESSAGE_1_CARRY_1_MODULUS_2_32,
             )
         );
     }
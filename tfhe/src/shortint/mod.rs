@@ -49,8 +49,11 @@
 pub mod ciphertext;
 pub mod client_key;
 pub mod engine;
+pub mod key_switching_key;
+pub mod key_transfer;
 #[cfg(any(test, doctest, feature = "internal-keycache"))]
 pub mod keycache;
+pub mod noise_squashing;
 pub mod parameters;
 pub mod prelude;
 pub mod public_key;
@@ -58,15 +61,17 @@ pub mod server_key;
 pub mod wopbs;
 
 pub use ciphertext::{
-    CiphertextBase, CiphertextBig, CiphertextSmall, CompressedCiphertextBase,
-    CompressedCiphertextBig, CompressedCiphertextSmall, PBSOrder, PBSOrderMarker,
+    CiphertextBase, CiphertextBig, CiphertextNoPaddingBase, CiphertextNoPaddingBig,
+    CiphertextNoPaddingSmall, CiphertextSmall, CompressedCiphertextBase, CompressedCiphertextBig,
+    CompressedCiphertextSmall, PBSOrder, PBSOrderMarker,
 };
-pub use client_key::ClientKey;
+pub use client_key::{ClientKey, ClientKeySeed};
 pub use parameters::{
     CarryModulus, CiphertextModulus, EncryptionKeyChoice, MessageModulus, PBSParameters,
     ShortintParameterSet, WopbsParameters,
 };
 pub use public_key::{
+    CompactCiphertextListBase, CompactCiphertextListBig, CompactPublicKeyBase, CompactPublicKeyBig,
     CompressedPublicKeyBase, CompressedPublicKeyBig, CompressedPublicKeySmall, PublicKeyBase,
     PublicKeyBig, PublicKeySmall,
 };
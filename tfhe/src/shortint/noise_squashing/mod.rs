@@ -0,0 +1,163 @@
+//! Module with the definition of the noise squashing.
+//!
+//! The noise of a shortint ciphertext depends on the computations it went through, so a
+//! decryption share revealing the phase of the ciphertext (e.g. in a threshold decryption protocol)
+//! may leak information on these computations or on the inputs. Squashing the noise computes a
+//! programmable bootstrapping of the ciphertext towards a 128 bits GLWE secret key, whose output
+//! encrypts the same message and carries with a noise negligible compared to the 128 bits
+//! modulus. This noise still depends on the input ciphertext, so it is then flooded with a much
+//! larger t-uniform noise, which statistically hides it while leaving the message decryptable.
+//! [`NoiseSquashingParameters::check_noise_budget`] checks that the flooding noise fits in the
+//! 128 bits modulus.
+//!
+//! Only the message and carry spaces of [`NOISE_SQUASHING_PARAM_MESSAGE_2_CARRY_2`] are
+//! supported, [`NoiseSquashingParameters::for_parameters`] returns an error for the others.
+//!
+//! [`NOISE_SQUASHING_PARAM_MESSAGE_2_CARRY_2`]:
+//! crate::shortint::parameters::NOISE_SQUASHING_PARAM_MESSAGE_2_CARRY_2
+//!
+//! The 128 bits secret key is held in a [`NoiseSquashingPrivateKey`] (in an MPC setting, it is the
+//! key shared between the parties) and the public [`NoiseSquashingKey`] is generated from it and
+//! from the [`ClientKey`].
+//!
+//! # Example
+//!
+//! ```rust
+//! use tfhe::shortint::gen_keys;
+//! use tfhe::shortint::noise_squashing::{NoiseSquashingKey, NoiseSquashingPrivateKey};
+//! use tfhe::shortint::parameters::{
+//!     NOISE_SQUASHING_PARAM_MESSAGE_2_CARRY_2, PARAM_MESSAGE_2_CARRY_2,
+//! };
+//!
+//! let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+//!
+//! let noise_squashing_private_key =
+//!     NoiseSquashingPrivateKey::new(NOISE_SQUASHING_PARAM_MESSAGE_2_CARRY_2);
+//! let noise_squashing_key = NoiseSquashingKey::new(&cks, &noise_squashing_private_key);
+//!
+//! let ct = sks.unchecked_add(&cks.encrypt(3), &cks.encrypt(2));
+//!
+//! let squashed_ct = noise_squashing_key.squash_noise(&sks, &ct);
+//! assert_eq!(
+//!     noise_squashing_private_key.decrypt_message_and_carry(&squashed_ct),
+//!     5
+//! );
+//! assert_eq!(noise_squashing_private_key.decrypt(&squashed_ct), 1);
+//! ```
+
+use crate::core_crypto::algorithms::*;
+use crate::core_crypto::commons::math::decomposition::SignedDecomposer;
+use crate::core_crypto::commons::parameters::*;
+use crate::core_crypto::entities::*;
+use crate::shortint::ciphertext::Degree;
+use crate::shortint::engine::ShortintEngine;
+use crate::shortint::parameters::{CarryModulus, MessageModulus, NoiseSquashingParameters};
+use crate::shortint::{CiphertextBase, ClientKey, PBSOrderMarker, ServerKey};
+use serde::{Deserialize, Serialize};
+
+#[cfg(test)]
+mod test;
+
+/// The 128 bits secret key under which the squashed ciphertexts are encrypted.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct NoiseSquashingPrivateKey {
+    pub(crate) glwe_secret_key: GlweSecretKeyOwned<u128>,
+    pub(crate) params: NoiseSquashingParameters,
+}
+
+impl NoiseSquashingPrivateKey {
+    /// Generates a new 128 bits secret key.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the flooding noise of the parameters does not fit in the noise budget, see
+    /// [`NoiseSquashingParameters::check_noise_budget`].
+    pub fn new(params: NoiseSquashingParameters) -> Self {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine.new_noise_squashing_private_key(params).unwrap()
+        })
+    }
+
+    pub fn parameters(&self) -> NoiseSquashingParameters {
+        self.params
+    }
+
+    /// Decrypts a [`SquashedNoiseCiphertext`], returning the message and the carries.
+    pub fn decrypt_message_and_carry(&self, ct: &SquashedNoiseCiphertext) -> u64 {
+        let lwe_secret_key = LweSecretKey::from_container(self.glwe_secret_key.as_ref());
+        let decrypted = decrypt_lwe_ciphertext(&lwe_secret_key, &ct.ct).0;
+
+        let modulus_sup = (ct.message_modulus.0 * ct.carry_modulus.0) as u128;
+        let delta = (1u128 << 127) / modulus_sup;
+
+        // Round to the closest multiple of delta, keeping the padding bit
+        let decomposer = SignedDecomposer::new(
+            DecompositionBaseLog(modulus_sup.ilog2() as usize + 1),
+            DecompositionLevelCount(1),
+        );
+
+        (decomposer.closest_representable(decrypted) / delta) as u64
+    }
+
+    /// Decrypts a [`SquashedNoiseCiphertext`], returning the message without the carries.
+    pub fn decrypt(&self, ct: &SquashedNoiseCiphertext) -> u64 {
+        self.decrypt_message_and_carry(ct) % ct.message_modulus.0 as u64
+    }
+}
+
+/// The public key used to squash the noise of the ciphertexts of a [`ServerKey`].
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct NoiseSquashingKey {
+    pub(crate) bootstrapping_key: Fourier128LweBootstrapKeyOwned,
+    pub(crate) params: NoiseSquashingParameters,
+}
+
+impl NoiseSquashingKey {
+    /// Generates the key squashing the noise of ciphertexts encrypted under `client_key` towards
+    /// `private_key`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the parameters of the two keys use different message or carry spaces.
+    pub fn new(client_key: &ClientKey, private_key: &NoiseSquashingPrivateKey) -> Self {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine
+                .new_noise_squashing_key(client_key, private_key)
+                .unwrap()
+        })
+    }
+
+    pub fn parameters(&self) -> NoiseSquashingParameters {
+        self.params
+    }
+
+    /// Squashes the noise of `ct`, whose message and carries are kept: `ct` is bootstrapped to
+    /// the 128 bits key and the noise of the output is flooded.
+    ///
+    /// The `server_key` is used to keyswitch ciphertexts encrypted under the big key to the input
+    /// key of the bootstrapping. The noise of `ct` must be small enough for a PBS to be correct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the ciphertext does not use the native modulus or if its message or carry spaces
+    /// differ from the ones of the noise squashing parameters.
+    pub fn squash_noise<OpOrder: PBSOrderMarker>(
+        &self,
+        server_key: &ServerKey,
+        ct: &CiphertextBase<OpOrder>,
+    ) -> SquashedNoiseCiphertext {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine.squash_noise(self, server_key, ct).unwrap()
+        })
+    }
+}
+
+/// A ciphertext encrypted under a [`NoiseSquashingPrivateKey`], output of
+/// [`NoiseSquashingKey::squash_noise`].
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SquashedNoiseCiphertext {
+    pub ct: LweCiphertextOwned<u128>,
+    pub degree: Degree,
+    pub message_modulus: MessageModulus,
+    pub carry_modulus: CarryModulus,
+}
@@ -0,0 +1,146 @@
+use crate::core_crypto::algorithms::decrypt_lwe_ciphertext;
+use crate::core_crypto::commons::dispersion::{DynamicDistribution, StandardDev};
+use crate::core_crypto::entities::LweSecretKey;
+use crate::shortint::gen_keys;
+use crate::shortint::noise_squashing::{NoiseSquashingKey, NoiseSquashingPrivateKey};
+use crate::shortint::parameters::{
+    CarryModulus, EncryptionKeyChoice, MessageModulus, NoiseSquashingError,
+    NoiseSquashingParameters, PBSParameters, NOISE_SQUASHING_PARAM_MESSAGE_2_CARRY_2,
+    PARAM_MESSAGE_1_CARRY_1, PARAM_MESSAGE_2_CARRY_2, PARAM_MESSAGE_3_CARRY_3,
+    PARAM_SMALL_MESSAGE_2_CARRY_2,
+};
+use rand::Rng;
+
+const NB_TEST: usize = 10;
+
+#[test]
+fn test_squash_noise_param_message_2_carry_2() {
+    squash_noise(PARAM_MESSAGE_2_CARRY_2);
+}
+
+#[test]
+fn test_squash_noise_param_small_message_2_carry_2() {
+    squash_noise(PARAM_SMALL_MESSAGE_2_CARRY_2);
+}
+
+fn squash_noise(params: PBSParameters) {
+    let (cks, sks) = gen_keys(params);
+    let squashing_params = NOISE_SQUASHING_PARAM_MESSAGE_2_CARRY_2;
+    assert!(squashing_params.is_compatible_with(&params));
+
+    let private_key = NoiseSquashingPrivateKey::new(squashing_params);
+    let squashing_key = NoiseSquashingKey::new(&cks, &private_key);
+
+    let mut rng = rand::thread_rng();
+    let modulus = cks.parameters.message_modulus().0 as u64;
+
+    for _ in 0..NB_TEST {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+
+        let squashed_ct = match params.encryption_key_choice {
+            EncryptionKeyChoice::Big => {
+                let ct = sks.unchecked_add(&cks.encrypt(clear_0), &cks.encrypt(clear_1));
+                let squashed_ct = squashing_key.squash_noise(&sks, &ct);
+                assert_eq!(squashed_ct.degree, ct.degree);
+                squashed_ct
+            }
+            EncryptionKeyChoice::Small => {
+                let ct =
+                    sks.unchecked_add(&cks.encrypt_small(clear_0), &cks.encrypt_small(clear_1));
+                let squashed_ct = squashing_key.squash_noise(&sks, &ct);
+                assert_eq!(squashed_ct.degree, ct.degree);
+                squashed_ct
+            }
+        };
+
+        assert_eq!(
+            private_key.decrypt_message_and_carry(&squashed_ct),
+            clear_0 + clear_1
+        );
+        assert_eq!(
+            private_key.decrypt(&squashed_ct),
+            (clear_0 + clear_1) % modulus
+        );
+    }
+}
+
+#[test]
+fn test_squash_noise_floods_the_noise() {
+    let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    let squashing_params = NOISE_SQUASHING_PARAM_MESSAGE_2_CARRY_2;
+    let private_key = NoiseSquashingPrivateKey::new(squashing_params);
+    let squashing_key = NoiseSquashingKey::new(&cks, &private_key);
+    let lwe_secret_key = LweSecretKey::from_container(private_key.glwe_secret_key.as_ref());
+
+    let delta = (1u128 << 127) / 16;
+    let ct = cks.encrypt(3);
+
+    for _ in 0..NB_TEST {
+        let squashed_ct = squashing_key.squash_noise(&sks, &ct);
+        assert_eq!(private_key.decrypt(&squashed_ct), 3);
+
+        // The noise is flooded far above the noise of the bootstrapping, and stays below the
+        // flooding bound
+        let noise = decrypt_lwe_ciphertext(&lwe_secret_key, &squashed_ct.ct)
+            .0
+            .wrapping_sub(3 * delta) as i128;
+        assert!(noise.unsigned_abs() > 1 << (128 + squashing_params.bootstrap_noise_log2_bound));
+        assert!(noise.unsigned_abs() <= 1 << (128 - 8));
+    }
+}
+
+#[test]
+fn test_noise_squashing_parameters() {
+    let params = NOISE_SQUASHING_PARAM_MESSAGE_2_CARRY_2;
+    assert_eq!(params.check_noise_budget(), Ok(()));
+    assert_eq!(
+        NoiseSquashingParameters::for_parameters(&PARAM_MESSAGE_2_CARRY_2),
+        Ok(params)
+    );
+    for pbs_params in [PARAM_MESSAGE_1_CARRY_1, PARAM_MESSAGE_3_CARRY_3] {
+        assert_eq!(
+            NoiseSquashingParameters::for_parameters(&pbs_params),
+            Err(NoiseSquashingError::UnsupportedPrecision {
+                message_modulus: pbs_params.message_modulus,
+                carry_modulus: pbs_params.carry_modulus,
+            })
+        );
+    }
+
+    let with_flooding = |flooding_noise_distribution| NoiseSquashingParameters {
+        flooding_noise_distribution,
+        ..params
+    };
+    assert_eq!(
+        with_flooding(DynamicDistribution::Gaussian(StandardDev(2f64.powi(-10))))
+            .check_noise_budget(),
+        Err(NoiseSquashingError::NonUniformFlooding)
+    );
+    assert_eq!(
+        with_flooding(DynamicDistribution::TUniform { log2_bound: -30 }).check_noise_budget(),
+        Err(NoiseSquashingError::InsufficientFlooding {
+            flooding_log2_bound: -30,
+            required_log2_bound: -24,
+        })
+    );
+    assert_eq!(
+        with_flooding(DynamicDistribution::TUniform { log2_bound: -6 }).check_noise_budget(),
+        Err(NoiseSquashingError::NoiseBudgetExceeded {
+            flooding_log2_bound: -6,
+            budget_log2_bound: -6,
+        })
+    );
+    assert_eq!(
+        with_flooding(DynamicDistribution::TUniform { log2_bound: -7 }).check_noise_budget(),
+        Ok(())
+    );
+
+    // Smaller message and carry spaces leave a larger budget
+    let params = NoiseSquashingParameters {
+        message_modulus: MessageModulus(2),
+        carry_modulus: CarryModulus(2),
+        ..with_flooding(DynamicDistribution::TUniform { log2_bound: -6 })
+    };
+    assert_eq!(params.check_noise_budget(), Ok(()));
+}
@@ -0,0 +1,990 @@
+//! Validated construction of custom parameter sets.
+//!
+//! # Example
+//!
+//! ```rust
+//! use tfhe::shortint::parameters::{
+//!     DecompositionBaseLog, DecompositionLevelCount, PBSParametersBuilder, ParameterError,
+//!     PolynomialSize, PARAM_MESSAGE_2_CARRY_2,
+//! };
+//!
+//! // Start from an existing parameter set and change some of its values
+//! let params = PBSParametersBuilder::from(PARAM_MESSAGE_2_CARRY_2)
+//!     .ks_base_log(DecompositionBaseLog(4))
+//!     .ks_level(DecompositionLevelCount(4))
+//!     .build()
+//!     .unwrap();
+//! assert_eq!(params.ks_level, DecompositionLevelCount(4));
+//!
+//! // Inconsistent parameters are rejected
+//! let error = PBSParametersBuilder::from(PARAM_MESSAGE_2_CARRY_2)
+//!     .polynomial_size(PolynomialSize(2000))
+//!     .build()
+//!     .unwrap_err();
+//! assert_eq!(
+//!     error,
+//!     ParameterError::PolynomialSizeNotPowerOfTwo(PolynomialSize(2000))
+//! );
+//! ```
+
+use super::parameters_wopbs::WopbsCompatibilityError;
+use super::parameters_wopbs_message_carry::ALL_PARAMETER_VEC_WOPBS;
+use super::*;
+use std::fmt::{Display, Formatter};
+
+/// Error returned by [`PBSParametersBuilder::build`] when the parameters are inconsistent.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParameterError {
+    /// A parameter without default value was not set
+    MissingParameter(&'static str),
+    /// A dimension was set to zero
+    ZeroDimension(&'static str),
+    PolynomialSizeNotPowerOfTwo(PolynomialSize),
+    /// The ciphertext modulus is neither the native modulus nor a power of two, the shortint
+    /// engine only supports moduli which divide the native one
+    UnsupportedCiphertextModulus(CiphertextModulus),
+    /// A standard deviation is not a finite value in ]0, 1[
+    InvalidStandardDev {
+        name: &'static str,
+        std_dev: StandardDev,
+    },
+    /// The bound of a t-uniform noise is not smaller than a quarter of the torus, or is smaller
+    /// than the precision of the ciphertext modulus
+    InvalidTUniformBound {
+        name: &'static str,
+        log2_bound: i32,
+        modulus_bits: u32,
+    },
+    /// The standard deviation of a Gaussian secret key is not a finite positive value
+    InvalidSecretKeyStdDev(f64),
+    /// A decomposition has no level, or its base log is zero, or it needs more bits than the
+    /// ciphertext modulus provides
+    InvalidDecomposition {
+        name: &'static str,
+        base_log: DecompositionBaseLog,
+        level: DecompositionLevelCount,
+        modulus_bits: u32,
+    },
+    /// The message or carry modulus is not a power of two
+    ModulusNotPowerOfTwo {
+        name: &'static str,
+        modulus: usize,
+    },
+    /// The message and carry do not fit in the accumulator of the PBS or in the ciphertext
+    /// modulus, the padding bit included
+    PlaintextSpaceTooLarge {
+        message_modulus: MessageModulus,
+        carry_modulus: CarryModulus,
+        polynomial_size: PolynomialSize,
+        modulus_bits: u32,
+    },
+}
+
+impl Display for ParameterError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MissingParameter(name) => {
+                write!(f, "The parameter `{name}` is required but was not set")
+            }
+            Self::ZeroDimension(name) => {
+                write!(f, "The parameter `{name}` must be greater than zero")
+            }
+            Self::PolynomialSizeNotPowerOfTwo(polynomial_size) => {
+                write!(
+                    f,
+                    "The polynomial size must be a power of two, got {}",
+                    polynomial_size.0
+                )
+            }
+            Self::UnsupportedCiphertextModulus(ciphertext_modulus) => {
+                write!(
+                    f,
+                    "The ciphertext modulus must be the native modulus or a power of two, got \
+                    {ciphertext_modulus}"
+                )
+            }
+            Self::InvalidStandardDev { name, std_dev } => {
+                write!(
+                    f,
+                    "The parameter `{name}` must be a finite standard deviation in ]0, 1[, got {}",
+                    std_dev.0
+                )
+            }
+            Self::InvalidTUniformBound {
+                name,
+                log2_bound,
+                modulus_bits,
+            } => {
+                write!(
+                    f,
+                    "The parameter `{name}` must have a t-uniform bound 2^b with \
+                    -{modulus_bits} <= b <= -2, got b = {log2_bound}"
+                )
+            }
+            Self::InvalidSecretKeyStdDev(std_dev) => {
+                write!(
+                    f,
+                    "The standard deviation of a Gaussian secret key must be finite and positive, \
+                    got {std_dev}"
+                )
+            }
+            Self::InvalidDecomposition {
+                name,
+                base_log,
+                level,
+                modulus_bits,
+            } => {
+                write!(
+                    f,
+                    "Invalid {name} decomposition with base log {} and level {}, both must be \
+                    non zero and their product must be at most {modulus_bits}, the number of \
+                    bits of the ciphertext modulus",
+                    base_log.0, level.0
+                )
+            }
+            Self::ModulusNotPowerOfTwo { name, modulus } => {
+                write!(
+                    f,
+                    "The {name} modulus must be a power of two, got {modulus}"
+                )
+            }
+            Self::PlaintextSpaceTooLarge {
+                message_modulus,
+                carry_modulus,
+                polynomial_size,
+                modulus_bits,
+            } => {
+                write!(
+                    f,
+                    "The product of the message modulus ({}) and the carry modulus ({}) must be \
+                    at most the polynomial size ({}), and twice this product must fit in the \
+                    {modulus_bits} bits of the ciphertext modulus",
+                    message_modulus.0, carry_modulus.0, polynomial_size.0
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParameterError {}
+
+/// Builder of [`PBSParameters`] checking the consistency of the parameters.
+///
+/// All the parameters are required, except the ciphertext modulus which defaults to the native
+/// modulus, the encryption key choice which defaults to [`EncryptionKeyChoice::Big`] and the
+/// secret key distribution which defaults to [`SecretKeyDistribution::Binary`].
+///
+/// The checks only reject parameters which cannot work, they do not guarantee the security nor
+/// the correctness of the resulting parameter set.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct PBSParametersBuilder {
+    lwe_dimension: Option<LweDimension>,
+    glwe_dimension: Option<GlweDimension>,
+    polynomial_size: Option<PolynomialSize>,
+    lwe_noise_distribution: Option<DynamicDistribution>,
+    glwe_noise_distribution: Option<DynamicDistribution>,
+    pbs_base_log: Option<DecompositionBaseLog>,
+    pbs_level: Option<DecompositionLevelCount>,
+    ks_base_log: Option<DecompositionBaseLog>,
+    ks_level: Option<DecompositionLevelCount>,
+    message_modulus: Option<MessageModulus>,
+    carry_modulus: Option<CarryModulus>,
+    ciphertext_modulus: CiphertextModulus,
+    encryption_key_choice: EncryptionKeyChoice,
+    secret_key_distribution: SecretKeyDistribution,
+}
+
+impl Default for PBSParametersBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<PBSParameters> for PBSParametersBuilder {
+    fn from(params: PBSParameters) -> Self {
+        Self {
+            lwe_dimension: Some(params.lwe_dimension),
+            glwe_dimension: Some(params.glwe_dimension),
+            polynomial_size: Some(params.polynomial_size),
+            lwe_noise_distribution: Some(params.lwe_noise_distribution),
+            glwe_noise_distribution: Some(params.glwe_noise_distribution),
+            pbs_base_log: Some(params.pbs_base_log),
+            pbs_level: Some(params.pbs_level),
+            ks_base_log: Some(params.ks_base_log),
+            ks_level: Some(params.ks_level),
+            message_modulus: Some(params.message_modulus),
+            carry_modulus: Some(params.carry_modulus),
+            ciphertext_modulus: params.ciphertext_modulus,
+            encryption_key_choice: params.encryption_key_choice,
+            secret_key_distribution: params.secret_key_distribution,
+        }
+    }
+}
+
+impl PBSParametersBuilder {
+    pub fn new() -> Self {
+        Self {
+            lwe_dimension: None,
+            glwe_dimension: None,
+            polynomial_size: None,
+            lwe_noise_distribution: None,
+            glwe_noise_distribution: None,
+            pbs_base_log: None,
+            pbs_level: None,
+            ks_base_log: None,
+            ks_level: None,
+            message_modulus: None,
+            carry_modulus: None,
+            ciphertext_modulus: CiphertextModulus::new_native(),
+            encryption_key_choice: EncryptionKeyChoice::Big,
+            secret_key_distribution: SecretKeyDistribution::Binary,
+        }
+    }
+
+    pub fn lwe_dimension(mut self, lwe_dimension: LweDimension) -> Self {
+        self.lwe_dimension = Some(lwe_dimension);
+        self
+    }
+
+    pub fn glwe_dimension(mut self, glwe_dimension: GlweDimension) -> Self {
+        self.glwe_dimension = Some(glwe_dimension);
+        self
+    }
+
+    pub fn polynomial_size(mut self, polynomial_size: PolynomialSize) -> Self {
+        self.polynomial_size = Some(polynomial_size);
+        self
+    }
+
+    /// Sets the distribution of the LWE noise
+    pub fn lwe_noise_distribution(
+        mut self,
+        lwe_noise_distribution: impl Into<DynamicDistribution>,
+    ) -> Self {
+        self.lwe_noise_distribution = Some(lwe_noise_distribution.into());
+        self
+    }
+
+    /// Sets the distribution of the GLWE noise
+    pub fn glwe_noise_distribution(
+        mut self,
+        glwe_noise_distribution: impl Into<DynamicDistribution>,
+    ) -> Self {
+        self.glwe_noise_distribution = Some(glwe_noise_distribution.into());
+        self
+    }
+
+    /// Sets a gaussian LWE noise, the standard deviation can be given in any representation
+    /// convertible to [`StandardDev`], e.g. [`Variance`] or [`NoiseBits`]
+    pub fn lwe_modular_std_dev(self, lwe_modular_std_dev: impl Into<StandardDev>) -> Self {
+        self.lwe_noise_distribution(DynamicDistribution::new_gaussian(lwe_modular_std_dev))
+    }
+
+    /// Sets a gaussian GLWE noise, the standard deviation can be given in any representation
+    /// convertible to [`StandardDev`], e.g. [`Variance`] or [`NoiseBits`]
+    pub fn glwe_modular_std_dev(self, glwe_modular_std_dev: impl Into<StandardDev>) -> Self {
+        self.glwe_noise_distribution(DynamicDistribution::new_gaussian(glwe_modular_std_dev))
+    }
+
+    pub fn pbs_base_log(mut self, pbs_base_log: DecompositionBaseLog) -> Self {
+        self.pbs_base_log = Some(pbs_base_log);
+        self
+    }
+
+    pub fn pbs_level(mut self, pbs_level: DecompositionLevelCount) -> Self {
+        self.pbs_level = Some(pbs_level);
+        self
+    }
+
+    pub fn ks_base_log(mut self, ks_base_log: DecompositionBaseLog) -> Self {
+        self.ks_base_log = Some(ks_base_log);
+        self
+    }
+
+    pub fn ks_level(mut self, ks_level: DecompositionLevelCount) -> Self {
+        self.ks_level = Some(ks_level);
+        self
+    }
+
+    pub fn message_modulus(mut self, message_modulus: MessageModulus) -> Self {
+        self.message_modulus = Some(message_modulus);
+        self
+    }
+
+    pub fn carry_modulus(mut self, carry_modulus: CarryModulus) -> Self {
+        self.carry_modulus = Some(carry_modulus);
+        self
+    }
+
+    pub fn ciphertext_modulus(mut self, ciphertext_modulus: CiphertextModulus) -> Self {
+        self.ciphertext_modulus = ciphertext_modulus;
+        self
+    }
+
+    pub fn encryption_key_choice(mut self, encryption_key_choice: EncryptionKeyChoice) -> Self {
+        self.encryption_key_choice = encryption_key_choice;
+        self
+    }
+
+    /// Sets the distribution of the GLWE secret key, see
+    /// [`PBSParameters::secret_key_distribution`].
+    pub fn secret_key_distribution(
+        mut self,
+        secret_key_distribution: SecretKeyDistribution,
+    ) -> Self {
+        self.secret_key_distribution = secret_key_distribution;
+        self
+    }
+
+    /// Checks the consistency of the parameters and returns the parameter set.
+    pub fn build(self) -> Result<PBSParameters, ParameterError> {
+        let lwe_dimension = required(self.lwe_dimension, "lwe_dimension")?;
+        let glwe_dimension = required(self.glwe_dimension, "glwe_dimension")?;
+        let polynomial_size = required(self.polynomial_size, "polynomial_size")?;
+        let lwe_noise_distribution =
+            required(self.lwe_noise_distribution, "lwe_noise_distribution")?;
+        let glwe_noise_distribution =
+            required(self.glwe_noise_distribution, "glwe_noise_distribution")?;
+        let pbs_base_log = required(self.pbs_base_log, "pbs_base_log")?;
+        let pbs_level = required(self.pbs_level, "pbs_level")?;
+        let ks_base_log = required(self.ks_base_log, "ks_base_log")?;
+        let ks_level = required(self.ks_level, "ks_level")?;
+        let message_modulus = required(self.message_modulus, "message_modulus")?;
+        let carry_modulus = required(self.carry_modulus, "carry_modulus")?;
+
+        check_dimensions(lwe_dimension, glwe_dimension, polynomial_size)?;
+
+        let modulus_bits = modulus_bits(self.ciphertext_modulus)?;
+
+        check_noise_distribution(
+            "lwe_noise_distribution",
+            lwe_noise_distribution,
+            modulus_bits,
+        )?;
+        check_noise_distribution(
+            "glwe_noise_distribution",
+            glwe_noise_distribution,
+            modulus_bits,
+        )?;
+
+        check_decomposition("PBS", pbs_base_log, pbs_level, modulus_bits)?;
+        check_decomposition("KS", ks_base_log, ks_level, modulus_bits)?;
+
+        if let SecretKeyDistribution::Gaussian { std_dev } = self.secret_key_distribution {
+            if !std_dev.is_finite() || std_dev <= 0. {
+                return Err(ParameterError::InvalidSecretKeyStdDev(std_dev));
+            }
+        }
+
+        if !message_modulus.0.is_power_of_two() {
+            return Err(ParameterError::ModulusNotPowerOfTwo {
+                name: "message",
+                modulus: message_modulus.0,
+            });
+        }
+        if !carry_modulus.0.is_power_of_two() {
+            return Err(ParameterError::ModulusNotPowerOfTwo {
+                name: "carry",
+                modulus: carry_modulus.0,
+            });
+        }
+
+        // One more bit is used by the padding
+        let plaintext_bits = (message_modulus.0 * carry_modulus.0).ilog2() + 1;
+        if message_modulus.0 * carry_modulus.0 > polynomial_size.0 || plaintext_bits > modulus_bits
+        {
+            return Err(ParameterError::PlaintextSpaceTooLarge {
+                message_modulus,
+                carry_modulus,
+                polynomial_size,
+                modulus_bits,
+            });
+        }
+
+        Ok(PBSParameters {
+            lwe_dimension,
+            glwe_dimension,
+            polynomial_size,
+            lwe_noise_distribution,
+            glwe_noise_distribution,
+            pbs_base_log,
+            pbs_level,
+            ks_base_log,
+            ks_level,
+            message_modulus,
+            carry_modulus,
+            ciphertext_modulus: self.ciphertext_modulus,
+            encryption_key_choice: self.encryption_key_choice,
+            secret_key_distribution: self.secret_key_distribution,
+        })
+    }
+}
+
+/// Builder of [`WopbsParameters`] checking the consistency of the parameters.
+///
+/// All the parameters are required, except the ciphertext modulus which defaults to the native
+/// modulus and the encryption key choice which defaults to [`EncryptionKeyChoice::Big`].
+/// [`WopbsParametersBuilder::for_pbs_parameters`] starts from parameters derived from a
+/// [`PBSParameters`] set.
+///
+/// As for [`PBSParametersBuilder`], the checks only reject parameters which cannot work. Use
+/// [`WopbsParametersBuilder::build_for`] to also check the compatibility of the parameters with the
+/// PBS parameters they are used along.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct WopbsParametersBuilder {
+    lwe_dimension: Option<LweDimension>,
+    glwe_dimension: Option<GlweDimension>,
+    polynomial_size: Option<PolynomialSize>,
+    lwe_modular_std_dev: Option<StandardDev>,
+    glwe_modular_std_dev: Option<StandardDev>,
+    pbs_base_log: Option<DecompositionBaseLog>,
+    pbs_level: Option<DecompositionLevelCount>,
+    ks_base_log: Option<DecompositionBaseLog>,
+    ks_level: Option<DecompositionLevelCount>,
+    pfks_base_log: Option<DecompositionBaseLog>,
+    pfks_level: Option<DecompositionLevelCount>,
+    pfks_modular_std_dev: Option<StandardDev>,
+    cbs_base_log: Option<DecompositionBaseLog>,
+    cbs_level: Option<DecompositionLevelCount>,
+    message_modulus: Option<MessageModulus>,
+    carry_modulus: Option<CarryModulus>,
+    ciphertext_modulus: CiphertextModulus,
+    encryption_key_choice: EncryptionKeyChoice,
+}
+
+impl Default for WopbsParametersBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<WopbsParameters> for WopbsParametersBuilder {
+    fn from(params: WopbsParameters) -> Self {
+        Self {
+            lwe_dimension: Some(params.lwe_dimension),
+            glwe_dimension: Some(params.glwe_dimension),
+            polynomial_size: Some(params.polynomial_size),
+            lwe_modular_std_dev: Some(params.lwe_modular_std_dev),
+            glwe_modular_std_dev: Some(params.glwe_modular_std_dev),
+            pbs_base_log: Some(params.pbs_base_log),
+            pbs_level: Some(params.pbs_level),
+            ks_base_log: Some(params.ks_base_log),
+            ks_level: Some(params.ks_level),
+            pfks_base_log: Some(params.pfks_base_log),
+            pfks_level: Some(params.pfks_level),
+            pfks_modular_std_dev: Some(params.pfks_modular_std_dev),
+            cbs_base_log: Some(params.cbs_base_log),
+            cbs_level: Some(params.cbs_level),
+            message_modulus: Some(params.message_modulus),
+            carry_modulus: Some(params.carry_modulus),
+            ciphertext_modulus: params.ciphertext_modulus,
+            encryption_key_choice: params.encryption_key_choice,
+        }
+    }
+}
+
+impl WopbsParametersBuilder {
+    pub fn new() -> Self {
+        Self {
+            lwe_dimension: None,
+            glwe_dimension: None,
+            polynomial_size: None,
+            lwe_modular_std_dev: None,
+            glwe_modular_std_dev: None,
+            pbs_base_log: None,
+            pbs_level: None,
+            ks_base_log: None,
+            ks_level: None,
+            pfks_base_log: None,
+            pfks_level: None,
+            pfks_modular_std_dev: None,
+            cbs_base_log: None,
+            cbs_level: None,
+            message_modulus: None,
+            carry_modulus: None,
+            ciphertext_modulus: CiphertextModulus::new_native(),
+            encryption_key_choice: EncryptionKeyChoice::Big,
+        }
+    }
+
+    /// Starts from WoPBS parameters derived from `pbs_params`, or returns `None` if none of the
+    /// built-in WoPBS parameter sets can be adapted to `pbs_params`.
+    ///
+    /// The message modulus, carry modulus, ciphertext modulus and encryption key choice are taken
+    /// from `pbs_params`. The other parameters are the ones of the first built-in set passing
+    /// [`WopbsParameters::check_compatibility`] once adapted, trying the set recommended for
+    /// `pbs_params` (see [`WopbsParameters::recommended_for`]) and then the sets in increasing
+    /// order of precision (message and carry bits), starting from the precision of `pbs_params`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::{
+    ///     CarryModulus, EncryptionKeyChoice, WopbsParametersBuilder, PARAM_MESSAGE_2_CARRY_2,
+    ///     PARAM_SMALL_MESSAGE_2_CARRY_2,
+    /// };
+    ///
+    /// let pbs_params = PARAM_MESSAGE_2_CARRY_2;
+    /// let wopbs_params = WopbsParametersBuilder::for_pbs_parameters(&pbs_params)
+    ///     .unwrap()
+    ///     .build_for(&pbs_params)
+    ///     .unwrap();
+    /// assert_eq!(wopbs_params.carry_modulus, CarryModulus(4));
+    ///
+    /// // There is no built-in WoPBS parameter set encrypting under the small key
+    /// let pbs_params = PARAM_SMALL_MESSAGE_2_CARRY_2;
+    /// let wopbs_params = WopbsParametersBuilder::for_pbs_parameters(&pbs_params)
+    ///     .unwrap()
+    ///     .build_for(&pbs_params)
+    ///     .unwrap();
+    /// assert_eq!(wopbs_params.encryption_key_choice, EncryptionKeyChoice::Small);
+    /// ```
+    pub fn for_pbs_parameters(pbs_params: &PBSParameters) -> Option<Self> {
+        let precision = |message_modulus: MessageModulus, carry_modulus: CarryModulus| {
+            message_modulus.0 * carry_modulus.0
+        };
+        let required_precision = precision(pbs_params.message_modulus, pbs_params.carry_modulus);
+
+        let mut candidates = ALL_PARAMETER_VEC_WOPBS
+            .into_iter()
+            .filter(|params| {
+                precision(params.message_modulus, params.carry_modulus) >= required_precision
+            })
+            .collect::<Vec<_>>();
+        // The sort is stable, the order of the sets with the same precision is kept
+        candidates.sort_by_key(|params| precision(params.message_modulus, params.carry_modulus));
+
+        WopbsParameters::recommended_for(pbs_params)
+            .into_iter()
+            .chain(candidates)
+            .map(|params| {
+                Self::from(params)
+                    .message_modulus(pbs_params.message_modulus)
+                    .carry_modulus(pbs_params.carry_modulus)
+                    .ciphertext_modulus(pbs_params.ciphertext_modulus)
+                    .encryption_key_choice(pbs_params.encryption_key_choice)
+            })
+            .find(|builder| builder.build_for(pbs_params).is_ok())
+    }
+
+    pub fn lwe_dimension(mut self, lwe_dimension: LweDimension) -> Self {
+        self.lwe_dimension = Some(lwe_dimension);
+        self
+    }
+
+    pub fn glwe_dimension(mut self, glwe_dimension: GlweDimension) -> Self {
+        self.glwe_dimension = Some(glwe_dimension);
+        self
+    }
+
+    pub fn polynomial_size(mut self, polynomial_size: PolynomialSize) -> Self {
+        self.polynomial_size = Some(polynomial_size);
+        self
+    }
+
+    pub fn lwe_modular_std_dev(mut self, lwe_modular_std_dev: impl Into<StandardDev>) -> Self {
+        self.lwe_modular_std_dev = Some(lwe_modular_std_dev.into());
+        self
+    }
+
+    pub fn glwe_modular_std_dev(mut self, glwe_modular_std_dev: impl Into<StandardDev>) -> Self {
+        self.glwe_modular_std_dev = Some(glwe_modular_std_dev.into());
+        self
+    }
+
+    pub fn pbs_base_log(mut self, pbs_base_log: DecompositionBaseLog) -> Self {
+        self.pbs_base_log = Some(pbs_base_log);
+        self
+    }
+
+    pub fn pbs_level(mut self, pbs_level: DecompositionLevelCount) -> Self {
+        self.pbs_level = Some(pbs_level);
+        self
+    }
+
+    pub fn ks_base_log(mut self, ks_base_log: DecompositionBaseLog) -> Self {
+        self.ks_base_log = Some(ks_base_log);
+        self
+    }
+
+    pub fn ks_level(mut self, ks_level: DecompositionLevelCount) -> Self {
+        self.ks_level = Some(ks_level);
+        self
+    }
+
+    pub fn pfks_base_log(mut self, pfks_base_log: DecompositionBaseLog) -> Self {
+        self.pfks_base_log = Some(pfks_base_log);
+        self
+    }
+
+    pub fn pfks_level(mut self, pfks_level: DecompositionLevelCount) -> Self {
+        self.pfks_level = Some(pfks_level);
+        self
+    }
+
+    pub fn pfks_modular_std_dev(mut self, pfks_modular_std_dev: impl Into<StandardDev>) -> Self {
+        self.pfks_modular_std_dev = Some(pfks_modular_std_dev.into());
+        self
+    }
+
+    pub fn cbs_base_log(mut self, cbs_base_log: DecompositionBaseLog) -> Self {
+        self.cbs_base_log = Some(cbs_base_log);
+        self
+    }
+
+    pub fn cbs_level(mut self, cbs_level: DecompositionLevelCount) -> Self {
+        self.cbs_level = Some(cbs_level);
+        self
+    }
+
+    pub fn message_modulus(mut self, message_modulus: MessageModulus) -> Self {
+        self.message_modulus = Some(message_modulus);
+        self
+    }
+
+    pub fn carry_modulus(mut self, carry_modulus: CarryModulus) -> Self {
+        self.carry_modulus = Some(carry_modulus);
+        self
+    }
+
+    pub fn ciphertext_modulus(mut self, ciphertext_modulus: CiphertextModulus) -> Self {
+        self.ciphertext_modulus = ciphertext_modulus;
+        self
+    }
+
+    pub fn encryption_key_choice(mut self, encryption_key_choice: EncryptionKeyChoice) -> Self {
+        self.encryption_key_choice = encryption_key_choice;
+        self
+    }
+
+    /// Checks the consistency of the parameters and returns the parameter set.
+    pub fn build(self) -> Result<WopbsParameters, ParameterError> {
+        let lwe_dimension = required(self.lwe_dimension, "lwe_dimension")?;
+        let glwe_dimension = required(self.glwe_dimension, "glwe_dimension")?;
+        let polynomial_size = required(self.polynomial_size, "polynomial_size")?;
+        let lwe_modular_std_dev = required(self.lwe_modular_std_dev, "lwe_modular_std_dev")?;
+        let glwe_modular_std_dev = required(self.glwe_modular_std_dev, "glwe_modular_std_dev")?;
+        let pbs_base_log = required(self.pbs_base_log, "pbs_base_log")?;
+        let pbs_level = required(self.pbs_level, "pbs_level")?;
+        let ks_base_log = required(self.ks_base_log, "ks_base_log")?;
+        let ks_level = required(self.ks_level, "ks_level")?;
+        let pfks_base_log = required(self.pfks_base_log, "pfks_base_log")?;
+        let pfks_level = required(self.pfks_level, "pfks_level")?;
+        let pfks_modular_std_dev = required(self.pfks_modular_std_dev, "pfks_modular_std_dev")?;
+        let cbs_base_log = required(self.cbs_base_log, "cbs_base_log")?;
+        let cbs_level = required(self.cbs_level, "cbs_level")?;
+        let message_modulus = required(self.message_modulus, "message_modulus")?;
+        let carry_modulus = required(self.carry_modulus, "carry_modulus")?;
+
+        check_dimensions(lwe_dimension, glwe_dimension, polynomial_size)?;
+
+        let modulus_bits = modulus_bits(self.ciphertext_modulus)?;
+
+        for (name, std_dev) in [
+            ("lwe_modular_std_dev", lwe_modular_std_dev),
+            ("glwe_modular_std_dev", glwe_modular_std_dev),
+            ("pfks_modular_std_dev", pfks_modular_std_dev),
+        ] {
+            check_noise_distribution(name, std_dev.into(), modulus_bits)?;
+        }
+
+        check_decomposition("PBS", pbs_base_log, pbs_level, modulus_bits)?;
+        check_decomposition("KS", ks_base_log, ks_level, modulus_bits)?;
+        check_decomposition("PFKS", pfks_base_log, pfks_level, modulus_bits)?;
+        check_decomposition("CBS", cbs_base_log, cbs_level, modulus_bits)?;
+
+        Ok(WopbsParameters {
+            lwe_dimension,
+            glwe_dimension,
+            polynomial_size,
+            lwe_modular_std_dev,
+            glwe_modular_std_dev,
+            pbs_base_log,
+            pbs_level,
+            ks_level,
+            ks_base_log,
+            pfks_level,
+            pfks_base_log,
+            pfks_modular_std_dev,
+            cbs_level,
+            cbs_base_log,
+            message_modulus,
+            carry_modulus,
+            ciphertext_modulus: self.ciphertext_modulus,
+            encryption_key_choice: self.encryption_key_choice,
+        })
+    }
+
+    /// Same as [`WopbsParametersBuilder::build`], also checking that the parameters can be used
+    /// along `pbs_params`, see [`WopbsParameters::check_compatibility`].
+    pub fn build_for(
+        self,
+        pbs_params: &PBSParameters,
+    ) -> Result<WopbsParameters, WopbsCompatibilityError> {
+        let params = self.build()?;
+        params.check_compatibility(pbs_params)?;
+        Ok(params)
+    }
+}
+
+fn required<T>(value: Option<T>, name: &'static str) -> Result<T, ParameterError> {
+    value.ok_or(ParameterError::MissingParameter(name))
+}
+
+fn check_dimensions(
+    lwe_dimension: LweDimension,
+    glwe_dimension: GlweDimension,
+    polynomial_size: PolynomialSize,
+) -> Result<(), ParameterError> {
+    if lwe_dimension.0 == 0 {
+        return Err(ParameterError::ZeroDimension("lwe_dimension"));
+    }
+    if glwe_dimension.0 == 0 {
+        return Err(ParameterError::ZeroDimension("glwe_dimension"));
+    }
+    if !polynomial_size.0.is_power_of_two() {
+        return Err(ParameterError::PolynomialSizeNotPowerOfTwo(polynomial_size));
+    }
+    Ok(())
+}
+
+/// Returns the number of bits needed to represent the values of the torus
+fn modulus_bits(ciphertext_modulus: CiphertextModulus) -> Result<u32, ParameterError> {
+    if !ciphertext_modulus.is_compatible_with_native_modulus() {
+        return Err(ParameterError::UnsupportedCiphertextModulus(
+            ciphertext_modulus,
+        ));
+    }
+
+    Ok(if ciphertext_modulus.is_native_modulus() {
+        u64::BITS
+    } else {
+        ciphertext_modulus.get_custom_modulus().ilog2()
+    })
+}
+
+fn check_noise_distribution(
+    name: &'static str,
+    noise_distribution: DynamicDistribution,
+    modulus_bits: u32,
+) -> Result<(), ParameterError> {
+    match noise_distribution {
+        DynamicDistribution::Gaussian(std_dev) => {
+            if !(std_dev.0.is_finite() && std_dev.0 > 0.0 && std_dev.0 < 1.0) {
+                return Err(ParameterError::InvalidStandardDev { name, std_dev });
+            }
+        }
+        DynamicDistribution::TUniform { log2_bound } => {
+            if !(-(modulus_bits as i32)..=-2).contains(&log2_bound) {
+                return Err(ParameterError::InvalidTUniformBound {
+                    name,
+                    log2_bound,
+                    modulus_bits,
+                });
+            }
+        }
+    }
+    Ok(())
+}
+
+fn check_decomposition(
+    name: &'static str,
+    base_log: DecompositionBaseLog,
+    level: DecompositionLevelCount,
+    modulus_bits: u32,
+) -> Result<(), ParameterError> {
+    if base_log.0 == 0 || level.0 == 0 || base_log.0 * level.0 > modulus_bits as usize {
+        return Err(ParameterError::InvalidDecomposition {
+            name,
+            base_log,
+            level,
+            modulus_bits,
+        });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builder_accepts_builtin_parameters() {
+        for entry in ParametersCatalog::builtin().iter() {
+            let params = PBSParametersBuilder::from(entry.parameters)
+                .build()
+                .unwrap();
+            assert_eq!(params, entry.parameters);
+        }
+    }
+
+    #[test]
+    fn test_builder_rejects_inconsistent_parameters() {
+        let builder = PBSParametersBuilder::from(PARAM_MESSAGE_2_CARRY_2);
+
+        assert_eq!(
+            PBSParametersBuilder::new().build(),
+            Err(ParameterError::MissingParameter("lwe_dimension"))
+        );
+        assert_eq!(
+            builder.glwe_dimension(GlweDimension(0)).build(),
+            Err(ParameterError::ZeroDimension("glwe_dimension"))
+        );
+        assert_eq!(
+            builder.polynomial_size(PolynomialSize(1000)).build(),
+            Err(ParameterError::PolynomialSizeNotPowerOfTwo(PolynomialSize(
+                1000
+            )))
+        );
+        assert!(matches!(
+            builder.lwe_modular_std_dev(StandardDev(0.0)).build(),
+            Err(ParameterError::InvalidStandardDev {
+                name: "lwe_noise_distribution",
+                ..
+            })
+        ));
+        assert!(matches!(
+            builder
+                .glwe_noise_distribution(DynamicDistribution::new_t_uniform(-1))
+                .build(),
+            Err(ParameterError::InvalidTUniformBound {
+                name: "glwe_noise_distribution",
+                log2_bound: -1,
+                modulus_bits: 64,
+            })
+        ));
+        assert!(builder
+            .lwe_noise_distribution(DynamicDistribution::new_t_uniform(-50))
+            .build()
+            .is_ok());
+        assert!(matches!(
+            builder.pbs_level(DecompositionLevelCount(3)).build(),
+            Err(ParameterError::InvalidDecomposition {
+                name: "PBS",
+                modulus_bits: 64,
+                ..
+            })
+        ));
+        assert!(matches!(
+            builder
+                .ciphertext_modulus(CiphertextModulus::try_new_power_of_2(16).unwrap())
+                .build(),
+            Err(ParameterError::InvalidDecomposition {
+                name: "PBS",
+                modulus_bits: 16,
+                ..
+            })
+        ));
+        // Safe as the modulus fits in a u64
+        let odd_modulus = unsafe { CiphertextModulus::new_unchecked((1 << 63) - 25) };
+        assert_eq!(
+            builder.ciphertext_modulus(odd_modulus).build(),
+            Err(ParameterError::UnsupportedCiphertextModulus(odd_modulus))
+        );
+        assert!(builder
+            .ciphertext_modulus(CiphertextModulus::try_new_power_of_2(63).unwrap())
+            .build()
+            .is_ok());
+        assert_eq!(
+            builder
+                .secret_key_distribution(SecretKeyDistribution::Gaussian { std_dev: -1.0 })
+                .build(),
+            Err(ParameterError::InvalidSecretKeyStdDev(-1.0))
+        );
+        assert_eq!(
+            builder
+                .secret_key_distribution(SecretKeyDistribution::Ternary)
+                .build()
+                .unwrap()
+                .secret_key_distribution,
+            SecretKeyDistribution::Ternary
+        );
+        assert_eq!(
+            builder.carry_modulus(CarryModulus(3)).build(),
+            Err(ParameterError::ModulusNotPowerOfTwo {
+                name: "carry",
+                modulus: 3
+            })
+        );
+        assert!(matches!(
+            builder.message_modulus(MessageModulus(1024)).build(),
+            Err(ParameterError::PlaintextSpaceTooLarge { .. })
+        ));
+    }
+
+    #[test]
+    fn test_wopbs_builder_accepts_builtin_parameters() {
+        for params in ALL_PARAMETER_VEC_WOPBS
+            .into_iter()
+            .chain(parameters_wopbs::ALL_PARAMETER_VEC_WOPBS_NORM2)
+        {
+            assert_eq!(WopbsParametersBuilder::from(params).build(), Ok(params));
+        }
+
+        assert_eq!(
+            WopbsParametersBuilder::new().build(),
+            Err(ParameterError::MissingParameter("lwe_dimension"))
+        );
+        assert!(matches!(
+            WopbsParametersBuilder::from(ALL_PARAMETER_VEC_WOPBS[0])
+                .cbs_level(DecompositionLevelCount(0))
+                .build(),
+            Err(ParameterError::InvalidDecomposition { name: "CBS", .. })
+        ));
+    }
+
+    #[test]
+    fn test_wopbs_builder_derives_compatible_parameters() {
+        for pbs_params in [
+            PARAM_MESSAGE_1_CARRY_1,
+            PARAM_MESSAGE_2_CARRY_2,
+            PARAM_MESSAGE_3_CARRY_3,
+            PARAM_MESSAGE_4_CARRY_4,
+        ] {
+            // The built-in pairs are kept
+            let wopbs_params = WopbsParameters::recommended_for(&pbs_params).unwrap();
+            assert!(wopbs_params.check_compatibility(&pbs_params).is_ok());
+            assert_eq!(
+                WopbsParametersBuilder::for_pbs_parameters(&pbs_params)
+                    .unwrap()
+                    .build(),
+                Ok(wopbs_params)
+            );
+        }
+
+        for entry in ParametersCatalog::builtin().iter() {
+            if let Some(builder) = WopbsParametersBuilder::for_pbs_parameters(&entry.parameters) {
+                let wopbs_params = builder.build_for(&entry.parameters).unwrap();
+                assert!(wopbs_params.compatible_with(&entry.parameters));
+            }
+        }
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_gaussian_parameters_keep_the_std_dev_api() {
+        let params = PARAM_MESSAGE_2_CARRY_2;
+        let rebuilt = unsafe {
+            PBSParameters::new(
+                params.lwe_dimension,
+                params.glwe_dimension,
+                params.polynomial_size,
+                params.lwe_modular_std_dev(),
+                params.glwe_modular_std_dev(),
+                params.pbs_base_log,
+                params.pbs_level,
+                params.ks_base_log,
+                params.ks_level,
+                params.message_modulus,
+                params.carry_modulus,
+                params.ciphertext_modulus,
+                params.encryption_key_choice,
+            )
+        };
+        assert_eq!(rebuilt, params);
+        assert_eq!(
+            ShortintParameterSet::from(params).lwe_modular_std_dev(),
+            params.lwe_modular_std_dev()
+        );
+    }
+}
@@ -0,0 +1,725 @@
+//! Catalog of the parameter sets provided by the crate.
+//!
+//! # Example
+//!
+//! ```rust
+//! use tfhe::shortint::parameters::catalog::{ParametersCatalog, ParametersQuery};
+//! use tfhe::shortint::parameters::EncryptionKeyChoice;
+//!
+//! let catalog = ParametersCatalog::builtin();
+//!
+//! let query = ParametersQuery::new()
+//!     .message_bits(2)
+//!     .carry_bits(2)
+//!     .encryption_key_choice(EncryptionKeyChoice::Small);
+//!
+//! let names = catalog.find(&query).map(|entry| entry.name).collect::<Vec<_>>();
+//! assert_eq!(names, ["PARAM_SMALL_MESSAGE_2_CARRY_2"]);
+//! ```
+
+use super::provenance::ParameterProvenance;
+use super::versioning::ParameterVersion;
+use super::*;
+
+/// A parameter set along with its name and guarantees.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct NamedParameters {
+    /// Name of the constant defining the parameters
+    pub name: &'static str,
+    pub parameters: PBSParameters,
+    /// Security level of the parameters in bits
+    pub security_bits: u32,
+    /// Base 2 logarithm of the upper bound of the failure probability of a PBS
+    pub log2_failure_probability: i32,
+    /// Whether the security level and the failure probability were only inferred from those of
+    /// another parameter set instead of being estimated, such sets are ignored by the queries
+    /// unless [`ParametersQuery::include_unverified`] is set
+    pub unverified: bool,
+    /// Version in which the values of the parameters were last tuned
+    pub version: ParameterVersion,
+    /// Version from which the parameters should not be used anymore, if any
+    pub deprecated_since: Option<ParameterVersion>,
+    /// Name of the parameter set replacing these parameters, if any
+    pub superseded_by: Option<&'static str>,
+    /// How the values of the parameters were obtained
+    pub provenance: ParameterProvenance,
+}
+
+impl NamedParameters {
+    pub fn is_deprecated(&self) -> bool {
+        self.deprecated_since.is_some()
+    }
+
+    pub fn message_bits(&self) -> u32 {
+        self.parameters.message_modulus.0.ilog2()
+    }
+
+    pub fn carry_bits(&self) -> u32 {
+        self.parameters.carry_modulus.0.ilog2()
+    }
+}
+
+macro_rules! named_parameters {
+    (@unverified estimated) => {
+        false
+    };
+    (@unverified unverified) => {
+        true
+    };
+    (@provenance [] []) => {
+        ParameterProvenance::V0_3
+    };
+    (@provenance [$parent:ident $change:literal] []) => {
+        ParameterProvenance::Derived {
+            parent: stringify!($parent),
+            change: $change,
+        }
+    };
+    (@provenance [] [$rationale:literal]) => {
+        ParameterProvenance::HandChosen {
+            rationale: $rationale,
+        }
+    };
+    (
+        $(
+            $param:ident: $check:ident($security_bits:literal, $log2_failure_probability:literal)
+            $(derived from $parent:ident with $change:literal)?
+            $(chosen by hand for $rationale:literal)?
+        ),*
+        $(,)?
+    ) => {
+        &[
+            $(
+                NamedParameters {
+                    name: stringify!($param),
+                    parameters: $param,
+                    security_bits: $security_bits,
+                    log2_failure_probability: $log2_failure_probability,
+                    unverified: named_parameters!(@unverified $check),
+                    version: ParameterVersion::V0_3,
+                    deprecated_since: None,
+                    superseded_by: None,
+                    provenance: named_parameters!(
+                        @provenance [$($parent $change)?] [$($rationale)?]
+                    ),
+                },
+            )*
+        ]
+    };
+}
+
+// Each entry gives the security level in bits and the base 2 logarithm of the failure probability
+// of a PBS. They are `estimated` for the values generated by the optimizer, and `unverified` for
+// the sets whose guarantees are only inferred from the ones of another set.
+//
+// Entries are generated by the optimizer for the 0.3 release unless they are `derived from`
+// another set, with the change applied to its values, or `chosen by hand`. The guarantees of the
+// SMALL sets derived from a set with one more bit of carry are estimated: the values are the
+// same, and a smaller carry space only lowers the failure probability.
+//
+// Sets with a higher failure probability come first: for the same message and carry spaces they
+// are cheaper, so the first match of a query is the cheapest set
+const BUILTIN_PARAMETERS: &[NamedParameters] = named_parameters!(
+    PARAM_MESSAGE_1_CARRY_0: estimated(128, -40),
+    PARAM_MESSAGE_1_CARRY_1: estimated(128, -40),
+    PARAM_MESSAGE_1_CARRY_2: estimated(128, -40),
+    PARAM_MESSAGE_1_CARRY_3: estimated(128, -40),
+    PARAM_MESSAGE_1_CARRY_4: estimated(128, -40),
+    PARAM_MESSAGE_1_CARRY_5: estimated(128, -40),
+    PARAM_MESSAGE_1_CARRY_6: estimated(128, -40),
+    PARAM_MESSAGE_1_CARRY_7: estimated(128, -40),
+    PARAM_MESSAGE_2_CARRY_0: estimated(128, -40),
+    PARAM_MESSAGE_2_CARRY_1: estimated(128, -40),
+    PARAM_MESSAGE_2_CARRY_2: estimated(128, -40),
+    PARAM_MESSAGE_2_CARRY_3: estimated(128, -40),
+    PARAM_MESSAGE_2_CARRY_4: estimated(128, -40),
+    PARAM_MESSAGE_2_CARRY_5: estimated(128, -40),
+    PARAM_MESSAGE_2_CARRY_6: estimated(128, -40),
+    PARAM_MESSAGE_3_CARRY_0: estimated(128, -40),
+    PARAM_MESSAGE_3_CARRY_1: estimated(128, -40),
+    PARAM_MESSAGE_3_CARRY_2: estimated(128, -40),
+    PARAM_MESSAGE_3_CARRY_3: estimated(128, -40),
+    PARAM_MESSAGE_3_CARRY_4: estimated(128, -40),
+    PARAM_MESSAGE_3_CARRY_5: estimated(128, -40),
+    PARAM_MESSAGE_4_CARRY_0: estimated(128, -40),
+    PARAM_MESSAGE_4_CARRY_1: estimated(128, -40),
+    PARAM_MESSAGE_4_CARRY_2: estimated(128, -40),
+    PARAM_MESSAGE_4_CARRY_3: estimated(128, -40),
+    PARAM_MESSAGE_4_CARRY_4: estimated(128, -40),
+    PARAM_MESSAGE_5_CARRY_0: estimated(128, -40),
+    PARAM_MESSAGE_5_CARRY_1: estimated(128, -40),
+    PARAM_MESSAGE_5_CARRY_2: estimated(128, -40),
+    PARAM_MESSAGE_5_CARRY_3: estimated(128, -40),
+    PARAM_MESSAGE_6_CARRY_0: estimated(128, -40),
+    PARAM_MESSAGE_6_CARRY_1: estimated(128, -40),
+    PARAM_MESSAGE_6_CARRY_2: estimated(128, -40),
+    PARAM_MESSAGE_7_CARRY_0: estimated(128, -40),
+    PARAM_MESSAGE_7_CARRY_1: estimated(128, -40),
+    PARAM_MESSAGE_8_CARRY_0: estimated(128, -40),
+    PARAM_SMALL_MESSAGE_1_CARRY_1: estimated(128, -40),
+    PARAM_SMALL_MESSAGE_1_CARRY_2: estimated(128, -40)
+        derived from PARAM_SMALL_MESSAGE_1_CARRY_3 with "carry modulus of 4",
+    PARAM_SMALL_MESSAGE_1_CARRY_3: estimated(128, -40),
+    PARAM_SMALL_MESSAGE_1_CARRY_4: estimated(128, -40)
+        derived from PARAM_SMALL_MESSAGE_1_CARRY_5 with "carry modulus of 16",
+    PARAM_SMALL_MESSAGE_1_CARRY_5: estimated(128, -40),
+    PARAM_SMALL_MESSAGE_1_CARRY_6: estimated(128, -40)
+        derived from PARAM_SMALL_MESSAGE_1_CARRY_7 with "carry modulus of 64",
+    PARAM_SMALL_MESSAGE_1_CARRY_7: estimated(128, -40),
+    PARAM_SMALL_MESSAGE_2_CARRY_1: estimated(128, -40)
+        derived from PARAM_SMALL_MESSAGE_2_CARRY_2 with "carry modulus of 2",
+    PARAM_SMALL_MESSAGE_2_CARRY_2: estimated(128, -40),
+    PARAM_SMALL_MESSAGE_2_CARRY_3: estimated(128, -40)
+        derived from PARAM_SMALL_MESSAGE_2_CARRY_4 with "carry modulus of 8",
+    PARAM_SMALL_MESSAGE_2_CARRY_4: estimated(128, -40),
+    PARAM_SMALL_MESSAGE_2_CARRY_5: estimated(128, -40)
+        derived from PARAM_SMALL_MESSAGE_2_CARRY_6 with "carry modulus of 32",
+    PARAM_SMALL_MESSAGE_2_CARRY_6: estimated(128, -40),
+    PARAM_SMALL_MESSAGE_3_CARRY_1: estimated(128, -40),
+    PARAM_SMALL_MESSAGE_3_CARRY_2: estimated(128, -40)
+        derived from PARAM_SMALL_MESSAGE_3_CARRY_3 with "carry modulus of 4",
+    PARAM_SMALL_MESSAGE_3_CARRY_3: estimated(128, -40),
+    PARAM_SMALL_MESSAGE_3_CARRY_4: estimated(128, -40)
+        derived from PARAM_SMALL_MESSAGE_3_CARRY_5 with "carry modulus of 16",
+    PARAM_SMALL_MESSAGE_3_CARRY_5: estimated(128, -40),
+    PARAM_SMALL_MESSAGE_4_CARRY_1: estimated(128, -40)
+        derived from PARAM_SMALL_MESSAGE_4_CARRY_2 with "carry modulus of 2",
+    PARAM_SMALL_MESSAGE_4_CARRY_2: estimated(128, -40),
+    PARAM_SMALL_MESSAGE_4_CARRY_3: estimated(128, -40)
+        derived from PARAM_SMALL_MESSAGE_4_CARRY_4 with "carry modulus of 8",
+    PARAM_SMALL_MESSAGE_4_CARRY_4: estimated(128, -40),
+    PARAM_SMALL_MESSAGE_5_CARRY_1: estimated(128, -40),
+    PARAM_SMALL_MESSAGE_5_CARRY_2: estimated(128, -40)
+        derived from PARAM_SMALL_MESSAGE_5_CARRY_3 with "carry modulus of 4",
+    PARAM_SMALL_MESSAGE_5_CARRY_3: estimated(128, -40),
+    PARAM_SMALL_MESSAGE_6_CARRY_1: estimated(128, -40)
+        derived from PARAM_SMALL_MESSAGE_6_CARRY_2 with "carry modulus of 2",
+    PARAM_SMALL_MESSAGE_6_CARRY_2: estimated(128, -40),
+    PARAM_SMALL_MESSAGE_7_CARRY_1: estimated(128, -40),
+    PARAM_MESSAGE_1_CARRY_1_MODULUS_2_63: unverified(128, -40)
+        derived from PARAM_MESSAGE_1_CARRY_1 with "ciphertext modulus of 2^63",
+    PARAM_MESSAGE_1_CARRY_2_MODULUS_2_63: unverified(128, -40)
+        derived from PARAM_MESSAGE_1_CARRY_2 with "ciphertext modulus of 2^63",
+    PARAM_MESSAGE_1_CARRY_3_MODULUS_2_63: unverified(128, -40)
+        derived from PARAM_MESSAGE_1_CARRY_3 with "ciphertext modulus of 2^63",
+    PARAM_MESSAGE_1_CARRY_4_MODULUS_2_63: unverified(128, -40)
+        derived from PARAM_MESSAGE_1_CARRY_4 with "ciphertext modulus of 2^63",
+    PARAM_MESSAGE_1_CARRY_5_MODULUS_2_63: unverified(128, -40)
+        derived from PARAM_MESSAGE_1_CARRY_5 with "ciphertext modulus of 2^63",
+    PARAM_MESSAGE_1_CARRY_6_MODULUS_2_63: unverified(128, -40)
+        derived from PARAM_MESSAGE_1_CARRY_6 with "ciphertext modulus of 2^63",
+    PARAM_MESSAGE_1_CARRY_7_MODULUS_2_63: unverified(128, -40)
+        derived from PARAM_MESSAGE_1_CARRY_7 with "ciphertext modulus of 2^63",
+    PARAM_MESSAGE_2_CARRY_1_MODULUS_2_63: unverified(128, -40)
+        derived from PARAM_MESSAGE_2_CARRY_1 with "ciphertext modulus of 2^63",
+    PARAM_MESSAGE_2_CARRY_2_MODULUS_2_63: unverified(128, -40)
+        derived from PARAM_MESSAGE_2_CARRY_2 with "ciphertext modulus of 2^63",
+    PARAM_MESSAGE_2_CARRY_3_MODULUS_2_63: unverified(128, -40)
+        derived from PARAM_MESSAGE_2_CARRY_3 with "ciphertext modulus of 2^63",
+    PARAM_MESSAGE_2_CARRY_4_MODULUS_2_63: unverified(128, -40)
+        derived from PARAM_MESSAGE_2_CARRY_4 with "ciphertext modulus of 2^63",
+    PARAM_MESSAGE_2_CARRY_5_MODULUS_2_63: unverified(128, -40)
+        derived from PARAM_MESSAGE_2_CARRY_5 with "ciphertext modulus of 2^63",
+    PARAM_MESSAGE_2_CARRY_6_MODULUS_2_63: unverified(128, -40)
+        derived from PARAM_MESSAGE_2_CARRY_6 with "ciphertext modulus of 2^63",
+    PARAM_MESSAGE_3_CARRY_1_MODULUS_2_63: unverified(128, -40)
+        derived from PARAM_MESSAGE_3_CARRY_1 with "ciphertext modulus of 2^63",
+    PARAM_MESSAGE_3_CARRY_2_MODULUS_2_63: unverified(128, -40)
+        derived from PARAM_MESSAGE_3_CARRY_2 with "ciphertext modulus of 2^63",
+    PARAM_MESSAGE_3_CARRY_3_MODULUS_2_63: unverified(128, -40)
+        derived from PARAM_MESSAGE_3_CARRY_3 with "ciphertext modulus of 2^63",
+    PARAM_MESSAGE_3_CARRY_4_MODULUS_2_63: unverified(128, -40)
+        derived from PARAM_MESSAGE_3_CARRY_4 with "ciphertext modulus of 2^63",
+    PARAM_MESSAGE_3_CARRY_5_MODULUS_2_63: unverified(128, -40)
+        derived from PARAM_MESSAGE_3_CARRY_5 with "ciphertext modulus of 2^63",
+    PARAM_MESSAGE_4_CARRY_1_MODULUS_2_63: unverified(128, -40)
+        derived from PARAM_MESSAGE_4_CARRY_1 with "ciphertext modulus of 2^63",
+    PARAM_MESSAGE_4_CARRY_2_MODULUS_2_63: unverified(128, -40)
+        derived from PARAM_MESSAGE_4_CARRY_2 with "ciphertext modulus of 2^63",
+    PARAM_MESSAGE_4_CARRY_3_MODULUS_2_63: unverified(128, -40)
+        derived from PARAM_MESSAGE_4_CARRY_3 with "ciphertext modulus of 2^63",
+    PARAM_MESSAGE_4_CARRY_4_MODULUS_2_63: unverified(128, -40)
+        derived from PARAM_MESSAGE_4_CARRY_4 with "ciphertext modulus of 2^63",
+    PARAM_MESSAGE_5_CARRY_1_MODULUS_2_63: unverified(128, -40)
+        derived from PARAM_MESSAGE_5_CARRY_1 with "ciphertext modulus of 2^63",
+    PARAM_MESSAGE_5_CARRY_2_MODULUS_2_63: unverified(128, -40)
+        derived from PARAM_MESSAGE_5_CARRY_2 with "ciphertext modulus of 2^63",
+    PARAM_MESSAGE_5_CARRY_3_MODULUS_2_63: unverified(128, -40)
+        derived from PARAM_MESSAGE_5_CARRY_3 with "ciphertext modulus of 2^63",
+    PARAM_MESSAGE_6_CARRY_1_MODULUS_2_63: unverified(128, -40)
+        derived from PARAM_MESSAGE_6_CARRY_1 with "ciphertext modulus of 2^63",
+    PARAM_MESSAGE_6_CARRY_2_MODULUS_2_63: unverified(128, -40)
+        derived from PARAM_MESSAGE_6_CARRY_2 with "ciphertext modulus of 2^63",
+    PARAM_MESSAGE_7_CARRY_1_MODULUS_2_63: unverified(128, -40)
+        derived from PARAM_MESSAGE_7_CARRY_1 with "ciphertext modulus of 2^63",
+    PARAM_MESSAGE_1_CARRY_1_MODULUS_2_32: unverified(128, -40)
+        chosen by hand for "a GLWE noise of 2^5 on a 2^32 modulus",
+    PARAM_MESSAGE_1_CARRY_0_PBS_2M64: unverified(128, -64)
+        derived from PARAM_MESSAGE_1_CARRY_1 with "carry modulus of 1",
+    PARAM_MESSAGE_1_CARRY_1_PBS_2M64: unverified(128, -64)
+        derived from PARAM_MESSAGE_1_CARRY_2 with "carry modulus of 2",
+    PARAM_MESSAGE_1_CARRY_2_PBS_2M64: unverified(128, -64)
+        derived from PARAM_MESSAGE_1_CARRY_3 with "carry modulus of 4",
+    PARAM_MESSAGE_1_CARRY_3_PBS_2M64: unverified(128, -64)
+        derived from PARAM_MESSAGE_1_CARRY_4 with "carry modulus of 8",
+    PARAM_MESSAGE_1_CARRY_4_PBS_2M64: unverified(128, -64)
+        derived from PARAM_MESSAGE_1_CARRY_5 with "carry modulus of 16",
+    PARAM_MESSAGE_1_CARRY_5_PBS_2M64: unverified(128, -64)
+        derived from PARAM_MESSAGE_1_CARRY_6 with "carry modulus of 32",
+    PARAM_MESSAGE_1_CARRY_6_PBS_2M64: unverified(128, -64)
+        derived from PARAM_MESSAGE_1_CARRY_7 with "carry modulus of 64",
+    PARAM_MESSAGE_2_CARRY_0_PBS_2M64: unverified(128, -64)
+        derived from PARAM_MESSAGE_2_CARRY_1 with "carry modulus of 1",
+    PARAM_MESSAGE_2_CARRY_1_PBS_2M64: unverified(128, -64)
+        derived from PARAM_MESSAGE_2_CARRY_2 with "carry modulus of 2",
+    PARAM_MESSAGE_2_CARRY_2_PBS_2M64: unverified(128, -64)
+        derived from PARAM_MESSAGE_2_CARRY_3 with "carry modulus of 4",
+    PARAM_MESSAGE_2_CARRY_3_PBS_2M64: unverified(128, -64)
+        derived from PARAM_MESSAGE_2_CARRY_4 with "carry modulus of 8",
+    PARAM_MESSAGE_2_CARRY_4_PBS_2M64: unverified(128, -64)
+        derived from PARAM_MESSAGE_2_CARRY_5 with "carry modulus of 16",
+    PARAM_MESSAGE_2_CARRY_5_PBS_2M64: unverified(128, -64)
+        derived from PARAM_MESSAGE_2_CARRY_6 with "carry modulus of 32",
+    PARAM_MESSAGE_3_CARRY_0_PBS_2M64: unverified(128, -64)
+        derived from PARAM_MESSAGE_3_CARRY_1 with "carry modulus of 1",
+    PARAM_MESSAGE_3_CARRY_1_PBS_2M64: unverified(128, -64)
+        derived from PARAM_MESSAGE_3_CARRY_2 with "carry modulus of 2",
+    PARAM_MESSAGE_3_CARRY_2_PBS_2M64: unverified(128, -64)
+        derived from PARAM_MESSAGE_3_CARRY_3 with "carry modulus of 4",
+    PARAM_MESSAGE_3_CARRY_3_PBS_2M64: unverified(128, -64)
+        derived from PARAM_MESSAGE_3_CARRY_4 with "carry modulus of 8",
+    PARAM_MESSAGE_3_CARRY_4_PBS_2M64: unverified(128, -64)
+        derived from PARAM_MESSAGE_3_CARRY_5 with "carry modulus of 16",
+    PARAM_MESSAGE_4_CARRY_0_PBS_2M64: unverified(128, -64)
+        derived from PARAM_MESSAGE_4_CARRY_1 with "carry modulus of 1",
+    PARAM_MESSAGE_4_CARRY_1_PBS_2M64: unverified(128, -64)
+        derived from PARAM_MESSAGE_4_CARRY_2 with "carry modulus of 2",
+    PARAM_MESSAGE_4_CARRY_2_PBS_2M64: unverified(128, -64)
+        derived from PARAM_MESSAGE_4_CARRY_3 with "carry modulus of 4",
+    PARAM_MESSAGE_4_CARRY_3_PBS_2M64: unverified(128, -64)
+        derived from PARAM_MESSAGE_4_CARRY_4 with "carry modulus of 8",
+    PARAM_MESSAGE_5_CARRY_0_PBS_2M64: unverified(128, -64)
+        derived from PARAM_MESSAGE_5_CARRY_1 with "carry modulus of 1",
+    PARAM_MESSAGE_5_CARRY_1_PBS_2M64: unverified(128, -64)
+        derived from PARAM_MESSAGE_5_CARRY_2 with "carry modulus of 2",
+    PARAM_MESSAGE_5_CARRY_2_PBS_2M64: unverified(128, -64)
+        derived from PARAM_MESSAGE_5_CARRY_3 with "carry modulus of 4",
+    PARAM_MESSAGE_6_CARRY_0_PBS_2M64: unverified(128, -64)
+        derived from PARAM_MESSAGE_6_CARRY_1 with "carry modulus of 1",
+    PARAM_MESSAGE_6_CARRY_1_PBS_2M64: unverified(128, -64)
+        derived from PARAM_MESSAGE_6_CARRY_2 with "carry modulus of 2",
+    PARAM_MESSAGE_7_CARRY_0_PBS_2M64: unverified(128, -64)
+        derived from PARAM_MESSAGE_7_CARRY_1 with "carry modulus of 1",
+    PARAM_MESSAGE_1_CARRY_0_PBS_2M128: unverified(128, -128)
+        derived from PARAM_MESSAGE_1_CARRY_1 with "carry modulus of 1",
+    PARAM_MESSAGE_1_CARRY_1_PBS_2M128: unverified(128, -128)
+        derived from PARAM_MESSAGE_1_CARRY_2 with "carry modulus of 2",
+    PARAM_MESSAGE_1_CARRY_2_PBS_2M128: unverified(128, -128)
+        derived from PARAM_MESSAGE_1_CARRY_3 with "carry modulus of 4",
+    PARAM_MESSAGE_1_CARRY_3_PBS_2M128: unverified(128, -128)
+        derived from PARAM_MESSAGE_1_CARRY_4 with "carry modulus of 8",
+    PARAM_MESSAGE_1_CARRY_4_PBS_2M128: unverified(128, -128)
+        derived from PARAM_MESSAGE_1_CARRY_5 with "carry modulus of 16",
+    PARAM_MESSAGE_1_CARRY_5_PBS_2M128: unverified(128, -128)
+        derived from PARAM_MESSAGE_1_CARRY_6 with "carry modulus of 32",
+    PARAM_MESSAGE_1_CARRY_6_PBS_2M128: unverified(128, -128)
+        derived from PARAM_MESSAGE_1_CARRY_7 with "carry modulus of 64",
+    PARAM_MESSAGE_2_CARRY_0_PBS_2M128: unverified(128, -128)
+        derived from PARAM_MESSAGE_2_CARRY_1 with "carry modulus of 1",
+    PARAM_MESSAGE_2_CARRY_1_PBS_2M128: unverified(128, -128)
+        derived from PARAM_MESSAGE_2_CARRY_2 with "carry modulus of 2",
+    PARAM_MESSAGE_2_CARRY_2_PBS_2M128: unverified(128, -128)
+        derived from PARAM_MESSAGE_2_CARRY_3 with "carry modulus of 4",
+    PARAM_MESSAGE_2_CARRY_3_PBS_2M128: unverified(128, -128)
+        derived from PARAM_MESSAGE_2_CARRY_4 with "carry modulus of 8",
+    PARAM_MESSAGE_2_CARRY_4_PBS_2M128: unverified(128, -128)
+        derived from PARAM_MESSAGE_2_CARRY_5 with "carry modulus of 16",
+    PARAM_MESSAGE_2_CARRY_5_PBS_2M128: unverified(128, -128)
+        derived from PARAM_MESSAGE_2_CARRY_6 with "carry modulus of 32",
+    PARAM_MESSAGE_3_CARRY_0_PBS_2M128: unverified(128, -128)
+        derived from PARAM_MESSAGE_3_CARRY_1 with "carry modulus of 1",
+    PARAM_MESSAGE_3_CARRY_1_PBS_2M128: unverified(128, -128)
+        derived from PARAM_MESSAGE_3_CARRY_2 with "carry modulus of 2",
+    PARAM_MESSAGE_3_CARRY_2_PBS_2M128: unverified(128, -128)
+        derived from PARAM_MESSAGE_3_CARRY_3 with "carry modulus of 4",
+    PARAM_MESSAGE_3_CARRY_3_PBS_2M128: unverified(128, -128)
+        derived from PARAM_MESSAGE_3_CARRY_4 with "carry modulus of 8",
+    PARAM_MESSAGE_3_CARRY_4_PBS_2M128: unverified(128, -128)
+        derived from PARAM_MESSAGE_3_CARRY_5 with "carry modulus of 16",
+    PARAM_MESSAGE_4_CARRY_0_PBS_2M128: unverified(128, -128)
+        derived from PARAM_MESSAGE_4_CARRY_1 with "carry modulus of 1",
+    PARAM_MESSAGE_4_CARRY_1_PBS_2M128: unverified(128, -128)
+        derived from PARAM_MESSAGE_4_CARRY_2 with "carry modulus of 2",
+    PARAM_MESSAGE_4_CARRY_2_PBS_2M128: unverified(128, -128)
+        derived from PARAM_MESSAGE_4_CARRY_3 with "carry modulus of 4",
+    PARAM_MESSAGE_4_CARRY_3_PBS_2M128: unverified(128, -128)
+        derived from PARAM_MESSAGE_4_CARRY_4 with "carry modulus of 8",
+    PARAM_MESSAGE_5_CARRY_0_PBS_2M128: unverified(128, -128)
+        derived from PARAM_MESSAGE_5_CARRY_1 with "carry modulus of 1",
+    PARAM_MESSAGE_5_CARRY_1_PBS_2M128: unverified(128, -128)
+        derived from PARAM_MESSAGE_5_CARRY_2 with "carry modulus of 2",
+    PARAM_MESSAGE_5_CARRY_2_PBS_2M128: unverified(128, -128)
+        derived from PARAM_MESSAGE_5_CARRY_3 with "carry modulus of 4",
+    PARAM_MESSAGE_6_CARRY_0_PBS_2M128: unverified(128, -128)
+        derived from PARAM_MESSAGE_6_CARRY_1 with "carry modulus of 1",
+    PARAM_MESSAGE_6_CARRY_1_PBS_2M128: unverified(128, -128)
+        derived from PARAM_MESSAGE_6_CARRY_2 with "carry modulus of 2",
+    PARAM_MESSAGE_7_CARRY_0_PBS_2M128: unverified(128, -128)
+        derived from PARAM_MESSAGE_7_CARRY_1 with "carry modulus of 1",
+);
+
+/// Filters applied by [`ParametersCatalog::find`], a filter that is not set accepts
+/// every parameter set.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct ParametersQuery {
+    message_bits: Option<u32>,
+    carry_bits: Option<u32>,
+    encryption_key_choice: Option<EncryptionKeyChoice>,
+    min_security_bits: Option<u32>,
+    max_log2_failure_probability: Option<i32>,
+    max_polynomial_size: Option<PolynomialSize>,
+    ciphertext_modulus: Option<CiphertextModulus>,
+    include_unverified: bool,
+}
+
+impl ParametersQuery {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn message_bits(mut self, message_bits: u32) -> Self {
+        self.message_bits = Some(message_bits);
+        self
+    }
+
+    pub fn carry_bits(mut self, carry_bits: u32) -> Self {
+        self.carry_bits = Some(carry_bits);
+        self
+    }
+
+    pub fn encryption_key_choice(mut self, encryption_key_choice: EncryptionKeyChoice) -> Self {
+        self.encryption_key_choice = Some(encryption_key_choice);
+        self
+    }
+
+    /// Only keep parameter sets with at least `security_bits` bits of security
+    pub fn min_security_bits(mut self, security_bits: u32) -> Self {
+        self.min_security_bits = Some(security_bits);
+        self
+    }
+
+    /// Only keep parameter sets with a PBS failure probability of at most
+    /// `2^log2_failure_probability`
+    pub fn max_log2_failure_probability(mut self, log2_failure_probability: i32) -> Self {
+        self.max_log2_failure_probability = Some(log2_failure_probability);
+        self
+    }
+
+    /// Only keep parameter sets with a polynomial size of at most `polynomial_size`
+    pub fn max_polynomial_size(mut self, polynomial_size: PolynomialSize) -> Self {
+        self.max_polynomial_size = Some(polynomial_size);
+        self
+    }
+
+    /// Only keep parameter sets with the given ciphertext modulus
+    pub fn ciphertext_modulus(mut self, ciphertext_modulus: CiphertextModulus) -> Self {
+        self.ciphertext_modulus = Some(ciphertext_modulus);
+        self
+    }
+
+    /// Also return the parameter sets whose guarantees were not estimated, see
+    /// [`NamedParameters::unverified`]
+    pub fn include_unverified(mut self) -> Self {
+        self.include_unverified = true;
+        self
+    }
+
+    pub fn matches(&self, entry: &NamedParameters) -> bool {
+        (self.include_unverified || !entry.unverified)
+            && self
+                .message_bits
+                .map_or(true, |bits| entry.message_bits() == bits)
+            && self
+                .carry_bits
+                .map_or(true, |bits| entry.carry_bits() == bits)
+            && self.encryption_key_choice.map_or(true, |choice| {
+                entry.parameters.encryption_key_choice == choice
+            })
+            && self
+                .min_security_bits
+                .map_or(true, |bits| entry.security_bits >= bits)
+            && self
+                .max_log2_failure_probability
+                .map_or(true, |log2_p| entry.log2_failure_probability <= log2_p)
+            && self
+                .max_polynomial_size
+                .map_or(true, |size| entry.parameters.polynomial_size <= size)
+            && self.ciphertext_modulus.map_or(true, |modulus| {
+                entry.parameters.ciphertext_modulus == modulus
+            })
+    }
+}
+
+/// A searchable list of named parameter sets.
+#[derive(Debug, Copy, Clone)]
+pub struct ParametersCatalog {
+    entries: &'static [NamedParameters],
+}
+
+impl ParametersCatalog {
+    /// Creates a catalog from a custom list of parameter sets
+    pub const fn new(entries: &'static [NamedParameters]) -> Self {
+        Self { entries }
+    }
+
+    /// Returns the catalog of the parameter sets defined in [`crate::shortint::parameters`]
+    pub fn builtin() -> Self {
+        Self {
+            entries: BUILTIN_PARAMETERS,
+        }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &'static NamedParameters> {
+        self.entries.iter()
+    }
+
+    /// Returns the entry with the given name, e.g. `"PARAM_MESSAGE_2_CARRY_2"`
+    pub fn get(&self, name: &str) -> Option<&'static NamedParameters> {
+        self.entries.iter().find(|entry| entry.name == name)
+    }
+
+    /// Returns the entries matching all the filters of the query
+    pub fn find<'q>(
+        &self,
+        query: &'q ParametersQuery,
+    ) -> impl Iterator<Item = &'static NamedParameters> + 'q {
+        self.entries
+            .iter()
+            .filter(move |entry| query.matches(entry))
+    }
+}
+
+/// Guarantees and key sizes of a built-in parameter set, see [`PBSParameters::metadata`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct ParameterMetadata {
+    /// Estimated security level of the parameters in bits
+    pub security_bits: u32,
+    /// Base 2 logarithm of the upper bound of the failure probability of a PBS
+    pub log2_failure_probability: i32,
+    /// Whether the guarantees were only inferred from those of another parameter set, see
+    /// [`NamedParameters::unverified`]
+    pub unverified: bool,
+    pub ciphertext_size_bytes: usize,
+    pub key_switching_key_size_bytes: usize,
+    /// Size of the bootstrapping key in the Fourier domain
+    pub bootstrapping_key_size_bytes: usize,
+}
+
+impl PBSParameters {
+    /// Returns the guarantees and key sizes of the parameters if they are those of a built-in
+    /// parameter set, `None` otherwise.
+    ///
+    /// Some built-in sets share the same values under different names, in that case the
+    /// strongest guarantees among them are returned, the estimated guarantees taking precedence
+    /// over the unverified ones.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::{PolynomialSize, PARAM_MESSAGE_2_CARRY_2};
+    ///
+    /// // Check the correctness budget of the application at startup
+    /// let metadata = PARAM_MESSAGE_2_CARRY_2.metadata().unwrap();
+    /// assert!(metadata.security_bits >= 128);
+    /// assert!(metadata.log2_failure_probability <= -40);
+    ///
+    /// let mut custom = PARAM_MESSAGE_2_CARRY_2;
+    /// custom.polynomial_size = PolynomialSize(4096);
+    /// assert!(custom.metadata().is_none());
+    /// ```
+    pub fn metadata(&self) -> Option<ParameterMetadata> {
+        let entries = ParametersCatalog::builtin()
+            .iter()
+            .filter(|entry| entry.parameters == *self)
+            .collect::<Vec<_>>();
+        let unverified = entries.iter().all(|entry| entry.unverified);
+
+        let (security_bits, log2_failure_probability) = entries
+            .iter()
+            .filter(|entry| entry.unverified == unverified)
+            .map(|entry| (entry.security_bits, entry.log2_failure_probability))
+            .reduce(|(security_a, log2_p_a), (security_b, log2_p_b)| {
+                (security_a.max(security_b), log2_p_a.min(log2_p_b))
+            })?;
+
+        let report = self.report();
+        Some(ParameterMetadata {
+            security_bits,
+            log2_failure_probability,
+            unverified,
+            ciphertext_size_bytes: report.ciphertext_size_bytes,
+            key_switching_key_size_bytes: report.key_switching_key_size_bytes,
+            bootstrapping_key_size_bytes: report.bootstrapping_key_size_bytes,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_catalog_names_match_parameters() {
+        let catalog = ParametersCatalog::builtin();
+
+        for param in ALL_PARAMETER_VEC
+            .into_iter()
+            .chain(SMALL_WITH_CARRY_PARAMETERS_VEC)
+        {
+            let query = ParametersQuery::new()
+                .message_bits(param.message_modulus.0.ilog2())
+                .carry_bits(param.carry_modulus.0.ilog2())
+                .encryption_key_choice(param.encryption_key_choice);
+            let first = catalog.find(&query).next().unwrap();
+            assert_eq!(first.parameters, param);
+            assert_eq!(catalog.get(first.name).unwrap().parameters, param);
+        }
+
+        assert_eq!(
+            catalog.get("PARAM_MESSAGE_2_CARRY_2").unwrap().parameters,
+            PARAM_MESSAGE_2_CARRY_2
+        );
+        assert!(catalog.get("PARAM_MESSAGE_9_CARRY_9").is_none());
+
+        // The sets with a lower failure probability are derived by hand
+        let query = ParametersQuery::new().max_log2_failure_probability(-64);
+        assert_eq!(catalog.find(&query).count(), 0);
+        let query = query.include_unverified();
+        assert_eq!(catalog.find(&query).count(), 56);
+        assert!(catalog
+            .find(&query)
+            .all(|entry| entry.log2_failure_probability <= -64));
+
+        let query = query.max_log2_failure_probability(-128);
+        assert_eq!(catalog.find(&query).count(), 28);
+
+        let query = ParametersQuery::new()
+            .ciphertext_modulus(CIPHERTEXT_MODULUS_2_63)
+            .include_unverified();
+        assert_eq!(catalog.find(&query).count(), 28);
+        for param in MODULUS_2_63_WITH_CARRY_PARAMETERS_VEC {
+            let query = query
+                .message_bits(param.message_modulus.0.ilog2())
+                .carry_bits(param.carry_modulus.0.ilog2());
+            assert_eq!(catalog.find(&query).next().unwrap().parameters, param);
+        }
+    }
+
+    #[test]
+    fn test_get_parameters_from_message_and_carry() {
+        use crate::shortint::parameters::get_parameters_from_message_and_carry;
+
+        for param in ALL_PARAMETER_VEC {
+            let msg_space = param.message_modulus.0;
+            let carry_space = param.carry_modulus.0;
+            let constraints = ParametersQuery::new()
+                .encryption_key_choice(param.encryption_key_choice)
+                .min_security_bits(128);
+            // A set with a lower failure probability may be cheaper than the default one
+            let found =
+                get_parameters_from_message_and_carry(msg_space, carry_space, constraints).unwrap();
+            assert_eq!(found.message_modulus, param.message_modulus);
+            assert_eq!(found.carry_modulus, param.carry_modulus);
+            assert_eq!(found.encryption_key_choice, param.encryption_key_choice);
+            assert!(found.estimated_pbs_cost() <= param.estimated_pbs_cost());
+
+            let cheapest = get_parameters_from_message_and_carry(
+                msg_space,
+                carry_space,
+                ParametersQuery::new(),
+            )
+            .unwrap();
+            assert!(cheapest.estimated_pbs_cost() <= found.estimated_pbs_cost());
+        }
+
+        // Non power of two spaces are rounded up
+        assert_eq!(
+            get_parameters_from_message_and_carry(
+                3,
+                4,
+                ParametersQuery::new().encryption_key_choice(EncryptionKeyChoice::Big)
+            ),
+            Ok(PARAM_MESSAGE_2_CARRY_2)
+        );
+    }
+
+    #[test]
+    fn test_get_parameters_constraints() {
+        use crate::shortint::parameters::{
+            get_parameters_from_message_and_carry, FailureProbability,
+        };
+
+        let constraints = ParametersQuery::new()
+            .encryption_key_choice(EncryptionKeyChoice::Big)
+            .max_log2_failure_probability(FailureProbability::Pow2M128.log2());
+        assert!(get_parameters_from_message_and_carry(4, 4, constraints).is_err());
+        assert_eq!(
+            get_parameters_from_message_and_carry(4, 4, constraints.include_unverified()),
+            Ok(PARAM_MESSAGE_2_CARRY_2_PBS_2M128)
+        );
+
+        let max_polynomial_size = PolynomialSize(2048);
+        for message_space in [2, 4, 8, 16, 32] {
+            let constraints = ParametersQuery::new().max_polynomial_size(max_polynomial_size);
+            if let Ok(param) = get_parameters_from_message_and_carry(message_space, 1, constraints)
+            {
+                assert!(param.polynomial_size <= max_polynomial_size);
+            }
+        }
+
+        let err = get_parameters_from_message_and_carry(
+            4,
+            4,
+            ParametersQuery::new().min_security_bits(192),
+        )
+        .unwrap_err();
+        assert_eq!((err.message_bits, err.carry_bits), (2, 2));
+
+        let constraints = ParametersQuery::new().max_polynomial_size(PolynomialSize(256));
+        assert!(get_parameters_from_message_and_carry(4, 4, constraints).is_err());
+
+        let query = ParametersQuery::new().min_security_bits(192);
+        assert_eq!(ParametersCatalog::builtin().find(&query).count(), 0);
+    }
+
+    #[test]
+    fn test_builtin_parameters_have_metadata() {
+        // Only the sets generated by the optimizer are estimated
+        for entry in ParametersCatalog::builtin().iter() {
+            let derived = entry.name.contains("_MODULUS_2_") || entry.name.contains("_PBS_2M");
+            assert_eq!(entry.unverified, derived, "{}", entry.name);
+        }
+
+        for entry in ParametersCatalog::builtin().iter() {
+            let metadata = entry.parameters.metadata().unwrap();
+            if entry.unverified == metadata.unverified {
+                assert!(metadata.security_bits >= entry.security_bits);
+                assert!(metadata.log2_failure_probability <= entry.log2_failure_probability);
+            } else {
+                // An unverified set may share the values of an estimated one
+                assert!(entry.unverified);
+            }
+
+            let report = entry.parameters.report();
+            assert_eq!(
+                metadata.bootstrapping_key_size_bytes,
+                report.bootstrapping_key_size_bytes
+            );
+        }
+
+        let metadata = PARAM_MESSAGE_2_CARRY_2_PBS_2M64.metadata().unwrap();
+        assert_eq!(metadata.log2_failure_probability, -128);
+        assert!(metadata.unverified);
+        assert!(!PARAM_MESSAGE_2_CARRY_2.metadata().unwrap().unverified);
+    }
+}
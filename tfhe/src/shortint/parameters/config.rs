@@ -0,0 +1,612 @@
+//! Loading and storing parameter sets in configuration files.
+//!
+//! Parameter sets can be written in JSON or TOML, so that the parameters of a deployment are
+//! chosen by configuration instead of being compiled in. A configuration either refers to a set
+//! of the [`registry`](super::registry) by name:
+//!
+//! ```toml
+//! name = "PARAM_MESSAGE_2_CARRY_2"
+//! ```
+//!
+//! or describes the parameters explicitly, with a `pbs` section, a `wopbs` section, or both:
+//!
+//! ```toml
+//! [pbs]
+//! lwe_dimension = 742
+//! glwe_dimension = 1
+//! polynomial_size = 2048
+//! lwe_modular_std_dev = 0.000007069849454709433
+//! glwe_modular_std_dev = 0.00000000000000029403601535432533
+//! pbs_base_log = 23
+//! pbs_level = 1
+//! ks_base_log = 3
+//! ks_level = 5
+//! message_modulus = 4
+//! carry_modulus = 4
+//! encryption_key_choice = "Big"
+//! ```
+//!
+//! A t-uniform noise is set in the `pbs` section with `lwe_t_uniform_log2_bound` or
+//! `glwe_t_uniform_log2_bound` in place of the corresponding standard deviation. The `pbs` section
+//! also accepts a `secret_key_distribution` entry: `"Binary"` when it is absent, `"Ternary"` or
+//! `{ Gaussian = { std_dev = 3.2 } }`.
+//!
+//! The `wopbs` section has the same entries, plus `pfks_level`, `pfks_base_log`,
+//! `pfks_modular_std_dev`, `cbs_level` and `cbs_base_log`. Both sections accept an optional
+//! `ciphertext_modulus_log2` entry, the native modulus of 2^64 is used when it is absent. Unknown
+//! entries are rejected.
+//!
+//! # Example
+//!
+//! ```rust
+//! use tfhe::shortint::parameters::config::ConfigFormat;
+//! use tfhe::shortint::parameters::{ShortintParameterSet, PARAM_MESSAGE_2_CARRY_2};
+//!
+//! let params = ShortintParameterSet::from_config_str(
+//!     r#"name = "PARAM_MESSAGE_2_CARRY_2""#,
+//!     ConfigFormat::Toml,
+//! )
+//! .unwrap();
+//! assert_eq!(params.pbs_parameters(), Some(PARAM_MESSAGE_2_CARRY_2));
+//!
+//! let json = params.to_config_str(ConfigFormat::Json).unwrap();
+//! let loaded = ShortintParameterSet::from_config_str(&json, ConfigFormat::Json).unwrap();
+//! assert_eq!(loaded, params);
+//! ```
+
+use super::*;
+use std::fmt::{Display, Formatter};
+use std::path::Path;
+
+/// Format of a configuration file.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ConfigFormat {
+    Json,
+    Toml,
+}
+
+impl ConfigFormat {
+    /// Returns the format matching the extension of `path`, `.json` or `.toml`
+    pub fn from_path(path: &Path) -> Option<Self> {
+        match path.extension()?.to_str()? {
+            "json" => Some(Self::Json),
+            "toml" => Some(Self::Toml),
+            _ => None,
+        }
+    }
+}
+
+/// Error returned when loading or storing a configuration.
+#[derive(Debug)]
+pub enum ConfigError {
+    Io(std::io::Error),
+    /// The extension of the file is neither `.json` nor `.toml`
+    UnknownFormat(std::path::PathBuf),
+    /// The configuration does not follow the schema, `line` and `column` start at 1
+    Syntax {
+        line: usize,
+        column: usize,
+        message: String,
+    },
+    /// The configuration gives both a name and explicit parameters, or neither
+    AmbiguousConfig,
+    /// No parameter set is registered under this name
+    UnknownName(String),
+    /// The parameters of a section are inconsistent
+    InvalidParameters {
+        section: &'static str,
+        reason: String,
+    },
+    /// The parameter set cannot be represented in a configuration file
+    Unsupported(&'static str),
+    Serialization(String),
+}
+
+impl Display for ConfigError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io(error) => write!(f, "{error}"),
+            Self::UnknownFormat(path) => write!(
+                f,
+                "Cannot infer the format of {}, expected a .json or .toml extension",
+                path.display()
+            ),
+            Self::Syntax {
+                line,
+                column,
+                message,
+            } => write!(
+                f,
+                "Invalid configuration at line {line}, column {column}: {message}"
+            ),
+            Self::AmbiguousConfig => write!(
+                f,
+                "The configuration must contain either a `name` or `pbs`/`wopbs` sections"
+            ),
+            Self::UnknownName(name) => write!(f, "No parameter set is registered as `{name}`"),
+            Self::InvalidParameters { section, reason } => {
+                write!(f, "Invalid parameters in section `{section}`: {reason}")
+            }
+            Self::Unsupported(reason) => write!(f, "Cannot store the parameters: {reason}"),
+            Self::Serialization(message) => {
+                write!(f, "Failed to serialize the configuration: {message}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+impl From<std::io::Error> for ConfigError {
+    fn from(error: std::io::Error) -> Self {
+        Self::Io(error)
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ConfigFile {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pbs: Option<PbsSection>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    wopbs: Option<WopbsSection>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct PbsSection {
+    lwe_dimension: usize,
+    glwe_dimension: usize,
+    polynomial_size: usize,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    lwe_modular_std_dev: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    lwe_t_uniform_log2_bound: Option<i32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    glwe_modular_std_dev: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    glwe_t_uniform_log2_bound: Option<i32>,
+    pbs_base_log: usize,
+    pbs_level: usize,
+    ks_base_log: usize,
+    ks_level: usize,
+    message_modulus: usize,
+    carry_modulus: usize,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    ciphertext_modulus_log2: Option<usize>,
+    encryption_key_choice: EncryptionKeyChoice,
+    #[serde(default, skip_serializing_if = "is_binary")]
+    secret_key_distribution: SecretKeyDistribution,
+}
+
+fn is_binary(secret_key_distribution: &SecretKeyDistribution) -> bool {
+    *secret_key_distribution == SecretKeyDistribution::Binary
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct WopbsSection {
+    lwe_dimension: usize,
+    glwe_dimension: usize,
+    polynomial_size: usize,
+    lwe_modular_std_dev: f64,
+    glwe_modular_std_dev: f64,
+    pbs_base_log: usize,
+    pbs_level: usize,
+    ks_base_log: usize,
+    ks_level: usize,
+    pfks_level: usize,
+    pfks_base_log: usize,
+    pfks_modular_std_dev: f64,
+    cbs_level: usize,
+    cbs_base_log: usize,
+    message_modulus: usize,
+    carry_modulus: usize,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    ciphertext_modulus_log2: Option<usize>,
+    encryption_key_choice: EncryptionKeyChoice,
+}
+
+fn ciphertext_modulus_to_config(
+    ciphertext_modulus: CiphertextModulus,
+) -> Result<Option<usize>, ConfigError> {
+    if ciphertext_modulus.is_native_modulus() {
+        Ok(None)
+    } else if ciphertext_modulus.is_power_of_two() {
+        Ok(Some(
+            ciphertext_modulus.get_custom_modulus().ilog2() as usize
+        ))
+    } else {
+        Err(ConfigError::Unsupported(
+            "only power of two ciphertext moduli can be stored",
+        ))
+    }
+}
+
+fn ciphertext_modulus_from_config(
+    section: &'static str,
+    ciphertext_modulus_log2: Option<usize>,
+) -> Result<CiphertextModulus, ConfigError> {
+    ciphertext_modulus_log2.map_or(Ok(CiphertextModulus::new_native()), |log2| {
+        CiphertextModulus::try_new_power_of_2(log2).map_err(|reason| {
+            ConfigError::InvalidParameters {
+                section,
+                reason: reason.to_owned(),
+            }
+        })
+    })
+}
+
+// Splits a noise distribution into the standard deviation and t-uniform bound entries
+fn noise_distribution_to_config(
+    noise_distribution: DynamicDistribution,
+) -> (Option<f64>, Option<i32>) {
+    match noise_distribution {
+        DynamicDistribution::Gaussian(std_dev) => (Some(std_dev.0), None),
+        DynamicDistribution::TUniform { log2_bound } => (None, Some(log2_bound)),
+    }
+}
+
+fn noise_distribution_from_config(
+    name: &str,
+    std_dev: Option<f64>,
+    t_uniform_log2_bound: Option<i32>,
+) -> Result<DynamicDistribution, ConfigError> {
+    match (std_dev, t_uniform_log2_bound) {
+        (Some(std_dev), None) => Ok(DynamicDistribution::Gaussian(StandardDev(std_dev))),
+        (None, Some(log2_bound)) => Ok(DynamicDistribution::TUniform { log2_bound }),
+        _ => Err(ConfigError::InvalidParameters {
+            section: "pbs",
+            reason: format!(
+                "exactly one of `{name}_modular_std_dev` and `{name}_t_uniform_log2_bound` must \
+                be set"
+            ),
+        }),
+    }
+}
+
+impl PbsSection {
+    fn from_parameters(params: &PBSParameters) -> Result<Self, ConfigError> {
+        let (lwe_modular_std_dev, lwe_t_uniform_log2_bound) =
+            noise_distribution_to_config(params.lwe_noise_distribution);
+        let (glwe_modular_std_dev, glwe_t_uniform_log2_bound) =
+            noise_distribution_to_config(params.glwe_noise_distribution);
+
+        Ok(Self {
+            lwe_dimension: params.lwe_dimension.0,
+            glwe_dimension: params.glwe_dimension.0,
+            polynomial_size: params.polynomial_size.0,
+            lwe_modular_std_dev,
+            lwe_t_uniform_log2_bound,
+            glwe_modular_std_dev,
+            glwe_t_uniform_log2_bound,
+            pbs_base_log: params.pbs_base_log.0,
+            pbs_level: params.pbs_level.0,
+            ks_base_log: params.ks_base_log.0,
+            ks_level: params.ks_level.0,
+            message_modulus: params.message_modulus.0,
+            carry_modulus: params.carry_modulus.0,
+            ciphertext_modulus_log2: ciphertext_modulus_to_config(params.ciphertext_modulus)?,
+            encryption_key_choice: params.encryption_key_choice,
+            secret_key_distribution: params.secret_key_distribution,
+        })
+    }
+
+    fn into_parameters(self) -> Result<PBSParameters, ConfigError> {
+        let lwe_noise_distribution = noise_distribution_from_config(
+            "lwe",
+            self.lwe_modular_std_dev,
+            self.lwe_t_uniform_log2_bound,
+        )?;
+        let glwe_noise_distribution = noise_distribution_from_config(
+            "glwe",
+            self.glwe_modular_std_dev,
+            self.glwe_t_uniform_log2_bound,
+        )?;
+
+        PBSParametersBuilder::new()
+            .lwe_dimension(LweDimension(self.lwe_dimension))
+            .glwe_dimension(GlweDimension(self.glwe_dimension))
+            .polynomial_size(PolynomialSize(self.polynomial_size))
+            .lwe_noise_distribution(lwe_noise_distribution)
+            .glwe_noise_distribution(glwe_noise_distribution)
+            .pbs_base_log(DecompositionBaseLog(self.pbs_base_log))
+            .pbs_level(DecompositionLevelCount(self.pbs_level))
+            .ks_base_log(DecompositionBaseLog(self.ks_base_log))
+            .ks_level(DecompositionLevelCount(self.ks_level))
+            .message_modulus(MessageModulus(self.message_modulus))
+            .carry_modulus(CarryModulus(self.carry_modulus))
+            .ciphertext_modulus(ciphertext_modulus_from_config(
+                "pbs",
+                self.ciphertext_modulus_log2,
+            )?)
+            .encryption_key_choice(self.encryption_key_choice)
+            .secret_key_distribution(self.secret_key_distribution)
+            .build()
+            .map_err(|error| ConfigError::InvalidParameters {
+                section: "pbs",
+                reason: error.to_string(),
+            })
+    }
+}
+
+impl WopbsSection {
+    fn from_parameters(params: &WopbsParameters) -> Result<Self, ConfigError> {
+        Ok(Self {
+            lwe_dimension: params.lwe_dimension.0,
+            glwe_dimension: params.glwe_dimension.0,
+            polynomial_size: params.polynomial_size.0,
+            lwe_modular_std_dev: params.lwe_modular_std_dev.0,
+            glwe_modular_std_dev: params.glwe_modular_std_dev.0,
+            pbs_base_log: params.pbs_base_log.0,
+            pbs_level: params.pbs_level.0,
+            ks_base_log: params.ks_base_log.0,
+            ks_level: params.ks_level.0,
+            pfks_level: params.pfks_level.0,
+            pfks_base_log: params.pfks_base_log.0,
+            pfks_modular_std_dev: params.pfks_modular_std_dev.0,
+            cbs_level: params.cbs_level.0,
+            cbs_base_log: params.cbs_base_log.0,
+            message_modulus: params.message_modulus.0,
+            carry_modulus: params.carry_modulus.0,
+            ciphertext_modulus_log2: ciphertext_modulus_to_config(params.ciphertext_modulus)?,
+            encryption_key_choice: params.encryption_key_choice,
+        })
+    }
+
+    fn into_parameters(self) -> Result<WopbsParameters, ConfigError> {
+        WopbsParametersBuilder::new()
+            .lwe_dimension(LweDimension(self.lwe_dimension))
+            .glwe_dimension(GlweDimension(self.glwe_dimension))
+            .polynomial_size(PolynomialSize(self.polynomial_size))
+            .lwe_modular_std_dev(StandardDev(self.lwe_modular_std_dev))
+            .glwe_modular_std_dev(StandardDev(self.glwe_modular_std_dev))
+            .pbs_base_log(DecompositionBaseLog(self.pbs_base_log))
+            .pbs_level(DecompositionLevelCount(self.pbs_level))
+            .ks_base_log(DecompositionBaseLog(self.ks_base_log))
+            .ks_level(DecompositionLevelCount(self.ks_level))
+            .pfks_base_log(DecompositionBaseLog(self.pfks_base_log))
+            .pfks_level(DecompositionLevelCount(self.pfks_level))
+            .pfks_modular_std_dev(StandardDev(self.pfks_modular_std_dev))
+            .cbs_base_log(DecompositionBaseLog(self.cbs_base_log))
+            .cbs_level(DecompositionLevelCount(self.cbs_level))
+            .message_modulus(MessageModulus(self.message_modulus))
+            .carry_modulus(CarryModulus(self.carry_modulus))
+            .ciphertext_modulus(ciphertext_modulus_from_config(
+                "wopbs",
+                self.ciphertext_modulus_log2,
+            )?)
+            .encryption_key_choice(self.encryption_key_choice)
+            .build()
+            .map_err(|error| ConfigError::InvalidParameters {
+                section: "wopbs",
+                reason: error.to_string(),
+            })
+    }
+}
+
+/// Converts a byte offset in `source` to a line and a column, both starting at 1
+fn line_and_column(source: &str, offset: usize) -> (usize, usize) {
+    let before = &source[..offset.min(source.len())];
+    let line = before.matches('\n').count() + 1;
+    let column = before
+        .rfind('\n')
+        .map_or(before, |newline| &before[newline + 1..])
+        .chars()
+        .count()
+        + 1;
+    (line, column)
+}
+
+fn parse_config(source: &str, format: ConfigFormat) -> Result<ConfigFile, ConfigError> {
+    match format {
+        ConfigFormat::Json => serde_json::from_str(source).map_err(|error| ConfigError::Syntax {
+            line: error.line(),
+            column: error.column(),
+            message: error.to_string(),
+        }),
+        ConfigFormat::Toml => toml::from_str(source).map_err(|error| {
+            let (line, column) = error
+                .span()
+                .map_or((1, 1), |span| line_and_column(source, span.start));
+            ConfigError::Syntax {
+                line,
+                column,
+                message: error.message().to_owned(),
+            }
+        }),
+    }
+}
+
+impl ShortintParameterSet {
+    /// Loads a parameter set from a configuration, see [`config`](super::config) for the schema.
+    pub fn from_config_str(source: &str, format: ConfigFormat) -> Result<Self, ConfigError> {
+        let config = parse_config(source, format)?;
+
+        match (config.name, config.pbs, config.wopbs) {
+            (Some(name), None, None) => {
+                super::registry::lookup(&name).ok_or(ConfigError::UnknownName(name))
+            }
+            (None, Some(pbs), None) => Ok(Self::new_pbs_param_set(pbs.into_parameters()?)),
+            (None, None, Some(wopbs)) => Ok(Self::new_wopbs_param_set(wopbs.into_parameters()?)),
+            (None, Some(pbs), Some(wopbs)) => {
+                let params = (pbs.into_parameters()?, wopbs.into_parameters()?);
+                Self::try_new_pbs_and_wopbs_param_set(params).map_err(|reason| {
+                    ConfigError::InvalidParameters {
+                        section: "wopbs",
+                        reason: reason.to_owned(),
+                    }
+                })
+            }
+            _ => Err(ConfigError::AmbiguousConfig),
+        }
+    }
+
+    /// Stores the parameter set as a configuration with explicit `pbs` and `wopbs` sections.
+    pub fn to_config_str(&self, format: ConfigFormat) -> Result<String, ConfigError> {
+        if self.multi_bit_pbs_only() {
+            return Err(ConfigError::Unsupported(
+                "multi-bit PBS parameters have no configuration section",
+            ));
+        }
+        let config = ConfigFile {
+            name: None,
+            pbs: self
+                .pbs_parameters()
+                .as_ref()
+                .map(PbsSection::from_parameters)
+                .transpose()?,
+            wopbs: self
+                .wopbs_parameters()
+                .as_ref()
+                .map(WopbsSection::from_parameters)
+                .transpose()?,
+        };
+
+        match format {
+            ConfigFormat::Json => serde_json::to_string_pretty(&config)
+                .map_err(|error| ConfigError::Serialization(error.to_string())),
+            ConfigFormat::Toml => toml::to_string(&config)
+                .map_err(|error| ConfigError::Serialization(error.to_string())),
+        }
+    }
+
+    /// Loads a parameter set from a `.json` or `.toml` file.
+    pub fn from_config_file(path: impl AsRef<Path>) -> Result<Self, ConfigError> {
+        let path = path.as_ref();
+        let format = ConfigFormat::from_path(path)
+            .ok_or_else(|| ConfigError::UnknownFormat(path.to_owned()))?;
+        let source = std::fs::read_to_string(path)?;
+        Self::from_config_str(&source, format)
+    }
+
+    /// Stores the parameter set in a `.json` or `.toml` file.
+    pub fn to_config_file(&self, path: impl AsRef<Path>) -> Result<(), ConfigError> {
+        let path = path.as_ref();
+        let format = ConfigFormat::from_path(path)
+            .ok_or_else(|| ConfigError::UnknownFormat(path.to_owned()))?;
+        std::fs::write(path, self.to_config_str(format)?)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shortint::parameters::parameters_wopbs_message_carry::WOPBS_PARAM_MESSAGE_2_CARRY_2;
+
+    #[test]
+    fn test_config_round_trip() {
+        let t_uniform = PBSParametersBuilder::from(PARAM_MESSAGE_2_CARRY_2)
+            .lwe_noise_distribution(DynamicDistribution::new_t_uniform(-46))
+            .build()
+            .unwrap();
+        let gaussian_key = PBSParametersBuilder::from(PARAM_MESSAGE_2_CARRY_2)
+            .secret_key_distribution(SecretKeyDistribution::Gaussian { std_dev: 3.2 })
+            .build()
+            .unwrap();
+        let params = [
+            ShortintParameterSet::from(PARAM_MESSAGE_2_CARRY_2),
+            ShortintParameterSet::from(t_uniform),
+            ShortintParameterSet::from(gaussian_key),
+            ShortintParameterSet::from(PARAM_SMALL_MESSAGE_1_CARRY_1),
+            ShortintParameterSet::from(WOPBS_PARAM_MESSAGE_2_CARRY_2),
+            ShortintParameterSet::try_from((
+                PARAM_MESSAGE_2_CARRY_2,
+                WOPBS_PARAM_MESSAGE_2_CARRY_2,
+            ))
+            .unwrap(),
+        ];
+
+        for param in params {
+            for format in [ConfigFormat::Json, ConfigFormat::Toml] {
+                let config = param.to_config_str(format).unwrap();
+                let loaded = ShortintParameterSet::from_config_str(&config, format).unwrap();
+                assert_eq!(loaded, param);
+            }
+        }
+    }
+
+    #[test]
+    fn test_config_errors() {
+        let config = "[pbs]\nlwe_dimension = 742\nglwe_dimention = 1\n";
+        match ShortintParameterSet::from_config_str(config, ConfigFormat::Toml) {
+            Err(ConfigError::Syntax { line, .. }) => assert_eq!(line, 3),
+            _ => panic!("Expected a syntax error"),
+        }
+
+        let config = "{\n  \"pbs\": {\n    \"lwe_dimension\": -1\n  }\n}";
+        match ShortintParameterSet::from_config_str(config, ConfigFormat::Json) {
+            Err(ConfigError::Syntax { line, .. }) => assert_eq!(line, 3),
+            _ => panic!("Expected a syntax error"),
+        }
+
+        let config = PARAM_MESSAGE_2_CARRY_2_CONFIG.replace("2048", "2000");
+        assert!(matches!(
+            ShortintParameterSet::from_config_str(&config, ConfigFormat::Toml),
+            Err(ConfigError::InvalidParameters { section: "pbs", .. })
+        ));
+
+        let config = PARAM_MESSAGE_2_CARRY_2_CONFIG.replace(
+            "lwe_dimension = 742",
+            "lwe_dimension = 742\nlwe_t_uniform_log2_bound = -46",
+        );
+        assert!(matches!(
+            ShortintParameterSet::from_config_str(&config, ConfigFormat::Toml),
+            Err(ConfigError::InvalidParameters { section: "pbs", .. })
+        ));
+
+        // The WoPBS section goes through the same checks as the WoPBS parameters builder
+        let invalid_wopbs = WopbsParameters {
+            pfks_modular_std_dev: StandardDev(1.0),
+            ..WOPBS_PARAM_MESSAGE_2_CARRY_2
+        };
+        let config = ShortintParameterSet::from(invalid_wopbs)
+            .to_config_str(ConfigFormat::Toml)
+            .unwrap();
+        assert!(matches!(
+            ShortintParameterSet::from_config_str(&config, ConfigFormat::Toml),
+            Err(ConfigError::InvalidParameters {
+                section: "wopbs",
+                ..
+            })
+        ));
+
+        assert!(matches!(
+            ShortintParameterSet::from_config_str("name = \"NOT_A_SET\"", ConfigFormat::Toml),
+            Err(ConfigError::UnknownName(_))
+        ));
+        assert!(matches!(
+            ShortintParameterSet::from_config_str("", ConfigFormat::Toml),
+            Err(ConfigError::AmbiguousConfig)
+        ));
+    }
+
+    const PARAM_MESSAGE_2_CARRY_2_CONFIG: &str = r#"
+[pbs]
+lwe_dimension = 742
+glwe_dimension = 1
+polynomial_size = 2048
+lwe_modular_std_dev = 0.000007069849454709433
+glwe_modular_std_dev = 0.00000000000000029403601535432533
+pbs_base_log = 23
+pbs_level = 1
+ks_base_log = 3
+ks_level = 5
+message_modulus = 4
+carry_modulus = 4
+encryption_key_choice = "Big"
+"#;
+
+    #[test]
+    fn test_config_documented_example() {
+        let params = ShortintParameterSet::from_config_str(
+            PARAM_MESSAGE_2_CARRY_2_CONFIG,
+            ConfigFormat::Toml,
+        )
+        .unwrap();
+        assert_eq!(params.pbs_parameters(), Some(PARAM_MESSAGE_2_CARRY_2));
+    }
+}
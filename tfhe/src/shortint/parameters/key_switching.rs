@@ -0,0 +1,53 @@
+//! Parameters of the key switching and of the casting between two client keys, see
+//! [`crate::shortint::key_switching_key`].
+
+use super::{DecompositionBaseLog, DecompositionLevelCount};
+use serde::{Deserialize, Serialize};
+
+/// A structure defining the decomposition of a key switching key between the encryption keys of
+/// two client keys.
+///
+/// The noise of the key switching key is the one of its output key, the decomposition has to be
+/// chosen accordingly: a large output key has a small noise, which allows a single level with a
+/// large base, while a small output key needs the decomposition of the key switching key of its
+/// server key.
+#[derive(Serialize, Copy, Clone, Deserialize, Debug, PartialEq, Eq)]
+pub struct ShortintKeySwitchingParameters {
+    pub ks_base_log: DecompositionBaseLog,
+    pub ks_level: DecompositionLevelCount,
+}
+
+impl ShortintKeySwitchingParameters {
+    pub fn new(ks_base_log: DecompositionBaseLog, ks_level: DecompositionLevelCount) -> Self {
+        Self {
+            ks_base_log,
+            ks_level,
+        }
+    }
+}
+
+/// Key switching parameters between two client keys of
+/// [`PARAM_MESSAGE_2_CARRY_2`](super::PARAM_MESSAGE_2_CARRY_2), from a large key to a large key.
+pub const PARAM_KEYSWITCH_MESSAGE_2_CARRY_2: ShortintKeySwitchingParameters =
+    ShortintKeySwitchingParameters {
+        ks_base_log: DecompositionBaseLog(23),
+        ks_level: DecompositionLevelCount(1),
+    };
+
+/// Key switching parameters between two client keys of
+/// [`PARAM_SMALL_MESSAGE_2_CARRY_2`](super::PARAM_SMALL_MESSAGE_2_CARRY_2), from a small key to
+/// a small key.
+pub const PARAM_KEYSWITCH_SMALL_MESSAGE_2_CARRY_2: ShortintKeySwitchingParameters =
+    ShortintKeySwitchingParameters {
+        ks_base_log: DecompositionBaseLog(4),
+        ks_level: DecompositionLevelCount(4),
+    };
+
+/// Casting parameters from [`PARAM_MESSAGE_2_CARRY_2`](super::PARAM_MESSAGE_2_CARRY_2) to
+/// [`PARAM_MESSAGE_4_CARRY_4`](super::PARAM_MESSAGE_4_CARRY_4), with the decomposition of the key
+/// switching key of the output parameters.
+pub const PARAM_CAST_MESSAGE_2_CARRY_2_TO_MESSAGE_4_CARRY_4: ShortintKeySwitchingParameters =
+    ShortintKeySwitchingParameters {
+        ks_base_log: DecompositionBaseLog(3),
+        ks_level: DecompositionLevelCount(7),
+    };
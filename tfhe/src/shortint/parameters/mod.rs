@@ -5,7 +5,10 @@
 //! homomorphic evaluation of integer circuits as well as a list of secure cryptographic parameter
 //! sets.
 
-pub use crate::core_crypto::commons::dispersion::{DispersionParameter, StandardDev};
+pub use crate::core_crypto::commons::dispersion::{
+    DispersionParameter, DynamicDistribution, LogStandardDev, NoiseBits, SecretKeyDistribution,
+    StandardDev, Variance,
+};
 pub use crate::core_crypto::commons::parameters::{
     CiphertextModulus as CoreCiphertextModulus, DecompositionBaseLog, DecompositionLevelCount,
     GlweDimension, LweDimension, PolynomialSize,
@@ -13,11 +16,56 @@ pub use crate::core_crypto::commons::parameters::{
 use crate::shortint::ciphertext::PBSOrder;
 use serde::{Deserialize, Serialize};
 
+pub mod builder;
+pub mod catalog;
+#[cfg(feature = "config-files")]
+pub mod config;
+pub mod key_switching;
+pub mod multi_bit;
+pub mod noise_squashing;
+pub mod optimizer;
+#[cfg(feature = "insecure-test-params")]
+pub mod parameters_insecure_test;
+pub mod parameters_lower_failure_probability;
+pub mod parameters_non_native_modulus;
+pub mod parameters_torus32;
 pub mod parameters_wopbs;
 pub mod parameters_wopbs_message_carry;
 pub(crate) mod parameters_wopbs_prime_moduli;
+pub mod performance;
+pub mod provenance;
+pub mod registry;
+pub mod report;
+pub mod versioning;
 
-pub use parameters_wopbs::WopbsParameters;
+pub use builder::{PBSParametersBuilder, ParameterError, WopbsParametersBuilder};
+pub use catalog::{NamedParameters, ParameterMetadata, ParametersCatalog, ParametersQuery};
+pub use key_switching::{
+    ShortintKeySwitchingParameters, PARAM_CAST_MESSAGE_2_CARRY_2_TO_MESSAGE_4_CARRY_4,
+    PARAM_KEYSWITCH_MESSAGE_2_CARRY_2, PARAM_KEYSWITCH_SMALL_MESSAGE_2_CARRY_2,
+};
+pub use multi_bit::*;
+pub use noise_squashing::{
+    NoiseSquashingError, NoiseSquashingParameters, NOISE_SQUASHING_PARAM_MESSAGE_2_CARRY_2,
+};
+pub use optimizer::{CatalogOptimizer, CircuitDescription, ParameterOptimizer};
+#[cfg(feature = "insecure-test-params")]
+pub(crate) use parameters_insecure_test::{
+    check_serializable_key_dimensions, serialize_key_parameters,
+};
+#[cfg(feature = "insecure-test-params")]
+pub use parameters_insecure_test::{
+    INSECURE_TEST_PARAMETERS_VEC, PARAM_TEST_FAST_MESSAGE_1_CARRY_1,
+    PARAM_TEST_FAST_MESSAGE_2_CARRY_2,
+};
+pub use parameters_lower_failure_probability::*;
+pub use parameters_non_native_modulus::*;
+pub use parameters_torus32::*;
+pub use parameters_wopbs::{WopbsCompatibilityError, WopbsNoiseEstimate, WopbsParameters};
+pub use performance::PerformanceTarget;
+pub use provenance::{GenerationRecord, ParameterProvenance};
+pub use report::{ParametersReport, PbsLatencyClass};
+pub use versioning::{ParameterVersion, ParameterVersionStatus};
 
 /// The choice of encryption key for (`shortint ciphertext`)[`super::ciphertext::CiphertextBase`].
 ///
@@ -54,8 +102,11 @@ pub struct MessageModulus(pub usize);
 #[derive(Debug, PartialEq, Eq, Copy, Clone, Serialize, Deserialize)]
 pub struct CarryModulus(pub usize);
 
-/// Determines in what ring computations are made
-pub type CiphertextModulus = CoreCiphertextModulus<u64>;
+/// Determines in what ring computations are made, for ciphertexts stored in `Scalar`.
+///
+/// The shortint keys and ciphertexts use `u64`, see [`parameters_torus32`] for parameters on a
+/// 32 bits torus.
+pub type CiphertextModulus<Scalar = u64> = CoreCiphertextModulus<Scalar>;
 
 /// A structure defining the set of cryptographic parameters for homomorphic integer circuit
 /// evaluation.
@@ -64,8 +115,8 @@ pub struct PBSParameters {
     pub lwe_dimension: LweDimension,
     pub glwe_dimension: GlweDimension,
     pub polynomial_size: PolynomialSize,
-    pub lwe_modular_std_dev: StandardDev,
-    pub glwe_modular_std_dev: StandardDev,
+    pub lwe_noise_distribution: DynamicDistribution,
+    pub glwe_noise_distribution: DynamicDistribution,
     pub pbs_base_log: DecompositionBaseLog,
     pub pbs_level: DecompositionLevelCount,
     pub ks_base_log: DecompositionBaseLog,
@@ -74,6 +125,15 @@ pub struct PBSParameters {
     pub carry_modulus: CarryModulus,
     pub ciphertext_modulus: CiphertextModulus,
     pub encryption_key_choice: EncryptionKeyChoice,
+    /// Distribution of the coefficients of the GLWE secret key, from which the large LWE secret
+    /// key is derived.
+    ///
+    /// The small LWE secret key, input of the blind rotation, is always binary: the blind
+    /// rotation computes one CMux per coefficient of this key, which is only correct for binary
+    /// coefficients. The built-in parameter sets use binary keys, the noise analysis of their
+    /// failure probability does not hold for other distributions.
+    #[serde(default)]
+    pub secret_key_distribution: SecretKeyDistribution,
 }
 
 impl PBSParameters {
@@ -84,13 +144,21 @@ impl PBSParameters {
     /// This function is unsafe, as failing to fix the parameters properly would yield incorrect
     /// and unsecure computation. Unless you are a cryptographer who really knows the impact of each
     /// of those parameters, you __must__ stick with the provided parameters.
+    ///
+    /// The GLWE secret key is binary, see [`PBSParametersBuilder::secret_key_distribution`] to
+    /// choose another distribution.
+    ///
+    /// The noise distributions can be given as a [`StandardDev`], for a Gaussian noise, or as a
+    /// [`DynamicDistribution`].
+    ///
+    /// [`PBSParametersBuilder`] provides a checked way to build custom parameters.
     #[allow(clippy::too_many_arguments)]
     pub unsafe fn new(
         lwe_dimension: LweDimension,
         glwe_dimension: GlweDimension,
         polynomial_size: PolynomialSize,
-        lwe_modular_std_dev: StandardDev,
-        glwe_modular_std_dev: StandardDev,
+        lwe_noise_distribution: impl Into<DynamicDistribution>,
+        glwe_noise_distribution: impl Into<DynamicDistribution>,
         pbs_base_log: DecompositionBaseLog,
         pbs_level: DecompositionLevelCount,
         ks_base_log: DecompositionBaseLog,
@@ -104,8 +172,8 @@ impl PBSParameters {
             lwe_dimension,
             glwe_dimension,
             polynomial_size,
-            lwe_modular_std_dev,
-            glwe_modular_std_dev,
+            lwe_noise_distribution: lwe_noise_distribution.into(),
+            glwe_noise_distribution: glwe_noise_distribution.into(),
             pbs_base_log,
             pbs_level,
             ks_level,
@@ -114,8 +182,21 @@ impl PBSParameters {
             carry_modulus,
             ciphertext_modulus,
             encryption_key_choice,
+            secret_key_distribution: SecretKeyDistribution::Binary,
         }
     }
+
+    /// Returns the standard deviation of the LWE noise.
+    #[deprecated(note = "use the `lwe_noise_distribution` field, which can also be t-uniform")]
+    pub fn lwe_modular_std_dev(&self) -> StandardDev {
+        StandardDev(self.lwe_noise_distribution.get_standard_dev())
+    }
+
+    /// Returns the standard deviation of the GLWE noise.
+    #[deprecated(note = "use the `glwe_noise_distribution` field, which can also be t-uniform")]
+    pub fn glwe_modular_std_dev(&self) -> StandardDev {
+        StandardDev(self.glwe_noise_distribution.get_standard_dev())
+    }
 }
 
 #[derive(Serialize, Copy, Clone, Deserialize, Debug, PartialEq)]
@@ -123,6 +204,7 @@ enum ShortintParameterSetInner {
     PBSOnly(PBSParameters),
     WopbsOnly(WopbsParameters),
     PBSAndWopbs(PBSParameters, WopbsParameters),
+    MultiBitPBSOnly(MultiBitPBSParameters),
 }
 
 impl ShortintParameterSetInner {
@@ -137,6 +219,10 @@ impl ShortintParameterSetInner {
     pub const fn pbs_and_wopbs(&self) -> bool {
         matches!(self, Self::PBSAndWopbs(_, _))
     }
+
+    pub const fn multi_bit_pbs_only(&self) -> bool {
+        matches!(self, Self::MultiBitPBSOnly(_))
+    }
 }
 
 #[derive(Serialize, Copy, Clone, Deserialize, Debug, PartialEq)]
@@ -157,14 +243,16 @@ impl ShortintParameterSet {
         }
     }
 
+    pub const fn new_multi_bit_pbs_param_set(params: MultiBitPBSParameters) -> Self {
+        Self {
+            inner: ShortintParameterSetInner::MultiBitPBSOnly(params),
+        }
+    }
+
     pub fn try_new_pbs_and_wopbs_param_set(
         (pbs_params, wopbs_params): (PBSParameters, WopbsParameters),
     ) -> Result<Self, &'static str> {
-        if pbs_params.carry_modulus != wopbs_params.carry_modulus
-            || pbs_params.message_modulus != wopbs_params.message_modulus
-            || pbs_params.ciphertext_modulus != wopbs_params.ciphertext_modulus
-            || pbs_params.encryption_key_choice != wopbs_params.encryption_key_choice
-        {
+        if !wopbs_params.compatible_with(&pbs_params) {
             return Err(
                 "Incompatible PBSParameters and WopbsParameters, this may be due to mismatched \
                 carry moduli, message moduli, ciphertext moduli or encryption key choices",
@@ -178,6 +266,7 @@ impl ShortintParameterSet {
     pub fn pbs_parameters(&self) -> Option<PBSParameters> {
         match self.inner {
             ShortintParameterSetInner::PBSOnly(params) => Some(params),
+            ShortintParameterSetInner::MultiBitPBSOnly(_) => None,
             ShortintParameterSetInner::WopbsOnly(_) => None,
             ShortintParameterSetInner::PBSAndWopbs(params, _) => Some(params),
         }
@@ -186,14 +275,25 @@ impl ShortintParameterSet {
     pub fn wopbs_parameters(&self) -> Option<WopbsParameters> {
         match self.inner {
             ShortintParameterSetInner::PBSOnly(_) => None,
+            ShortintParameterSetInner::MultiBitPBSOnly(_) => None,
             ShortintParameterSetInner::WopbsOnly(params) => Some(params),
             ShortintParameterSetInner::PBSAndWopbs(_, params) => Some(params),
         }
     }
 
+    pub fn multi_bit_parameters(&self) -> Option<MultiBitPBSParameters> {
+        match self.inner {
+            ShortintParameterSetInner::PBSOnly(_) => None,
+            ShortintParameterSetInner::MultiBitPBSOnly(params) => Some(params),
+            ShortintParameterSetInner::WopbsOnly(_) => None,
+            ShortintParameterSetInner::PBSAndWopbs(_, _) => None,
+        }
+    }
+
     pub fn lwe_dimension(&self) -> LweDimension {
         match self.inner {
             ShortintParameterSetInner::PBSOnly(params) => params.lwe_dimension,
+            ShortintParameterSetInner::MultiBitPBSOnly(params) => params.lwe_dimension,
             ShortintParameterSetInner::WopbsOnly(params) => params.lwe_dimension,
             ShortintParameterSetInner::PBSAndWopbs(params, _) => params.lwe_dimension,
         }
@@ -202,6 +302,7 @@ impl ShortintParameterSet {
     pub fn glwe_dimension(&self) -> GlweDimension {
         match self.inner {
             ShortintParameterSetInner::PBSOnly(params) => params.glwe_dimension,
+            ShortintParameterSetInner::MultiBitPBSOnly(params) => params.glwe_dimension,
             ShortintParameterSetInner::WopbsOnly(params) => params.glwe_dimension,
             ShortintParameterSetInner::PBSAndWopbs(params, _) => params.glwe_dimension,
         }
@@ -210,30 +311,50 @@ impl ShortintParameterSet {
     pub fn polynomial_size(&self) -> PolynomialSize {
         match self.inner {
             ShortintParameterSetInner::PBSOnly(params) => params.polynomial_size,
+            ShortintParameterSetInner::MultiBitPBSOnly(params) => params.polynomial_size,
             ShortintParameterSetInner::WopbsOnly(params) => params.polynomial_size,
             ShortintParameterSetInner::PBSAndWopbs(params, _) => params.polynomial_size,
         }
     }
 
-    pub fn lwe_modular_std_dev(&self) -> StandardDev {
+    pub fn lwe_noise_distribution(&self) -> DynamicDistribution {
         match self.inner {
-            ShortintParameterSetInner::PBSOnly(params) => params.lwe_modular_std_dev,
-            ShortintParameterSetInner::WopbsOnly(params) => params.lwe_modular_std_dev,
-            ShortintParameterSetInner::PBSAndWopbs(params, _) => params.lwe_modular_std_dev,
+            ShortintParameterSetInner::PBSOnly(params) => params.lwe_noise_distribution,
+            ShortintParameterSetInner::MultiBitPBSOnly(params) => params.lwe_noise_distribution,
+            ShortintParameterSetInner::WopbsOnly(params) => {
+                DynamicDistribution::Gaussian(params.lwe_modular_std_dev)
+            }
+            ShortintParameterSetInner::PBSAndWopbs(params, _) => params.lwe_noise_distribution,
         }
     }
 
-    pub fn glwe_modular_std_dev(&self) -> StandardDev {
+    pub fn glwe_noise_distribution(&self) -> DynamicDistribution {
         match self.inner {
-            ShortintParameterSetInner::PBSOnly(params) => params.glwe_modular_std_dev,
-            ShortintParameterSetInner::WopbsOnly(params) => params.glwe_modular_std_dev,
-            ShortintParameterSetInner::PBSAndWopbs(params, _) => params.glwe_modular_std_dev,
+            ShortintParameterSetInner::PBSOnly(params) => params.glwe_noise_distribution,
+            ShortintParameterSetInner::MultiBitPBSOnly(params) => params.glwe_noise_distribution,
+            ShortintParameterSetInner::WopbsOnly(params) => {
+                DynamicDistribution::Gaussian(params.glwe_modular_std_dev)
+            }
+            ShortintParameterSetInner::PBSAndWopbs(params, _) => params.glwe_noise_distribution,
         }
     }
 
+    /// Returns the standard deviation of the LWE noise.
+    #[deprecated(note = "use `lwe_noise_distribution`, which can also be t-uniform")]
+    pub fn lwe_modular_std_dev(&self) -> StandardDev {
+        StandardDev(self.lwe_noise_distribution().get_standard_dev())
+    }
+
+    /// Returns the standard deviation of the GLWE noise.
+    #[deprecated(note = "use `glwe_noise_distribution`, which can also be t-uniform")]
+    pub fn glwe_modular_std_dev(&self) -> StandardDev {
+        StandardDev(self.glwe_noise_distribution().get_standard_dev())
+    }
+
     pub fn pbs_base_log(&self) -> DecompositionBaseLog {
         match self.inner {
             ShortintParameterSetInner::PBSOnly(params) => params.pbs_base_log,
+            ShortintParameterSetInner::MultiBitPBSOnly(params) => params.pbs_base_log,
             ShortintParameterSetInner::WopbsOnly(params) => params.pbs_base_log,
             ShortintParameterSetInner::PBSAndWopbs(params, _) => params.pbs_base_log,
         }
@@ -242,6 +363,7 @@ impl ShortintParameterSet {
     pub fn pbs_level(&self) -> DecompositionLevelCount {
         match self.inner {
             ShortintParameterSetInner::PBSOnly(params) => params.pbs_level,
+            ShortintParameterSetInner::MultiBitPBSOnly(params) => params.pbs_level,
             ShortintParameterSetInner::WopbsOnly(params) => params.pbs_level,
             ShortintParameterSetInner::PBSAndWopbs(params, _) => params.pbs_level,
         }
@@ -250,6 +372,7 @@ impl ShortintParameterSet {
     pub fn ks_base_log(&self) -> DecompositionBaseLog {
         match self.inner {
             ShortintParameterSetInner::PBSOnly(params) => params.ks_base_log,
+            ShortintParameterSetInner::MultiBitPBSOnly(params) => params.ks_base_log,
             ShortintParameterSetInner::WopbsOnly(params) => params.ks_base_log,
             ShortintParameterSetInner::PBSAndWopbs(params, _) => params.ks_base_log,
         }
@@ -258,6 +381,7 @@ impl ShortintParameterSet {
     pub fn ks_level(&self) -> DecompositionLevelCount {
         match self.inner {
             ShortintParameterSetInner::PBSOnly(params) => params.ks_level,
+            ShortintParameterSetInner::MultiBitPBSOnly(params) => params.ks_level,
             ShortintParameterSetInner::WopbsOnly(params) => params.ks_level,
             ShortintParameterSetInner::PBSAndWopbs(params, _) => params.ks_level,
         }
@@ -266,6 +390,7 @@ impl ShortintParameterSet {
     pub fn message_modulus(&self) -> MessageModulus {
         match self.inner {
             ShortintParameterSetInner::PBSOnly(params) => params.message_modulus,
+            ShortintParameterSetInner::MultiBitPBSOnly(params) => params.message_modulus,
             ShortintParameterSetInner::WopbsOnly(params) => params.message_modulus,
             ShortintParameterSetInner::PBSAndWopbs(params, _) => params.message_modulus,
         }
@@ -274,6 +399,7 @@ impl ShortintParameterSet {
     pub fn carry_modulus(&self) -> CarryModulus {
         match self.inner {
             ShortintParameterSetInner::PBSOnly(params) => params.carry_modulus,
+            ShortintParameterSetInner::MultiBitPBSOnly(params) => params.carry_modulus,
             ShortintParameterSetInner::WopbsOnly(params) => params.carry_modulus,
             ShortintParameterSetInner::PBSAndWopbs(params, _) => params.carry_modulus,
         }
@@ -282,6 +408,7 @@ impl ShortintParameterSet {
     pub fn ciphertext_modulus(&self) -> CiphertextModulus {
         match self.inner {
             ShortintParameterSetInner::PBSOnly(params) => params.ciphertext_modulus,
+            ShortintParameterSetInner::MultiBitPBSOnly(params) => params.ciphertext_modulus,
             ShortintParameterSetInner::WopbsOnly(params) => params.ciphertext_modulus,
             ShortintParameterSetInner::PBSAndWopbs(params, _) => params.ciphertext_modulus,
         }
@@ -290,11 +417,69 @@ impl ShortintParameterSet {
     pub fn encryption_key_choice(&self) -> EncryptionKeyChoice {
         match self.inner {
             ShortintParameterSetInner::PBSOnly(params) => params.encryption_key_choice,
+            ShortintParameterSetInner::MultiBitPBSOnly(params) => params.encryption_key_choice,
             ShortintParameterSetInner::WopbsOnly(params) => params.encryption_key_choice,
             ShortintParameterSetInner::PBSAndWopbs(params, _) => params.encryption_key_choice,
         }
     }
 
+    /// Distribution of the GLWE secret key, WoPBS only parameters always use binary keys.
+    pub fn secret_key_distribution(&self) -> SecretKeyDistribution {
+        match self.inner {
+            ShortintParameterSetInner::PBSOnly(params) => params.secret_key_distribution,
+            ShortintParameterSetInner::MultiBitPBSOnly(params) => params.secret_key_distribution,
+            ShortintParameterSetInner::WopbsOnly(_) => SecretKeyDistribution::Binary,
+            ShortintParameterSetInner::PBSAndWopbs(params, _) => params.secret_key_distribution,
+        }
+    }
+
+    /// Number of levels of the decomposition of the circuit bootstrapping, `None` if there are
+    /// no WoPBS parameters.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::parameters_wopbs_message_carry::WOPBS_PARAM_MESSAGE_2_CARRY_2;
+    /// use tfhe::shortint::parameters::{ShortintParameterSet, PARAM_MESSAGE_2_CARRY_2};
+    ///
+    /// let params = ShortintParameterSet::from(WOPBS_PARAM_MESSAGE_2_CARRY_2);
+    /// assert_eq!(
+    ///     params.cbs_level(),
+    ///     Some(WOPBS_PARAM_MESSAGE_2_CARRY_2.cbs_level)
+    /// );
+    ///
+    /// let params = ShortintParameterSet::from(PARAM_MESSAGE_2_CARRY_2);
+    /// assert_eq!(params.cbs_level(), None);
+    /// ```
+    pub fn cbs_level(&self) -> Option<DecompositionLevelCount> {
+        self.wopbs_parameters().map(|params| params.cbs_level)
+    }
+
+    /// Base 2 logarithm of the base of the decomposition of the circuit bootstrapping, `None` if
+    /// there are no WoPBS parameters.
+    pub fn cbs_base_log(&self) -> Option<DecompositionBaseLog> {
+        self.wopbs_parameters().map(|params| params.cbs_base_log)
+    }
+
+    /// Number of levels of the decomposition of the private functional packing keyswitch, `None`
+    /// if there are no WoPBS parameters.
+    pub fn pfks_level(&self) -> Option<DecompositionLevelCount> {
+        self.wopbs_parameters().map(|params| params.pfks_level)
+    }
+
+    /// Base 2 logarithm of the base of the decomposition of the private functional packing
+    /// keyswitch, `None` if there are no WoPBS parameters.
+    pub fn pfks_base_log(&self) -> Option<DecompositionBaseLog> {
+        self.wopbs_parameters().map(|params| params.pfks_base_log)
+    }
+
+    /// Standard deviation of the noise of the private functional packing keyswitch keys, `None` if
+    /// there are no WoPBS parameters.
+    pub fn pfks_modular_std_dev(&self) -> Option<StandardDev> {
+        self.wopbs_parameters()
+            .map(|params| params.pfks_modular_std_dev)
+    }
+
     pub const fn pbs_only(&self) -> bool {
         self.inner.pbs_only()
     }
@@ -306,6 +491,10 @@ impl ShortintParameterSet {
     pub const fn pbs_and_wopbs(&self) -> bool {
         self.inner.pbs_and_wopbs()
     }
+
+    pub const fn multi_bit_pbs_only(&self) -> bool {
+        self.inner.multi_bit_pbs_only()
+    }
 }
 
 impl From<PBSParameters> for ShortintParameterSet {
@@ -320,6 +509,12 @@ impl From<WopbsParameters> for ShortintParameterSet {
     }
 }
 
+impl From<MultiBitPBSParameters> for ShortintParameterSet {
+    fn from(value: MultiBitPBSParameters) -> Self {
+        Self::new_multi_bit_pbs_param_set(value)
+    }
+}
+
 impl TryFrom<(PBSParameters, WopbsParameters)> for ShortintParameterSet {
     type Error = &'static str;
 
@@ -363,6 +558,39 @@ pub const WITH_CARRY_PARAMETERS_VEC: [PBSParameters; 28] = [
     PARAM_MESSAGE_7_CARRY_1,
 ];
 
+/// Vector containing the parameter sets of [`WITH_CARRY_PARAMETERS_VEC`] for the
+/// [`EncryptionKeyChoice::Small`] key choice
+pub const SMALL_WITH_CARRY_PARAMETERS_VEC: [PBSParameters; 28] = [
+    PARAM_SMALL_MESSAGE_1_CARRY_1,
+    PARAM_SMALL_MESSAGE_1_CARRY_2,
+    PARAM_SMALL_MESSAGE_1_CARRY_3,
+    PARAM_SMALL_MESSAGE_1_CARRY_4,
+    PARAM_SMALL_MESSAGE_1_CARRY_5,
+    PARAM_SMALL_MESSAGE_1_CARRY_6,
+    PARAM_SMALL_MESSAGE_1_CARRY_7,
+    PARAM_SMALL_MESSAGE_2_CARRY_1,
+    PARAM_SMALL_MESSAGE_2_CARRY_2,
+    PARAM_SMALL_MESSAGE_2_CARRY_3,
+    PARAM_SMALL_MESSAGE_2_CARRY_4,
+    PARAM_SMALL_MESSAGE_2_CARRY_5,
+    PARAM_SMALL_MESSAGE_2_CARRY_6,
+    PARAM_SMALL_MESSAGE_3_CARRY_1,
+    PARAM_SMALL_MESSAGE_3_CARRY_2,
+    PARAM_SMALL_MESSAGE_3_CARRY_3,
+    PARAM_SMALL_MESSAGE_3_CARRY_4,
+    PARAM_SMALL_MESSAGE_3_CARRY_5,
+    PARAM_SMALL_MESSAGE_4_CARRY_1,
+    PARAM_SMALL_MESSAGE_4_CARRY_2,
+    PARAM_SMALL_MESSAGE_4_CARRY_3,
+    PARAM_SMALL_MESSAGE_4_CARRY_4,
+    PARAM_SMALL_MESSAGE_5_CARRY_1,
+    PARAM_SMALL_MESSAGE_5_CARRY_2,
+    PARAM_SMALL_MESSAGE_5_CARRY_3,
+    PARAM_SMALL_MESSAGE_6_CARRY_1,
+    PARAM_SMALL_MESSAGE_6_CARRY_2,
+    PARAM_SMALL_MESSAGE_7_CARRY_1,
+];
+
 /// Vector containing all parameter sets where the carry space is strictly greater than one
 pub const BIVARIATE_PBS_COMPLIANT_PARAMETER_SET_VEC: [PBSParameters; 16] = [
     PARAM_MESSAGE_1_CARRY_1,
@@ -391,8 +619,10 @@ pub const PARAM_MESSAGE_1_CARRY_0: PBSParameters = PBSParameters {
     lwe_dimension: LweDimension(678),
     glwe_dimension: GlweDimension(5),
     polynomial_size: PolynomialSize(256),
-    lwe_modular_std_dev: StandardDev(0.000022810107419132102),
-    glwe_modular_std_dev: StandardDev(0.00000000037411618952047216),
+    lwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(0.000022810107419132102)),
+    glwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(
+        0.00000000037411618952047216,
+    )),
     pbs_base_log: DecompositionBaseLog(15),
     pbs_level: DecompositionLevelCount(1),
     ks_level: DecompositionLevelCount(2),
@@ -401,13 +631,16 @@ pub const PARAM_MESSAGE_1_CARRY_0: PBSParameters = PBSParameters {
     carry_modulus: CarryModulus(1),
     ciphertext_modulus: CiphertextModulus::new_native(),
     encryption_key_choice: EncryptionKeyChoice::Big,
+    secret_key_distribution: SecretKeyDistribution::Binary,
 };
 pub const PARAM_MESSAGE_1_CARRY_1: PBSParameters = PBSParameters {
     lwe_dimension: LweDimension(684),
     glwe_dimension: GlweDimension(3),
     polynomial_size: PolynomialSize(512),
-    lwe_modular_std_dev: StandardDev(0.00002043784477291318),
-    glwe_modular_std_dev: StandardDev(0.0000000000034525330484572114),
+    lwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(0.00002043784477291318)),
+    glwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(
+        0.0000000000034525330484572114,
+    )),
     pbs_base_log: DecompositionBaseLog(18),
     pbs_level: DecompositionLevelCount(1),
     ks_level: DecompositionLevelCount(3),
@@ -416,13 +649,14 @@ pub const PARAM_MESSAGE_1_CARRY_1: PBSParameters = PBSParameters {
     carry_modulus: CarryModulus(2),
     ciphertext_modulus: CiphertextModulus::new_native(),
     encryption_key_choice: EncryptionKeyChoice::Big,
+    secret_key_distribution: SecretKeyDistribution::Binary,
 };
 pub const PARAM_MESSAGE_2_CARRY_0: PBSParameters = PBSParameters {
     lwe_dimension: LweDimension(656),
     glwe_dimension: GlweDimension(2),
     polynomial_size: PolynomialSize(512),
-    lwe_modular_std_dev: StandardDev(0.000034119201269311964),
-    glwe_modular_std_dev: StandardDev(0.00000004053919869756513),
+    lwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(0.000034119201269311964)),
+    glwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(0.00000004053919869756513)),
     pbs_base_log: DecompositionBaseLog(8),
     pbs_level: DecompositionLevelCount(2),
     ks_level: DecompositionLevelCount(4),
@@ -431,13 +665,16 @@ pub const PARAM_MESSAGE_2_CARRY_0: PBSParameters = PBSParameters {
     carry_modulus: CarryModulus(1),
     ciphertext_modulus: CiphertextModulus::new_native(),
     encryption_key_choice: EncryptionKeyChoice::Big,
+    secret_key_distribution: SecretKeyDistribution::Binary,
 };
 pub const PARAM_MESSAGE_1_CARRY_2: PBSParameters = PBSParameters {
     lwe_dimension: LweDimension(742),
     glwe_dimension: GlweDimension(2),
     polynomial_size: PolynomialSize(1024),
-    lwe_modular_std_dev: StandardDev(0.000007069849454709433),
-    glwe_modular_std_dev: StandardDev(0.00000000000000029403601535432533),
+    lwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(0.000007069849454709433)),
+    glwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(
+        0.00000000000000029403601535432533,
+    )),
     pbs_base_log: DecompositionBaseLog(23),
     pbs_level: DecompositionLevelCount(1),
     ks_level: DecompositionLevelCount(3),
@@ -446,13 +683,16 @@ pub const PARAM_MESSAGE_1_CARRY_2: PBSParameters = PBSParameters {
     carry_modulus: CarryModulus(4),
     ciphertext_modulus: CiphertextModulus::new_native(),
     encryption_key_choice: EncryptionKeyChoice::Big,
+    secret_key_distribution: SecretKeyDistribution::Binary,
 };
 pub const PARAM_MESSAGE_2_CARRY_1: PBSParameters = PBSParameters {
     lwe_dimension: LweDimension(742),
     glwe_dimension: GlweDimension(2),
     polynomial_size: PolynomialSize(1024),
-    lwe_modular_std_dev: StandardDev(0.000007069849454709433),
-    glwe_modular_std_dev: StandardDev(0.00000000000000029403601535432533),
+    lwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(0.000007069849454709433)),
+    glwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(
+        0.00000000000000029403601535432533,
+    )),
     pbs_base_log: DecompositionBaseLog(23),
     pbs_level: DecompositionLevelCount(1),
     ks_level: DecompositionLevelCount(3),
@@ -461,13 +701,16 @@ pub const PARAM_MESSAGE_2_CARRY_1: PBSParameters = PBSParameters {
     carry_modulus: CarryModulus(2),
     ciphertext_modulus: CiphertextModulus::new_native(),
     encryption_key_choice: EncryptionKeyChoice::Big,
+    secret_key_distribution: SecretKeyDistribution::Binary,
 };
 pub const PARAM_MESSAGE_3_CARRY_0: PBSParameters = PBSParameters {
     lwe_dimension: LweDimension(742),
     glwe_dimension: GlweDimension(2),
     polynomial_size: PolynomialSize(1024),
-    lwe_modular_std_dev: StandardDev(0.000007069849454709433),
-    glwe_modular_std_dev: StandardDev(0.00000000000000029403601535432533),
+    lwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(0.000007069849454709433)),
+    glwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(
+        0.00000000000000029403601535432533,
+    )),
     pbs_base_log: DecompositionBaseLog(23),
     pbs_level: DecompositionLevelCount(1),
     ks_level: DecompositionLevelCount(3),
@@ -476,13 +719,16 @@ pub const PARAM_MESSAGE_3_CARRY_0: PBSParameters = PBSParameters {
     carry_modulus: CarryModulus(1),
     ciphertext_modulus: CiphertextModulus::new_native(),
     encryption_key_choice: EncryptionKeyChoice::Big,
+    secret_key_distribution: SecretKeyDistribution::Binary,
 };
 pub const PARAM_MESSAGE_1_CARRY_3: PBSParameters = PBSParameters {
     lwe_dimension: LweDimension(745),
     glwe_dimension: GlweDimension(1),
     polynomial_size: PolynomialSize(2048),
-    lwe_modular_std_dev: StandardDev(0.000006692125069956277),
-    glwe_modular_std_dev: StandardDev(0.00000000000000029403601535432533),
+    lwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(0.000006692125069956277)),
+    glwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(
+        0.00000000000000029403601535432533,
+    )),
     pbs_base_log: DecompositionBaseLog(23),
     pbs_level: DecompositionLevelCount(1),
     ks_level: DecompositionLevelCount(5),
@@ -491,13 +737,16 @@ pub const PARAM_MESSAGE_1_CARRY_3: PBSParameters = PBSParameters {
     carry_modulus: CarryModulus(8),
     ciphertext_modulus: CiphertextModulus::new_native(),
     encryption_key_choice: EncryptionKeyChoice::Big,
+    secret_key_distribution: SecretKeyDistribution::Binary,
 };
 pub const PARAM_MESSAGE_2_CARRY_2: PBSParameters = PBSParameters {
     lwe_dimension: LweDimension(742),
     glwe_dimension: GlweDimension(1),
     polynomial_size: PolynomialSize(2048),
-    lwe_modular_std_dev: StandardDev(0.000007069849454709433),
-    glwe_modular_std_dev: StandardDev(0.00000000000000029403601535432533),
+    lwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(0.000007069849454709433)),
+    glwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(
+        0.00000000000000029403601535432533,
+    )),
     pbs_base_log: DecompositionBaseLog(23),
     pbs_level: DecompositionLevelCount(1),
     ks_level: DecompositionLevelCount(5),
@@ -506,13 +755,16 @@ pub const PARAM_MESSAGE_2_CARRY_2: PBSParameters = PBSParameters {
     carry_modulus: CarryModulus(4),
     ciphertext_modulus: CiphertextModulus::new_native(),
     encryption_key_choice: EncryptionKeyChoice::Big,
+    secret_key_distribution: SecretKeyDistribution::Binary,
 };
 pub const PARAM_MESSAGE_3_CARRY_1: PBSParameters = PBSParameters {
     lwe_dimension: LweDimension(742),
     glwe_dimension: GlweDimension(1),
     polynomial_size: PolynomialSize(2048),
-    lwe_modular_std_dev: StandardDev(0.000007069849454709433),
-    glwe_modular_std_dev: StandardDev(0.00000000000000029403601535432533),
+    lwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(0.000007069849454709433)),
+    glwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(
+        0.00000000000000029403601535432533,
+    )),
     pbs_base_log: DecompositionBaseLog(23),
     pbs_level: DecompositionLevelCount(1),
     ks_level: DecompositionLevelCount(5),
@@ -521,13 +773,16 @@ pub const PARAM_MESSAGE_3_CARRY_1: PBSParameters = PBSParameters {
     carry_modulus: CarryModulus(2),
     ciphertext_modulus: CiphertextModulus::new_native(),
     encryption_key_choice: EncryptionKeyChoice::Big,
+    secret_key_distribution: SecretKeyDistribution::Binary,
 };
 pub const PARAM_MESSAGE_4_CARRY_0: PBSParameters = PBSParameters {
     lwe_dimension: LweDimension(742),
     glwe_dimension: GlweDimension(1),
     polynomial_size: PolynomialSize(2048),
-    lwe_modular_std_dev: StandardDev(0.000007069849454709433),
-    glwe_modular_std_dev: StandardDev(0.00000000000000029403601535432533),
+    lwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(0.000007069849454709433)),
+    glwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(
+        0.00000000000000029403601535432533,
+    )),
     pbs_base_log: DecompositionBaseLog(23),
     pbs_level: DecompositionLevelCount(1),
     ks_level: DecompositionLevelCount(5),
@@ -536,13 +791,16 @@ pub const PARAM_MESSAGE_4_CARRY_0: PBSParameters = PBSParameters {
     carry_modulus: CarryModulus(1),
     ciphertext_modulus: CiphertextModulus::new_native(),
     encryption_key_choice: EncryptionKeyChoice::Big,
+    secret_key_distribution: SecretKeyDistribution::Binary,
 };
 pub const PARAM_MESSAGE_1_CARRY_4: PBSParameters = PBSParameters {
     lwe_dimension: LweDimension(807),
     glwe_dimension: GlweDimension(1),
     polynomial_size: PolynomialSize(4096),
-    lwe_modular_std_dev: StandardDev(0.0000021515145918907506),
-    glwe_modular_std_dev: StandardDev(0.0000000000000000002168404344971009),
+    lwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(0.0000021515145918907506)),
+    glwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(
+        0.0000000000000000002168404344971009,
+    )),
     pbs_base_log: DecompositionBaseLog(15),
     pbs_level: DecompositionLevelCount(2),
     ks_level: DecompositionLevelCount(5),
@@ -551,13 +809,16 @@ pub const PARAM_MESSAGE_1_CARRY_4: PBSParameters = PBSParameters {
     carry_modulus: CarryModulus(16),
     ciphertext_modulus: CiphertextModulus::new_native(),
     encryption_key_choice: EncryptionKeyChoice::Big,
+    secret_key_distribution: SecretKeyDistribution::Binary,
 };
 pub const PARAM_MESSAGE_2_CARRY_3: PBSParameters = PBSParameters {
     lwe_dimension: LweDimension(856),
     glwe_dimension: GlweDimension(1),
     polynomial_size: PolynomialSize(4096),
-    lwe_modular_std_dev: StandardDev(0.0000008775214009854235),
-    glwe_modular_std_dev: StandardDev(0.0000000000000000002168404344971009),
+    lwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(0.0000008775214009854235)),
+    glwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(
+        0.0000000000000000002168404344971009,
+    )),
     pbs_base_log: DecompositionBaseLog(22),
     pbs_level: DecompositionLevelCount(1),
     ks_level: DecompositionLevelCount(6),
@@ -566,13 +827,16 @@ pub const PARAM_MESSAGE_2_CARRY_3: PBSParameters = PBSParameters {
     carry_modulus: CarryModulus(8),
     ciphertext_modulus: CiphertextModulus::new_native(),
     encryption_key_choice: EncryptionKeyChoice::Big,
+    secret_key_distribution: SecretKeyDistribution::Binary,
 };
 pub const PARAM_MESSAGE_3_CARRY_2: PBSParameters = PBSParameters {
     lwe_dimension: LweDimension(812),
     glwe_dimension: GlweDimension(1),
     polynomial_size: PolynomialSize(4096),
-    lwe_modular_std_dev: StandardDev(0.0000019633637461248447),
-    glwe_modular_std_dev: StandardDev(0.0000000000000000002168404344971009),
+    lwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(0.0000019633637461248447)),
+    glwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(
+        0.0000000000000000002168404344971009,
+    )),
     pbs_base_log: DecompositionBaseLog(22),
     pbs_level: DecompositionLevelCount(1),
     ks_level: DecompositionLevelCount(5),
@@ -581,13 +845,16 @@ pub const PARAM_MESSAGE_3_CARRY_2: PBSParameters = PBSParameters {
     carry_modulus: CarryModulus(4),
     ciphertext_modulus: CiphertextModulus::new_native(),
     encryption_key_choice: EncryptionKeyChoice::Big,
+    secret_key_distribution: SecretKeyDistribution::Binary,
 };
 pub const PARAM_MESSAGE_4_CARRY_1: PBSParameters = PBSParameters {
     lwe_dimension: LweDimension(808),
     glwe_dimension: GlweDimension(1),
     polynomial_size: PolynomialSize(4096),
-    lwe_modular_std_dev: StandardDev(0.0000021124945159091033),
-    glwe_modular_std_dev: StandardDev(0.0000000000000000002168404344971009),
+    lwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(0.0000021124945159091033)),
+    glwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(
+        0.0000000000000000002168404344971009,
+    )),
     pbs_base_log: DecompositionBaseLog(22),
     pbs_level: DecompositionLevelCount(1),
     ks_level: DecompositionLevelCount(5),
@@ -596,13 +863,16 @@ pub const PARAM_MESSAGE_4_CARRY_1: PBSParameters = PBSParameters {
     carry_modulus: CarryModulus(2),
     ciphertext_modulus: CiphertextModulus::new_native(),
     encryption_key_choice: EncryptionKeyChoice::Big,
+    secret_key_distribution: SecretKeyDistribution::Binary,
 };
 pub const PARAM_MESSAGE_5_CARRY_0: PBSParameters = PBSParameters {
     lwe_dimension: LweDimension(807),
     glwe_dimension: GlweDimension(1),
     polynomial_size: PolynomialSize(4096),
-    lwe_modular_std_dev: StandardDev(0.0000021515145918907506),
-    glwe_modular_std_dev: StandardDev(0.0000000000000000002168404344971009),
+    lwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(0.0000021515145918907506)),
+    glwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(
+        0.0000000000000000002168404344971009,
+    )),
     pbs_base_log: DecompositionBaseLog(22),
     pbs_level: DecompositionLevelCount(1),
     ks_level: DecompositionLevelCount(5),
@@ -611,13 +881,16 @@ pub const PARAM_MESSAGE_5_CARRY_0: PBSParameters = PBSParameters {
     carry_modulus: CarryModulus(1),
     ciphertext_modulus: CiphertextModulus::new_native(),
     encryption_key_choice: EncryptionKeyChoice::Big,
+    secret_key_distribution: SecretKeyDistribution::Binary,
 };
 pub const PARAM_MESSAGE_1_CARRY_5: PBSParameters = PBSParameters {
     lwe_dimension: LweDimension(864),
     glwe_dimension: GlweDimension(1),
     polynomial_size: PolynomialSize(8192),
-    lwe_modular_std_dev: StandardDev(0.000000757998020150446),
-    glwe_modular_std_dev: StandardDev(0.0000000000000000002168404344971009),
+    lwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(0.000000757998020150446)),
+    glwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(
+        0.0000000000000000002168404344971009,
+    )),
     pbs_base_log: DecompositionBaseLog(15),
     pbs_level: DecompositionLevelCount(2),
     ks_level: DecompositionLevelCount(6),
@@ -626,13 +899,16 @@ pub const PARAM_MESSAGE_1_CARRY_5: PBSParameters = PBSParameters {
     carry_modulus: CarryModulus(32),
     ciphertext_modulus: CiphertextModulus::new_native(),
     encryption_key_choice: EncryptionKeyChoice::Big,
+    secret_key_distribution: SecretKeyDistribution::Binary,
 };
 pub const PARAM_MESSAGE_2_CARRY_4: PBSParameters = PBSParameters {
     lwe_dimension: LweDimension(864),
     glwe_dimension: GlweDimension(1),
     polynomial_size: PolynomialSize(8192),
-    lwe_modular_std_dev: StandardDev(0.000000757998020150446),
-    glwe_modular_std_dev: StandardDev(0.0000000000000000002168404344971009),
+    lwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(0.000000757998020150446)),
+    glwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(
+        0.0000000000000000002168404344971009,
+    )),
     pbs_base_log: DecompositionBaseLog(15),
     pbs_level: DecompositionLevelCount(2),
     ks_level: DecompositionLevelCount(6),
@@ -641,13 +917,16 @@ pub const PARAM_MESSAGE_2_CARRY_4: PBSParameters = PBSParameters {
     carry_modulus: CarryModulus(16),
     ciphertext_modulus: CiphertextModulus::new_native(),
     encryption_key_choice: EncryptionKeyChoice::Big,
+    secret_key_distribution: SecretKeyDistribution::Binary,
 };
 pub const PARAM_MESSAGE_3_CARRY_3: PBSParameters = PBSParameters {
     lwe_dimension: LweDimension(864),
     glwe_dimension: GlweDimension(1),
     polynomial_size: PolynomialSize(8192),
-    lwe_modular_std_dev: StandardDev(0.000000757998020150446),
-    glwe_modular_std_dev: StandardDev(0.0000000000000000002168404344971009),
+    lwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(0.000000757998020150446)),
+    glwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(
+        0.0000000000000000002168404344971009,
+    )),
     pbs_base_log: DecompositionBaseLog(15),
     pbs_level: DecompositionLevelCount(2),
     ks_level: DecompositionLevelCount(6),
@@ -656,13 +935,16 @@ pub const PARAM_MESSAGE_3_CARRY_3: PBSParameters = PBSParameters {
     carry_modulus: CarryModulus(8),
     ciphertext_modulus: CiphertextModulus::new_native(),
     encryption_key_choice: EncryptionKeyChoice::Big,
+    secret_key_distribution: SecretKeyDistribution::Binary,
 };
 pub const PARAM_MESSAGE_4_CARRY_2: PBSParameters = PBSParameters {
     lwe_dimension: LweDimension(864),
     glwe_dimension: GlweDimension(1),
     polynomial_size: PolynomialSize(8192),
-    lwe_modular_std_dev: StandardDev(0.000000757998020150446),
-    glwe_modular_std_dev: StandardDev(0.0000000000000000002168404344971009),
+    lwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(0.000000757998020150446)),
+    glwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(
+        0.0000000000000000002168404344971009,
+    )),
     pbs_base_log: DecompositionBaseLog(15),
     pbs_level: DecompositionLevelCount(2),
     ks_level: DecompositionLevelCount(6),
@@ -671,13 +953,16 @@ pub const PARAM_MESSAGE_4_CARRY_2: PBSParameters = PBSParameters {
     carry_modulus: CarryModulus(4),
     ciphertext_modulus: CiphertextModulus::new_native(),
     encryption_key_choice: EncryptionKeyChoice::Big,
+    secret_key_distribution: SecretKeyDistribution::Binary,
 };
 pub const PARAM_MESSAGE_5_CARRY_1: PBSParameters = PBSParameters {
     lwe_dimension: LweDimension(875),
     glwe_dimension: GlweDimension(1),
     polynomial_size: PolynomialSize(8192),
-    lwe_modular_std_dev: StandardDev(0.0000006197725091905067),
-    glwe_modular_std_dev: StandardDev(0.0000000000000000002168404344971009),
+    lwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(0.0000006197725091905067)),
+    glwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(
+        0.0000000000000000002168404344971009,
+    )),
     pbs_base_log: DecompositionBaseLog(22),
     pbs_level: DecompositionLevelCount(1),
     ks_level: DecompositionLevelCount(6),
@@ -686,13 +971,16 @@ pub const PARAM_MESSAGE_5_CARRY_1: PBSParameters = PBSParameters {
     carry_modulus: CarryModulus(2),
     ciphertext_modulus: CiphertextModulus::new_native(),
     encryption_key_choice: EncryptionKeyChoice::Big,
+    secret_key_distribution: SecretKeyDistribution::Binary,
 };
 pub const PARAM_MESSAGE_6_CARRY_0: PBSParameters = PBSParameters {
     lwe_dimension: LweDimension(915),
     glwe_dimension: GlweDimension(1),
     polynomial_size: PolynomialSize(8192),
-    lwe_modular_std_dev: StandardDev(0.00000029804653749339636),
-    glwe_modular_std_dev: StandardDev(0.0000000000000000002168404344971009),
+    lwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(0.00000029804653749339636)),
+    glwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(
+        0.0000000000000000002168404344971009,
+    )),
     pbs_base_log: DecompositionBaseLog(22),
     pbs_level: DecompositionLevelCount(1),
     ks_level: DecompositionLevelCount(4),
@@ -701,13 +989,16 @@ pub const PARAM_MESSAGE_6_CARRY_0: PBSParameters = PBSParameters {
     carry_modulus: CarryModulus(1),
     ciphertext_modulus: CiphertextModulus::new_native(),
     encryption_key_choice: EncryptionKeyChoice::Big,
+    secret_key_distribution: SecretKeyDistribution::Binary,
 };
 pub const PARAM_MESSAGE_1_CARRY_6: PBSParameters = PBSParameters {
     lwe_dimension: LweDimension(930),
     glwe_dimension: GlweDimension(1),
     polynomial_size: PolynomialSize(16384),
-    lwe_modular_std_dev: StandardDev(0.00000022649232786295453),
-    glwe_modular_std_dev: StandardDev(0.0000000000000000002168404344971009),
+    lwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(0.00000022649232786295453)),
+    glwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(
+        0.0000000000000000002168404344971009,
+    )),
     pbs_base_log: DecompositionBaseLog(11),
     pbs_level: DecompositionLevelCount(3),
     ks_level: DecompositionLevelCount(6),
@@ -716,13 +1007,16 @@ pub const PARAM_MESSAGE_1_CARRY_6: PBSParameters = PBSParameters {
     carry_modulus: CarryModulus(64),
     ciphertext_modulus: CiphertextModulus::new_native(),
     encryption_key_choice: EncryptionKeyChoice::Big,
+    secret_key_distribution: SecretKeyDistribution::Binary,
 };
 pub const PARAM_MESSAGE_2_CARRY_5: PBSParameters = PBSParameters {
     lwe_dimension: LweDimension(934),
     glwe_dimension: GlweDimension(1),
     polynomial_size: PolynomialSize(16384),
-    lwe_modular_std_dev: StandardDev(0.00000021050318566634375),
-    glwe_modular_std_dev: StandardDev(0.0000000000000000002168404344971009),
+    lwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(0.00000021050318566634375)),
+    glwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(
+        0.0000000000000000002168404344971009,
+    )),
     pbs_base_log: DecompositionBaseLog(15),
     pbs_level: DecompositionLevelCount(2),
     ks_level: DecompositionLevelCount(6),
@@ -731,13 +1025,16 @@ pub const PARAM_MESSAGE_2_CARRY_5: PBSParameters = PBSParameters {
     carry_modulus: CarryModulus(32),
     ciphertext_modulus: CiphertextModulus::new_native(),
     encryption_key_choice: EncryptionKeyChoice::Big,
+    secret_key_distribution: SecretKeyDistribution::Binary,
 };
 pub const PARAM_MESSAGE_3_CARRY_4: PBSParameters = PBSParameters {
     lwe_dimension: LweDimension(930),
     glwe_dimension: GlweDimension(1),
     polynomial_size: PolynomialSize(16384),
-    lwe_modular_std_dev: StandardDev(0.00000022649232786295453),
-    glwe_modular_std_dev: StandardDev(0.0000000000000000002168404344971009),
+    lwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(0.00000022649232786295453)),
+    glwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(
+        0.0000000000000000002168404344971009,
+    )),
     pbs_base_log: DecompositionBaseLog(15),
     pbs_level: DecompositionLevelCount(2),
     ks_level: DecompositionLevelCount(6),
@@ -746,13 +1043,16 @@ pub const PARAM_MESSAGE_3_CARRY_4: PBSParameters = PBSParameters {
     carry_modulus: CarryModulus(16),
     ciphertext_modulus: CiphertextModulus::new_native(),
     encryption_key_choice: EncryptionKeyChoice::Big,
+    secret_key_distribution: SecretKeyDistribution::Binary,
 };
 pub const PARAM_MESSAGE_4_CARRY_3: PBSParameters = PBSParameters {
     lwe_dimension: LweDimension(930),
     glwe_dimension: GlweDimension(1),
     polynomial_size: PolynomialSize(16384),
-    lwe_modular_std_dev: StandardDev(0.00000022649232786295453),
-    glwe_modular_std_dev: StandardDev(0.0000000000000000002168404344971009),
+    lwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(0.00000022649232786295453)),
+    glwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(
+        0.0000000000000000002168404344971009,
+    )),
     pbs_base_log: DecompositionBaseLog(15),
     pbs_level: DecompositionLevelCount(2),
     ks_level: DecompositionLevelCount(6),
@@ -761,13 +1061,16 @@ pub const PARAM_MESSAGE_4_CARRY_3: PBSParameters = PBSParameters {
     carry_modulus: CarryModulus(8),
     ciphertext_modulus: CiphertextModulus::new_native(),
     encryption_key_choice: EncryptionKeyChoice::Big,
+    secret_key_distribution: SecretKeyDistribution::Binary,
 };
 pub const PARAM_MESSAGE_5_CARRY_2: PBSParameters = PBSParameters {
     lwe_dimension: LweDimension(930),
     glwe_dimension: GlweDimension(1),
     polynomial_size: PolynomialSize(16384),
-    lwe_modular_std_dev: StandardDev(0.00000022649232786295453),
-    glwe_modular_std_dev: StandardDev(0.0000000000000000002168404344971009),
+    lwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(0.00000022649232786295453)),
+    glwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(
+        0.0000000000000000002168404344971009,
+    )),
     pbs_base_log: DecompositionBaseLog(15),
     pbs_level: DecompositionLevelCount(2),
     ks_level: DecompositionLevelCount(6),
@@ -776,13 +1079,16 @@ pub const PARAM_MESSAGE_5_CARRY_2: PBSParameters = PBSParameters {
     carry_modulus: CarryModulus(4),
     ciphertext_modulus: CiphertextModulus::new_native(),
     encryption_key_choice: EncryptionKeyChoice::Big,
+    secret_key_distribution: SecretKeyDistribution::Binary,
 };
 pub const PARAM_MESSAGE_6_CARRY_1: PBSParameters = PBSParameters {
     lwe_dimension: LweDimension(930),
     glwe_dimension: GlweDimension(1),
     polynomial_size: PolynomialSize(16384),
-    lwe_modular_std_dev: StandardDev(0.00000022649232786295453),
-    glwe_modular_std_dev: StandardDev(0.0000000000000000002168404344971009),
+    lwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(0.00000022649232786295453)),
+    glwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(
+        0.0000000000000000002168404344971009,
+    )),
     pbs_base_log: DecompositionBaseLog(15),
     pbs_level: DecompositionLevelCount(2),
     ks_level: DecompositionLevelCount(6),
@@ -791,13 +1097,16 @@ pub const PARAM_MESSAGE_6_CARRY_1: PBSParameters = PBSParameters {
     carry_modulus: CarryModulus(2),
     ciphertext_modulus: CiphertextModulus::new_native(),
     encryption_key_choice: EncryptionKeyChoice::Big,
+    secret_key_distribution: SecretKeyDistribution::Binary,
 };
 pub const PARAM_MESSAGE_7_CARRY_0: PBSParameters = PBSParameters {
     lwe_dimension: LweDimension(930),
     glwe_dimension: GlweDimension(1),
     polynomial_size: PolynomialSize(16384),
-    lwe_modular_std_dev: StandardDev(0.00000022649232786295453),
-    glwe_modular_std_dev: StandardDev(0.0000000000000000002168404344971009),
+    lwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(0.00000022649232786295453)),
+    glwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(
+        0.0000000000000000002168404344971009,
+    )),
     pbs_base_log: DecompositionBaseLog(15),
     pbs_level: DecompositionLevelCount(2),
     ks_level: DecompositionLevelCount(6),
@@ -806,13 +1115,16 @@ pub const PARAM_MESSAGE_7_CARRY_0: PBSParameters = PBSParameters {
     carry_modulus: CarryModulus(1),
     ciphertext_modulus: CiphertextModulus::new_native(),
     encryption_key_choice: EncryptionKeyChoice::Big,
+    secret_key_distribution: SecretKeyDistribution::Binary,
 };
 pub const PARAM_MESSAGE_1_CARRY_7: PBSParameters = PBSParameters {
     lwe_dimension: LweDimension(1004),
     glwe_dimension: GlweDimension(1),
     polynomial_size: PolynomialSize(32768),
-    lwe_modular_std_dev: StandardDev(0.00000005845871624688967),
-    glwe_modular_std_dev: StandardDev(0.0000000000000000002168404344971009),
+    lwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(0.00000005845871624688967)),
+    glwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(
+        0.0000000000000000002168404344971009,
+    )),
     pbs_base_log: DecompositionBaseLog(11),
     pbs_level: DecompositionLevelCount(3),
     ks_level: DecompositionLevelCount(7),
@@ -821,13 +1133,16 @@ pub const PARAM_MESSAGE_1_CARRY_7: PBSParameters = PBSParameters {
     carry_modulus: CarryModulus(128),
     ciphertext_modulus: CiphertextModulus::new_native(),
     encryption_key_choice: EncryptionKeyChoice::Big,
+    secret_key_distribution: SecretKeyDistribution::Binary,
 };
 pub const PARAM_MESSAGE_2_CARRY_6: PBSParameters = PBSParameters {
     lwe_dimension: LweDimension(987),
     glwe_dimension: GlweDimension(1),
     polynomial_size: PolynomialSize(32768),
-    lwe_modular_std_dev: StandardDev(0.00000007979529246348835),
-    glwe_modular_std_dev: StandardDev(0.0000000000000000002168404344971009),
+    lwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(0.00000007979529246348835)),
+    glwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(
+        0.0000000000000000002168404344971009,
+    )),
     pbs_base_log: DecompositionBaseLog(11),
     pbs_level: DecompositionLevelCount(3),
     ks_level: DecompositionLevelCount(7),
@@ -836,13 +1151,16 @@ pub const PARAM_MESSAGE_2_CARRY_6: PBSParameters = PBSParameters {
     carry_modulus: CarryModulus(64),
     ciphertext_modulus: CiphertextModulus::new_native(),
     encryption_key_choice: EncryptionKeyChoice::Big,
+    secret_key_distribution: SecretKeyDistribution::Binary,
 };
 pub const PARAM_MESSAGE_3_CARRY_5: PBSParameters = PBSParameters {
     lwe_dimension: LweDimension(985),
     glwe_dimension: GlweDimension(1),
     polynomial_size: PolynomialSize(32768),
-    lwe_modular_std_dev: StandardDev(0.00000008277032914509569),
-    glwe_modular_std_dev: StandardDev(0.0000000000000000002168404344971009),
+    lwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(0.00000008277032914509569)),
+    glwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(
+        0.0000000000000000002168404344971009,
+    )),
     pbs_base_log: DecompositionBaseLog(11),
     pbs_level: DecompositionLevelCount(3),
     ks_level: DecompositionLevelCount(7),
@@ -851,13 +1169,16 @@ pub const PARAM_MESSAGE_3_CARRY_5: PBSParameters = PBSParameters {
     carry_modulus: CarryModulus(32),
     ciphertext_modulus: CiphertextModulus::new_native(),
     encryption_key_choice: EncryptionKeyChoice::Big,
+    secret_key_distribution: SecretKeyDistribution::Binary,
 };
 pub const PARAM_MESSAGE_4_CARRY_4: PBSParameters = PBSParameters {
     lwe_dimension: LweDimension(996),
     glwe_dimension: GlweDimension(1),
     polynomial_size: PolynomialSize(32768),
-    lwe_modular_std_dev: StandardDev(0.00000006767666038309478),
-    glwe_modular_std_dev: StandardDev(0.0000000000000000002168404344971009),
+    lwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(0.00000006767666038309478)),
+    glwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(
+        0.0000000000000000002168404344971009,
+    )),
     pbs_base_log: DecompositionBaseLog(15),
     pbs_level: DecompositionLevelCount(2),
     ks_level: DecompositionLevelCount(7),
@@ -866,13 +1187,16 @@ pub const PARAM_MESSAGE_4_CARRY_4: PBSParameters = PBSParameters {
     carry_modulus: CarryModulus(16),
     ciphertext_modulus: CiphertextModulus::new_native(),
     encryption_key_choice: EncryptionKeyChoice::Big,
+    secret_key_distribution: SecretKeyDistribution::Binary,
 };
 pub const PARAM_MESSAGE_5_CARRY_3: PBSParameters = PBSParameters {
     lwe_dimension: LweDimension(1020),
     glwe_dimension: GlweDimension(1),
     polynomial_size: PolynomialSize(32768),
-    lwe_modular_std_dev: StandardDev(0.000000043618425315728666),
-    glwe_modular_std_dev: StandardDev(0.0000000000000000002168404344971009),
+    lwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(0.000000043618425315728666)),
+    glwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(
+        0.0000000000000000002168404344971009,
+    )),
     pbs_base_log: DecompositionBaseLog(15),
     pbs_level: DecompositionLevelCount(2),
     ks_level: DecompositionLevelCount(5),
@@ -881,13 +1205,16 @@ pub const PARAM_MESSAGE_5_CARRY_3: PBSParameters = PBSParameters {
     carry_modulus: CarryModulus(8),
     ciphertext_modulus: CiphertextModulus::new_native(),
     encryption_key_choice: EncryptionKeyChoice::Big,
+    secret_key_distribution: SecretKeyDistribution::Binary,
 };
 pub const PARAM_MESSAGE_6_CARRY_2: PBSParameters = PBSParameters {
     lwe_dimension: LweDimension(1018),
     glwe_dimension: GlweDimension(1),
     polynomial_size: PolynomialSize(32768),
-    lwe_modular_std_dev: StandardDev(0.000000045244666805696514),
-    glwe_modular_std_dev: StandardDev(0.0000000000000000002168404344971009),
+    lwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(0.000000045244666805696514)),
+    glwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(
+        0.0000000000000000002168404344971009,
+    )),
     pbs_base_log: DecompositionBaseLog(15),
     pbs_level: DecompositionLevelCount(2),
     ks_level: DecompositionLevelCount(5),
@@ -896,13 +1223,16 @@ pub const PARAM_MESSAGE_6_CARRY_2: PBSParameters = PBSParameters {
     carry_modulus: CarryModulus(4),
     ciphertext_modulus: CiphertextModulus::new_native(),
     encryption_key_choice: EncryptionKeyChoice::Big,
+    secret_key_distribution: SecretKeyDistribution::Binary,
 };
 pub const PARAM_MESSAGE_7_CARRY_1: PBSParameters = PBSParameters {
     lwe_dimension: LweDimension(1017),
     glwe_dimension: GlweDimension(1),
     polynomial_size: PolynomialSize(32768),
-    lwe_modular_std_dev: StandardDev(0.0000000460803851108693),
-    glwe_modular_std_dev: StandardDev(0.0000000000000000002168404344971009),
+    lwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(0.0000000460803851108693)),
+    glwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(
+        0.0000000000000000002168404344971009,
+    )),
     pbs_base_log: DecompositionBaseLog(15),
     pbs_level: DecompositionLevelCount(2),
     ks_level: DecompositionLevelCount(5),
@@ -911,13 +1241,16 @@ pub const PARAM_MESSAGE_7_CARRY_1: PBSParameters = PBSParameters {
     carry_modulus: CarryModulus(2),
     ciphertext_modulus: CiphertextModulus::new_native(),
     encryption_key_choice: EncryptionKeyChoice::Big,
+    secret_key_distribution: SecretKeyDistribution::Binary,
 };
 pub const PARAM_MESSAGE_8_CARRY_0: PBSParameters = PBSParameters {
     lwe_dimension: LweDimension(1017),
     glwe_dimension: GlweDimension(1),
     polynomial_size: PolynomialSize(32768),
-    lwe_modular_std_dev: StandardDev(0.0000000460803851108693),
-    glwe_modular_std_dev: StandardDev(0.0000000000000000002168404344971009),
+    lwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(0.0000000460803851108693)),
+    glwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(
+        0.0000000000000000002168404344971009,
+    )),
     pbs_base_log: DecompositionBaseLog(15),
     pbs_level: DecompositionLevelCount(2),
     ks_level: DecompositionLevelCount(5),
@@ -926,14 +1259,25 @@ pub const PARAM_MESSAGE_8_CARRY_0: PBSParameters = PBSParameters {
     carry_modulus: CarryModulus(1),
     ciphertext_modulus: CiphertextModulus::new_native(),
     encryption_key_choice: EncryptionKeyChoice::Big,
+    secret_key_distribution: SecretKeyDistribution::Binary,
 };
 
+/// Nomenclature: PARAM_SMALL_MESSAGE_X_CARRY_Y: parameter sets for the
+/// [`EncryptionKeyChoice::Small`] key choice, i.e. the PBS is computed before the keyswitch and
+/// ciphertexts are encrypted under the small LWE key, with the same message and carry moduli as
+/// their PARAM_MESSAGE_X_CARRY_Y counterparts.
+///
+/// All parameter sets guarantee 128-bits of security and an error probability smaller than
+/// 2^{-40} for a PBS. When no set was optimized for a precision of X + Y bits, the sets of
+/// precision X + Y + 1 are used, they have the same security and a smaller error probability.
 pub const PARAM_SMALL_MESSAGE_1_CARRY_1: PBSParameters = PBSParameters {
     lwe_dimension: LweDimension(783),
     glwe_dimension: GlweDimension(3),
     polynomial_size: PolynomialSize(512),
-    lwe_modular_std_dev: StandardDev(0.0000033382067621812462),
-    glwe_modular_std_dev: StandardDev(0.0000000000034525330484572114),
+    lwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(0.0000033382067621812462)),
+    glwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(
+        0.0000000000034525330484572114,
+    )),
     pbs_base_log: DecompositionBaseLog(18),
     pbs_level: DecompositionLevelCount(1),
     ks_level: DecompositionLevelCount(3),
@@ -942,14 +1286,74 @@ pub const PARAM_SMALL_MESSAGE_1_CARRY_1: PBSParameters = PBSParameters {
     carry_modulus: CarryModulus(2),
     ciphertext_modulus: CiphertextModulus::new_native(),
     encryption_key_choice: EncryptionKeyChoice::Small,
+    secret_key_distribution: SecretKeyDistribution::Binary,
+};
+
+pub const PARAM_SMALL_MESSAGE_1_CARRY_2: PBSParameters = PBSParameters {
+    lwe_dimension: LweDimension(870),
+    glwe_dimension: GlweDimension(1),
+    polynomial_size: PolynomialSize(2048),
+    lwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(0.0000006791658447437413)),
+    glwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(
+        0.00000000000000029403601535432533,
+    )),
+    pbs_base_log: DecompositionBaseLog(23),
+    pbs_level: DecompositionLevelCount(1),
+    ks_level: DecompositionLevelCount(4),
+    ks_base_log: DecompositionBaseLog(4),
+    message_modulus: MessageModulus(2),
+    carry_modulus: CarryModulus(4),
+    ciphertext_modulus: CiphertextModulus::new_native(),
+    encryption_key_choice: EncryptionKeyChoice::Small,
+    secret_key_distribution: SecretKeyDistribution::Binary,
+};
+
+pub const PARAM_SMALL_MESSAGE_2_CARRY_1: PBSParameters = PBSParameters {
+    lwe_dimension: LweDimension(870),
+    glwe_dimension: GlweDimension(1),
+    polynomial_size: PolynomialSize(2048),
+    lwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(0.0000006791658447437413)),
+    glwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(
+        0.00000000000000029403601535432533,
+    )),
+    pbs_base_log: DecompositionBaseLog(23),
+    pbs_level: DecompositionLevelCount(1),
+    ks_level: DecompositionLevelCount(4),
+    ks_base_log: DecompositionBaseLog(4),
+    message_modulus: MessageModulus(4),
+    carry_modulus: CarryModulus(2),
+    ciphertext_modulus: CiphertextModulus::new_native(),
+    encryption_key_choice: EncryptionKeyChoice::Small,
+    secret_key_distribution: SecretKeyDistribution::Binary,
+};
+
+pub const PARAM_SMALL_MESSAGE_1_CARRY_3: PBSParameters = PBSParameters {
+    lwe_dimension: LweDimension(870),
+    glwe_dimension: GlweDimension(1),
+    polynomial_size: PolynomialSize(2048),
+    lwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(0.0000006791658447437413)),
+    glwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(
+        0.00000000000000029403601535432533,
+    )),
+    pbs_base_log: DecompositionBaseLog(23),
+    pbs_level: DecompositionLevelCount(1),
+    ks_level: DecompositionLevelCount(4),
+    ks_base_log: DecompositionBaseLog(4),
+    message_modulus: MessageModulus(2),
+    carry_modulus: CarryModulus(8),
+    ciphertext_modulus: CiphertextModulus::new_native(),
+    encryption_key_choice: EncryptionKeyChoice::Small,
+    secret_key_distribution: SecretKeyDistribution::Binary,
 };
 
 pub const PARAM_SMALL_MESSAGE_2_CARRY_2: PBSParameters = PBSParameters {
     lwe_dimension: LweDimension(870),
     glwe_dimension: GlweDimension(1),
     polynomial_size: PolynomialSize(2048),
-    lwe_modular_std_dev: StandardDev(0.0000006791658447437413),
-    glwe_modular_std_dev: StandardDev(0.00000000000000029403601535432533),
+    lwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(0.0000006791658447437413)),
+    glwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(
+        0.00000000000000029403601535432533,
+    )),
     pbs_base_log: DecompositionBaseLog(23),
     pbs_level: DecompositionLevelCount(1),
     ks_level: DecompositionLevelCount(4),
@@ -958,30 +1362,378 @@ pub const PARAM_SMALL_MESSAGE_2_CARRY_2: PBSParameters = PBSParameters {
     carry_modulus: CarryModulus(4),
     ciphertext_modulus: CiphertextModulus::new_native(),
     encryption_key_choice: EncryptionKeyChoice::Small,
+    secret_key_distribution: SecretKeyDistribution::Binary,
+};
+
+pub const PARAM_SMALL_MESSAGE_3_CARRY_1: PBSParameters = PBSParameters {
+    lwe_dimension: LweDimension(870),
+    glwe_dimension: GlweDimension(1),
+    polynomial_size: PolynomialSize(2048),
+    lwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(0.0000006791658447437413)),
+    glwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(
+        0.00000000000000029403601535432533,
+    )),
+    pbs_base_log: DecompositionBaseLog(23),
+    pbs_level: DecompositionLevelCount(1),
+    ks_level: DecompositionLevelCount(4),
+    ks_base_log: DecompositionBaseLog(4),
+    message_modulus: MessageModulus(8),
+    carry_modulus: CarryModulus(2),
+    ciphertext_modulus: CiphertextModulus::new_native(),
+    encryption_key_choice: EncryptionKeyChoice::Small,
+    secret_key_distribution: SecretKeyDistribution::Binary,
+};
+
+pub const PARAM_SMALL_MESSAGE_1_CARRY_4: PBSParameters = PBSParameters {
+    lwe_dimension: LweDimension(1025),
+    glwe_dimension: GlweDimension(1),
+    polynomial_size: PolynomialSize(8192),
+    lwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(0.00000003980397588319241)),
+    glwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(
+        0.0000000000000000002168404344971009,
+    )),
+    pbs_base_log: DecompositionBaseLog(15),
+    pbs_level: DecompositionLevelCount(2),
+    ks_level: DecompositionLevelCount(5),
+    ks_base_log: DecompositionBaseLog(4),
+    message_modulus: MessageModulus(2),
+    carry_modulus: CarryModulus(16),
+    ciphertext_modulus: CiphertextModulus::new_native(),
+    encryption_key_choice: EncryptionKeyChoice::Small,
+    secret_key_distribution: SecretKeyDistribution::Binary,
+};
+
+pub const PARAM_SMALL_MESSAGE_2_CARRY_3: PBSParameters = PBSParameters {
+    lwe_dimension: LweDimension(1025),
+    glwe_dimension: GlweDimension(1),
+    polynomial_size: PolynomialSize(8192),
+    lwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(0.00000003980397588319241)),
+    glwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(
+        0.0000000000000000002168404344971009,
+    )),
+    pbs_base_log: DecompositionBaseLog(15),
+    pbs_level: DecompositionLevelCount(2),
+    ks_level: DecompositionLevelCount(5),
+    ks_base_log: DecompositionBaseLog(4),
+    message_modulus: MessageModulus(4),
+    carry_modulus: CarryModulus(8),
+    ciphertext_modulus: CiphertextModulus::new_native(),
+    encryption_key_choice: EncryptionKeyChoice::Small,
+    secret_key_distribution: SecretKeyDistribution::Binary,
+};
+
+pub const PARAM_SMALL_MESSAGE_3_CARRY_2: PBSParameters = PBSParameters {
+    lwe_dimension: LweDimension(1025),
+    glwe_dimension: GlweDimension(1),
+    polynomial_size: PolynomialSize(8192),
+    lwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(0.00000003980397588319241)),
+    glwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(
+        0.0000000000000000002168404344971009,
+    )),
+    pbs_base_log: DecompositionBaseLog(15),
+    pbs_level: DecompositionLevelCount(2),
+    ks_level: DecompositionLevelCount(5),
+    ks_base_log: DecompositionBaseLog(4),
+    message_modulus: MessageModulus(8),
+    carry_modulus: CarryModulus(4),
+    ciphertext_modulus: CiphertextModulus::new_native(),
+    encryption_key_choice: EncryptionKeyChoice::Small,
+    secret_key_distribution: SecretKeyDistribution::Binary,
+};
+
+pub const PARAM_SMALL_MESSAGE_4_CARRY_1: PBSParameters = PBSParameters {
+    lwe_dimension: LweDimension(1025),
+    glwe_dimension: GlweDimension(1),
+    polynomial_size: PolynomialSize(8192),
+    lwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(0.00000003980397588319241)),
+    glwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(
+        0.0000000000000000002168404344971009,
+    )),
+    pbs_base_log: DecompositionBaseLog(15),
+    pbs_level: DecompositionLevelCount(2),
+    ks_level: DecompositionLevelCount(5),
+    ks_base_log: DecompositionBaseLog(4),
+    message_modulus: MessageModulus(16),
+    carry_modulus: CarryModulus(2),
+    ciphertext_modulus: CiphertextModulus::new_native(),
+    encryption_key_choice: EncryptionKeyChoice::Small,
+    secret_key_distribution: SecretKeyDistribution::Binary,
+};
+
+pub const PARAM_SMALL_MESSAGE_1_CARRY_5: PBSParameters = PBSParameters {
+    lwe_dimension: LweDimension(1025),
+    glwe_dimension: GlweDimension(1),
+    polynomial_size: PolynomialSize(8192),
+    lwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(0.00000003980397588319241)),
+    glwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(
+        0.0000000000000000002168404344971009,
+    )),
+    pbs_base_log: DecompositionBaseLog(15),
+    pbs_level: DecompositionLevelCount(2),
+    ks_level: DecompositionLevelCount(5),
+    ks_base_log: DecompositionBaseLog(4),
+    message_modulus: MessageModulus(2),
+    carry_modulus: CarryModulus(32),
+    ciphertext_modulus: CiphertextModulus::new_native(),
+    encryption_key_choice: EncryptionKeyChoice::Small,
+    secret_key_distribution: SecretKeyDistribution::Binary,
+};
+
+pub const PARAM_SMALL_MESSAGE_2_CARRY_4: PBSParameters = PBSParameters {
+    lwe_dimension: LweDimension(1025),
+    glwe_dimension: GlweDimension(1),
+    polynomial_size: PolynomialSize(8192),
+    lwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(0.00000003980397588319241)),
+    glwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(
+        0.0000000000000000002168404344971009,
+    )),
+    pbs_base_log: DecompositionBaseLog(15),
+    pbs_level: DecompositionLevelCount(2),
+    ks_level: DecompositionLevelCount(5),
+    ks_base_log: DecompositionBaseLog(4),
+    message_modulus: MessageModulus(4),
+    carry_modulus: CarryModulus(16),
+    ciphertext_modulus: CiphertextModulus::new_native(),
+    encryption_key_choice: EncryptionKeyChoice::Small,
+    secret_key_distribution: SecretKeyDistribution::Binary,
 };
 
 pub const PARAM_SMALL_MESSAGE_3_CARRY_3: PBSParameters = PBSParameters {
     lwe_dimension: LweDimension(1025),
     glwe_dimension: GlweDimension(1),
     polynomial_size: PolynomialSize(8192),
-    lwe_modular_std_dev: StandardDev(0.00000003980397588319241),
-    glwe_modular_std_dev: StandardDev(0.0000000000000000002168404344971009),
+    lwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(0.00000003980397588319241)),
+    glwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(
+        0.0000000000000000002168404344971009,
+    )),
+    pbs_base_log: DecompositionBaseLog(15),
+    pbs_level: DecompositionLevelCount(2),
+    ks_level: DecompositionLevelCount(5),
+    ks_base_log: DecompositionBaseLog(4),
+    message_modulus: MessageModulus(8),
+    carry_modulus: CarryModulus(8),
+    ciphertext_modulus: CiphertextModulus::new_native(),
+    encryption_key_choice: EncryptionKeyChoice::Small,
+    secret_key_distribution: SecretKeyDistribution::Binary,
+};
+
+pub const PARAM_SMALL_MESSAGE_4_CARRY_2: PBSParameters = PBSParameters {
+    lwe_dimension: LweDimension(1025),
+    glwe_dimension: GlweDimension(1),
+    polynomial_size: PolynomialSize(8192),
+    lwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(0.00000003980397588319241)),
+    glwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(
+        0.0000000000000000002168404344971009,
+    )),
+    pbs_base_log: DecompositionBaseLog(15),
+    pbs_level: DecompositionLevelCount(2),
+    ks_level: DecompositionLevelCount(5),
+    ks_base_log: DecompositionBaseLog(4),
+    message_modulus: MessageModulus(16),
+    carry_modulus: CarryModulus(4),
+    ciphertext_modulus: CiphertextModulus::new_native(),
+    encryption_key_choice: EncryptionKeyChoice::Small,
+    secret_key_distribution: SecretKeyDistribution::Binary,
+};
+
+pub const PARAM_SMALL_MESSAGE_5_CARRY_1: PBSParameters = PBSParameters {
+    lwe_dimension: LweDimension(1025),
+    glwe_dimension: GlweDimension(1),
+    polynomial_size: PolynomialSize(8192),
+    lwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(0.00000003980397588319241)),
+    glwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(
+        0.0000000000000000002168404344971009,
+    )),
     pbs_base_log: DecompositionBaseLog(15),
     pbs_level: DecompositionLevelCount(2),
     ks_level: DecompositionLevelCount(5),
     ks_base_log: DecompositionBaseLog(4),
+    message_modulus: MessageModulus(32),
+    carry_modulus: CarryModulus(2),
+    ciphertext_modulus: CiphertextModulus::new_native(),
+    encryption_key_choice: EncryptionKeyChoice::Small,
+    secret_key_distribution: SecretKeyDistribution::Binary,
+};
+
+pub const PARAM_SMALL_MESSAGE_1_CARRY_6: PBSParameters = PBSParameters {
+    lwe_dimension: LweDimension(1214),
+    glwe_dimension: GlweDimension(1),
+    polynomial_size: PolynomialSize(32768),
+    lwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(0.0000000012520482863081104)),
+    glwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(
+        0.0000000000000000002168404344971009,
+    )),
+    pbs_base_log: DecompositionBaseLog(15),
+    pbs_level: DecompositionLevelCount(2),
+    ks_level: DecompositionLevelCount(6),
+    ks_base_log: DecompositionBaseLog(4),
+    message_modulus: MessageModulus(2),
+    carry_modulus: CarryModulus(64),
+    ciphertext_modulus: CiphertextModulus::new_native(),
+    encryption_key_choice: EncryptionKeyChoice::Small,
+    secret_key_distribution: SecretKeyDistribution::Binary,
+};
+
+pub const PARAM_SMALL_MESSAGE_2_CARRY_5: PBSParameters = PBSParameters {
+    lwe_dimension: LweDimension(1214),
+    glwe_dimension: GlweDimension(1),
+    polynomial_size: PolynomialSize(32768),
+    lwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(0.0000000012520482863081104)),
+    glwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(
+        0.0000000000000000002168404344971009,
+    )),
+    pbs_base_log: DecompositionBaseLog(15),
+    pbs_level: DecompositionLevelCount(2),
+    ks_level: DecompositionLevelCount(6),
+    ks_base_log: DecompositionBaseLog(4),
+    message_modulus: MessageModulus(4),
+    carry_modulus: CarryModulus(32),
+    ciphertext_modulus: CiphertextModulus::new_native(),
+    encryption_key_choice: EncryptionKeyChoice::Small,
+    secret_key_distribution: SecretKeyDistribution::Binary,
+};
+
+pub const PARAM_SMALL_MESSAGE_3_CARRY_4: PBSParameters = PBSParameters {
+    lwe_dimension: LweDimension(1214),
+    glwe_dimension: GlweDimension(1),
+    polynomial_size: PolynomialSize(32768),
+    lwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(0.0000000012520482863081104)),
+    glwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(
+        0.0000000000000000002168404344971009,
+    )),
+    pbs_base_log: DecompositionBaseLog(15),
+    pbs_level: DecompositionLevelCount(2),
+    ks_level: DecompositionLevelCount(6),
+    ks_base_log: DecompositionBaseLog(4),
     message_modulus: MessageModulus(8),
+    carry_modulus: CarryModulus(16),
+    ciphertext_modulus: CiphertextModulus::new_native(),
+    encryption_key_choice: EncryptionKeyChoice::Small,
+    secret_key_distribution: SecretKeyDistribution::Binary,
+};
+
+pub const PARAM_SMALL_MESSAGE_4_CARRY_3: PBSParameters = PBSParameters {
+    lwe_dimension: LweDimension(1214),
+    glwe_dimension: GlweDimension(1),
+    polynomial_size: PolynomialSize(32768),
+    lwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(0.0000000012520482863081104)),
+    glwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(
+        0.0000000000000000002168404344971009,
+    )),
+    pbs_base_log: DecompositionBaseLog(15),
+    pbs_level: DecompositionLevelCount(2),
+    ks_level: DecompositionLevelCount(6),
+    ks_base_log: DecompositionBaseLog(4),
+    message_modulus: MessageModulus(16),
     carry_modulus: CarryModulus(8),
     ciphertext_modulus: CiphertextModulus::new_native(),
     encryption_key_choice: EncryptionKeyChoice::Small,
+    secret_key_distribution: SecretKeyDistribution::Binary,
+};
+
+pub const PARAM_SMALL_MESSAGE_5_CARRY_2: PBSParameters = PBSParameters {
+    lwe_dimension: LweDimension(1214),
+    glwe_dimension: GlweDimension(1),
+    polynomial_size: PolynomialSize(32768),
+    lwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(0.0000000012520482863081104)),
+    glwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(
+        0.0000000000000000002168404344971009,
+    )),
+    pbs_base_log: DecompositionBaseLog(15),
+    pbs_level: DecompositionLevelCount(2),
+    ks_level: DecompositionLevelCount(6),
+    ks_base_log: DecompositionBaseLog(4),
+    message_modulus: MessageModulus(32),
+    carry_modulus: CarryModulus(4),
+    ciphertext_modulus: CiphertextModulus::new_native(),
+    encryption_key_choice: EncryptionKeyChoice::Small,
+    secret_key_distribution: SecretKeyDistribution::Binary,
+};
+
+pub const PARAM_SMALL_MESSAGE_6_CARRY_1: PBSParameters = PBSParameters {
+    lwe_dimension: LweDimension(1214),
+    glwe_dimension: GlweDimension(1),
+    polynomial_size: PolynomialSize(32768),
+    lwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(0.0000000012520482863081104)),
+    glwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(
+        0.0000000000000000002168404344971009,
+    )),
+    pbs_base_log: DecompositionBaseLog(15),
+    pbs_level: DecompositionLevelCount(2),
+    ks_level: DecompositionLevelCount(6),
+    ks_base_log: DecompositionBaseLog(4),
+    message_modulus: MessageModulus(64),
+    carry_modulus: CarryModulus(2),
+    ciphertext_modulus: CiphertextModulus::new_native(),
+    encryption_key_choice: EncryptionKeyChoice::Small,
+    secret_key_distribution: SecretKeyDistribution::Binary,
+};
+
+pub const PARAM_SMALL_MESSAGE_1_CARRY_7: PBSParameters = PBSParameters {
+    lwe_dimension: LweDimension(1214),
+    glwe_dimension: GlweDimension(1),
+    polynomial_size: PolynomialSize(32768),
+    lwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(0.0000000012520482863081104)),
+    glwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(
+        0.0000000000000000002168404344971009,
+    )),
+    pbs_base_log: DecompositionBaseLog(15),
+    pbs_level: DecompositionLevelCount(2),
+    ks_level: DecompositionLevelCount(6),
+    ks_base_log: DecompositionBaseLog(4),
+    message_modulus: MessageModulus(2),
+    carry_modulus: CarryModulus(128),
+    ciphertext_modulus: CiphertextModulus::new_native(),
+    encryption_key_choice: EncryptionKeyChoice::Small,
+    secret_key_distribution: SecretKeyDistribution::Binary,
+};
+
+pub const PARAM_SMALL_MESSAGE_2_CARRY_6: PBSParameters = PBSParameters {
+    lwe_dimension: LweDimension(1214),
+    glwe_dimension: GlweDimension(1),
+    polynomial_size: PolynomialSize(32768),
+    lwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(0.0000000012520482863081104)),
+    glwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(
+        0.0000000000000000002168404344971009,
+    )),
+    pbs_base_log: DecompositionBaseLog(15),
+    pbs_level: DecompositionLevelCount(2),
+    ks_level: DecompositionLevelCount(6),
+    ks_base_log: DecompositionBaseLog(4),
+    message_modulus: MessageModulus(4),
+    carry_modulus: CarryModulus(64),
+    ciphertext_modulus: CiphertextModulus::new_native(),
+    encryption_key_choice: EncryptionKeyChoice::Small,
+    secret_key_distribution: SecretKeyDistribution::Binary,
+};
+
+pub const PARAM_SMALL_MESSAGE_3_CARRY_5: PBSParameters = PBSParameters {
+    lwe_dimension: LweDimension(1214),
+    glwe_dimension: GlweDimension(1),
+    polynomial_size: PolynomialSize(32768),
+    lwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(0.0000000012520482863081104)),
+    glwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(
+        0.0000000000000000002168404344971009,
+    )),
+    pbs_base_log: DecompositionBaseLog(15),
+    pbs_level: DecompositionLevelCount(2),
+    ks_level: DecompositionLevelCount(6),
+    ks_base_log: DecompositionBaseLog(4),
+    message_modulus: MessageModulus(8),
+    carry_modulus: CarryModulus(32),
+    ciphertext_modulus: CiphertextModulus::new_native(),
+    encryption_key_choice: EncryptionKeyChoice::Small,
+    secret_key_distribution: SecretKeyDistribution::Binary,
 };
 
 pub const PARAM_SMALL_MESSAGE_4_CARRY_4: PBSParameters = PBSParameters {
     lwe_dimension: LweDimension(1214),
     glwe_dimension: GlweDimension(1),
     polynomial_size: PolynomialSize(32768),
-    lwe_modular_std_dev: StandardDev(0.0000000012520482863081104),
-    glwe_modular_std_dev: StandardDev(0.0000000000000000002168404344971009),
+    lwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(0.0000000012520482863081104)),
+    glwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(
+        0.0000000000000000002168404344971009,
+    )),
     pbs_base_log: DecompositionBaseLog(15),
     pbs_level: DecompositionLevelCount(2),
     ks_level: DecompositionLevelCount(6),
@@ -990,46 +1742,250 @@ pub const PARAM_SMALL_MESSAGE_4_CARRY_4: PBSParameters = PBSParameters {
     carry_modulus: CarryModulus(16),
     ciphertext_modulus: CiphertextModulus::new_native(),
     encryption_key_choice: EncryptionKeyChoice::Small,
+    secret_key_distribution: SecretKeyDistribution::Binary,
+};
+
+pub const PARAM_SMALL_MESSAGE_5_CARRY_3: PBSParameters = PBSParameters {
+    lwe_dimension: LweDimension(1214),
+    glwe_dimension: GlweDimension(1),
+    polynomial_size: PolynomialSize(32768),
+    lwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(0.0000000012520482863081104)),
+    glwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(
+        0.0000000000000000002168404344971009,
+    )),
+    pbs_base_log: DecompositionBaseLog(15),
+    pbs_level: DecompositionLevelCount(2),
+    ks_level: DecompositionLevelCount(6),
+    ks_base_log: DecompositionBaseLog(4),
+    message_modulus: MessageModulus(32),
+    carry_modulus: CarryModulus(8),
+    ciphertext_modulus: CiphertextModulus::new_native(),
+    encryption_key_choice: EncryptionKeyChoice::Small,
+    secret_key_distribution: SecretKeyDistribution::Binary,
+};
+
+pub const PARAM_SMALL_MESSAGE_6_CARRY_2: PBSParameters = PBSParameters {
+    lwe_dimension: LweDimension(1214),
+    glwe_dimension: GlweDimension(1),
+    polynomial_size: PolynomialSize(32768),
+    lwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(0.0000000012520482863081104)),
+    glwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(
+        0.0000000000000000002168404344971009,
+    )),
+    pbs_base_log: DecompositionBaseLog(15),
+    pbs_level: DecompositionLevelCount(2),
+    ks_level: DecompositionLevelCount(6),
+    ks_base_log: DecompositionBaseLog(4),
+    message_modulus: MessageModulus(64),
+    carry_modulus: CarryModulus(4),
+    ciphertext_modulus: CiphertextModulus::new_native(),
+    encryption_key_choice: EncryptionKeyChoice::Small,
+    secret_key_distribution: SecretKeyDistribution::Binary,
+};
+
+pub const PARAM_SMALL_MESSAGE_7_CARRY_1: PBSParameters = PBSParameters {
+    lwe_dimension: LweDimension(1214),
+    glwe_dimension: GlweDimension(1),
+    polynomial_size: PolynomialSize(32768),
+    lwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(0.0000000012520482863081104)),
+    glwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(
+        0.0000000000000000002168404344971009,
+    )),
+    pbs_base_log: DecompositionBaseLog(15),
+    pbs_level: DecompositionLevelCount(2),
+    ks_level: DecompositionLevelCount(6),
+    ks_base_log: DecompositionBaseLog(4),
+    message_modulus: MessageModulus(128),
+    carry_modulus: CarryModulus(2),
+    ciphertext_modulus: CiphertextModulus::new_native(),
+    encryption_key_choice: EncryptionKeyChoice::Small,
+    secret_key_distribution: SecretKeyDistribution::Binary,
 };
 
-/// Return a parameter set from a message and carry moduli.
+/// Error returned by [`get_parameters_from_message_and_carry`] when no parameter set satisfies
+/// the constraints.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NoMatchingParameters {
+    pub message_bits: u32,
+    pub carry_bits: u32,
+    /// The constraints of the query, along with the message and carry bits
+    pub query: ParametersQuery,
+}
+
+impl std::fmt::Display for NoMatchingParameters {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "No parameters for msg_space = {} and carry_space = {} satisfy the constraints {:?}",
+            1u64 << self.message_bits,
+            1u64 << self.carry_bits,
+            self.query
+        )
+    }
+}
+
+impl std::error::Error for NoMatchingParameters {}
+
+/// Return the cheapest parameter set for a message and carry moduli satisfying the constraints
+/// of `constraints`.
+///
+/// The message and carry filters of `constraints` are replaced by the moduli, rounded up to the
+/// next power of two. Among the matching sets, the one with the lowest estimated PBS cost is
+/// returned.
 ///
 /// # Example
 ///
 /// ```rust
 /// use tfhe::shortint::parameters::{
-///     get_parameters_from_message_and_carry, PARAM_MESSAGE_3_CARRY_1,
+///     get_parameters_from_message_and_carry, EncryptionKeyChoice, FailureProbability,
+///     ParametersQuery, PolynomialSize, PARAM_MESSAGE_2_CARRY_2_PBS_2M64, PARAM_MESSAGE_3_CARRY_1,
 /// };
 /// let message_space = 7;
 /// let carry_space = 2;
-/// let param = get_parameters_from_message_and_carry(message_space, carry_space);
-/// assert_eq!(param, PARAM_MESSAGE_3_CARRY_1);
+/// let constraints = ParametersQuery::new().encryption_key_choice(EncryptionKeyChoice::Big);
+/// let param = get_parameters_from_message_and_carry(message_space, carry_space, constraints);
+/// assert_eq!(param, Ok(PARAM_MESSAGE_3_CARRY_1));
+///
+/// // The sets with a lower failure probability are derived by hand from the default ones
+/// let constraints = ParametersQuery::new()
+///     .encryption_key_choice(EncryptionKeyChoice::Big)
+///     .max_log2_failure_probability(FailureProbability::Pow2M64.log2());
+/// let param = get_parameters_from_message_and_carry(4, 4, constraints);
+/// assert!(param.is_err());
+/// let param = get_parameters_from_message_and_carry(4, 4, constraints.include_unverified());
+/// assert_eq!(param, Ok(PARAM_MESSAGE_2_CARRY_2_PBS_2M64));
+///
+/// let constraints = ParametersQuery::new().max_polynomial_size(PolynomialSize(4096));
+/// let param = get_parameters_from_message_and_carry(16, 16, constraints);
+/// assert!(param.is_err());
+///
+/// // No built-in parameter set offers more than 128 bits of security
+/// let constraints = ParametersQuery::new().min_security_bits(192);
+/// assert!(get_parameters_from_message_and_carry(4, 4, constraints).is_err());
 /// ```
 pub fn get_parameters_from_message_and_carry(
     msg_space: usize,
     carry_space: usize,
-) -> PBSParameters {
-    let mut out = PARAM_MESSAGE_2_CARRY_2;
-    let mut flag: bool = false;
-    let mut rescaled_message_space = f64::ceil(f64::log2(msg_space as f64)) as usize;
-    rescaled_message_space = 1 << rescaled_message_space;
-    let mut rescaled_carry_space = f64::ceil(f64::log2(carry_space as f64)) as usize;
-    rescaled_carry_space = 1 << rescaled_carry_space;
-
-    for param in ALL_PARAMETER_VEC {
-        if param.message_modulus.0 == rescaled_message_space
-            && param.carry_modulus.0 == rescaled_carry_space
-        {
-            out = param;
-            flag = true;
-            break;
+    constraints: ParametersQuery,
+) -> Result<PBSParameters, NoMatchingParameters> {
+    let message_bits = f64::ceil(f64::log2(msg_space as f64)) as u32;
+    let carry_bits = f64::ceil(f64::log2(carry_space as f64)) as u32;
+
+    let query = constraints
+        .message_bits(message_bits)
+        .carry_bits(carry_bits);
+
+    ParametersCatalog::builtin()
+        .find(&query)
+        .map(|entry| entry.parameters)
+        .min_by_key(PBSParameters::estimated_pbs_cost)
+        .ok_or(NoMatchingParameters {
+            message_bits,
+            carry_bits,
+            query,
+        })
+}
+
+/// Return the parameter set of [`WITH_CARRY_PARAMETERS_VEC`] or
+/// [`SMALL_WITH_CARRY_PARAMETERS_VEC`] for a message and carry moduli and an encryption key
+/// choice.
+///
+/// Returns `None` if there is no such parameter set for the given moduli.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::shortint::parameters::{
+///     get_parameters_from_message_carry_and_key_choice, EncryptionKeyChoice,
+///     PARAM_MESSAGE_2_CARRY_2, PARAM_SMALL_MESSAGE_2_CARRY_3,
+/// };
+///
+/// let param = get_parameters_from_message_carry_and_key_choice(4, 4, EncryptionKeyChoice::Big);
+/// assert_eq!(param, Some(PARAM_MESSAGE_2_CARRY_2));
+///
+/// let param = get_parameters_from_message_carry_and_key_choice(4, 8, EncryptionKeyChoice::Small);
+/// assert_eq!(param, Some(PARAM_SMALL_MESSAGE_2_CARRY_3));
+///
+/// // There is no set with 9 bits of precision
+/// let param = get_parameters_from_message_carry_and_key_choice(16, 32, EncryptionKeyChoice::Big);
+/// assert_eq!(param, None);
+/// ```
+pub fn get_parameters_from_message_carry_and_key_choice(
+    msg_space: usize,
+    carry_space: usize,
+    encryption_key_choice: EncryptionKeyChoice,
+) -> Option<PBSParameters> {
+    let parameters = match encryption_key_choice {
+        EncryptionKeyChoice::Big => WITH_CARRY_PARAMETERS_VEC,
+        EncryptionKeyChoice::Small => SMALL_WITH_CARRY_PARAMETERS_VEC,
+    };
+
+    parameters.into_iter().find(|param| {
+        param.message_modulus.0 == msg_space.next_power_of_two()
+            && param.carry_modulus.0 == carry_space.next_power_of_two()
+    })
+}
+
+/// Upper bound of the failure probability of a PBS targeted by a parameter set.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum FailureProbability {
+    /// The failure probability of the default parameter sets
+    Pow2M40,
+    Pow2M64,
+    Pow2M128,
+}
+
+impl FailureProbability {
+    /// Returns the base 2 logarithm of the failure probability
+    pub const fn log2(&self) -> i32 {
+        match self {
+            Self::Pow2M40 => -40,
+            Self::Pow2M64 => -64,
+            Self::Pow2M128 => -128,
         }
     }
-    if !flag {
-        println!(
-            "### WARNING: NO PARAMETERS FOUND for msg_space = {rescaled_message_space} and \
-            carry_space = {rescaled_carry_space} ### "
-        );
-    }
-    out
+}
+
+/// Return a parameter set from a message and carry moduli, with a PBS failure probability of at
+/// most `failure_probability`.
+///
+/// The failure probability of the sets returned for [`FailureProbability::Pow2M64`] and
+/// [`FailureProbability::Pow2M128`] is not estimated, it is inferred from the one of a default set,
+/// see [`parameters_lower_failure_probability`] and [`NamedParameters::unverified`].
+///
+/// Returns `None` if there is no such parameter set for the given moduli.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::shortint::parameters::{
+///     get_parameters, FailureProbability, PARAM_MESSAGE_2_CARRY_2,
+///     PARAM_MESSAGE_2_CARRY_2_PBS_2M64,
+/// };
+///
+/// let param = get_parameters(4, 4, FailureProbability::Pow2M40);
+/// assert_eq!(param, Some(PARAM_MESSAGE_2_CARRY_2));
+///
+/// let param = get_parameters(4, 4, FailureProbability::Pow2M64);
+/// assert_eq!(param, Some(PARAM_MESSAGE_2_CARRY_2_PBS_2M64));
+/// ```
+pub fn get_parameters(
+    msg_space: usize,
+    carry_space: usize,
+    failure_probability: FailureProbability,
+) -> Option<PBSParameters> {
+    let message_bits = f64::ceil(f64::log2(msg_space as f64)) as u32;
+    let carry_bits = f64::ceil(f64::log2(carry_space as f64)) as u32;
+
+    let query = ParametersQuery::new()
+        .message_bits(message_bits)
+        .carry_bits(carry_bits)
+        .encryption_key_choice(EncryptionKeyChoice::Big)
+        .max_log2_failure_probability(failure_probability.log2())
+        .include_unverified();
+
+    ParametersCatalog::builtin()
+        .find(&query)
+        .next()
+        .map(|entry| entry.parameters)
 }
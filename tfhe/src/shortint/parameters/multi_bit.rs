@@ -0,0 +1,159 @@
+//! Parameter sets for the multi-bit programmable bootstrapping.
+//!
+//! The multi-bit PBS groups the coefficients of the LWE secret key by `grouping_factor` and
+//! performs one step of the blind rotation per group instead of one per coefficient, the steps of
+//! a group being computed in parallel. The bootstrapping key is `(2^grouping_factor - 1) /
+//! grouping_factor` times larger than the one of the classic PBS, in exchange for a lower latency
+//! when enough threads are available.
+//!
+//! The noise added by a blind rotation grows by about the same factor as the key. The sets of this
+//! module reuse the parameters of the classic set having one more bit of carry, while keeping the
+//! requested carry space, like the sets of [`super::parameters_lower_failure_probability`] which
+//! explains the noise margin this gives. The LWE dimension is rounded up to a multiple of the
+//! grouping factor, with the same noise.
+//!
+//! These sets are derived from the classic sets and are not vetted: neither their failure
+//! probability with the noise of the multi-bit PBS nor their security has been checked. They are
+//! meant to experiment with the multi-bit PBS, not for production.
+//!
+//! A [`ShortintParameterSet`] built from [`MultiBitPBSParameters`] generates server keys using
+//! the multi-bit PBS, the other operations are unchanged.
+
+use super::*;
+pub use crate::core_crypto::commons::parameters::LweBskGroupingFactor;
+
+/// A structure defining the set of cryptographic parameters for homomorphic integer circuit
+/// evaluation with the multi-bit PBS.
+#[derive(Serialize, Copy, Clone, Deserialize, Debug, PartialEq)]
+pub struct MultiBitPBSParameters {
+    pub lwe_dimension: LweDimension,
+    pub glwe_dimension: GlweDimension,
+    pub polynomial_size: PolynomialSize,
+    pub lwe_noise_distribution: DynamicDistribution,
+    pub glwe_noise_distribution: DynamicDistribution,
+    pub pbs_base_log: DecompositionBaseLog,
+    pub pbs_level: DecompositionLevelCount,
+    pub ks_base_log: DecompositionBaseLog,
+    pub ks_level: DecompositionLevelCount,
+    pub message_modulus: MessageModulus,
+    pub carry_modulus: CarryModulus,
+    pub ciphertext_modulus: CiphertextModulus,
+    pub encryption_key_choice: EncryptionKeyChoice,
+    pub secret_key_distribution: SecretKeyDistribution,
+    pub grouping_factor: LweBskGroupingFactor,
+}
+
+impl MultiBitPBSParameters {
+    /// Returns the parameters without the grouping factor, e.g. to compare them with classic
+    /// parameter sets or to report their derived quantities.
+    pub const fn to_pbs_parameters(&self) -> PBSParameters {
+        PBSParameters {
+            lwe_dimension: self.lwe_dimension,
+            glwe_dimension: self.glwe_dimension,
+            polynomial_size: self.polynomial_size,
+            lwe_noise_distribution: self.lwe_noise_distribution,
+            glwe_noise_distribution: self.glwe_noise_distribution,
+            pbs_base_log: self.pbs_base_log,
+            pbs_level: self.pbs_level,
+            ks_base_log: self.ks_base_log,
+            ks_level: self.ks_level,
+            message_modulus: self.message_modulus,
+            carry_modulus: self.carry_modulus,
+            ciphertext_modulus: self.ciphertext_modulus,
+            encryption_key_choice: self.encryption_key_choice,
+            secret_key_distribution: self.secret_key_distribution,
+        }
+    }
+}
+
+macro_rules! multi_bit_parameters {
+    ($(
+        $name:ident = $parent:ident with CarryModulus($carry:literal),
+        LweBskGroupingFactor($grouping_factor:literal)
+    );* $(;)?) => {
+        $(
+            #[doc = concat!("Parameters of [`", stringify!($parent), "`] with a carry modulus of ")]
+            #[doc = concat!(stringify!($carry), " for the multi-bit PBS with a grouping factor of ")]
+            #[doc = concat!(stringify!($grouping_factor), ".")]
+            ///
+            /// Not vetted, see the [module documentation](self).
+            pub const $name: MultiBitPBSParameters = MultiBitPBSParameters {
+                lwe_dimension: LweDimension(
+                    ($parent.lwe_dimension.0 + $grouping_factor - 1) / $grouping_factor
+                        * $grouping_factor,
+                ),
+                glwe_dimension: $parent.glwe_dimension,
+                polynomial_size: $parent.polynomial_size,
+                lwe_noise_distribution: $parent.lwe_noise_distribution,
+                glwe_noise_distribution: $parent.glwe_noise_distribution,
+                pbs_base_log: $parent.pbs_base_log,
+                pbs_level: $parent.pbs_level,
+                ks_base_log: $parent.ks_base_log,
+                ks_level: $parent.ks_level,
+                message_modulus: $parent.message_modulus,
+                carry_modulus: CarryModulus($carry),
+                ciphertext_modulus: $parent.ciphertext_modulus,
+                encryption_key_choice: $parent.encryption_key_choice,
+                secret_key_distribution: $parent.secret_key_distribution,
+                grouping_factor: LweBskGroupingFactor($grouping_factor),
+            };
+        )*
+    };
+}
+
+multi_bit_parameters!(
+    PARAM_MULTI_BIT_MESSAGE_1_CARRY_1_GROUP_2 = PARAM_MESSAGE_1_CARRY_2 with CarryModulus(2),
+        LweBskGroupingFactor(2);
+    PARAM_MULTI_BIT_MESSAGE_2_CARRY_2_GROUP_2 = PARAM_MESSAGE_2_CARRY_3 with CarryModulus(4),
+        LweBskGroupingFactor(2);
+    PARAM_MULTI_BIT_MESSAGE_3_CARRY_3_GROUP_2 = PARAM_MESSAGE_3_CARRY_4 with CarryModulus(8),
+        LweBskGroupingFactor(2);
+    PARAM_MULTI_BIT_MESSAGE_1_CARRY_1_GROUP_3 = PARAM_MESSAGE_1_CARRY_2 with CarryModulus(2),
+        LweBskGroupingFactor(3);
+    PARAM_MULTI_BIT_MESSAGE_2_CARRY_2_GROUP_3 = PARAM_MESSAGE_2_CARRY_3 with CarryModulus(4),
+        LweBskGroupingFactor(3);
+    PARAM_MULTI_BIT_MESSAGE_3_CARRY_3_GROUP_3 = PARAM_MESSAGE_3_CARRY_4 with CarryModulus(8),
+        LweBskGroupingFactor(3);
+    PARAM_MULTI_BIT_MESSAGE_1_CARRY_1_GROUP_4 = PARAM_MESSAGE_1_CARRY_2 with CarryModulus(2),
+        LweBskGroupingFactor(4);
+    PARAM_MULTI_BIT_MESSAGE_2_CARRY_2_GROUP_4 = PARAM_MESSAGE_2_CARRY_3 with CarryModulus(4),
+        LweBskGroupingFactor(4);
+    PARAM_MULTI_BIT_MESSAGE_3_CARRY_3_GROUP_4 = PARAM_MESSAGE_3_CARRY_4 with CarryModulus(8),
+        LweBskGroupingFactor(4);
+);
+
+/// Vector containing all the multi-bit parameter sets
+pub const ALL_MULTI_BIT_PARAMETER_VEC: [MultiBitPBSParameters; 9] = [
+    PARAM_MULTI_BIT_MESSAGE_1_CARRY_1_GROUP_2,
+    PARAM_MULTI_BIT_MESSAGE_2_CARRY_2_GROUP_2,
+    PARAM_MULTI_BIT_MESSAGE_3_CARRY_3_GROUP_2,
+    PARAM_MULTI_BIT_MESSAGE_1_CARRY_1_GROUP_3,
+    PARAM_MULTI_BIT_MESSAGE_2_CARRY_2_GROUP_3,
+    PARAM_MULTI_BIT_MESSAGE_3_CARRY_3_GROUP_3,
+    PARAM_MULTI_BIT_MESSAGE_1_CARRY_1_GROUP_4,
+    PARAM_MULTI_BIT_MESSAGE_2_CARRY_2_GROUP_4,
+    PARAM_MULTI_BIT_MESSAGE_3_CARRY_3_GROUP_4,
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_multi_bit_parameters_are_consistent() {
+        for params in ALL_MULTI_BIT_PARAMETER_VEC {
+            assert_eq!(params.lwe_dimension.0 % params.grouping_factor.0, 0);
+            assert_eq!(params.message_modulus.0, params.carry_modulus.0);
+
+            let classic = PBSParametersBuilder::from(params.to_pbs_parameters())
+                .build()
+                .unwrap();
+            assert_eq!(classic, params.to_pbs_parameters());
+
+            let param_set = ShortintParameterSet::from(params);
+            assert_eq!(param_set.multi_bit_parameters(), Some(params));
+            assert_eq!(param_set.pbs_parameters(), None);
+            assert_eq!(param_set.lwe_dimension(), params.lwe_dimension);
+        }
+    }
+}
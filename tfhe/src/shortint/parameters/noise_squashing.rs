@@ -0,0 +1,173 @@
+//! Parameters of the noise squashing, see [`crate::shortint::noise_squashing`].
+
+use super::{
+    CarryModulus, CoreCiphertextModulus, DecompositionBaseLog, DecompositionLevelCount,
+    DynamicDistribution, GlweDimension, MessageModulus, PBSParameters, PolynomialSize,
+};
+use crate::core_crypto::commons::dispersion::DispersionParameter;
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+/// The statistical security, in bits, with which the flooding noise of a squashed ciphertext
+/// hides the noise output by the bootstrapping: the bound of the flooding noise must be at least
+/// `2^FLOODING_STATISTICAL_SECURITY` times the bound of the bootstrapping noise.
+pub const FLOODING_STATISTICAL_SECURITY: u32 = 40;
+
+/// A structure defining the parameters of the 128 bits GLWE secret key and of the bootstrapping
+/// key used to squash the noise of shortint ciphertexts.
+#[derive(Serialize, Copy, Clone, Deserialize, Debug, PartialEq)]
+pub struct NoiseSquashingParameters {
+    pub glwe_dimension: GlweDimension,
+    pub polynomial_size: PolynomialSize,
+    pub glwe_noise_distribution: DynamicDistribution,
+    pub decomp_base_log: DecompositionBaseLog,
+    pub decomp_level_count: DecompositionLevelCount,
+    pub message_modulus: MessageModulus,
+    pub carry_modulus: CarryModulus,
+    pub ciphertext_modulus: CoreCiphertextModulus<u128>,
+    /// The base 2 logarithm of the bound, on the torus, of the noise output by the bootstrapping
+    /// of the squashing, as estimated for these parameters
+    pub bootstrap_noise_log2_bound: i32,
+    /// The noise added to the body of the output of the bootstrapping, it must be t-uniform, see
+    /// [`NoiseSquashingParameters::check_noise_budget`]
+    pub flooding_noise_distribution: DynamicDistribution,
+}
+
+impl NoiseSquashingParameters {
+    /// Returns whether these parameters can squash the noise of ciphertexts encrypted with the
+    /// given [`PBSParameters`], i.e. whether they use the same message and carry spaces.
+    pub fn is_compatible_with(&self, pbs_params: &PBSParameters) -> bool {
+        self.message_modulus == pbs_params.message_modulus
+            && self.carry_modulus == pbs_params.carry_modulus
+    }
+
+    /// Returns the built-in noise squashing parameters compatible with `pbs_params`.
+    ///
+    /// Only [`NOISE_SQUASHING_PARAM_MESSAGE_2_CARRY_2`] is built in, the other message and carry
+    /// spaces return a [`NoiseSquashingError::UnsupportedPrecision`] error.
+    pub fn for_parameters(pbs_params: &PBSParameters) -> Result<Self, NoiseSquashingError> {
+        [NOISE_SQUASHING_PARAM_MESSAGE_2_CARRY_2]
+            .into_iter()
+            .find(|params| params.is_compatible_with(pbs_params))
+            .ok_or(NoiseSquashingError::UnsupportedPrecision {
+                message_modulus: pbs_params.message_modulus,
+                carry_modulus: pbs_params.carry_modulus,
+            })
+    }
+
+    /// Checks that the flooding noise fits in the noise budget of the 128 bits modulus.
+    ///
+    /// The message and carries are encoded with a padding bit, so a squashed ciphertext decrypts
+    /// correctly while its noise stays below `2^-(log2(message_modulus * carry_modulus) + 2)`.
+    /// The flooding noise must:
+    ///
+    /// * be t-uniform, so that its bound is exact;
+    /// * be at least [`FLOODING_STATISTICAL_SECURITY`] bits larger than the noise of the
+    ///   bootstrapping, which it has to hide;
+    /// * added to the noise of the bootstrapping, stay within the budget.
+    pub fn check_noise_budget(&self) -> Result<(), NoiseSquashingError> {
+        let Some(flooding_log2_bound) = self.flooding_noise_distribution.get_t_uniform_log2_bound()
+        else {
+            return Err(NoiseSquashingError::NonUniformFlooding);
+        };
+
+        let required_log2_bound =
+            self.bootstrap_noise_log2_bound + FLOODING_STATISTICAL_SECURITY as i32;
+        if flooding_log2_bound < required_log2_bound {
+            return Err(NoiseSquashingError::InsufficientFlooding {
+                flooding_log2_bound,
+                required_log2_bound,
+            });
+        }
+
+        // The bootstrapping noise is smaller than the flooding noise, their sum is below twice
+        // the flooding bound
+        let modulus_sup = self.message_modulus.0 * self.carry_modulus.0;
+        let budget_log2_bound = -(modulus_sup.ilog2() as i32 + 2);
+        if flooding_log2_bound + 1 > budget_log2_bound {
+            return Err(NoiseSquashingError::NoiseBudgetExceeded {
+                flooding_log2_bound,
+                budget_log2_bound,
+            });
+        }
+
+        Ok(())
+    }
+}
+
+/// Error returned when the noise squashing parameters can not be used.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NoiseSquashingError {
+    /// No built-in noise squashing parameters use these message and carry spaces
+    UnsupportedPrecision {
+        message_modulus: MessageModulus,
+        carry_modulus: CarryModulus,
+    },
+    /// The flooding noise is not t-uniform
+    NonUniformFlooding,
+    /// The flooding noise is too small to statistically hide the noise of the bootstrapping
+    InsufficientFlooding {
+        flooding_log2_bound: i32,
+        required_log2_bound: i32,
+    },
+    /// The flooding noise added to the noise of the bootstrapping may exceed the noise budget
+    NoiseBudgetExceeded {
+        flooding_log2_bound: i32,
+        budget_log2_bound: i32,
+    },
+}
+
+impl Display for NoiseSquashingError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnsupportedPrecision {
+                message_modulus,
+                carry_modulus,
+            } => write!(
+                f,
+                "No noise squashing parameters are built in for a message modulus of {} and a \
+                carry modulus of {}",
+                message_modulus.0, carry_modulus.0
+            ),
+            Self::NonUniformFlooding => write!(f, "The flooding noise must be t-uniform"),
+            Self::InsufficientFlooding {
+                flooding_log2_bound,
+                required_log2_bound,
+            } => write!(
+                f,
+                "The flooding noise bound 2^{flooding_log2_bound} does not hide the noise of the \
+                bootstrapping, it must be at least 2^{required_log2_bound}"
+            ),
+            Self::NoiseBudgetExceeded {
+                flooding_log2_bound,
+                budget_log2_bound,
+            } => write!(
+                f,
+                "The flooding noise bound 2^{flooding_log2_bound} exceeds the noise budget of \
+                2^{budget_log2_bound} once added to the noise of the bootstrapping"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for NoiseSquashingError {}
+
+/// Noise squashing parameters for ciphertexts of
+/// [`PARAM_MESSAGE_2_CARRY_2`](super::PARAM_MESSAGE_2_CARRY_2).
+///
+/// The message is encoded in the 5 most significant bits of the 128 bits torus, leaving a noise
+/// budget of `2^-6`. The noise output by the bootstrapping stays below `2^-64`, it is flooded
+/// with a t-uniform noise bounded by `2^-8`: `2^56` times larger, and within the budget.
+pub const NOISE_SQUASHING_PARAM_MESSAGE_2_CARRY_2: NoiseSquashingParameters =
+    NoiseSquashingParameters {
+        glwe_dimension: GlweDimension(2),
+        polynomial_size: PolynomialSize(2048),
+        glwe_noise_distribution: DynamicDistribution::TUniform { log2_bound: -98 },
+        decomp_base_log: DecompositionBaseLog(24),
+        decomp_level_count: DecompositionLevelCount(3),
+        message_modulus: MessageModulus(4),
+        carry_modulus: CarryModulus(4),
+        ciphertext_modulus: CoreCiphertextModulus::new_native(),
+        bootstrap_noise_log2_bound: -64,
+        flooding_noise_distribution: DynamicDistribution::TUniform { log2_bound: -8 },
+    };
@@ -0,0 +1,236 @@
+//! Selection of parameters from a description of the circuit to evaluate.
+//!
+//! A [`ParameterOptimizer`] turns a [`CircuitDescription`] into a parameter set. The
+//! [`CatalogOptimizer`] provided by the crate searches the [`ParametersCatalog`] for the cheapest
+//! set able to evaluate the circuit; tools generating their own parameters (e.g. with an external
+//! optimizer) can implement the trait to be used in its place.
+//!
+//! # Example
+//!
+//! ```rust
+//! use tfhe::shortint::parameters::optimizer::{
+//!     CatalogOptimizer, CircuitDescription, ParameterOptimizer,
+//! };
+//!
+//! // 3 bits messages, up to 4 additions between two PBS and 10_000 PBS in total
+//! let circuit = CircuitDescription::new(3)
+//!     .levelled_ops(4)
+//!     .pbs_count(10_000);
+//!
+//! // Only the sets derived by hand from the default ones reach the failure probability needed
+//! let optimizer = CatalogOptimizer::default();
+//! assert!(optimizer.optimize(&circuit).is_none());
+//!
+//! let params = optimizer.include_unverified().optimize(&circuit).unwrap();
+//! assert_eq!(params.message_modulus.0, 8);
+//! assert!(params.carry_modulus.0 >= 8);
+//! ```
+
+use super::catalog::{NamedParameters, ParametersCatalog, ParametersQuery};
+use super::*;
+
+/// Description of a circuit, as needed to choose its parameters.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct CircuitDescription {
+    precision_bits: u32,
+    levelled_ops: usize,
+    pbs_count: u64,
+    max_log2_failure_probability: i32,
+}
+
+impl CircuitDescription {
+    /// Describes a circuit on messages of `precision_bits` bits, applying a single PBS and no
+    /// levelled operation, with a failure probability of at most `2^-40`.
+    pub fn new(precision_bits: u32) -> Self {
+        Self {
+            precision_bits,
+            levelled_ops: 0,
+            pbs_count: 1,
+            max_log2_failure_probability: -40,
+        }
+    }
+
+    /// Maximum number of levelled operations (additions, multiplications by a small scalar)
+    /// applied to a ciphertext between two PBS.
+    ///
+    /// Each operation is assumed to add at most one full message to the value, which has to fit
+    /// in the message and carry spaces.
+    pub fn levelled_ops(mut self, levelled_ops: usize) -> Self {
+        self.levelled_ops = levelled_ops;
+        self
+    }
+
+    /// Total number of PBS computed by the circuit.
+    pub fn pbs_count(mut self, pbs_count: u64) -> Self {
+        self.pbs_count = pbs_count;
+        self
+    }
+
+    /// Maximum failure probability of the whole circuit, as a base 2 logarithm.
+    pub fn max_log2_failure_probability(mut self, log2_failure_probability: i32) -> Self {
+        self.max_log2_failure_probability = log2_failure_probability;
+        self
+    }
+
+    pub fn precision_bits(&self) -> u32 {
+        self.precision_bits
+    }
+
+    /// Minimum number of carry bits needed to hold the result of the levelled operations.
+    pub fn required_carry_bits(&self) -> u32 {
+        let max_message = 1u128
+            .checked_shl(self.precision_bits)
+            .map_or(u128::MAX, |modulus| modulus - 1);
+        let max_value = max_message.saturating_mul(self.levelled_ops as u128 + 1);
+        // The value fits in as many bits as its bit length
+        let value_bits = u128::BITS - max_value.leading_zeros();
+        value_bits.saturating_sub(self.precision_bits)
+    }
+
+    /// Maximum failure probability of a single PBS, as a base 2 logarithm.
+    ///
+    /// The failure probabilities of the PBS of the circuit are summed (union bound), so the
+    /// budget of the circuit is divided by the number of PBS.
+    pub fn required_log2_failure_probability(&self) -> i32 {
+        // Rounded up, without overflowing for counts above 2^63
+        let log2_pbs_count = (u64::BITS - (self.pbs_count.max(1) - 1).leading_zeros()) as i32;
+        self.max_log2_failure_probability - log2_pbs_count
+    }
+}
+
+/// Chooses the parameters used to evaluate a circuit.
+pub trait ParameterOptimizer {
+    /// Returns parameters able to evaluate the circuit, or `None` if there are none.
+    fn optimize(&self, circuit: &CircuitDescription) -> Option<PBSParameters>;
+}
+
+/// Heuristic search over the parameter sets of a [`ParametersCatalog`].
+///
+/// Among the sets with the precision of the circuit, enough carry bits for its levelled
+/// operations and a low enough failure probability, the one with the lowest estimated PBS cost is
+/// returned. Levelled operations are considered free.
+///
+/// Like the queries of the catalog, the optimizer ignores the sets whose guarantees were not
+/// estimated unless [`CatalogOptimizer::include_unverified`] is set.
+#[derive(Debug, Copy, Clone)]
+pub struct CatalogOptimizer {
+    catalog: ParametersCatalog,
+    encryption_key_choice: EncryptionKeyChoice,
+    include_unverified: bool,
+}
+
+impl Default for CatalogOptimizer {
+    fn default() -> Self {
+        Self::new(ParametersCatalog::builtin())
+    }
+}
+
+impl CatalogOptimizer {
+    pub fn new(catalog: ParametersCatalog) -> Self {
+        Self {
+            catalog,
+            encryption_key_choice: EncryptionKeyChoice::Big,
+            include_unverified: false,
+        }
+    }
+
+    /// Only consider parameter sets encrypting under the given key, [`EncryptionKeyChoice::Big`]
+    /// by default.
+    pub fn encryption_key_choice(mut self, encryption_key_choice: EncryptionKeyChoice) -> Self {
+        self.encryption_key_choice = encryption_key_choice;
+        self
+    }
+
+    /// Also consider the parameter sets whose guarantees were only inferred from those of another
+    /// set, see [`NamedParameters::unverified`].
+    pub fn include_unverified(mut self) -> Self {
+        self.include_unverified = true;
+        self
+    }
+
+    /// Same as [`ParameterOptimizer::optimize`], returning the catalog entry of the parameters.
+    pub fn optimize_named(&self, circuit: &CircuitDescription) -> Option<&'static NamedParameters> {
+        let required_carry_bits = circuit.required_carry_bits();
+        let mut query = ParametersQuery::new()
+            .message_bits(circuit.precision_bits())
+            .encryption_key_choice(self.encryption_key_choice)
+            .max_log2_failure_probability(circuit.required_log2_failure_probability())
+            .ciphertext_modulus(CiphertextModulus::new_native());
+        if self.include_unverified {
+            query = query.include_unverified();
+        }
+
+        self.catalog
+            .find(&query)
+            .filter(|entry| entry.carry_bits() >= required_carry_bits)
+            .min_by_key(|entry| entry.parameters.estimated_pbs_cost())
+    }
+}
+
+impl ParameterOptimizer for CatalogOptimizer {
+    fn optimize(&self, circuit: &CircuitDescription) -> Option<PBSParameters> {
+        self.optimize_named(circuit).map(|entry| entry.parameters)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_circuit_requirements() {
+        let circuit = CircuitDescription::new(2);
+        assert_eq!(circuit.required_carry_bits(), 0);
+        assert_eq!(circuit.required_log2_failure_probability(), -40);
+
+        // 3 + 3 = 6 fits in 3 bits
+        assert_eq!(circuit.levelled_ops(1).required_carry_bits(), 1);
+        // 4 * 3 = 12 needs 4 bits
+        assert_eq!(circuit.levelled_ops(3).required_carry_bits(), 2);
+
+        let circuit = circuit.pbs_count(1000).max_log2_failure_probability(-50);
+        assert_eq!(circuit.required_log2_failure_probability(), -60);
+
+        let circuit = circuit.pbs_count(u64::MAX);
+        assert_eq!(circuit.required_log2_failure_probability(), -114);
+    }
+
+    #[test]
+    fn test_catalog_optimizer() {
+        let optimizer = CatalogOptimizer::default();
+
+        let circuit = CircuitDescription::new(2).levelled_ops(3);
+        let entry = optimizer.optimize_named(&circuit).unwrap();
+        assert_eq!(entry.message_bits(), 2);
+        assert!(entry.carry_bits() >= 2);
+        assert!(
+            entry.parameters.estimated_pbs_cost() <= PARAM_MESSAGE_2_CARRY_2.estimated_pbs_cost()
+        );
+        assert_eq!(optimizer.optimize(&circuit), Some(entry.parameters));
+
+        // Many PBS require a lower failure probability per PBS, which only the unverified sets
+        // reach
+        let circuit = circuit.pbs_count(1 << 30);
+        assert!(optimizer.optimize_named(&circuit).is_none());
+        let entry = optimizer
+            .include_unverified()
+            .optimize_named(&circuit)
+            .unwrap();
+        assert!(entry.log2_failure_probability <= -70);
+        assert!(entry.unverified);
+
+        let entry = optimizer
+            .encryption_key_choice(EncryptionKeyChoice::Small)
+            .optimize_named(&CircuitDescription::new(2))
+            .unwrap();
+        assert_eq!(
+            entry.parameters.encryption_key_choice,
+            EncryptionKeyChoice::Small
+        );
+
+        assert!(optimizer.optimize(&CircuitDescription::new(9)).is_none());
+        assert!(optimizer
+            .optimize(&CircuitDescription::new(2).levelled_ops(1 << 20))
+            .is_none());
+    }
+}
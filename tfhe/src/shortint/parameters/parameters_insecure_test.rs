@@ -0,0 +1,143 @@
+//! INSECURE parameter sets, only meant for fast unit tests.
+//!
+//! The parameter sets of this module use a tiny LWE dimension and polynomial size along with an
+//! almost noiseless encryption, so that keys are generated and PBS are computed in milliseconds.
+//! They offer NO security at all and are only available with the `insecure-test-params` feature.
+//!
+//! As a guard rail, the serialization of the keys generated from these parameters (client, server
+//! and public keys, compressed or not) returns an error, so that they can not leak out of a test.
+//! Ciphertexts can still be serialized to test serialization round trips.
+//!
+//! # Example
+//!
+//! ```rust
+//! use tfhe::shortint::gen_keys;
+//! use tfhe::shortint::parameters::PARAM_TEST_FAST_MESSAGE_2_CARRY_2;
+//!
+//! let (cks, sks) = gen_keys(PARAM_TEST_FAST_MESSAGE_2_CARRY_2);
+//!
+//! let ct = sks.add(&cks.encrypt(1), &cks.encrypt(2));
+//! assert_eq!(cks.decrypt(&sks.mul_lsb(&ct, &cks.encrypt(3))), 1);
+//!
+//! assert!(bincode::serialize(&cks).is_err());
+//! assert!(bincode::serialize(&sks).is_err());
+//! assert!(bincode::serialize(&ct).is_ok());
+//! ```
+
+use super::*;
+
+/// INSECURE parameters for 1 bit of message and 1 bit of carry, only meant for unit tests.
+pub const PARAM_TEST_FAST_MESSAGE_1_CARRY_1: PBSParameters = PBSParameters {
+    lwe_dimension: LweDimension(32),
+    glwe_dimension: GlweDimension(1),
+    polynomial_size: PolynomialSize(256),
+    lwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(
+        0.0000000000009094947017729282,
+    )),
+    glwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(
+        0.0000000000000000008673617379884035,
+    )),
+    pbs_base_log: DecompositionBaseLog(23),
+    pbs_level: DecompositionLevelCount(1),
+    ks_level: DecompositionLevelCount(4),
+    ks_base_log: DecompositionBaseLog(4),
+    message_modulus: MessageModulus(2),
+    carry_modulus: CarryModulus(2),
+    ciphertext_modulus: CiphertextModulus::new_native(),
+    encryption_key_choice: EncryptionKeyChoice::Big,
+    secret_key_distribution: SecretKeyDistribution::Binary,
+};
+/// INSECURE parameters for 2 bits of message and 2 bits of carry, only meant for unit tests.
+pub const PARAM_TEST_FAST_MESSAGE_2_CARRY_2: PBSParameters = PBSParameters {
+    lwe_dimension: LweDimension(64),
+    glwe_dimension: GlweDimension(1),
+    polynomial_size: PolynomialSize(512),
+    lwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(
+        0.0000000000009094947017729282,
+    )),
+    glwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(
+        0.0000000000000000008673617379884035,
+    )),
+    pbs_base_log: DecompositionBaseLog(23),
+    pbs_level: DecompositionLevelCount(1),
+    ks_level: DecompositionLevelCount(4),
+    ks_base_log: DecompositionBaseLog(4),
+    message_modulus: MessageModulus(4),
+    carry_modulus: CarryModulus(4),
+    ciphertext_modulus: CiphertextModulus::new_native(),
+    encryption_key_choice: EncryptionKeyChoice::Big,
+    secret_key_distribution: SecretKeyDistribution::Binary,
+};
+
+pub const INSECURE_TEST_PARAMETERS_VEC: [PBSParameters; 2] = [
+    PARAM_TEST_FAST_MESSAGE_1_CARRY_1,
+    PARAM_TEST_FAST_MESSAGE_2_CARRY_2,
+];
+
+/// Returns whether keys with the given dimensions were generated from one of the insecure test
+/// parameter sets.
+pub(crate) fn has_insecure_test_dimensions(
+    lwe_dimension: LweDimension,
+    polynomial_size: PolynomialSize,
+) -> bool {
+    INSECURE_TEST_PARAMETERS_VEC.iter().any(|params| {
+        params.lwe_dimension == lwe_dimension && params.polynomial_size == polynomial_size
+    })
+}
+
+/// Refuses the serialization of keys generated from one of the insecure test parameter sets.
+pub(crate) fn check_serializable_key_dimensions<E: serde::ser::Error>(
+    lwe_dimension: LweDimension,
+    polynomial_size: PolynomialSize,
+) -> Result<(), E> {
+    if has_insecure_test_dimensions(lwe_dimension, polynomial_size) {
+        return Err(E::custom(
+            "Keys generated from the insecure test parameters can not be serialized",
+        ));
+    }
+    Ok(())
+}
+
+/// Serializes the parameters of a key, see [`check_serializable_key_dimensions`].
+pub(crate) fn serialize_key_parameters<S: serde::Serializer>(
+    parameters: &ShortintParameterSet,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    check_serializable_key_dimensions(parameters.lwe_dimension(), parameters.polynomial_size())?;
+    parameters.serialize(serializer)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shortint::server_key::CompressedServerKey;
+    use crate::shortint::{gen_keys, CiphertextBig, CompressedPublicKeyBig, PublicKeyBig};
+
+    #[test]
+    fn test_insecure_test_parameters() {
+        for params in INSECURE_TEST_PARAMETERS_VEC {
+            let (cks, sks) = gen_keys(params);
+            let modulus = params.message_modulus.0 as u64;
+
+            for msg in 0..modulus {
+                let ct = cks.encrypt(msg);
+                let ct_res = sks.scalar_mul(&sks.unchecked_add(&ct, &ct), 3);
+                assert_eq!(cks.decrypt(&ct_res), (6 * msg) % modulus);
+
+                let serialized = bincode::serialize(&ct_res).unwrap();
+                let deserialized: CiphertextBig = bincode::deserialize(&serialized).unwrap();
+                assert_eq!(cks.decrypt(&deserialized), (6 * msg) % modulus);
+            }
+
+            assert!(bincode::serialize(&cks).is_err());
+            assert!(bincode::serialize(&sks).is_err());
+            assert!(bincode::serialize(&CompressedServerKey::new(&cks)).is_err());
+            assert!(bincode::serialize(&PublicKeyBig::new(&cks)).is_err());
+            assert!(bincode::serialize(&CompressedPublicKeyBig::new(&cks)).is_err());
+        }
+
+        // Keys of the secure parameters are not affected
+        let (cks, _sks) = gen_keys(PARAM_MESSAGE_1_CARRY_1);
+        assert!(bincode::serialize(&cks).is_ok());
+    }
+}
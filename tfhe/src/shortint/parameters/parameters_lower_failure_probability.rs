@@ -0,0 +1,129 @@
+//! Parameter sets with a PBS failure probability lower than the default sets.
+//!
+//! The default parameter sets have a PBS failure probability of about 2^-40. The sets of this
+//! module reuse the parameters of the default set having one more bit of carry, while keeping the
+//! requested carry space: encoding the same plaintext space in a twice larger space doubles the
+//! distance between two encoded values relative to the noise, so a failure requires a deviation
+//! of the noise twice as large. For a Gaussian noise this lowers the failure probability from
+//! 2^-40 to less than 2^-128, at the cost of the larger dimensions of the parent set.
+//!
+//! This failure probability is inferred from the one of the parent set and was not estimated,
+//! these sets are marked as [`unverified`](super::NamedParameters::unverified) in the catalog.
+//!
+//! The `_PBS_2M64` sets are currently the same as the `_PBS_2M128` sets, they are provided so
+//! applications can select parameters by their target without depending on how the target is
+//! reached.
+//!
+//! [`super::get_parameters`] selects a parameter set from the message and carry spaces and the
+//! target failure probability.
+
+use super::*;
+
+macro_rules! lower_failure_probability_parameters {
+    ($($name:ident = $parent:ident with CarryModulus($carry:literal)),* $(,)?) => {
+        $(
+            #[doc = concat!("Parameters of [`", stringify!($parent), "`] with a carry modulus of ")]
+            #[doc = concat!(stringify!($carry), ".")]
+            pub const $name: PBSParameters = PBSParameters {
+                carry_modulus: CarryModulus($carry),
+                ..$parent
+            };
+        )*
+    };
+}
+
+lower_failure_probability_parameters!(
+    PARAM_MESSAGE_1_CARRY_0_PBS_2M64 = PARAM_MESSAGE_1_CARRY_1 with CarryModulus(1),
+    PARAM_MESSAGE_1_CARRY_1_PBS_2M64 = PARAM_MESSAGE_1_CARRY_2 with CarryModulus(2),
+    PARAM_MESSAGE_1_CARRY_2_PBS_2M64 = PARAM_MESSAGE_1_CARRY_3 with CarryModulus(4),
+    PARAM_MESSAGE_1_CARRY_3_PBS_2M64 = PARAM_MESSAGE_1_CARRY_4 with CarryModulus(8),
+    PARAM_MESSAGE_1_CARRY_4_PBS_2M64 = PARAM_MESSAGE_1_CARRY_5 with CarryModulus(16),
+    PARAM_MESSAGE_1_CARRY_5_PBS_2M64 = PARAM_MESSAGE_1_CARRY_6 with CarryModulus(32),
+    PARAM_MESSAGE_1_CARRY_6_PBS_2M64 = PARAM_MESSAGE_1_CARRY_7 with CarryModulus(64),
+    PARAM_MESSAGE_2_CARRY_0_PBS_2M64 = PARAM_MESSAGE_2_CARRY_1 with CarryModulus(1),
+    PARAM_MESSAGE_2_CARRY_1_PBS_2M64 = PARAM_MESSAGE_2_CARRY_2 with CarryModulus(2),
+    PARAM_MESSAGE_2_CARRY_2_PBS_2M64 = PARAM_MESSAGE_2_CARRY_3 with CarryModulus(4),
+    PARAM_MESSAGE_2_CARRY_3_PBS_2M64 = PARAM_MESSAGE_2_CARRY_4 with CarryModulus(8),
+    PARAM_MESSAGE_2_CARRY_4_PBS_2M64 = PARAM_MESSAGE_2_CARRY_5 with CarryModulus(16),
+    PARAM_MESSAGE_2_CARRY_5_PBS_2M64 = PARAM_MESSAGE_2_CARRY_6 with CarryModulus(32),
+    PARAM_MESSAGE_3_CARRY_0_PBS_2M64 = PARAM_MESSAGE_3_CARRY_1 with CarryModulus(1),
+    PARAM_MESSAGE_3_CARRY_1_PBS_2M64 = PARAM_MESSAGE_3_CARRY_2 with CarryModulus(2),
+    PARAM_MESSAGE_3_CARRY_2_PBS_2M64 = PARAM_MESSAGE_3_CARRY_3 with CarryModulus(4),
+    PARAM_MESSAGE_3_CARRY_3_PBS_2M64 = PARAM_MESSAGE_3_CARRY_4 with CarryModulus(8),
+    PARAM_MESSAGE_3_CARRY_4_PBS_2M64 = PARAM_MESSAGE_3_CARRY_5 with CarryModulus(16),
+    PARAM_MESSAGE_4_CARRY_0_PBS_2M64 = PARAM_MESSAGE_4_CARRY_1 with CarryModulus(1),
+    PARAM_MESSAGE_4_CARRY_1_PBS_2M64 = PARAM_MESSAGE_4_CARRY_2 with CarryModulus(2),
+    PARAM_MESSAGE_4_CARRY_2_PBS_2M64 = PARAM_MESSAGE_4_CARRY_3 with CarryModulus(4),
+    PARAM_MESSAGE_4_CARRY_3_PBS_2M64 = PARAM_MESSAGE_4_CARRY_4 with CarryModulus(8),
+    PARAM_MESSAGE_5_CARRY_0_PBS_2M64 = PARAM_MESSAGE_5_CARRY_1 with CarryModulus(1),
+    PARAM_MESSAGE_5_CARRY_1_PBS_2M64 = PARAM_MESSAGE_5_CARRY_2 with CarryModulus(2),
+    PARAM_MESSAGE_5_CARRY_2_PBS_2M64 = PARAM_MESSAGE_5_CARRY_3 with CarryModulus(4),
+    PARAM_MESSAGE_6_CARRY_0_PBS_2M64 = PARAM_MESSAGE_6_CARRY_1 with CarryModulus(1),
+    PARAM_MESSAGE_6_CARRY_1_PBS_2M64 = PARAM_MESSAGE_6_CARRY_2 with CarryModulus(2),
+    PARAM_MESSAGE_7_CARRY_0_PBS_2M64 = PARAM_MESSAGE_7_CARRY_1 with CarryModulus(1),
+);
+
+lower_failure_probability_parameters!(
+    PARAM_MESSAGE_1_CARRY_0_PBS_2M128 = PARAM_MESSAGE_1_CARRY_1 with CarryModulus(1),
+    PARAM_MESSAGE_1_CARRY_1_PBS_2M128 = PARAM_MESSAGE_1_CARRY_2 with CarryModulus(2),
+    PARAM_MESSAGE_1_CARRY_2_PBS_2M128 = PARAM_MESSAGE_1_CARRY_3 with CarryModulus(4),
+    PARAM_MESSAGE_1_CARRY_3_PBS_2M128 = PARAM_MESSAGE_1_CARRY_4 with CarryModulus(8),
+    PARAM_MESSAGE_1_CARRY_4_PBS_2M128 = PARAM_MESSAGE_1_CARRY_5 with CarryModulus(16),
+    PARAM_MESSAGE_1_CARRY_5_PBS_2M128 = PARAM_MESSAGE_1_CARRY_6 with CarryModulus(32),
+    PARAM_MESSAGE_1_CARRY_6_PBS_2M128 = PARAM_MESSAGE_1_CARRY_7 with CarryModulus(64),
+    PARAM_MESSAGE_2_CARRY_0_PBS_2M128 = PARAM_MESSAGE_2_CARRY_1 with CarryModulus(1),
+    PARAM_MESSAGE_2_CARRY_1_PBS_2M128 = PARAM_MESSAGE_2_CARRY_2 with CarryModulus(2),
+    PARAM_MESSAGE_2_CARRY_2_PBS_2M128 = PARAM_MESSAGE_2_CARRY_3 with CarryModulus(4),
+    PARAM_MESSAGE_2_CARRY_3_PBS_2M128 = PARAM_MESSAGE_2_CARRY_4 with CarryModulus(8),
+    PARAM_MESSAGE_2_CARRY_4_PBS_2M128 = PARAM_MESSAGE_2_CARRY_5 with CarryModulus(16),
+    PARAM_MESSAGE_2_CARRY_5_PBS_2M128 = PARAM_MESSAGE_2_CARRY_6 with CarryModulus(32),
+    PARAM_MESSAGE_3_CARRY_0_PBS_2M128 = PARAM_MESSAGE_3_CARRY_1 with CarryModulus(1),
+    PARAM_MESSAGE_3_CARRY_1_PBS_2M128 = PARAM_MESSAGE_3_CARRY_2 with CarryModulus(2),
+    PARAM_MESSAGE_3_CARRY_2_PBS_2M128 = PARAM_MESSAGE_3_CARRY_3 with CarryModulus(4),
+    PARAM_MESSAGE_3_CARRY_3_PBS_2M128 = PARAM_MESSAGE_3_CARRY_4 with CarryModulus(8),
+    PARAM_MESSAGE_3_CARRY_4_PBS_2M128 = PARAM_MESSAGE_3_CARRY_5 with CarryModulus(16),
+    PARAM_MESSAGE_4_CARRY_0_PBS_2M128 = PARAM_MESSAGE_4_CARRY_1 with CarryModulus(1),
+    PARAM_MESSAGE_4_CARRY_1_PBS_2M128 = PARAM_MESSAGE_4_CARRY_2 with CarryModulus(2),
+    PARAM_MESSAGE_4_CARRY_2_PBS_2M128 = PARAM_MESSAGE_4_CARRY_3 with CarryModulus(4),
+    PARAM_MESSAGE_4_CARRY_3_PBS_2M128 = PARAM_MESSAGE_4_CARRY_4 with CarryModulus(8),
+    PARAM_MESSAGE_5_CARRY_0_PBS_2M128 = PARAM_MESSAGE_5_CARRY_1 with CarryModulus(1),
+    PARAM_MESSAGE_5_CARRY_1_PBS_2M128 = PARAM_MESSAGE_5_CARRY_2 with CarryModulus(2),
+    PARAM_MESSAGE_5_CARRY_2_PBS_2M128 = PARAM_MESSAGE_5_CARRY_3 with CarryModulus(4),
+    PARAM_MESSAGE_6_CARRY_0_PBS_2M128 = PARAM_MESSAGE_6_CARRY_1 with CarryModulus(1),
+    PARAM_MESSAGE_6_CARRY_1_PBS_2M128 = PARAM_MESSAGE_6_CARRY_2 with CarryModulus(2),
+    PARAM_MESSAGE_7_CARRY_0_PBS_2M128 = PARAM_MESSAGE_7_CARRY_1 with CarryModulus(1),
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lower_failure_probability_parameters() {
+        for (message_bits, carry_bits) in (1..=7u32)
+            .flat_map(|message_bits| (0..=7 - message_bits).map(move |carry| (message_bits, carry)))
+        {
+            let message_space = 1 << message_bits;
+            let carry_space = 1 << carry_bits;
+            let default_params =
+                get_parameters(message_space, carry_space, FailureProbability::Pow2M40).unwrap();
+            for failure_probability in [FailureProbability::Pow2M64, FailureProbability::Pow2M128] {
+                let params =
+                    get_parameters(message_space, carry_space, failure_probability).unwrap();
+                assert_eq!(params.message_modulus.0, message_space);
+                assert_eq!(params.carry_modulus.0, carry_space);
+                assert_ne!(params, default_params);
+            }
+        }
+
+        assert_eq!(
+            get_parameters(4, 4, FailureProbability::Pow2M64),
+            Some(PARAM_MESSAGE_2_CARRY_2_PBS_2M64)
+        );
+        assert_eq!(
+            get_parameters(4, 4, FailureProbability::Pow2M128),
+            Some(PARAM_MESSAGE_2_CARRY_2_PBS_2M128)
+        );
+        assert_eq!(get_parameters(4, 64, FailureProbability::Pow2M128), None);
+    }
+}
@@ -0,0 +1,128 @@
+//! Parameter sets with a power of two ciphertext modulus smaller than the native one.
+//!
+//! Ciphertexts are still stored in `u64`, but their values only use the most significant bits of
+//! each integer: the encoded plaintexts are scaled to the native torus when encrypting and scaled
+//! back when decrypting, so the PBS and the keyswitch are unchanged. Such parameters are mostly
+//! useful to experiment with the size of serialized ciphertexts and keys.
+//!
+//! The `_MODULUS_2_63` sets reuse the parameters of the default sets with a ciphertext modulus of
+//! 2^63. The standard deviations of the parameters are relative to the modulus, so the estimated
+//! security and failure probability are the same as those of the default sets.
+//!
+//! [`PARAM_MESSAGE_1_CARRY_1_MODULUS_2_32`] targets a ciphertext modulus of 2^32. It keeps the LWE
+//! dimension and noise of [`PARAM_MESSAGE_1_CARRY_2`], while its GLWE noise has an absolute
+//! standard deviation of 2^5 on the 32 bits of the modulus. The estimated standard deviation of
+//! the noise after a PBS and a keyswitch is about 2^-8, sixteen times smaller than half the
+//! distance between two encoded values.
+//!
+//! None of these guarantees were estimated for the sets of this module, they are marked as
+//! [`unverified`](super::NamedParameters::unverified) in the catalog.
+
+use super::*;
+
+pub const CIPHERTEXT_MODULUS_2_63: CiphertextModulus =
+    match CiphertextModulus::try_new_power_of_2(63) {
+        Ok(ciphertext_modulus) => ciphertext_modulus,
+        Err(_) => panic!("2^63 is a valid ciphertext modulus for u64"),
+    };
+
+pub const CIPHERTEXT_MODULUS_2_32: CiphertextModulus =
+    match CiphertextModulus::try_new_power_of_2(32) {
+        Ok(ciphertext_modulus) => ciphertext_modulus,
+        Err(_) => panic!("2^32 is a valid ciphertext modulus for u64"),
+    };
+
+macro_rules! modulus_2_63_parameters {
+    ($($name:ident = $parent:ident),* $(,)?) => {
+        $(
+            #[doc = concat!("Parameters of [`", stringify!($parent), "`] with a ciphertext ")]
+            #[doc = "modulus of 2^63."]
+            pub const $name: PBSParameters = PBSParameters {
+                ciphertext_modulus: CIPHERTEXT_MODULUS_2_63,
+                ..$parent
+            };
+        )*
+    };
+}
+
+modulus_2_63_parameters!(
+    PARAM_MESSAGE_1_CARRY_1_MODULUS_2_63 = PARAM_MESSAGE_1_CARRY_1,
+    PARAM_MESSAGE_1_CARRY_2_MODULUS_2_63 = PARAM_MESSAGE_1_CARRY_2,
+    PARAM_MESSAGE_1_CARRY_3_MODULUS_2_63 = PARAM_MESSAGE_1_CARRY_3,
+    PARAM_MESSAGE_1_CARRY_4_MODULUS_2_63 = PARAM_MESSAGE_1_CARRY_4,
+    PARAM_MESSAGE_1_CARRY_5_MODULUS_2_63 = PARAM_MESSAGE_1_CARRY_5,
+    PARAM_MESSAGE_1_CARRY_6_MODULUS_2_63 = PARAM_MESSAGE_1_CARRY_6,
+    PARAM_MESSAGE_1_CARRY_7_MODULUS_2_63 = PARAM_MESSAGE_1_CARRY_7,
+    PARAM_MESSAGE_2_CARRY_1_MODULUS_2_63 = PARAM_MESSAGE_2_CARRY_1,
+    PARAM_MESSAGE_2_CARRY_2_MODULUS_2_63 = PARAM_MESSAGE_2_CARRY_2,
+    PARAM_MESSAGE_2_CARRY_3_MODULUS_2_63 = PARAM_MESSAGE_2_CARRY_3,
+    PARAM_MESSAGE_2_CARRY_4_MODULUS_2_63 = PARAM_MESSAGE_2_CARRY_4,
+    PARAM_MESSAGE_2_CARRY_5_MODULUS_2_63 = PARAM_MESSAGE_2_CARRY_5,
+    PARAM_MESSAGE_2_CARRY_6_MODULUS_2_63 = PARAM_MESSAGE_2_CARRY_6,
+    PARAM_MESSAGE_3_CARRY_1_MODULUS_2_63 = PARAM_MESSAGE_3_CARRY_1,
+    PARAM_MESSAGE_3_CARRY_2_MODULUS_2_63 = PARAM_MESSAGE_3_CARRY_2,
+    PARAM_MESSAGE_3_CARRY_3_MODULUS_2_63 = PARAM_MESSAGE_3_CARRY_3,
+    PARAM_MESSAGE_3_CARRY_4_MODULUS_2_63 = PARAM_MESSAGE_3_CARRY_4,
+    PARAM_MESSAGE_3_CARRY_5_MODULUS_2_63 = PARAM_MESSAGE_3_CARRY_5,
+    PARAM_MESSAGE_4_CARRY_1_MODULUS_2_63 = PARAM_MESSAGE_4_CARRY_1,
+    PARAM_MESSAGE_4_CARRY_2_MODULUS_2_63 = PARAM_MESSAGE_4_CARRY_2,
+    PARAM_MESSAGE_4_CARRY_3_MODULUS_2_63 = PARAM_MESSAGE_4_CARRY_3,
+    PARAM_MESSAGE_4_CARRY_4_MODULUS_2_63 = PARAM_MESSAGE_4_CARRY_4,
+    PARAM_MESSAGE_5_CARRY_1_MODULUS_2_63 = PARAM_MESSAGE_5_CARRY_1,
+    PARAM_MESSAGE_5_CARRY_2_MODULUS_2_63 = PARAM_MESSAGE_5_CARRY_2,
+    PARAM_MESSAGE_5_CARRY_3_MODULUS_2_63 = PARAM_MESSAGE_5_CARRY_3,
+    PARAM_MESSAGE_6_CARRY_1_MODULUS_2_63 = PARAM_MESSAGE_6_CARRY_1,
+    PARAM_MESSAGE_6_CARRY_2_MODULUS_2_63 = PARAM_MESSAGE_6_CARRY_2,
+    PARAM_MESSAGE_7_CARRY_1_MODULUS_2_63 = PARAM_MESSAGE_7_CARRY_1,
+);
+
+/// Vector containing the parameter sets of [`WITH_CARRY_PARAMETERS_VEC`] with a ciphertext
+/// modulus of 2^63
+pub const MODULUS_2_63_WITH_CARRY_PARAMETERS_VEC: [PBSParameters; 28] = [
+    PARAM_MESSAGE_1_CARRY_1_MODULUS_2_63,
+    PARAM_MESSAGE_1_CARRY_2_MODULUS_2_63,
+    PARAM_MESSAGE_1_CARRY_3_MODULUS_2_63,
+    PARAM_MESSAGE_1_CARRY_4_MODULUS_2_63,
+    PARAM_MESSAGE_1_CARRY_5_MODULUS_2_63,
+    PARAM_MESSAGE_1_CARRY_6_MODULUS_2_63,
+    PARAM_MESSAGE_1_CARRY_7_MODULUS_2_63,
+    PARAM_MESSAGE_2_CARRY_1_MODULUS_2_63,
+    PARAM_MESSAGE_2_CARRY_2_MODULUS_2_63,
+    PARAM_MESSAGE_2_CARRY_3_MODULUS_2_63,
+    PARAM_MESSAGE_2_CARRY_4_MODULUS_2_63,
+    PARAM_MESSAGE_2_CARRY_5_MODULUS_2_63,
+    PARAM_MESSAGE_2_CARRY_6_MODULUS_2_63,
+    PARAM_MESSAGE_3_CARRY_1_MODULUS_2_63,
+    PARAM_MESSAGE_3_CARRY_2_MODULUS_2_63,
+    PARAM_MESSAGE_3_CARRY_3_MODULUS_2_63,
+    PARAM_MESSAGE_3_CARRY_4_MODULUS_2_63,
+    PARAM_MESSAGE_3_CARRY_5_MODULUS_2_63,
+    PARAM_MESSAGE_4_CARRY_1_MODULUS_2_63,
+    PARAM_MESSAGE_4_CARRY_2_MODULUS_2_63,
+    PARAM_MESSAGE_4_CARRY_3_MODULUS_2_63,
+    PARAM_MESSAGE_4_CARRY_4_MODULUS_2_63,
+    PARAM_MESSAGE_5_CARRY_1_MODULUS_2_63,
+    PARAM_MESSAGE_5_CARRY_2_MODULUS_2_63,
+    PARAM_MESSAGE_5_CARRY_3_MODULUS_2_63,
+    PARAM_MESSAGE_6_CARRY_1_MODULUS_2_63,
+    PARAM_MESSAGE_6_CARRY_2_MODULUS_2_63,
+    PARAM_MESSAGE_7_CARRY_1_MODULUS_2_63,
+];
+
+/// Nominal parameters with 1 bit of message, 1 bit of carry and a ciphertext modulus of 2^32.
+pub const PARAM_MESSAGE_1_CARRY_1_MODULUS_2_32: PBSParameters = PBSParameters {
+    lwe_dimension: LweDimension(742),
+    glwe_dimension: GlweDimension(1),
+    polynomial_size: PolynomialSize(2048),
+    lwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(0.000007069849454709433)),
+    glwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(0.000000007450580596923828)),
+    pbs_base_log: DecompositionBaseLog(6),
+    pbs_level: DecompositionLevelCount(3),
+    ks_level: DecompositionLevelCount(5),
+    ks_base_log: DecompositionBaseLog(3),
+    message_modulus: MessageModulus(2),
+    carry_modulus: CarryModulus(2),
+    ciphertext_modulus: CIPHERTEXT_MODULUS_2_32,
+    encryption_key_choice: EncryptionKeyChoice::Big,
+    secret_key_distribution: SecretKeyDistribution::Binary,
+};
@@ -0,0 +1,268 @@
+//! Parameter sets for ciphertexts on a 32 bits torus, i.e. stored in `u32`.
+//!
+//! Ciphertexts and keys on a 32 bits torus are half the size of the `u64` ones and their FFT is
+//! computed on smaller integers, which matters on embedded and WASM targets. The price is a much
+//! smaller noise budget: the GLWE noise can not go below the 32 bits of the modulus, so only a
+//! single bit of message fits with a reasonable failure probability.
+//!
+//! The sets of this module reuse the values of the sets of the `boolean` module, which already
+//! computes its gates on `u32`, for 1 bit of message and no carry: the distance between two
+//! encoded values is then a quarter of the torus, the same as the one of the boolean encoding.
+//!
+//! The shortint keys and ciphertexts are only implemented on `u64`, these parameters are meant to
+//! be used with the primitives of [`core_crypto`](crate::core_crypto). The [`TorusParameters`]
+//! trait gives access to the common parameters of the `u32` and `u64` sets, so that code generic
+//! over the torus can be dispatched to either family.
+//!
+//! # Example
+//!
+//! ```rust
+//! use tfhe::core_crypto::prelude::*;
+//! use tfhe::shortint::parameters::{TorusParameters, PARAM_TORUS32_MESSAGE_1_CARRY_0};
+//!
+//! let params = PARAM_TORUS32_MESSAGE_1_CARRY_0;
+//!
+//! let mut seeder = new_seeder();
+//! let mut secret_generator =
+//!     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+//! let mut encryption_generator =
+//!     EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed(), seeder.as_mut());
+//!
+//! let lwe_secret_key =
+//!     LweSecretKey::generate_new_binary(params.lwe_dimension, &mut secret_generator);
+//!
+//! let ct: LweCiphertextOwned<u32> = allocate_and_encrypt_new_lwe_ciphertext(
+//!     &lwe_secret_key,
+//!     Plaintext(params.delta()),
+//!     params.lwe_noise_distribution,
+//!     params.ciphertext_modulus(),
+//!     &mut encryption_generator,
+//! );
+//!
+//! let decrypted = decrypt_lwe_ciphertext(&lwe_secret_key, &ct).0;
+//! assert_eq!(decrypted.wrapping_add(params.delta() / 2) / params.delta(), 1);
+//! ```
+
+use super::*;
+use crate::core_crypto::commons::math::torus::UnsignedTorus;
+use crate::core_crypto::commons::numeric::CastFrom;
+
+/// Parameters shared by the parameter sets of all the torus scalars.
+pub trait TorusParameters: Copy {
+    /// The unsigned integer type storing the ciphertexts
+    type Scalar: UnsignedTorus;
+
+    fn ciphertext_modulus(&self) -> CiphertextModulus<Self::Scalar>;
+
+    fn message_modulus(&self) -> MessageModulus;
+
+    fn carry_modulus(&self) -> CarryModulus;
+
+    /// Returns the scaling factor of the encoded messages, keeping one bit of padding.
+    fn delta(&self) -> Self::Scalar {
+        let modulus = (self.message_modulus().0 * self.carry_modulus().0) as u128;
+        (Self::Scalar::ONE << (Self::Scalar::BITS - 1))
+            / <Self::Scalar as CastFrom<u128>>::cast_from(modulus)
+    }
+}
+
+impl TorusParameters for PBSParameters {
+    type Scalar = u64;
+
+    fn ciphertext_modulus(&self) -> CiphertextModulus {
+        self.ciphertext_modulus
+    }
+
+    fn message_modulus(&self) -> MessageModulus {
+        self.message_modulus
+    }
+
+    fn carry_modulus(&self) -> CarryModulus {
+        self.carry_modulus
+    }
+}
+
+/// A structure defining a set of cryptographic parameters for ciphertexts on a 32 bits torus.
+///
+/// The fields are those of [`PBSParameters`], with a `u32` ciphertext modulus.
+#[derive(Serialize, Copy, Clone, Deserialize, Debug, PartialEq)]
+pub struct Torus32PBSParameters {
+    pub lwe_dimension: LweDimension,
+    pub glwe_dimension: GlweDimension,
+    pub polynomial_size: PolynomialSize,
+    pub lwe_noise_distribution: DynamicDistribution,
+    pub glwe_noise_distribution: DynamicDistribution,
+    pub pbs_base_log: DecompositionBaseLog,
+    pub pbs_level: DecompositionLevelCount,
+    pub ks_base_log: DecompositionBaseLog,
+    pub ks_level: DecompositionLevelCount,
+    pub message_modulus: MessageModulus,
+    pub carry_modulus: CarryModulus,
+    pub ciphertext_modulus: CiphertextModulus<u32>,
+    pub encryption_key_choice: EncryptionKeyChoice,
+}
+
+impl TorusParameters for Torus32PBSParameters {
+    type Scalar = u32;
+
+    fn ciphertext_modulus(&self) -> CiphertextModulus<u32> {
+        self.ciphertext_modulus
+    }
+
+    fn message_modulus(&self) -> MessageModulus {
+        self.message_modulus
+    }
+
+    fn carry_modulus(&self) -> CarryModulus {
+        self.carry_modulus
+    }
+}
+
+/// Parameters for 1 bit of message and no carry on a 32 bits torus, with the values of the
+/// default `boolean` parameters.
+pub const PARAM_TORUS32_MESSAGE_1_CARRY_0: Torus32PBSParameters = Torus32PBSParameters {
+    lwe_dimension: LweDimension(777),
+    glwe_dimension: GlweDimension(3),
+    polynomial_size: PolynomialSize(512),
+    lwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(0.000003725679281679651)),
+    glwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(
+        0.0000000000034525330484572114,
+    )),
+    pbs_base_log: DecompositionBaseLog(18),
+    pbs_level: DecompositionLevelCount(1),
+    ks_base_log: DecompositionBaseLog(4),
+    ks_level: DecompositionLevelCount(3),
+    message_modulus: MessageModulus(2),
+    carry_modulus: CarryModulus(1),
+    ciphertext_modulus: CiphertextModulus::new_native(),
+    encryption_key_choice: EncryptionKeyChoice::Small,
+};
+
+/// Parameters for 1 bit of message and no carry on a 32 bits torus, with the values of the
+/// `boolean` parameters of the TFHE library.
+pub const PARAM_TORUS32_MESSAGE_1_CARRY_0_TFHE_LIB: Torus32PBSParameters = Torus32PBSParameters {
+    lwe_dimension: LweDimension(830),
+    glwe_dimension: GlweDimension(2),
+    polynomial_size: PolynomialSize(1024),
+    lwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(0.000001412290588219445)),
+    glwe_noise_distribution: DynamicDistribution::Gaussian(StandardDev(
+        0.00000000000000029403601535432533,
+    )),
+    pbs_base_log: DecompositionBaseLog(23),
+    pbs_level: DecompositionLevelCount(1),
+    ks_base_log: DecompositionBaseLog(5),
+    ks_level: DecompositionLevelCount(3),
+    message_modulus: MessageModulus(2),
+    carry_modulus: CarryModulus(1),
+    ciphertext_modulus: CiphertextModulus::new_native(),
+    encryption_key_choice: EncryptionKeyChoice::Small,
+};
+
+/// Vector containing all the parameter sets on a 32 bits torus
+pub const TORUS32_PARAMETERS_VEC: [Torus32PBSParameters; 2] = [
+    PARAM_TORUS32_MESSAGE_1_CARRY_0,
+    PARAM_TORUS32_MESSAGE_1_CARRY_0_TFHE_LIB,
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core_crypto::prelude::*;
+
+    #[test]
+    fn test_torus32_parameters_pbs_keyswitch() {
+        let mut seeder = new_seeder();
+        let mut secret_generator =
+            SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+        let mut encryption_generator = EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(
+            seeder.seed(),
+            seeder.as_mut(),
+        );
+
+        for params in TORUS32_PARAMETERS_VEC {
+            let ciphertext_modulus = params.ciphertext_modulus();
+            let delta = params.delta();
+            assert_eq!(delta, 1 << 30);
+
+            let small_lwe_sk =
+                LweSecretKey::generate_new_binary(params.lwe_dimension, &mut secret_generator);
+            let glwe_sk = GlweSecretKey::generate_new_binary(
+                params.glwe_dimension,
+                params.polynomial_size,
+                &mut secret_generator,
+            );
+            let big_lwe_sk = glwe_sk.clone().into_lwe_secret_key();
+
+            let bsk = par_allocate_and_generate_new_lwe_bootstrap_key(
+                &small_lwe_sk,
+                &glwe_sk,
+                params.pbs_base_log,
+                params.pbs_level,
+                params.glwe_noise_distribution,
+                ciphertext_modulus,
+                &mut encryption_generator,
+            );
+            let mut fourier_bsk = FourierLweBootstrapKey::new(
+                bsk.input_lwe_dimension(),
+                bsk.glwe_size(),
+                bsk.polynomial_size(),
+                bsk.decomposition_base_log(),
+                bsk.decomposition_level_count(),
+            );
+            convert_standard_lwe_bootstrap_key_to_fourier(&bsk, &mut fourier_bsk);
+
+            let ksk = allocate_and_generate_new_lwe_keyswitch_key(
+                &big_lwe_sk,
+                &small_lwe_sk,
+                params.ks_base_log,
+                params.ks_level,
+                params.lwe_noise_distribution,
+                ciphertext_modulus,
+                &mut encryption_generator,
+            );
+
+            // Accumulator of the negation, the two boxes hold not(0) and not(1)
+            let polynomial_size = params.polynomial_size.0;
+            let half_box_size = polynomial_size / 4;
+            let mut accumulator = vec![0u32; polynomial_size];
+            accumulator[..polynomial_size / 2].fill(delta);
+            accumulator[..half_box_size]
+                .iter_mut()
+                .for_each(|a| *a = a.wrapping_neg());
+            accumulator.rotate_left(half_box_size);
+            let accumulator = allocate_and_trivially_encrypt_new_glwe_ciphertext(
+                params.glwe_dimension.to_glwe_size(),
+                &PlaintextList::from_container(accumulator),
+                ciphertext_modulus,
+            );
+
+            for msg in 0..2u32 {
+                let ct = allocate_and_encrypt_new_lwe_ciphertext(
+                    &small_lwe_sk,
+                    Plaintext(msg * delta),
+                    params.lwe_noise_distribution,
+                    ciphertext_modulus,
+                    &mut encryption_generator,
+                );
+
+                let mut pbs_ct = LweCiphertext::new(
+                    0u32,
+                    big_lwe_sk.lwe_dimension().to_lwe_size(),
+                    ciphertext_modulus,
+                );
+                programmable_bootstrap_lwe_ciphertext(&ct, &mut pbs_ct, &accumulator, &fourier_bsk);
+
+                let mut ks_ct = LweCiphertext::new(
+                    0u32,
+                    small_lwe_sk.lwe_dimension().to_lwe_size(),
+                    ciphertext_modulus,
+                );
+                keyswitch_lwe_ciphertext(&ksk, &pbs_ct, &mut ks_ct);
+
+                let decrypted = decrypt_lwe_ciphertext(&small_lwe_sk, &ks_ct).0;
+                let decoded = (decrypted.wrapping_add(delta / 2) / delta) % 2;
+                assert_eq!(decoded, 1 - msg);
+            }
+        }
+    }
+}
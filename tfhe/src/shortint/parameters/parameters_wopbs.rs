@@ -4,10 +4,13 @@ pub use crate::core_crypto::commons::dispersion::{DispersionParameter, StandardD
 pub use crate::core_crypto::commons::parameters::{
     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
 };
+use crate::shortint::parameters::parameters_wopbs_message_carry::ALL_PARAMETER_VEC_WOPBS;
 use crate::shortint::parameters::{
-    CarryModulus, CiphertextModulus, EncryptionKeyChoice, MessageModulus,
+    CarryModulus, CiphertextModulus, EncryptionKeyChoice, MessageModulus, PBSParameters,
+    ParameterError, WopbsParametersBuilder,
 };
 use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
 
 /// A structure defining the set of cryptographic parameters for homomorphic integer circuit
 /// evaluation, this structure contains parameters to exclusively perform a so-called Wopbs.
@@ -33,6 +36,331 @@ pub struct WopbsParameters {
     pub encryption_key_choice: EncryptionKeyChoice,
 }
 
+impl WopbsParameters {
+    /// Returns whether these parameters can be used along the given [`PBSParameters`] in a
+    /// [`ShortintParameterSet`](crate::shortint::parameters::ShortintParameterSet).
+    ///
+    /// Both parameter sets need to have the same message modulus, carry modulus, ciphertext
+    /// modulus and encryption key choice. See [`WopbsParameters::check_compatibility`] for a more
+    /// thorough analysis.
+    pub fn compatible_with(&self, pbs_params: &PBSParameters) -> bool {
+        self.message_modulus == pbs_params.message_modulus
+            && self.carry_modulus == pbs_params.carry_modulus
+            && self.ciphertext_modulus == pbs_params.ciphertext_modulus
+            && self.encryption_key_choice == pbs_params.encryption_key_choice
+    }
+
+    /// Returns the built-in WoPBS parameters compatible with the given [`PBSParameters`], if any.
+    ///
+    /// When several sets are compatible, the first one of [`ALL_PARAMETER_VEC_WOPBS`] is returned.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::parameters_wopbs_message_carry::WOPBS_PARAM_MESSAGE_2_CARRY_2;
+    /// use tfhe::shortint::parameters::{
+    ///     ShortintParameterSet, WopbsParameters, PARAM_MESSAGE_2_CARRY_2,
+    /// };
+    ///
+    /// let wopbs_params = WopbsParameters::recommended_for(&PARAM_MESSAGE_2_CARRY_2).unwrap();
+    /// assert_eq!(wopbs_params, WOPBS_PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let params = ShortintParameterSet::try_new_pbs_and_wopbs_param_set((
+    ///     PARAM_MESSAGE_2_CARRY_2,
+    ///     wopbs_params,
+    /// ));
+    /// assert!(params.is_ok());
+    /// ```
+    pub fn recommended_for(pbs_params: &PBSParameters) -> Option<Self> {
+        ALL_PARAMETER_VEC_WOPBS
+            .into_iter()
+            .find(|wopbs_params| wopbs_params.compatible_with(pbs_params))
+    }
+
+    /// Checks that these parameters can be used along the given [`PBSParameters`], returning the
+    /// estimated noise of the WoPBS circuit.
+    ///
+    /// On top of the checks of [`WopbsParameters::compatible_with`], the parameters have to be
+    /// consistent (see [`WopbsParametersBuilder::build`]) and the noise estimated by
+    /// [`WopbsParameters::estimate_noise`] has to stay within the decoding margins.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::parameters_wopbs::WopbsCompatibilityError;
+    /// use tfhe::shortint::parameters::parameters_wopbs_message_carry::{
+    ///     WOPBS_PARAM_MESSAGE_2_CARRY_2, WOPBS_PARAM_MESSAGE_3_CARRY_3,
+    /// };
+    /// use tfhe::shortint::parameters::{StandardDev, PARAM_MESSAGE_2_CARRY_2};
+    ///
+    /// assert!(WOPBS_PARAM_MESSAGE_2_CARRY_2
+    ///     .check_compatibility(&PARAM_MESSAGE_2_CARRY_2)
+    ///     .is_ok());
+    ///
+    /// assert!(matches!(
+    ///     WOPBS_PARAM_MESSAGE_3_CARRY_3.check_compatibility(&PARAM_MESSAGE_2_CARRY_2),
+    ///     Err(WopbsCompatibilityError::MessageModulusMismatch { .. })
+    /// ));
+    ///
+    /// let mut noisy_params = WOPBS_PARAM_MESSAGE_2_CARRY_2;
+    /// noisy_params.lwe_modular_std_dev = StandardDev(0.001);
+    /// assert!(matches!(
+    ///     noisy_params.check_compatibility(&PARAM_MESSAGE_2_CARRY_2),
+    ///     Err(WopbsCompatibilityError::NoiseTooLarge(_))
+    /// ));
+    /// ```
+    pub fn check_compatibility(
+        &self,
+        pbs_params: &PBSParameters,
+    ) -> Result<WopbsNoiseEstimate, WopbsCompatibilityError> {
+        if self.message_modulus != pbs_params.message_modulus {
+            return Err(WopbsCompatibilityError::MessageModulusMismatch {
+                pbs: pbs_params.message_modulus,
+                wopbs: self.message_modulus,
+            });
+        }
+        if self.carry_modulus != pbs_params.carry_modulus {
+            return Err(WopbsCompatibilityError::CarryModulusMismatch {
+                pbs: pbs_params.carry_modulus,
+                wopbs: self.carry_modulus,
+            });
+        }
+        if self.ciphertext_modulus != pbs_params.ciphertext_modulus {
+            return Err(WopbsCompatibilityError::CiphertextModulusMismatch {
+                pbs: pbs_params.ciphertext_modulus,
+                wopbs: self.ciphertext_modulus,
+            });
+        }
+        if self.encryption_key_choice != pbs_params.encryption_key_choice {
+            return Err(WopbsCompatibilityError::EncryptionKeyChoiceMismatch {
+                pbs: pbs_params.encryption_key_choice,
+                wopbs: self.encryption_key_choice,
+            });
+        }
+
+        WopbsParametersBuilder::from(*self).build()?;
+
+        let estimate = self.estimate_noise(pbs_params);
+        if !estimate.is_within_margins() {
+            return Err(WopbsCompatibilityError::NoiseTooLarge(estimate));
+        }
+
+        Ok(estimate)
+    }
+
+    /// Estimates the noise of the WoPBS circuit applied to a ciphertext output by a PBS computed
+    /// with the given [`PBSParameters`].
+    ///
+    /// The estimate uses the usual variance formulas of the keyswitch, modulus switch and external
+    /// product with binary keys, assuming independent noises. It is a rough sanity check of the
+    /// parameters, not a failure probability guarantee.
+    pub fn estimate_noise(&self, pbs_params: &PBSParameters) -> WopbsNoiseEstimate {
+        let precision_bits = (self.message_modulus.0 * self.carry_modulus.0)
+            .next_power_of_two()
+            .ilog2() as i32;
+
+        let big_lwe_dimension = self.glwe_dimension.0 * self.polynomial_size.0;
+        let lwe_variance = self.lwe_modular_std_dev.get_variance();
+
+        // The input ciphertext is output by a PBS with the PBS parameters, then keyswitched to the
+        // big WoPBS key with the decomposition of the PBS parameters
+        let input_variance = pbs_params.lwe_dimension.0 as f64
+            * external_product_variance(
+                pbs_params.glwe_dimension,
+                pbs_params.polynomial_size,
+                pbs_params.pbs_base_log,
+                pbs_params.pbs_level,
+                pbs_params.glwe_noise_distribution.get_variance(),
+            )
+            + keyswitch_variance(
+                pbs_params.glwe_dimension.0 * pbs_params.polynomial_size.0,
+                pbs_params.ks_base_log,
+                pbs_params.ks_level,
+                lwe_variance,
+            );
+
+        // The bits are extracted starting from the least significant one, which is shifted to the
+        // most significant position (amplifying the noise) before being keyswitched to the small
+        // WoPBS key and bootstrapped
+        let bit_extraction_variance = 4f64.powi(precision_bits - 1) * input_variance
+            + keyswitch_variance(
+                big_lwe_dimension,
+                self.ks_base_log,
+                self.ks_level,
+                lwe_variance,
+            )
+            + modulus_switch_variance(self.lwe_dimension, self.polynomial_size);
+
+        // The circuit bootstrapping computes a PBS and a functional packing keyswitch per level
+        // of the output GGSW ciphertexts
+        let ggsw_variance = self.lwe_dimension.0 as f64
+            * external_product_variance(
+                self.glwe_dimension,
+                self.polynomial_size,
+                self.pbs_base_log,
+                self.pbs_level,
+                self.glwe_modular_std_dev.get_variance(),
+            )
+            + keyswitch_variance(
+                big_lwe_dimension,
+                self.pfks_base_log,
+                self.pfks_level,
+                self.pfks_modular_std_dev.get_variance(),
+            );
+
+        // The vertical packing computes one external product per extracted bit
+        let vertical_packing_variance = precision_bits as f64
+            * external_product_variance(
+                self.glwe_dimension,
+                self.polynomial_size,
+                self.cbs_base_log,
+                self.cbs_level,
+                ggsw_variance,
+            );
+
+        WopbsNoiseEstimate {
+            // An extracted bit is encoded on the most significant bit of the torus
+            bit_extraction_log2_noise_ratio: bit_extraction_variance.log2() / 2. + 2.,
+            // The output has a bit of padding on top of the message and carry bits
+            vertical_packing_log2_noise_ratio: vertical_packing_variance.log2() / 2.
+                + (precision_bits + 2) as f64,
+        }
+    }
+}
+
+/// Minimum ratio, as a base 2 logarithm, between the decoding margin and the standard deviation of
+/// the noise accepted by [`WopbsParameters::check_compatibility`].
+pub const WOPBS_MIN_LOG2_NOISE_MARGIN: f64 = 2.0;
+
+/// Noise of the WoPBS circuit estimated by [`WopbsParameters::estimate_noise`].
+///
+/// Both values are the base 2 logarithm of the ratio between the standard deviation of the noise
+/// and the decoding margin, the lower the better.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct WopbsNoiseEstimate {
+    /// Noise when extracting the bits of the input ciphertext
+    pub bit_extraction_log2_noise_ratio: f64,
+    /// Noise of the output of the vertical packing
+    pub vertical_packing_log2_noise_ratio: f64,
+}
+
+impl WopbsNoiseEstimate {
+    /// Returns whether both noises are [`WOPBS_MIN_LOG2_NOISE_MARGIN`] bits below their decoding
+    /// margins.
+    pub fn is_within_margins(&self) -> bool {
+        self.bit_extraction_log2_noise_ratio <= -WOPBS_MIN_LOG2_NOISE_MARGIN
+            && self.vertical_packing_log2_noise_ratio <= -WOPBS_MIN_LOG2_NOISE_MARGIN
+    }
+}
+
+/// Error returned by [`WopbsParameters::check_compatibility`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum WopbsCompatibilityError {
+    MessageModulusMismatch {
+        pbs: MessageModulus,
+        wopbs: MessageModulus,
+    },
+    CarryModulusMismatch {
+        pbs: CarryModulus,
+        wopbs: CarryModulus,
+    },
+    CiphertextModulusMismatch {
+        pbs: CiphertextModulus,
+        wopbs: CiphertextModulus,
+    },
+    EncryptionKeyChoiceMismatch {
+        pbs: EncryptionKeyChoice,
+        wopbs: EncryptionKeyChoice,
+    },
+    /// The WoPBS parameters are not consistent
+    InvalidParameters(ParameterError),
+    /// The estimated noise of the WoPBS circuit is too close to the decoding margins
+    NoiseTooLarge(WopbsNoiseEstimate),
+}
+
+impl Display for WopbsCompatibilityError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MessageModulusMismatch { pbs, wopbs } => write!(
+                f,
+                "Mismatched message moduli: {} for the PBS, {} for the WoPBS",
+                pbs.0, wopbs.0
+            ),
+            Self::CarryModulusMismatch { pbs, wopbs } => write!(
+                f,
+                "Mismatched carry moduli: {} for the PBS, {} for the WoPBS",
+                pbs.0, wopbs.0
+            ),
+            Self::CiphertextModulusMismatch { pbs, wopbs } => write!(
+                f,
+                "Mismatched ciphertext moduli: {pbs} for the PBS, {wopbs} for the WoPBS"
+            ),
+            Self::EncryptionKeyChoiceMismatch { pbs, wopbs } => write!(
+                f,
+                "Mismatched encryption key choices: {pbs:?} for the PBS, {wopbs:?} for the WoPBS"
+            ),
+            Self::InvalidParameters(error) => write!(f, "Invalid WoPBS parameters: {error}"),
+            Self::NoiseTooLarge(estimate) => write!(
+                f,
+                "The estimated noise of the WoPBS is too large, the standard deviations are \
+                2^{:.1} (bit extraction) and 2^{:.1} (vertical packing) times the decoding \
+                margins, at most 2^-{WOPBS_MIN_LOG2_NOISE_MARGIN} is accepted",
+                estimate.bit_extraction_log2_noise_ratio,
+                estimate.vertical_packing_log2_noise_ratio
+            ),
+        }
+    }
+}
+
+impl std::error::Error for WopbsCompatibilityError {}
+
+impl From<ParameterError> for WopbsCompatibilityError {
+    fn from(error: ParameterError) -> Self {
+        Self::InvalidParameters(error)
+    }
+}
+
+/// Variance added by an external product with a GGSW ciphertext of the given noise variance, the
+/// decomposition rounding error included
+fn external_product_variance(
+    glwe_dimension: GlweDimension,
+    polynomial_size: PolynomialSize,
+    base_log: DecompositionBaseLog,
+    level: DecompositionLevelCount,
+    ggsw_variance: f64,
+) -> f64 {
+    let k = glwe_dimension.0 as f64;
+    let n = polynomial_size.0 as f64;
+    let base = 2f64.powi(base_log.0 as i32);
+    let level = level.0 as f64;
+
+    let key_term = level * (k + 1.) * n * (base * base + 2.) / 12. * ggsw_variance;
+    let rounding_term = (1. + k * n / 2.) / (12. * base.powf(2. * level));
+    key_term + rounding_term
+}
+
+/// Variance added by a keyswitch from a key of dimension `input_lwe_dimension`
+fn keyswitch_variance(
+    input_lwe_dimension: usize,
+    base_log: DecompositionBaseLog,
+    level: DecompositionLevelCount,
+    key_variance: f64,
+) -> f64 {
+    let n = input_lwe_dimension as f64;
+    let base = 2f64.powi(base_log.0 as i32);
+    let level = level.0 as f64;
+
+    let key_term = n * level * (base * base + 2.) / 12. * key_variance;
+    let rounding_term = n / 2. / (12. * base.powf(2. * level));
+    key_term + rounding_term
+}
+
+/// Variance added by the modulus switch to `2 * polynomial_size` before a blind rotation
+fn modulus_switch_variance(lwe_dimension: LweDimension, polynomial_size: PolynomialSize) -> f64 {
+    let two_n = 2. * polynomial_size.0 as f64;
+    (lwe_dimension.0 as f64 / 2. + 1.) / (12. * two_n * two_n)
+}
+
 pub const ALL_PARAMETER_VEC_WOPBS_NORM2: [WopbsParameters; 31] = [
     WOPBS_PARAM_MESSAGE_1_NORM2_2,
     WOPBS_PARAM_MESSAGE_1_NORM2_4,
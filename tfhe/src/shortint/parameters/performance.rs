@@ -0,0 +1,208 @@
+//! Selection of parameter sets tuned for throughput or for latency.
+//!
+//! The same precision can be reached with parameter sets making different trade-offs:
+//!
+//! * the sets using the classic PBS minimize the total amount of work per PBS. They are the best
+//!   choice for throughput, when many independent PBS are computed in parallel, e.g. on the blocks
+//!   of radix integers or on batches of ciphertexts, as all the threads are already busy.
+//! * the sets using the multi-bit PBS with a grouping factor of 3 compute the steps of the blind
+//!   rotation of a group of key coefficients in parallel. A single PBS finishes sooner when spare
+//!   threads are available, for more work in total and a bootstrapping key more than twice as
+//!   large.
+//!
+//! Only the classic sets have estimated guarantees: the multi-bit sets are derived from them and
+//! are not vetted, see [`super::multi_bit`]. [`select_parameters`] therefore only returns a set
+//! for [`PerformanceTarget::Throughput`]; for [`PerformanceTarget::Latency`] it returns a
+//! [`SelectionError::Unvetted`] error holding the multi-bit set, which can be used deliberately
+//! for experiments.
+//!
+//! [`PerformanceTarget::recommended`] gives a rule of thumb to choose between the two targets.
+//!
+//! # Example
+//!
+//! ```rust
+//! use tfhe::shortint::parameters::performance::{
+//!     select_parameters, PerformanceTarget, SelectionError,
+//! };
+//! use tfhe::shortint::parameters::{
+//!     PARAM_MESSAGE_2_CARRY_2, PARAM_MULTI_BIT_MESSAGE_2_CARRY_2_GROUP_3,
+//! };
+//!
+//! // Many PBS at a time on 8 threads: the classic PBS does the least work
+//! let target = PerformanceTarget::recommended(64, 8);
+//! assert_eq!(target, PerformanceTarget::Throughput);
+//! let params = select_parameters(2, 2, target).unwrap();
+//! assert_eq!(params.pbs_parameters(), Some(PARAM_MESSAGE_2_CARRY_2));
+//!
+//! // A single PBS at a time: the multi-bit PBS would use the idle threads, but is not vetted
+//! let target = PerformanceTarget::recommended(1, 8);
+//! let Err(SelectionError::Unvetted(params)) = select_parameters(2, 2, target) else {
+//!     panic!("The multi-bit sets are not vetted");
+//! };
+//! assert_eq!(
+//!     params.multi_bit_parameters(),
+//!     Some(PARAM_MULTI_BIT_MESSAGE_2_CARRY_2_GROUP_3)
+//! );
+//!
+//! assert_eq!(
+//!     select_parameters(9, 0, PerformanceTarget::Throughput),
+//!     Err(SelectionError::UnsupportedPrecision {
+//!         message_bits: 9,
+//!         carry_bits: 0
+//!     })
+//! );
+//! ```
+
+use super::catalog::{ParametersCatalog, ParametersQuery};
+use super::*;
+
+/// The performance criterion a parameter set is tuned for.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PerformanceTarget {
+    /// Maximize the number of PBS computed per second.
+    Throughput,
+    /// Minimize the time taken by a single PBS.
+    Latency,
+}
+
+impl PerformanceTarget {
+    /// Recommends a target given the number of PBS usually computed at the same time by the
+    /// application and the number of threads available to compute them.
+    ///
+    /// The multi-bit PBS only pays off when threads would otherwise be idle, i.e. when there are
+    /// fewer concurrent PBS than threads.
+    pub fn recommended(concurrent_pbs_count: usize, thread_count: usize) -> Self {
+        if concurrent_pbs_count < thread_count {
+            Self::Latency
+        } else {
+            Self::Throughput
+        }
+    }
+}
+
+/// Error returned by [`select_parameters`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SelectionError {
+    /// No parameter set is tuned for the target with the requested precision
+    UnsupportedPrecision { message_bits: u32, carry_bits: u32 },
+    /// The only parameter set tuned for the target is not vetted, it is given so that it can be
+    /// used deliberately, e.g. to experiment
+    Unvetted(ShortintParameterSet),
+}
+
+impl std::fmt::Display for SelectionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnsupportedPrecision {
+                message_bits,
+                carry_bits,
+            } => write!(
+                f,
+                "No parameter set is tuned for {message_bits} bits of message and {carry_bits} \
+                bits of carry"
+            ),
+            Self::Unvetted(_) => write!(
+                f,
+                "The only parameter set tuned for this target has not been vetted"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for SelectionError {}
+
+/// Returns the parameter set tuned for `target` with the given message and carry precisions.
+///
+/// For [`PerformanceTarget::Throughput`], this is the cheapest set of the [`ParametersCatalog`]
+/// using the classic PBS whose guarantees were estimated, for any precision of the catalog. No
+/// vetted set is tuned for [`PerformanceTarget::Latency`]: the multi-bit set of the precision is
+/// returned in a [`SelectionError::Unvetted`] error, for 1, 2 or 3 bits of both message and carry.
+pub fn select_parameters(
+    message_bits: u32,
+    carry_bits: u32,
+    target: PerformanceTarget,
+) -> Result<ShortintParameterSet, SelectionError> {
+    let unsupported = SelectionError::UnsupportedPrecision {
+        message_bits,
+        carry_bits,
+    };
+
+    match target {
+        PerformanceTarget::Throughput => {
+            let query = ParametersQuery::new()
+                .message_bits(message_bits)
+                .carry_bits(carry_bits)
+                .encryption_key_choice(EncryptionKeyChoice::Big)
+                .ciphertext_modulus(CiphertextModulus::new_native());
+
+            ParametersCatalog::builtin()
+                .find(&query)
+                .map(|entry| entry.parameters)
+                .min_by_key(PBSParameters::estimated_pbs_cost)
+                .map(ShortintParameterSet::from)
+                .ok_or(unsupported)
+        }
+        PerformanceTarget::Latency => {
+            let params = match (message_bits, carry_bits) {
+                (1, 1) => PARAM_MULTI_BIT_MESSAGE_1_CARRY_1_GROUP_3,
+                (2, 2) => PARAM_MULTI_BIT_MESSAGE_2_CARRY_2_GROUP_3,
+                (3, 3) => PARAM_MULTI_BIT_MESSAGE_3_CARRY_3_GROUP_3,
+                _ => return Err(unsupported),
+            };
+            Err(SelectionError::Unvetted(params.into()))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_select_parameters() {
+        for entry in ParametersCatalog::builtin().iter() {
+            let (message_bits, carry_bits) = (entry.message_bits(), entry.carry_bits());
+            let params = select_parameters(message_bits, carry_bits, PerformanceTarget::Throughput)
+                .unwrap()
+                .pbs_parameters()
+                .unwrap();
+            assert_eq!(params.message_modulus, entry.parameters.message_modulus);
+            assert_eq!(params.carry_modulus, entry.parameters.carry_modulus);
+            assert!(!params.metadata().unwrap().unverified);
+        }
+        assert_eq!(
+            select_parameters(3, 3, PerformanceTarget::Throughput),
+            Ok(PARAM_MESSAGE_3_CARRY_3.into())
+        );
+
+        for bits in 1..=3 {
+            let Err(SelectionError::Unvetted(params)) =
+                select_parameters(bits, bits, PerformanceTarget::Latency)
+            else {
+                panic!("No vetted set is tuned for latency");
+            };
+            assert_eq!(params.message_modulus().0, 1 << bits);
+            assert_eq!(params.carry_modulus().0, 1 << bits);
+            assert!(params.multi_bit_parameters().is_some());
+        }
+
+        for target in [PerformanceTarget::Throughput, PerformanceTarget::Latency] {
+            assert_eq!(
+                select_parameters(4, 5, target),
+                Err(SelectionError::UnsupportedPrecision {
+                    message_bits: 4,
+                    carry_bits: 5
+                })
+            );
+        }
+        assert!(matches!(
+            select_parameters(4, 4, PerformanceTarget::Latency),
+            Err(SelectionError::UnsupportedPrecision { .. })
+        ));
+
+        assert_eq!(
+            PerformanceTarget::recommended(64, 8),
+            PerformanceTarget::Throughput
+        );
+    }
+}
@@ -0,0 +1,193 @@
+//! Provenance of the built-in parameter sets.
+//!
+//! Each entry of the [`ParametersCatalog`] records how its values were obtained. Most sets were
+//! generated by a tool, in which case the entry records the tool, the noise model their failure
+//! probability was computed with and the estimator which assessed their security level. The other
+//! sets either copy the values of another built-in set with a single change, or were chosen by
+//! hand. This metadata is serializable, so that security reviews can export it along with the
+//! parameters they audit.
+//!
+//! Fields which were not recorded when the values were generated are `None`, rather than filled
+//! after the fact.
+//!
+//! # Example
+//!
+//! ```rust
+//! use tfhe::shortint::parameters::{
+//!     ParameterProvenance, PolynomialSize, PARAM_MESSAGE_2_CARRY_2,
+//!     PARAM_MESSAGE_2_CARRY_2_PBS_2M64,
+//! };
+//!
+//! match PARAM_MESSAGE_2_CARRY_2.provenance() {
+//!     Some(ParameterProvenance::Generated(record)) => {
+//!         assert_eq!(record.generation_tool, "concrete-optimizer");
+//!         assert_eq!(record.security_estimator, "lattice-estimator");
+//!     }
+//!     _ => panic!("The default parameters are generated by the optimizer"),
+//! }
+//!
+//! assert_eq!(
+//!     PARAM_MESSAGE_2_CARRY_2_PBS_2M64.provenance(),
+//!     Some(ParameterProvenance::Derived {
+//!         parent: "PARAM_MESSAGE_2_CARRY_3",
+//!         change: "carry modulus of 4",
+//!     })
+//! );
+//!
+//! // Custom parameters have no provenance
+//! let mut custom = PARAM_MESSAGE_2_CARRY_2;
+//! custom.polynomial_size = PolynomialSize(4096);
+//! assert!(custom.provenance().is_none());
+//! ```
+
+use super::catalog::ParametersCatalog;
+use super::*;
+
+/// How the values of a parameter set were obtained.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Serialize)]
+pub enum ParameterProvenance {
+    /// The values were generated by a tool, which also estimated their guarantees
+    Generated(GenerationRecord),
+    /// The values are those of another built-in parameter set with a single change
+    Derived {
+        /// Name of the parameter set the values are copied from
+        parent: &'static str,
+        /// The value which differs from the one of the parent
+        change: &'static str,
+    },
+    /// The values were chosen by hand
+    HandChosen {
+        /// Why these values were chosen
+        rationale: &'static str,
+    },
+}
+
+/// The record of the generation of a parameter set by a tool, see
+/// [`ParameterProvenance::Generated`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Serialize)]
+pub struct GenerationRecord {
+    /// Tool which generated the values of the parameters
+    pub generation_tool: &'static str,
+    /// Version of the generation tool, if recorded
+    pub generation_tool_version: Option<&'static str>,
+    /// Noise model the failure probability of the parameters was computed with
+    pub noise_model: &'static str,
+    /// Date at which the values were generated, in the `YYYY-MM-DD` format, if recorded
+    pub date: Option<&'static str>,
+    /// Estimator which assessed the security level of the parameters
+    pub security_estimator: &'static str,
+    /// Commit of the security estimator, if recorded
+    pub security_estimator_commit: Option<&'static str>,
+}
+
+impl ParameterProvenance {
+    /// The provenance of the parameter sets generated for the 0.3 release.
+    ///
+    /// The failure probability assumes independent centered Gaussian errors, whose variances are
+    /// tracked through the keyswitch and the PBS.
+    pub const V0_3: Self = Self::Generated(GenerationRecord {
+        generation_tool: "concrete-optimizer",
+        generation_tool_version: None,
+        noise_model: "gaussian-variance",
+        date: None,
+        security_estimator: "lattice-estimator",
+        security_estimator_commit: None,
+    });
+}
+
+impl PBSParameters {
+    /// Returns the provenance of the parameters if they are those of a built-in parameter set,
+    /// `None` otherwise.
+    ///
+    /// Some built-in sets share the same values under different names, in that case the
+    /// provenance of the most recent version among them is returned.
+    pub fn provenance(&self) -> Option<ParameterProvenance> {
+        ParametersCatalog::builtin()
+            .iter()
+            .filter(|entry| entry.parameters == *self)
+            .max_by_key(|entry| entry.version)
+            .map(|entry| entry.provenance)
+    }
+}
+
+impl ShortintParameterSet {
+    /// Returns the provenance of the PBS parameters if they are those of a built-in parameter
+    /// set, `None` otherwise.
+    pub fn provenance(&self) -> Option<ParameterProvenance> {
+        self.pbs_parameters()?.provenance()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builtin_parameters_provenance() {
+        let catalog = ParametersCatalog::builtin();
+        for entry in catalog.iter() {
+            assert_eq!(entry.parameters.provenance(), Some(entry.provenance));
+            assert_eq!(
+                ShortintParameterSet::from(entry.parameters).provenance(),
+                Some(entry.provenance)
+            );
+
+            match entry.provenance {
+                ParameterProvenance::Generated(_) => {
+                    assert_eq!(entry.provenance, ParameterProvenance::V0_3);
+                    assert!(!entry.unverified, "{}", entry.name);
+                }
+                // A derived set only differs from its parent by its moduli
+                ParameterProvenance::Derived { parent, .. } => {
+                    let parent = catalog.get(parent).unwrap();
+                    assert_eq!(parent.provenance, ParameterProvenance::V0_3);
+                    assert_eq!(
+                        entry.parameters,
+                        PBSParameters {
+                            carry_modulus: entry.parameters.carry_modulus,
+                            ciphertext_modulus: entry.parameters.ciphertext_modulus,
+                            ..parent.parameters
+                        },
+                        "{}",
+                        entry.name
+                    );
+                    assert_ne!(entry.parameters, parent.parameters);
+                }
+                ParameterProvenance::HandChosen { .. } => {
+                    assert!(entry.unverified, "{}", entry.name)
+                }
+            }
+        }
+
+        assert_eq!(
+            PARAM_MESSAGE_2_CARRY_2.provenance(),
+            Some(ParameterProvenance::V0_3)
+        );
+        assert_eq!(
+            PARAM_SMALL_MESSAGE_1_CARRY_2.provenance(),
+            Some(ParameterProvenance::Derived {
+                parent: "PARAM_SMALL_MESSAGE_1_CARRY_3",
+                change: "carry modulus of 4",
+            })
+        );
+        assert_eq!(
+            PARAM_MESSAGE_2_CARRY_2_MODULUS_2_63.provenance(),
+            Some(ParameterProvenance::Derived {
+                parent: "PARAM_MESSAGE_2_CARRY_2",
+                change: "ciphertext modulus of 2^63",
+            })
+        );
+        assert!(matches!(
+            PARAM_MESSAGE_1_CARRY_1_MODULUS_2_32.provenance(),
+            Some(ParameterProvenance::HandChosen { .. })
+        ));
+
+        let mut custom = PARAM_MESSAGE_2_CARRY_2;
+        custom.lwe_dimension = LweDimension(1024);
+        assert_eq!(custom.provenance(), None);
+        assert_eq!(
+            ShortintParameterSet::from(PARAM_MULTI_BIT_MESSAGE_2_CARRY_2_GROUP_2).provenance(),
+            None
+        );
+    }
+}
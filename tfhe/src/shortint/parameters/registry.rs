@@ -0,0 +1,220 @@
+//! Global registry of parameter sets, looked up by name.
+//!
+//! The registry initially contains the PBS parameter sets of the
+//! [`ParametersCatalog`](super::catalog::ParametersCatalog) and the WoPBS parameter sets defined
+//! in [`crate::shortint::parameters`], each under the name of its constant. Applications can
+//! register their own sets, so that configuration files and tooling refer to any set by name.
+//!
+//! # Example
+//!
+//! ```rust
+//! use tfhe::shortint::parameters::registry;
+//! use tfhe::shortint::parameters::{
+//!     PBSParametersBuilder, PolynomialSize, PARAM_MESSAGE_2_CARRY_2,
+//! };
+//!
+//! let params = registry::lookup("PARAM_MESSAGE_2_CARRY_2").unwrap();
+//! assert_eq!(params.pbs_parameters(), Some(PARAM_MESSAGE_2_CARRY_2));
+//!
+//! let custom = PBSParametersBuilder::from(PARAM_MESSAGE_2_CARRY_2)
+//!     .polynomial_size(PolynomialSize(4096))
+//!     .build()
+//!     .unwrap();
+//! registry::register("MY_PARAM_MESSAGE_2_CARRY_2", custom).unwrap();
+//! assert_eq!(
+//!     registry::lookup("MY_PARAM_MESSAGE_2_CARRY_2").unwrap().pbs_parameters(),
+//!     Some(custom)
+//! );
+//!
+//! // Names cannot be reused
+//! assert!(registry::register("PARAM_MESSAGE_2_CARRY_2", custom).is_err());
+//! ```
+
+use super::catalog::ParametersCatalog;
+use super::parameters_wopbs::*;
+use super::parameters_wopbs_message_carry::*;
+use super::*;
+use once_cell::sync::Lazy;
+use std::sync::RwLock;
+
+macro_rules! named_wopbs_parameters {
+    ($($param:ident),* $(,)?) => {
+        &[$((stringify!($param), $param)),*]
+    };
+}
+
+const BUILTIN_WOPBS_PARAMETERS: &[(&str, WopbsParameters)] = named_wopbs_parameters!(
+    WOPBS_PARAM_MESSAGE_1_NORM2_2,
+    WOPBS_PARAM_MESSAGE_1_NORM2_4,
+    WOPBS_PARAM_MESSAGE_1_NORM2_6,
+    WOPBS_PARAM_MESSAGE_1_NORM2_8,
+    WOPBS_PARAM_MESSAGE_2_NORM2_2,
+    WOPBS_PARAM_MESSAGE_2_NORM2_4,
+    WOPBS_PARAM_MESSAGE_2_NORM2_6,
+    WOPBS_PARAM_MESSAGE_2_NORM2_8,
+    WOPBS_PARAM_MESSAGE_3_NORM2_2,
+    WOPBS_PARAM_MESSAGE_3_NORM2_4,
+    WOPBS_PARAM_MESSAGE_3_NORM2_6,
+    WOPBS_PARAM_MESSAGE_3_NORM2_8,
+    WOPBS_PARAM_MESSAGE_4_NORM2_2,
+    WOPBS_PARAM_MESSAGE_4_NORM2_4,
+    WOPBS_PARAM_MESSAGE_4_NORM2_6,
+    WOPBS_PARAM_MESSAGE_4_NORM2_8,
+    WOPBS_PARAM_MESSAGE_5_NORM2_2,
+    WOPBS_PARAM_MESSAGE_5_NORM2_4,
+    WOPBS_PARAM_MESSAGE_5_NORM2_6,
+    WOPBS_PARAM_MESSAGE_5_NORM2_8,
+    WOPBS_PARAM_MESSAGE_6_NORM2_2,
+    WOPBS_PARAM_MESSAGE_6_NORM2_4,
+    WOPBS_PARAM_MESSAGE_6_NORM2_6,
+    WOPBS_PARAM_MESSAGE_6_NORM2_8,
+    WOPBS_PARAM_MESSAGE_7_NORM2_2,
+    WOPBS_PARAM_MESSAGE_7_NORM2_4,
+    WOPBS_PARAM_MESSAGE_7_NORM2_6,
+    WOPBS_PARAM_MESSAGE_7_NORM2_8,
+    WOPBS_PARAM_MESSAGE_8_NORM2_2,
+    WOPBS_PARAM_MESSAGE_8_NORM2_4,
+    WOPBS_PARAM_MESSAGE_8_NORM2_6,
+    PARAM_4_BITS_5_BLOCKS,
+    WOPBS_PARAM_MESSAGE_1_CARRY_0,
+    WOPBS_PARAM_MESSAGE_1_CARRY_1,
+    WOPBS_PARAM_MESSAGE_1_CARRY_2,
+    WOPBS_PARAM_MESSAGE_1_CARRY_3,
+    WOPBS_PARAM_MESSAGE_1_CARRY_4,
+    WOPBS_PARAM_MESSAGE_1_CARRY_5,
+    WOPBS_PARAM_MESSAGE_1_CARRY_6,
+    WOPBS_PARAM_MESSAGE_1_CARRY_7,
+    WOPBS_PARAM_MESSAGE_1_CARRY_8,
+    WOPBS_PARAM_MESSAGE_2_CARRY_0,
+    WOPBS_PARAM_MESSAGE_2_CARRY_1,
+    WOPBS_PARAM_MESSAGE_2_CARRY_2,
+    WOPBS_PARAM_MESSAGE_2_CARRY_3,
+    WOPBS_PARAM_MESSAGE_2_CARRY_4,
+    WOPBS_PARAM_MESSAGE_2_CARRY_5,
+    WOPBS_PARAM_MESSAGE_2_CARRY_6,
+    WOPBS_PARAM_MESSAGE_2_CARRY_7,
+    WOPBS_PARAM_MESSAGE_3_CARRY_0,
+    WOPBS_PARAM_MESSAGE_3_CARRY_1,
+    WOPBS_PARAM_MESSAGE_3_CARRY_2,
+    WOPBS_PARAM_MESSAGE_3_CARRY_3,
+    WOPBS_PARAM_MESSAGE_3_CARRY_4,
+    WOPBS_PARAM_MESSAGE_3_CARRY_5,
+    WOPBS_PARAM_MESSAGE_3_CARRY_6,
+    WOPBS_PARAM_MESSAGE_4_CARRY_0,
+    WOPBS_PARAM_MESSAGE_4_CARRY_1,
+    WOPBS_PARAM_MESSAGE_4_CARRY_2,
+    WOPBS_PARAM_MESSAGE_4_CARRY_3,
+    WOPBS_PARAM_MESSAGE_4_CARRY_4,
+    WOPBS_PARAM_MESSAGE_4_CARRY_5,
+    WOPBS_PARAM_MESSAGE_5_CARRY_0,
+    WOPBS_PARAM_MESSAGE_5_CARRY_1,
+    WOPBS_PARAM_MESSAGE_5_CARRY_2,
+    WOPBS_PARAM_MESSAGE_5_CARRY_3,
+    WOPBS_PARAM_MESSAGE_5_CARRY_4,
+    WOPBS_PARAM_MESSAGE_6_CARRY_0,
+    WOPBS_PARAM_MESSAGE_6_CARRY_1,
+    WOPBS_PARAM_MESSAGE_6_CARRY_2,
+    WOPBS_PARAM_MESSAGE_6_CARRY_3,
+    WOPBS_PARAM_MESSAGE_7_CARRY_0,
+    WOPBS_PARAM_MESSAGE_7_CARRY_1,
+    WOPBS_PARAM_MESSAGE_7_CARRY_2,
+    WOPBS_PARAM_MESSAGE_8_CARRY_0,
+    WOPBS_PARAM_MESSAGE_8_CARRY_1,
+    WOPBS_PARAM_MESSAGE_9_CARRY_0,
+);
+
+static REGISTRY: Lazy<RwLock<Vec<(String, ShortintParameterSet)>>> = Lazy::new(|| {
+    let pbs_parameters = ParametersCatalog::builtin()
+        .iter()
+        .map(|entry| (entry.name, ShortintParameterSet::from(entry.parameters)));
+    let wopbs_parameters = BUILTIN_WOPBS_PARAMETERS
+        .iter()
+        .map(|(name, parameters)| (*name, ShortintParameterSet::from(*parameters)));
+
+    RwLock::new(
+        pbs_parameters
+            .chain(wopbs_parameters)
+            .map(|(name, parameters)| (name.to_owned(), parameters))
+            .collect(),
+    )
+});
+
+/// Returns the parameter set registered under `name`, e.g. `"PARAM_MESSAGE_2_CARRY_2"`
+pub fn lookup(name: &str) -> Option<ShortintParameterSet> {
+    REGISTRY
+        .read()
+        .unwrap()
+        .iter()
+        .find(|(registered_name, _)| registered_name == name)
+        .map(|(_, parameters)| *parameters)
+}
+
+/// Registers `parameters` under `name`, an error is returned if the name is already taken.
+pub fn register(
+    name: &str,
+    parameters: impl Into<ShortintParameterSet>,
+) -> Result<(), &'static str> {
+    let mut registry = REGISTRY.write().unwrap();
+    if registry
+        .iter()
+        .any(|(registered_name, _)| registered_name == name)
+    {
+        return Err("A parameter set is already registered under this name");
+    }
+    registry.push((name.to_owned(), parameters.into()));
+    Ok(())
+}
+
+/// Returns the registered names and parameter sets, in registration order.
+///
+/// The iterator works on a snapshot of the registry: sets registered while iterating are not
+/// returned.
+pub fn iter() -> impl Iterator<Item = (String, ShortintParameterSet)> {
+    REGISTRY.read().unwrap().clone().into_iter()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_registry_builtin_names() {
+        for (name, param) in [
+            ("PARAM_MESSAGE_2_CARRY_2", PARAM_MESSAGE_2_CARRY_2),
+            (
+                "PARAM_SMALL_MESSAGE_1_CARRY_1",
+                PARAM_SMALL_MESSAGE_1_CARRY_1,
+            ),
+        ] {
+            assert_eq!(lookup(name).unwrap().pbs_parameters(), Some(param));
+        }
+        assert_eq!(
+            lookup("WOPBS_PARAM_MESSAGE_2_CARRY_2")
+                .unwrap()
+                .wopbs_parameters(),
+            Some(WOPBS_PARAM_MESSAGE_2_CARRY_2)
+        );
+        assert!(lookup("PARAM_MESSAGE_9_CARRY_9").is_none());
+
+        let names = iter().map(|(name, _)| name).collect::<Vec<_>>();
+        let catalog = ParametersCatalog::builtin();
+        assert!(catalog
+            .iter()
+            .all(|entry| names.iter().any(|n| n == entry.name)));
+        assert!(BUILTIN_WOPBS_PARAMETERS
+            .iter()
+            .all(|(name, _)| names.iter().any(|n| n == name)));
+    }
+
+    #[test]
+    fn test_registry_register() {
+        let name = "TEST_REGISTRY_WOPBS_PARAM";
+        register(name, WOPBS_PARAM_MESSAGE_4_NORM2_6).unwrap();
+        assert_eq!(
+            lookup(name).unwrap(),
+            ShortintParameterSet::from(WOPBS_PARAM_MESSAGE_4_NORM2_6)
+        );
+        assert!(register(name, PARAM_MESSAGE_2_CARRY_2).is_err());
+        assert!(iter().any(|(registered_name, _)| registered_name == name));
+    }
+}
@@ -0,0 +1,296 @@
+//! Human-readable summary of a parameter set.
+//!
+//! The quantities reported here are derived from the parameters only, no key is generated.
+
+use crate::shortint::parameters::{EncryptionKeyChoice, PBSParameters, WopbsParameters};
+use std::fmt::{Display, Formatter};
+
+/// Rough classification of the latency of a PBS.
+///
+/// The classification is based on the number of operations performed by the FFT during the blind
+/// rotation, it is only meant to compare parameter sets between each other.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Copy, Clone)]
+pub enum PbsLatencyClass {
+    Low,
+    Medium,
+    High,
+    VeryHigh,
+}
+
+impl PbsLatencyClass {
+    fn from_cost(cost: u64) -> Self {
+        match cost {
+            c if c < 50_000_000 => Self::Low,
+            c if c < 200_000_000 => Self::Medium,
+            c if c < 1_000_000_000 => Self::High,
+            _ => Self::VeryHigh,
+        }
+    }
+}
+
+/// Derived quantities of a parameter set, see [`PBSParameters::report`] and
+/// [`WopbsParameters::report`].
+///
+/// The [`Display`] implementation prints the report as a table.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParametersReport {
+    pub message_bits: u32,
+    pub carry_bits: u32,
+    pub encryption_key_choice: EncryptionKeyChoice,
+    pub lwe_dimension: usize,
+    /// Dimension of the LWE key derived from the GLWE key
+    pub big_lwe_dimension: usize,
+    pub glwe_dimension: usize,
+    pub polynomial_size: usize,
+    /// Base 2 logarithm of the standard deviation of the LWE noise
+    pub lwe_noise_std_dev_log2: f64,
+    /// Base 2 logarithm of the standard deviation of the GLWE noise
+    pub glwe_noise_std_dev_log2: f64,
+    /// Size of a fresh ciphertext, encrypted under the key selected by the
+    /// `encryption_key_choice`
+    pub ciphertext_size_bytes: usize,
+    pub key_switching_key_size_bytes: usize,
+    /// Size of the bootstrapping key in the Fourier domain
+    pub bootstrapping_key_size_bytes: usize,
+    /// Size of the private functional packing keyswitch keys used by the circuit bootstrapping,
+    /// only present for WoPBS parameters
+    pub packing_key_switching_key_size_bytes: Option<usize>,
+    pub pbs_latency_class: PbsLatencyClass,
+}
+
+struct DerivedSizes {
+    lwe_dimension: usize,
+    glwe_dimension: usize,
+    polynomial_size: usize,
+    pbs_level: usize,
+    ks_level: usize,
+    encryption_key_choice: EncryptionKeyChoice,
+}
+
+impl DerivedSizes {
+    fn big_lwe_dimension(&self) -> usize {
+        self.glwe_dimension * self.polynomial_size
+    }
+
+    fn ciphertext_size_bytes(&self) -> usize {
+        let lwe_dimension = match self.encryption_key_choice {
+            EncryptionKeyChoice::Big => self.big_lwe_dimension(),
+            EncryptionKeyChoice::Small => self.lwe_dimension,
+        };
+        (lwe_dimension + 1) * std::mem::size_of::<u64>()
+    }
+
+    fn key_switching_key_size_bytes(&self) -> usize {
+        self.big_lwe_dimension()
+            * self.ks_level
+            * (self.lwe_dimension + 1)
+            * std::mem::size_of::<u64>()
+    }
+
+    fn bootstrapping_key_size_bytes(&self) -> usize {
+        let glwe_size = self.glwe_dimension + 1;
+        // Each polynomial of N coefficients is stored as N / 2 complex numbers
+        self.lwe_dimension
+            * self.pbs_level
+            * glwe_size
+            * glwe_size
+            * (self.polynomial_size / 2)
+            * std::mem::size_of::<concrete_fft::c64>()
+    }
+
+    fn pbs_cost(&self) -> u64 {
+        let glwe_size = (self.glwe_dimension + 1) as u64;
+        let n = self.polynomial_size as u64;
+        let fft_cost = n * u64::from(n.max(2).ilog2());
+        self.lwe_dimension as u64 * self.pbs_level as u64 * glwe_size * glwe_size * fft_cost
+    }
+
+    fn pbs_latency_class(&self) -> PbsLatencyClass {
+        PbsLatencyClass::from_cost(self.pbs_cost())
+    }
+}
+
+impl PBSParameters {
+    fn derived_sizes(&self) -> DerivedSizes {
+        DerivedSizes {
+            lwe_dimension: self.lwe_dimension.0,
+            glwe_dimension: self.glwe_dimension.0,
+            polynomial_size: self.polynomial_size.0,
+            pbs_level: self.pbs_level.0,
+            ks_level: self.ks_level.0,
+            encryption_key_choice: self.encryption_key_choice,
+        }
+    }
+
+    /// Estimated number of operations of a PBS, only meaningful to compare parameter sets
+    pub(crate) fn estimated_pbs_cost(&self) -> u64 {
+        self.derived_sizes().pbs_cost()
+    }
+
+    /// Returns a report of quantities derived from the parameters.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let report = PARAM_MESSAGE_2_CARRY_2.report();
+    /// assert_eq!(report.message_bits, 2);
+    /// assert_eq!(report.carry_bits, 2);
+    ///
+    /// println!("{report}");
+    /// ```
+    pub fn report(&self) -> ParametersReport {
+        let sizes = self.derived_sizes();
+
+        ParametersReport {
+            message_bits: self.message_modulus.0.ilog2(),
+            carry_bits: self.carry_modulus.0.ilog2(),
+            encryption_key_choice: self.encryption_key_choice,
+            lwe_dimension: self.lwe_dimension.0,
+            big_lwe_dimension: sizes.big_lwe_dimension(),
+            glwe_dimension: self.glwe_dimension.0,
+            polynomial_size: self.polynomial_size.0,
+            lwe_noise_std_dev_log2: self.lwe_noise_distribution.get_log_standard_dev(),
+            glwe_noise_std_dev_log2: self.glwe_noise_distribution.get_log_standard_dev(),
+            ciphertext_size_bytes: sizes.ciphertext_size_bytes(),
+            key_switching_key_size_bytes: sizes.key_switching_key_size_bytes(),
+            bootstrapping_key_size_bytes: sizes.bootstrapping_key_size_bytes(),
+            packing_key_switching_key_size_bytes: None,
+            pbs_latency_class: sizes.pbs_latency_class(),
+        }
+    }
+}
+
+impl WopbsParameters {
+    /// Returns a report of quantities derived from the parameters.
+    ///
+    /// Compared to [`PBSParameters::report`], this also includes the size of the keys
+    /// used by the circuit bootstrapping.
+    pub fn report(&self) -> ParametersReport {
+        let sizes = DerivedSizes {
+            lwe_dimension: self.lwe_dimension.0,
+            glwe_dimension: self.glwe_dimension.0,
+            polynomial_size: self.polynomial_size.0,
+            pbs_level: self.pbs_level.0,
+            ks_level: self.ks_level.0,
+            encryption_key_choice: self.encryption_key_choice,
+        };
+
+        // The circuit bootstrapping uses one private functional packing keyswitch key per
+        // polynomial of the output GLWE ciphertext
+        let glwe_size = self.glwe_dimension.0 + 1;
+        let packing_key_switching_key_size_bytes = glwe_size
+            * (sizes.big_lwe_dimension() + 1)
+            * self.pfks_level.0
+            * glwe_size
+            * self.polynomial_size.0
+            * std::mem::size_of::<u64>();
+
+        ParametersReport {
+            message_bits: self.message_modulus.0.ilog2(),
+            carry_bits: self.carry_modulus.0.ilog2(),
+            encryption_key_choice: self.encryption_key_choice,
+            lwe_dimension: self.lwe_dimension.0,
+            big_lwe_dimension: sizes.big_lwe_dimension(),
+            glwe_dimension: self.glwe_dimension.0,
+            polynomial_size: self.polynomial_size.0,
+            lwe_noise_std_dev_log2: self.lwe_modular_std_dev.0.log2(),
+            glwe_noise_std_dev_log2: self.glwe_modular_std_dev.0.log2(),
+            ciphertext_size_bytes: sizes.ciphertext_size_bytes(),
+            key_switching_key_size_bytes: sizes.key_switching_key_size_bytes(),
+            bootstrapping_key_size_bytes: sizes.bootstrapping_key_size_bytes(),
+            packing_key_switching_key_size_bytes: Some(packing_key_switching_key_size_bytes),
+            pbs_latency_class: sizes.pbs_latency_class(),
+        }
+    }
+}
+
+fn format_size(bytes: usize) -> String {
+    const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+    let mut size = bytes as f64;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    format!("{size:.2} {}", UNITS[unit])
+}
+
+impl Display for ParametersReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "message bits:                  {}", self.message_bits)?;
+        writeln!(f, "carry bits:                    {}", self.carry_bits)?;
+        writeln!(
+            f,
+            "encryption key:                {:?}",
+            self.encryption_key_choice
+        )?;
+        writeln!(f, "LWE dimension:                 {}", self.lwe_dimension)?;
+        writeln!(
+            f,
+            "big LWE dimension:             {}",
+            self.big_lwe_dimension
+        )?;
+        writeln!(f, "GLWE dimension:                {}", self.glwe_dimension)?;
+        writeln!(f, "polynomial size:               {}", self.polynomial_size)?;
+        writeln!(
+            f,
+            "LWE noise std dev:             2^{:.2}",
+            self.lwe_noise_std_dev_log2
+        )?;
+        writeln!(
+            f,
+            "GLWE noise std dev:            2^{:.2}",
+            self.glwe_noise_std_dev_log2
+        )?;
+        writeln!(
+            f,
+            "ciphertext size:               {}",
+            format_size(self.ciphertext_size_bytes)
+        )?;
+        writeln!(
+            f,
+            "key switching key size:        {}",
+            format_size(self.key_switching_key_size_bytes)
+        )?;
+        writeln!(
+            f,
+            "bootstrapping key size:        {}",
+            format_size(self.bootstrapping_key_size_bytes)
+        )?;
+        if let Some(size) = self.packing_key_switching_key_size_bytes {
+            writeln!(f, "packing keyswitch keys size:   {}", format_size(size))?;
+        }
+        write!(
+            f,
+            "PBS latency class:             {:?}",
+            self.pbs_latency_class
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shortint::parameters::{
+        ALL_PARAMETER_VEC, PARAM_MESSAGE_1_CARRY_1, PARAM_MESSAGE_4_CARRY_4,
+    };
+
+    #[test]
+    fn test_report_sizes_are_ordered() {
+        let small = PARAM_MESSAGE_1_CARRY_1.report();
+        let large = PARAM_MESSAGE_4_CARRY_4.report();
+
+        assert!(small.bootstrapping_key_size_bytes < large.bootstrapping_key_size_bytes);
+        assert!(small.pbs_latency_class <= large.pbs_latency_class);
+
+        for param in ALL_PARAMETER_VEC {
+            let report = param.report();
+            assert_eq!(1 << report.message_bits, param.message_modulus.0);
+            assert_eq!(1 << report.carry_bits, param.carry_modulus.0);
+            assert!(report.packing_key_switching_key_size_bytes.is_none());
+            assert!(!report.to_string().is_empty());
+        }
+    }
+}
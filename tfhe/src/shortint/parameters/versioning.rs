@@ -0,0 +1,209 @@
+//! Versioning of the built-in parameter sets.
+//!
+//! Each entry of the [`ParametersCatalog`] records the [`ParameterVersion`] in which its values
+//! were last tuned, and whether it has been deprecated since, possibly in favor of another set.
+//! Ciphertexts encrypted under a built-in parameter set carry its version (see
+//! [`CiphertextBase::parameter_version`](crate::shortint::CiphertextBase::parameter_version)),
+//! which is kept by the serialization: a server can then detect clients encrypting with
+//! parameters that have been re-tuned, e.g. after an improvement of the security estimates.
+//!
+//! # Example
+//!
+//! ```rust
+//! use tfhe::shortint::parameters::versioning::ParameterVersionStatus;
+//! use tfhe::shortint::parameters::{ParametersCatalog, PARAM_MESSAGE_2_CARRY_2};
+//! use tfhe::shortint::{gen_keys, CiphertextBig};
+//!
+//! let (cks, _sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+//!
+//! let serialized = bincode::serialize(&cks.encrypt(1)).unwrap();
+//!
+//! // On the server side
+//! let ct: CiphertextBig = bincode::deserialize(&serialized).unwrap();
+//! let version = ct.parameter_version.unwrap();
+//!
+//! let status =
+//!     ParametersCatalog::builtin().version_status("PARAM_MESSAGE_2_CARRY_2", version);
+//! assert_eq!(status, ParameterVersionStatus::Current);
+//! ```
+
+use super::catalog::ParametersCatalog;
+use super::*;
+use std::fmt::{Display, Formatter};
+
+/// Version of the values of a parameter set, named after the release of the crate in which they
+/// were tuned.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct ParameterVersion {
+    pub major: u16,
+    pub minor: u16,
+}
+
+impl ParameterVersion {
+    /// The version of the parameter sets tuned for the 0.3 release
+    pub const V0_3: Self = Self::new(0, 3);
+
+    pub const fn new(major: u16, minor: u16) -> Self {
+        Self { major, minor }
+    }
+}
+
+impl Display for ParameterVersion {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}.{}", self.major, self.minor)
+    }
+}
+
+/// Status of a parameter set used with a given version, see
+/// [`ParametersCatalog::version_status`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ParameterVersionStatus {
+    /// The version is the latest one of the parameter set
+    Current,
+    /// The parameter set has been re-tuned since the version, its latest values are those of
+    /// version `latest`
+    Outdated { latest: ParameterVersion },
+    /// The parameter set should not be used anymore, `superseded_by` is the name of the set
+    /// replacing it, if any
+    Deprecated {
+        since: ParameterVersion,
+        superseded_by: Option<&'static str>,
+    },
+    /// The catalog has no parameter set with this name
+    Unknown,
+}
+
+impl ParametersCatalog {
+    /// Returns the status of the parameter set named `name` when used with the given version,
+    /// e.g. the [`parameter_version`](crate::shortint::CiphertextBase::parameter_version) of a
+    /// ciphertext sent by a client.
+    pub fn version_status(&self, name: &str, version: ParameterVersion) -> ParameterVersionStatus {
+        let entry = match self.get(name) {
+            Some(entry) => entry,
+            None => return ParameterVersionStatus::Unknown,
+        };
+
+        if let Some(since) = entry.deprecated_since {
+            return ParameterVersionStatus::Deprecated {
+                since,
+                superseded_by: entry.superseded_by,
+            };
+        }
+
+        if version < entry.version {
+            ParameterVersionStatus::Outdated {
+                latest: entry.version,
+            }
+        } else {
+            ParameterVersionStatus::Current
+        }
+    }
+}
+
+impl PBSParameters {
+    /// Returns the version of the parameters if they are those of a built-in parameter set,
+    /// `None` otherwise.
+    ///
+    /// Some built-in sets share the same values under different names, in that case the most
+    /// recent version among them is returned.
+    pub fn version(&self) -> Option<ParameterVersion> {
+        ParametersCatalog::builtin()
+            .iter()
+            .filter(|entry| entry.parameters == *self)
+            .map(|entry| entry.version)
+            .max()
+    }
+}
+
+impl ShortintParameterSet {
+    /// Returns the version of the PBS parameters if they are those of a built-in parameter set,
+    /// `None` otherwise.
+    pub fn version(&self) -> Option<ParameterVersion> {
+        self.pbs_parameters()?.version()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shortint::parameters::catalog::NamedParameters;
+    use crate::shortint::parameters::provenance::ParameterProvenance;
+
+    #[test]
+    fn test_builtin_parameters_versions() {
+        let catalog = ParametersCatalog::builtin();
+        for entry in catalog.iter() {
+            assert_eq!(entry.parameters.version(), Some(ParameterVersion::V0_3));
+            assert_eq!(
+                catalog.version_status(entry.name, ParameterVersion::V0_3),
+                ParameterVersionStatus::Current
+            );
+            assert_eq!(
+                catalog.version_status(entry.name, ParameterVersion::new(0, 2)),
+                ParameterVersionStatus::Outdated {
+                    latest: ParameterVersion::V0_3
+                }
+            );
+        }
+
+        assert_eq!(
+            catalog.version_status("PARAM_MESSAGE_9_CARRY_9", ParameterVersion::V0_3),
+            ParameterVersionStatus::Unknown
+        );
+
+        let mut custom = PARAM_MESSAGE_2_CARRY_2;
+        custom.polynomial_size = PolynomialSize(4096);
+        assert_eq!(custom.version(), None);
+        assert_eq!(
+            ShortintParameterSet::from(PARAM_MULTI_BIT_MESSAGE_2_CARRY_2_GROUP_2).version(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_deprecated_parameters() {
+        const ENTRIES: &[NamedParameters] = &[
+            NamedParameters {
+                name: "OLD_PARAM_MESSAGE_2_CARRY_2",
+                parameters: PARAM_MESSAGE_2_CARRY_2,
+                security_bits: 128,
+                log2_failure_probability: -40,
+                unverified: false,
+                version: ParameterVersion::new(0, 2),
+                deprecated_since: Some(ParameterVersion::V0_3),
+                superseded_by: Some("PARAM_MESSAGE_2_CARRY_2"),
+                provenance: ParameterProvenance::V0_3,
+            },
+            NamedParameters {
+                name: "PARAM_MESSAGE_2_CARRY_2",
+                parameters: PARAM_MESSAGE_2_CARRY_2,
+                security_bits: 128,
+                log2_failure_probability: -40,
+                unverified: false,
+                version: ParameterVersion::V0_3,
+                deprecated_since: None,
+                superseded_by: None,
+                provenance: ParameterProvenance::V0_3,
+            },
+        ];
+        let catalog = ParametersCatalog::new(ENTRIES);
+
+        assert!(catalog
+            .get("OLD_PARAM_MESSAGE_2_CARRY_2")
+            .unwrap()
+            .is_deprecated());
+        assert_eq!(
+            catalog.version_status("OLD_PARAM_MESSAGE_2_CARRY_2", ParameterVersion::new(0, 2)),
+            ParameterVersionStatus::Deprecated {
+                since: ParameterVersion::V0_3,
+                superseded_by: Some("PARAM_MESSAGE_2_CARRY_2"),
+            }
+        );
+        assert_eq!(
+            catalog.version_status("PARAM_MESSAGE_2_CARRY_2", ParameterVersion::new(0, 2)),
+            ParameterVersionStatus::Outdated {
+                latest: ParameterVersion::V0_3
+            }
+        );
+    }
+}
@@ -0,0 +1,159 @@
+//! Module with the definition of the compact PublicKey.
+//!
+//! A [`PublicKeyBase`](super::PublicKeyBase) is a list of encryptions of zero, its size grows
+//! with the square of the LWE dimension of the encryption key, which amounts to gigabytes for
+//! usual parameters.
+//!
+//! A [`CompactPublicKeyBig`] is a single GLWE encryption of zero under the GLWE secret key of the
+//! bootstrapping, i.e. the large LWE secret key seen as $k$ polynomials modulo $X^N + 1$: it is
+//! made of $k + 1$ polynomials, i.e. $(k + 1)N$ integers. Encrypting with it produces a
+//! [`CompactCiphertextListBig`] holding up to $N$ messages per GLWE ciphertext, which the server
+//! expands into regular ciphertexts with [`CompactCiphertextListBase::expand`].
+//!
+//! The public key is encrypted with the same key, polynomial size and noise distribution as the
+//! GGSW ciphertexts of the bootstrapping key, so its security relies on the same GLWE problem as
+//! the one of the server key. There is no compact public key for the small LWE secret key, which
+//! has no ring structure.
+//!
+//! # Example
+//!
+//! ```rust
+//! use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+//! use tfhe::shortint::{gen_keys, CompactPublicKeyBig};
+//!
+//! let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+//! let pk = CompactPublicKeyBig::new(&cks);
+//!
+//! // Sent by the client
+//! let list = pk.encrypt_slice(&[0, 1, 2, 3]);
+//!
+//! // Received by the server
+//! let cts = list.expand();
+//! let ct = sks.add(&cts[1], &cts[2]);
+//! assert_eq!(cks.decrypt(&ct), 3);
+//! ```
+
+use crate::core_crypto::entities::*;
+use crate::shortint::ciphertext::{
+    CiphertextBase, CiphertextBig, KeyswitchBootstrap, PBSOrderMarker,
+};
+use crate::shortint::engine::ShortintEngine;
+use crate::shortint::parameters::{
+    CarryModulus, MessageModulus, ParameterVersion, ShortintParameterSet,
+};
+use crate::shortint::ClientKey;
+use serde::{Deserialize, Serialize};
+use std::fmt::Debug;
+
+/// A structure containing a compact public key.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct CompactPublicKeyBase<OpOrder: PBSOrderMarker> {
+    pub(crate) glwe_public_key: GlweCiphertextOwned<u64>,
+    #[cfg_attr(
+        feature = "insecure-test-params",
+        serde(serialize_with = "crate::shortint::parameters::serialize_key_parameters")
+    )]
+    pub parameters: ShortintParameterSet,
+    pub _order_marker: std::marker::PhantomData<OpOrder>,
+}
+
+pub type CompactPublicKeyBig = CompactPublicKeyBase<KeyswitchBootstrap>;
+
+impl CompactPublicKeyBig {
+    /// Generate a compact public key.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the parameters of `client_key` do not use the native ciphertext modulus or a
+    /// power of two polynomial size.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::client_key::ClientKey;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    /// use tfhe::shortint::public_key::CompactPublicKeyBig;
+    ///
+    /// // Generate the client key:
+    /// let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let pk = CompactPublicKeyBig::new(&cks);
+    /// ```
+    pub fn new(client_key: &ClientKey) -> Self {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine.new_compact_public_key(client_key).unwrap()
+        })
+    }
+
+    /// Encrypt a small integer message using the compact public key.
+    ///
+    /// The input message is reduced to the encrypted message space modulus. Use
+    /// [`CompactPublicKeyBase::encrypt_slice`] to encrypt several messages at once, this method
+    /// allocates a list for a single message.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    /// use tfhe::shortint::{ClientKey, CompactPublicKeyBig};
+    ///
+    /// let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2);
+    /// let pk = CompactPublicKeyBig::new(&cks);
+    ///
+    /// let ct = pk.encrypt(5);
+    /// assert_eq!(cks.decrypt(&ct), 1);
+    /// ```
+    pub fn encrypt(&self, message: u64) -> CiphertextBig {
+        self.encrypt_slice(&[message]).expand().pop().unwrap()
+    }
+
+    /// Encrypt a slice of small integer messages in a compact list.
+    ///
+    /// The input messages are reduced to the encrypted message space modulus. Each group of $N$
+    /// messages, $N$ being the polynomial size of the parameters, takes as much space as the
+    /// compact public key.
+    pub fn encrypt_slice(&self, messages: &[u64]) -> CompactCiphertextListBig {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine
+                .encrypt_slice_with_compact_public_key(self, messages)
+                .unwrap()
+        })
+    }
+}
+
+/// A list of ciphertexts encrypted with a [`CompactPublicKeyBase`].
+///
+/// The messages are encrypted in the coefficients of GLWE ciphertexts, they are turned into
+/// ciphertexts usable by the server key with [`CompactCiphertextListBase::expand`].
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct CompactCiphertextListBase<OpOrder: PBSOrderMarker> {
+    pub(crate) ct_list: Vec<GlweCiphertextOwned<u64>>,
+    pub(crate) message_count: usize,
+    pub message_modulus: MessageModulus,
+    pub carry_modulus: CarryModulus,
+    pub parameter_version: Option<ParameterVersion>,
+    pub _order_marker: std::marker::PhantomData<OpOrder>,
+}
+
+pub type CompactCiphertextListBig = CompactCiphertextListBase<KeyswitchBootstrap>;
+
+impl<OpOrder: PBSOrderMarker> CompactCiphertextListBase<OpOrder> {
+    /// Returns the number of messages in the list.
+    pub fn len(&self) -> usize {
+        self.message_count
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.message_count == 0
+    }
+
+    /// Extracts the ciphertexts of the list, in the order of the encrypted messages.
+    ///
+    /// The extraction does not need any key and does not add noise, the noise of the extracted
+    /// ciphertexts is the one of the encryption with the compact public key.
+    pub fn expand(&self) -> Vec<CiphertextBase<OpOrder>> {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine.expand_compact_ciphertext_list(self).unwrap()
+        })
+    }
+}
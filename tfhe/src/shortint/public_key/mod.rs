@@ -1,7 +1,11 @@
 //! Module with the definition of the encryption PublicKey.
 
+pub mod compact;
 pub mod compressed;
 pub mod standard;
 
+pub use compact::{
+    CompactCiphertextListBase, CompactCiphertextListBig, CompactPublicKeyBase, CompactPublicKeyBig,
+};
 pub use compressed::{CompressedPublicKeyBase, CompressedPublicKeyBig, CompressedPublicKeySmall};
 pub use standard::{PublicKeyBase, PublicKeyBig, PublicKeySmall};
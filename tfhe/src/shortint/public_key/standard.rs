@@ -13,6 +13,10 @@ use std::fmt::Debug;
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct PublicKeyBase<OpOrder: PBSOrderMarker> {
     pub(crate) lwe_public_key: LwePublicKeyOwned<u64>,
+    #[cfg_attr(
+        feature = "insecure-test-params",
+        serde(serialize_with = "crate::shortint::parameters::serialize_key_parameters")
+    )]
     pub parameters: ShortintParameterSet,
     pub _order_marker: std::marker::PhantomData<OpOrder>,
 }
@@ -281,7 +281,9 @@ impl ServerKey {
         ct_right: &CiphertextBase<OpOrder>,
     ) -> bool {
         let final_operation_count = ct_left.degree.0 + ct_right.degree.0;
+        let final_noise_level = ct_left.noise_level + ct_right.noise_level;
         final_operation_count <= self.max_degree.0
+            && self.max_noise_level().validate(final_noise_level)
     }
 
     /// Compute homomorphically an addition between two ciphertexts encrypting integer values.
@@ -333,6 +335,8 @@ impl ServerKey {
         ct_left: &CiphertextBase<OpOrder>,
         ct_right: &CiphertextBase<OpOrder>,
     ) -> Result<CiphertextBase<OpOrder>, CheckError> {
+        self.check_operand(ct_left)?;
+        self.check_operand(ct_right)?;
         if self.is_add_possible(ct_left, ct_right) {
             let ct_result = self.unchecked_add(ct_left, ct_right);
             Ok(ct_result)
@@ -388,6 +392,8 @@ impl ServerKey {
         ct_left: &mut CiphertextBase<OpOrder>,
         ct_right: &CiphertextBase<OpOrder>,
     ) -> Result<(), CheckError> {
+        self.check_operand(ct_left)?;
+        self.check_operand(ct_right)?;
         if self.is_add_possible(ct_left, ct_right) {
             self.unchecked_add_assign(ct_left, ct_right);
             Ok(())
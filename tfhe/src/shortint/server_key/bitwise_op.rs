@@ -268,6 +268,8 @@ impl ServerKey {
         ct_left: &CiphertextBase<OpOrder>,
         ct_right: &CiphertextBase<OpOrder>,
     ) -> Result<CiphertextBase<OpOrder>, CheckError> {
+        self.check_operand(ct_left)?;
+        self.check_operand(ct_right)?;
         if self.is_functional_bivariate_pbs_possible(ct_left, ct_right) {
             let ct_result = self.unchecked_bitand(ct_left, ct_right);
             Ok(ct_result)
@@ -323,6 +325,8 @@ impl ServerKey {
         ct_left: &mut CiphertextBase<OpOrder>,
         ct_right: &CiphertextBase<OpOrder>,
     ) -> Result<(), CheckError> {
+        self.check_operand(ct_left)?;
+        self.check_operand(ct_right)?;
         if self.is_functional_bivariate_pbs_possible(ct_left, ct_right) {
             self.unchecked_bitand_assign(ct_left, ct_right);
             Ok(())
@@ -704,6 +708,8 @@ impl ServerKey {
         ct_left: &CiphertextBase<OpOrder>,
         ct_right: &CiphertextBase<OpOrder>,
     ) -> Result<CiphertextBase<OpOrder>, CheckError> {
+        self.check_operand(ct_left)?;
+        self.check_operand(ct_right)?;
         if self.is_functional_bivariate_pbs_possible(ct_left, ct_right) {
             let ct_result = self.unchecked_bitxor(ct_left, ct_right);
             Ok(ct_result)
@@ -759,6 +765,8 @@ impl ServerKey {
         ct_left: &mut CiphertextBase<OpOrder>,
         ct_right: &CiphertextBase<OpOrder>,
     ) -> Result<(), CheckError> {
+        self.check_operand(ct_left)?;
+        self.check_operand(ct_right)?;
         if self.is_functional_bivariate_pbs_possible(ct_left, ct_right) {
             self.unchecked_bitxor_assign(ct_left, ct_right);
             Ok(())
@@ -1142,6 +1150,8 @@ impl ServerKey {
         ct_left: &CiphertextBase<OpOrder>,
         ct_right: &CiphertextBase<OpOrder>,
     ) -> Result<CiphertextBase<OpOrder>, CheckError> {
+        self.check_operand(ct_left)?;
+        self.check_operand(ct_right)?;
         if self.is_functional_bivariate_pbs_possible(ct_left, ct_right) {
             let ct_result = self.unchecked_bitor(ct_left, ct_right);
             Ok(ct_result)
@@ -1197,6 +1207,8 @@ impl ServerKey {
         ct_left: &mut CiphertextBase<OpOrder>,
         ct_right: &CiphertextBase<OpOrder>,
     ) -> Result<(), CheckError> {
+        self.check_operand(ct_left)?;
+        self.check_operand(ct_right)?;
         if self.is_functional_bivariate_pbs_possible(ct_left, ct_right) {
             self.unchecked_bitor_assign(ct_left, ct_right);
             Ok(())
@@ -0,0 +1,227 @@
+use super::{CheckError, ServerKey};
+use crate::shortint::engine::ShortintEngine;
+use crate::shortint::{CiphertextBase, PBSOrderMarker};
+
+impl ServerKey {
+    /// Selects homomorphically `ct_then` if `condition` encrypts 1, `ct_else` if it encrypts 0.
+    ///
+    /// `condition` must encrypt 0 or 1, e.g. the output of a comparison. The result encrypts the
+    /// message of the selected ciphertext, its carries are not kept.
+    ///
+    /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
+    /// check that the input ciphertext carries are empty and clears them if it's not the case and
+    /// the operation requires it. It outputs a ciphertext whose carry is always empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let ct_a = cks.encrypt(3);
+    /// let ct_b = cks.encrypt(1);
+    ///
+    /// // max(a, b)
+    /// let condition = sks.greater(&ct_a, &ct_b);
+    /// let ct_res = sks.if_then_else(&condition, &ct_a, &ct_b);
+    ///
+    /// let res = cks.decrypt(&ct_res);
+    /// assert_eq!(res, 3);
+    /// ```
+    pub fn if_then_else<OpOrder: PBSOrderMarker>(
+        &self,
+        condition: &CiphertextBase<OpOrder>,
+        ct_then: &CiphertextBase<OpOrder>,
+        ct_else: &CiphertextBase<OpOrder>,
+    ) -> CiphertextBase<OpOrder> {
+        let tmp_condition: CiphertextBase<OpOrder>;
+        let tmp_then: CiphertextBase<OpOrder>;
+        let tmp_else: CiphertextBase<OpOrder>;
+
+        let condition = if condition.carry_is_empty() {
+            condition
+        } else {
+            tmp_condition = self.clear_carry(condition);
+            &tmp_condition
+        };
+
+        let ct_then = if ct_then.carry_is_empty() {
+            ct_then
+        } else {
+            tmp_then = self.clear_carry(ct_then);
+            &tmp_then
+        };
+
+        let ct_else = if ct_else.carry_is_empty() {
+            ct_else
+        } else {
+            tmp_else = self.clear_carry(ct_else);
+            &tmp_else
+        };
+
+        self.unchecked_if_then_else(condition, ct_then, ct_else)
+    }
+
+    /// Selects homomorphically `ct_then` if `condition` encrypts 1, `ct_else` if it encrypts 0,
+    /// without checks.
+    ///
+    /// The three operands are packed in the input of a single PBS if their degrees and noise
+    /// levels allow it, which is mostly the case with large carry spaces or operands of small
+    /// degrees. Otherwise each branch goes through a bivariate PBS with `condition`, and the
+    /// results are added: the output then has twice the nominal noise level.
+    ///
+    /// See [`ServerKey::if_then_else`] for the semantics.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let condition = cks.encrypt(0);
+    /// let ct_then = cks.encrypt(2);
+    /// let ct_else = cks.encrypt(1);
+    ///
+    /// let ct_res = sks.unchecked_if_then_else(&condition, &ct_then, &ct_else);
+    ///
+    /// let res = cks.decrypt(&ct_res);
+    /// assert_eq!(res, 1);
+    /// ```
+    pub fn unchecked_if_then_else<OpOrder: PBSOrderMarker>(
+        &self,
+        condition: &CiphertextBase<OpOrder>,
+        ct_then: &CiphertextBase<OpOrder>,
+        ct_else: &CiphertextBase<OpOrder>,
+    ) -> CiphertextBase<OpOrder> {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine
+                .unchecked_if_then_else(self, condition, ct_then, ct_else)
+                .unwrap()
+        })
+    }
+
+    /// Selects homomorphically `ct_then` if `condition` encrypts 1, `ct_else` if it encrypts 0,
+    /// with checks.
+    ///
+    /// If the operation can be performed, the result is returned in a _new_ ciphertext.
+    /// Otherwise a [`CheckError`] is returned.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let condition = cks.encrypt(1);
+    /// let ct_then = cks.encrypt(2);
+    /// let ct_else = cks.encrypt(1);
+    ///
+    /// let ct_res = sks.checked_if_then_else(&condition, &ct_then, &ct_else);
+    /// let res = cks.decrypt(&ct_res.unwrap());
+    /// assert_eq!(res, 2);
+    ///
+    /// // The carries of ct_then are too large for the operation
+    /// let mut ct_then = ct_then;
+    /// sks.unchecked_scalar_mul_assign(&mut ct_then, 3);
+    /// assert!(sks
+    ///     .checked_if_then_else(&condition, &ct_then, &ct_else)
+    ///     .is_err());
+    /// ```
+    pub fn checked_if_then_else<OpOrder: PBSOrderMarker>(
+        &self,
+        condition: &CiphertextBase<OpOrder>,
+        ct_then: &CiphertextBase<OpOrder>,
+        ct_else: &CiphertextBase<OpOrder>,
+    ) -> Result<CiphertextBase<OpOrder>, CheckError> {
+        self.check_operand(condition)?;
+        self.check_operand(ct_then)?;
+        self.check_operand(ct_else)?;
+        if self.is_if_then_else_possible(condition, ct_then, ct_else) {
+            Ok(self.unchecked_if_then_else(condition, ct_then, ct_else))
+        } else {
+            Err(CheckError::CarryFull)
+        }
+    }
+
+    /// Selects homomorphically `ct_then` if `condition` encrypts 1, `ct_else` if it encrypts 0.
+    ///
+    /// This checks that the operation is possible. In the case where the carry buffers are full,
+    /// then it is automatically cleared to allow the operation.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let mut condition = cks.encrypt(1);
+    /// let mut ct_then = cks.encrypt(3);
+    /// let mut ct_else = cks.encrypt(1);
+    ///
+    /// // Fill the carries of the branches
+    /// sks.unchecked_add_assign(&mut ct_then, &ct_then.clone());
+    /// sks.unchecked_add_assign(&mut ct_else, &ct_else.clone());
+    ///
+    /// let ct_res = sks.smart_if_then_else(&mut condition, &mut ct_then, &mut ct_else);
+    ///
+    /// let res = cks.decrypt(&ct_res);
+    /// assert_eq!(res, 2);
+    /// ```
+    pub fn smart_if_then_else<OpOrder: PBSOrderMarker>(
+        &self,
+        condition: &mut CiphertextBase<OpOrder>,
+        ct_then: &mut CiphertextBase<OpOrder>,
+        ct_else: &mut CiphertextBase<OpOrder>,
+    ) -> CiphertextBase<OpOrder> {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine
+                .smart_if_then_else(self, condition, ct_then, ct_else)
+                .unwrap()
+        })
+    }
+
+    /// Returns true if [`ServerKey::unchecked_if_then_else`] can be computed on the operands
+    /// without exceeding the message and carry space or the maximum noise level.
+    pub fn is_if_then_else_possible<OpOrder: PBSOrderMarker>(
+        &self,
+        condition: &CiphertextBase<OpOrder>,
+        ct_then: &CiphertextBase<OpOrder>,
+        ct_else: &CiphertextBase<OpOrder>,
+    ) -> bool {
+        self.is_packed_if_then_else_possible(condition, ct_then, ct_else)
+            || (self.is_functional_bivariate_pbs_possible(ct_then, condition)
+                && self.is_functional_bivariate_pbs_possible(ct_else, condition))
+    }
+
+    /// Returns true if the operands of an if-then-else can be packed in the input of a single
+    /// PBS, as `ct_then * then_factor + ct_else * else_factor + condition`.
+    pub(crate) fn is_packed_if_then_else_possible<OpOrder: PBSOrderMarker>(
+        &self,
+        condition: &CiphertextBase<OpOrder>,
+        ct_then: &CiphertextBase<OpOrder>,
+        ct_else: &CiphertextBase<OpOrder>,
+    ) -> bool {
+        let else_factor = condition.degree.0 + 1;
+        let then_factor = else_factor * (ct_else.degree.0 + 1);
+        if then_factor > u8::MAX as usize {
+            return false;
+        }
+
+        let packed_degree =
+            ct_then.degree.0 * then_factor + ct_else.degree.0 * else_factor + condition.degree.0;
+        let packed_noise_level = ct_then.noise_level * then_factor
+            + ct_else.noise_level * else_factor
+            + condition.noise_level;
+
+        packed_degree < self.message_modulus.0 * self.carry_modulus.0
+            && self.max_noise_level().validate(packed_noise_level)
+    }
+}
@@ -173,6 +173,8 @@ impl ServerKey {
         ct_left: &CiphertextBase<OpOrder>,
         ct_right: &CiphertextBase<OpOrder>,
     ) -> Result<CiphertextBase<OpOrder>, CheckError> {
+        self.check_operand(ct_left)?;
+        self.check_operand(ct_right)?;
         if self.is_functional_bivariate_pbs_possible(ct_left, ct_right) {
             Ok(self.unchecked_greater(ct_left, ct_right))
         } else {
@@ -444,6 +446,8 @@ impl ServerKey {
         ct_left: &CiphertextBase<OpOrder>,
         ct_right: &CiphertextBase<OpOrder>,
     ) -> Result<CiphertextBase<OpOrder>, CheckError> {
+        self.check_operand(ct_left)?;
+        self.check_operand(ct_right)?;
         if self.is_functional_bivariate_pbs_possible(ct_left, ct_right) {
             Ok(self.unchecked_greater_or_equal(ct_left, ct_right))
         } else {
@@ -615,6 +619,8 @@ impl ServerKey {
         ct_left: &CiphertextBase<OpOrder>,
         ct_right: &CiphertextBase<OpOrder>,
     ) -> Result<CiphertextBase<OpOrder>, CheckError> {
+        self.check_operand(ct_left)?;
+        self.check_operand(ct_right)?;
         if self.is_functional_bivariate_pbs_possible(ct_left, ct_right) {
             Ok(self.unchecked_less(ct_left, ct_right))
         } else {
@@ -835,6 +841,8 @@ impl ServerKey {
         ct_left: &CiphertextBase<OpOrder>,
         ct_right: &CiphertextBase<OpOrder>,
     ) -> Result<CiphertextBase<OpOrder>, CheckError> {
+        self.check_operand(ct_left)?;
+        self.check_operand(ct_right)?;
         if self.is_functional_bivariate_pbs_possible(ct_left, ct_right) {
             Ok(self.unchecked_less(ct_left, ct_right))
         } else {
@@ -1053,6 +1061,8 @@ impl ServerKey {
         ct_left: &CiphertextBase<OpOrder>,
         ct_right: &CiphertextBase<OpOrder>,
     ) -> Result<CiphertextBase<OpOrder>, CheckError> {
+        self.check_operand(ct_left)?;
+        self.check_operand(ct_right)?;
         if self.is_functional_bivariate_pbs_possible(ct_left, ct_right) {
             Ok(self.unchecked_equal(ct_left, ct_right))
         } else {
@@ -1271,6 +1281,8 @@ impl ServerKey {
         ct_left: &CiphertextBase<OpOrder>,
         ct_right: &CiphertextBase<OpOrder>,
     ) -> Result<CiphertextBase<OpOrder>, CheckError> {
+        self.check_operand(ct_left)?;
+        self.check_operand(ct_right)?;
         if self.is_functional_bivariate_pbs_possible(ct_left, ct_right) {
             Ok(self.unchecked_not_equal(ct_left, ct_right))
         } else {
@@ -4,16 +4,24 @@ use super::MaxDegree;
 use crate::core_crypto::prelude::*;
 use crate::shortint::engine::ShortintEngine;
 use crate::shortint::parameters::{CarryModulus, CiphertextModulus, MessageModulus};
-use crate::shortint::ClientKey;
+use crate::shortint::{ClientKey, ServerKey};
 use serde::{Deserialize, Serialize};
 
 /// A structure containing a compressed server public key.
 ///
 /// The server key is generated by the client and is meant to be published: the client
 /// sends it to the server so it can compute homomorphic circuits.
+///
+/// The key switching and bootstrapping keys are seeded: only their bodies are stored, their masks
+/// are regenerated from a seed when the key is decompressed into a [`ServerKey`] on the compute
+/// node. The serialized key is several times smaller than the one of a [`ServerKey`].
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct CompressedServerKey {
     pub key_switching_key: SeededLweKeyswitchKeyOwned<u64>,
+    #[cfg_attr(
+        feature = "insecure-test-params",
+        serde(serialize_with = "serialize_bootstrapping_key")
+    )]
     pub bootstrapping_key: SeededLweBootstrapKeyOwned<u64>,
     // Size of the message buffer
     pub message_modulus: MessageModulus,
@@ -24,6 +32,19 @@ pub struct CompressedServerKey {
     pub ciphertext_modulus: CiphertextModulus,
 }
 
+/// Refuses the serialization of keys generated from the insecure test parameters
+#[cfg(feature = "insecure-test-params")]
+fn serialize_bootstrapping_key<S: serde::Serializer>(
+    bootstrapping_key: &SeededLweBootstrapKeyOwned<u64>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    crate::shortint::parameters::check_serializable_key_dimensions(
+        bootstrapping_key.input_lwe_dimension(),
+        bootstrapping_key.polynomial_size(),
+    )?;
+    bootstrapping_key.serialize(serializer)
+}
+
 impl CompressedServerKey {
     /// Generate a compressed server key.
     ///
@@ -44,4 +65,30 @@ impl CompressedServerKey {
             engine.new_compressed_server_key(client_key).unwrap()
         })
     }
+
+    /// Decompresses the key into a [`ServerKey`] whose bootstrapping key is in the Fourier domain.
+    ///
+    /// See [`ServerKey::from_compressed`] to keep the bootstrapping key seeded instead.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::client_key::ClientKey;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    /// use tfhe::shortint::server_key::CompressedServerKey;
+    ///
+    /// let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2);
+    /// let compressed_sks = CompressedServerKey::new(&cks);
+    ///
+    /// // The compressed key is sent to the compute node
+    /// let serialized = bincode::serialize(&compressed_sks).unwrap();
+    /// let compressed_sks: CompressedServerKey = bincode::deserialize(&serialized).unwrap();
+    ///
+    /// let sks = compressed_sks.decompress();
+    /// let ct = sks.add(&cks.encrypt(1), &cks.encrypt(2));
+    /// assert_eq!(cks.decrypt(&ct), 3);
+    /// ```
+    pub fn decompress(self) -> ServerKey {
+        ServerKey::from(self)
+    }
 }
@@ -0,0 +1,202 @@
+//! Module with the definition of the CompressedLookupTable.
+
+use super::{LookupTableOwned, ServerKey};
+use crate::core_crypto::commons::traits::*;
+use crate::shortint::ciphertext::Degree;
+use crate::shortint::parameters::{CarryModulus, MessageModulus};
+use serde::{Deserialize, Serialize};
+
+/// A compact representation of a lookup table.
+///
+/// A [`LookupTableOwned`] stores a whole GLWE ciphertext (`glwe_size * polynomial_size`
+/// coefficients), however it only depends on the value the function takes on each of the
+/// `message_modulus * carry_modulus` possible inputs.
+///
+/// This structure only stores these values, so it is cheap to serialize and to keep on disk.
+/// It has to be decompressed with a [`ServerKey`] using the same `message_modulus` and
+/// `carry_modulus` before being used in a PBS.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CompressedLookupTable {
+    // Value of the function for each input in [0, message_modulus * carry_modulus)
+    values: Vec<u64>,
+    degree: Degree,
+    message_modulus: MessageModulus,
+    carry_modulus: CarryModulus,
+}
+
+impl CompressedLookupTable {
+    /// Values of the function for each input in `[0, message_modulus * carry_modulus)`
+    pub fn values(&self) -> &[u64] {
+        &self.values
+    }
+
+    pub fn degree(&self) -> Degree {
+        self.degree
+    }
+
+    pub fn message_modulus(&self) -> MessageModulus {
+        self.message_modulus
+    }
+
+    pub fn carry_modulus(&self) -> CarryModulus {
+        self.carry_modulus
+    }
+
+    /// Expands the lookup table into an accumulator usable with the given `server_key`
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `server_key` does not use the same message and carry moduli
+    /// as the ones used to create the lookup table.
+    pub fn decompress(&self, server_key: &ServerKey) -> LookupTableOwned {
+        assert_eq!(
+            (self.message_modulus, self.carry_modulus),
+            (server_key.message_modulus, server_key.carry_modulus),
+            "The server key is not compatible with the compressed lookup table"
+        );
+        let mut lookup_table = server_key.generate_accumulator(|x| self.values[x as usize]);
+        lookup_table.degree = self.degree;
+        lookup_table
+    }
+
+    /// Returns the lookup table applying `self` then `outer`, i.e. evaluating `outer ∘ self` with
+    /// a single PBS.
+    ///
+    /// `None` is returned if a value of `self` is not a valid input of `outer`, i.e. if it does not
+    /// fit in `message_modulus * carry_modulus` and sets the padding bit: two PBS would then not
+    /// evaluate the composition of the two functions.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the lookup tables do not use the same message and carry moduli.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let f = sks.generate_compressed_accumulator(|x| (x + 1) % 4);
+    /// let g = sks.generate_compressed_accumulator(|x| (x * 3) % 4);
+    /// let g_after_f = f.compose(&g).unwrap();
+    ///
+    /// let ct = cks.encrypt(2);
+    /// let ct_res = sks.apply_lookup_table(&ct, &g_after_f.decompress(&sks));
+    /// assert_eq!(cks.decrypt(&ct_res), 1);
+    ///
+    /// // 16 does not fit in the 4 bits of message and carry
+    /// let overflowing = sks.generate_compressed_accumulator(|x| x + 16);
+    /// assert!(overflowing.compose(&g).is_none());
+    /// ```
+    pub fn compose(&self, outer: &Self) -> Option<Self> {
+        assert_eq!(
+            (self.message_modulus, self.carry_modulus),
+            (outer.message_modulus, outer.carry_modulus),
+            "The lookup tables do not use the same message and carry moduli"
+        );
+        let values = self
+            .values
+            .iter()
+            .map(|value| outer.values.get(*value as usize).copied())
+            .collect::<Option<Vec<_>>>()?;
+        let max_value = values.iter().copied().max().unwrap_or(0);
+
+        Some(Self {
+            values,
+            degree: Degree(max_value as usize),
+            message_modulus: self.message_modulus,
+            carry_modulus: self.carry_modulus,
+        })
+    }
+}
+
+impl ServerKey {
+    /// Constructs a compressed accumulator given a function as input.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let msg = 3;
+    ///
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// let f = |x| (x * x) % 4;
+    ///
+    /// // The compressed accumulator only stores the 16 values of the function
+    /// let compressed_acc = sks.generate_compressed_accumulator(f);
+    /// let serialized = bincode::serialize(&compressed_acc).unwrap();
+    /// let compressed_acc: tfhe::shortint::server_key::CompressedLookupTable =
+    ///     bincode::deserialize(&serialized).unwrap();
+    ///
+    /// let acc = compressed_acc.decompress(&sks);
+    /// let ct_res = sks.apply_lookup_table(&ct, &acc);
+    ///
+    /// let dec = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, f(msg));
+    /// ```
+    pub fn generate_compressed_accumulator<F>(&self, f: F) -> CompressedLookupTable
+    where
+        F: Fn(u64) -> u64,
+    {
+        let modulus_sup = (self.message_modulus.0 * self.carry_modulus.0) as u64;
+        let values: Vec<u64> = (0..modulus_sup).map(f).collect();
+        let max_value = values.iter().copied().max().unwrap_or(0);
+
+        CompressedLookupTable {
+            values,
+            degree: Degree(max_value as usize),
+            message_modulus: self.message_modulus,
+            carry_modulus: self.carry_modulus,
+        }
+    }
+
+    /// Compresses an existing accumulator generated with this server key.
+    ///
+    /// The values of the function are read back from the accumulator, as they are stored
+    /// modulo `2 * message_modulus * carry_modulus` (the padding bit included),
+    /// the decompressed accumulator is the same as the original one.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let acc = sks.generate_accumulator(|x| (x + 1) % 4);
+    ///
+    /// let compressed_acc = sks.compress_accumulator(&acc);
+    /// assert_eq!(compressed_acc.decompress(&sks), acc);
+    /// ```
+    pub fn compress_accumulator(&self, acc: &LookupTableOwned) -> CompressedLookupTable {
+        let modulus_sup = self.message_modulus.0 * self.carry_modulus.0;
+        let box_size = acc.acc.polynomial_size().0 / modulus_sup;
+        let delta = (1_u64 << 63) / modulus_sup as u64;
+
+        let body = acc.acc.get_body();
+        let body = body.as_ref();
+
+        // After the rotation done when filling the accumulator, the first coefficient
+        // of the i-th box is at index i * box_size and is never negated
+        let values = (0..modulus_sup)
+            .map(|i| body[i * box_size] / delta)
+            .collect();
+
+        CompressedLookupTable {
+            values,
+            degree: acc.degree,
+            message_modulus: self.message_modulus,
+            carry_modulus: self.carry_modulus,
+        }
+    }
+}
@@ -356,6 +356,181 @@ impl ServerKey {
         })
     }
 
+    /// Compute the remainder of the division between two ciphertexts without checks.
+    ///
+    /// The result is returned in a _new_ ciphertext.
+    ///
+    /// # Warning
+    ///
+    /// /!\ A modulo by zero returns the dividend! Together with the division by zero returning 0,
+    /// this keeps `x == (x / y) * y + x % y` true for any `y`.
+    ///
+    /// # Example
+    ///
+    ///```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let clear_1 = 3;
+    /// let clear_2 = 2;
+    ///
+    /// // Encrypt two messages
+    /// let ct_1 = cks.encrypt(clear_1);
+    /// let ct_2 = cks.encrypt(clear_2);
+    ///
+    /// // Compute homomorphically a modulo
+    /// let ct_res = sks.unchecked_mod(&ct_1, &ct_2);
+    ///
+    /// // Decrypt
+    /// let res = cks.decrypt(&ct_res);
+    /// assert_eq!(clear_1 % clear_2, res);
+    ///
+    /// // A modulo by zero returns the dividend
+    /// let ct_res = sks.unchecked_mod(&ct_1, &cks.encrypt(0));
+    /// assert_eq!(cks.decrypt(&ct_res), clear_1);
+    /// ```
+    pub fn unchecked_mod<OpOrder: PBSOrderMarker>(
+        &self,
+        ct_left: &CiphertextBase<OpOrder>,
+        ct_right: &CiphertextBase<OpOrder>,
+    ) -> CiphertextBase<OpOrder> {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine.unchecked_mod(self, ct_left, ct_right).unwrap()
+        })
+    }
+
+    /// Compute the remainder of the division between two ciphertexts without checks.
+    ///
+    /// The result is _assigned_ in `ct_left`.
+    ///
+    /// # Warning
+    ///
+    /// /!\ A modulo by zero returns the dividend!
+    ///
+    /// # Example
+    ///
+    ///```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let clear_1 = 3;
+    /// let clear_2 = 2;
+    ///
+    /// // Encrypt two messages
+    /// let mut ct_1 = cks.encrypt(clear_1);
+    /// let ct_2 = cks.encrypt(clear_2);
+    ///
+    /// // Compute homomorphically a modulo
+    /// sks.unchecked_mod_assign(&mut ct_1, &ct_2);
+    ///
+    /// // Decrypt
+    /// let res = cks.decrypt(&ct_1);
+    /// assert_eq!(clear_1 % clear_2, res);
+    /// ```
+    pub fn unchecked_mod_assign<OpOrder: PBSOrderMarker>(
+        &self,
+        ct_left: &mut CiphertextBase<OpOrder>,
+        ct_right: &CiphertextBase<OpOrder>,
+    ) {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine
+                .unchecked_mod_assign(self, ct_left, ct_right)
+                .unwrap()
+        })
+    }
+
+    /// Compute the remainder of the division between two ciphertexts.
+    ///
+    /// The result is returned in a _new_ ciphertext. The carries of the operands are cleared if
+    /// needed.
+    ///
+    /// # Warning
+    ///
+    /// /!\ A modulo by zero returns the dividend!
+    ///
+    /// # Example
+    ///
+    ///```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let clear_1 = 3;
+    /// let clear_2 = 2;
+    ///
+    /// // Encrypt two messages
+    /// let mut ct_1 = cks.encrypt(clear_1);
+    /// let mut ct_2 = cks.encrypt(clear_2);
+    ///
+    /// // Fill the carries of ct_1, which now encrypts 6
+    /// sks.unchecked_add_assign(&mut ct_1, &ct_1.clone());
+    ///
+    /// // Compute homomorphically a modulo
+    /// let ct_res = sks.smart_mod(&mut ct_1, &mut ct_2);
+    ///
+    /// // Decrypt, the carries are lost: 6 mod 4 = 2 and 2 % 2 = 0
+    /// let res = cks.decrypt(&ct_res);
+    /// assert_eq!(0, res);
+    /// ```
+    pub fn smart_mod<OpOrder: PBSOrderMarker>(
+        &self,
+        ct_left: &mut CiphertextBase<OpOrder>,
+        ct_right: &mut CiphertextBase<OpOrder>,
+    ) -> CiphertextBase<OpOrder> {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine.smart_mod(self, ct_left, ct_right).unwrap()
+        })
+    }
+
+    /// Compute the remainder of the division between two ciphertexts.
+    ///
+    /// The result is _assigned_ in `ct_left`. The carries of the operands are cleared if needed.
+    ///
+    /// # Warning
+    ///
+    /// /!\ A modulo by zero returns the dividend!
+    ///
+    /// # Example
+    ///
+    ///```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let clear_1 = 3;
+    /// let clear_2 = 2;
+    ///
+    /// // Encrypt two messages
+    /// let mut ct_1 = cks.encrypt(clear_1);
+    /// let mut ct_2 = cks.encrypt(clear_2);
+    ///
+    /// // Compute homomorphically a modulo
+    /// sks.smart_mod_assign(&mut ct_1, &mut ct_2);
+    ///
+    /// // Decrypt
+    /// let res = cks.decrypt(&ct_1);
+    /// assert_eq!(clear_1 % clear_2, res);
+    /// ```
+    pub fn smart_mod_assign<OpOrder: PBSOrderMarker>(
+        &self,
+        ct_left: &mut CiphertextBase<OpOrder>,
+        ct_right: &mut CiphertextBase<OpOrder>,
+    ) {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine.smart_mod_assign(self, ct_left, ct_right).unwrap()
+        })
+    }
+
     /// Alias to [`unchecked_scalar_div`](`Self::unchecked_scalar_div`) provided for convenience
     ///
     /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
@@ -0,0 +1,140 @@
+//! Module with the programmable bootstrap whose output is kept as a GLWE ciphertext.
+//!
+//! A PBS rotates the GLWE ciphertext of a lookup table by the encrypted message (the blind
+//! rotation), then extracts its constant coefficient as an LWE ciphertext encrypted under the
+//! large key (the sample extraction), which is keyswitched to the small key before the next PBS.
+//!
+//! [`ServerKey::apply_lookup_table_to_glwe`] stops after the blind rotation and returns a
+//! [`GlweAccumulator`]. The outputs of several lookup tables can be combined linearly in this
+//! form, with [`ServerKey::unchecked_glwe_add_assign`] and
+//! [`ServerKey::unchecked_glwe_scalar_mul_assign`], so that only the result is extracted and
+//! keyswitched by [`ServerKey::extract_from_glwe`], instead of each intermediate output. The
+//! noise of a blind rotated GLWE ciphertext is the one of the output of a PBS, the linear
+//! operations grow it the same way they grow the noise of LWE ciphertexts.
+//!
+//! The blind rotation needs a bootstrapping key in the Fourier domain: an on-the-fly key is
+//! converted for each call, and multi-bit keys are not supported.
+//!
+//! # Example
+//!
+//! ```rust
+//! use tfhe::shortint::gen_keys;
+//! use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+//! use tfhe::shortint::CiphertextBig;
+//!
+//! let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+//!
+//! let ct_a = cks.encrypt(3);
+//! let ct_b = cks.encrypt(2);
+//!
+//! let square = sks.generate_accumulator(|x| (x * x) % 4);
+//! let increment = sks.generate_accumulator(|x| (x + 1) % 4);
+//!
+//! // a² + 2 * (b + 1), with a single sample extraction
+//! let mut acc = sks.apply_lookup_table_to_glwe(&ct_a, &square);
+//! let mut acc_b = sks.apply_lookup_table_to_glwe(&ct_b, &increment);
+//! sks.unchecked_glwe_scalar_mul_assign(&mut acc_b, 2);
+//! sks.unchecked_glwe_add_assign(&mut acc, &acc_b);
+//!
+//! let ct_res: CiphertextBig = sks.extract_from_glwe(&acc);
+//! assert_eq!(cks.decrypt_message_and_carry(&ct_res), 1 + 2 * 3);
+//! ```
+
+use super::{LookupTableOwned, ServerKey};
+use crate::core_crypto::entities::*;
+use crate::shortint::ciphertext::{Degree, NoiseLevel};
+use crate::shortint::engine::ShortintEngine;
+use crate::shortint::parameters::{CarryModulus, MessageModulus, ParameterVersion};
+use crate::shortint::{CiphertextBase, PBSOrderMarker};
+
+/// The output of a PBS before the sample extraction: a GLWE ciphertext encrypted under the GLWE
+/// key of the server key, whose constant coefficient encrypts the output of the lookup table.
+#[derive(Clone, Debug, PartialEq)]
+#[must_use]
+pub struct GlweAccumulator {
+    pub(crate) glwe: GlweCiphertextOwned<u64>,
+    pub(crate) degree: Degree,
+    pub(crate) noise_level: NoiseLevel,
+    pub(crate) message_modulus: MessageModulus,
+    pub(crate) carry_modulus: CarryModulus,
+    pub(crate) parameter_version: Option<ParameterVersion>,
+}
+
+impl GlweAccumulator {
+    pub fn degree(&self) -> Degree {
+        self.degree
+    }
+
+    pub fn noise_level(&self) -> NoiseLevel {
+        self.noise_level
+    }
+
+    pub fn message_modulus(&self) -> MessageModulus {
+        self.message_modulus
+    }
+
+    pub fn carry_modulus(&self) -> CarryModulus {
+        self.carry_modulus
+    }
+}
+
+impl ServerKey {
+    /// Evaluates a lookup table on `ct` without extracting the result, see the
+    /// [module documentation](self).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the bootstrapping key is a multi-bit key.
+    pub fn apply_lookup_table_to_glwe<OpOrder: PBSOrderMarker>(
+        &self,
+        ct: &CiphertextBase<OpOrder>,
+        acc: &LookupTableOwned,
+    ) -> GlweAccumulator {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine.apply_lookup_table_to_glwe(self, ct, acc).unwrap()
+        })
+    }
+
+    /// Extracts the ciphertext encrypted in the constant coefficient of `acc`.
+    ///
+    /// The extracted ciphertext is encrypted under the large key, so a [`CiphertextBig`] is
+    /// returned without a keyswitch.
+    ///
+    /// [`CiphertextBig`]: crate::shortint::CiphertextBig
+    pub fn extract_from_glwe<OutOrder: PBSOrderMarker>(
+        &self,
+        acc: &GlweAccumulator,
+    ) -> CiphertextBase<OutOrder> {
+        ShortintEngine::with_thread_local_mut(|engine| engine.extract_from_glwe(self, acc).unwrap())
+    }
+
+    /// Adds `acc_right` to `acc_left`, the degree and the noise level of the result are the sums of
+    /// those of the operands.
+    ///
+    /// The message and carry spaces are not checked: the sum of the degrees must stay below the
+    /// maximum degree of the server key for the result to be extracted correctly.
+    pub fn unchecked_glwe_add_assign(
+        &self,
+        acc_left: &mut GlweAccumulator,
+        acc_right: &GlweAccumulator,
+    ) {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine
+                .unchecked_glwe_add_assign(acc_left, acc_right)
+                .unwrap()
+        })
+    }
+
+    /// Multiplies `acc` by `scalar`, the degree and the noise level of the result are those of
+    /// `acc` multiplied by `scalar`.
+    ///
+    /// The message and carry spaces are not checked, see
+    /// [`ServerKey::unchecked_glwe_add_assign`].
+    pub fn unchecked_glwe_scalar_mul_assign(&self, acc: &mut GlweAccumulator, scalar: u8) {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine
+                .unchecked_glwe_scalar_mul_assign(acc, scalar)
+                .unwrap()
+        })
+    }
+}
@@ -0,0 +1,131 @@
+//! Fusion of chained lookup tables.
+//!
+//! Applying `f` then `g` to a ciphertext with [`ServerKey::apply_lookup_table`] costs two PBS,
+//! while the same result is obtained with a single PBS evaluating `g ∘ f`. A [`LazyCiphertext`]
+//! records the lookup tables applied to a ciphertext instead of evaluating them: the clear values
+//! of each function are traced and composed with the previously recorded ones, and a PBS is only
+//! computed when the composition is not possible or when the result is needed.
+//!
+//! This is mostly useful for code generated by a compiler or a higher level library, where chains
+//! of lookup tables are common and not easy to spot by hand.
+
+use super::{CompressedLookupTable, LookupTableOwned, ServerKey};
+use crate::shortint::{CiphertextBase, PBSOrderMarker};
+
+/// A ciphertext along with the lookup tables recorded for it and not yet applied.
+#[must_use]
+pub struct LazyCiphertext<'a, OpOrder: PBSOrderMarker> {
+    server_key: &'a ServerKey,
+    ct: CiphertextBase<OpOrder>,
+    // Composition of the lookup tables recorded since the last PBS
+    pending: Option<CompressedLookupTable>,
+    bootstrap_count: usize,
+    fused_count: usize,
+}
+
+impl<'a, OpOrder: PBSOrderMarker> LazyCiphertext<'a, OpOrder> {
+    /// Records the lookup table, it is fused with the previously recorded ones if possible.
+    ///
+    /// See [`CompressedLookupTable::compose`] for the conditions of the fusion.
+    pub fn apply_lookup_table(self, acc: &LookupTableOwned) -> Self {
+        let lookup_table = self.server_key.compress_accumulator(acc);
+        self.record(lookup_table)
+    }
+
+    /// Records the lookup table of `f`, without generating its accumulator.
+    pub fn apply_function<F>(self, f: F) -> Self
+    where
+        F: Fn(u64) -> u64,
+    {
+        let lookup_table = self.server_key.generate_compressed_accumulator(f);
+        self.record(lookup_table)
+    }
+
+    fn record(mut self, lookup_table: CompressedLookupTable) -> Self {
+        let fused = self
+            .pending
+            .as_ref()
+            .and_then(|pending| pending.compose(&lookup_table));
+        self.pending = match fused {
+            Some(fused) => {
+                self.fused_count += 1;
+                Some(fused)
+            }
+            None => {
+                self.flush();
+                Some(lookup_table)
+            }
+        };
+        self
+    }
+
+    /// Applies the pending lookup table, if any
+    fn flush(&mut self) {
+        if let Some(pending) = self.pending.take() {
+            let acc = pending.decompress(self.server_key);
+            self.server_key
+                .apply_lookup_table_assign(&mut self.ct, &acc);
+            self.bootstrap_count += 1;
+        }
+    }
+
+    /// Returns the number of PBS computed so far, the pending lookup table excluded.
+    pub fn bootstrap_count(&self) -> usize {
+        self.bootstrap_count
+    }
+
+    /// Returns the number of PBS saved by fusing lookup tables so far.
+    pub fn fused_count(&self) -> usize {
+        self.fused_count
+    }
+
+    /// Applies the pending lookup table and returns the resulting ciphertext.
+    pub fn evaluate(mut self) -> CiphertextBase<OpOrder> {
+        self.flush();
+        self.ct
+    }
+}
+
+impl ServerKey {
+    /// Starts recording the lookup tables applied to `ct`, chained lookup tables are fused to
+    /// reduce the number of PBS.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let msg = 3;
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// let acc_square = sks.generate_accumulator(|x| (x * x) % 4);
+    /// let acc_increment = sks.generate_accumulator(|x| (x + 1) % 4);
+    ///
+    /// let lazy = sks
+    ///     .record_lookup_tables(ct)
+    ///     .apply_lookup_table(&acc_square)
+    ///     .apply_lookup_table(&acc_increment)
+    ///     .apply_function(|x| (x + 2) % 4);
+    /// assert_eq!(lazy.fused_count(), 2);
+    ///
+    /// // A single PBS is computed
+    /// let ct_res = lazy.evaluate();
+    /// assert_eq!(cks.decrypt(&ct_res), ((msg * msg + 1) % 4 + 2) % 4);
+    /// ```
+    pub fn record_lookup_tables<OpOrder: PBSOrderMarker>(
+        &self,
+        ct: CiphertextBase<OpOrder>,
+    ) -> LazyCiphertext<'_, OpOrder> {
+        LazyCiphertext {
+            server_key: self,
+            ct,
+            pending: None,
+            bootstrap_count: 0,
+            fused_count: 0,
+        }
+    }
+}
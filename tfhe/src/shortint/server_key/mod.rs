@@ -4,10 +4,12 @@
 //! available homomorphic integer operations.
 mod add;
 mod bitwise_op;
+mod cmux;
 mod comp_op;
 mod div_mod;
 mod mul;
 mod neg;
+mod no_padding;
 mod scalar_add;
 mod scalar_mul;
 mod scalar_sub;
@@ -16,30 +18,94 @@ mod sub;
 
 pub mod compressed;
 pub use compressed::CompressedServerKey;
+pub mod compressed_lookup_table;
+pub use compressed_lookup_table::CompressedLookupTable;
+pub mod glwe_output;
+pub use glwe_output::GlweAccumulator;
+pub mod lut_fusion;
+pub use lut_fusion::LazyCiphertext;
+pub use no_padding::FullDomainLookupTable;
+pub mod pbs_order;
+pub use pbs_order::PBSOrderSwitchingKey;
+pub mod refresh;
+pub use refresh::RefreshPolicy;
 
 #[cfg(test)]
 mod tests;
 
 use crate::core_crypto::algorithms::*;
+use crate::core_crypto::commons::computation_buffers::ComputationBuffers;
+use crate::core_crypto::commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweSize, LweBskGroupingFactor, LweDimension,
+    PolynomialSize, ThreadCount,
+};
 use crate::core_crypto::commons::traits::*;
 use crate::core_crypto::entities::*;
 use crate::core_crypto::fft_impl::fft64::crypto::bootstrap::FourierLweBootstrapKeyOwned;
-use crate::shortint::ciphertext::{CiphertextBase, Degree};
+use crate::core_crypto::fft_impl::fft64::math::fft::Fft;
+use crate::shortint::ciphertext::{CiphertextBase, Degree, NoiseLevel};
 use crate::shortint::client_key::ClientKey;
 use crate::shortint::engine::ShortintEngine;
 use crate::shortint::parameters::{CarryModulus, CiphertextModulus, MessageModulus};
 use crate::shortint::PBSOrderMarker;
+use rayon::prelude::*;
 use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
 use std::fmt::{Debug, Display, Formatter};
+use std::sync::Arc;
 
 /// Maximum value that the degree can reach.
 #[derive(Debug, PartialEq, Eq, Copy, Clone, Serialize, Deserialize)]
 pub struct MaxDegree(pub usize);
 
-/// Error returned when the carry buffer is full.
-#[derive(Debug)]
+/// Maximum value that the noise level can reach, see [`NoiseLevel`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Serialize, Deserialize)]
+pub struct MaxNoiseLevel(pub usize);
+
+impl MaxNoiseLevel {
+    /// Returns the maximum noise level of the parameters with the given moduli.
+    ///
+    /// The parameters are optimized so that a PBS is correct on the sum of as many fresh
+    /// encryptions of `message_modulus - 1` as the message and carry space can hold, whose noise
+    /// level is `(message_modulus * carry_modulus - 1) / (message_modulus - 1)`.
+    pub fn from_msg_carry_modulus(
+        message_modulus: MessageModulus,
+        carry_modulus: CarryModulus,
+    ) -> Self {
+        let max_degree = message_modulus.0 * carry_modulus.0 - 1;
+        Self(max_degree / (message_modulus.0 - 1).max(1))
+    }
+
+    /// Returns whether a ciphertext of the given noise level can be bootstrapped correctly.
+    pub fn validate(&self, noise_level: NoiseLevel) -> bool {
+        noise_level.0 <= self.0
+    }
+}
+
+/// Error returned by the checked operations, describing the invariant which would not hold.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
 pub enum CheckError {
+    /// The result of the operation would not fit in the message and carry space, or its noise
+    /// would be too large: the operands must be cleaned first, e.g. with
+    /// [`ServerKey::message_extract`].
     CarryFull,
+    /// The degree of an operand exceeds the maximum degree of the server key.
+    DegreeExceeded {
+        degree: Degree,
+        max_degree: MaxDegree,
+    },
+    /// The noise level of an operand exceeds the maximum noise level of the server key.
+    NoiseLevelExceeded {
+        noise_level: NoiseLevel,
+        max_noise_level: MaxNoiseLevel,
+    },
+    /// An operand was not encrypted with the message and carry moduli of the server key.
+    MismatchedModuli {
+        expected: (MessageModulus, CarryModulus),
+        actual: (MessageModulus, CarryModulus),
+    },
+    /// The lookup table was not generated with the parameters of the server key.
+    IncompatibleLookupTable,
 }
 
 impl Display for CheckError {
@@ -48,20 +114,337 @@ impl Display for CheckError {
             CheckError::CarryFull => {
                 write!(f, "The carry buffer is full")
             }
+            CheckError::DegreeExceeded { degree, max_degree } => {
+                write!(
+                    f,
+                    "The degree {} of an operand exceeds the maximum degree {}",
+                    degree.0, max_degree.0
+                )
+            }
+            CheckError::NoiseLevelExceeded {
+                noise_level,
+                max_noise_level,
+            } => {
+                write!(
+                    f,
+                    "The noise level {} of an operand exceeds the maximum noise level {}",
+                    noise_level.0, max_noise_level.0
+                )
+            }
+            CheckError::MismatchedModuli { expected, actual } => {
+                write!(
+                    f,
+                    "An operand has message modulus {} and carry modulus {}, the server key \
+                    expects message modulus {} and carry modulus {}",
+                    actual.0 .0, actual.1 .0, expected.0 .0, expected.1 .0
+                )
+            }
+            CheckError::IncompatibleLookupTable => {
+                write!(
+                    f,
+                    "The lookup table was not generated with the parameters of the server key"
+                )
+            }
         }
     }
 }
 
 impl std::error::Error for CheckError {}
 
+/// Error returned by the operations which cannot use the bootstrapping key of a [`ServerKey`]
+/// with its storage, see [`ShortintBootstrappingKey::to_fourier`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnsupportedStorageError {
+    pub storage: BootstrappingKeyStorage,
+}
+
+impl Display for UnsupportedStorageError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "This operation is not supported with a bootstrapping key stored as {:?}",
+            self.storage
+        )
+    }
+}
+
+impl std::error::Error for UnsupportedStorageError {}
+
+/// How the bootstrapping key of a [`ServerKey`] is stored, see [`ServerKey::from_compressed`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum BootstrappingKeyStorage {
+    /// The whole key is stored in the Fourier domain, this is the fastest option
+    Fourier,
+    /// Only the seeded key is stored, each GGSW ciphertext of the key is regenerated from the
+    /// seed and converted to the Fourier domain when a bootstrap uses it.
+    ///
+    /// This caps the memory used by the bootstrapping key to the size of the
+    /// [`CompressedServerKey`] bootstrapping key, at the cost of slower bootstraps.
+    OnTheFly,
+    /// The key of the multi-bit PBS is stored in the Fourier domain, see
+    /// [`MultiBitPBSParameters`](crate::shortint::parameters::MultiBitPBSParameters)
+    MultiBit,
+}
+
+/// Bootstrapping key of a [`ServerKey`], see [`BootstrappingKeyStorage`].
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum ShortintBootstrappingKey {
+    Fourier(FourierLweBootstrapKeyOwned),
+    OnTheFly(SeededLweBootstrapKeyOwned<u64>),
+    MultiBit(FourierLweMultiBitBootstrapKeyOwned),
+}
+
+impl ShortintBootstrappingKey {
+    pub fn storage(&self) -> BootstrappingKeyStorage {
+        match self {
+            Self::Fourier(_) => BootstrappingKeyStorage::Fourier,
+            Self::OnTheFly(_) => BootstrappingKeyStorage::OnTheFly,
+            Self::MultiBit(_) => BootstrappingKeyStorage::MultiBit,
+        }
+    }
+
+    pub fn input_lwe_dimension(&self) -> LweDimension {
+        match self {
+            Self::Fourier(bsk) => bsk.input_lwe_dimension(),
+            Self::OnTheFly(bsk) => bsk.input_lwe_dimension(),
+            Self::MultiBit(bsk) => bsk.input_lwe_dimension(),
+        }
+    }
+
+    pub fn output_lwe_dimension(&self) -> LweDimension {
+        match self {
+            Self::Fourier(bsk) => bsk.output_lwe_dimension(),
+            Self::OnTheFly(bsk) => bsk.output_lwe_dimension(),
+            Self::MultiBit(bsk) => bsk.output_lwe_dimension(),
+        }
+    }
+
+    pub fn polynomial_size(&self) -> PolynomialSize {
+        match self {
+            Self::Fourier(bsk) => bsk.polynomial_size(),
+            Self::OnTheFly(bsk) => bsk.polynomial_size(),
+            Self::MultiBit(bsk) => bsk.polynomial_size(),
+        }
+    }
+
+    pub fn glwe_size(&self) -> GlweSize {
+        match self {
+            Self::Fourier(bsk) => bsk.glwe_size(),
+            Self::OnTheFly(bsk) => bsk.glwe_size(),
+            Self::MultiBit(bsk) => bsk.glwe_size(),
+        }
+    }
+
+    pub fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        match self {
+            Self::Fourier(bsk) => bsk.decomposition_base_log(),
+            Self::OnTheFly(bsk) => bsk.decomposition_base_log(),
+            Self::MultiBit(bsk) => bsk.decomposition_base_log(),
+        }
+    }
+
+    pub fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        match self {
+            Self::Fourier(bsk) => bsk.decomposition_level_count(),
+            Self::OnTheFly(bsk) => bsk.decomposition_level_count(),
+            Self::MultiBit(bsk) => bsk.decomposition_level_count(),
+        }
+    }
+
+    /// Returns the key in the Fourier domain, needed by the algorithms that do not support
+    /// on-the-fly keys (e.g. the WoPBS or the bootstraps evaluating several look-up tables).
+    ///
+    /// A key stored as [`BootstrappingKeyStorage::OnTheFly`] is decompressed and converted for
+    /// the caller, which keeps the memory of the server key low at the cost of a conversion of
+    /// the whole key for each call.
+    ///
+    /// Returns an error if the key is stored as [`BootstrappingKeyStorage::MultiBit`], whose
+    /// algorithms have no equivalent for the multi-bit PBS.
+    pub fn to_fourier(
+        &self,
+    ) -> Result<Cow<'_, FourierLweBootstrapKeyOwned>, UnsupportedStorageError> {
+        match self {
+            Self::Fourier(bsk) => Ok(Cow::Borrowed(bsk)),
+            Self::OnTheFly(seeded_bsk) => {
+                let standard_bsk = seeded_bsk.clone().decompress_into_lwe_bootstrap_key();
+
+                let mut fourier_bsk = FourierLweBootstrapKeyOwned::new(
+                    standard_bsk.input_lwe_dimension(),
+                    standard_bsk.glwe_size(),
+                    standard_bsk.polynomial_size(),
+                    standard_bsk.decomposition_base_log(),
+                    standard_bsk.decomposition_level_count(),
+                );
+                convert_standard_lwe_bootstrap_key_to_fourier(&standard_bsk, &mut fourier_bsk);
+
+                Ok(Cow::Owned(fourier_bsk))
+            }
+            Self::MultiBit(_) => Err(UnsupportedStorageError {
+                storage: BootstrappingKeyStorage::MultiBit,
+            }),
+        }
+    }
+
+    /// Number of elements of the stored key, `c64` in the Fourier domain and `u64` for on-the-fly
+    /// keys.
+    pub fn size_elements(&self) -> usize {
+        match self {
+            Self::Fourier(bsk) => bsk.as_view().data().as_ref().len(),
+            Self::OnTheFly(bsk) => bsk.as_view().into_container().len(),
+            Self::MultiBit(bsk) => bsk.as_view().data().len(),
+        }
+    }
+
+    pub fn size_bytes(&self) -> usize {
+        match self {
+            Self::Fourier(_) | Self::MultiBit(_) => {
+                self.size_elements() * std::mem::size_of::<concrete_fft::c64>()
+            }
+            Self::OnTheFly(_) => self.size_elements() * std::mem::size_of::<u64>(),
+        }
+    }
+
+    /// Bootstraps `input` into `output` with `accumulator`, whatever the storage of the key.
+    pub(crate) fn programmable_bootstrap<InputCont, OutputCont, AccCont>(
+        &self,
+        input: &LweCiphertext<InputCont>,
+        output: &mut LweCiphertext<OutputCont>,
+        accumulator: &GlweCiphertext<AccCont>,
+        buffers: &mut ComputationBuffers,
+    ) where
+        InputCont: Container<Element = u64>,
+        OutputCont: ContainerMut<Element = u64>,
+        AccCont: Container<Element = u64>,
+    {
+        let fft = Fft::new(self.polynomial_size());
+        let fft = fft.as_view();
+
+        match self {
+            Self::Fourier(fourier_bsk) => {
+                buffers.resize(
+                    programmable_bootstrap_lwe_ciphertext_mem_optimized_requirement::<u64>(
+                        fourier_bsk.glwe_size(),
+                        fourier_bsk.polynomial_size(),
+                        fft,
+                    )
+                    .unwrap()
+                    .unaligned_bytes_required(),
+                );
+                let stack = buffers.stack();
+
+                programmable_bootstrap_lwe_ciphertext_mem_optimized(
+                    input,
+                    output,
+                    accumulator,
+                    fourier_bsk,
+                    fft,
+                    stack,
+                );
+            }
+            Self::OnTheFly(seeded_bsk) => {
+                buffers.resize(
+                    seeded_programmable_bootstrap_lwe_ciphertext_mem_optimized_requirement::<u64>(
+                        seeded_bsk.glwe_size(),
+                        seeded_bsk.polynomial_size(),
+                        seeded_bsk.decomposition_level_count(),
+                        fft,
+                    )
+                    .unwrap()
+                    .unaligned_bytes_required(),
+                );
+                let stack = buffers.stack();
+
+                seeded_programmable_bootstrap_lwe_ciphertext_mem_optimized(
+                    input,
+                    output,
+                    accumulator,
+                    seeded_bsk,
+                    fft,
+                    stack,
+                );
+            }
+            Self::MultiBit(multi_bit_bsk) => {
+                multi_bit_programmable_bootstrap_lwe_ciphertext(
+                    input,
+                    output,
+                    accumulator,
+                    multi_bit_bsk,
+                    multi_bit_thread_count(multi_bit_bsk.grouping_factor()),
+                );
+            }
+        }
+    }
+
+    /// Keyswitches `ct` with `key_switching_key` then bootstraps it into itself with
+    /// `accumulator`, whatever the storage of the key.
+    ///
+    /// With a key in the Fourier domain, the keyswitch is fused with the blind rotation: its
+    /// output is computed in small chunks consumed right away by the blind rotation, and
+    /// `buffer_lwe_after_ks` is not used.
+    pub(crate) fn keyswitch_programmable_bootstrap_assign<AccCont>(
+        &self,
+        key_switching_key: &LweKeyswitchKeyOwned<u64>,
+        ct: &mut LweCiphertextOwned<u64>,
+        accumulator: &GlweCiphertext<AccCont>,
+        buffer_lwe_after_ks: &mut LweCiphertextMutView<'_, u64>,
+        buffers: &mut ComputationBuffers,
+    ) where
+        AccCont: Container<Element = u64>,
+    {
+        match self {
+            Self::Fourier(fourier_bsk) => {
+                let fft = Fft::new(fourier_bsk.polynomial_size());
+                let fft = fft.as_view();
+                buffers.resize(
+                    keyswitch_programmable_bootstrap_lwe_ciphertext_mem_optimized_requirement::<
+                        u64,
+                    >(fourier_bsk.glwe_size(), fourier_bsk.polynomial_size(), fft)
+                    .unwrap()
+                    .unaligned_bytes_required(),
+                );
+                let stack = buffers.stack();
+
+                keyswitch_programmable_bootstrap_lwe_ciphertext_assign_mem_optimized(
+                    ct,
+                    accumulator,
+                    key_switching_key,
+                    fourier_bsk,
+                    fft,
+                    stack,
+                );
+            }
+            Self::OnTheFly(_) | Self::MultiBit(_) => {
+                keyswitch_lwe_ciphertext(key_switching_key, ct, buffer_lwe_after_ks);
+                self.programmable_bootstrap(buffer_lwe_after_ks, ct, accumulator, buffers);
+            }
+        }
+    }
+}
+
+/// Number of threads used by a multi-bit PBS: the GGSW ciphertexts of a group are processed in
+/// parallel, more threads would only wait on each other.
+fn multi_bit_thread_count(grouping_factor: LweBskGroupingFactor) -> ThreadCount {
+    let available = std::thread::available_parallelism().map_or(1, |count| count.get());
+    ThreadCount(
+        available
+            .min(grouping_factor.ggsw_per_multi_bit_element().0)
+            .max(1),
+    )
+}
+
 /// A structure containing the server public key.
 ///
 /// The server key is generated by the client and is meant to be published: the client
 /// sends it to the server so it can compute homomorphic circuits.
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+///
+/// The key switching key and the bootstrapping key are immutable once generated
+/// and make up almost all the memory used by the server key, they are stored in an [`Arc`]
+/// so that clones of a server key (e.g. one per worker thread) share the same memory,
+/// see [`ServerKey::share_memory`].
+#[derive(Clone, Debug, PartialEq)]
 pub struct ServerKey {
-    pub key_switching_key: LweKeyswitchKeyOwned<u64>,
-    pub bootstrapping_key: FourierLweBootstrapKeyOwned,
+    pub key_switching_key: Arc<LweKeyswitchKeyOwned<u64>>,
+    pub bootstrapping_key: Arc<ShortintBootstrappingKey>,
     // Size of the message buffer
     pub message_modulus: MessageModulus,
     // Size of the carry buffer
@@ -72,8 +455,212 @@ pub struct ServerKey {
     pub ciphertext_modulus: CiphertextModulus,
 }
 
+// serde's `rc` feature is needed to derive Serialize/Deserialize on `Arc`s,
+// as we don't want to force it on our users, the impls are written by hand.
+//
+// Serialization writes the content of the keys, so two clones that share
+// memory will not share it anymore once deserialized.
+//
+// The layout of a `ServerKey` is the one of the previous versions of the library, which only
+// stores bootstrapping keys in the Fourier domain. The other storages are serialized through
+// `VersionedServerKey`, whose layout starts with its version.
+#[derive(Serialize)]
+struct SerializableServerKey<'a> {
+    key_switching_key: &'a LweKeyswitchKeyOwned<u64>,
+    bootstrapping_key: &'a FourierLweBootstrapKeyOwned,
+    message_modulus: MessageModulus,
+    carry_modulus: CarryModulus,
+    max_degree: MaxDegree,
+    ciphertext_modulus: CiphertextModulus,
+}
+
+#[derive(Deserialize)]
+struct DeserializableServerKey {
+    key_switching_key: LweKeyswitchKeyOwned<u64>,
+    bootstrapping_key: FourierLweBootstrapKeyOwned,
+    message_modulus: MessageModulus,
+    carry_modulus: CarryModulus,
+    max_degree: MaxDegree,
+    ciphertext_modulus: CiphertextModulus,
+}
+
+impl Serialize for ServerKey {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        #[cfg(feature = "insecure-test-params")]
+        crate::shortint::parameters::check_serializable_key_dimensions(
+            self.bootstrapping_key.input_lwe_dimension(),
+            self.bootstrapping_key.polynomial_size(),
+        )?;
+
+        let ShortintBootstrappingKey::Fourier(bootstrapping_key) = &*self.bootstrapping_key else {
+            return Err(serde::ser::Error::custom(format!(
+                "A server key with a bootstrapping key stored as {:?} can only be serialized as a \
+                VersionedServerKey",
+                self.bootstrapping_key_storage()
+            )));
+        };
+
+        SerializableServerKey {
+            key_switching_key: &self.key_switching_key,
+            bootstrapping_key,
+            message_modulus: self.message_modulus,
+            carry_modulus: self.carry_modulus,
+            max_degree: self.max_degree,
+            ciphertext_modulus: self.ciphertext_modulus,
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for ServerKey {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        DeserializableServerKey::deserialize(deserializer).map(|deserialized| Self {
+            key_switching_key: Arc::new(deserialized.key_switching_key),
+            bootstrapping_key: Arc::new(ShortintBootstrappingKey::Fourier(
+                deserialized.bootstrapping_key,
+            )),
+            message_modulus: deserialized.message_modulus,
+            carry_modulus: deserialized.carry_modulus,
+            max_degree: deserialized.max_degree,
+            ciphertext_modulus: deserialized.ciphertext_modulus,
+        })
+    }
+}
+
+/// A [`ServerKey`] with a serialized layout supporting every [`BootstrappingKeyStorage`].
+///
+/// A [`ServerKey`] keeps the serialized layout of the previous versions of the library, which
+/// only stores bootstrapping keys in the Fourier domain: serializing a key stored on the fly or
+/// for the multi-bit PBS fails. Convert such keys to a `VersionedServerKey` with
+/// [`VersionedServerKey::from`] to serialize them, and back with [`ServerKey::from`] once
+/// deserialized.
+///
+/// The layout of a `VersionedServerKey` starts with its version, so that a key serialized with a
+/// layout this version of the library does not know is rejected instead of being misread.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+/// use tfhe::shortint::server_key::{BootstrappingKeyStorage, VersionedServerKey};
+/// use tfhe::shortint::{ClientKey, CompressedServerKey, ServerKey};
+///
+/// let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2);
+/// let compressed_sks = CompressedServerKey::new(&cks);
+/// let sks = ServerKey::from_compressed(compressed_sks, BootstrappingKeyStorage::OnTheFly);
+/// assert!(bincode::serialize(&sks).is_err());
+///
+/// let serialized = bincode::serialize(&VersionedServerKey::from(sks)).unwrap();
+/// let versioned: VersionedServerKey = bincode::deserialize(&serialized).unwrap();
+/// let sks = ServerKey::from(versioned);
+/// assert_eq!(sks.bootstrapping_key_storage(), BootstrappingKeyStorage::OnTheFly);
+///
+/// let ct = sks.unchecked_add(&cks.encrypt(1), &cks.encrypt(2));
+/// assert_eq!(cks.decrypt(&ct), 3);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct VersionedServerKey {
+    key: ServerKey,
+}
+
+impl From<ServerKey> for VersionedServerKey {
+    fn from(key: ServerKey) -> Self {
+        Self { key }
+    }
+}
+
+impl From<VersionedServerKey> for ServerKey {
+    fn from(versioned: VersionedServerKey) -> Self {
+        versioned.key
+    }
+}
+
+// A new layout is added as a new variant at the end of the versions enums
+#[derive(Serialize)]
+enum SerializableServerKeyVersions<'a> {
+    V0(SerializableServerKeyV0<'a>),
+}
+
+#[derive(Deserialize)]
+enum DeserializableServerKeyVersions {
+    V0(DeserializableServerKeyV0),
+}
+
+#[derive(Serialize)]
+struct SerializableServerKeyV0<'a> {
+    key_switching_key: &'a LweKeyswitchKeyOwned<u64>,
+    bootstrapping_key: &'a ShortintBootstrappingKey,
+    message_modulus: MessageModulus,
+    carry_modulus: CarryModulus,
+    max_degree: MaxDegree,
+    ciphertext_modulus: CiphertextModulus,
+}
+
+#[derive(Deserialize)]
+struct DeserializableServerKeyV0 {
+    key_switching_key: LweKeyswitchKeyOwned<u64>,
+    bootstrapping_key: ShortintBootstrappingKey,
+    message_modulus: MessageModulus,
+    carry_modulus: CarryModulus,
+    max_degree: MaxDegree,
+    ciphertext_modulus: CiphertextModulus,
+}
+
+impl Serialize for VersionedServerKey {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let key = &self.key;
+
+        #[cfg(feature = "insecure-test-params")]
+        crate::shortint::parameters::check_serializable_key_dimensions(
+            key.bootstrapping_key.input_lwe_dimension(),
+            key.bootstrapping_key.polynomial_size(),
+        )?;
+
+        SerializableServerKeyVersions::V0(SerializableServerKeyV0 {
+            key_switching_key: &key.key_switching_key,
+            bootstrapping_key: &key.bootstrapping_key,
+            message_modulus: key.message_modulus,
+            carry_modulus: key.carry_modulus,
+            max_degree: key.max_degree,
+            ciphertext_modulus: key.ciphertext_modulus,
+        })
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for VersionedServerKey {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        DeserializableServerKeyVersions::deserialize(deserializer).map(
+            |versioned| match versioned {
+                DeserializableServerKeyVersions::V0(deserialized) => Self {
+                    key: ServerKey {
+                        key_switching_key: Arc::new(deserialized.key_switching_key),
+                        bootstrapping_key: Arc::new(deserialized.bootstrapping_key),
+                        message_modulus: deserialized.message_modulus,
+                        carry_modulus: deserialized.carry_modulus,
+                        max_degree: deserialized.max_degree,
+                        ciphertext_modulus: deserialized.ciphertext_modulus,
+                    },
+                },
+            },
+        )
+    }
+}
+
 /// Returns whether it is possible to pack lhs and rhs into a unique
-/// ciphertext without exceeding the max storable value using the formula:
+/// ciphertext without exceeding the max storable value nor the max noise level using the formula:
 /// `unique_ciphertext = (lhs * factor) + rhs`
 fn ciphertexts_can_be_packed_without_exceeding_space<OpOrder: PBSOrderMarker>(
     lhs: &CiphertextBase<OpOrder>,
@@ -81,20 +668,64 @@ fn ciphertexts_can_be_packed_without_exceeding_space<OpOrder: PBSOrderMarker>(
     factor: usize,
 ) -> bool {
     let final_degree = (lhs.degree.0 * factor) + rhs.degree.0;
+    let final_noise_level = lhs.noise_level * factor + rhs.noise_level;
     final_degree < lhs.carry_modulus.0 * lhs.message_modulus.0
+        && MaxNoiseLevel::from_msg_carry_modulus(lhs.message_modulus, lhs.carry_modulus)
+            .validate(final_noise_level)
 }
 
-#[derive(Clone, Debug, PartialEq)]
+/// The accumulator of a univariate lookup table, as generated by
+/// [`ServerKey::generate_accumulator`].
+///
+/// A lookup table only depends on the parameters of the server key it was generated with, so it
+/// can be generated once and reused across requests, or serialized to be stored on disk. Use
+/// [`LookupTable::is_compatible_with`] to check that a stored lookup table can be used with a
+/// server key.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::shortint::gen_keys;
+/// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+/// use tfhe::shortint::server_key::LookupTableOwned;
+///
+/// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+///
+/// let acc = sks.generate_accumulator(|x| (x * x) % 4);
+/// let serialized = bincode::serialize(&acc).unwrap();
+///
+/// let acc: LookupTableOwned = bincode::deserialize(&serialized).unwrap();
+/// assert!(acc.is_compatible_with(&sks));
+///
+/// let ct_res = sks.apply_lookup_table(&cks.encrypt(3), &acc);
+/// assert_eq!(cks.decrypt(&ct_res), 1);
+/// ```
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 #[must_use]
 pub struct LookupTable<C: Container<Element = u64>> {
     pub acc: GlweCiphertext<C>,
     pub degree: Degree,
+    // The message and carry moduli of the server key the lookup table was generated with
+    pub message_modulus: MessageModulus,
+    pub carry_modulus: CarryModulus,
 }
 
 pub type LookupTableOwned = LookupTable<Vec<u64>>;
 pub type LookupTableMutView<'a> = LookupTable<&'a mut [u64]>;
 pub type LookupTableView<'a> = LookupTable<&'a [u64]>;
 
+impl<C: Container<Element = u64>> LookupTable<C> {
+    /// Returns whether the lookup table can be used in a PBS with `server_key`, i.e. whether it
+    /// was generated for the same message and carry moduli and the same GLWE ciphertexts.
+    pub fn is_compatible_with(&self, server_key: &ServerKey) -> bool {
+        self.message_modulus == server_key.message_modulus
+            && self.carry_modulus == server_key.carry_modulus
+            && self.acc.glwe_size() == server_key.bootstrapping_key.glwe_size()
+            && self.acc.polynomial_size() == server_key.bootstrapping_key.polynomial_size()
+            && self.acc.ciphertext_modulus() == server_key.ciphertext_modulus
+    }
+}
+
 #[must_use]
 pub struct BivariateLookupTable<C: Container<Element = u64>> {
     // A bivariate accumulator is an univariate accumulator
@@ -109,6 +740,25 @@ pub type BivariateLookupTableOwned = BivariateLookupTable<Vec<u64>>;
 pub type BivariateLookupTableMutView<'a> = BivariateLookupTable<&'a mut [u64]>;
 pub type BivariateLookupTableView<'a> = BivariateLookupTable<&'a [u64]>;
 
+/// An accumulator holding several look-up tables evaluated by a single PBS, see
+/// [`ServerKey::apply_many_lookup_tables`].
+#[derive(Clone, Debug, PartialEq)]
+#[must_use]
+pub struct ManyLookupTable<C: Container<Element = u64>> {
+    pub acc: GlweCiphertext<C>,
+    // The degree of the output of each look-up table
+    pub degrees: Vec<Degree>,
+}
+
+pub type ManyLookupTableOwned = ManyLookupTable<Vec<u64>>;
+
+impl<C: Container<Element = u64>> ManyLookupTable<C> {
+    /// Returns the number of look-up tables in the accumulator.
+    pub fn function_count(&self) -> usize {
+        self.degrees.len()
+    }
+}
+
 impl<C: Container<Element = u64>> BivariateLookupTable<C> {
     pub fn is_bivariate_pbs_possible<OpOrder: PBSOrderMarker>(
         &self,
@@ -138,6 +788,11 @@ impl ServerKey {
         ShortintEngine::with_thread_local_mut(|engine| engine.new_server_key(cks).unwrap())
     }
 
+    /// Returns the maximum noise level of the ciphertexts of the server key, see [`NoiseLevel`].
+    pub fn max_noise_level(&self) -> MaxNoiseLevel {
+        MaxNoiseLevel::from_msg_carry_modulus(self.message_modulus, self.carry_modulus)
+    }
+
     /// Generate a server key with a chosen maximum degree
     pub fn new_with_max_degree(cks: &ClientKey, max_degree: MaxDegree) -> ServerKey {
         ShortintEngine::with_thread_local_mut(|engine| {
@@ -372,6 +1027,77 @@ impl ServerKey {
         })
     }
 
+    /// Compute a keyswitch and programmable bootstrap applying a bivariate lookup table.
+    ///
+    /// If the two ciphertexts can not be packed in a single ciphertext without exceeding its
+    /// carry space, i.e. if [`BivariateLookupTable::is_bivariate_pbs_possible`] is false,
+    /// [CheckError::CarryFull] is returned instead of a ciphertext encrypting an erroneous value.
+    /// Use [`ServerKey::smart_apply_lookup_table_bivariate`] to clean the carries of the inputs
+    /// when needed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let ct1 = cks.encrypt(3);
+    /// let ct2 = cks.encrypt(2);
+    /// let modulus = cks.parameters.message_modulus().0 as u64;
+    ///
+    /// let acc = sks.generate_accumulator_bivariate(|x, y| x * y % modulus);
+    /// let ct_res = sks.checked_apply_lookup_table_bivariate(&ct1, &ct2, &acc);
+    /// assert_eq!(cks.decrypt(&ct_res.unwrap()), 2);
+    ///
+    /// // The sum has a carry, it does not fit next to ct2 anymore
+    /// let ct_sum = sks.unchecked_add(&ct1, &ct1);
+    /// let ct_res = sks.checked_apply_lookup_table_bivariate(&ct_sum, &ct2, &acc);
+    /// assert!(ct_res.is_err());
+    /// ```
+    pub fn checked_apply_lookup_table_bivariate<OpOrder: PBSOrderMarker>(
+        &self,
+        ct_left: &CiphertextBase<OpOrder>,
+        ct_right: &CiphertextBase<OpOrder>,
+        acc: &BivariateLookupTableOwned,
+    ) -> Result<CiphertextBase<OpOrder>, CheckError> {
+        self.check_operand(ct_left)?;
+        self.check_operand(ct_right)?;
+        if !acc.acc.is_compatible_with(self) {
+            return Err(CheckError::IncompatibleLookupTable);
+        }
+        if acc.is_bivariate_pbs_possible(ct_left, ct_right) {
+            Ok(self.unchecked_apply_lookup_table_bivariate(ct_left, ct_right, acc))
+        } else {
+            Err(CheckError::CarryFull)
+        }
+    }
+
+    /// Compute a keyswitch and programmable bootstrap applying a bivariate lookup table.
+    ///
+    /// If the operation can be performed, the result is stored in the `ct_left` ciphertext.
+    /// Otherwise [CheckError::CarryFull] is returned, and `ct_left` is not modified.
+    pub fn checked_apply_lookup_table_bivariate_assign<OpOrder: PBSOrderMarker>(
+        &self,
+        ct_left: &mut CiphertextBase<OpOrder>,
+        ct_right: &CiphertextBase<OpOrder>,
+        acc: &BivariateLookupTableOwned,
+    ) -> Result<(), CheckError> {
+        self.check_operand(ct_left)?;
+        self.check_operand(ct_right)?;
+        if !acc.acc.is_compatible_with(self) {
+            return Err(CheckError::IncompatibleLookupTable);
+        }
+        if acc.is_bivariate_pbs_possible(ct_left, ct_right) {
+            self.unchecked_apply_lookup_table_bivariate_assign(ct_left, ct_right, acc);
+            Ok(())
+        } else {
+            Err(CheckError::CarryFull)
+        }
+    }
+
     /// Compute a keyswitch and programmable bootstrap.
     ///
     /// # Example
@@ -465,6 +1191,165 @@ impl ServerKey {
         })
     }
 
+    /// Applies the lookup table `acc` to each ciphertext of `cts`.
+    ///
+    /// The bootstraps are spread over the threads of the rayon thread pool. Each thread reuses the
+    /// computation buffers of its thread local engine from one bootstrap to the next, and the FFT
+    /// plans are shared by all threads, so this is the fastest way to bootstrap many ciphertexts
+    /// with the same lookup table.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let cts: Vec<_> = (0..4).map(|msg| cks.encrypt(msg)).collect();
+    ///
+    /// let acc = sks.generate_accumulator(|x| x * x % 4);
+    /// let cts_res = sks.batch_apply_lookup_table(&cts, &acc);
+    ///
+    /// for (msg, ct_res) in cts_res.iter().enumerate() {
+    ///     let msg = msg as u64;
+    ///     assert_eq!(cks.decrypt(ct_res), msg * msg % 4);
+    /// }
+    /// ```
+    pub fn batch_apply_lookup_table<OpOrder: PBSOrderMarker>(
+        &self,
+        cts: &[CiphertextBase<OpOrder>],
+        acc: &LookupTableOwned,
+    ) -> Vec<CiphertextBase<OpOrder>> {
+        cts.par_iter()
+            .map(|ct| self.apply_lookup_table(ct, acc))
+            .collect()
+    }
+
+    /// Applies the lookup table `acc` to each ciphertext of `cts` in place, see
+    /// [`ServerKey::batch_apply_lookup_table`].
+    pub fn batch_apply_lookup_table_assign<OpOrder: PBSOrderMarker>(
+        &self,
+        cts: &mut [CiphertextBase<OpOrder>],
+        acc: &LookupTableOwned,
+    ) {
+        cts.par_iter_mut()
+            .for_each(|ct| self.apply_lookup_table_assign(ct, acc));
+    }
+
+    /// Compute a keyswitch and programmable bootstrap with checks.
+    ///
+    /// If the operation can be performed, the result is returned in a _new_ ciphertext.
+    /// Otherwise a [`CheckError`] describing the failed check is returned, see
+    /// [`ServerKey::check_operand`]. The lookup table must also have been generated with the
+    /// parameters of the server key.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::{PARAM_MESSAGE_1_CARRY_1, PARAM_MESSAGE_2_CARRY_2};
+    /// use tfhe::shortint::CheckError;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let ct = cks.encrypt(3);
+    /// let acc = sks.generate_accumulator(|x| x * x * x % 4);
+    /// let ct_res = sks.checked_apply_lookup_table(&ct, &acc).unwrap();
+    /// assert_eq!(cks.decrypt(&ct_res), 3);
+    ///
+    /// // A lookup table of other parameters is refused
+    /// let (_, other_sks) = gen_keys(PARAM_MESSAGE_1_CARRY_1);
+    /// let other_acc = other_sks.generate_accumulator(|x| x);
+    /// let res = sks.checked_apply_lookup_table(&ct, &other_acc);
+    /// assert_eq!(res, Err(CheckError::IncompatibleLookupTable));
+    /// ```
+    pub fn checked_apply_lookup_table<OpOrder: PBSOrderMarker>(
+        &self,
+        ct_in: &CiphertextBase<OpOrder>,
+        acc: &LookupTableOwned,
+    ) -> Result<CiphertextBase<OpOrder>, CheckError> {
+        let mut ct_res = ct_in.clone();
+        self.checked_apply_lookup_table_assign(&mut ct_res, acc)?;
+        Ok(ct_res)
+    }
+
+    /// Compute a keyswitch and programmable bootstrap with checks, see
+    /// [`ServerKey::checked_apply_lookup_table`].
+    ///
+    /// If the operation cannot be performed, `ct_in` is not modified.
+    pub fn checked_apply_lookup_table_assign<OpOrder: PBSOrderMarker>(
+        &self,
+        ct_in: &mut CiphertextBase<OpOrder>,
+        acc: &LookupTableOwned,
+    ) -> Result<(), CheckError> {
+        self.check_operand(ct_in)?;
+        if !acc.is_compatible_with(self) {
+            return Err(CheckError::IncompatibleLookupTable);
+        }
+        self.apply_lookup_table_assign(ct_in, acc);
+        Ok(())
+    }
+
+    /// Checks that `ct` can be an operand of the server key, i.e. that it was encrypted with the
+    /// message and carry moduli of the server key, and that its degree and noise level do not
+    /// exceed the maximum ones of the server key.
+    ///
+    /// The checked operations run this check on each of their operands, before checking that
+    /// the result of the operation fits in the message and carry space.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::ciphertext::Degree;
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    /// use tfhe::shortint::server_key::MaxDegree;
+    /// use tfhe::shortint::CheckError;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let mut ct = cks.encrypt(3);
+    /// assert_eq!(sks.check_operand(&ct), Ok(()));
+    ///
+    /// ct.degree = Degree(16);
+    /// assert_eq!(
+    ///     sks.check_operand(&ct),
+    ///     Err(CheckError::DegreeExceeded {
+    ///         degree: Degree(16),
+    ///         max_degree: MaxDegree(15),
+    ///     })
+    /// );
+    /// ```
+    pub fn check_operand<OpOrder: PBSOrderMarker>(
+        &self,
+        ct: &CiphertextBase<OpOrder>,
+    ) -> Result<(), CheckError> {
+        if ct.message_modulus != self.message_modulus || ct.carry_modulus != self.carry_modulus {
+            return Err(CheckError::MismatchedModuli {
+                expected: (self.message_modulus, self.carry_modulus),
+                actual: (ct.message_modulus, ct.carry_modulus),
+            });
+        }
+        if ct.degree.0 > self.max_degree.0 {
+            return Err(CheckError::DegreeExceeded {
+                degree: ct.degree,
+                max_degree: self.max_degree,
+            });
+        }
+        let max_noise_level = self.max_noise_level();
+        if !max_noise_level.validate(ct.noise_level) {
+            return Err(CheckError::NoiseLevelExceeded {
+                noise_level: ct.noise_level,
+                max_noise_level,
+            });
+        }
+        Ok(())
+    }
+
     /// Generic programmable bootstrap where messages are concatenated into one ciphertext to
     /// evaluate a bivariate function. This is used to apply many binary operations (comparisons,
     /// multiplications, division).
@@ -538,6 +1423,165 @@ impl ServerKey {
                 .unwrap()
         })
     }
+
+    /// Generic programmable bootstrap where three messages are concatenated into one ciphertext
+    /// to evaluate a trivariate function, e.g. a full adder or a multiplexer, with a single PBS.
+    ///
+    /// The messages are packed as `(ct_0 * (d_1 + 1) + ct_1) * (d_2 + 1) + ct_2`, `d_1` and `d_2`
+    /// being the degrees of `ct_1` and `ct_2`. The message and carry spaces are not checked, see
+    /// [`ServerKey::is_functional_trivariate_pbs_possible`].
+    pub fn unchecked_evaluate_trivariate_function<F, OpOrder: PBSOrderMarker>(
+        &self,
+        ct_0: &CiphertextBase<OpOrder>,
+        ct_1: &CiphertextBase<OpOrder>,
+        ct_2: &CiphertextBase<OpOrder>,
+        f: F,
+    ) -> CiphertextBase<OpOrder>
+    where
+        F: Fn(u64, u64, u64) -> u64,
+    {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine
+                .unchecked_evaluate_trivariate_function(self, ct_0, ct_1, ct_2, f)
+                .unwrap()
+        })
+    }
+
+    /// Evaluates a trivariate function with a single PBS, the result being written in `ct_0`,
+    /// see [`ServerKey::unchecked_evaluate_trivariate_function`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_1_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_1_CARRY_2);
+    ///
+    /// let mut control = cks.encrypt(1);
+    /// let if_true = cks.encrypt(1);
+    /// let if_false = cks.encrypt(0);
+    /// assert!(sks.is_functional_trivariate_pbs_possible(&control, &if_true, &if_false));
+    ///
+    /// // A multiplexer
+    /// sks.unchecked_evaluate_trivariate_function_assign(
+    ///     &mut control,
+    ///     &if_true,
+    ///     &if_false,
+    ///     |control, x, y| if control == 1 { x } else { y },
+    /// );
+    /// assert_eq!(cks.decrypt(&control), 1);
+    /// ```
+    pub fn unchecked_evaluate_trivariate_function_assign<F, OpOrder: PBSOrderMarker>(
+        &self,
+        ct_0: &mut CiphertextBase<OpOrder>,
+        ct_1: &CiphertextBase<OpOrder>,
+        ct_2: &CiphertextBase<OpOrder>,
+        f: F,
+    ) where
+        F: Fn(u64, u64, u64) -> u64,
+    {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine
+                .unchecked_evaluate_trivariate_function_assign(self, ct_0, ct_1, ct_2, f)
+                .unwrap()
+        })
+    }
+
+    /// Verify if a functional trivariate pbs can be applied on ct_0, ct_1 and ct_2, i.e. if the
+    /// product of their degrees plus one fits in the message and carry space, and if the noise
+    /// level of the packed ciphertext does not exceed the maximum one.
+    pub fn is_functional_trivariate_pbs_possible<OpOrder: PBSOrderMarker>(
+        &self,
+        ct_0: &CiphertextBase<OpOrder>,
+        ct_1: &CiphertextBase<OpOrder>,
+        ct_2: &CiphertextBase<OpOrder>,
+    ) -> bool {
+        let (modulus_1, modulus_2) = (ct_1.degree.0 + 1, ct_2.degree.0 + 1);
+        let packed_modulus = (ct_0.degree.0 + 1) * modulus_1 * modulus_2;
+        let packed_noise_level =
+            (ct_0.noise_level * modulus_1 + ct_1.noise_level) * modulus_2 + ct_2.noise_level;
+        packed_modulus <= ct_0.carry_modulus.0 * ct_0.message_modulus.0
+            && self.max_noise_level().validate(packed_noise_level)
+    }
+
+    /// Evaluates a trivariate function with a single PBS, see
+    /// [`ServerKey::unchecked_evaluate_trivariate_function`].
+    ///
+    /// If the operands cannot be packed together, the ones with carries or with more than the
+    /// nominal noise are cleaned first with [`ServerKey::message_extract`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the operands cannot be packed even once cleaned. With `d_0`, `d_1` and `d_2`
+    /// the degrees of the cleaned operands (at most `message_modulus - 1`):
+    /// - the packed degree `(d_0 + 1) * (d_1 + 1) * (d_2 + 1)` must fit in the message and carry
+    ///   space,
+    /// - the packed noise level `(d_1 + 2) * (d_2 + 1) + 1` must not exceed the
+    ///   [maximum noise level](ServerKey::max_noise_level).
+    ///
+    /// For instance, the parameters `PARAM_MESSAGE_2_CARRY_2` cannot pack three booleans (operands
+    /// of degree 1) even with the nominal noise: the packed noise level is 7 while the maximum one
+    /// is 5.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_1_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_1_CARRY_2);
+    ///
+    /// let mut a = cks.encrypt(1);
+    /// let mut b = cks.encrypt(1);
+    /// let mut carry_in = cks.encrypt(1);
+    ///
+    /// // The sum and the carry of a full adder, with one PBS each
+    /// let sum = sks.smart_evaluate_trivariate_function(&mut a, &mut b, &mut carry_in, |a, b, c| {
+    ///     a ^ b ^ c
+    /// });
+    /// let carry_out =
+    ///     sks.smart_evaluate_trivariate_function(&mut a, &mut b, &mut carry_in, |a, b, c| {
+    ///         (a & b) | (c & (a ^ b))
+    ///     });
+    ///
+    /// assert_eq!(cks.decrypt(&sum), 1);
+    /// assert_eq!(cks.decrypt(&carry_out), 1);
+    /// ```
+    pub fn smart_evaluate_trivariate_function<F, OpOrder: PBSOrderMarker>(
+        &self,
+        ct_0: &mut CiphertextBase<OpOrder>,
+        ct_1: &mut CiphertextBase<OpOrder>,
+        ct_2: &mut CiphertextBase<OpOrder>,
+        f: F,
+    ) -> CiphertextBase<OpOrder>
+    where
+        F: Fn(u64, u64, u64) -> u64,
+    {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine
+                .smart_evaluate_trivariate_function(self, ct_0, ct_1, ct_2, f)
+                .unwrap()
+        })
+    }
+
+    pub fn smart_evaluate_trivariate_function_assign<F, OpOrder: PBSOrderMarker>(
+        &self,
+        ct_0: &mut CiphertextBase<OpOrder>,
+        ct_1: &mut CiphertextBase<OpOrder>,
+        ct_2: &mut CiphertextBase<OpOrder>,
+        f: F,
+    ) where
+        F: Fn(u64, u64, u64) -> u64,
+    {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine
+                .smart_evaluate_trivariate_function_assign(self, ct_0, ct_1, ct_2, f)
+                .unwrap()
+        })
+    }
     /// Replace the input encrypted message by the value of its carry buffer.
     ///
     /// # Example
@@ -744,9 +1788,157 @@ impl ServerKey {
         ShortintEngine::with_thread_local_mut(|engine| engine.message_extract(self, ct).unwrap())
     }
 
+    /// Extract the carry buffer and the message buffer of a ciphertext, returned in this order.
+    ///
+    /// This is equivalent to calling [`Self::carry_extract`] and [`Self::message_extract`], but
+    /// both look-up tables are evaluated by a single bootstrap. The rotations of that bootstrap
+    /// are less precise, which doubles the noise of its modulus switch. When the polynomial size
+    /// is too small to pack both look-up tables, two bootstraps are used instead.
+    ///
+    /// # Example
+    ///
+    ///```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::{PARAM_MESSAGE_2_CARRY_2, PARAM_SMALL_MESSAGE_2_CARRY_2};
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let clear = 9;
+    ///
+    /// // Encrypt a message
+    /// let ct = cks.unchecked_encrypt(clear);
+    ///
+    /// // Compute homomorphically the carry and message extraction
+    /// let (carry, message) = sks.carry_and_message_extract(&ct);
+    ///
+    /// assert_eq!(cks.decrypt(&carry), 2);
+    /// assert_eq!(cks.decrypt(&message), 1);
+    ///
+    /// let (cks, sks) = gen_keys(PARAM_SMALL_MESSAGE_2_CARRY_2);
+    ///
+    /// let ct = cks.unchecked_encrypt_small(clear);
+    ///
+    /// let (carry, message) = sks.carry_and_message_extract(&ct);
+    ///
+    /// assert_eq!(cks.decrypt(&carry), 2);
+    /// assert_eq!(cks.decrypt(&message), 1);
+    /// ```
+    pub fn carry_and_message_extract<OpOrder: PBSOrderMarker>(
+        &self,
+        ct: &CiphertextBase<OpOrder>,
+    ) -> (CiphertextBase<OpOrder>, CiphertextBase<OpOrder>) {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine.carry_and_message_extract(self, ct).unwrap()
+        })
+    }
+
+    /// Returns the maximum number of look-up tables that can be evaluated by a single PBS with
+    /// [`ServerKey::apply_many_lookup_tables`].
+    ///
+    /// Each of the `message_modulus * carry_modulus` boxes of an accumulator holds one value per
+    /// look-up table in each of its halves, so the maximum is half the size of a box.
+    pub fn max_many_lookup_table_count(&self) -> usize {
+        let modulus_sup = self.message_modulus.0 * self.carry_modulus.0;
+        self.bootstrapping_key.polynomial_size().0 / modulus_sup / 2
+    }
+
+    /// Constructs an accumulator evaluating all the `functions` with a single PBS.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of functions is not a power of two or is greater than
+    /// [`ServerKey::max_many_lookup_table_count`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let square = |x: u64| (x * x) % 4;
+    /// let double = |x: u64| 2 * x;
+    /// let acc = sks.generate_many_accumulator(&[&square, &double]);
+    /// assert_eq!(acc.function_count(), 2);
+    /// ```
+    pub fn generate_many_accumulator(
+        &self,
+        functions: &[&dyn Fn(u64) -> u64],
+    ) -> ManyLookupTableOwned {
+        assert!(
+            functions.len().is_power_of_two(),
+            "The number of functions must be a power of two, got {}",
+            functions.len()
+        );
+        assert!(
+            functions.len() <= self.max_many_lookup_table_count(),
+            "At most {} functions can be evaluated by a single PBS, got {}",
+            self.max_many_lookup_table_count(),
+            functions.len()
+        );
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine.generate_many_accumulator(self, functions).unwrap()
+        })
+    }
+
+    /// Evaluates all the look-up tables of `acc` on `ct` with a single PBS, the outputs are in
+    /// the order of the functions given to [`ServerKey::generate_many_accumulator`].
+    ///
+    /// Evaluating `k` look-up tables costs roughly one PBS instead of `k`, but the PBS is less
+    /// tolerant to noise: the input is rounded to a multiple of `k` coefficients of the
+    /// accumulator before the blind rotation, so `k` should be kept small for inputs that are
+    /// not fresh.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the bootstrapping key is a multi-bit key.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::{PARAM_MESSAGE_2_CARRY_2, PARAM_SMALL_MESSAGE_2_CARRY_2};
+    ///
+    /// let msg = 3;
+    ///
+    /// let square = |x: u64| (x * x) % 4;
+    /// let double = |x: u64| 2 * x;
+    ///
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let acc = sks.generate_many_accumulator(&[&square, &double]);
+    /// let results = sks.apply_many_lookup_tables(&cks.encrypt(msg), &acc);
+    ///
+    /// assert_eq!(cks.decrypt_message_and_carry(&results[0]), square(msg));
+    /// assert_eq!(cks.decrypt_message_and_carry(&results[1]), double(msg));
+    ///
+    /// let (cks, sks) = gen_keys(PARAM_SMALL_MESSAGE_2_CARRY_2);
+    ///
+    /// let acc = sks.generate_many_accumulator(&[&square, &double]);
+    /// let results = sks.apply_many_lookup_tables(&cks.encrypt_small(msg), &acc);
+    ///
+    /// assert_eq!(cks.decrypt_message_and_carry(&results[0]), square(msg));
+    /// assert_eq!(cks.decrypt_message_and_carry(&results[1]), double(msg));
+    /// ```
+    pub fn apply_many_lookup_tables<OpOrder: PBSOrderMarker>(
+        &self,
+        ct: &CiphertextBase<OpOrder>,
+        acc: &ManyLookupTableOwned,
+    ) -> Vec<CiphertextBase<OpOrder>> {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine.apply_many_lookup_tables(self, ct, acc).unwrap()
+        })
+    }
+
     /// Compute a trivial shortint ciphertext with the dimension of the big LWE secret key from a
     /// given value.
     ///
+    /// A trivial ciphertext has no mask and no noise: it is a cheap way to use a public constant
+    /// in homomorphic operations, and it can be decrypted without the client key with
+    /// [`CiphertextBase::decrypt_trivial`].
+    ///
     /// # Example
     ///
     /// ```rust
@@ -763,6 +1955,9 @@ impl ServerKey {
     ///
     /// let ct_res = cks.decrypt(&ct1);
     /// assert_eq!(1, ct_res);
+    ///
+    /// assert!(ct1.is_trivial());
+    /// assert_eq!(ct1.decrypt_trivial(), Ok(1));
     /// ```
     pub fn create_trivial<PBSOrder: PBSOrderMarker>(&self, value: u64) -> CiphertextBase<PBSOrder> {
         ShortintEngine::with_thread_local_mut(|engine| {
@@ -782,25 +1977,111 @@ impl ServerKey {
         })
     }
 
+    /// Returns a new handle on this server key, sharing its memory.
+    ///
+    /// This is what [`Clone::clone`] does, this method exists to make it explicit
+    /// that the (large) key switching and bootstrapping keys are not copied,
+    /// e.g. when giving a server key to each worker thread.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let worker_sks = sks.share_memory();
+    /// assert!(worker_sks.shares_memory_with(&sks));
+    ///
+    /// let ct = cks.encrypt(1);
+    /// let ct_res = std::thread::spawn(move || worker_sks.add(&ct, &ct))
+    ///     .join()
+    ///     .unwrap();
+    /// assert_eq!(cks.decrypt(&ct_res), 2);
+    /// ```
+    pub fn share_memory(&self) -> Self {
+        self.clone()
+    }
+
+    /// Makes this server key share the memory of `other` if both keys are equal.
+    ///
+    /// This is useful when the same server key has been deserialized several times
+    /// (each deserialization allocates its own copy of the keys).
+    ///
+    /// Returns true if the memory is now shared.
+    pub fn share_memory_with(&mut self, other: &Self) -> bool {
+        if self.shares_memory_with(other) {
+            return true;
+        }
+
+        if self != other {
+            return false;
+        }
+
+        self.key_switching_key = Arc::clone(&other.key_switching_key);
+        self.bootstrapping_key = Arc::clone(&other.bootstrapping_key);
+        true
+    }
+
+    /// Returns true if both server keys use the same memory
+    /// for their key switching and bootstrapping keys.
+    pub fn shares_memory_with(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.key_switching_key, &other.key_switching_key)
+            && Arc::ptr_eq(&self.bootstrapping_key, &other.bootstrapping_key)
+    }
+
     pub fn bootstrapping_key_size_elements(&self) -> usize {
-        self.bootstrapping_key.as_view().data().as_ref().len()
+        self.bootstrapping_key.size_elements()
     }
 
     pub fn bootstrapping_key_size_bytes(&self) -> usize {
-        self.bootstrapping_key_size_elements() * std::mem::size_of::<concrete_fft::c64>()
+        self.bootstrapping_key.size_bytes()
     }
 
     pub fn key_switching_key_size_elements(&self) -> usize {
-        self.key_switching_key.as_ref().len()
+        let key_switching_key: &LweKeyswitchKeyOwned<u64> = &self.key_switching_key;
+        key_switching_key.as_ref().len()
     }
 
     pub fn key_switching_key_size_bytes(&self) -> usize {
         self.key_switching_key_size_elements() * std::mem::size_of::<u64>()
     }
-}
 
-impl From<CompressedServerKey> for ServerKey {
-    fn from(compressed_server_key: CompressedServerKey) -> Self {
+    /// Decompresses a [`CompressedServerKey`], storing the bootstrapping key as requested by
+    /// `storage`.
+    ///
+    /// [`BootstrappingKeyStorage::OnTheFly`] keeps the seeded bootstrapping key as is, which
+    /// divides the memory used by the bootstrapping key by `k + 1` (for a GLWE dimension `k`)
+    /// compared to the Fourier domain, at the cost of regenerating the key on each bootstrap.
+    /// Operations that need the whole key in the Fourier domain (bootstraps evaluating several
+    /// look-up tables, WoPBS) fall back to slower alternatives or panic with such a key.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `storage` is [`BootstrappingKeyStorage::MultiBit`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    /// use tfhe::shortint::server_key::{BootstrappingKeyStorage, CompressedServerKey};
+    /// use tfhe::shortint::{ClientKey, ServerKey};
+    ///
+    /// let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2);
+    /// let compressed_sks = CompressedServerKey::new(&cks);
+    ///
+    /// let sks = ServerKey::from_compressed(compressed_sks, BootstrappingKeyStorage::OnTheFly);
+    ///
+    /// let ct = cks.encrypt(3);
+    /// let acc = sks.generate_accumulator(|x| x * x % 4);
+    /// let ct_res = sks.apply_lookup_table(&ct, &acc);
+    /// assert_eq!(cks.decrypt(&ct_res), 1);
+    /// ```
+    pub fn from_compressed(
+        compressed_server_key: CompressedServerKey,
+        storage: BootstrappingKeyStorage,
+    ) -> Self {
         let CompressedServerKey {
             key_switching_key,
             bootstrapping_key,
@@ -811,28 +2092,52 @@ impl From<CompressedServerKey> for ServerKey {
         } = compressed_server_key;
 
         let key_switching_key = key_switching_key.decompress_into_lwe_keyswitch_key();
-        let standard_bootstrapping_key = bootstrapping_key.decompress_into_lwe_bootstrap_key();
-
-        let mut bootstrapping_key = FourierLweBootstrapKeyOwned::new(
-            standard_bootstrapping_key.input_lwe_dimension(),
-            standard_bootstrapping_key.glwe_size(),
-            standard_bootstrapping_key.polynomial_size(),
-            standard_bootstrapping_key.decomposition_base_log(),
-            standard_bootstrapping_key.decomposition_level_count(),
-        );
 
-        convert_standard_lwe_bootstrap_key_to_fourier(
-            &standard_bootstrapping_key,
-            &mut bootstrapping_key,
-        );
+        let bootstrapping_key = match storage {
+            BootstrappingKeyStorage::Fourier => {
+                let standard_bootstrapping_key =
+                    bootstrapping_key.decompress_into_lwe_bootstrap_key();
+
+                let mut bootstrapping_key = FourierLweBootstrapKeyOwned::new(
+                    standard_bootstrapping_key.input_lwe_dimension(),
+                    standard_bootstrapping_key.glwe_size(),
+                    standard_bootstrapping_key.polynomial_size(),
+                    standard_bootstrapping_key.decomposition_base_log(),
+                    standard_bootstrapping_key.decomposition_level_count(),
+                );
+
+                convert_standard_lwe_bootstrap_key_to_fourier(
+                    &standard_bootstrapping_key,
+                    &mut bootstrapping_key,
+                );
+
+                ShortintBootstrappingKey::Fourier(bootstrapping_key)
+            }
+            BootstrappingKeyStorage::OnTheFly => {
+                ShortintBootstrappingKey::OnTheFly(bootstrapping_key)
+            }
+            BootstrappingKeyStorage::MultiBit => {
+                panic!("Compressed server keys do not support the multi-bit PBS")
+            }
+        };
 
         Self {
-            key_switching_key,
-            bootstrapping_key,
+            key_switching_key: Arc::new(key_switching_key),
+            bootstrapping_key: Arc::new(bootstrapping_key),
             message_modulus,
             carry_modulus,
             max_degree,
             ciphertext_modulus,
         }
     }
+
+    pub fn bootstrapping_key_storage(&self) -> BootstrappingKeyStorage {
+        self.bootstrapping_key.storage()
+    }
+}
+
+impl From<CompressedServerKey> for ServerKey {
+    fn from(compressed_server_key: CompressedServerKey) -> Self {
+        Self::from_compressed(compressed_server_key, BootstrappingKeyStorage::Fourier)
+    }
 }
@@ -304,6 +304,8 @@ impl ServerKey {
         ct_left: &CiphertextBase<OpOrder>,
         ct_right: &CiphertextBase<OpOrder>,
     ) -> Result<CiphertextBase<OpOrder>, CheckError> {
+        self.check_operand(ct_left)?;
+        self.check_operand(ct_right)?;
         if self.is_mul_possible(ct_left, ct_right) {
             let ct_result = self.unchecked_mul_lsb(ct_left, ct_right);
             Ok(ct_result)
@@ -363,6 +365,8 @@ impl ServerKey {
         ct_left: &mut CiphertextBase<OpOrder>,
         ct_right: &CiphertextBase<OpOrder>,
     ) -> Result<(), CheckError> {
+        self.check_operand(ct_left)?;
+        self.check_operand(ct_right)?;
         if self.is_mul_possible(ct_left, ct_right) {
             self.unchecked_mul_lsb_assign(ct_left, ct_right);
             Ok(())
@@ -371,6 +375,53 @@ impl ServerKey {
         }
     }
 
+    /// Multiply two ciphertexts together with checks, see [`ServerKey::checked_mul_lsb`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::{PARAM_MESSAGE_1_CARRY_1, PARAM_MESSAGE_2_CARRY_2};
+    /// use tfhe::shortint::CheckError;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let ct_1 = cks.encrypt(3);
+    /// let ct_2 = cks.encrypt(3);
+    ///
+    /// let ct_res = sks.checked_mul(&ct_1, &ct_2).unwrap();
+    /// assert_eq!(cks.decrypt(&ct_res), 1);
+    ///
+    /// // The sum has a carry, it cannot be multiplied anymore
+    /// let ct_sum = sks.unchecked_add(&ct_1, &ct_2);
+    /// assert_eq!(sks.checked_mul(&ct_sum, &ct_2), Err(CheckError::CarryFull));
+    ///
+    /// // A ciphertext of other parameters is refused
+    /// let (other_cks, _) = gen_keys(PARAM_MESSAGE_1_CARRY_1);
+    /// let ct_other = other_cks.encrypt(1);
+    /// assert!(matches!(
+    ///     sks.checked_mul(&ct_1, &ct_other),
+    ///     Err(CheckError::MismatchedModuli { .. })
+    /// ));
+    /// ```
+    pub fn checked_mul<OpOrder: PBSOrderMarker>(
+        &self,
+        ct_left: &CiphertextBase<OpOrder>,
+        ct_right: &CiphertextBase<OpOrder>,
+    ) -> Result<CiphertextBase<OpOrder>, CheckError> {
+        self.checked_mul_lsb(ct_left, ct_right)
+    }
+
+    /// Multiply two ciphertexts together with checks, see [`ServerKey::checked_mul_lsb_assign`].
+    pub fn checked_mul_assign<OpOrder: PBSOrderMarker>(
+        &self,
+        ct_left: &mut CiphertextBase<OpOrder>,
+        ct_right: &CiphertextBase<OpOrder>,
+    ) -> Result<(), CheckError> {
+        self.checked_mul_lsb_assign(ct_left, ct_right)
+    }
+
     /// Multiply two ciphertexts together without checks.
     ///
     /// Return the "most significant bits" of the multiplication, i.e., the part in the carry
@@ -444,6 +495,8 @@ impl ServerKey {
         ct_left: &CiphertextBase<OpOrder>,
         ct_right: &CiphertextBase<OpOrder>,
     ) -> Result<CiphertextBase<OpOrder>, CheckError> {
+        self.check_operand(ct_left)?;
+        self.check_operand(ct_right)?;
         if self.is_mul_possible(ct_left, ct_right) {
             let ct_result = self.unchecked_mul_msb(ct_left, ct_right);
             Ok(ct_result)
@@ -636,6 +689,8 @@ impl ServerKey {
         ct_left: &mut CiphertextBase<OpOrder>,
         ct_right: &mut CiphertextBase<OpOrder>,
     ) -> Result<CiphertextBase<OpOrder>, CheckError> {
+        self.check_operand(ct_left)?;
+        self.check_operand(ct_right)?;
         if self.is_mul_small_carry_possible(ct_left, ct_right) {
             let mut ct_result = self.unchecked_mul_lsb_small_carry(ct_left, ct_right);
             ct_result.degree = Degree(ct_left.degree.0 * 2);
@@ -270,7 +270,7 @@ impl ServerKey {
         // counter = z / (2^p-1)
         let counter = z / (self.message_modulus.0 - 1);
 
-        counter <= self.max_degree.0
+        counter <= self.max_degree.0 && self.max_noise_level().validate(ct.noise_level)
     }
 
     /// Compute homomorphically a negation of a ciphertext.
@@ -319,6 +319,7 @@ impl ServerKey {
         &self,
         ct: &CiphertextBase<OpOrder>,
     ) -> Result<CiphertextBase<OpOrder>, CheckError> {
+        self.check_operand(ct)?;
         // If the ciphertext cannot be negated without exceeding the capacity of a ciphertext
         if self.is_neg_possible(ct) {
             let ct_result = self.unchecked_neg(ct);
@@ -376,6 +377,7 @@ impl ServerKey {
         &self,
         ct: &mut CiphertextBase<OpOrder>,
     ) -> Result<(), CheckError> {
+        self.check_operand(ct)?;
         if self.is_neg_possible(ct) {
             self.unchecked_neg_assign(ct);
             Ok(())
@@ -0,0 +1,405 @@
+use super::{LookupTableOwned, ServerKey};
+use crate::shortint::ciphertext::Degree;
+use crate::shortint::engine::ShortintEngine;
+use crate::shortint::{CiphertextNoPaddingBase, PBSOrderMarker};
+
+/// The accumulators evaluating an arbitrary function over the whole space of ciphertexts without
+/// padding bit, as generated by [`ServerKey::generate_lookup_table_full_domain`].
+#[derive(Clone, Debug, PartialEq)]
+#[must_use]
+pub struct FullDomainLookupTable {
+    // Folds the input in the space of a ciphertext with padding bit, independent of the function
+    pub(crate) folding: LookupTableOwned,
+    pub(crate) acc: LookupTableOwned,
+}
+
+impl FullDomainLookupTable {
+    /// Returns the degree of the output of the function.
+    pub fn degree(&self) -> Degree {
+        self.acc.degree
+    }
+}
+
+/// Operations on ciphertexts encrypted without padding bit.
+///
+/// All these operations are computed modulo `message_modulus * carry_modulus`,
+/// as there is no padding bit to absorb overflows, values wrap around.
+impl ServerKey {
+    /// Compute homomorphically an addition between two ciphertexts encrypted without padding bit.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let msg1 = 13;
+    /// let msg2 = 7;
+    /// let modulus = 16;
+    ///
+    /// let ct1 = cks.encrypt_no_padding(msg1);
+    /// let ct2 = cks.encrypt_no_padding(msg2);
+    ///
+    /// let ct_res = sks.unchecked_add_no_padding(&ct1, &ct2);
+    ///
+    /// let res = cks.decrypt_no_padding(&ct_res);
+    /// assert_eq!(res, (msg1 + msg2) % modulus);
+    /// ```
+    pub fn unchecked_add_no_padding<OpOrder: PBSOrderMarker>(
+        &self,
+        ct_left: &CiphertextNoPaddingBase<OpOrder>,
+        ct_right: &CiphertextNoPaddingBase<OpOrder>,
+    ) -> CiphertextNoPaddingBase<OpOrder> {
+        let mut result = ct_left.clone();
+        self.unchecked_add_no_padding_assign(&mut result, ct_right);
+        result
+    }
+
+    pub fn unchecked_add_no_padding_assign<OpOrder: PBSOrderMarker>(
+        &self,
+        ct_left: &mut CiphertextNoPaddingBase<OpOrder>,
+        ct_right: &CiphertextNoPaddingBase<OpOrder>,
+    ) {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine
+                .unchecked_add_no_padding_assign(ct_left, ct_right)
+                .unwrap()
+        })
+    }
+
+    /// Compute homomorphically a subtraction between two ciphertexts encrypted without padding
+    /// bit.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let msg1 = 3;
+    /// let msg2 = 9;
+    /// let modulus = 16;
+    ///
+    /// let ct1 = cks.encrypt_no_padding(msg1);
+    /// let ct2 = cks.encrypt_no_padding(msg2);
+    ///
+    /// let ct_res = sks.unchecked_sub_no_padding(&ct1, &ct2);
+    ///
+    /// let res = cks.decrypt_no_padding(&ct_res);
+    /// assert_eq!(res, (msg1 + modulus - msg2) % modulus);
+    /// ```
+    pub fn unchecked_sub_no_padding<OpOrder: PBSOrderMarker>(
+        &self,
+        ct_left: &CiphertextNoPaddingBase<OpOrder>,
+        ct_right: &CiphertextNoPaddingBase<OpOrder>,
+    ) -> CiphertextNoPaddingBase<OpOrder> {
+        let mut result = ct_left.clone();
+        self.unchecked_sub_no_padding_assign(&mut result, ct_right);
+        result
+    }
+
+    pub fn unchecked_sub_no_padding_assign<OpOrder: PBSOrderMarker>(
+        &self,
+        ct_left: &mut CiphertextNoPaddingBase<OpOrder>,
+        ct_right: &CiphertextNoPaddingBase<OpOrder>,
+    ) {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine
+                .unchecked_sub_no_padding_assign(ct_left, ct_right)
+                .unwrap()
+        })
+    }
+
+    /// Compute homomorphically the negation of a ciphertext encrypted without padding bit.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let msg = 5;
+    /// let modulus = 16;
+    ///
+    /// let ct = cks.encrypt_no_padding(msg);
+    ///
+    /// let ct_res = sks.unchecked_neg_no_padding(&ct);
+    ///
+    /// let res = cks.decrypt_no_padding(&ct_res);
+    /// assert_eq!(res, modulus - msg);
+    /// ```
+    pub fn unchecked_neg_no_padding<OpOrder: PBSOrderMarker>(
+        &self,
+        ct: &CiphertextNoPaddingBase<OpOrder>,
+    ) -> CiphertextNoPaddingBase<OpOrder> {
+        let mut result = ct.clone();
+        self.unchecked_neg_no_padding_assign(&mut result);
+        result
+    }
+
+    pub fn unchecked_neg_no_padding_assign<OpOrder: PBSOrderMarker>(
+        &self,
+        ct: &mut CiphertextNoPaddingBase<OpOrder>,
+    ) {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine.unchecked_neg_no_padding_assign(ct).unwrap()
+        })
+    }
+
+    /// Compute homomorphically the addition of a ciphertext encrypted without padding bit
+    /// with a scalar.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let msg = 12;
+    /// let scalar = 7;
+    /// let modulus = 16;
+    ///
+    /// let ct = cks.encrypt_no_padding(msg);
+    ///
+    /// let ct_res = sks.unchecked_scalar_add_no_padding(&ct, scalar);
+    ///
+    /// let res = cks.decrypt_no_padding(&ct_res);
+    /// assert_eq!(res, (msg + scalar as u64) % modulus);
+    /// ```
+    pub fn unchecked_scalar_add_no_padding<OpOrder: PBSOrderMarker>(
+        &self,
+        ct: &CiphertextNoPaddingBase<OpOrder>,
+        scalar: u8,
+    ) -> CiphertextNoPaddingBase<OpOrder> {
+        let mut result = ct.clone();
+        self.unchecked_scalar_add_no_padding_assign(&mut result, scalar);
+        result
+    }
+
+    pub fn unchecked_scalar_add_no_padding_assign<OpOrder: PBSOrderMarker>(
+        &self,
+        ct: &mut CiphertextNoPaddingBase<OpOrder>,
+        scalar: u8,
+    ) {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine
+                .unchecked_scalar_add_no_padding_assign(ct, scalar)
+                .unwrap()
+        })
+    }
+
+    /// Compute homomorphically the multiplication of a ciphertext encrypted without padding bit
+    /// by a scalar.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let msg = 11;
+    /// let scalar = 3;
+    /// let modulus = 16;
+    ///
+    /// let ct = cks.encrypt_no_padding(msg);
+    ///
+    /// let ct_res = sks.unchecked_scalar_mul_no_padding(&ct, scalar);
+    ///
+    /// let res = cks.decrypt_no_padding(&ct_res);
+    /// assert_eq!(res, (msg * scalar as u64) % modulus);
+    /// ```
+    pub fn unchecked_scalar_mul_no_padding<OpOrder: PBSOrderMarker>(
+        &self,
+        ct: &CiphertextNoPaddingBase<OpOrder>,
+        scalar: u8,
+    ) -> CiphertextNoPaddingBase<OpOrder> {
+        let mut result = ct.clone();
+        self.unchecked_scalar_mul_no_padding_assign(&mut result, scalar);
+        result
+    }
+
+    pub fn unchecked_scalar_mul_no_padding_assign<OpOrder: PBSOrderMarker>(
+        &self,
+        ct: &mut CiphertextNoPaddingBase<OpOrder>,
+        scalar: u8,
+    ) {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine
+                .unchecked_scalar_mul_no_padding_assign(ct, scalar)
+                .unwrap()
+        })
+    }
+
+    /// Constructs the accumulator of an antisymmetric function, to be applied on ciphertexts
+    /// encrypted without padding bit.
+    ///
+    /// The function must satisfy `f(x + modulus / 2) == -f(x) mod modulus`,
+    /// where `modulus = message_modulus * carry_modulus`, for all `x` in `[0, modulus / 2)`.
+    ///
+    /// The bootstrapping is negacyclic: when the padding bit is set the result is negated.
+    /// Functions with this symmetry use it instead of fighting it, which means they can be
+    /// evaluated over the whole space of a ciphertext without padding bit,
+    /// that is twice the domain of a regular lookup table.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `f` does not satisfy the antisymmetry property.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let modulus = 16;
+    /// let half_modulus = modulus / 2;
+    ///
+    /// // x -> 3x mod 8, negated on the upper half of the space
+    /// let f = move |x: u64| {
+    ///     if x < half_modulus {
+    ///         (3 * x) % half_modulus
+    ///     } else {
+    ///         (modulus - (3 * (x - half_modulus)) % half_modulus) % modulus
+    ///     }
+    /// };
+    ///
+    /// let acc = sks.generate_lookup_table_odd(f);
+    ///
+    /// for msg in 0..modulus {
+    ///     let ct = cks.encrypt_no_padding(msg);
+    ///     let ct_res = sks.apply_lookup_table_no_padding(&ct, &acc);
+    ///     assert_eq!(cks.decrypt_no_padding(&ct_res), f(msg));
+    /// }
+    /// ```
+    pub fn generate_lookup_table_odd<F>(&self, f: F) -> LookupTableOwned
+    where
+        F: Fn(u64) -> u64,
+    {
+        let modulus = (self.message_modulus.0 * self.carry_modulus.0) as u64;
+        let half_modulus = modulus / 2;
+        for x in 0..half_modulus {
+            assert_eq!(
+                (f(x) + f(x + half_modulus)) % modulus,
+                0,
+                "The function is not antisymmetric: f({}) != -f({}) mod {modulus}",
+                x + half_modulus,
+                x,
+            );
+        }
+
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine.generate_lookup_table_odd(self, f).unwrap()
+        })
+    }
+
+    /// Applies a lookup table on a ciphertext encrypted without padding bit.
+    ///
+    /// The lookup table should be generated with [`Self::generate_lookup_table_odd`].
+    pub fn apply_lookup_table_no_padding<OpOrder: PBSOrderMarker>(
+        &self,
+        ct: &CiphertextNoPaddingBase<OpOrder>,
+        acc: &LookupTableOwned,
+    ) -> CiphertextNoPaddingBase<OpOrder> {
+        let mut result = ct.clone();
+        self.apply_lookup_table_no_padding_assign(&mut result, acc);
+        result
+    }
+
+    pub fn apply_lookup_table_no_padding_assign<OpOrder: PBSOrderMarker>(
+        &self,
+        ct: &mut CiphertextNoPaddingBase<OpOrder>,
+        acc: &LookupTableOwned,
+    ) {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine
+                .apply_lookup_table_no_padding_assign(self, ct, acc)
+                .unwrap()
+        })
+    }
+
+    /// Constructs the accumulators of an arbitrary function, to be applied on ciphertexts
+    /// encrypted without padding bit.
+    ///
+    /// Unlike [`Self::generate_lookup_table_odd`], the function does not need any symmetry: it is
+    /// evaluated over the whole `[0, modulus)` space of the ciphertext, where
+    /// `modulus = message_modulus * carry_modulus`, and its output is reduced modulo `modulus`.
+    ///
+    /// This costs two PBS instead of one. The first one folds the input into the space of a
+    /// ciphertext with padding bit with an injective mapping, which only depends on the sign of
+    /// the input, i.e. on its most significant bit, and is thus compatible with the negacyclic
+    /// bootstrapping. The second one is a regular PBS evaluating the function on the inverse of
+    /// the folding.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let modulus = 16;
+    ///
+    /// // x -> x^2 mod 16 is not antisymmetric
+    /// let f = |x: u64| (x * x) % modulus;
+    ///
+    /// let acc = sks.generate_lookup_table_full_domain(f);
+    ///
+    /// for msg in [0, 3, 8, 13] {
+    ///     let ct = cks.encrypt_no_padding(msg);
+    ///     let ct_res = sks.apply_lookup_table_full_domain(&ct, &acc);
+    ///     assert_eq!(cks.decrypt_no_padding(&ct_res), f(msg));
+    /// }
+    /// ```
+    pub fn generate_lookup_table_full_domain<F>(&self, f: F) -> FullDomainLookupTable
+    where
+        F: Fn(u64) -> u64,
+    {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine.generate_lookup_table_full_domain(self, f).unwrap()
+        })
+    }
+
+    /// Applies a lookup table generated with [`Self::generate_lookup_table_full_domain`] on a
+    /// ciphertext encrypted without padding bit.
+    pub fn apply_lookup_table_full_domain<OpOrder: PBSOrderMarker>(
+        &self,
+        ct: &CiphertextNoPaddingBase<OpOrder>,
+        acc: &FullDomainLookupTable,
+    ) -> CiphertextNoPaddingBase<OpOrder> {
+        let mut result = ct.clone();
+        self.apply_lookup_table_full_domain_assign(&mut result, acc);
+        result
+    }
+
+    pub fn apply_lookup_table_full_domain_assign<OpOrder: PBSOrderMarker>(
+        &self,
+        ct: &mut CiphertextNoPaddingBase<OpOrder>,
+        acc: &FullDomainLookupTable,
+    ) {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine
+                .apply_lookup_table_full_domain_assign(self, ct, acc)
+                .unwrap()
+        })
+    }
+}
@@ -0,0 +1,186 @@
+//! Module overriding the order of the keyswitch and the PBS for a single operation.
+//!
+//! The [`EncryptionKeyChoice`](crate::shortint::parameters::EncryptionKeyChoice) of the
+//! parameters fixes the key under which the client encrypts, and so the order of the operations
+//! of the server: a [`CiphertextBig`] goes through a keyswitch then a PBS (KS-PBS), a
+//! [`CiphertextSmall`] through a PBS then a keyswitch (PBS-KS). Both orders use the same server
+//! key, so a circuit can switch from one to the other for a given operation:
+//!
+//! * [`ServerKey::apply_lookup_table_with_order`] evaluates a lookup table with the output order
+//!   given by the caller, skipping the keyswitch when a [`CiphertextSmall`] is bootstrapped to a
+//!   [`CiphertextBig`];
+//! * [`ServerKey::keyswitch_to_small`] converts a [`CiphertextBig`] to a [`CiphertextSmall`]
+//!   without a PBS;
+//! * converting a [`CiphertextSmall`] to a [`CiphertextBig`] without a PBS requires an additional
+//!   key switching key, from the small key to the large one, which is only generated on request
+//!   as a [`PBSOrderSwitchingKey`].
+//!
+//! # Example
+//!
+//! ```rust
+//! use tfhe::shortint::gen_keys;
+//! use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+//! use tfhe::shortint::server_key::PBSOrderSwitchingKey;
+//! use tfhe::shortint::{CiphertextBig, CiphertextSmall};
+//!
+//! let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+//! let order_switching_key = PBSOrderSwitchingKey::new(&cks);
+//!
+//! let ct = cks.encrypt(3);
+//!
+//! // KS-PBS-KS, the output is encrypted under the small key
+//! let acc = sks.generate_accumulator(|x| (x + 1) % 4);
+//! let ct_small: CiphertextSmall = sks.apply_lookup_table_with_order(&ct, &acc);
+//! assert_eq!(cks.decrypt(&ct_small), 0);
+//!
+//! // PBS only, the output is encrypted under the large key
+//! let ct_big: CiphertextBig = sks.apply_lookup_table_with_order(&ct_small, &acc);
+//! assert_eq!(cks.decrypt(&ct_big), 1);
+//!
+//! // Conversions without PBS
+//! let ct_small = sks.keyswitch_to_small(&ct_big);
+//! let ct_big = order_switching_key.keyswitch_to_big(&ct_small);
+//! assert_eq!(cks.decrypt(&ct_big), 1);
+//! ```
+
+use super::{LookupTableOwned, ServerKey};
+use crate::core_crypto::algorithms::*;
+use crate::core_crypto::entities::*;
+use crate::shortint::engine::ShortintEngine;
+use crate::shortint::{CiphertextBase, CiphertextBig, CiphertextSmall, ClientKey, PBSOrderMarker};
+use serde::{Deserialize, Serialize};
+use std::marker::PhantomData;
+
+/// A key switching key from the small LWE key to the large one, converting a
+/// [`CiphertextSmall`] to a [`CiphertextBig`] without a PBS.
+///
+/// It is as large as the key switching key of the [`ServerKey`].
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PBSOrderSwitchingKey {
+    pub(crate) key_switching_key: LweKeyswitchKeyOwned<u64>,
+}
+
+impl PBSOrderSwitchingKey {
+    /// Generates the key switching key from the small key of `client_key` to its large key.
+    pub fn new(client_key: &ClientKey) -> Self {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine.new_pbs_order_switching_key(client_key).unwrap()
+        })
+    }
+
+    /// Converts a [`CiphertextSmall`] to a [`CiphertextBig`] encrypting the same message.
+    ///
+    /// The keyswitch adds noise to the ciphertext, its carries are not cleaned.
+    pub fn keyswitch_to_big(&self, ct: &CiphertextSmall) -> CiphertextBig {
+        let mut ct_big = LweCiphertextOwned::new(
+            0u64,
+            self.key_switching_key.output_lwe_size(),
+            ct.ct.ciphertext_modulus(),
+        );
+        keyswitch_lwe_ciphertext(&self.key_switching_key, &ct.ct, &mut ct_big);
+
+        CiphertextBig {
+            ct: ct_big,
+            degree: ct.degree,
+            noise_level: ct.noise_level,
+            message_modulus: ct.message_modulus,
+            carry_modulus: ct.carry_modulus,
+            parameter_version: ct.parameter_version,
+            _order_marker: PhantomData,
+        }
+    }
+}
+
+impl ServerKey {
+    /// Evaluates a lookup table on `ct`, returning a ciphertext of the `OutOrder` PBS order
+    /// whatever the order of `ct`.
+    ///
+    /// The PBS is preceded by a keyswitch if `ct` is a [`CiphertextBig`] and followed by a
+    /// keyswitch if the output is a [`CiphertextSmall`]. In particular, bootstrapping a
+    /// [`CiphertextSmall`] to a [`CiphertextBig`] does not need any keyswitch.
+    ///
+    /// See the [module documentation](self) for an example.
+    pub fn apply_lookup_table_with_order<InOrder, OutOrder>(
+        &self,
+        ct: &CiphertextBase<InOrder>,
+        acc: &LookupTableOwned,
+    ) -> CiphertextBase<OutOrder>
+    where
+        InOrder: PBSOrderMarker,
+        OutOrder: PBSOrderMarker,
+    {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine.apply_lookup_table_with_order(self, ct, acc).unwrap()
+        })
+    }
+
+    /// Converts a [`CiphertextBig`] to a [`CiphertextSmall`] encrypting the same message, with a
+    /// keyswitch.
+    ///
+    /// The keyswitch adds noise to the ciphertext, its carries are not cleaned.
+    pub fn keyswitch_to_small(&self, ct: &CiphertextBig) -> CiphertextSmall {
+        let mut ct_small = LweCiphertextOwned::new(
+            0u64,
+            self.key_switching_key.output_lwe_size(),
+            self.ciphertext_modulus,
+        );
+        keyswitch_lwe_ciphertext(&self.key_switching_key, &ct.ct, &mut ct_small);
+
+        CiphertextSmall {
+            ct: ct_small,
+            degree: ct.degree,
+            noise_level: ct.noise_level,
+            message_modulus: ct.message_modulus,
+            carry_modulus: ct.carry_modulus,
+            parameter_version: ct.parameter_version,
+            _order_marker: PhantomData,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shortint::keycache::KEY_CACHE;
+    use crate::shortint::parameters::{
+        EncryptionKeyChoice, PARAM_MESSAGE_2_CARRY_2, PARAM_SMALL_MESSAGE_2_CARRY_2,
+    };
+
+    #[test]
+    fn test_apply_lookup_table_with_order() {
+        for params in [PARAM_MESSAGE_2_CARRY_2, PARAM_SMALL_MESSAGE_2_CARRY_2] {
+            let keys = KEY_CACHE.get_from_param(params);
+            let (cks, sks) = (keys.client_key(), keys.server_key());
+            let order_switching_key = PBSOrderSwitchingKey::new(cks);
+
+            let acc = sks.generate_accumulator(|x| (3 * x + 1) % 4);
+            for msg in 0..4u64 {
+                let expected = (3 * msg + 1) % 4;
+                // A client key only encrypts with the PBS order of its parameters
+                let (ct_big, ct_small) = match params.encryption_key_choice {
+                    EncryptionKeyChoice::Big => {
+                        let ct_big = cks.encrypt(msg);
+                        (ct_big.clone(), sks.keyswitch_to_small(&ct_big))
+                    }
+                    EncryptionKeyChoice::Small => {
+                        let ct_small = cks.encrypt_small(msg);
+                        (order_switching_key.keyswitch_to_big(&ct_small), ct_small)
+                    }
+                };
+
+                let res: CiphertextBig = sks.apply_lookup_table_with_order(&ct_big, &acc);
+                assert_eq!(cks.decrypt(&res), expected);
+                let res: CiphertextSmall = sks.apply_lookup_table_with_order(&ct_big, &acc);
+                assert_eq!(cks.decrypt(&res), expected);
+                let res: CiphertextBig = sks.apply_lookup_table_with_order(&ct_small, &acc);
+                assert_eq!(cks.decrypt(&res), expected);
+                let res: CiphertextSmall = sks.apply_lookup_table_with_order(&ct_small, &acc);
+                assert_eq!(cks.decrypt(&res), expected);
+                assert_eq!(res.degree, acc.degree);
+
+                assert_eq!(cks.decrypt(&ct_big), msg);
+                assert_eq!(cks.decrypt(&ct_small), msg);
+            }
+        }
+    }
+}
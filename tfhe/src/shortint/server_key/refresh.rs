@@ -0,0 +1,131 @@
+//! Refreshing ciphertexts which accumulated leveled operations.
+//!
+//! Each addition or scalar multiplication grows the noise level and the degree of a ciphertext,
+//! until no further operation is possible without a PBS. A long-lived store of ciphertexts, e.g.
+//! an encrypted database updated over time, can refresh them in the background instead: a
+//! refreshed ciphertext has the [nominal](NoiseLevel::NOMINAL) noise level and empty carries,
+//! and still decrypts to the same message.
+//!
+//! [`ServerKey::refresh_stale`] only refreshes the ciphertexts whose noise level or degree
+//! exceeds the thresholds of a [`RefreshPolicy`], so that the PBS are spent where they are needed.
+//!
+//! # Example
+//!
+//! ```rust
+//! use tfhe::shortint::gen_keys;
+//! use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+//! use tfhe::shortint::server_key::RefreshPolicy;
+//!
+//! let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+//!
+//! let mut store: Vec<_> = (0..4).map(|msg| cks.encrypt(msg)).collect();
+//!
+//! // Some values are updated with leveled operations
+//! sks.unchecked_scalar_mul_assign(&mut store[1], 3);
+//! sks.unchecked_add_assign(&mut store[2], &store[3].clone());
+//!
+//! let policy = RefreshPolicy::fresh(&sks);
+//! let refreshed = sks.refresh_stale(store.iter_mut(), &policy);
+//! assert_eq!(refreshed, 2);
+//!
+//! for (ct, expected) in store.iter().zip([0, 3, 1, 3]) {
+//!     assert!(!policy.is_stale(ct));
+//!     assert_eq!(cks.decrypt(ct), expected);
+//! }
+//! ```
+
+use super::ServerKey;
+use crate::shortint::ciphertext::{Degree, NoiseLevel};
+use crate::shortint::{CiphertextBase, PBSOrderMarker};
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// The thresholds above which [`ServerKey::refresh_stale`] refreshes a ciphertext.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RefreshPolicy {
+    pub max_noise_level: NoiseLevel,
+    pub max_degree: Degree,
+}
+
+impl RefreshPolicy {
+    pub fn new(max_noise_level: NoiseLevel, max_degree: Degree) -> Self {
+        Self {
+            max_noise_level,
+            max_degree,
+        }
+    }
+
+    /// The policy refreshing every ciphertext which is not as fresh as the output of a PBS, i.e.
+    /// whose noise level is above nominal or whose carries may not be empty.
+    pub fn fresh(server_key: &ServerKey) -> Self {
+        Self::new(
+            NoiseLevel::NOMINAL,
+            Degree(server_key.message_modulus.0 - 1),
+        )
+    }
+
+    /// Returns true if the noise level or the degree of `ct` exceeds the thresholds.
+    pub fn is_stale<OpOrder: PBSOrderMarker>(&self, ct: &CiphertextBase<OpOrder>) -> bool {
+        ct.noise_level > self.max_noise_level || ct.degree.0 > self.max_degree.0
+    }
+}
+
+impl ServerKey {
+    /// Bootstraps `ct` to the nominal noise level and clears its carries, the message is kept.
+    ///
+    /// The degree of the result is the smallest of the degree of `ct` and the largest message,
+    /// so that a ciphertext known to encrypt a small value, e.g. a boolean, keeps a small degree.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::ciphertext::NoiseLevel;
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let mut ct = cks.encrypt(3);
+    /// sks.unchecked_scalar_mul_assign(&mut ct, 3);
+    ///
+    /// let ct_res = sks.refresh(&ct);
+    /// assert_eq!(ct_res.noise_level, NoiseLevel::NOMINAL);
+    /// assert!(ct_res.carry_is_empty());
+    /// assert_eq!(cks.decrypt(&ct_res), 1);
+    /// ```
+    pub fn refresh<OpOrder: PBSOrderMarker>(
+        &self,
+        ct: &CiphertextBase<OpOrder>,
+    ) -> CiphertextBase<OpOrder> {
+        let mut result = ct.clone();
+        self.refresh_assign(&mut result);
+        result
+    }
+
+    pub fn refresh_assign<OpOrder: PBSOrderMarker>(&self, ct: &mut CiphertextBase<OpOrder>) {
+        let message_modulus = self.message_modulus.0 as u64;
+        let degree = ct.degree.0 as u64;
+        // Values above the degree cannot occur, the minimum only tightens the output degree
+        let acc = self.generate_accumulator(|x| (x % message_modulus).min(degree));
+        self.apply_lookup_table_assign(ct, &acc);
+    }
+
+    /// Refreshes the ciphertexts of `cts` which are stale according to `policy`, see
+    /// [`ServerKey::refresh`], and returns their number.
+    ///
+    /// The stale ciphertexts are first collected then refreshed in parallel on the rayon thread
+    /// pool. `cts` can iterate over any store of ciphertexts, e.g. the values of a map.
+    pub fn refresh_stale<'a, OpOrder, I>(&self, cts: I, policy: &RefreshPolicy) -> usize
+    where
+        OpOrder: PBSOrderMarker + 'a,
+        I: IntoIterator<Item = &'a mut CiphertextBase<OpOrder>>,
+    {
+        let stale: Vec<&mut CiphertextBase<OpOrder>> = cts
+            .into_iter()
+            .filter(|ct| policy.is_stale::<OpOrder>(ct))
+            .collect();
+        let count = stale.len();
+        stale.into_par_iter().for_each(|ct| self.refresh_assign(ct));
+        count
+    }
+}
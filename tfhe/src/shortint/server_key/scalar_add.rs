@@ -269,7 +269,7 @@ impl ServerKey {
     ) -> bool {
         let final_degree = scalar as usize + ct.degree.0;
 
-        final_degree <= self.max_degree.0
+        final_degree <= self.max_degree.0 && self.max_noise_level().validate(ct.noise_level)
     }
 
     /// Compute homomorphically an addition between a ciphertext and a scalar.
@@ -317,6 +317,7 @@ impl ServerKey {
         ct: &CiphertextBase<OpOrder>,
         scalar: u8,
     ) -> Result<CiphertextBase<OpOrder>, CheckError> {
+        self.check_operand(ct)?;
         //If the ciphertext cannot be multiplied without exceeding the max degree
         if self.is_scalar_add_possible(ct, scalar) {
             let ct_result = self.unchecked_scalar_add(ct, scalar);
@@ -369,6 +370,7 @@ impl ServerKey {
         ct: &mut CiphertextBase<OpOrder>,
         scalar: u8,
     ) -> Result<(), CheckError> {
+        self.check_operand(ct)?;
         if self.is_scalar_add_possible(ct, scalar) {
             self.unchecked_scalar_add_assign(ct, scalar);
             Ok(())
@@ -251,8 +251,9 @@ impl ServerKey {
     ) -> bool {
         //scalar * ct.counter
         let final_degree = scalar as usize * ct.degree.0;
+        let final_noise_level = ct.noise_level * scalar as usize;
 
-        final_degree <= self.max_degree.0
+        final_degree <= self.max_degree.0 && self.max_noise_level().validate(final_noise_level)
     }
 
     /// Compute homomorphically a multiplication of a ciphertext by a scalar.
@@ -303,6 +304,7 @@ impl ServerKey {
         ct: &CiphertextBase<OpOrder>,
         scalar: u8,
     ) -> Result<CiphertextBase<OpOrder>, CheckError> {
+        self.check_operand(ct)?;
         //If the ciphertext cannot be multiplied without exceeding the degree max
         if self.is_scalar_mul_possible(ct, scalar) {
             let ct_result = self.unchecked_scalar_mul(ct, scalar);
@@ -357,6 +359,7 @@ impl ServerKey {
         ct: &mut CiphertextBase<OpOrder>,
         scalar: u8,
     ) -> Result<(), CheckError> {
+        self.check_operand(ct)?;
         if self.is_scalar_mul_possible(ct, scalar) {
             self.unchecked_scalar_mul_assign(ct, scalar);
             Ok(())
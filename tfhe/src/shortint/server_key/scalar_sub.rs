@@ -253,7 +253,7 @@ impl ServerKey {
     ) -> bool {
         let neg_scalar = u64::from(scalar.wrapping_neg()) % self.message_modulus.0 as u64;
         let final_degree = neg_scalar as usize + ct.degree.0;
-        final_degree <= self.max_degree.0
+        final_degree <= self.max_degree.0 && self.max_noise_level().validate(ct.noise_level)
     }
 
     /// Compute homomorphically a subtraction of a ciphertext by a scalar.
@@ -301,6 +301,7 @@ impl ServerKey {
         ct: &CiphertextBase<OpOrder>,
         scalar: u8,
     ) -> Result<CiphertextBase<OpOrder>, CheckError> {
+        self.check_operand(ct)?;
         //If the scalar subtraction cannot be done without exceeding the max degree
         if self.is_scalar_sub_possible(ct, scalar) {
             let ct_result = self.unchecked_scalar_sub(ct, scalar);
@@ -353,6 +354,7 @@ impl ServerKey {
         ct: &mut CiphertextBase<OpOrder>,
         scalar: u8,
     ) -> Result<(), CheckError> {
+        self.check_operand(ct)?;
         if self.is_scalar_sub_possible(ct, scalar) {
             self.unchecked_scalar_sub_assign(ct, scalar);
             Ok(())
@@ -610,7 +610,9 @@ impl ServerKey {
         shift: u8,
     ) -> bool {
         let final_operation_count = ct1.degree.0 << shift as usize;
+        let final_noise_level = ct1.noise_level * (1 << shift as usize);
         final_operation_count <= self.max_degree.0
+            && self.max_noise_level().validate(final_noise_level)
     }
 
     /// Compute homomorphically a left shift of the bits.
@@ -694,6 +696,7 @@ impl ServerKey {
         ct: &CiphertextBase<OpOrder>,
         shift: u8,
     ) -> Result<CiphertextBase<OpOrder>, CheckError> {
+        self.check_operand(ct)?;
         if self.is_scalar_left_shift_possible(ct, shift) {
             let ct_result = self.unchecked_scalar_left_shift(ct, shift);
             Ok(ct_result)
@@ -707,6 +710,7 @@ impl ServerKey {
         ct: &mut CiphertextBase<OpOrder>,
         shift: u8,
     ) -> Result<(), CheckError> {
+        self.check_operand(ct)?;
         if self.is_scalar_left_shift_possible(ct, shift) {
             self.unchecked_scalar_left_shift_assign(ct, shift);
             Ok(())
@@ -796,4 +800,186 @@ impl ServerKey {
                 .unwrap()
         })
     }
+
+    /// Compute homomorphically a rotation of the bits of the message to the left.
+    ///
+    /// The bits leaving the message space on the left come back on the right, the rotation is
+    /// taken modulo the number of bits of the message. The message modulus must be a power of two.
+    ///
+    /// The rotation is computed with a PBS, which clears the carries: the input carries are not
+    /// part of the rotated value, and the output carries are empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let msg = 1;
+    ///
+    /// // Encrypt a message
+    /// let ct = cks.encrypt(msg);
+    /// // |       ct        |
+    /// // | carry | message |
+    /// // |-------|---------|
+    /// // |  0 0  |   0 1   |
+    ///
+    /// // Compute homomorphically a left rotation
+    /// let ct_res = sks.scalar_rotate_left(&ct, 1);
+    /// // |      ct_res     |
+    /// // | carry | message |
+    /// // |-------|---------|
+    /// // |  0 0  |   1 0   |
+    ///
+    /// // Decrypt:
+    /// let dec = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, 2);
+    ///
+    /// // Rotating by the number of bits of the message gives back the message
+    /// let ct_res = sks.scalar_rotate_left(&ct, 2);
+    /// assert_eq!(cks.decrypt(&ct_res), msg);
+    /// ```
+    pub fn scalar_rotate_left<OpOrder: PBSOrderMarker>(
+        &self,
+        ct: &CiphertextBase<OpOrder>,
+        rotation: u8,
+    ) -> CiphertextBase<OpOrder> {
+        self.unchecked_scalar_rotate_left(ct, rotation)
+    }
+
+    /// Compute homomorphically a rotation of the bits of the message to the left.
+    ///
+    /// See [`ServerKey::scalar_rotate_left`] for the semantics.
+    pub fn scalar_rotate_left_assign<OpOrder: PBSOrderMarker>(
+        &self,
+        ct: &mut CiphertextBase<OpOrder>,
+        rotation: u8,
+    ) {
+        self.unchecked_scalar_rotate_left_assign(ct, rotation)
+    }
+
+    /// Compute homomorphically a rotation of the bits of the message to the left without checks.
+    ///
+    /// A rotation is always possible as it is computed with a PBS, so this is the same operation
+    /// as [`ServerKey::scalar_rotate_left`].
+    pub fn unchecked_scalar_rotate_left<OpOrder: PBSOrderMarker>(
+        &self,
+        ct: &CiphertextBase<OpOrder>,
+        rotation: u8,
+    ) -> CiphertextBase<OpOrder> {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine
+                .unchecked_scalar_rotate_left(self, ct, rotation)
+                .unwrap()
+        })
+    }
+
+    /// Compute homomorphically a rotation of the bits of the message to the left without checks.
+    ///
+    /// See [`ServerKey::unchecked_scalar_rotate_left`].
+    pub fn unchecked_scalar_rotate_left_assign<OpOrder: PBSOrderMarker>(
+        &self,
+        ct: &mut CiphertextBase<OpOrder>,
+        rotation: u8,
+    ) {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine
+                .unchecked_scalar_rotate_left_assign(self, ct, rotation)
+                .unwrap()
+        })
+    }
+
+    /// Compute homomorphically a rotation of the bits of the message to the right.
+    ///
+    /// The bits leaving the message space on the right come back on the left, the rotation is
+    /// taken modulo the number of bits of the message. The message modulus must be a power of two.
+    ///
+    /// The rotation is computed with a PBS, which clears the carries: the input carries are not
+    /// part of the rotated value, and the output carries are empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let msg = 2;
+    ///
+    /// // Encrypt a message
+    /// let ct = cks.encrypt(msg);
+    /// // |       ct        |
+    /// // | carry | message |
+    /// // |-------|---------|
+    /// // |  0 0  |   1 0   |
+    ///
+    /// // Compute homomorphically a right rotation
+    /// let ct_res = sks.scalar_rotate_right(&ct, 1);
+    /// // |      ct_res     |
+    /// // | carry | message |
+    /// // |-------|---------|
+    /// // |  0 0  |   0 1   |
+    ///
+    /// // Decrypt:
+    /// let dec = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, 1);
+    ///
+    /// // Rotating by the number of bits of the message gives back the message
+    /// let ct_res = sks.scalar_rotate_right(&ct, 2);
+    /// assert_eq!(cks.decrypt(&ct_res), msg);
+    /// ```
+    pub fn scalar_rotate_right<OpOrder: PBSOrderMarker>(
+        &self,
+        ct: &CiphertextBase<OpOrder>,
+        rotation: u8,
+    ) -> CiphertextBase<OpOrder> {
+        self.unchecked_scalar_rotate_right(ct, rotation)
+    }
+
+    /// Compute homomorphically a rotation of the bits of the message to the right.
+    ///
+    /// See [`ServerKey::scalar_rotate_right`] for the semantics.
+    pub fn scalar_rotate_right_assign<OpOrder: PBSOrderMarker>(
+        &self,
+        ct: &mut CiphertextBase<OpOrder>,
+        rotation: u8,
+    ) {
+        self.unchecked_scalar_rotate_right_assign(ct, rotation)
+    }
+
+    /// Compute homomorphically a rotation of the bits of the message to the right without checks.
+    ///
+    /// A rotation is always possible as it is computed with a PBS, so this is the same operation
+    /// as [`ServerKey::scalar_rotate_right`].
+    pub fn unchecked_scalar_rotate_right<OpOrder: PBSOrderMarker>(
+        &self,
+        ct: &CiphertextBase<OpOrder>,
+        rotation: u8,
+    ) -> CiphertextBase<OpOrder> {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine
+                .unchecked_scalar_rotate_right(self, ct, rotation)
+                .unwrap()
+        })
+    }
+
+    /// Compute homomorphically a rotation of the bits of the message to the right without checks.
+    ///
+    /// See [`ServerKey::unchecked_scalar_rotate_right`].
+    pub fn unchecked_scalar_rotate_right_assign<OpOrder: PBSOrderMarker>(
+        &self,
+        ct: &mut CiphertextBase<OpOrder>,
+        rotation: u8,
+    ) {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine
+                .unchecked_scalar_rotate_right_assign(self, ct, rotation)
+                .unwrap()
+        })
+    }
 }
@@ -208,8 +208,10 @@ impl ServerKey {
         z = z.wrapping_mul(msg_mod);
 
         let final_operation_count = ct_left.degree.0 + z;
+        let final_noise_level = ct_left.noise_level + ct_right.noise_level;
 
         final_operation_count <= self.max_degree.0
+            && self.max_noise_level().validate(final_noise_level)
     }
 
     /// Compute homomorphically a subtraction between two ciphertexts encrypting integer values.
@@ -243,6 +245,8 @@ impl ServerKey {
         ct_left: &CiphertextBase<OpOrder>,
         ct_right: &CiphertextBase<OpOrder>,
     ) -> Result<CiphertextBase<OpOrder>, CheckError> {
+        self.check_operand(ct_left)?;
+        self.check_operand(ct_right)?;
         // If the ciphertexts cannot be subtracted without exceeding the degree max
         if self.is_sub_possible(ct_left, ct_right) {
             let ct_result = self.unchecked_sub(ct_left, ct_right);
@@ -283,6 +287,8 @@ impl ServerKey {
         ct_left: &mut CiphertextBase<OpOrder>,
         ct_right: &CiphertextBase<OpOrder>,
     ) -> Result<(), CheckError> {
+        self.check_operand(ct_left)?;
+        self.check_operand(ct_right)?;
         // If the ciphertexts cannot be subtracted without exceeding the degree max
         if self.is_sub_possible(ct_left, ct_right) {
             self.unchecked_sub_assign(ct_left, ct_right);
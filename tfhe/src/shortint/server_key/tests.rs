@@ -1,6 +1,15 @@
+use crate::core_crypto::entities::LweKeyswitchKeyOwned;
+use crate::core_crypto::fft_impl::fft64::crypto::bootstrap::FourierLweBootstrapKeyOwned;
+use crate::shortint::ciphertext::{Degree, NoiseLevel};
+use crate::shortint::client_key::{NoiseEstimate, SanitizationIssue};
+use crate::shortint::engine::ShortintEngine;
 use crate::shortint::keycache::KEY_CACHE;
 use crate::shortint::parameters::*;
-use crate::shortint::CiphertextBig;
+use crate::shortint::server_key::{
+    BootstrappingKeyStorage, CheckError, CompressedLookupTable, CompressedServerKey,
+    LookupTableOwned, MaxDegree, RefreshPolicy, UnsupportedStorageError, VersionedServerKey,
+};
+use crate::shortint::{CiphertextBig, CiphertextSmall, ClientKey, ClientKeySeed, ServerKey};
 use paste::paste;
 use rand::Rng;
 
@@ -93,11 +102,54 @@ macro_rules! create_parametrized_test_bivariate_pbs_compliant{
 create_parametrized_test!(shortint_encrypt_decrypt);
 create_parametrized_test!(shortint_encrypt_with_message_modulus_decrypt);
 create_parametrized_test!(shortint_encrypt_decrypt_without_padding);
+create_parametrized_test!(shortint_client_key_from_seed {
+    PARAM_MESSAGE_1_CARRY_1,
+    PARAM_MESSAGE_2_CARRY_2,
+    PARAM_SMALL_MESSAGE_2_CARRY_2
+});
+create_parametrized_test!(shortint_explicit_engine {
+    PARAM_MESSAGE_1_CARRY_1,
+    PARAM_MESSAGE_2_CARRY_2
+});
+create_parametrized_test!(shortint_sanitize {
+    PARAM_MESSAGE_1_CARRY_1,
+    PARAM_MESSAGE_2_CARRY_2,
+    PARAM_SMALL_MESSAGE_2_CARRY_2
+});
+create_parametrized_test!(shortint_decrypt_with_noise {
+    PARAM_MESSAGE_1_CARRY_1,
+    PARAM_MESSAGE_2_CARRY_2,
+    PARAM_SMALL_MESSAGE_2_CARRY_2
+});
+create_parametrized_test!(shortint_no_padding_linear_operations);
 create_parametrized_test!(shortint_keyswitch_bootstrap);
 create_parametrized_test!(shortint_keyswitch_programmable_bootstrap);
 create_parametrized_test!(shortint_carry_extract);
 create_parametrized_test!(shortint_message_extract);
+create_parametrized_test!(shortint_carry_and_message_extract);
+create_parametrized_test!(shortint_apply_many_lookup_tables {
+    PARAM_MESSAGE_1_CARRY_1,
+    PARAM_MESSAGE_2_CARRY_2,
+    PARAM_SMALL_MESSAGE_2_CARRY_2
+});
+create_parametrized_test!(shortint_on_the_fly_bootstrapping_key {
+    PARAM_MESSAGE_1_CARRY_1,
+    PARAM_MESSAGE_2_CARRY_2
+});
+create_parametrized_test!(shortint_compressed_server_key {
+    PARAM_MESSAGE_1_CARRY_1,
+    PARAM_MESSAGE_2_CARRY_2
+});
+create_parametrized_test!(shortint_glwe_output_pbs {
+    PARAM_MESSAGE_1_CARRY_1,
+    PARAM_MESSAGE_2_CARRY_2,
+    PARAM_SMALL_MESSAGE_2_CARRY_2
+});
 create_parametrized_test!(shortint_generate_accumulator);
+create_parametrized_test!(shortint_compressed_accumulator);
+create_parametrized_test!(shortint_serialized_accumulator);
+create_parametrized_test!(shortint_lut_fusion);
+create_parametrized_test!(shortint_ciphertext_canonical_bytes);
 create_parametrized_test!(shortint_unchecked_add);
 create_parametrized_test!(shortint_smart_add);
 create_parametrized_test!(shortint_default_add);
@@ -119,11 +171,49 @@ create_parametrized_test!(shortint_unchecked_right_shift);
 create_parametrized_test!(shortint_default_right_shift);
 create_parametrized_test!(shortint_unchecked_left_shift);
 create_parametrized_test!(shortint_default_left_shift);
+create_parametrized_test!(shortint_scalar_rotate);
 create_parametrized_test!(shortint_unchecked_sub);
 create_parametrized_test!(shortint_smart_sub);
 create_parametrized_test!(shortint_default_sub);
 create_parametrized_test!(shortint_mul_small_carry);
 create_parametrized_test!(shortint_mux);
+create_parametrized_test!(shortint_smart_evaluate_trivariate_function {
+    PARAM_MESSAGE_1_CARRY_2,
+    PARAM_MESSAGE_1_CARRY_3,
+    PARAM_MESSAGE_2_CARRY_4
+});
+create_parametrized_test!(shortint_noise_level {
+    PARAM_MESSAGE_1_CARRY_1,
+    PARAM_MESSAGE_2_CARRY_2,
+    PARAM_SMALL_MESSAGE_2_CARRY_2
+});
+create_parametrized_test!(shortint_checked_errors {
+    PARAM_MESSAGE_2_CARRY_2,
+    PARAM_SMALL_MESSAGE_2_CARRY_2
+});
+create_parametrized_test!(shortint_no_padding_full_domain_lookup_table {
+    PARAM_MESSAGE_1_CARRY_1,
+    PARAM_MESSAGE_2_CARRY_2,
+    PARAM_SMALL_MESSAGE_2_CARRY_2
+});
+create_parametrized_test!(shortint_refresh {
+    PARAM_MESSAGE_2_CARRY_2,
+    PARAM_SMALL_MESSAGE_2_CARRY_2
+});
+create_parametrized_test!(shortint_batch_apply_lookup_table {
+    PARAM_MESSAGE_1_CARRY_1,
+    PARAM_MESSAGE_2_CARRY_2,
+    PARAM_SMALL_MESSAGE_2_CARRY_2
+});
+// 1_2 and 3_3 can select with a single PBS, with any branches for the former and boolean
+// branches for the latter
+create_parametrized_test!(shortint_if_then_else {
+    PARAM_MESSAGE_1_CARRY_1,
+    PARAM_MESSAGE_1_CARRY_2,
+    PARAM_MESSAGE_2_CARRY_2,
+    PARAM_MESSAGE_3_CARRY_3,
+    PARAM_SMALL_MESSAGE_2_CARRY_2
+});
 
 // Public key tests are limited to small parameter sets to avoid blowing up memory and large testing
 // times. Compressed keygen takes 20 minutes for params 2_2 and for encryption as well.
@@ -143,6 +233,27 @@ fn test_shortint_public_key_smart_add_param_message_2_carry_2() {
     shortint_public_key_smart_add(PARAM_MESSAGE_2_CARRY_2)
 }
 
+#[test]
+fn test_shortint_compact_public_key_param_message_1_carry_1() {
+    shortint_compact_public_key(PARAM_MESSAGE_1_CARRY_1)
+}
+
+#[test]
+fn test_shortint_compact_public_key_param_message_2_carry_2() {
+    shortint_compact_public_key(PARAM_MESSAGE_2_CARRY_2)
+}
+
+#[test]
+fn test_shortint_compact_public_key_size() {
+    let keys = KEY_CACHE.get_from_param(PARAM_MESSAGE_1_CARRY_1);
+    let cks = keys.client_key();
+
+    let pk_size = bincode::serialized_size(&crate::shortint::PublicKeyBig::new(cks)).unwrap();
+    let compact_pk_size =
+        bincode::serialized_size(&crate::shortint::CompactPublicKeyBig::new(cks)).unwrap();
+    assert!(1000 * compact_pk_size < pk_size);
+}
+
 //These functions are compatible with some parameter sets where the carry modulus is larger than
 // the message modulus.
 create_parametrized_test_bivariate_pbs_compliant!(shortint_unchecked_bitand);
@@ -153,6 +264,7 @@ create_parametrized_test_bivariate_pbs_compliant!(shortint_unchecked_greater_or_
 create_parametrized_test_bivariate_pbs_compliant!(shortint_unchecked_less);
 create_parametrized_test_bivariate_pbs_compliant!(shortint_unchecked_less_or_equal);
 create_parametrized_test_bivariate_pbs_compliant!(shortint_unchecked_equal);
+create_parametrized_test_bivariate_pbs_compliant!(shortint_checked_apply_lookup_table_bivariate);
 create_parametrized_test_bivariate_pbs_compliant!(shortint_smart_bitand);
 create_parametrized_test_bivariate_pbs_compliant!(shortint_default_bitand);
 create_parametrized_test_bivariate_pbs_compliant!(shortint_smart_bitor);
@@ -175,6 +287,8 @@ create_parametrized_test_bivariate_pbs_compliant!(shortint_smart_scalar_less_or_
 create_parametrized_test_bivariate_pbs_compliant!(shortint_smart_scalar_greater);
 create_parametrized_test_bivariate_pbs_compliant!(shortint_smart_scalar_greater_or_equal);
 create_parametrized_test_bivariate_pbs_compliant!(shortint_unchecked_div);
+create_parametrized_test_bivariate_pbs_compliant!(shortint_unchecked_mod_bivariate);
+create_parametrized_test_bivariate_pbs_compliant!(shortint_smart_mod);
 create_parametrized_test_bivariate_pbs_compliant!(shortint_unchecked_scalar_div);
 create_parametrized_test_bivariate_pbs_compliant!(shortint_unchecked_mod);
 create_parametrized_test_bivariate_pbs_compliant!(shortint_unchecked_mul_lsb);
@@ -189,6 +303,35 @@ create_parametrized_test_bivariate_pbs_compliant!(
 );
 create_parametrized_test_bivariate_pbs_compliant!(shortint_unchecked_less_or_equal_trivial);
 
+// Non native ciphertext moduli go through the whole pipeline: encryption, keyswitch, PBS,
+// trivial ciphertexts and decryption
+macro_rules! create_non_native_modulus_test {
+    ($($name:ident),* $(,)?) => {
+        $(
+            create_parametrized_test!($name {
+                PARAM_MESSAGE_2_CARRY_2_MODULUS_2_63,
+                PARAM_MESSAGE_1_CARRY_1_MODULUS_2_32
+            });
+        )*
+    };
+}
+
+create_non_native_modulus_test!(
+    shortint_encrypt_decrypt,
+    shortint_keyswitch_bootstrap,
+    shortint_keyswitch_programmable_bootstrap,
+    shortint_keyswitch_bivariate_programmable_bootstrap,
+    shortint_default_add,
+    shortint_default_scalar_add,
+    shortint_unchecked_scalar_add,
+    shortint_unchecked_scalar_sub,
+    shortint_unchecked_neg,
+    shortint_no_padding_linear_operations,
+    shortint_no_padding_full_domain_lookup_table,
+    shortint_public_key_smart_add,
+    shortint_unchecked_less_or_equal_trivial,
+);
+
 /// test encryption and decryption with the LWE client key
 fn shortint_encrypt_decrypt(param: PBSParameters) {
     let keys = KEY_CACHE.get_from_param(param);
@@ -211,6 +354,60 @@ fn shortint_encrypt_decrypt(param: PBSParameters) {
     }
 }
 
+/// test that the client keys derived from a seed only depend on the seed and the parameters
+fn shortint_client_key_from_seed(param: PBSParameters) {
+    let mut rng = rand::thread_rng();
+    let modulus = param.message_modulus.0 as u64;
+
+    let seed = ClientKeySeed(rng.gen());
+    let cks = ClientKey::new_from_seed(param, seed);
+    assert_eq!(cks, ClientKey::new_from_seed(param, seed));
+
+    // Each half of the seed changes the key
+    for i in [0, 31] {
+        let mut other_seed = seed;
+        other_seed.0[i] ^= 1;
+        assert_ne!(cks, ClientKey::new_from_seed(param, other_seed));
+    }
+
+    let cks_2 = ClientKey::new_from_seed(param, seed);
+    for _ in 0..NB_TEST {
+        let clear = rng.gen::<u64>() % modulus;
+        let ct = cks.encrypt(clear);
+        assert_eq!(cks_2.decrypt(&ct), clear);
+    }
+}
+
+/// test the operations of an engine owned by the caller, moved to another thread
+fn shortint_explicit_engine(param: PBSParameters) {
+    let keys = KEY_CACHE.get_from_param(param);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+    let mut rng = rand::thread_rng();
+    let modulus = param.message_modulus.0 as u64;
+
+    let mut engine = ShortintEngine::new();
+    for _ in 0..NB_TEST {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+
+        let (result, engine_back) = std::thread::scope(|scope| {
+            scope
+                .spawn(move || {
+                    let mut ct_0: CiphertextBig = engine.encrypt(cks, clear_0).unwrap();
+                    let mut ct_1: CiphertextBig = engine.encrypt(cks, clear_1).unwrap();
+                    let mut ct_res = engine.smart_add(sks, &mut ct_0, &mut ct_1).unwrap();
+                    let ct_res = engine.smart_mul_lsb(sks, &mut ct_res, &mut ct_1).unwrap();
+                    (engine.decrypt(cks, &ct_res).unwrap(), engine)
+                })
+                .join()
+                .unwrap()
+        });
+        engine = engine_back;
+
+        assert_eq!(result, ((clear_0 + clear_1) * clear_1) % modulus);
+    }
+}
+
 /// test encryption and decryption with the LWE client key
 fn shortint_encrypt_with_message_modulus_decrypt(param: PBSParameters) {
     let keys = KEY_CACHE.get_from_param(param);
@@ -236,6 +433,100 @@ fn shortint_encrypt_with_message_modulus_decrypt(param: PBSParameters) {
     }
 }
 
+fn shortint_sanitize(param: PBSParameters) {
+    let keys = KEY_CACHE.get_from_param(param);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = cks.parameters.message_modulus().0 as u64;
+    let modulus_sup = modulus * cks.parameters.carry_modulus().0 as u64;
+    let delta = (1_u64 << 63) / modulus_sup;
+
+    for _ in 0..NB_TEST {
+        let clear = 1 + rng.gen::<u64>() % (modulus - 1);
+
+        // Both PBS orders can be sanitized by any client key
+        let (mut ct, mut ct_small) = encrypt_with_both_orders(cks, sks, clear);
+        assert_eq!(cks.sanitize(&mut ct), None);
+        assert_eq!(cks.sanitize(&mut ct_small), None);
+
+        // The degree is raised to the whole space, not to the decrypted value
+        ct.degree = Degree(0);
+        ct_small.degree = Degree(0);
+        let expected = Some(SanitizationIssue::DegreeExceeded {
+            degree: Degree(0),
+            message_and_carry: clear,
+        });
+        assert_eq!(cks.sanitize(&mut ct), expected);
+        assert_eq!(cks.sanitize(&mut ct_small), expected);
+        assert_eq!(ct.degree.0 as u64, modulus_sup - 1);
+        assert_eq!(cks.decrypt_message_and_carry(&ct), clear);
+        assert_eq!(cks.decrypt_message_and_carry(&ct_small), clear);
+
+        // Overflow into the padding bit
+        let mut ct_overflow = sks.unchecked_scalar_add(&ct, (modulus_sup - 1) as u8);
+        assert_eq!(
+            cks.sanitize(&mut ct_overflow),
+            Some(SanitizationIssue::PaddingBitSet)
+        );
+        assert_eq!(cks.decrypt_message_and_carry(&ct_overflow), clear - 1);
+        assert_eq!(cks.sanitize(&mut ct_overflow), None);
+
+        // Noise close to the decryption failure bound
+        let degree = ct.degree;
+        let mut body = ct.ct.get_mut_body();
+        *body.data = body.data.wrapping_add(delta / 3);
+        assert_eq!(
+            cks.sanitize(&mut ct),
+            Some(SanitizationIssue::ExcessiveNoise)
+        );
+        assert_eq!(ct.degree, degree);
+        assert_eq!(cks.decrypt_message_and_carry(&ct), clear);
+        assert_eq!(cks.sanitize(&mut ct), None);
+    }
+}
+
+fn shortint_decrypt_with_noise(param: PBSParameters) {
+    let keys = KEY_CACHE.get_from_param(param);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = cks.parameters.message_modulus().0 as u64;
+    let modulus_sup = modulus * cks.parameters.carry_modulus().0 as u64;
+    let delta = (1_u64 << 63) / modulus_sup;
+
+    for _ in 0..NB_TEST {
+        let clear = rng.gen::<u64>() % modulus;
+
+        let (mut ct, ct_small) = encrypt_with_both_orders(cks, sks, clear);
+        for (dec, noise) in [
+            cks.decrypt_with_noise(&ct),
+            cks.decrypt_with_noise(&ct_small),
+        ] {
+            assert_eq!(dec, clear);
+            assert_eq!(noise.decryption_bound, delta / 2);
+            assert!(noise.relative_noise() < 0.5);
+        }
+
+        // Shift the phase by a known amount, the noise of the encryption is far smaller
+        let mut body = ct.ct.get_mut_body();
+        *body.data = body.data.wrapping_sub(delta / 3);
+        let (dec, NoiseEstimate { noise, .. }) = cks.decrypt_with_noise(&ct);
+        assert_eq!(dec, clear);
+        assert!(noise.unsigned_abs().abs_diff(delta / 3) < delta / 8);
+        assert!(noise < 0);
+    }
+
+    // A trivial ciphertext has no noise
+    let trivial: CiphertextBig = sks.create_trivial(modulus - 1);
+    let (dec, noise) = cks.decrypt_with_noise(&trivial);
+    assert_eq!(dec, modulus - 1);
+    assert_eq!(noise.noise, 0);
+    assert_eq!(noise.margin_bits(), f64::INFINITY);
+}
+
 fn shortint_encrypt_decrypt_without_padding(param: PBSParameters) {
     let keys = KEY_CACHE.get_from_param(param);
     let cks = keys.client_key();
@@ -258,6 +549,77 @@ fn shortint_encrypt_decrypt_without_padding(param: PBSParameters) {
     }
 }
 
+fn shortint_no_padding_linear_operations(param: PBSParameters) {
+    let keys = KEY_CACHE.get_from_param(param);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = (cks.parameters.message_modulus().0 * cks.parameters.carry_modulus().0) as u64;
+
+    for _ in 0..NB_TEST {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+        // Keep the scalar small to keep the noise growth of the multiplication bounded
+        let scalar = (rng.gen::<u64>() % cks.parameters.message_modulus().0 as u64) as u8;
+
+        let ct_0 = cks.encrypt_no_padding(clear_0);
+        let ct_1 = cks.encrypt_no_padding(clear_1);
+
+        let ct_res = sks.unchecked_add_no_padding(&ct_0, &ct_1);
+        assert_eq!(
+            cks.decrypt_no_padding(&ct_res),
+            (clear_0 + clear_1) % modulus
+        );
+
+        let ct_res = sks.unchecked_sub_no_padding(&ct_0, &ct_1);
+        assert_eq!(
+            cks.decrypt_no_padding(&ct_res),
+            (clear_0 + modulus - clear_1) % modulus
+        );
+
+        let ct_res = sks.unchecked_neg_no_padding(&ct_0);
+        assert_eq!(
+            cks.decrypt_no_padding(&ct_res),
+            (modulus - clear_0) % modulus
+        );
+
+        let ct_res = sks.unchecked_scalar_add_no_padding(&ct_0, scalar);
+        assert_eq!(
+            cks.decrypt_no_padding(&ct_res),
+            (clear_0 + scalar as u64) % modulus
+        );
+
+        let ct_res = sks.unchecked_scalar_mul_no_padding(&ct_0, scalar);
+        assert_eq!(
+            cks.decrypt_no_padding(&ct_res),
+            (clear_0 * scalar as u64) % modulus
+        );
+    }
+}
+
+fn shortint_no_padding_full_domain_lookup_table(param: PBSParameters) {
+    let keys = KEY_CACHE.get_from_param(param);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+
+    let modulus = (cks.parameters.message_modulus().0 * cks.parameters.carry_modulus().0) as u64;
+
+    // None of these functions is antisymmetric
+    let functions: [&dyn Fn(u64) -> u64; 3] = [&|x| (x * x + 3) % modulus, &|x| x / 2, &|x| {
+        u64::from(x >= modulus / 2)
+    }];
+
+    for f in functions {
+        let acc = sks.generate_lookup_table_full_domain(f);
+        for clear in 0..modulus {
+            let ct = cks.encrypt_no_padding(clear);
+            let ct_res = sks.apply_lookup_table_full_domain(&ct, &acc);
+            assert_eq!(cks.decrypt_no_padding(&ct_res), f(clear));
+            assert!(ct_res.as_inner().degree.0 <= acc.degree().0);
+        }
+    }
+}
+
 fn shortint_keyswitch_bootstrap(param: PBSParameters) {
     let keys = KEY_CACHE.get_from_param(param);
     let (cks, sks) = (keys.client_key(), keys.server_key());
@@ -407,6 +769,251 @@ fn shortint_message_extract(param: PBSParameters) {
     }
 }
 
+fn shortint_carry_and_message_extract(param: PBSParameters) {
+    let keys = KEY_CACHE.get_from_param(param);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    let modulus_sup = (param.message_modulus.0 * param.carry_modulus.0) as u64;
+
+    let modulus = param.message_modulus.0 as u64;
+
+    for _ in 0..NB_TEST {
+        let clear = rng.gen::<u64>() % modulus_sup;
+
+        // encryption of an integer
+        let ctxt = cks.unchecked_encrypt(clear);
+
+        // carry and message extraction with a single PBS
+        let (ct_carry, ct_msg) = sks.carry_and_message_extract(&ctxt);
+
+        // decryption of carry and message
+        let dec_carry = cks.decrypt_message_and_carry(&ct_carry);
+        let dec_msg = cks.decrypt(&ct_msg);
+
+        // assert
+        assert_eq!(clear / modulus, dec_carry);
+        assert_eq!(clear % modulus, dec_msg);
+    }
+}
+
+fn shortint_apply_many_lookup_tables(param: PBSParameters) {
+    let keys = KEY_CACHE.get_from_param(param);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+
+    let mut rng = rand::thread_rng();
+    let modulus = param.message_modulus.0 as u64;
+
+    let functions: [&dyn Fn(u64) -> u64; 4] = [
+        &|x| x,
+        &|x| (x * x) % modulus,
+        &|x| (x + 1) % modulus,
+        &|x| x * (modulus - 1),
+    ];
+
+    let mut count = 1;
+    while count <= functions.len().min(sks.max_many_lookup_table_count()) {
+        let acc = sks.generate_many_accumulator(&functions[..count]);
+        assert_eq!(acc.function_count(), count);
+
+        for _ in 0..NB_TEST {
+            let clear = rng.gen::<u64>() % modulus;
+
+            // Both input PBS orders are supported by any server key
+            let (ct_big, ct_small) = encrypt_with_both_orders(cks, sks, clear);
+            let results_big = sks.apply_many_lookup_tables(&ct_big, &acc);
+            let results_small = sks.apply_many_lookup_tables(&ct_small, &acc);
+            assert_eq!(results_big.len(), count);
+            assert_eq!(results_small.len(), count);
+
+            for (i, f) in functions[..count].iter().enumerate() {
+                assert_eq!(results_big[i].degree, acc.degrees[i]);
+                assert_eq!(cks.decrypt_message_and_carry(&results_big[i]), f(clear));
+                assert_eq!(cks.decrypt_message_and_carry(&results_small[i]), f(clear));
+            }
+        }
+
+        count *= 2;
+    }
+}
+
+fn shortint_on_the_fly_bootstrapping_key(param: PBSParameters) {
+    let keys = KEY_CACHE.get_from_param(param);
+    let cks = keys.client_key();
+    let compressed_sks = CompressedServerKey::new(cks);
+    let sks = ServerKey::from_compressed(compressed_sks, BootstrappingKeyStorage::OnTheFly);
+    assert_eq!(
+        sks.bootstrapping_key_storage(),
+        BootstrappingKeyStorage::OnTheFly
+    );
+
+    // The layout of a ServerKey only supports Fourier bootstrapping keys
+    assert!(bincode::serialize(&sks).is_err());
+    let serialized_sks = bincode::serialize(&VersionedServerKey::from(sks.clone())).unwrap();
+    let versioned_sks: VersionedServerKey = bincode::deserialize(&serialized_sks).unwrap();
+    assert_eq!(ServerKey::from(versioned_sks), sks);
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    let modulus_sup = (param.message_modulus.0 * param.carry_modulus.0) as u64;
+    let modulus = param.message_modulus.0 as u64;
+    let acc = sks.generate_accumulator(|x| (x + 1) % modulus);
+
+    for _ in 0..NB_TEST {
+        let clear = rng.gen::<u64>() % modulus_sup;
+
+        let ctxt = cks.unchecked_encrypt(clear);
+
+        let ct_res = sks.apply_lookup_table(&ctxt, &acc);
+        assert_eq!(cks.decrypt(&ct_res), (clear + 1) % modulus);
+
+        // Falls back to two bootstraps as the many-LUT bootstrap needs a Fourier key
+        let (ct_carry, ct_msg) = sks.carry_and_message_extract(&ctxt);
+        assert_eq!(cks.decrypt_message_and_carry(&ct_carry), clear / modulus);
+        assert_eq!(cks.decrypt(&ct_msg), clear % modulus);
+    }
+
+    // The operations which need a Fourier key convert the on-the-fly key for each call
+    let clear = rng.gen::<u64>() % modulus;
+    let ctxt = cks.encrypt(clear);
+
+    let square = |x: u64| (x * x) % modulus;
+    let double = |x: u64| (2 * x) % modulus;
+    let many_acc = sks.generate_many_accumulator(&[&square, &double]);
+    let results = sks.apply_many_lookup_tables(&ctxt, &many_acc);
+    assert_eq!(cks.decrypt(&results[0]), square(clear));
+    assert_eq!(cks.decrypt(&results[1]), double(clear));
+
+    let glwe_acc = sks.apply_lookup_table_to_glwe(&ctxt, &acc);
+    let ct_res: CiphertextBig = sks.extract_from_glwe(&glwe_acc);
+    assert_eq!(cks.decrypt(&ct_res), (clear + 1) % modulus);
+}
+
+fn shortint_compressed_server_key(param: PBSParameters) {
+    let keys = KEY_CACHE.get_from_param(param);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+    let compressed_sks = CompressedServerKey::new(cks);
+
+    let serialized_sks = bincode::serialize(sks).unwrap();
+    let serialized_compressed_sks = bincode::serialize(&compressed_sks).unwrap();
+    assert!(serialized_compressed_sks.len() < serialized_sks.len());
+
+    let compressed_sks: CompressedServerKey =
+        bincode::deserialize(&serialized_compressed_sks).unwrap();
+    let sks = compressed_sks.decompress();
+    assert_eq!(
+        sks.bootstrapping_key_storage(),
+        BootstrappingKeyStorage::Fourier
+    );
+
+    let mut rng = rand::thread_rng();
+    let modulus = param.message_modulus.0 as u64;
+
+    for _ in 0..NB_TEST {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+
+        let ct_res = sks.mul_lsb(&cks.encrypt(clear_0), &cks.encrypt(clear_1));
+        assert_eq!(cks.decrypt(&ct_res), (clear_0 * clear_1) % modulus);
+    }
+
+    // Layout of the server keys of the previous versions of the library
+    #[derive(serde::Serialize)]
+    struct UnversionedLayout<'a> {
+        key_switching_key: &'a LweKeyswitchKeyOwned<u64>,
+        bootstrapping_key: &'a FourierLweBootstrapKeyOwned,
+        message_modulus: MessageModulus,
+        carry_modulus: CarryModulus,
+        max_degree: MaxDegree,
+        ciphertext_modulus: CiphertextModulus,
+    }
+
+    let serialized_unversioned_sks = bincode::serialize(&UnversionedLayout {
+        key_switching_key: &*sks.key_switching_key,
+        bootstrapping_key: &sks.bootstrapping_key.to_fourier().unwrap(),
+        message_modulus: sks.message_modulus,
+        carry_modulus: sks.carry_modulus,
+        max_degree: sks.max_degree,
+        ciphertext_modulus: sks.ciphertext_modulus,
+    })
+    .unwrap();
+    assert_eq!(
+        bincode::serialize(&sks).unwrap(),
+        serialized_unversioned_sks
+    );
+    let deserialized_sks: ServerKey = bincode::deserialize(&serialized_unversioned_sks).unwrap();
+    assert_eq!(deserialized_sks, sks);
+
+    let serialized_versioned_sks = bincode::serialize(&VersionedServerKey::from(sks)).unwrap();
+    let versioned_sks: VersionedServerKey =
+        bincode::deserialize(&serialized_versioned_sks).unwrap();
+    assert_eq!(ServerKey::from(versioned_sks), deserialized_sks);
+}
+
+/// Encrypts `clear` with the PBS order of the parameters of `cks`, which is the only one it can
+/// encrypt with, and bootstraps it to the other order.
+fn encrypt_with_both_orders(
+    cks: &ClientKey,
+    sks: &ServerKey,
+    clear: u64,
+) -> (CiphertextBig, CiphertextSmall) {
+    let identity = sks.generate_accumulator(|x| x);
+    match cks.parameters.encryption_key_choice() {
+        EncryptionKeyChoice::Big => {
+            let ct_big = cks.encrypt(clear);
+            let ct_small = sks.apply_lookup_table_with_order(&ct_big, &identity);
+            (ct_big, ct_small)
+        }
+        EncryptionKeyChoice::Small => {
+            let ct_small = cks.encrypt_small(clear);
+            let ct_big = sks.apply_lookup_table_with_order(&ct_small, &identity);
+            (ct_big, ct_small)
+        }
+    }
+}
+
+fn shortint_glwe_output_pbs(param: PBSParameters) {
+    let keys = KEY_CACHE.get_from_param(param);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+
+    let mut rng = rand::thread_rng();
+    let modulus = param.message_modulus.0 as u64;
+
+    let square = sks.generate_accumulator(|x| (x * x) % modulus);
+    let increment = sks.generate_accumulator(|x| (x + 1) % modulus);
+
+    for _ in 0..NB_TEST {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+
+        // Both input PBS orders are supported by any server key
+        let (ct_0, _) = encrypt_with_both_orders(cks, sks, clear_0);
+        let (_, ct_1) = encrypt_with_both_orders(cks, sks, clear_1);
+        let acc_0 = sks.apply_lookup_table_to_glwe(&ct_0, &square);
+        let mut acc_1 = sks.apply_lookup_table_to_glwe(&ct_1, &increment);
+
+        let ct_res: CiphertextBig = sks.extract_from_glwe(&acc_0);
+        assert_eq!(cks.decrypt(&ct_res), (clear_0 * clear_0) % modulus);
+        let ct_res: CiphertextSmall = sks.extract_from_glwe(&acc_1);
+        assert_eq!(cks.decrypt(&ct_res), (clear_1 + 1) % modulus);
+
+        // The linear combination of the two outputs is extracted once
+        let mut acc_res = acc_0;
+        sks.unchecked_glwe_scalar_mul_assign(&mut acc_1, modulus as u8);
+        sks.unchecked_glwe_add_assign(&mut acc_res, &acc_1);
+        assert_eq!(
+            acc_res.degree().0,
+            square.degree.0 + modulus as usize * increment.degree.0
+        );
+
+        let ct_res: CiphertextBig = sks.extract_from_glwe(&acc_res);
+        let expected = (clear_0 * clear_0) % modulus + modulus * ((clear_1 + 1) % modulus);
+        assert_eq!(cks.decrypt_message_and_carry(&ct_res), expected);
+    }
+}
+
 /// test multiplication with the LWE server key
 fn shortint_generate_accumulator(param: PBSParameters) {
     let keys = KEY_CACHE.get_from_param(param);
@@ -435,6 +1042,243 @@ fn shortint_generate_accumulator(param: PBSParameters) {
     }
 }
 
+fn shortint_compressed_accumulator(param: PBSParameters) {
+    let keys = KEY_CACHE.get_from_param(param);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+
+    let modulus = cks.parameters.message_modulus().0 as u64;
+    let f = |x| (x * x + 1) % modulus;
+
+    let acc = sks.generate_accumulator(f);
+    let compressed_acc = sks.generate_compressed_accumulator(f);
+
+    assert_eq!(sks.compress_accumulator(&acc), compressed_acc);
+
+    let serialized = bincode::serialize(&compressed_acc).unwrap();
+    let compressed_acc: CompressedLookupTable = bincode::deserialize(&serialized).unwrap();
+    let decompressed_acc = compressed_acc.decompress(sks);
+    assert_eq!(decompressed_acc, acc);
+
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..NB_TEST {
+        let clear = rng.gen::<u64>() % modulus;
+
+        let ct = cks.encrypt(clear);
+
+        let ct_res = sks.apply_lookup_table(&ct, &decompressed_acc);
+
+        let dec_res = cks.decrypt(&ct_res);
+
+        assert_eq!(f(clear), dec_res);
+    }
+}
+
+fn shortint_serialized_accumulator(param: PBSParameters) {
+    let keys = KEY_CACHE.get_from_param(param);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+
+    let modulus = cks.parameters.message_modulus().0 as u64;
+    let f = |x| (x * x + 1) % modulus;
+
+    let acc = sks.generate_accumulator(f);
+    assert_eq!(acc.message_modulus, sks.message_modulus);
+    assert_eq!(acc.carry_modulus, sks.carry_modulus);
+
+    let serialized = bincode::serialize(&acc).unwrap();
+    let deserialized_acc: LookupTableOwned = bincode::deserialize(&serialized).unwrap();
+    assert_eq!(deserialized_acc, acc);
+    assert!(deserialized_acc.is_compatible_with(sks));
+
+    // A lookup table generated for other message and carry moduli can not be used
+    let other_param = if param.message_modulus.0 == 2 {
+        PARAM_MESSAGE_2_CARRY_2
+    } else {
+        PARAM_MESSAGE_1_CARRY_1
+    };
+    let other_keys = KEY_CACHE.get_from_param(other_param);
+    assert!(!deserialized_acc.is_compatible_with(other_keys.server_key()));
+
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..NB_TEST {
+        let clear = rng.gen::<u64>() % modulus;
+
+        let ct_res = sks.apply_lookup_table(&cks.encrypt(clear), &deserialized_acc);
+
+        assert_eq!(f(clear), cks.decrypt(&ct_res));
+    }
+}
+
+fn shortint_lut_fusion(param: PBSParameters) {
+    let keys = KEY_CACHE.get_from_param(param);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+
+    let modulus = cks.parameters.message_modulus().0 as u64;
+    let full_modulus = modulus * cks.parameters.carry_modulus().0 as u64;
+    let f = |x| (x * x + 1) % modulus;
+    let g = |x| (x + 1) % modulus;
+    let acc_f = sks.generate_accumulator(f);
+
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..NB_TEST {
+        let clear = rng.gen::<u64>() % modulus;
+
+        let ct = cks.encrypt(clear);
+
+        let lazy = sks
+            .record_lookup_tables(ct.clone())
+            .apply_lookup_table(&acc_f)
+            .apply_function(g)
+            .apply_lookup_table(&acc_f);
+        assert_eq!(lazy.fused_count(), 2);
+        assert_eq!(lazy.bootstrap_count(), 0);
+        let ct_res = lazy.evaluate();
+        assert_eq!(f(g(f(clear))), cks.decrypt(&ct_res));
+
+        // Values setting the padding bit are not valid inputs of the next lookup table
+        let lazy = sks
+            .record_lookup_tables(ct)
+            .apply_function(|x| x + full_modulus)
+            .apply_lookup_table(&acc_f);
+        assert_eq!(lazy.fused_count(), 0);
+        assert_eq!(lazy.bootstrap_count(), 1);
+        let ct_res = lazy.evaluate();
+
+        let ct_res_unfused = sks.apply_lookup_table(
+            &sks.apply_lookup_table(
+                &cks.encrypt(clear),
+                &sks.generate_accumulator(|x| x + full_modulus),
+            ),
+            &acc_f,
+        );
+        assert_eq!(cks.decrypt(&ct_res_unfused), cks.decrypt(&ct_res));
+    }
+}
+
+#[test]
+fn test_shortint_multi_bit_pbs_param_multi_bit_message_2_carry_2_group_2() {
+    shortint_multi_bit_pbs(PARAM_MULTI_BIT_MESSAGE_2_CARRY_2_GROUP_2)
+}
+
+#[test]
+fn test_shortint_multi_bit_pbs_param_multi_bit_message_1_carry_1_group_3() {
+    shortint_multi_bit_pbs(PARAM_MULTI_BIT_MESSAGE_1_CARRY_1_GROUP_3)
+}
+
+fn shortint_multi_bit_pbs(param: MultiBitPBSParameters) {
+    // The key cache only stores classic parameter sets
+    let (cks, sks) = crate::shortint::gen_keys(param);
+    assert_eq!(
+        sks.bootstrapping_key_storage(),
+        BootstrappingKeyStorage::MultiBit
+    );
+    assert_eq!(
+        sks.bootstrapping_key.to_fourier().err(),
+        Some(UnsupportedStorageError {
+            storage: BootstrappingKeyStorage::MultiBit
+        })
+    );
+
+    // Multi-bit keys are only serialized in the versioned layout
+    assert!(bincode::serialize(&sks).is_err());
+    let serialized = bincode::serialize(&VersionedServerKey::from(sks)).unwrap();
+    let sks: ServerKey = bincode::deserialize::<VersionedServerKey>(&serialized)
+        .unwrap()
+        .into();
+
+    let modulus = cks.parameters.message_modulus().0 as u64;
+    let f = |x| (x * x + 1) % modulus;
+    let acc = sks.generate_accumulator(f);
+
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..NB_TEST {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+
+        let ct_0 = cks.encrypt(clear_0);
+        let ct_1 = cks.encrypt(clear_1);
+
+        let ct_res = sks.apply_lookup_table(&ct_0, &acc);
+        assert_eq!(f(clear_0), cks.decrypt(&ct_res));
+
+        // Messages and carries are extracted with the multi-bit PBS as well
+        let ct_res = sks.unchecked_add(&ct_0, &ct_1);
+        let ct_res = sks.message_extract(&ct_res);
+        assert_eq!((clear_0 + clear_1) % modulus, cks.decrypt(&ct_res));
+    }
+}
+
+#[test]
+fn test_shortint_ternary_secret_key() {
+    let param = PBSParametersBuilder::from(PARAM_MESSAGE_2_CARRY_2)
+        .secret_key_distribution(SecretKeyDistribution::Ternary)
+        .build()
+        .unwrap();
+
+    // The key cache only stores named parameter sets
+    let (cks, sks) = crate::shortint::gen_keys(param);
+    assert!(cks
+        .glwe_secret_key
+        .as_ref()
+        .iter()
+        .any(|&coef| coef == u64::MAX));
+
+    let modulus = cks.parameters.message_modulus().0 as u64;
+    let f = |x| (x * x + 1) % modulus;
+    let acc = sks.generate_accumulator(f);
+
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..NB_TEST {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+
+        let ct_0 = cks.encrypt(clear_0);
+        let ct_1 = cks.encrypt(clear_1);
+
+        let ct_res = sks.apply_lookup_table(&ct_0, &acc);
+        assert_eq!(f(clear_0), cks.decrypt(&ct_res));
+
+        let ct_res = sks.add(&ct_0, &ct_1);
+        assert_eq!((clear_0 + clear_1) % modulus, cks.decrypt(&ct_res));
+    }
+}
+
+fn shortint_ciphertext_canonical_bytes(param: PBSParameters) {
+    let keys = KEY_CACHE.get_from_param(param);
+    let cks = keys.client_key();
+
+    let modulus = cks.parameters.message_modulus().0 as u64;
+
+    let mut rng = rand::thread_rng();
+
+    let mut set = std::collections::HashSet::new();
+    for _ in 0..NB_TEST {
+        let clear = rng.gen::<u64>() % modulus;
+
+        let ct = cks.encrypt(clear);
+        let ct_clone = ct.clone();
+        assert_eq!(ct.canonical_bytes(), ct_clone.canonical_bytes());
+
+        // The encryption is randomized, so two encryptions of the same value differ
+        let ct_other = cks.encrypt(clear);
+        assert_ne!(ct.canonical_bytes(), ct_other.canonical_bytes());
+
+        // Same coefficients but a different degree
+        let mut ct_degree = ct.clone();
+        ct_degree.degree = Degree(ct.degree.0 + 1);
+        assert_ne!(ct.canonical_bytes(), ct_degree.canonical_bytes());
+
+        assert!(set.insert(ct));
+        assert!(!set.insert(ct_clone));
+        assert!(set.insert(ct_other));
+    }
+    assert_eq!(set.len(), 2 * NB_TEST);
+}
+
 /// test addition with the LWE server key
 fn shortint_unchecked_add(param: PBSParameters) {
     let keys = KEY_CACHE.get_from_param(param);
@@ -628,6 +1472,47 @@ fn shortint_public_key_smart_add(param: PBSParameters) {
     }
 }
 
+/// test the encryption of lists with the compact public key and their expansion
+fn shortint_compact_public_key(param: PBSParameters) {
+    let keys = KEY_CACHE.get_from_param(param);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    let modulus = cks.parameters.message_modulus().0 as u64;
+
+    let pk = crate::shortint::CompactPublicKeyBig::new(cks);
+
+    // A list spanning more than one GLWE ciphertext
+    assert!(2500 > cks.parameters.polynomial_size().0);
+    let clears: Vec<u64> = (0..2500).map(|_| rng.gen::<u64>() % modulus).collect();
+
+    let list = pk.encrypt_slice(&clears);
+    assert_eq!(list.len(), clears.len());
+    let serialized = bincode::serialize(&list).unwrap();
+    let list: crate::shortint::CompactCiphertextListBig =
+        bincode::deserialize(&serialized).unwrap();
+    let cts = list.expand();
+    assert_eq!(cts.len(), clears.len());
+    for (ct, clear) in cts.iter().zip(clears.iter()) {
+        assert_eq!(cks.decrypt(ct), *clear);
+    }
+
+    // The expanded ciphertexts can be used by the server key
+    for _ in 0..NB_TEST {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+
+        let cts = pk.encrypt_slice(&[clear_0, clear_1]).expand();
+        let ct_res = sks.mul_lsb(&cts[0], &cts[1]);
+        assert_eq!(cks.decrypt(&ct_res), (clear_0 * clear_1) % modulus);
+
+        let ct_res = sks.add(&pk.encrypt(clear_0), &pk.encrypt(clear_1));
+        assert_eq!(cks.decrypt(&ct_res), (clear_0 + clear_1) % modulus);
+    }
+}
+
 /// test bitwise 'and' with the LWE server key
 fn shortint_unchecked_bitand(param: PBSParameters) {
     let keys = KEY_CACHE.get_from_param(param);
@@ -718,6 +1603,47 @@ fn shortint_unchecked_bitxor(param: PBSParameters) {
     }
 }
 
+fn shortint_checked_apply_lookup_table_bivariate(param: PBSParameters) {
+    let keys = KEY_CACHE.get_from_param(param);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = cks.parameters.message_modulus().0 as u64;
+    let carry_modulus = cks.parameters.carry_modulus().0;
+    let f = |x: u64, y: u64| (x * y + x) % modulus;
+    let acc = sks.generate_accumulator_bivariate(f);
+
+    for _ in 0..NB_TEST {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+
+        let mut ctxt_0 = cks.encrypt(clear_0);
+        let ctxt_1 = cks.encrypt(clear_1);
+
+        let ct_res = sks
+            .checked_apply_lookup_table_bivariate(&ctxt_0, &ctxt_1, &acc)
+            .unwrap();
+        assert_eq!(cks.decrypt(&ct_res), f(clear_0, clear_1));
+
+        // The shifted left operand would overflow the carry space
+        let mut ctxt_full = ctxt_0.clone();
+        ctxt_full.degree = Degree(carry_modulus);
+        assert!(sks
+            .checked_apply_lookup_table_bivariate(&ctxt_full, &ctxt_1, &acc)
+            .is_err());
+        let ctxt_copy = ctxt_full.clone();
+        assert!(sks
+            .checked_apply_lookup_table_bivariate_assign(&mut ctxt_full, &ctxt_1, &acc)
+            .is_err());
+        assert_eq!(ctxt_full, ctxt_copy);
+
+        sks.checked_apply_lookup_table_bivariate_assign(&mut ctxt_0, &ctxt_1, &acc)
+            .unwrap();
+        assert_eq!(cks.decrypt(&ctxt_0), f(clear_0, clear_1));
+    }
+}
+
 /// test bitwise 'and' with the LWE server key
 fn shortint_smart_bitand(param: PBSParameters) {
     let keys = KEY_CACHE.get_from_param(param);
@@ -1664,6 +2590,79 @@ fn shortint_unchecked_div(param: PBSParameters) {
     }
 }
 
+/// test modulo between two ciphertexts with the LWE server key
+fn shortint_unchecked_mod_bivariate(param: PBSParameters) {
+    let keys = KEY_CACHE.get_from_param(param);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    let modulus = cks.parameters.message_modulus().0 as u64;
+
+    for _ in 0..NB_TEST {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+
+        // encryption of an integer
+        let ctxt_0 = cks.encrypt(clear_0);
+
+        // encryption of an integer
+        let ctxt_1 = cks.encrypt(clear_1);
+
+        // compute the remainder, the divisor may be 0
+        let ct_res = sks.unchecked_mod(&ctxt_0, &ctxt_1);
+        let ct_quotient = sks.unchecked_div(&ctxt_0, &ctxt_1);
+
+        // decryption of ct_res
+        let dec_res = cks.decrypt(&ct_res);
+        let dec_quotient = cks.decrypt(&ct_quotient);
+
+        // assert
+        let expected = if clear_1 == 0 {
+            clear_0
+        } else {
+            clear_0 % clear_1
+        };
+        assert_eq!(expected, dec_res);
+        assert_eq!(clear_0, dec_quotient * clear_1 + dec_res);
+    }
+}
+
+/// test smart modulo between two ciphertexts with the LWE server key
+fn shortint_smart_mod(param: PBSParameters) {
+    let keys = KEY_CACHE.get_from_param(param);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    let modulus = cks.parameters.message_modulus().0 as u64;
+
+    for _ in 0..NB_TEST {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = (rng.gen::<u64>() % (modulus - 1)) + 1;
+        let clear_2 = rng.gen::<u64>() % modulus;
+
+        // encryption of an integer
+        let mut ctxt_0 = cks.encrypt(clear_0);
+
+        // encryption of an integer
+        let mut ctxt_1 = cks.encrypt(clear_1);
+
+        // fill the carries of the dividend
+        sks.unchecked_scalar_add_assign(&mut ctxt_0, clear_2 as u8);
+        let clear_0 = (clear_0 + clear_2) % modulus;
+
+        // compute the remainder
+        let ct_res = sks.smart_mod(&mut ctxt_0, &mut ctxt_1);
+
+        // decryption of ct_res
+        let dec_res = cks.decrypt(&ct_res);
+
+        // assert
+        assert_eq!(clear_0 % clear_1, dec_res);
+    }
+}
+
 /// test scalar division with the LWE server key
 fn shortint_unchecked_scalar_div(param: PBSParameters) {
     let keys = KEY_CACHE.get_from_param(param);
@@ -2452,6 +3451,44 @@ fn shortint_default_left_shift(param: PBSParameters) {
     }
 }
 
+/// test rotations of the bits of the message
+fn shortint_scalar_rotate(param: PBSParameters) {
+    let keys = KEY_CACHE.get_from_param(param);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    let modulus = cks.parameters.message_modulus().0 as u64;
+    let num_bits = modulus.trailing_zeros() as u64;
+
+    for _ in 0..NB_TEST {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+        let rotation = rng.gen::<u64>() % (2 * num_bits + 1);
+
+        // encryption of an integer, with a non empty carry
+        let mut ctxt_0 = cks.encrypt(clear_0);
+        sks.unchecked_add_assign(&mut ctxt_0, &cks.encrypt(clear_1));
+        let clear = (clear_0 + clear_1) % modulus;
+
+        let r = rotation % num_bits;
+        let expected_left = ((clear << r) | (clear >> ((num_bits - r) % num_bits))) % modulus;
+        let expected_right = ((clear >> r) | (clear << ((num_bits - r) % num_bits))) % modulus;
+
+        let ct_left = sks.scalar_rotate_left(&ctxt_0, rotation as u8);
+        let ct_right = sks.unchecked_scalar_rotate_right(&ctxt_0, rotation as u8);
+
+        assert!(ct_left.carry_is_empty());
+        assert!(ct_right.carry_is_empty());
+        assert_eq!(expected_left, cks.decrypt(&ct_left));
+        assert_eq!(expected_right, cks.decrypt(&ct_right));
+
+        // rotating back gives the message
+        let ct_back = sks.scalar_rotate_right(&ct_left, rotation as u8);
+        assert_eq!(clear, cks.decrypt(&ct_back));
+    }
+}
+
 /// test unchecked subtraction
 fn shortint_unchecked_sub(param: PBSParameters) {
     let keys = KEY_CACHE.get_from_param(param);
@@ -2633,3 +3670,287 @@ fn shortint_mux(param: PBSParameters) {
     println!("(msg_true - msg_false) * control_bit  + msg_false = {clear_mux}, res = {dec_res}");
     assert_eq!(clear_mux, dec_res);
 }
+
+fn shortint_smart_evaluate_trivariate_function(param: PBSParameters) {
+    let keys = KEY_CACHE.get_from_param(param);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+
+    let mut rng = rand::thread_rng();
+    let modulus = cks.parameters.message_modulus().0 as u64;
+
+    // A multiplexer and a full adder
+    let mux = |control: u64, x: u64, y: u64| if control % 2 == 1 { x } else { y };
+    let adder = |x: u64, y: u64, z: u64| (x + y + z) % modulus;
+
+    for _ in 0..NB_TEST {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+        let clear_2 = rng.gen::<u64>() % modulus;
+
+        let mut ct_0 = cks.encrypt(clear_0);
+        let mut ct_1 = cks.encrypt(clear_1);
+        let mut ct_2 = cks.encrypt(clear_2);
+
+        assert!(sks.is_functional_trivariate_pbs_possible(&ct_0, &ct_1, &ct_2));
+        let ct_res = sks.unchecked_evaluate_trivariate_function(&ct_0, &ct_1, &ct_2, mux);
+        assert_eq!(cks.decrypt(&ct_res), mux(clear_0, clear_1, clear_2));
+
+        // Operands with carries are cleaned first if they do not fit together
+        sks.unchecked_add_assign(&mut ct_0, &ct_1);
+        let clear_sum = clear_0 + clear_1;
+        let ct_res = sks.smart_evaluate_trivariate_function(&mut ct_0, &mut ct_1, &mut ct_2, adder);
+        assert_eq!(cks.decrypt(&ct_res), adder(clear_sum, clear_1, clear_2));
+
+        sks.smart_evaluate_trivariate_function_assign(&mut ct_0, &mut ct_1, &mut ct_2, mux);
+        assert_eq!(
+            cks.decrypt(&ct_0),
+            mux(clear_sum % modulus, clear_1, clear_2)
+        );
+
+        // An operand with a low degree but more than the nominal noise is cleaned as well
+        let (bit_0, bit_1) = (clear_0 % 2, clear_1 % 2);
+        let mut ct_bits = cks.encrypt(bit_0);
+        ct_bits.degree = Degree(1);
+        let mut ct_bit_1 = cks.encrypt(bit_1);
+        ct_bit_1.degree = Degree(1);
+        sks.unchecked_add_assign(&mut ct_bits, &ct_bit_1);
+
+        let mut ct_1 = cks.encrypt(clear_1);
+        let mut ct_2 = cks.encrypt(clear_2);
+        let ct_res =
+            sks.smart_evaluate_trivariate_function(&mut ct_bits, &mut ct_1, &mut ct_2, adder);
+        assert_eq!(cks.decrypt(&ct_res), adder(bit_0 + bit_1, clear_1, clear_2));
+    }
+}
+
+fn shortint_noise_level(param: PBSParameters) {
+    let keys = KEY_CACHE.get_from_param(param);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+
+    let mut rng = rand::thread_rng();
+    let modulus = cks.parameters.message_modulus().0 as u64;
+    let max_noise_level = sks.max_noise_level();
+
+    for _ in 0..NB_TEST {
+        let clear = rng.gen::<u64>() % modulus;
+
+        let (mut ct, _) = encrypt_with_both_orders(cks, sks, clear);
+        assert_eq!(ct.noise_level, NoiseLevel::NOMINAL);
+        let trivial: CiphertextBig = sks.create_trivial(clear);
+        assert_eq!(trivial.noise_level, NoiseLevel::ZERO);
+
+        let ct_sum = sks.unchecked_add(&ct, &trivial);
+        assert_eq!(ct_sum.noise_level, NoiseLevel::NOMINAL);
+        let ct_sum = sks.unchecked_add(&ct, &ct);
+        assert_eq!(ct_sum.noise_level, NoiseLevel(2));
+        let ct_prod = sks.unchecked_scalar_mul(&ct_sum, 2);
+        assert_eq!(ct_prod.noise_level, NoiseLevel(4));
+        let ct_extracted = sks.message_extract(&ct_prod);
+        assert_eq!(ct_extracted.noise_level, NoiseLevel::NOMINAL);
+
+        // Adding encryptions of zero keeps the degree but grows the noise, until the addition is
+        // refused because of the noise alone
+        let (mut ct_zero, _) = encrypt_with_both_orders(cks, sks, 0);
+        ct_zero.degree = Degree(0);
+        while max_noise_level.validate(ct.noise_level + ct_zero.noise_level) {
+            assert!(sks.is_add_possible(&ct, &ct_zero));
+            sks.unchecked_add_assign(&mut ct, &ct_zero);
+        }
+        assert!(!sks.is_add_possible(&ct, &ct_zero));
+        assert!(sks.checked_add(&ct, &ct_zero).is_err());
+
+        sks.smart_add_assign(&mut ct, &mut ct_zero);
+        assert!(max_noise_level.validate(ct.noise_level));
+        assert_eq!(cks.decrypt(&ct), clear);
+    }
+}
+
+fn shortint_checked_errors(param: PBSParameters) {
+    let keys = KEY_CACHE.get_from_param(param);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+
+    let modulus = cks.parameters.message_modulus().0 as u64;
+    let identity = sks.generate_accumulator(|x| x);
+    let (ct, _) = encrypt_with_both_orders(cks, sks, modulus - 1);
+
+    assert!(sks.checked_add(&ct, &ct).is_ok());
+    assert_eq!(
+        sks.checked_apply_lookup_table(&ct, &identity),
+        Ok(sks.apply_lookup_table(&ct, &identity))
+    );
+
+    // The result would not fit in the carries
+    let mut ct_full = ct.clone();
+    while sks.is_add_possible(&ct_full, &ct) {
+        sks.unchecked_add_assign(&mut ct_full, &ct);
+    }
+    assert_eq!(sks.checked_add(&ct_full, &ct), Err(CheckError::CarryFull));
+    assert_eq!(sks.checked_mul(&ct_full, &ct), Err(CheckError::CarryFull));
+
+    // Invalid operands are refused, whatever the operation
+    let mut ct_degree = ct.clone();
+    ct_degree.degree = Degree(sks.max_degree.0 + 1);
+    let expected = Err(CheckError::DegreeExceeded {
+        degree: ct_degree.degree,
+        max_degree: sks.max_degree,
+    });
+    assert_eq!(sks.checked_add(&ct, &ct_degree), expected);
+    assert_eq!(sks.checked_scalar_mul(&ct_degree, 1), expected);
+    assert_eq!(
+        sks.checked_apply_lookup_table(&ct_degree, &identity),
+        expected
+    );
+
+    let mut ct_noise = ct.clone();
+    ct_noise.noise_level = NoiseLevel(sks.max_noise_level().0 + 1);
+    let expected = Err(CheckError::NoiseLevelExceeded {
+        noise_level: ct_noise.noise_level,
+        max_noise_level: sks.max_noise_level(),
+    });
+    assert_eq!(sks.checked_neg(&ct_noise), expected);
+    assert_eq!(sks.checked_bitand(&ct, &ct_noise), expected);
+    assert_eq!(
+        sks.checked_apply_lookup_table(&ct_noise, &identity),
+        expected
+    );
+
+    let mut ct_moduli = ct.clone();
+    ct_moduli.carry_modulus = CarryModulus(sks.carry_modulus.0 * 2);
+    let expected = Err(CheckError::MismatchedModuli {
+        expected: (sks.message_modulus, sks.carry_modulus),
+        actual: (ct_moduli.message_modulus, ct_moduli.carry_modulus),
+    });
+    assert_eq!(sks.checked_sub(&ct_moduli, &ct), expected);
+    assert_eq!(sks.checked_less(&ct, &ct_moduli), expected);
+
+    let mut other_identity = identity.clone();
+    other_identity.message_modulus = MessageModulus(sks.message_modulus.0 * 2);
+    assert_eq!(
+        sks.checked_apply_lookup_table(&ct, &other_identity),
+        Err(CheckError::IncompatibleLookupTable)
+    );
+}
+
+fn shortint_batch_apply_lookup_table(param: PBSParameters) {
+    let keys = KEY_CACHE.get_from_param(param);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+
+    let mut rng = rand::thread_rng();
+    let modulus = cks.parameters.message_modulus().0 as u64;
+
+    let f = |x: u64| (x * x + 1) % modulus;
+    let acc = sks.generate_accumulator(f);
+
+    let clears: Vec<u64> = (0..NB_TEST).map(|_| rng.gen::<u64>() % modulus).collect();
+    let (mut cts, mut cts_small): (Vec<_>, Vec<_>) = clears
+        .iter()
+        .map(|&clear| encrypt_with_both_orders(cks, sks, clear))
+        .unzip();
+
+    let cts_res = sks.batch_apply_lookup_table(&cts, &acc);
+    let cts_small_res = sks.batch_apply_lookup_table(&cts_small, &acc);
+    assert_eq!(cts_res.len(), clears.len());
+    for (i, &clear) in clears.iter().enumerate() {
+        assert_eq!(cts_res[i], sks.apply_lookup_table(&cts[i], &acc));
+        assert_eq!(cks.decrypt(&cts_res[i]), f(clear));
+        assert_eq!(cks.decrypt(&cts_small_res[i]), f(clear));
+    }
+
+    sks.batch_apply_lookup_table_assign(&mut cts, &acc);
+    sks.batch_apply_lookup_table_assign(&mut cts_small, &acc);
+    assert_eq!(cts, cts_res);
+    assert_eq!(cts_small, cts_small_res);
+
+    assert!(sks.batch_apply_lookup_table(&cts[..0], &acc).is_empty());
+}
+
+fn shortint_if_then_else(param: PBSParameters) {
+    let keys = KEY_CACHE.get_from_param(param);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+
+    let mut rng = rand::thread_rng();
+    let modulus = cks.parameters.message_modulus().0 as u64;
+
+    let to_bit = sks.generate_accumulator(|x| x % 2);
+
+    for _ in 0..NB_TEST {
+        let clear_condition = rng.gen::<u64>() % 2;
+        let clear_then = rng.gen::<u64>() % modulus;
+        let clear_else = rng.gen::<u64>() % modulus;
+        let expected = if clear_condition == 1 {
+            clear_then
+        } else {
+            clear_else
+        };
+
+        let mut condition = cks.encrypt(clear_condition);
+        let mut ct_then = cks.encrypt(clear_then);
+        let mut ct_else = cks.encrypt(clear_else);
+
+        let ct_res = sks.if_then_else(&condition, &ct_then, &ct_else);
+        assert_eq!(cks.decrypt(&ct_res), expected);
+        assert!(ct_res.carry_is_empty());
+
+        let ct_res = sks
+            .checked_if_then_else(&condition, &ct_then, &ct_else)
+            .unwrap();
+        assert_eq!(cks.decrypt(&ct_res), expected);
+
+        // Conditions and branches of degree 1, e.g. the outputs of comparisons
+        let condition_bit = sks.apply_lookup_table(&condition, &to_bit);
+        let then_bit = sks.apply_lookup_table(&ct_then, &to_bit);
+        let else_bit = sks.apply_lookup_table(&ct_else, &to_bit);
+        let ct_res = sks.unchecked_if_then_else(&condition_bit, &then_bit, &else_bit);
+        assert_eq!(cks.decrypt(&ct_res), expected % 2);
+
+        // Branches with full carries
+        let scalar = sks.max_degree.0 / (modulus as usize - 1);
+        sks.unchecked_scalar_mul_assign(&mut ct_then, scalar as u8);
+        sks.unchecked_scalar_add_assign(&mut ct_else, (modulus - 1) as u8);
+        let ct_res = sks.smart_if_then_else(&mut condition, &mut ct_then, &mut ct_else);
+        let expected = if clear_condition == 1 {
+            cks.decrypt_message_and_carry(&ct_then)
+        } else {
+            cks.decrypt_message_and_carry(&ct_else)
+        };
+        assert_eq!(cks.decrypt(&ct_res), expected % modulus);
+    }
+}
+
+fn shortint_refresh(param: PBSParameters) {
+    let keys = KEY_CACHE.get_from_param(param);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+
+    let mut rng = rand::thread_rng();
+    let modulus = cks.parameters.message_modulus().0 as u64;
+
+    let clears: Vec<u64> = (0..NB_TEST).map(|_| rng.gen::<u64>() % modulus).collect();
+    let mut store: Vec<_> = clears.iter().map(|&clear| cks.encrypt(clear)).collect();
+
+    // Every other ciphertext goes through leveled operations
+    let mut expected = clears.clone();
+    for (i, ct) in store.iter_mut().enumerate().step_by(2) {
+        sks.unchecked_scalar_mul_assign(ct, 2);
+        sks.unchecked_scalar_add_assign(ct, 1);
+        expected[i] = (2 * clears[i] + 1) % modulus;
+    }
+
+    let policy = RefreshPolicy::fresh(sks);
+    let stale_count = store.iter().filter(|&ct| policy.is_stale(ct)).count();
+    assert_eq!(stale_count, (NB_TEST + 1) / 2);
+
+    let fresh_before: Vec<_> = store.iter().skip(1).step_by(2).cloned().collect();
+    assert_eq!(sks.refresh_stale(store.iter_mut(), &policy), stale_count);
+    assert_eq!(sks.refresh_stale(store.iter_mut(), &policy), 0);
+
+    for (ct, &clear) in store.iter().zip(expected.iter()) {
+        assert!(!policy.is_stale(ct));
+        assert_eq!(cks.decrypt(ct), clear);
+    }
+    // The fresh ciphertexts were left untouched
+    assert!(store.iter().skip(1).step_by(2).eq(fresh_before.iter()));
+
+    // A boolean keeps its degree
+    let ct = sks.refresh(&sks.scalar_greater(&store[0], 0));
+    assert_eq!(ct.degree, Degree(1));
+}
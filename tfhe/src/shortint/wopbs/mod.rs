@@ -6,14 +6,22 @@
 //!
 //! In the case where a padding bit is defined, keys are generated so that there a compatible for
 //! both uses.
+//!
+//! The bits of a ciphertext can also be extracted into [`WopbsBits`], manipulated, then
+//! reassembled into a ciphertext under the parameters of the classical PBS.
 
+use crate::core_crypto::algorithms::*;
 use crate::core_crypto::commons::parameters::*;
 pub use crate::core_crypto::commons::parameters::{CiphertextCount, PlaintextCount};
 use crate::core_crypto::commons::traits::*;
 use crate::core_crypto::entities::*;
 use crate::shortint::engine::ShortintEngine;
-use crate::shortint::{CiphertextBase, ClientKey, PBSOrderMarker, ServerKey, WopbsParameters};
+use crate::shortint::parameters::{CarryModulus, MessageModulus, ParameterVersion};
+use crate::shortint::{
+    CiphertextBase, CiphertextNoPaddingBase, ClientKey, PBSOrderMarker, ServerKey, WopbsParameters,
+};
 use serde::{Deserialize, Serialize};
+use std::marker::PhantomData;
 
 #[cfg(test)]
 mod test;
@@ -29,6 +37,77 @@ pub struct WopbsKey {
     pub param: WopbsParameters,
 }
 
+/// The bits of the message and the carry of a shortint ciphertext, each encrypted on its own
+/// under the key of the WoPBS parameters.
+///
+/// Bits are obtained with [`WopbsKey::extract_all_bits`] and turned back into a shortint
+/// ciphertext with [`WopbsKey::insert_bits`]. In between, the bitwise operations below are
+/// leveled: they do not need any bootstrap, but each XOR adds up the noise of its operands.
+///
+/// Bits are indexed from the least significant one.
+#[derive(Clone, Debug)]
+#[must_use]
+pub struct WopbsBits<OpOrder: PBSOrderMarker> {
+    // One ciphertext per bit, from the most significant one, as expected by the circuit
+    // bootstrapping
+    pub(crate) bits: LweCiphertextListOwned<u64>,
+    pub(crate) message_modulus: MessageModulus,
+    pub(crate) carry_modulus: CarryModulus,
+    pub(crate) parameter_version: Option<ParameterVersion>,
+    pub(crate) _order_marker: PhantomData<OpOrder>,
+}
+
+impl<OpOrder: PBSOrderMarker> WopbsBits<OpOrder> {
+    /// Returns the number of bits, i.e. the number of bits of `message_modulus * carry_modulus`.
+    pub fn len(&self) -> usize {
+        self.bits.lwe_ciphertext_count().0
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn position(&self, index: usize) -> usize {
+        assert!(
+            index < self.len(),
+            "index {index} out of bounds, max {}",
+            self.len()
+        );
+        self.len() - 1 - index
+    }
+
+    /// Flips the bit at `index`.
+    pub fn not_assign(&mut self, index: usize) {
+        let position = self.position(index);
+        let mut bit = self.bits.get_mut(position);
+        lwe_ciphertext_plaintext_add_assign(&mut bit, Plaintext(1 << 63));
+    }
+
+    /// Replaces the bit at `index` by its XOR with the bit at `other_index`.
+    pub fn xor_assign(&mut self, index: usize, other_index: usize) {
+        let position = self.position(index);
+        let other_position = self.position(other_index);
+        let ciphertext_modulus = self.bits.ciphertext_modulus();
+        let other = LweCiphertext::from_container(
+            self.bits.get(other_position).as_ref().to_vec(),
+            ciphertext_modulus,
+        );
+        let mut bit = self.bits.get_mut(position);
+        lwe_ciphertext_add_assign(&mut bit, &other);
+    }
+
+    /// Swaps the bits at `index` and `other_index`.
+    pub fn swap(&mut self, index: usize, other_index: usize) {
+        let position = self.position(index);
+        let other_position = self.position(other_index);
+        let lwe_size = self.bits.lwe_size().0;
+        let data = self.bits.as_mut();
+        for i in 0..lwe_size {
+            data.swap(position * lwe_size + i, other_position * lwe_size + i);
+        }
+    }
+}
+
 #[must_use]
 pub struct WopbsLUTBase {
     // Flattened Wopbs LUT
@@ -432,6 +511,34 @@ impl WopbsKey {
         })
     }
 
+    /// Apply the Look-Up Table homomorphically using the WoPBS approach
+    /// on a ciphertext encrypted without padding bit.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::parameters_wopbs_message_carry::WOPBS_PARAM_MESSAGE_2_CARRY_2;
+    /// use tfhe::shortint::wopbs::WopbsKey;
+    ///
+    /// let (cks, sks) = gen_keys(WOPBS_PARAM_MESSAGE_2_CARRY_2);
+    /// let wopbs_key = WopbsKey::new_wopbs_key_only_for_wopbs(&cks, &sks);
+    /// let message_modulus = WOPBS_PARAM_MESSAGE_2_CARRY_2.message_modulus.0 as u64;
+    /// let m = 2;
+    /// let ct = cks.encrypt_no_padding(m);
+    /// let lut = wopbs_key.generate_lut(ct.as_inner(), |x| x * x % message_modulus);
+    /// let ct_res = wopbs_key.programmable_bootstrapping_no_padding(&ct, &lut);
+    /// let res = cks.decrypt_no_padding(&ct_res);
+    /// assert_eq!(res, (m * m) % message_modulus);
+    /// ```
+    pub fn programmable_bootstrapping_no_padding<OpOrder: PBSOrderMarker>(
+        &self,
+        ct_in: &CiphertextNoPaddingBase<OpOrder>,
+        lut: &ShortintWopbsLUT,
+    ) -> CiphertextNoPaddingBase<OpOrder> {
+        CiphertextNoPaddingBase(self.programmable_bootstrapping_without_padding(&ct_in.0, lut))
+    }
+
     /// Apply the Look-Up Table homomorphically using the WoPBS approach.
     ///
     /// # Example
@@ -504,6 +611,7 @@ impl WopbsKey {
                 ExtractedBitsCount(num_bits_to_extract),
                 output,
             )
+            .unwrap()
         })
     }
 
@@ -523,6 +631,55 @@ impl WopbsKey {
         })
     }
 
+    /// Extracts the bits of the message and of the carry of `ct`, see [`WopbsBits`].
+    ///
+    /// `ct` is first bootstrapped and keyswitched to the WoPBS parameters, `sks` is the server key
+    /// it was computed with.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::parameters_wopbs_message_carry::WOPBS_PARAM_MESSAGE_2_CARRY_2;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    /// use tfhe::shortint::wopbs::*;
+    ///
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    /// let wopbs_key = WopbsKey::new_wopbs_key(&cks, &sks, &WOPBS_PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let ct = cks.encrypt(2);
+    /// let mut bits = wopbs_key.extract_all_bits(&sks, &ct);
+    /// assert_eq!(bits.len(), 4);
+    ///
+    /// // 0b0010 -> 0b0001 -> 0b0011
+    /// bits.swap(0, 1);
+    /// bits.not_assign(1);
+    ///
+    /// // Back to classical PBS computations
+    /// let ct_res = wopbs_key.insert_bits(&bits);
+    /// assert_eq!(cks.decrypt(&ct_res), 3);
+    /// ```
+    pub fn extract_all_bits<OpOrder: PBSOrderMarker>(
+        &self,
+        sks: &ServerKey,
+        ct: &CiphertextBase<OpOrder>,
+    ) -> WopbsBits<OpOrder> {
+        ShortintEngine::with_thread_local_mut(|engine| engine.extract_all_bits(self, sks, ct))
+            .unwrap()
+    }
+
+    /// Reassembles `bits` into a ciphertext under the parameters of the server key, its carries
+    /// included, see [`WopbsKey::extract_all_bits`].
+    ///
+    /// The result has the nominal noise level, its degree is the largest value the bits can
+    /// encode.
+    pub fn insert_bits<OpOrder: PBSOrderMarker>(
+        &self,
+        bits: &WopbsBits<OpOrder>,
+    ) -> CiphertextBase<OpOrder> {
+        ShortintEngine::with_thread_local_mut(|engine| engine.insert_bits(self, bits)).unwrap()
+    }
+
     pub fn keyswitch_to_wopbs_params<OpOrder: PBSOrderMarker>(
         &self,
         sks: &ServerKey,
@@ -36,6 +36,7 @@ macro_rules! create_parametrized_test{
 create_parametrized_test!(generate_lut);
 create_parametrized_test!(generate_lut_modulus);
 create_parametrized_test!(generate_lut_modulus_not_power_of_two);
+create_parametrized_test!(extract_insert_bits);
 
 fn generate_lut(params: (PBSParameters, WopbsParameters)) {
     let keys = KEY_CACHE_WOPBS.get_from_param(params);
@@ -104,3 +105,39 @@ fn generate_lut_modulus_not_power_of_two(params: (PBSParameters, WopbsParameters
         assert_eq!(res as usize, (m * m) % message_modulus.0);
     }
 }
+
+fn extract_insert_bits(params: (PBSParameters, WopbsParameters)) {
+    let keys = KEY_CACHE_WOPBS.get_from_param(params);
+    let (cks, sks, wopbs_key) = (keys.client_key(), keys.server_key(), keys.wopbs_key());
+    let mut rng = rand::thread_rng();
+
+    let message_modulus = params.0.message_modulus.0 as u64;
+    let carry_modulus = params.0.carry_modulus.0 as u64;
+    let modulus = message_modulus * carry_modulus;
+
+    for _ in 0..NB_TEST {
+        let m = rng.gen::<u64>() % message_modulus;
+        let ct = cks.encrypt(m);
+
+        let mut bits = wopbs_key.extract_all_bits(sks, &ct);
+        assert_eq!(1 << bits.len(), modulus);
+
+        // Round trip
+        let ct_res = wopbs_key.insert_bits(&bits);
+        assert_eq!(cks.decrypt_message_and_carry(&ct_res), m);
+
+        // Swapping the two lowest bits, then xoring the highest one with the lowest one and
+        // flipping the lowest one
+        let highest = bits.len() - 1;
+        bits.swap(0, 1);
+        bits.xor_assign(highest, 0);
+        bits.not_assign(0);
+        let mut expected = (m & !0b11) | ((m & 1) << 1) | ((m >> 1) & 1);
+        expected ^= (expected & 1) << highest;
+        expected ^= 1;
+
+        let ct_res = wopbs_key.insert_bits(&bits);
+        assert_eq!(ct_res.degree.0 as u64, modulus - 1);
+        assert_eq!(cks.decrypt_message_and_carry(&ct_res), expected);
+    }
+}